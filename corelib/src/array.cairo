@@ -21,6 +21,19 @@ trait ArrayTrait<T> {
     fn len(self: @Array<T>) -> usize;
     fn is_empty(self: @Array<T>) -> bool;
     fn span(self: @Array<T>) -> Span<T>;
+    fn map<U, F, impl FFn: Fn::<F, (@T, ), U>, impl FDrop: Drop::<F>, impl UDrop: Drop::<U>>(
+        self: @Array<T>, f: F
+    ) -> Array<U>;
+    fn filter<F,
+    impl FFn: Fn::<F, (@T, ), bool>,
+    impl FDrop: Drop::<F>,
+    impl TCopy: Copy::<T>,
+    impl TDrop: Drop::<T>>(
+        self: @Array<T>, f: F
+    ) -> Array<T>;
+    fn fold<A, F, impl FFn: Fn::<F, (A, @T), A>, impl FDrop: Drop::<F>, impl ADrop: Drop::<A>>(
+        self: @Array<T>, init: A, f: F
+    ) -> A;
 }
 impl ArrayImpl<T> of ArrayTrait::<T> {
     #[inline(always)]
@@ -58,6 +71,32 @@ impl ArrayImpl<T> of ArrayTrait::<T> {
     fn span(self: @Array<T>) -> Span<T> {
         Span { snapshot: self }
     }
+    fn map<U, F, impl FFn: Fn::<F, (@T, ), U>, impl FDrop: Drop::<F>, impl UDrop: Drop::<U>>(
+        self: @Array<T>, f: F
+    ) -> Array<U> {
+        self.span().map(f)
+    }
+    fn filter<F,
+    impl FFn: Fn::<F, (@T, ), bool>,
+    impl FDrop: Drop::<F>,
+    impl TCopy: Copy::<T>,
+    impl TDrop: Drop::<T>>(
+        self: @Array<T>, f: F
+    ) -> Array<T> {
+        self.span().filter(f)
+    }
+    fn fold<A, F, impl FFn: Fn::<F, (A, @T), A>, impl FDrop: Drop::<F>, impl ADrop: Drop::<A>>(
+        self: @Array<T>, init: A, f: F
+    ) -> A {
+        self.span().fold(init, f)
+    }
+}
+
+impl ArrayIndex<T> of IndexView::<Array::<T>, usize, @T> {
+    #[inline(always)]
+    fn index(self: @Array<T>, index: usize) -> @T {
+        array_at(self, index).unbox()
+    }
 }
 
 // Impls for common generic types
@@ -78,6 +117,23 @@ trait SpanTrait<T> {
     fn at(self: Span<T>, index: usize) -> @T;
     fn len(self: Span<T>) -> usize;
     fn is_empty(self: Span<T>) -> bool;
+    /// Returns an array of the results of applying `f` to each of the elements.
+    fn map<U, F, impl FFn: Fn::<F, (@T, ), U>, impl FDrop: Drop::<F>, impl UDrop: Drop::<U>>(
+        self: Span<T>, f: F
+    ) -> Array<U>;
+    /// Returns an array of the elements for which `f` returns true.
+    fn filter<F,
+    impl FFn: Fn::<F, (@T, ), bool>,
+    impl FDrop: Drop::<F>,
+    impl TCopy: Copy::<T>,
+    impl TDrop: Drop::<T>>(
+        self: Span<T>, f: F
+    ) -> Array<T>;
+    /// Combines the elements into a single value, by applying `f` to the accumulated value and
+    /// each of the elements, starting with `init`.
+    fn fold<A, F, impl FFn: Fn::<F, (A, @T), A>, impl FDrop: Drop::<F>, impl ADrop: Drop::<A>>(
+        self: Span<T>, init: A, f: F
+    ) -> A;
 }
 impl SpanImpl<T> of SpanTrait::<T> {
     #[inline(always)]
@@ -107,6 +163,95 @@ impl SpanImpl<T> of SpanTrait::<T> {
     fn is_empty(self: Span<T>) -> bool {
         self.len() == 0_usize
     }
+    fn map<U, F, impl FFn: Fn::<F, (@T, ), U>, impl FDrop: Drop::<F>, impl UDrop: Drop::<U>>(
+        mut self: Span<T>, f: F
+    ) -> Array<U> {
+        let mut result = array_new();
+        loop {
+            match self.pop_front() {
+                Option::Some(item) => {
+                    array_append(ref result, f(item));
+                },
+                Option::None(_) => {
+                    break ();
+                },
+            };
+        };
+        result
+    }
+    fn filter<F,
+    impl FFn: Fn::<F, (@T, ), bool>,
+    impl FDrop: Drop::<F>,
+    impl TCopy: Copy::<T>,
+    impl TDrop: Drop::<T>>(
+        mut self: Span<T>, f: F
+    ) -> Array<T> {
+        let mut result = array_new();
+        loop {
+            match self.pop_front() {
+                Option::Some(item) => {
+                    if f(item) {
+                        array_append(ref result, *item);
+                    }
+                },
+                Option::None(_) => {
+                    break ();
+                },
+            };
+        };
+        result
+    }
+    fn fold<A, F, impl FFn: Fn::<F, (A, @T), A>, impl FDrop: Drop::<F>, impl ADrop: Drop::<A>>(
+        mut self: Span<T>, init: A, f: F
+    ) -> A {
+        let mut acc = init;
+        loop {
+            match self.pop_front() {
+                Option::Some(item) => {
+                    acc = f(acc, item);
+                },
+                Option::None(_) => {
+                    break ();
+                },
+            };
+        };
+        acc
+    }
+}
+
+impl SpanIndex<T> of IndexView::<Span::<T>, usize, @T> {
+    #[inline(always)]
+    fn index(self: @Span<T>, index: usize) -> @T {
+        array_at(*self.snapshot, index).unbox()
+    }
+}
+
+impl SpanIterator<T> of Iterator<Span<T>, @T> {
+    #[inline(always)]
+    fn next(ref self: Span<T>) -> Option<@T> {
+        self.pop_front()
+    }
+}
+
+impl SpanIntoIterator<T> of IntoIterator<Span<T>, Span<T>> {
+    #[inline(always)]
+    fn into_iter(self: Span<T>) -> Span<T> {
+        self
+    }
+}
+
+impl ArrayIterator<T> of Iterator<Array<T>, T> {
+    #[inline(always)]
+    fn next(ref self: Array<T>) -> Option<T> {
+        self.pop_front()
+    }
+}
+
+impl ArrayIntoIterator<T> of IntoIterator<Array<T>, Array<T>> {
+    #[inline(always)]
+    fn into_iter(self: Array<T>) -> Array<T> {
+        self
+    }
 }
 
 impl ArrayTCloneImpl<T, impl TClone: Clone::<T>, impl TDrop: Drop::<T>> of Clone::<Array<T>> {
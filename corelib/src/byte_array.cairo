@@ -0,0 +1,212 @@
+use array::ArrayTrait;
+use array::SpanTrait;
+use bytes_31::Bytes31Trait;
+use bytes_31::one_shift_left_bytes_felt252;
+use bytes_31::one_shift_left_bytes_u128;
+use bytes_31::u8_at_u256;
+use clone::Clone;
+use integer::u256_as_non_zero;
+use integer::u256_safe_div_rem;
+use option::OptionTrait;
+use traits::Into;
+use traits::TryInto;
+
+/// A magic constant, marking panic data that holds a serialized `ByteArray`.
+const BYTE_ARRAY_MAGIC: felt252 = 0x46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3;
+
+/// A growable array of bytes.
+/// The bytes are stored in full words of 31 bytes, followed by a pending word holding the
+/// remaining bytes.
+/// A `ByteArray` literal of any length is written as a string literal with the `_ByteArray`
+/// suffix, e.g. `'a string longer than 31 bytes'_ByteArray`. A string literal without a suffix is
+/// a `felt252` short string, holding at most 31 bytes.
+#[derive(Drop, Clone, Serde)]
+struct ByteArray {
+    // Full words of 31 bytes each. The first byte of a word is its most significant byte.
+    data: Array<bytes31>,
+    // The remaining bytes, which do not fill a full word. The first byte is the most significant
+    // among the `pending_word_len` bytes of the word.
+    pending_word: felt252,
+    // The number of bytes in `pending_word`, in the range [0, 30].
+    pending_word_len: usize,
+}
+
+trait ByteArrayTrait {
+    fn append_word(ref self: ByteArray, word: felt252, len: usize);
+    fn append(ref self: ByteArray, other: @ByteArray);
+    fn concat(left: @ByteArray, right: @ByteArray) -> ByteArray;
+    fn append_byte(ref self: ByteArray, byte: u8);
+    fn len(self: @ByteArray) -> usize;
+    fn at(self: @ByteArray, index: usize) -> Option<u8>;
+}
+
+impl ByteArrayImpl of ByteArrayTrait {
+    /// Appends `len` bytes, given as the big-endian value `word`, to the byte array.
+    /// Assumes that `len <= 31` and that `word` fits in `len` bytes.
+    fn append_word(ref self: ByteArray, word: felt252, len: usize) {
+        if len == 0_u32 {
+            return ();
+        }
+        let total_pending_len = self.pending_word_len + len;
+        if total_pending_len < 31_u32 {
+            self.pending_word = self.pending_word * one_shift_left_bytes_felt252(len) + word;
+            self.pending_word_len = total_pending_len;
+            return ();
+        }
+        // The new bytes complete the pending word to a full word. The rest of the bytes become
+        // the new pending word.
+        let new_pending_len = total_pending_len - 31_u32;
+        let (high, low) = split_word(word, new_pending_len);
+        let full_word = self.pending_word * one_shift_left_bytes_felt252(len - new_pending_len)
+            + high;
+        self.data.append(full_word.try_into().unwrap());
+        self.pending_word = low;
+        self.pending_word_len = new_pending_len;
+    }
+
+    /// Appends the bytes of `other` to the byte array.
+    fn append(ref self: ByteArray, other: @ByteArray) {
+        append_full_words(ref self, other.data.span());
+        self.append_word(*other.pending_word, *other.pending_word_len);
+    }
+
+    /// Returns the concatenation of `left` and `right`.
+    fn concat(left: @ByteArray, right: @ByteArray) -> ByteArray {
+        let mut result = left.clone();
+        result.append(right);
+        result
+    }
+
+    /// Appends a single byte to the byte array.
+    fn append_byte(ref self: ByteArray, byte: u8) {
+        self.append_word(byte.into(), 1_u32);
+    }
+
+    /// Returns the number of bytes in the byte array.
+    fn len(self: @ByteArray) -> usize {
+        self.data.len() * 31_u32 + *self.pending_word_len
+    }
+
+    /// Returns the byte at the given index, or `Option::None` if the index is out of bounds.
+    fn at(self: @ByteArray, index: usize) -> Option<u8> {
+        let (word_index, index_in_word) = integer::u32_safe_divmod(
+            index, integer::u32_as_non_zero(31_u32)
+        );
+        let data_len = self.data.len();
+        if word_index < data_len {
+            // Byte 0 of a full word is its most significant byte, byte 30.
+            return Option::Some((*self.data.at(word_index)).at(30_u32 - index_in_word));
+        }
+        if word_index != data_len | index_in_word >= *self.pending_word_len {
+            return Option::None(());
+        }
+        Option::Some(
+            u8_at_u256((*self.pending_word).into(), *self.pending_word_len - 1_u32 - index_in_word)
+        )
+    }
+}
+
+/// Appends the given full words to `byte_array`.
+fn append_full_words(ref byte_array: ByteArray, mut words: Span<bytes31>) {
+    match gas::withdraw_gas() {
+        Option::Some(_) => {},
+        Option::None(_) => {
+            let mut data = ArrayTrait::new();
+            data.append('Out of gas');
+            panic(data);
+        },
+    }
+    match words.pop_front() {
+        Option::Some(word) => {
+            byte_array.append_word((*word).into(), 31_u32);
+            append_full_words(ref byte_array, words);
+        },
+        Option::None(_) => {},
+    }
+}
+
+/// Splits `word` into its bytes above the lowest `n_bytes` bytes, and its lowest `n_bytes` bytes.
+/// Assumes `word < 2 ** 248` and `n_bytes < 31`.
+fn split_word(word: felt252, n_bytes: usize) -> (felt252, felt252) {
+    let divisor = if n_bytes < 16_u32 {
+        u256 { low: one_shift_left_bytes_u128(n_bytes), high: 0_u128 }
+    } else {
+        u256 { low: 0_u128, high: one_shift_left_bytes_u128(n_bytes - 16_u32) }
+    };
+    let (high, low) = u256_safe_div_rem(word.into(), u256_as_non_zero(divisor));
+    (u256_to_felt252(high), u256_to_felt252(low))
+}
+
+/// Converts a u256 known to be smaller than 2 ** 248 into a felt252.
+fn u256_to_felt252(value: u256) -> felt252 {
+    value.high.into() * 0x100000000000000000000000000000000 + value.low.into()
+}
+
+impl ByteArrayDefault of Default::<ByteArray> {
+    fn default() -> ByteArray {
+        ByteArray { data: ArrayTrait::new(), pending_word: 0, pending_word_len: 0_u32 }
+    }
+}
+
+impl ByteArrayAdd of Add::<ByteArray> {
+    fn add(a: ByteArray, b: ByteArray) -> ByteArray {
+        ByteArrayTrait::concat(@a, @b)
+    }
+}
+impl ByteArrayAddEq of AddEq::<ByteArray> {
+    fn add_eq(ref self: ByteArray, other: ByteArray) {
+        self.append(@other);
+    }
+}
+
+impl ByteArrayIndexView of IndexView::<ByteArray, usize, u8> {
+    fn index(self: @ByteArray, index: usize) -> u8 {
+        self.at(index).expect('Index out of bounds')
+    }
+}
+
+impl ByteArrayPartialEq of PartialEq::<ByteArray> {
+    fn eq(a: ByteArray, b: ByteArray) -> bool {
+        if a.pending_word_len != b.pending_word_len | a.pending_word != b.pending_word {
+            return false;
+        }
+        words_eq(a.data.span(), b.data.span())
+    }
+    fn ne(a: ByteArray, b: ByteArray) -> bool {
+        !(a == b)
+    }
+}
+
+/// Returns whether the two spans of words are equal.
+fn words_eq(mut a: Span<bytes31>, mut b: Span<bytes31>) -> bool {
+    match gas::withdraw_gas() {
+        Option::Some(_) => {},
+        Option::None(_) => {
+            let mut data = ArrayTrait::new();
+            data.append('Out of gas');
+            panic(data);
+        },
+    }
+    match a.pop_front() {
+        Option::Some(a_word) => {
+            match b.pop_front() {
+                Option::Some(b_word) => *a_word == *b_word & words_eq(a, b),
+                Option::None(_) => false,
+            }
+        },
+        Option::None(_) => b.len() == 0_u32,
+    }
+}
+
+/// Returns a byte array holding only the pending word `pending_word`.
+/// Used by the compiler to construct string literals.
+fn string_literal_new(pending_word: felt252, pending_word_len: usize) -> ByteArray {
+    ByteArray { data: ArrayTrait::new(), pending_word, pending_word_len }
+}
+
+/// Appends the full word `word` to the data of `byte_array`.
+/// Used by the compiler to construct string literals.
+fn string_literal_append_word(mut byte_array: ByteArray, word: bytes31) -> ByteArray {
+    byte_array.data.append(word);
+    byte_array
+}
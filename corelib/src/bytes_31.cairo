@@ -0,0 +1,90 @@
+use array::ArrayTrait;
+use option::OptionTrait;
+use traits::Into;
+use traits::TryInto;
+
+/// A type holding 31 bytes, as a value in the range [0, 2 ** 248).
+#[derive(Copy, Drop)]
+extern type bytes31;
+
+extern fn bytes31_const<const value>() -> bytes31 nopanic;
+extern fn bytes31_to_felt252(value: bytes31) -> felt252 nopanic;
+extern fn bytes31_try_from_felt252(value: felt252) -> Option<bytes31> implicits(RangeCheck) nopanic;
+
+trait Bytes31Trait {
+    fn at(self: bytes31, index: usize) -> u8;
+}
+impl Bytes31Impl of Bytes31Trait {
+    /// Returns the byte at the given index, where index 0 is the least significant byte.
+    fn at(self: bytes31, index: usize) -> u8 {
+        assert(index < 31_u32, 'index out of range');
+        u8_at_u256(bytes31_to_felt252(self).into(), index)
+    }
+}
+
+impl Bytes31IntoFelt252 of Into::<bytes31, felt252> {
+    fn into(self: bytes31) -> felt252 {
+        bytes31_to_felt252(self)
+    }
+}
+impl Felt252TryIntoBytes31 of TryInto::<felt252, bytes31> {
+    fn try_into(self: felt252) -> Option<bytes31> {
+        bytes31_try_from_felt252(self)
+    }
+}
+
+impl Bytes31Serde of serde::Serde::<bytes31> {
+    fn serialize(ref serialized: Array<felt252>, input: bytes31) {
+        serde::Serde::serialize(ref serialized, bytes31_to_felt252(input));
+    }
+    fn deserialize(ref serialized: Span<felt252>) -> Option<bytes31> {
+        Option::Some(bytes31_try_from_felt252(serde::Serde::deserialize(ref serialized)?)?)
+    }
+}
+
+impl Bytes31PartialEq of PartialEq::<bytes31> {
+    #[inline(always)]
+    fn eq(a: bytes31, b: bytes31) -> bool {
+        bytes31_to_felt252(a) == bytes31_to_felt252(b)
+    }
+    #[inline(always)]
+    fn ne(a: bytes31, b: bytes31) -> bool {
+        !(a == b)
+    }
+}
+
+/// Returns the byte at the given index of `value`, where index 0 is the least significant byte.
+fn u8_at_u256(value: u256, index: usize) -> u8 {
+    let byte = if index < 16_u32 {
+        (value.low / one_shift_left_bytes_u128(index)) % 0x100_u128
+    } else {
+        (value.high / one_shift_left_bytes_u128(index - 16_u32)) % 0x100_u128
+    };
+    let byte: felt252 = byte.into();
+    byte.try_into().unwrap()
+}
+
+/// Returns `256 ** n_bytes`, as a felt252. Assumes `n_bytes < 32`.
+fn one_shift_left_bytes_felt252(n_bytes: usize) -> felt252 {
+    if n_bytes < 16_u32 {
+        one_shift_left_bytes_u128(n_bytes).into()
+    } else {
+        one_shift_left_bytes_u128(n_bytes - 16_u32).into() * 0x100000000000000000000000000000000
+    }
+}
+
+/// Returns `256 ** n_bytes`, as a u128. Assumes `n_bytes < 16`.
+fn one_shift_left_bytes_u128(n_bytes: usize) -> u128 {
+    match gas::withdraw_gas() {
+        Option::Some(_) => {},
+        Option::None(_) => {
+            let mut data = ArrayTrait::new();
+            data.append('Out of gas');
+            panic(data);
+        },
+    }
+    if n_bytes == 0_u32 {
+        return 1_u128;
+    }
+    0x100_u128 * one_shift_left_bytes_u128(n_bytes - 1_u32)
+}
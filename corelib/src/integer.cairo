@@ -971,6 +971,125 @@ impl U256BitOr of BitOr::<u256> {
     }
 }
 
+extern fn u256_is_zero(a: u256) -> IsZeroResult<u256> implicits() nopanic;
+
+#[panic_with('u256 is 0', u256_as_non_zero)]
+fn u256_try_as_non_zero(a: u256) -> Option<NonZero<u256>> implicits() nopanic {
+    match u256_is_zero(a) {
+        IsZeroResult::Zero(()) => Option::None(()),
+        IsZeroResult::NonZero(x) => Option::Some(x),
+    }
+}
+
+/// A guarantee that `a * b == high * 2**128 + low`, returned by the libfuncs that compute such
+/// products with a hint. Must be consumed by `u128_mul_guarantee_verify`.
+extern type U128MulGuarantee;
+extern fn u128_guarantee_mul(a: u128, b: u128) -> (u128, u128, U128MulGuarantee) nopanic;
+extern fn u128_mul_guarantee_verify(guarantee: U128MulGuarantee) implicits(RangeCheck) nopanic;
+
+extern fn u256_safe_divmod(
+    lhs: u256, rhs: NonZero<u256>
+) -> (u256, u256, U128MulGuarantee) implicits(RangeCheck) nopanic;
+
+/// Returns the quotient and the remainder of the division of `lhs` by `rhs`.
+fn u256_safe_div_rem(lhs: u256, rhs: NonZero<u256>) -> (u256, u256) implicits(RangeCheck) nopanic {
+    let (q, r, guarantee) = u256_safe_divmod(lhs, rhs);
+    u128_mul_guarantee_verify(guarantee);
+    (q, r)
+}
+
+impl U256Div of Div::<u256> {
+    fn div(a: u256, b: u256) -> u256 {
+        let (q, r) = u256_safe_div_rem(a, u256_as_non_zero(b));
+        q
+    }
+}
+impl U256DivEq of DivEq::<u256> {
+    #[inline(always)]
+    fn div_eq(ref self: u256, other: u256) {
+        self = Div::div(self, other);
+    }
+}
+
+impl U256Rem of Rem::<u256> {
+    fn rem(a: u256, b: u256) -> u256 {
+        let (q, r) = u256_safe_div_rem(a, u256_as_non_zero(b));
+        r
+    }
+}
+impl U256RemEq of RemEq::<u256> {
+    #[inline(always)]
+    fn rem_eq(ref self: u256, other: u256) {
+        self = Rem::rem(self, other);
+    }
+}
+
+extern fn u256_sqrt(a: u256) -> u128 implicits(RangeCheck) nopanic;
+
+#[derive(Copy, Drop)]
+struct u512 {
+    limb0: u128,
+    limb1: u128,
+    limb2: u128,
+    limb3: u128,
+}
+
+/// Returns the full 512-bit product of `a` and `b`.
+fn u256_wide_mul(a: u256, b: u256) -> u512 implicits(RangeCheck) nopanic {
+    let (limb1, limb0) = u128_wide_mul(a.low, b.low);
+    let (limb2, limb1_part) = u128_wide_mul(a.low, b.high);
+    let (limb1, limb2) = match u128_overflowing_add(limb1, limb1_part) {
+        Result::Ok(limb1) => (limb1, limb2),
+        Result::Err(limb1) => (limb1, u128_wrapping_add(limb2, 1_u128)),
+    };
+    let (limb2_part, limb1_part) = u128_wide_mul(a.high, b.low);
+    let (limb1, limb2) = match u128_overflowing_add(limb1, limb1_part) {
+        Result::Ok(limb1) => (limb1, limb2),
+        Result::Err(limb1) => (limb1, u128_wrapping_add(limb2, 1_u128)),
+    };
+    let (limb3, limb2_part2) = u128_wide_mul(a.high, b.high);
+    let (limb2, limb3) = match u128_overflowing_add(limb2, limb2_part) {
+        Result::Ok(limb2) => (limb2, limb3),
+        Result::Err(limb2) => (limb2, u128_wrapping_add(limb3, 1_u128)),
+    };
+    let (limb2, limb3) = match u128_overflowing_add(limb2, limb2_part2) {
+        Result::Ok(limb2) => (limb2, limb3),
+        Result::Err(limb2) => (limb2, u128_wrapping_add(limb3, 1_u128)),
+    };
+    u512 { limb0, limb1, limb2, limb3 }
+}
+
+extern fn u512_safe_divmod_by_u256(
+    lhs: u512, rhs: NonZero<u256>
+) -> (
+    u512,
+    u256,
+    U128MulGuarantee,
+    U128MulGuarantee,
+    U128MulGuarantee,
+    U128MulGuarantee,
+    U128MulGuarantee
+) implicits(RangeCheck) nopanic;
+
+/// Returns the quotient and the remainder of the division of `lhs` by `rhs`.
+fn u512_safe_div_rem_by_u256(
+    lhs: u512, rhs: NonZero<u256>
+) -> (u512, u256) implicits(RangeCheck) nopanic {
+    let (q, r, g0, g1, g2, g3, g4) = u512_safe_divmod_by_u256(lhs, rhs);
+    u128_mul_guarantee_verify(g0);
+    u128_mul_guarantee_verify(g1);
+    u128_mul_guarantee_verify(g2);
+    u128_mul_guarantee_verify(g3);
+    u128_mul_guarantee_verify(g4);
+    (q, r)
+}
+
+/// Returns `a * b mod n`.
+fn u256_mul_mod_n(a: u256, b: u256, n: NonZero<u256>) -> u256 implicits(RangeCheck) nopanic {
+    let (q, r) = u512_safe_div_rem_by_u256(u256_wide_mul(a, b), n);
+    r
+}
+
 fn u256_from_felt252(a: felt252) -> u256 implicits(RangeCheck) nopanic {
     match u128s_from_felt252(a) {
         U128sFromFelt252Result::Narrow(low) => u256 { low, high: 0_u128 },
@@ -1063,6 +1182,707 @@ impl U128TryIntoU64 of TryInto::<u128, u64> {
     }
 }
 
+impl U8Default of Default::<u8> {
+    #[inline(always)]
+    fn default() -> u8 {
+        0_u8
+    }
+}
+
+impl U16Default of Default::<u16> {
+    #[inline(always)]
+    fn default() -> u16 {
+        0_u16
+    }
+}
+
+impl U32Default of Default::<u32> {
+    #[inline(always)]
+    fn default() -> u32 {
+        0_u32
+    }
+}
+
+impl U64Default of Default::<u64> {
+    #[inline(always)]
+    fn default() -> u64 {
+        0_u64
+    }
+}
+
+impl U128Default of Default::<u128> {
+    #[inline(always)]
+    fn default() -> u128 {
+        0_u128
+    }
+}
+
+impl U256Default of Default::<u256> {
+    #[inline(always)]
+    fn default() -> u256 {
+        u256 { low: 0_u128, high: 0_u128 }
+    }
+}
+
+// Signed integers.
+
+/// The result of a signed integer overflowing operation, holding the wrapped value on overflow.
+enum SignedIntegerResult<T> {
+    InRange: T,
+    Underflow: T,
+    Overflow: T,
+}
+
+#[derive(Copy, Drop)]
+extern type i8;
+extern fn i8_const<value>() -> i8 nopanic;
+extern fn i8_to_felt252(a: i8) -> felt252 nopanic;
+
+#[panic_with('i8_from OF', i8_from_felt252)]
+extern fn i8_try_from_felt252(a: felt252) -> Option<i8> implicits(RangeCheck) nopanic;
+
+extern fn i8_lt(a: i8, b: i8) -> bool implicits(RangeCheck) nopanic;
+extern fn i8_eq(a: i8, b: i8) -> bool implicits() nopanic;
+extern fn i8_le(a: i8, b: i8) -> bool implicits(RangeCheck) nopanic;
+extern fn i8_is_zero(a: i8) -> IsZeroResult<i8> implicits() nopanic;
+
+impl I8PartialEq of PartialEq::<i8> {
+    #[inline(always)]
+    fn eq(a: i8, b: i8) -> bool {
+        i8_eq(a, b)
+    }
+    #[inline(always)]
+    fn ne(a: i8, b: i8) -> bool {
+        !(a == b)
+    }
+}
+
+impl I8PartialOrd of PartialOrd::<i8> {
+    #[inline(always)]
+    fn le(a: i8, b: i8) -> bool {
+        i8_le(a, b)
+    }
+    #[inline(always)]
+    fn ge(a: i8, b: i8) -> bool {
+        i8_le(b, a)
+    }
+    #[inline(always)]
+    fn lt(a: i8, b: i8) -> bool {
+        i8_lt(a, b)
+    }
+    #[inline(always)]
+    fn gt(a: i8, b: i8) -> bool {
+        i8_lt(b, a)
+    }
+}
+
+extern fn i8_overflowing_add_impl(
+    a: i8, b: i8
+) -> SignedIntegerResult<i8> implicits(RangeCheck) nopanic;
+extern fn i8_overflowing_sub_impl(
+    a: i8, b: i8
+) -> SignedIntegerResult<i8> implicits(RangeCheck) nopanic;
+
+fn i8_checked_add(a: i8, b: i8) -> Option<i8> implicits(RangeCheck) nopanic {
+    match i8_overflowing_add_impl(a, b) {
+        SignedIntegerResult::InRange(r) => Option::Some(r),
+        SignedIntegerResult::Underflow(_) => Option::None(()),
+        SignedIntegerResult::Overflow(_) => Option::None(()),
+    }
+}
+impl I8Add of Add::<i8> {
+    fn add(a: i8, b: i8) -> i8 {
+        i8_checked_add(a, b).expect('i8_add Overflow')
+    }
+}
+impl I8AddEq of AddEq::<i8> {
+    #[inline(always)]
+    fn add_eq(ref self: i8, other: i8) {
+        self = Add::add(self, other);
+    }
+}
+
+fn i8_checked_sub(a: i8, b: i8) -> Option<i8> implicits(RangeCheck) nopanic {
+    match i8_overflowing_sub_impl(a, b) {
+        SignedIntegerResult::InRange(r) => Option::Some(r),
+        SignedIntegerResult::Underflow(_) => Option::None(()),
+        SignedIntegerResult::Overflow(_) => Option::None(()),
+    }
+}
+impl I8Sub of Sub::<i8> {
+    fn sub(a: i8, b: i8) -> i8 {
+        i8_checked_sub(a, b).expect('i8_sub Overflow')
+    }
+}
+impl I8SubEq of SubEq::<i8> {
+    #[inline(always)]
+    fn sub_eq(ref self: i8, other: i8) {
+        self = Sub::sub(self, other);
+    }
+}
+
+impl I8Neg of Neg::<i8> {
+    #[inline(always)]
+    fn neg(a: i8) -> i8 {
+        0_i8 - a
+    }
+}
+
+extern fn i8_wide_mul(a: i8, b: i8) -> i16 implicits() nopanic;
+impl I8Mul of Mul::<i8> {
+    fn mul(a: i8, b: i8) -> i8 {
+        downcast(i8_wide_mul(a, b)).expect('i8_mul Overflow')
+    }
+}
+impl I8MulEq of MulEq::<i8> {
+    #[inline(always)]
+    fn mul_eq(ref self: i8, other: i8) {
+        self = Mul::mul(self, other);
+    }
+}
+
+impl Felt252TryIntoI8 of TryInto::<felt252, i8> {
+    fn try_into(self: felt252) -> Option<i8> {
+        i8_try_from_felt252(self)
+    }
+}
+impl I8IntoFelt252 of Into::<i8, felt252> {
+    fn into(self: i8) -> felt252 {
+        i8_to_felt252(self)
+    }
+}
+
+impl I8Default of Default::<i8> {
+    #[inline(always)]
+    fn default() -> i8 {
+        0_i8
+    }
+}
+
+#[derive(Copy, Drop)]
+extern type i16;
+extern fn i16_const<value>() -> i16 nopanic;
+extern fn i16_to_felt252(a: i16) -> felt252 nopanic;
+
+#[panic_with('i16_from OF', i16_from_felt252)]
+extern fn i16_try_from_felt252(a: felt252) -> Option<i16> implicits(RangeCheck) nopanic;
+
+extern fn i16_lt(a: i16, b: i16) -> bool implicits(RangeCheck) nopanic;
+extern fn i16_eq(a: i16, b: i16) -> bool implicits() nopanic;
+extern fn i16_le(a: i16, b: i16) -> bool implicits(RangeCheck) nopanic;
+extern fn i16_is_zero(a: i16) -> IsZeroResult<i16> implicits() nopanic;
+
+impl I16PartialEq of PartialEq::<i16> {
+    #[inline(always)]
+    fn eq(a: i16, b: i16) -> bool {
+        i16_eq(a, b)
+    }
+    #[inline(always)]
+    fn ne(a: i16, b: i16) -> bool {
+        !(a == b)
+    }
+}
+
+impl I16PartialOrd of PartialOrd::<i16> {
+    #[inline(always)]
+    fn le(a: i16, b: i16) -> bool {
+        i16_le(a, b)
+    }
+    #[inline(always)]
+    fn ge(a: i16, b: i16) -> bool {
+        i16_le(b, a)
+    }
+    #[inline(always)]
+    fn lt(a: i16, b: i16) -> bool {
+        i16_lt(a, b)
+    }
+    #[inline(always)]
+    fn gt(a: i16, b: i16) -> bool {
+        i16_lt(b, a)
+    }
+}
+
+extern fn i16_overflowing_add_impl(
+    a: i16, b: i16
+) -> SignedIntegerResult<i16> implicits(RangeCheck) nopanic;
+extern fn i16_overflowing_sub_impl(
+    a: i16, b: i16
+) -> SignedIntegerResult<i16> implicits(RangeCheck) nopanic;
+
+fn i16_checked_add(a: i16, b: i16) -> Option<i16> implicits(RangeCheck) nopanic {
+    match i16_overflowing_add_impl(a, b) {
+        SignedIntegerResult::InRange(r) => Option::Some(r),
+        SignedIntegerResult::Underflow(_) => Option::None(()),
+        SignedIntegerResult::Overflow(_) => Option::None(()),
+    }
+}
+impl I16Add of Add::<i16> {
+    fn add(a: i16, b: i16) -> i16 {
+        i16_checked_add(a, b).expect('i16_add Overflow')
+    }
+}
+impl I16AddEq of AddEq::<i16> {
+    #[inline(always)]
+    fn add_eq(ref self: i16, other: i16) {
+        self = Add::add(self, other);
+    }
+}
+
+fn i16_checked_sub(a: i16, b: i16) -> Option<i16> implicits(RangeCheck) nopanic {
+    match i16_overflowing_sub_impl(a, b) {
+        SignedIntegerResult::InRange(r) => Option::Some(r),
+        SignedIntegerResult::Underflow(_) => Option::None(()),
+        SignedIntegerResult::Overflow(_) => Option::None(()),
+    }
+}
+impl I16Sub of Sub::<i16> {
+    fn sub(a: i16, b: i16) -> i16 {
+        i16_checked_sub(a, b).expect('i16_sub Overflow')
+    }
+}
+impl I16SubEq of SubEq::<i16> {
+    #[inline(always)]
+    fn sub_eq(ref self: i16, other: i16) {
+        self = Sub::sub(self, other);
+    }
+}
+
+impl I16Neg of Neg::<i16> {
+    #[inline(always)]
+    fn neg(a: i16) -> i16 {
+        0_i16 - a
+    }
+}
+
+extern fn i16_wide_mul(a: i16, b: i16) -> i32 implicits() nopanic;
+impl I16Mul of Mul::<i16> {
+    fn mul(a: i16, b: i16) -> i16 {
+        downcast(i16_wide_mul(a, b)).expect('i16_mul Overflow')
+    }
+}
+impl I16MulEq of MulEq::<i16> {
+    #[inline(always)]
+    fn mul_eq(ref self: i16, other: i16) {
+        self = Mul::mul(self, other);
+    }
+}
+
+impl Felt252TryIntoI16 of TryInto::<felt252, i16> {
+    fn try_into(self: felt252) -> Option<i16> {
+        i16_try_from_felt252(self)
+    }
+}
+impl I16IntoFelt252 of Into::<i16, felt252> {
+    fn into(self: i16) -> felt252 {
+        i16_to_felt252(self)
+    }
+}
+
+impl I16Default of Default::<i16> {
+    #[inline(always)]
+    fn default() -> i16 {
+        0_i16
+    }
+}
+
+#[derive(Copy, Drop)]
+extern type i32;
+extern fn i32_const<value>() -> i32 nopanic;
+extern fn i32_to_felt252(a: i32) -> felt252 nopanic;
+
+#[panic_with('i32_from OF', i32_from_felt252)]
+extern fn i32_try_from_felt252(a: felt252) -> Option<i32> implicits(RangeCheck) nopanic;
+
+extern fn i32_lt(a: i32, b: i32) -> bool implicits(RangeCheck) nopanic;
+extern fn i32_eq(a: i32, b: i32) -> bool implicits() nopanic;
+extern fn i32_le(a: i32, b: i32) -> bool implicits(RangeCheck) nopanic;
+extern fn i32_is_zero(a: i32) -> IsZeroResult<i32> implicits() nopanic;
+
+impl I32PartialEq of PartialEq::<i32> {
+    #[inline(always)]
+    fn eq(a: i32, b: i32) -> bool {
+        i32_eq(a, b)
+    }
+    #[inline(always)]
+    fn ne(a: i32, b: i32) -> bool {
+        !(a == b)
+    }
+}
+
+impl I32PartialOrd of PartialOrd::<i32> {
+    #[inline(always)]
+    fn le(a: i32, b: i32) -> bool {
+        i32_le(a, b)
+    }
+    #[inline(always)]
+    fn ge(a: i32, b: i32) -> bool {
+        i32_le(b, a)
+    }
+    #[inline(always)]
+    fn lt(a: i32, b: i32) -> bool {
+        i32_lt(a, b)
+    }
+    #[inline(always)]
+    fn gt(a: i32, b: i32) -> bool {
+        i32_lt(b, a)
+    }
+}
+
+extern fn i32_overflowing_add_impl(
+    a: i32, b: i32
+) -> SignedIntegerResult<i32> implicits(RangeCheck) nopanic;
+extern fn i32_overflowing_sub_impl(
+    a: i32, b: i32
+) -> SignedIntegerResult<i32> implicits(RangeCheck) nopanic;
+
+fn i32_checked_add(a: i32, b: i32) -> Option<i32> implicits(RangeCheck) nopanic {
+    match i32_overflowing_add_impl(a, b) {
+        SignedIntegerResult::InRange(r) => Option::Some(r),
+        SignedIntegerResult::Underflow(_) => Option::None(()),
+        SignedIntegerResult::Overflow(_) => Option::None(()),
+    }
+}
+impl I32Add of Add::<i32> {
+    fn add(a: i32, b: i32) -> i32 {
+        i32_checked_add(a, b).expect('i32_add Overflow')
+    }
+}
+impl I32AddEq of AddEq::<i32> {
+    #[inline(always)]
+    fn add_eq(ref self: i32, other: i32) {
+        self = Add::add(self, other);
+    }
+}
+
+fn i32_checked_sub(a: i32, b: i32) -> Option<i32> implicits(RangeCheck) nopanic {
+    match i32_overflowing_sub_impl(a, b) {
+        SignedIntegerResult::InRange(r) => Option::Some(r),
+        SignedIntegerResult::Underflow(_) => Option::None(()),
+        SignedIntegerResult::Overflow(_) => Option::None(()),
+    }
+}
+impl I32Sub of Sub::<i32> {
+    fn sub(a: i32, b: i32) -> i32 {
+        i32_checked_sub(a, b).expect('i32_sub Overflow')
+    }
+}
+impl I32SubEq of SubEq::<i32> {
+    #[inline(always)]
+    fn sub_eq(ref self: i32, other: i32) {
+        self = Sub::sub(self, other);
+    }
+}
+
+impl I32Neg of Neg::<i32> {
+    #[inline(always)]
+    fn neg(a: i32) -> i32 {
+        0_i32 - a
+    }
+}
+
+extern fn i32_wide_mul(a: i32, b: i32) -> i64 implicits() nopanic;
+impl I32Mul of Mul::<i32> {
+    fn mul(a: i32, b: i32) -> i32 {
+        downcast(i32_wide_mul(a, b)).expect('i32_mul Overflow')
+    }
+}
+impl I32MulEq of MulEq::<i32> {
+    #[inline(always)]
+    fn mul_eq(ref self: i32, other: i32) {
+        self = Mul::mul(self, other);
+    }
+}
+
+impl Felt252TryIntoI32 of TryInto::<felt252, i32> {
+    fn try_into(self: felt252) -> Option<i32> {
+        i32_try_from_felt252(self)
+    }
+}
+impl I32IntoFelt252 of Into::<i32, felt252> {
+    fn into(self: i32) -> felt252 {
+        i32_to_felt252(self)
+    }
+}
+
+impl I32Default of Default::<i32> {
+    #[inline(always)]
+    fn default() -> i32 {
+        0_i32
+    }
+}
+
+#[derive(Copy, Drop)]
+extern type i64;
+extern fn i64_const<value>() -> i64 nopanic;
+extern fn i64_to_felt252(a: i64) -> felt252 nopanic;
+
+#[panic_with('i64_from OF', i64_from_felt252)]
+extern fn i64_try_from_felt252(a: felt252) -> Option<i64> implicits(RangeCheck) nopanic;
+
+extern fn i64_lt(a: i64, b: i64) -> bool implicits(RangeCheck) nopanic;
+extern fn i64_eq(a: i64, b: i64) -> bool implicits() nopanic;
+extern fn i64_le(a: i64, b: i64) -> bool implicits(RangeCheck) nopanic;
+extern fn i64_is_zero(a: i64) -> IsZeroResult<i64> implicits() nopanic;
+
+impl I64PartialEq of PartialEq::<i64> {
+    #[inline(always)]
+    fn eq(a: i64, b: i64) -> bool {
+        i64_eq(a, b)
+    }
+    #[inline(always)]
+    fn ne(a: i64, b: i64) -> bool {
+        !(a == b)
+    }
+}
+
+impl I64PartialOrd of PartialOrd::<i64> {
+    #[inline(always)]
+    fn le(a: i64, b: i64) -> bool {
+        i64_le(a, b)
+    }
+    #[inline(always)]
+    fn ge(a: i64, b: i64) -> bool {
+        i64_le(b, a)
+    }
+    #[inline(always)]
+    fn lt(a: i64, b: i64) -> bool {
+        i64_lt(a, b)
+    }
+    #[inline(always)]
+    fn gt(a: i64, b: i64) -> bool {
+        i64_lt(b, a)
+    }
+}
+
+extern fn i64_overflowing_add_impl(
+    a: i64, b: i64
+) -> SignedIntegerResult<i64> implicits(RangeCheck) nopanic;
+extern fn i64_overflowing_sub_impl(
+    a: i64, b: i64
+) -> SignedIntegerResult<i64> implicits(RangeCheck) nopanic;
+
+fn i64_checked_add(a: i64, b: i64) -> Option<i64> implicits(RangeCheck) nopanic {
+    match i64_overflowing_add_impl(a, b) {
+        SignedIntegerResult::InRange(r) => Option::Some(r),
+        SignedIntegerResult::Underflow(_) => Option::None(()),
+        SignedIntegerResult::Overflow(_) => Option::None(()),
+    }
+}
+impl I64Add of Add::<i64> {
+    fn add(a: i64, b: i64) -> i64 {
+        i64_checked_add(a, b).expect('i64_add Overflow')
+    }
+}
+impl I64AddEq of AddEq::<i64> {
+    #[inline(always)]
+    fn add_eq(ref self: i64, other: i64) {
+        self = Add::add(self, other);
+    }
+}
+
+fn i64_checked_sub(a: i64, b: i64) -> Option<i64> implicits(RangeCheck) nopanic {
+    match i64_overflowing_sub_impl(a, b) {
+        SignedIntegerResult::InRange(r) => Option::Some(r),
+        SignedIntegerResult::Underflow(_) => Option::None(()),
+        SignedIntegerResult::Overflow(_) => Option::None(()),
+    }
+}
+impl I64Sub of Sub::<i64> {
+    fn sub(a: i64, b: i64) -> i64 {
+        i64_checked_sub(a, b).expect('i64_sub Overflow')
+    }
+}
+impl I64SubEq of SubEq::<i64> {
+    #[inline(always)]
+    fn sub_eq(ref self: i64, other: i64) {
+        self = Sub::sub(self, other);
+    }
+}
+
+impl I64Neg of Neg::<i64> {
+    #[inline(always)]
+    fn neg(a: i64) -> i64 {
+        0_i64 - a
+    }
+}
+
+extern fn i64_wide_mul(a: i64, b: i64) -> i128 implicits() nopanic;
+impl I64Mul of Mul::<i64> {
+    fn mul(a: i64, b: i64) -> i64 {
+        downcast(i64_wide_mul(a, b)).expect('i64_mul Overflow')
+    }
+}
+impl I64MulEq of MulEq::<i64> {
+    #[inline(always)]
+    fn mul_eq(ref self: i64, other: i64) {
+        self = Mul::mul(self, other);
+    }
+}
+
+impl Felt252TryIntoI64 of TryInto::<felt252, i64> {
+    fn try_into(self: felt252) -> Option<i64> {
+        i64_try_from_felt252(self)
+    }
+}
+impl I64IntoFelt252 of Into::<i64, felt252> {
+    fn into(self: i64) -> felt252 {
+        i64_to_felt252(self)
+    }
+}
+
+impl I64Default of Default::<i64> {
+    #[inline(always)]
+    fn default() -> i64 {
+        0_i64
+    }
+}
+
+#[derive(Copy, Drop)]
+extern type i128;
+extern fn i128_const<value>() -> i128 nopanic;
+extern fn i128_to_felt252(a: i128) -> felt252 nopanic;
+
+#[panic_with('i128_from OF', i128_from_felt252)]
+extern fn i128_try_from_felt252(a: felt252) -> Option<i128> implicits(RangeCheck) nopanic;
+
+extern fn i128_lt(a: i128, b: i128) -> bool implicits(RangeCheck) nopanic;
+extern fn i128_eq(a: i128, b: i128) -> bool implicits() nopanic;
+extern fn i128_le(a: i128, b: i128) -> bool implicits(RangeCheck) nopanic;
+extern fn i128_is_zero(a: i128) -> IsZeroResult<i128> implicits() nopanic;
+
+impl I128PartialEq of PartialEq::<i128> {
+    #[inline(always)]
+    fn eq(a: i128, b: i128) -> bool {
+        i128_eq(a, b)
+    }
+    #[inline(always)]
+    fn ne(a: i128, b: i128) -> bool {
+        !(a == b)
+    }
+}
+
+impl I128PartialOrd of PartialOrd::<i128> {
+    #[inline(always)]
+    fn le(a: i128, b: i128) -> bool {
+        i128_le(a, b)
+    }
+    #[inline(always)]
+    fn ge(a: i128, b: i128) -> bool {
+        i128_le(b, a)
+    }
+    #[inline(always)]
+    fn lt(a: i128, b: i128) -> bool {
+        i128_lt(a, b)
+    }
+    #[inline(always)]
+    fn gt(a: i128, b: i128) -> bool {
+        i128_lt(b, a)
+    }
+}
+
+extern fn i128_overflowing_add_impl(
+    a: i128, b: i128
+) -> SignedIntegerResult<i128> implicits(RangeCheck) nopanic;
+extern fn i128_overflowing_sub_impl(
+    a: i128, b: i128
+) -> SignedIntegerResult<i128> implicits(RangeCheck) nopanic;
+
+fn i128_checked_add(a: i128, b: i128) -> Option<i128> implicits(RangeCheck) nopanic {
+    match i128_overflowing_add_impl(a, b) {
+        SignedIntegerResult::InRange(r) => Option::Some(r),
+        SignedIntegerResult::Underflow(_) => Option::None(()),
+        SignedIntegerResult::Overflow(_) => Option::None(()),
+    }
+}
+impl I128Add of Add::<i128> {
+    fn add(a: i128, b: i128) -> i128 {
+        i128_checked_add(a, b).expect('i128_add Overflow')
+    }
+}
+impl I128AddEq of AddEq::<i128> {
+    #[inline(always)]
+    fn add_eq(ref self: i128, other: i128) {
+        self = Add::add(self, other);
+    }
+}
+
+fn i128_checked_sub(a: i128, b: i128) -> Option<i128> implicits(RangeCheck) nopanic {
+    match i128_overflowing_sub_impl(a, b) {
+        SignedIntegerResult::InRange(r) => Option::Some(r),
+        SignedIntegerResult::Underflow(_) => Option::None(()),
+        SignedIntegerResult::Overflow(_) => Option::None(()),
+    }
+}
+impl I128Sub of Sub::<i128> {
+    fn sub(a: i128, b: i128) -> i128 {
+        i128_checked_sub(a, b).expect('i128_sub Overflow')
+    }
+}
+impl I128SubEq of SubEq::<i128> {
+    #[inline(always)]
+    fn sub_eq(ref self: i128, other: i128) {
+        self = Sub::sub(self, other);
+    }
+}
+
+impl I128Neg of Neg::<i128> {
+    #[inline(always)]
+    fn neg(a: i128) -> i128 {
+        0_i128 - a
+    }
+}
+
+impl I128Mul of Mul::<i128> {
+    fn mul(a: i128, b: i128) -> i128 {
+        let a_neg = a < 0_i128;
+        let b_neg = b < 0_i128;
+        let a_abs = if a_neg {
+            -i128_to_felt252(a)
+        } else {
+            i128_to_felt252(a)
+        };
+        let b_abs = if b_neg {
+            -i128_to_felt252(b)
+        } else {
+            i128_to_felt252(b)
+        };
+        let res_abs = u128_checked_mul(
+            u128_from_felt252(a_abs), u128_from_felt252(b_abs)
+        ).expect('i128_mul Overflow');
+        let res_abs = u128_to_felt252(res_abs);
+        let res = if a_neg ^ b_neg {
+            -res_abs
+        } else {
+            res_abs
+        };
+        i128_try_from_felt252(res).expect('i128_mul Overflow')
+    }
+}
+impl I128MulEq of MulEq::<i128> {
+    #[inline(always)]
+    fn mul_eq(ref self: i128, other: i128) {
+        self = Mul::mul(self, other);
+    }
+}
+
+impl Felt252TryIntoI128 of TryInto::<felt252, i128> {
+    fn try_into(self: felt252) -> Option<i128> {
+        i128_try_from_felt252(self)
+    }
+}
+impl I128IntoFelt252 of Into::<i128, felt252> {
+    fn into(self: i128) -> felt252 {
+        i128_to_felt252(self)
+    }
+}
+
+impl I128Default of Default::<i128> {
+    #[inline(always)]
+    fn default() -> i128 {
+        0_i128
+    }
+}
+
 // TODO(lior): Restrict the function (using traits) in the high-level compiler so that wrong types
 //   will not lead to Sierra errors.
 extern fn upcast<FromType, ToType>(x: FromType) -> ToType nopanic;
@@ -0,0 +1,11 @@
+/// Trait for types that produce a sequence of items, one at a time.
+trait Iterator<T, Item> {
+    /// Advances the iterator and returns the next item, or `Option::None` when the iteration is
+    /// finished.
+    fn next(ref self: T) -> Option<Item>;
+}
+
+/// Trait for types that can be converted into an iterator. This is what `for` loops iterate over.
+trait IntoIterator<T, IntoIter> {
+    fn into_iter(self: T) -> IntoIter;
+}
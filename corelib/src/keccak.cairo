@@ -0,0 +1,131 @@
+use array::ArrayTrait;
+use array::SpanTrait;
+use gas::get_builtin_costs;
+use gas::withdraw_gas_all;
+use option::OptionTrait;
+use starknet::SyscallResultTrait;
+use traits::TryInto;
+
+/// Splits a u128 into its high and low u64 words.
+fn u128_split(input: u128) -> (u64, u64) {
+    let (high, low) = integer::u128_safe_divmod(
+        input, integer::u128_as_non_zero(0x10000000000000000_u128)
+    );
+    (high.try_into().unwrap(), low.try_into().unwrap())
+}
+
+/// Appends the 4 u64 words of `value`, lowest word first, to `keccak_input`.
+fn keccak_add_u256_le(ref keccak_input: Array<u64>, value: u256) {
+    let (high, low) = u128_split(value.low);
+    keccak_input.append(low);
+    keccak_input.append(high);
+    let (high, low) = u128_split(value.high);
+    keccak_input.append(low);
+    keccak_input.append(high);
+}
+
+fn keccak_add_u256s_le(ref keccak_input: Array<u64>, mut input: Span<u256>) {
+    match withdraw_gas_all(get_builtin_costs()) {
+        Option::Some(_) => {},
+        Option::None(_) => {
+            let mut data = ArrayTrait::new();
+            data.append('Out of gas');
+            panic(data);
+        },
+    }
+    match input.pop_front() {
+        Option::Some(value) => {
+            keccak_add_u256_le(ref keccak_input, *value);
+            keccak_add_u256s_le(ref keccak_input, input);
+        },
+        Option::None(_) => {},
+    }
+}
+
+/// Computes the keccak256 of the given u256 values, where each value is interpreted as 32
+/// little-endian bytes.
+/// The 32-byte result is returned as a little-endian u256.
+fn keccak_u256s_le(input: Span<u256>) -> u256 {
+    let mut keccak_input = ArrayTrait::new();
+    keccak_add_u256s_le(ref keccak_input, input);
+    add_padding(ref keccak_input);
+    starknet::keccak_syscall(keccak_input.span()).unwrap_syscall()
+}
+
+fn keccak_add_u256s_be(ref keccak_input: Array<u64>, mut input: Span<u256>) {
+    match withdraw_gas_all(get_builtin_costs()) {
+        Option::Some(_) => {},
+        Option::None(_) => {
+            let mut data = ArrayTrait::new();
+            data.append('Out of gas');
+            panic(data);
+        },
+    }
+    match input.pop_front() {
+        Option::Some(value) => {
+            keccak_add_u256_le(ref keccak_input, u256_reverse_endian(*value));
+            keccak_add_u256s_be(ref keccak_input, input);
+        },
+        Option::None(_) => {},
+    }
+}
+
+/// Computes the keccak256 of the given u256 values, where each value is interpreted as 32
+/// big-endian bytes.
+/// The 32-byte result is returned as a little-endian u256.
+fn keccak_u256s_be(input: Span<u256>) -> u256 {
+    let mut keccak_input = ArrayTrait::new();
+    keccak_add_u256s_be(ref keccak_input, input);
+    add_padding(ref keccak_input);
+    starknet::keccak_syscall(keccak_input.span()).unwrap_syscall()
+}
+
+/// Pads the input words to a whole number of 17-word blocks, with a 0x01 byte right after the
+/// input, a 0x80 byte at the end of the last block, and zero bytes in between.
+fn add_padding(ref input: Array<u64>) {
+    let (_, last_block_num_full_words) = integer::u32_safe_divmod(
+        input.len(), integer::u32_as_non_zero(17_u32)
+    );
+    if last_block_num_full_words == 16_u32 {
+        input.append(0x8000000000000001_u64);
+        return ();
+    }
+    input.append(1_u64);
+    finalize_padding(ref input, 16_u32 - last_block_num_full_words);
+}
+
+/// Appends `num_padding_words - 1` zero words, followed by 0x8000000000000000.
+fn finalize_padding(ref input: Array<u64>, num_padding_words: u32) {
+    match withdraw_gas_all(get_builtin_costs()) {
+        Option::Some(_) => {},
+        Option::None(_) => {
+            let mut data = ArrayTrait::new();
+            data.append('Out of gas');
+            panic(data);
+        },
+    }
+    if num_padding_words == 1_u32 {
+        input.append(0x8000000000000000_u64);
+        return ();
+    }
+    input.append(0_u64);
+    finalize_padding(ref input, num_padding_words - 1_u32);
+}
+
+/// Returns `value` with the order of its bytes reversed.
+fn u256_reverse_endian(value: u256) -> u256 {
+    u256 { low: u128_reverse_endian(value.high), high: u128_reverse_endian(value.low) }
+}
+
+/// Returns `value` with the order of its bytes reversed.
+fn u128_reverse_endian(value: u128) -> u128 {
+    // Swap adjacent bytes, then adjacent 2-byte words, 4-byte words and finally 8-byte words.
+    let value = (value & 0x00ff00ff00ff00ff00ff00ff00ff00ff_u128) * 0x100_u128
+        + (value & 0xff00ff00ff00ff00ff00ff00ff00ff00_u128) / 0x100_u128;
+    let value = (value & 0x0000ffff0000ffff0000ffff0000ffff_u128) * 0x10000_u128
+        + (value & 0xffff0000ffff0000ffff0000ffff0000_u128) / 0x10000_u128;
+    let value = (value & 0x00000000ffffffff00000000ffffffff_u128) * 0x100000000_u128
+        + (value & 0xffffffff00000000ffffffff00000000_u128) / 0x100000000_u128;
+    (value & 0x0000000000000000ffffffffffffffff_u128) * 0x10000000000000000_u128
+        + value / 0x10000000000000000_u128
+}
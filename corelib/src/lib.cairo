@@ -21,6 +21,11 @@ use traits::Neg;
 use traits::Into;
 use traits::TryInto;
 use traits::Destruct;
+use traits::Default;
+use traits::FnOnce;
+use traits::Fn;
+use traits::Index;
+use traits::IndexView;
 
 #[derive(Copy, Drop)]
 enum bool {
@@ -78,6 +83,13 @@ impl BoolPartialEq of PartialEq::<bool> {
 
 extern fn bool_to_felt252(a: bool) -> felt252 implicits() nopanic;
 
+impl BoolDefault of Default::<bool> {
+    #[inline(always)]
+    fn default() -> bool {
+        false
+    }
+}
+
 // General purpose implicits.
 extern type RangeCheck;
 extern type SegmentArena;
@@ -173,6 +185,32 @@ impl Felt252PartialEq of PartialEq::<felt252> {
 
 extern fn felt252_is_zero(a: felt252) -> IsZeroResult<felt252> nopanic;
 
+impl Felt252Default of Default::<felt252> {
+    #[inline(always)]
+    fn default() -> felt252 {
+        0
+    }
+}
+
+// Unit.
+impl TupleSize0Default of Default::<()> {
+    #[inline(always)]
+    fn default() -> () {
+        ()
+    }
+}
+
+impl TupleSize0PartialEq of PartialEq::<()> {
+    #[inline(always)]
+    fn eq(a: (), b: ()) -> bool {
+        true
+    }
+    #[inline(always)]
+    fn ne(a: (), b: ()) -> bool {
+        false
+    }
+}
+
 // TODO(spapini): Constraint using Copy and Drop traits.
 extern fn dup<T>(obj: T) -> (T, T) nopanic;
 extern fn drop<T>(obj: T) nopanic;
@@ -195,10 +233,41 @@ mod array;
 use array::Array;
 use array::ArrayTrait;
 use array::ArrayImpl;
+use array::ArrayIndex;
 type usize = u32;
 
 // Span.
 use array::Span;
+use array::SpanIndex;
+use array::SpanIterator;
+use array::SpanIntoIterator;
+use array::ArrayIterator;
+use array::ArrayIntoIterator;
+
+// Iteration.
+mod iter;
+use iter::Iterator;
+use iter::IntoIterator;
+
+// Ranges.
+mod range;
+use range::Range;
+use range::RangeOp;
+use range::RangeOpImpl;
+use range::RangeIterator;
+use range::RangeIntoIterator;
+use range::Step;
+use range::U8Step;
+use range::U16Step;
+use range::U32Step;
+use range::U64Step;
+use range::U128Step;
+use range::U256Step;
+use range::I8Step;
+use range::I16Step;
+use range::I32Step;
+use range::I64Step;
+use range::I128Step;
 
 
 // Dictionary.
@@ -211,6 +280,7 @@ use dict::felt252_dict_read;
 use dict::felt252_dict_squash;
 use dict::Felt252DictTrait;
 use dict::Felt252DictImpl;
+use dict::Felt252DictIndex;
 
 // Result.
 mod result;
@@ -275,6 +345,7 @@ use integer::U128PartialEq;
 use integer::U128BitAnd;
 use integer::U128BitOr;
 use integer::U128BitXor;
+use integer::U128Default;
 use integer::u128_is_zero;
 use integer::u8;
 use integer::u8_const;
@@ -290,6 +361,7 @@ use integer::U8DivEq;
 use integer::U8RemEq;
 use integer::U8PartialEq;
 use integer::U8PartialOrd;
+use integer::U8Default;
 use integer::u16;
 use integer::u16_const;
 use integer::U16Add;
@@ -304,6 +376,7 @@ use integer::U16DivEq;
 use integer::U16RemEq;
 use integer::U16PartialEq;
 use integer::U16PartialOrd;
+use integer::U16Default;
 use integer::u32;
 use integer::u32_const;
 use integer::U32Add;
@@ -318,6 +391,7 @@ use integer::U32DivEq;
 use integer::U32RemEq;
 use integer::U32PartialEq;
 use integer::U32PartialOrd;
+use integer::U32Default;
 use integer::u64;
 use integer::u64_const;
 use integer::U64Add;
@@ -332,18 +406,25 @@ use integer::U64DivEq;
 use integer::U64RemEq;
 use integer::U64PartialEq;
 use integer::U64PartialOrd;
+use integer::U64Default;
 use integer::u256;
+use integer::u256_sqrt;
 use integer::U256Add;
 use integer::U256Sub;
 use integer::U256Mul;
+use integer::U256Div;
+use integer::U256Rem;
 use integer::U256AddEq;
 use integer::U256SubEq;
 use integer::U256MulEq;
+use integer::U256DivEq;
+use integer::U256RemEq;
 use integer::U256PartialOrd;
 use integer::U256PartialEq;
 use integer::U256BitAnd;
 use integer::U256BitOr;
 use integer::U256BitXor;
+use integer::U256Default;
 use integer::Felt252TryIntoU8;
 use integer::U8IntoFelt252;
 use integer::Felt252TryIntoU16;
@@ -359,8 +440,97 @@ use integer::U32TryIntoU16;
 use integer::U64TryIntoU32;
 use integer::U128TryIntoU64;
 use integer::Felt252IntoU256;
+use integer::i8;
+use integer::i8_const;
+use integer::I8Add;
+use integer::I8Sub;
+use integer::I8Mul;
+use integer::I8AddEq;
+use integer::I8SubEq;
+use integer::I8MulEq;
+use integer::I8Neg;
+use integer::I8PartialEq;
+use integer::I8PartialOrd;
+use integer::I8Default;
+use integer::Felt252TryIntoI8;
+use integer::I8IntoFelt252;
+use integer::i16;
+use integer::i16_const;
+use integer::I16Add;
+use integer::I16Sub;
+use integer::I16Mul;
+use integer::I16AddEq;
+use integer::I16SubEq;
+use integer::I16MulEq;
+use integer::I16Neg;
+use integer::I16PartialEq;
+use integer::I16PartialOrd;
+use integer::I16Default;
+use integer::Felt252TryIntoI16;
+use integer::I16IntoFelt252;
+use integer::i32;
+use integer::i32_const;
+use integer::I32Add;
+use integer::I32Sub;
+use integer::I32Mul;
+use integer::I32AddEq;
+use integer::I32SubEq;
+use integer::I32MulEq;
+use integer::I32Neg;
+use integer::I32PartialEq;
+use integer::I32PartialOrd;
+use integer::I32Default;
+use integer::Felt252TryIntoI32;
+use integer::I32IntoFelt252;
+use integer::i64;
+use integer::i64_const;
+use integer::I64Add;
+use integer::I64Sub;
+use integer::I64Mul;
+use integer::I64AddEq;
+use integer::I64SubEq;
+use integer::I64MulEq;
+use integer::I64Neg;
+use integer::I64PartialEq;
+use integer::I64PartialOrd;
+use integer::I64Default;
+use integer::Felt252TryIntoI64;
+use integer::I64IntoFelt252;
+use integer::i128;
+use integer::i128_const;
+use integer::I128Add;
+use integer::I128Sub;
+use integer::I128Mul;
+use integer::I128AddEq;
+use integer::I128SubEq;
+use integer::I128MulEq;
+use integer::I128Neg;
+use integer::I128PartialEq;
+use integer::I128PartialOrd;
+use integer::I128Default;
+use integer::Felt252TryIntoI128;
+use integer::I128IntoFelt252;
 use integer::Bitwise;
 
+// Bytes31.
+mod bytes_31;
+use bytes_31::bytes31;
+use bytes_31::bytes31_const;
+use bytes_31::Bytes31IntoFelt252;
+use bytes_31::Felt252TryIntoBytes31;
+use bytes_31::Bytes31PartialEq;
+
+// ByteArray.
+mod byte_array;
+use byte_array::ByteArray;
+use byte_array::ByteArrayTrait;
+use byte_array::ByteArrayImpl;
+use byte_array::ByteArrayAdd;
+use byte_array::ByteArrayAddEq;
+use byte_array::ByteArrayDefault;
+use byte_array::ByteArrayIndexView;
+use byte_array::ByteArrayPartialEq;
+
 // Gas.
 mod gas;
 use gas::BuiltinCosts;
@@ -384,6 +554,14 @@ fn assert(cond: bool, err_code: felt252) {
     }
 }
 
+/// Panics with the given byte array as the error message.
+fn panic_with_byte_array(err: @ByteArray) {
+    let mut data = ArrayTrait::new();
+    data.append(byte_array::BYTE_ARRAY_MAGIC);
+    serde::Serde::serialize(ref data, err.clone());
+    panic(data);
+}
+
 // Serialization and Deserialization.
 mod serde;
 
@@ -391,6 +569,9 @@ mod serde;
 mod hash;
 use hash::pedersen;
 use hash::Pedersen;
+mod poseidon;
+use poseidon::Poseidon;
+mod keccak;
 
 // Debug.
 mod debug;
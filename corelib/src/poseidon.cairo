@@ -0,0 +1,78 @@
+use array::ArrayTrait;
+use array::SpanTrait;
+use gas::get_builtin_costs;
+use gas::withdraw_gas_all;
+use option::OptionTrait;
+
+extern type Poseidon;
+
+extern fn hades_permutation(
+    s0: felt252, s1: felt252, s2: felt252
+) -> (felt252, felt252, felt252) implicits(Poseidon) nopanic;
+
+/// State for Poseidon hash.
+#[derive(Copy, Drop)]
+struct HashState {
+    s0: felt252,
+    s1: felt252,
+    s2: felt252,
+    // Whether an absorbed value is still waiting for its pair, before applying the permutation.
+    odd: bool,
+}
+
+trait HashStateTrait {
+    fn new() -> HashState;
+    fn update(self: HashState, value: felt252) -> HashState;
+    fn finalize(self: HashState) -> felt252;
+}
+
+impl HashStateImpl of HashStateTrait {
+    #[inline(always)]
+    fn new() -> HashState {
+        HashState { s0: 0, s1: 0, s2: 0, odd: false }
+    }
+
+    #[inline(always)]
+    fn update(self: HashState, value: felt252) -> HashState {
+        if self.odd {
+            let (s0, s1, s2) = hades_permutation(self.s0, self.s1 + value, self.s2);
+            HashState { s0, s1, s2, odd: false }
+        } else {
+            HashState { s0: self.s0 + value, s1: self.s1, s2: self.s2, odd: true }
+        }
+    }
+
+    /// Pads the absorbed values with 1 (and 0 if needed to complete a pair) and returns the first
+    /// element of the permuted state.
+    #[inline(always)]
+    fn finalize(self: HashState) -> felt252 {
+        if self.odd {
+            let (r, _, _) = hades_permutation(self.s0, self.s1 + 1, self.s2);
+            r
+        } else {
+            let (r, _, _) = hades_permutation(self.s0 + 1, self.s1, self.s2);
+            r
+        }
+    }
+}
+
+/// Computes the Poseidon hash of the given input, compatible with `poseidon_hash_many` of the
+/// Starknet OS.
+fn poseidon_hash_span(span: Span<felt252>) -> felt252 {
+    poseidon_hash_span_inner(span, HashStateTrait::new())
+}
+
+fn poseidon_hash_span_inner(mut span: Span<felt252>, state: HashState) -> felt252 {
+    match withdraw_gas_all(get_builtin_costs()) {
+        Option::Some(_) => {},
+        Option::None(_) => {
+            let mut data = ArrayTrait::new();
+            data.append('Out of gas');
+            panic(data);
+        },
+    }
+    match span.pop_front() {
+        Option::Some(value) => poseidon_hash_span_inner(span, state.update(*value)),
+        Option::None(_) => state.finalize(),
+    }
+}
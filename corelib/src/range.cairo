@@ -0,0 +1,99 @@
+/// The range of values from `start` (inclusive) to `end` (exclusive), created by `start..end`.
+struct Range<T> {
+    start: T,
+    end: T,
+}
+impl RangeCopy<T, +Copy<T>> of Copy<Range<T>>;
+impl RangeDrop<T, +Drop<T>> of Drop<Range<T>>;
+
+/// Trait for the `..` operator.
+trait RangeOp<T> {
+    fn range(start: T, end: T) -> Range<T>;
+}
+impl RangeOpImpl<T> of RangeOp<T> {
+    #[inline(always)]
+    fn range(start: T, end: T) -> Range<T> {
+        Range { start, end }
+    }
+}
+
+/// Trait for types whose values have a successor, allowing iterating over ranges of them.
+trait Step<T> {
+    /// Returns the value following `self`.
+    fn successor(self: T) -> T;
+}
+
+impl RangeIterator<T, +Step<T>, +PartialOrd<T>, +Copy<T>, +Drop<T>> of Iterator<Range<T>, T> {
+    fn next(ref self: Range<T>) -> Option<T> {
+        if self.start < self.end {
+            let value = self.start;
+            self.start = self.start.successor();
+            Option::Some(value)
+        } else {
+            Option::None(())
+        }
+    }
+}
+
+impl RangeIntoIterator<T> of IntoIterator<Range<T>, Range<T>> {
+    #[inline(always)]
+    fn into_iter(self: Range<T>) -> Range<T> {
+        self
+    }
+}
+
+impl U8Step of Step<u8> {
+    fn successor(self: u8) -> u8 {
+        self + 1_u8
+    }
+}
+impl U16Step of Step<u16> {
+    fn successor(self: u16) -> u16 {
+        self + 1_u16
+    }
+}
+impl U32Step of Step<u32> {
+    fn successor(self: u32) -> u32 {
+        self + 1_u32
+    }
+}
+impl U64Step of Step<u64> {
+    fn successor(self: u64) -> u64 {
+        self + 1_u64
+    }
+}
+impl U128Step of Step<u128> {
+    fn successor(self: u128) -> u128 {
+        self + 1_u128
+    }
+}
+impl U256Step of Step<u256> {
+    fn successor(self: u256) -> u256 {
+        self + 1_u256
+    }
+}
+impl I8Step of Step<i8> {
+    fn successor(self: i8) -> i8 {
+        self + 1_i8
+    }
+}
+impl I16Step of Step<i16> {
+    fn successor(self: i16) -> i16 {
+        self + 1_i16
+    }
+}
+impl I32Step of Step<i32> {
+    fn successor(self: i32) -> i32 {
+        self + 1_i32
+    }
+}
+impl I64Step of Step<i64> {
+    fn successor(self: i64) -> i64 {
+        self + 1_i64
+    }
+}
+impl I128Step of Step<i128> {
+    fn successor(self: i128) -> i128 {
+        self + 1_i128
+    }
+}
@@ -90,7 +90,7 @@ impl U256Serde of Serde::<u256> {
     }
 }
 
-impl ArraySerde<T, impl TSerde: Serde::<T>, impl TDrop: Drop::<T>> of Serde::<Array::<T>> {
+impl ArraySerde<T, +Serde<T>, +Drop<T>> of Serde<Array<T>> {
     fn serialize(ref serialized: Array<felt252>, mut input: Array<T>) {
         Serde::<usize>::serialize(ref serialized, input.len());
         serialize_array_helper(ref serialized, input);
@@ -102,7 +102,7 @@ impl ArraySerde<T, impl TSerde: Serde::<T>, impl TDrop: Drop::<T>> of Serde::<Ar
     }
 }
 
-fn serialize_array_helper<T, impl TSerde: Serde::<T>, impl TDrop: Drop::<T>>(
+fn serialize_array_helper<T, +Serde<T>, +Drop<T>>(
     ref serialized: Array<felt252>, mut input: Array<T>
 ) {
     // TODO(orizi): Replace with simple call once inlining is supported.
@@ -116,14 +116,14 @@ fn serialize_array_helper<T, impl TSerde: Serde::<T>, impl TDrop: Drop::<T>>(
     }
     match input.pop_front() {
         Option::Some(value) => {
-            TSerde::serialize(ref serialized, value);
+            Serde::serialize(ref serialized, value);
             serialize_array_helper(ref serialized, input);
         },
         Option::None(_) => {},
     }
 }
 
-fn deserialize_array_helper<T, impl TSerde: Serde::<T>, impl TDrop: Drop::<T>>(
+fn deserialize_array_helper<T, +Serde<T>, +Drop<T>>(
     ref serialized: Span<felt252>, mut curr_output: Array<T>, remaining: felt252
 ) -> Option<Array<T>> {
     // TODO(orizi): Replace with simple call once inlining is supported.
@@ -138,7 +138,7 @@ fn deserialize_array_helper<T, impl TSerde: Serde::<T>, impl TDrop: Drop::<T>>(
     if remaining == 0 {
         return Option::Some(curr_output);
     }
-    curr_output.append(TSerde::deserialize(ref serialized)?);
+    curr_output.append(Serde::deserialize(ref serialized)?);
     deserialize_array_helper(ref serialized, curr_output, remaining - 1)
 }
 
@@ -149,53 +149,48 @@ impl TupleSize0Serde of Serde::<()> {
     }
 }
 
-impl TupleSize1Serde<E0, impl E0Serde: Serde::<E0>> of Serde::<(E0, )> {
+impl TupleSize1Serde<E0, +Serde<E0>> of Serde<(E0, )> {
     fn serialize(ref serialized: Array<felt252>, mut input: (E0, )) {
         let (e0, ) = input;
-        E0Serde::serialize(ref serialized, e0)
+        Serde::serialize(ref serialized, e0)
     }
     fn deserialize(ref serialized: Span<felt252>) -> Option<(E0, )> {
-        Option::Some((E0Serde::deserialize(ref serialized)?, ))
+        Option::Some((Serde::deserialize(ref serialized)?, ))
     }
 }
 
-impl TupleSize2Serde<E0,
-E1,
-impl E0Serde: Serde::<E0>,
-impl E0Drop: Drop::<E0>,
-impl E1Serde: Serde::<E1>,
-impl E0Drop: Drop::<E1>> of Serde::<(E0, E1)> {
+impl TupleSize2Serde<E0, E1, +Serde<E0>, +Drop<E0>, +Serde<E1>, +Drop<E1>> of Serde<(E0, E1)> {
     fn serialize(ref serialized: Array<felt252>, mut input: (E0, E1)) {
         let (e0, e1) = input;
-        E0Serde::serialize(ref serialized, e0);
-        E1Serde::serialize(ref serialized, e1)
+        Serde::serialize(ref serialized, e0);
+        Serde::serialize(ref serialized, e1)
     }
     fn deserialize(ref serialized: Span<felt252>) -> Option<(E0, E1)> {
-        Option::Some((E0Serde::deserialize(ref serialized)?, E1Serde::deserialize(ref serialized)?))
+        Option::Some((Serde::deserialize(ref serialized)?, Serde::deserialize(ref serialized)?))
     }
 }
 
 impl TupleSize3Serde<E0,
 E1,
 E2,
-impl E0Serde: Serde::<E0>,
-impl E0Drop: Drop::<E0>,
-impl E1Serde: Serde::<E1>,
-impl E1Drop: Drop::<E1>,
-impl E2Serde: Serde::<E2>,
-impl E2Drop: Drop::<E2>> of Serde::<(E0, E1, E2)> {
++Serde<E0>,
++Drop<E0>,
++Serde<E1>,
++Drop<E1>,
++Serde<E2>,
++Drop<E2>> of Serde<(E0, E1, E2)> {
     fn serialize(ref serialized: Array<felt252>, mut input: (E0, E1, E2)) {
         let (e0, e1, e2) = input;
-        E0Serde::serialize(ref serialized, e0);
-        E1Serde::serialize(ref serialized, e1);
-        E2Serde::serialize(ref serialized, e2)
+        Serde::serialize(ref serialized, e0);
+        Serde::serialize(ref serialized, e1);
+        Serde::serialize(ref serialized, e2)
     }
     fn deserialize(ref serialized: Span<felt252>) -> Option<(E0, E1, E2)> {
         Option::Some(
             (
-                E0Serde::deserialize(ref serialized)?,
-                E1Serde::deserialize(ref serialized)?,
-                E2Serde::deserialize(ref serialized)?
+                Serde::deserialize(ref serialized)?,
+                Serde::deserialize(ref serialized)?,
+                Serde::deserialize(ref serialized)?
             )
         )
     }
@@ -205,28 +200,28 @@ impl TupleSize4Serde<E0,
 E1,
 E2,
 E3,
-impl E0Serde: Serde::<E0>,
-impl E0Drop: Drop::<E0>,
-impl E1Serde: Serde::<E1>,
-impl E1Drop: Drop::<E1>,
-impl E2Serde: Serde::<E2>,
-impl E2Drop: Drop::<E2>,
-impl E3Serde: Serde::<E3>,
-impl E3Drop: Drop::<E3>> of Serde::<(E0, E1, E2, E3)> {
++Serde<E0>,
++Drop<E0>,
++Serde<E1>,
++Drop<E1>,
++Serde<E2>,
++Drop<E2>,
++Serde<E3>,
++Drop<E3>> of Serde<(E0, E1, E2, E3)> {
     fn serialize(ref serialized: Array<felt252>, mut input: (E0, E1, E2, E3)) {
         let (e0, e1, e2, e3) = input;
-        E0Serde::serialize(ref serialized, e0);
-        E1Serde::serialize(ref serialized, e1);
-        E2Serde::serialize(ref serialized, e2);
-        E3Serde::serialize(ref serialized, e3)
+        Serde::serialize(ref serialized, e0);
+        Serde::serialize(ref serialized, e1);
+        Serde::serialize(ref serialized, e2);
+        Serde::serialize(ref serialized, e3)
     }
     fn deserialize(ref serialized: Span<felt252>) -> Option<(E0, E1, E2, E3)> {
         Option::Some(
             (
-                E0Serde::deserialize(ref serialized)?,
-                E1Serde::deserialize(ref serialized)?,
-                E2Serde::deserialize(ref serialized)?,
-                E3Serde::deserialize(ref serialized)?
+                Serde::deserialize(ref serialized)?,
+                Serde::deserialize(ref serialized)?,
+                Serde::deserialize(ref serialized)?,
+                Serde::deserialize(ref serialized)?
             )
         )
     }
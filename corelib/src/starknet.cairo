@@ -20,8 +20,21 @@ use storage_access::storage_address_try_from_felt252;
 // Module containing all the extern declaration of the syscalls.
 mod syscalls;
 use syscalls::call_contract_syscall;
+use syscalls::deploy_syscall;
+use syscalls::keccak_syscall;
+use syscalls::library_call_syscall;
+use syscalls::replace_class_syscall;
+use syscalls::send_message_to_l1_syscall;
 use syscalls::storage_read_syscall;
 use syscalls::storage_write_syscall;
+use syscalls::secp256k1_ec_add_syscall;
+use syscalls::secp256k1_ec_mul_syscall;
+use syscalls::secp256k1_ec_get_point_from_x_syscall;
+use syscalls::secp256k1_ec_get_coordinates_syscall;
+
+// secp256k1
+mod secp256k1;
+use secp256k1::Secp256K1EcPoint;
 
 // ContractAddress
 mod contract_address;
@@ -39,6 +52,8 @@ use class_hash::ClassHash;
 use class_hash::ClassHashIntoFelt252;
 use class_hash::Felt252TryIntoClassHash;
 use class_hash::class_hash_const;
+use class_hash::class_hash_to_felt252;
+use class_hash::class_hash_try_from_felt252;
 use class_hash::ClassHashZeroable;
 
 mod info;
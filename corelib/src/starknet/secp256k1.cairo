@@ -0,0 +1,117 @@
+use array::ArrayTrait;
+use integer::u256_mul_mod_n;
+use integer::u256_safe_div_rem;
+use option::OptionTrait;
+use starknet::SyscallResultTrait;
+use traits::Into;
+
+/// A point on the secp256k1 curve, held by the system.
+#[derive(Copy, Drop)]
+extern type Secp256K1EcPoint;
+
+/// An Ethereum signature of a message hash.
+#[derive(Copy, Drop)]
+struct Signature {
+    r: u256,
+    s: u256,
+    // The parity of the y coordinate of the point whose x coordinate is `r`.
+    y_parity: bool,
+}
+
+/// Returns the order of the secp256k1 curve.
+fn get_curve_size() -> u256 {
+    u256 {
+        low: 0xbaaedce6af48a03bbfd25e8cd0364141_u128, high: 0xfffffffffffffffffffffffffffffffe_u128
+    }
+}
+
+/// Returns the generator point of the secp256k1 curve.
+fn get_generator_point() -> Secp256K1EcPoint {
+    let x = u256 {
+        low: 0x029bfcdb2dce28d959f2815b16f81798_u128, high: 0x79be667ef9dcbbac55a06295ce870b07_u128
+    };
+    starknet::secp256k1_ec_get_point_from_x_syscall(x, false).unwrap_syscall().unwrap()
+}
+
+/// Computes `base ** exponent` modulo `n`.
+fn pow_mod_n(base: u256, exponent: u256, n: NonZero<u256>) -> u256 {
+    match gas::withdraw_gas() {
+        Option::Some(_) => {},
+        Option::None(_) => {
+            let mut data = ArrayTrait::new();
+            data.append('Out of gas');
+            panic(data);
+        },
+    }
+    if exponent == 0.into() {
+        return 1.into();
+    }
+    let (half_exponent, bit) = u256_safe_div_rem(exponent, integer::u256_as_non_zero(2.into()));
+    let half = pow_mod_n(base, half_exponent, n);
+    let result = u256_mul_mod_n(half, half, n);
+    if bit == 0.into() {
+        result
+    } else {
+        u256_mul_mod_n(result, base, n)
+    }
+}
+
+/// Computes the inverse of `value` modulo the (prime) curve order.
+fn inverse_mod_curve_size(value: u256) -> u256 {
+    let n = get_curve_size();
+    pow_mod_n(value, n - 2.into(), integer::u256_as_non_zero(n))
+}
+
+/// Recovers the public key of the signer of `msg_hash`, given the signature.
+/// Returns `Option::None` if the signature is invalid.
+fn recover_public_key(msg_hash: u256, signature: Signature) -> Option<Secp256K1EcPoint> {
+    let r = signature.r;
+    let s = signature.s;
+    if !is_signature_scalar_valid(r) | !is_signature_scalar_valid(s) {
+        return Option::None(());
+    }
+    let r_point = starknet::secp256k1_ec_get_point_from_x_syscall(
+        r, signature.y_parity
+    ).unwrap_syscall()?;
+
+    // The public key is `(s * R - msg_hash * G) / r`.
+    let n = get_curve_size();
+    let n_nz = integer::u256_as_non_zero(n);
+    let r_inv = inverse_mod_curve_size(r);
+    let (_, msg_hash_mod_n) = u256_safe_div_rem(msg_hash, n_nz);
+    let (_, neg_msg_hash) = u256_safe_div_rem(n - msg_hash_mod_n, n_nz);
+    let u1 = u256_mul_mod_n(neg_msg_hash, r_inv, n_nz);
+    let u2 = u256_mul_mod_n(s, r_inv, n_nz);
+
+    let point1 = starknet::secp256k1_ec_mul_syscall(get_generator_point(), u1).unwrap_syscall();
+    let point2 = starknet::secp256k1_ec_mul_syscall(r_point, u2).unwrap_syscall();
+    Option::Some(starknet::secp256k1_ec_add_syscall(point1, point2).unwrap_syscall())
+}
+
+/// Returns whether `value` is in the range `[1, n)`, where `n` is the curve order.
+fn is_signature_scalar_valid(value: u256) -> bool {
+    value != 0.into() & value < get_curve_size()
+}
+
+/// Converts a public key point to the corresponding Ethereum address.
+fn public_key_point_to_eth_address(public_key_point: Secp256K1EcPoint) -> felt252 {
+    let (x, y) = starknet::secp256k1_ec_get_coordinates_syscall(public_key_point).unwrap_syscall();
+
+    let mut keccak_input = ArrayTrait::new();
+    keccak_input.append(x);
+    keccak_input.append(y);
+    // The address is the last 20 bytes of the big-endian hash.
+    let point_hash = keccak::u256_reverse_endian(keccak::keccak_u256s_be(keccak_input.span()));
+    let high: felt252 = (point_hash.high & 0xffffffff_u128).into();
+    high * 0x100000000000000000000000000000000 + point_hash.low.into()
+}
+
+/// Returns whether `signature` is a valid signature of `msg_hash` by the owner of `eth_address`.
+fn verify_eth_signature(msg_hash: u256, signature: Signature, eth_address: felt252) -> bool {
+    match recover_public_key(msg_hash, signature) {
+        Option::Some(public_key_point) => {
+            public_key_point_to_eth_address(public_key_point) == eth_address
+        },
+        Option::None(_) => false,
+    }
+}
@@ -2,6 +2,7 @@ use starknet::SyscallResult;
 use starknet::storage_access::StorageAddress;
 use starknet::class_hash::ClassHash;
 use starknet::contract_address::ContractAddress;
+use starknet::secp256k1::Secp256K1EcPoint;
 
 // Calls a given contract.
 // `address` - The address of the called contract.
@@ -77,3 +78,39 @@ extern fn storage_write_syscall(
 extern fn replace_class_syscall(
     class_hash: ClassHash
 ) -> SyscallResult<()> implicits(GasBuiltin, System) nopanic;
+
+// Applies the keccak permutation on the given input, and returns the first 4 words of the
+// resulting state as a little-endian u256.
+// `input` - The input blocks, of 17 words each. The input must already be padded.
+extern fn keccak_syscall(
+    input: Span<u64>
+) -> SyscallResult<u256> implicits(GasBuiltin, System) nopanic;
+
+// Adds two points on the secp256k1 curve.
+// `p0` - The first point.
+// `p1` - The second point.
+extern fn secp256k1_ec_add_syscall(
+    p0: Secp256K1EcPoint, p1: Secp256K1EcPoint
+) -> SyscallResult<Secp256K1EcPoint> implicits(GasBuiltin, System) nopanic;
+
+// Multiplies a point on the secp256k1 curve by a scalar.
+// `p` - The point.
+// `scalar` - The scalar.
+extern fn secp256k1_ec_mul_syscall(
+    p: Secp256K1EcPoint, scalar: u256
+) -> SyscallResult<Secp256K1EcPoint> implicits(GasBuiltin, System) nopanic;
+
+// Gets the point on the secp256k1 curve with the given x coordinate and y coordinate parity.
+// Returns `Option::None` if there is no such point.
+// `x` - The x coordinate of the point.
+// `y_parity` - Whether the y coordinate of the point is odd.
+extern fn secp256k1_ec_get_point_from_x_syscall(
+    x: u256, y_parity: bool
+) -> SyscallResult<Option<Secp256K1EcPoint>> implicits(GasBuiltin, System) nopanic;
+
+// Gets the x and y coordinates of a point on the secp256k1 curve.
+// The point at infinity is represented as (0, 0).
+// `p` - The point.
+extern fn secp256k1_ec_get_coordinates_syscall(
+    p: Secp256K1EcPoint
+) -> SyscallResult<(u256, u256)> implicits(GasBuiltin, System) nopanic;
@@ -1,3 +1,5 @@
+use array::Span;
+use option::Option;
 use starknet::ContractAddress;
 
 extern fn set_caller_address(address: ContractAddress) implicits() nopanic;
@@ -5,3 +7,16 @@ extern fn set_contract_address(address: ContractAddress) implicits() nopanic;
 extern fn set_sequencer_address(address: ContractAddress) implicits() nopanic;
 extern fn set_block_number(block_number: u64) implicits() nopanic;
 extern fn set_block_timestamp(block_timestamp: u64) implicits() nopanic;
+
+// Pops the earliest unpopped event emitted by the contract at `address`.
+// Returns the keys and the data of the event, or `None` if there are no unpopped events.
+extern fn pop_log(
+    address: ContractAddress
+) -> Option<(Span::<felt252>, Span::<felt252>)> implicits() nopanic;
+
+// Pops the earliest unpopped message sent to L1 by the contract at `address`.
+// Returns the recipient and the payload of the message, or `None` if there are no unpopped
+// messages.
+extern fn pop_l2_to_l1_message(
+    address: ContractAddress
+) -> Option<(felt252, Span::<felt252>)> implicits() nopanic;
@@ -160,6 +160,45 @@ fn test_ec_mul() {
     );
 }
 
+#[test]
+fn test_poseidon_hades_permutation() {
+    let (s0, s1, s2) = poseidon::hades_permutation(0, 0, 0);
+    assert(s0 == 0x79e8d1e78258000a28fc9d49e233bc6852357968577b1e386550ed6a9086133, 'wrong s0');
+    assert(s1 == 0x3840d003d0f3f96dbb796ff6aa6a63be5b5404b91ccaabca256154cbb6fb984, 'wrong s1');
+    assert(s2 == 0x1eb39da3f7d3b04142d0ac83d9da00c9325a61fb2ef326e50b70eaa8a3c7cc7, 'wrong s2');
+}
+
+#[test]
+#[available_gas(300000)]
+fn test_poseidon_hash_span() {
+    let empty = ArrayTrait::new();
+    assert(
+        poseidon::poseidon_hash_span(
+            empty.span()
+        ) == 0x2272be0f580fd156823304800919530eaa97430e972d7213ee13f4fbf7a5dbc,
+        'wrong empty hash'
+    );
+    let mut odd = ArrayTrait::new();
+    odd.append(1);
+    assert(
+        poseidon::poseidon_hash_span(
+            odd.span()
+        ) == 0x579e8877c7755365d5ec1ec7d3a94a457eff5d1f40482bbe9729c064cdead2,
+        'wrong odd hash'
+    );
+    let mut even = ArrayTrait::new();
+    even.append(1);
+    even.append(2);
+    even.append(3);
+    even.append(4);
+    assert(
+        poseidon::poseidon_hash_span(
+            even.span()
+        ) == 0x26e3ad8b876e02bc8a4fc43dad40a8f81a6384083cabffa190bcf40d512ae1d,
+        'wrong even hash'
+    );
+}
+
 #[test]
 fn test_felt252_operators() {
     assert(1 + 3 == 4, '1 + 3 == 4');
@@ -640,6 +679,336 @@ fn test_u128_mod_by_0() {
     2_u128 % 0_u128;
 }
 
+#[test]
+fn test_i8_operators() {
+    assert(1_i8 == 1_i8, '1 == 1');
+    assert(1_i8 != 2_i8, '1 != 2');
+    assert(1_i8 + 3_i8 == 4_i8, '1 + 3 == 4');
+    assert(1_i8 - 3_i8 == -2_i8, '1 - 3 == -2');
+    assert(-3_i8 + 6_i8 == 3_i8, '-3 + 6 == 3');
+    assert(-3_i8 - 6_i8 == -9_i8, '-3 - 6 == -9');
+    assert(3_i8 * 6_i8 == 18_i8, '3 * 6 == 18');
+    assert(-3_i8 * 6_i8 == -18_i8, '-3 * 6 == -18');
+    assert(-3_i8 * -6_i8 == 18_i8, '-3 * -6 == 18');
+    assert(-1_i8 < 4_i8, '-1 < 4');
+    assert(-4_i8 <= -1_i8, '-4 <= -1');
+    assert(!(4_i8 < -4_i8), '!(4 < -4)');
+    assert(-4_i8 <= -4_i8, '-4 <= -4');
+    assert(5_i8 > -2_i8, '5 > -2');
+    assert(-2_i8 >= -5_i8, '-2 >= -5');
+    assert(!(-3_i8 > -3_i8), '!(-3 > -3)');
+    let minus_three: felt252 = (-3_i8).into();
+    assert(minus_three == -3, '-3 into felt252');
+    let minus_three: i8 = (-3).try_into().unwrap();
+    assert(minus_three == -3_i8, '-3 from felt252');
+    let max: i8 = 127.try_into().unwrap();
+    assert(max == 127_i8, 'max from felt252');
+    let above_max: Option<i8> = 128.try_into();
+    assert(above_max.is_none(), 'max + 1 from felt252');
+    let below_min: Option<i8> = (-127 - 2).try_into();
+    assert(below_min.is_none(), 'min - 1 from felt252');
+}
+
+#[test]
+#[should_panic]
+fn test_i8_add_overflow() {
+    127_i8 + 1_i8;
+}
+
+#[test]
+#[should_panic]
+fn test_i8_add_underflow() {
+    -127_i8 + -2_i8;
+}
+
+#[test]
+#[should_panic]
+fn test_i8_sub_overflow() {
+    127_i8 - -1_i8;
+}
+
+#[test]
+#[should_panic]
+fn test_i8_sub_underflow() {
+    -127_i8 - 2_i8;
+}
+
+#[test]
+#[should_panic]
+fn test_i8_mul_overflow() {
+    127_i8 * 2_i8;
+}
+
+#[test]
+#[should_panic]
+fn test_i8_mul_underflow() {
+    -127_i8 * 2_i8;
+}
+
+#[test]
+fn test_i16_operators() {
+    assert(1_i16 == 1_i16, '1 == 1');
+    assert(1_i16 != 2_i16, '1 != 2');
+    assert(1_i16 + 3_i16 == 4_i16, '1 + 3 == 4');
+    assert(1_i16 - 3_i16 == -2_i16, '1 - 3 == -2');
+    assert(-3_i16 + 6_i16 == 3_i16, '-3 + 6 == 3');
+    assert(-3_i16 - 6_i16 == -9_i16, '-3 - 6 == -9');
+    assert(3_i16 * 6_i16 == 18_i16, '3 * 6 == 18');
+    assert(-3_i16 * 6_i16 == -18_i16, '-3 * 6 == -18');
+    assert(-3_i16 * -6_i16 == 18_i16, '-3 * -6 == 18');
+    assert(-1_i16 < 4_i16, '-1 < 4');
+    assert(-4_i16 <= -1_i16, '-4 <= -1');
+    assert(!(4_i16 < -4_i16), '!(4 < -4)');
+    assert(-4_i16 <= -4_i16, '-4 <= -4');
+    assert(5_i16 > -2_i16, '5 > -2');
+    assert(-2_i16 >= -5_i16, '-2 >= -5');
+    assert(!(-3_i16 > -3_i16), '!(-3 > -3)');
+    let minus_three: felt252 = (-3_i16).into();
+    assert(minus_three == -3, '-3 into felt252');
+    let minus_three: i16 = (-3).try_into().unwrap();
+    assert(minus_three == -3_i16, '-3 from felt252');
+    let max: i16 = 32767.try_into().unwrap();
+    assert(max == 32767_i16, 'max from felt252');
+    let above_max: Option<i8> = 32768.try_into();
+    assert(above_max.is_none(), 'max + 1 from felt252');
+    let below_min: Option<i16> = (-32767 - 2).try_into();
+    assert(below_min.is_none(), 'min - 1 from felt252');
+}
+
+#[test]
+#[should_panic]
+fn test_i16_add_overflow() {
+    32767_i16 + 1_i16;
+}
+
+#[test]
+#[should_panic]
+fn test_i16_add_underflow() {
+    -32767_i16 + -2_i16;
+}
+
+#[test]
+#[should_panic]
+fn test_i16_sub_overflow() {
+    32767_i16 - -1_i16;
+}
+
+#[test]
+#[should_panic]
+fn test_i16_sub_underflow() {
+    -32767_i16 - 2_i16;
+}
+
+#[test]
+#[should_panic]
+fn test_i16_mul_overflow() {
+    32767_i16 * 2_i16;
+}
+
+#[test]
+#[should_panic]
+fn test_i16_mul_underflow() {
+    -32767_i16 * 2_i16;
+}
+
+#[test]
+fn test_i32_operators() {
+    assert(1_i32 == 1_i32, '1 == 1');
+    assert(1_i32 != 2_i32, '1 != 2');
+    assert(1_i32 + 3_i32 == 4_i32, '1 + 3 == 4');
+    assert(1_i32 - 3_i32 == -2_i32, '1 - 3 == -2');
+    assert(-3_i32 + 6_i32 == 3_i32, '-3 + 6 == 3');
+    assert(-3_i32 - 6_i32 == -9_i32, '-3 - 6 == -9');
+    assert(3_i32 * 6_i32 == 18_i32, '3 * 6 == 18');
+    assert(-3_i32 * 6_i32 == -18_i32, '-3 * 6 == -18');
+    assert(-3_i32 * -6_i32 == 18_i32, '-3 * -6 == 18');
+    assert(-1_i32 < 4_i32, '-1 < 4');
+    assert(-4_i32 <= -1_i32, '-4 <= -1');
+    assert(!(4_i32 < -4_i32), '!(4 < -4)');
+    assert(-4_i32 <= -4_i32, '-4 <= -4');
+    assert(5_i32 > -2_i32, '5 > -2');
+    assert(-2_i32 >= -5_i32, '-2 >= -5');
+    assert(!(-3_i32 > -3_i32), '!(-3 > -3)');
+    let minus_three: felt252 = (-3_i32).into();
+    assert(minus_three == -3, '-3 into felt252');
+    let minus_three: i32 = (-3).try_into().unwrap();
+    assert(minus_three == -3_i32, '-3 from felt252');
+    let max: i32 = 2147483647.try_into().unwrap();
+    assert(max == 2147483647_i32, 'max from felt252');
+    let above_max: Option<i8> = 2147483648.try_into();
+    assert(above_max.is_none(), 'max + 1 from felt252');
+    let below_min: Option<i32> = (-2147483647 - 2).try_into();
+    assert(below_min.is_none(), 'min - 1 from felt252');
+}
+
+#[test]
+#[should_panic]
+fn test_i32_add_overflow() {
+    2147483647_i32 + 1_i32;
+}
+
+#[test]
+#[should_panic]
+fn test_i32_add_underflow() {
+    -2147483647_i32 + -2_i32;
+}
+
+#[test]
+#[should_panic]
+fn test_i32_sub_overflow() {
+    2147483647_i32 - -1_i32;
+}
+
+#[test]
+#[should_panic]
+fn test_i32_sub_underflow() {
+    -2147483647_i32 - 2_i32;
+}
+
+#[test]
+#[should_panic]
+fn test_i32_mul_overflow() {
+    2147483647_i32 * 2_i32;
+}
+
+#[test]
+#[should_panic]
+fn test_i32_mul_underflow() {
+    -2147483647_i32 * 2_i32;
+}
+
+#[test]
+fn test_i64_operators() {
+    assert(1_i64 == 1_i64, '1 == 1');
+    assert(1_i64 != 2_i64, '1 != 2');
+    assert(1_i64 + 3_i64 == 4_i64, '1 + 3 == 4');
+    assert(1_i64 - 3_i64 == -2_i64, '1 - 3 == -2');
+    assert(-3_i64 + 6_i64 == 3_i64, '-3 + 6 == 3');
+    assert(-3_i64 - 6_i64 == -9_i64, '-3 - 6 == -9');
+    assert(3_i64 * 6_i64 == 18_i64, '3 * 6 == 18');
+    assert(-3_i64 * 6_i64 == -18_i64, '-3 * 6 == -18');
+    assert(-3_i64 * -6_i64 == 18_i64, '-3 * -6 == 18');
+    assert(-1_i64 < 4_i64, '-1 < 4');
+    assert(-4_i64 <= -1_i64, '-4 <= -1');
+    assert(!(4_i64 < -4_i64), '!(4 < -4)');
+    assert(-4_i64 <= -4_i64, '-4 <= -4');
+    assert(5_i64 > -2_i64, '5 > -2');
+    assert(-2_i64 >= -5_i64, '-2 >= -5');
+    assert(!(-3_i64 > -3_i64), '!(-3 > -3)');
+    let minus_three: felt252 = (-3_i64).into();
+    assert(minus_three == -3, '-3 into felt252');
+    let minus_three: i64 = (-3).try_into().unwrap();
+    assert(minus_three == -3_i64, '-3 from felt252');
+    let max: i64 = 9223372036854775807.try_into().unwrap();
+    assert(max == 9223372036854775807_i64, 'max from felt252');
+    let above_max: Option<i8> = 9223372036854775808.try_into();
+    assert(above_max.is_none(), 'max + 1 from felt252');
+    let below_min: Option<i64> = (-9223372036854775807 - 2).try_into();
+    assert(below_min.is_none(), 'min - 1 from felt252');
+}
+
+#[test]
+#[should_panic]
+fn test_i64_add_overflow() {
+    9223372036854775807_i64 + 1_i64;
+}
+
+#[test]
+#[should_panic]
+fn test_i64_add_underflow() {
+    -9223372036854775807_i64 + -2_i64;
+}
+
+#[test]
+#[should_panic]
+fn test_i64_sub_overflow() {
+    9223372036854775807_i64 - -1_i64;
+}
+
+#[test]
+#[should_panic]
+fn test_i64_sub_underflow() {
+    -9223372036854775807_i64 - 2_i64;
+}
+
+#[test]
+#[should_panic]
+fn test_i64_mul_overflow() {
+    9223372036854775807_i64 * 2_i64;
+}
+
+#[test]
+#[should_panic]
+fn test_i64_mul_underflow() {
+    -9223372036854775807_i64 * 2_i64;
+}
+
+#[test]
+fn test_i128_operators() {
+    assert(1_i128 == 1_i128, '1 == 1');
+    assert(1_i128 != 2_i128, '1 != 2');
+    assert(1_i128 + 3_i128 == 4_i128, '1 + 3 == 4');
+    assert(1_i128 - 3_i128 == -2_i128, '1 - 3 == -2');
+    assert(-3_i128 + 6_i128 == 3_i128, '-3 + 6 == 3');
+    assert(-3_i128 - 6_i128 == -9_i128, '-3 - 6 == -9');
+    assert(3_i128 * 6_i128 == 18_i128, '3 * 6 == 18');
+    assert(-3_i128 * 6_i128 == -18_i128, '-3 * 6 == -18');
+    assert(-3_i128 * -6_i128 == 18_i128, '-3 * -6 == 18');
+    assert(-1_i128 < 4_i128, '-1 < 4');
+    assert(-4_i128 <= -1_i128, '-4 <= -1');
+    assert(!(4_i128 < -4_i128), '!(4 < -4)');
+    assert(-4_i128 <= -4_i128, '-4 <= -4');
+    assert(5_i128 > -2_i128, '5 > -2');
+    assert(-2_i128 >= -5_i128, '-2 >= -5');
+    assert(!(-3_i128 > -3_i128), '!(-3 > -3)');
+    let minus_three: felt252 = (-3_i128).into();
+    assert(minus_three == -3, '-3 into felt252');
+    let minus_three: i128 = (-3).try_into().unwrap();
+    assert(minus_three == -3_i128, '-3 from felt252');
+    let max: i128 = 170141183460469231731687303715884105727.try_into().unwrap();
+    assert(max == 170141183460469231731687303715884105727_i128, 'max from felt252');
+    let above_max: Option<i8> = 170141183460469231731687303715884105728.try_into();
+    assert(above_max.is_none(), 'max + 1 from felt252');
+    let below_min: Option<i128> = (-170141183460469231731687303715884105727 - 2).try_into();
+    assert(below_min.is_none(), 'min - 1 from felt252');
+}
+
+#[test]
+#[should_panic]
+fn test_i128_add_overflow() {
+    170141183460469231731687303715884105727_i128 + 1_i128;
+}
+
+#[test]
+#[should_panic]
+fn test_i128_add_underflow() {
+    -170141183460469231731687303715884105727_i128 + -2_i128;
+}
+
+#[test]
+#[should_panic]
+fn test_i128_sub_overflow() {
+    170141183460469231731687303715884105727_i128 - -1_i128;
+}
+
+#[test]
+#[should_panic]
+fn test_i128_sub_underflow() {
+    -170141183460469231731687303715884105727_i128 - 2_i128;
+}
+
+#[test]
+#[should_panic]
+fn test_i128_mul_overflow() {
+    170141183460469231731687303715884105727_i128 * 2_i128;
+}
+
+#[test]
+#[should_panic]
+fn test_i128_mul_underflow() {
+    -170141183460469231731687303715884105727_i128 * 2_i128;
+}
+
 // TODO(orizi): Remove when u256 literals are supported.
 fn as_u256(high: u128, low: u128) -> u256 {
     u256 { low, high }
@@ -785,6 +1154,109 @@ fn test_u256_mul_overflow_2() {
     as_u256(0_u128, pow_2_127()) * as_u256(2_u128, 0_u128);
 }
 
+#[test]
+fn test_u256_div_rem() {
+    assert(
+        as_u256(0x1234567890abcdef1234567890abcdef_u128, 0xfedcba0987654321fedcba0987654321_u128)
+            / as_u256(
+                0x1357_u128, 0x9bdf2468ace013579bdf2468ace0aaaa_u128
+            ) == as_u256(0x0_u128, 0xf0f0f0fce0a21bf93737f8df01a7_u128),
+        'div by low divisor'
+    );
+    assert(
+        as_u256(
+            0x1234567890abcdef1234567890abcdef_u128, 0xfedcba0987654321fedcba0987654321_u128
+        ) % as_u256(
+            0x1357_u128, 0x9bdf2468ace013579bdf2468ace0aaaa_u128
+        ) == as_u256(0x216_u128, 0x6dbff9ebec2c72fdfc3623848615443b_u128),
+        'rem by low divisor'
+    );
+    assert(
+        as_u256(0x1234567890abcdef1234567890abcdef_u128, 0xfedcba0987654321fedcba0987654321_u128)
+            / as_u256(
+                0x1_u128, 0xf_u128
+            ) == as_u256(0x0_u128, 0x1234567890abcdef1234567890abcdee_u128),
+        'div by high divisor'
+    );
+    assert(
+        as_u256(
+            0x1234567890abcdef1234567890abcdef_u128, 0xfedcba0987654321fedcba0987654321_u128
+        ) % as_u256(
+            0x1_u128, 0xf_u128
+        ) == as_u256(0x0_u128, 0xedcba8f90d54321fedcba8f90d54322f_u128),
+        'rem by high divisor'
+    );
+    assert(as_u256(4_u128, 3_u128) / as_u256(4_u128, 3_u128) == as_u256(0_u128, 1_u128), 'x / x');
+    assert(as_u256(4_u128, 3_u128) % as_u256(4_u128, 3_u128) == as_u256(0_u128, 0_u128), 'x % x');
+    assert(
+        as_u256(4_u128, 3_u128) / as_u256(5_u128, 0_u128) == as_u256(0_u128, 0_u128), 'small / big'
+    );
+    let mut v = as_u256(4_u128, 3_u128);
+    v /= as_u256(0_u128, 2_u128);
+    assert(v == as_u256(2_u128, 1_u128), 'div_eq');
+    v %= as_u256(0_u128, 2_u128);
+    assert(v == as_u256(0_u128, 1_u128), 'rem_eq');
+}
+
+#[test]
+#[should_panic]
+fn test_u256_div_by_0() {
+    as_u256(2_u128, 1_u128) / as_u256(0_u128, 0_u128);
+}
+
+#[test]
+fn test_u256_sqrt() {
+    assert(u256_sqrt(as_u256(0_u128, 0_u128)) == 0_u128, 'sqrt(0)');
+    assert(u256_sqrt(as_u256(0_u128, 10_u128)) == 3_u128, 'sqrt(10)');
+    assert(u256_sqrt(as_u256(1_u128, 0_u128)) == 0x10000000000000000_u128, 'sqrt(2^128)');
+    assert(
+        u256_sqrt(
+            as_u256(
+                0x1234567890abcdef1234567890abcdef_u128, 0xfedcba0987654321fedcba0987654321_u128
+            )
+        ) == 0x444444443164444261a844c3abf49280_u128,
+        'sqrt(a)'
+    );
+    assert(
+        u256_sqrt(
+            as_u256(
+                0xffffffffffffffffffffffffffffffff_u128, 0xffffffffffffffffffffffffffffffff_u128
+            )
+        ) == 0xffffffffffffffffffffffffffffffff_u128,
+        'sqrt(max_u256)'
+    );
+}
+
+#[test]
+fn test_u256_wide_mul_and_mul_mod() {
+    let x = as_u256(
+        0xdeadbeefdeadbeefdeadbeefdeadbeef_u128, 0x123456789abcdef0123456789abcdef_u128
+    );
+    let y = as_u256(
+        0xcafebabecafebabecafebabecafebabe_u128, 0xfedcba9876543210fedcba9876543210_u128
+    );
+    let product = integer::u256_wide_mul(x, y);
+    assert(product.limb0 == 0x458fab20783af1222236d88fe5618cf0_u128, 'wrong limb0');
+    assert(product.limb1 == 0x3dbe00e5cfe8a1d9d37d232fd52855c6_u128, 'wrong limb1');
+    assert(product.limb2 == 0x138cc9a3f455ff132e626c2e0f2ba19c_u128, 'wrong limb2');
+    assert(product.limb3 == 0xb092ab7ce9f4b25b2356b9395cb8c017_u128, 'wrong limb3');
+    let n = integer::u256_as_non_zero(
+        as_u256(0xfffffffffffffffffffffffffffffffe_u128, 0xbaaedce6af48a03bbfd25e8cd0364141_u128)
+    );
+    assert(
+        integer::u256_mul_mod_n(
+            x, y, n
+        ) == as_u256(
+            0x9d1a4bc310531cca42e7e98638c86168_u128, 0xb091d2d3c1d5005d37f371ba66ded866_u128
+        ),
+        'wrong mul_mod_n'
+    );
+    let (q, r) = integer::u512_safe_div_rem_by_u256(product, integer::u256_as_non_zero(y));
+    assert(q.limb0 == x.low & q.limb1 == x.high, 'wrong quotient low');
+    assert(q.limb2 == 0_u128 & q.limb3 == 0_u128, 'wrong quotient high');
+    assert(r == as_u256(0_u128, 0_u128), 'wrong remainder');
+}
+
 fn test_array_helper() -> Array::<felt252> {
     let mut arr = ArrayTrait::new();
     arr.append(10);
@@ -815,6 +1287,59 @@ fn test_array_out_of_bound_2() {
     arr.at(11_usize);
 }
 
+#[test]
+fn test_array_index() {
+    let arr = test_array_helper();
+    assert(*arr[0_usize] == 10, 'array[0] == 10');
+    assert(*arr[1_usize] == 11, 'array[1] == 11');
+    assert(*arr[2_usize] == 12, 'array[2] == 12');
+}
+
+#[test]
+#[should_panic]
+fn test_array_index_out_of_bound() {
+    let arr = test_array_helper();
+    arr[3_usize];
+}
+
+#[test]
+#[available_gas(100000)]
+fn test_array_map() {
+    let offset = 5;
+    let arr = test_array_helper().map(|x: @felt252| *x + offset);
+    assert(arr.len() == 3_usize, 'array len == 3');
+    assert(*arr[0_usize] == 15, 'array[0] == 15');
+    assert(*arr[1_usize] == 16, 'array[1] == 16');
+    assert(*arr[2_usize] == 17, 'array[2] == 17');
+}
+
+#[test]
+#[available_gas(100000)]
+fn test_array_filter() {
+    let arr = test_array_helper().filter(|x: @felt252| *x != 11);
+    assert(arr.len() == 2_usize, 'array len == 2');
+    assert(*arr[0_usize] == 10, 'array[0] == 10');
+    assert(*arr[1_usize] == 12, 'array[1] == 12');
+}
+
+#[test]
+#[available_gas(100000)]
+fn test_span_fold() {
+    let sum = test_array_helper().span().fold(0, |acc, x: @felt252| acc + *x);
+    assert(sum == 33, 'sum == 33');
+}
+
+#[test]
+fn test_closure() {
+    let a = 3;
+    let add_a = |x| x + a;
+    let mul = |x: felt252, y: felt252| -> felt252 {
+        x * y
+    };
+    assert(add_a(1) == 4, 'add_a(1) == 4');
+    assert(mul(add_a(2), 2) == 10, 'mul(add_a(2), 2) == 10');
+}
+
 #[test]
 fn test_felt252_clone() {
     let felt252_snap = @2;
@@ -869,6 +1394,17 @@ fn test_dict_write_read() {
     assert(val12 == 0, 'default_val == 0');
 }
 
+#[test]
+fn test_dict_index() {
+    let mut dict = Felt252DictTrait::new();
+    dict.insert(10, 110);
+    let val10 = dict[10];
+    let val11 = dict[11];
+    let squashed_dict = dict.squash();
+    assert(val10 == 110, 'dict[10] == 110');
+    assert(val11 == 0, 'default_val == 0');
+}
+
 #[test]
 fn test_box_unbox_felt252s() {
     let x = 10;
@@ -900,6 +1436,7 @@ fn test_span() {
     assert(*span.pop_front().unwrap() == 10, 'Unexpected element');
     assert(span.len() == 2_u32, 'Unexpected span length.');
     assert(*span.at(1_u32) == 12, 'Unexpected element');
+    assert(*span[0_u32] == 11, 'Unexpected element');
 }
 
 #[test]
@@ -912,3 +1449,350 @@ fn test_get_available_gas_no_gas_supply() {
 fn test_get_available_gas_with_gas_supply() {
     assert(testing::get_available_gas() > 5000_u128, 'high amount of gas used')
 }
+
+#[derive(Drop, Clone, Default, PartialEq, Serde)]
+struct DerivedStruct {
+    a: felt252,
+    b: u256,
+}
+
+#[derive(Copy, Drop, Default, PartialEq, Serde)]
+enum DerivedEnum {
+    A: felt252,
+    B: u128,
+    C: (),
+}
+
+#[derive(Destruct)]
+struct DerivedDestruct {
+    dict: Felt252Dict::<felt252>
+}
+
+#[test]
+fn test_derive_clone_and_default() {
+    let s = DerivedStruct { a: 1, b: as_u256(0_u128, 2_u128) };
+    assert(s.clone() == s, 'clone == original');
+    let default: DerivedStruct = Default::default();
+    assert(default == DerivedStruct { a: 0, b: as_u256(0_u128, 0_u128) }, 'struct default');
+    let default: DerivedEnum = Default::default();
+    assert(default == DerivedEnum::A(0), 'enum default');
+}
+
+#[test]
+fn test_derive_partial_eq() {
+    let s = DerivedStruct { a: 1, b: as_u256(0_u128, 2_u128) };
+    assert(s.clone() == DerivedStruct { a: 1, b: as_u256(0_u128, 2_u128) }, 'struct eq');
+    assert(s != DerivedStruct { a: 1, b: as_u256(1_u128, 2_u128) }, 'struct ne');
+    assert(DerivedEnum::B(3_u128) == DerivedEnum::B(3_u128), 'enum eq');
+    assert(DerivedEnum::B(3_u128) != DerivedEnum::B(4_u128), 'enum ne same variant');
+    assert(DerivedEnum::A(3) != DerivedEnum::B(3_u128), 'enum ne other variant');
+    assert(DerivedEnum::C(()) == DerivedEnum::C(()), 'enum eq unit');
+}
+
+#[test]
+#[available_gas(100000)]
+fn test_derive_serde() {
+    let mut serialized = ArrayTrait::new();
+    serde::Serde::serialize(ref serialized, DerivedStruct { a: 1, b: as_u256(2_u128, 3_u128) });
+    serde::Serde::serialize(ref serialized, DerivedEnum::B(4_u128));
+    assert(serialized.len() == 5_usize, 'serialized len');
+    assert(*serialized[0_usize] == 1, 'struct a');
+    assert(*serialized[1_usize] == 3, 'struct b.low');
+    assert(*serialized[2_usize] == 2, 'struct b.high');
+    assert(*serialized[3_usize] == 1, 'enum variant index');
+    assert(*serialized[4_usize] == 4, 'enum value');
+    let mut span = serialized.span();
+    let s: DerivedStruct = serde::Serde::deserialize(ref span).unwrap();
+    assert(s == DerivedStruct { a: 1, b: as_u256(2_u128, 3_u128) }, 'deserialized struct');
+    let e: DerivedEnum = serde::Serde::deserialize(ref span).unwrap();
+    assert(e == DerivedEnum::B(4_u128), 'deserialized enum');
+    assert(span.is_empty(), 'span fully consumed');
+    let mut bad_index = ArrayTrait::new();
+    bad_index.append(3);
+    let mut span = bad_index.span();
+    let e: Option<DerivedEnum> = serde::Serde::deserialize(ref span);
+    assert(e.is_none(), 'bad variant index');
+}
+
+#[test]
+fn test_derive_destruct() {
+    let mut dict = Felt252DictTrait::new();
+    dict.insert(1, 2);
+    let s = DerivedDestruct { dict };
+}
+
+use bytes_31::Bytes31Trait;
+use byte_array::ByteArrayTrait;
+use byte_array::ByteArrayClone;
+#[test]
+#[available_gas(1000000)]
+fn test_bytes31() {
+    let word = 0x010203_bytes31;
+    assert(word.at(0_usize) == 3_u8, 'byte 0');
+    assert(word.at(1_usize) == 2_u8, 'byte 1');
+    assert(word.at(2_usize) == 1_u8, 'byte 2');
+    assert(word.at(30_usize) == 0_u8, 'byte 30');
+    let felt: felt252 = word.into();
+    assert(felt == 0x010203, 'bytes31 into felt252');
+    let max: Option<bytes31> =
+        0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff.try_into();
+    assert(max.unwrap().at(30_usize) == 0xff_u8, 'max byte 30');
+    let too_big: Option<bytes31> =
+        0x100000000000000000000000000000000000000000000000000000000000000.try_into();
+    assert(too_big.is_none(), '2**248 is not a bytes31');
+    let negative: Option<bytes31> = (-1).try_into();
+    assert(negative.is_none(), '-1 is not a bytes31');
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_byte_array_literal() {
+    let ba = 'abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ_'_ByteArray;
+    assert(ba.len() == 63_usize, 'literal len');
+    assert(ba.at(0_usize).unwrap() == 'a'_u8, 'literal byte 0');
+    assert(ba.at(30_usize).unwrap() == '4'_u8, 'literal byte 30');
+    assert(ba.at(31_usize).unwrap() == '5'_u8, 'literal byte 31');
+    assert(ba.at(61_usize).unwrap() == 'Z'_u8, 'literal byte 61');
+    assert(ba[62_usize] == '_'_u8, 'literal byte 62');
+    assert(ba.at(63_usize).is_none(), 'literal byte 63');
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_byte_array_append() {
+    let mut ba: ByteArray = Default::default();
+    ba.append_word('abcdefghijklmnopqrstuvwxyz', 26_usize);
+    ba.append_word('0123456789ABCDEFGHIJ', 20_usize);
+    ba.append_byte('K'_u8);
+    assert(ba.len() == 47_usize, 'len');
+    assert(ba.at(46_usize).unwrap() == 'K'_u8, 'append_byte');
+    let appended = @ba;
+    assert(
+        appended.clone() == 'abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJK'_ByteArray,
+        'append_word'
+    );
+
+    let mut other: ByteArray = Default::default();
+    other.append_word('LMNOPQRSTUVWXYZ', 15_usize);
+    ba.append(@other);
+    let appended = @ba;
+    assert(
+        appended.clone() == 'abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ'_ByteArray,
+        'append'
+    );
+    assert(ba != 'abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXY_'_ByteArray, 'ne');
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_byte_array_concat() {
+    let left = 'abcdefghijklmnopqrstuvwxyz0123456789'_ByteArray;
+    let right = 'ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789'_ByteArray;
+    let concatenated = ByteArrayTrait::concat(@left, @right);
+    assert(
+        concatenated == 'abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789'_ByteArray,
+        'concat'
+    );
+    let mut sum = left + right;
+    assert(sum.len() == 72_usize, 'add');
+    sum += 'abcdefghijklmnopqrstuvwxyz0123456789'_ByteArray;
+    assert(sum.len() == 108_usize, 'add_eq');
+    assert(sum.at(72_usize).unwrap() == 'a'_u8, 'add_eq byte');
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_byte_array_serde() {
+    let ba = 'abcdefghijklmnopqrstuvwxyz0123456789'_ByteArray;
+    let mut serialized = ArrayTrait::new();
+    serde::Serde::serialize(ref serialized, ba);
+    assert(serialized.len() == 4_usize, 'serialized len');
+    assert(*serialized[0_usize] == 1, 'full words len');
+    assert(*serialized[1_usize] == 'abcdefghijklmnopqrstuvwxyz01234', 'full word');
+    assert(*serialized[2_usize] == '56789', 'pending word');
+    assert(*serialized[3_usize] == 5, 'pending word len');
+    let mut span = serialized.span();
+    let deserialized: ByteArray = serde::Serde::deserialize(ref span).unwrap();
+    assert(deserialized == 'abcdefghijklmnopqrstuvwxyz0123456789'_ByteArray, 'deserialized');
+}
+
+#[test]
+#[available_gas(1000000)]
+#[should_panic(expected =
+    (
+        0x46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3,
+        1,
+        'This is an error message longer',
+        ' than 31 bytes.',
+        15,
+    ))]
+fn test_panic_with_byte_array() {
+    panic_with_byte_array(@'This is an error message longer than 31 bytes.'_ByteArray);
+}
+
+const TEST_CONST_BASE: u128 = 21_u128;
+const TEST_CONST_DOUBLE: u128 = TEST_CONST_BASE * 2_u128;
+const TEST_CONST_U256: u256 = 0x100000000000000000000000000000002_u256 + u256 {
+    low: 1_u128, high: 0_u128
+};
+const TEST_CONST_FELT252: felt252 = -'abc' + 1;
+const TEST_CONST_TUPLE: (u8, Option::<bool>) = (255_u8 / 2_u8, Option::Some(true));
+
+#[test]
+fn test_const_evaluation() {
+    assert(TEST_CONST_DOUBLE == 42_u128, 'arithmetic');
+    assert(TEST_CONST_U256 == as_u256(1_u128, 3_u128), 'u256');
+    assert(TEST_CONST_FELT252 + 'abc' == 1, 'felt252');
+    let (a, b) = TEST_CONST_TUPLE;
+    assert(a == 127_u8, 'tuple');
+    assert(b.unwrap(), 'enum');
+}
+
+trait Sized<T> {
+    type Key;
+    const SIZE: u32;
+}
+#[derive(Copy, Drop)]
+struct Pair {
+    a: felt252,
+    b: felt252,
+}
+impl PairSized of Sized::<Pair> {
+    type Key = felt252;
+    const SIZE: u32 = 2_u32;
+}
+impl U256Sized of Sized::<u256> {
+    type Key = u128;
+    const SIZE: u32 = PairSized::SIZE * 2_u32;
+}
+
+fn total_size<T, impl TSized: Sized::<T>>(values: @Array::<T>) -> u32 {
+    TSized::SIZE * values.len()
+}
+
+fn default_key<T, impl TSized: Sized::<T>, impl D: Default::<TSized::Key>>() -> TSized::Key {
+    Default::default()
+}
+
+fn type_param_size<T, impl TSized: Sized::<T>, impl TDrop: Drop::<T>>(value: T) -> u32 {
+    T::SIZE
+}
+
+fn type_param_default_key<T, impl TSized: Sized::<T>, impl D: Default::<T::Key>>() -> T::Key {
+    Default::default()
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_trait_associated_items() {
+    assert(PairSized::SIZE == 2_u32, 'impl constant');
+    assert(U256Sized::SIZE == 4_u32, 'impl constant from constant');
+    let key: PairSized::Key = 5;
+    assert(key == 5, 'impl type');
+    let mut pairs = ArrayTrait::new();
+    pairs.append(Pair { a: 1, b: 2 });
+    pairs.append(Pair { a: 3, b: 4 });
+    assert(total_size(@pairs) == 4_u32, 'generic constant');
+    let mut values = ArrayTrait::new();
+    values.append(as_u256(5_u128, 6_u128));
+    assert(total_size(@values) == 4_u32, 'generic constant u256');
+    assert(default_key::<u256>() == 0_u128, 'generic type');
+    assert(type_param_size(Pair { a: 1, b: 2 }) == 2_u32, 'type param constant');
+    assert(type_param_default_key::<u256>() == 0_u128, 'type param type');
+}
+
+trait Keyed<T> {
+    type Key;
+    fn key(self: @T) -> Self::Key;
+}
+impl PairKeyed of Keyed::<Pair> {
+    type Key = felt252;
+    fn key(self: @Pair) -> Self::Key {
+        *self.a
+    }
+}
+
+fn generic_key<T, impl TKeyed: Keyed::<T>>(value: @T) -> TKeyed::Key {
+    TKeyed::key(value)
+}
+
+#[test]
+fn test_trait_function_associated_type() {
+    let pair = Pair { a: 1, b: 2 };
+    assert(pair.key() == 1, 'method');
+    assert(Keyed::key(@pair) == 1, 'trait function');
+    assert(generic_key(@pair) == 1, 'generic function');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_for_loop_over_span() {
+    let mut sum = 0;
+    for x in test_array_helper().span() {
+        sum += *x;
+    };
+    assert(sum == 33, 'sum == 33');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_for_loop_over_array() {
+    let mut pairs = ArrayTrait::new();
+    pairs.append((1, 2));
+    pairs.append((3, 4));
+    let mut sum = 0;
+    for (a, b) in pairs {
+        sum += a * b;
+    };
+    assert(sum == 14, 'sum == 14');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_for_loop_over_span_with_builtin() {
+    let mut values = ArrayTrait::new();
+    values.append(1);
+    values.append(2);
+    values.append(3);
+    let mut hash = 0;
+    for value in values.span() {
+        hash = pedersen(hash, *value);
+    };
+    assert(hash == pedersen(pedersen(pedersen(0, 1), 2), 3), 'hash chain');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_for_loop_over_range() {
+    let mut sum = 0_u32;
+    for i in 0_u32..10_u32 {
+        if i == 3_u32 {
+            continue;
+        }
+        if i == 6_u32 {
+            break;
+        }
+        sum += i;
+    };
+    assert(sum == 12_u32, 'sum == 12');
+    let mut count = 0_u8;
+    for _ in 5_u8..5_u8 {
+        count += 1_u8;
+    };
+    assert(count == 0_u8, 'empty range');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_while_loop() {
+    let mut i = 0_u32;
+    let mut found = 0_u32;
+    let span = test_array_helper().span();
+    while i < span.len() {
+        if *span[i] == 11 {
+            found = i;
+        }
+        i += 1_u32;
+    };
+    assert(found == 1_u32, 'found == 1');
+}
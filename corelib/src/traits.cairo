@@ -86,6 +86,33 @@ trait Not<T> {
     fn not(a: T) -> T;
 }
 
+/// The following two traits are for implementing the [] operator. Only one should be implemented
+/// for each type. Neither consumes self: `IndexView` gets a snapshot of the indexed object, and
+/// `Index` gets it by `ref`.
+trait IndexView<C, I, V> {
+    fn index(self: @C, index: I) -> V;
+}
+
+trait Index<C, I, V> {
+    fn index(ref self: C, index: I) -> V;
+}
+
+/// Trait for types with a default value.
+trait Default<T> {
+    fn default() -> T;
+}
+
+/// The following two traits are implemented by the compiler for closures, and are used for calling
+/// them. `FnOnce` consumes the closure, while `Fn` only takes a snapshot of it, and is implemented
+/// only for closures whose captured variables are all copyable.
+trait FnOnce<T, Args, R> {
+    fn call_once(self: T, args: Args) -> R;
+}
+
+trait Fn<T, Args, R> {
+    fn call(self: @T, args: Args) -> R;
+}
+
 trait Destruct<T> {
     fn destruct(self: T) nopanic;
 }
@@ -34,6 +34,52 @@ pub enum Hint {
         value: ResOperand,
         dst: CellRef,
     },
+    /// Computes the high and low 128-bit words of the multiplication of two u128s.
+    WideMul128 {
+        lhs: ResOperand,
+        rhs: ResOperand,
+        high: CellRef,
+        low: CellRef,
+    },
+    /// Divides a u256 by a non-zero u256, writing the 128-bit words of the quotient and the
+    /// remainder.
+    Uint256DivMod {
+        dividend0: ResOperand,
+        dividend1: ResOperand,
+        divisor0: ResOperand,
+        divisor1: ResOperand,
+        quotient0: CellRef,
+        quotient1: CellRef,
+        remainder0: CellRef,
+        remainder1: CellRef,
+    },
+    /// Divides a u512 by a non-zero u256, writing the 128-bit words of the quotient and the
+    /// remainder.
+    Uint512DivModByUint256 {
+        dividend0: ResOperand,
+        dividend1: ResOperand,
+        dividend2: ResOperand,
+        dividend3: ResOperand,
+        divisor0: ResOperand,
+        divisor1: ResOperand,
+        quotient0: CellRef,
+        quotient1: CellRef,
+        quotient2: CellRef,
+        quotient3: CellRef,
+        remainder0: CellRef,
+        remainder1: CellRef,
+    },
+    /// Computes the floored square root of a u256, as its two 64-bit words, the remainder
+    /// `value - sqrt**2` as its 128-bit words, and whether `2 * sqrt - remainder >= 2**128`.
+    Uint256SquareRoot {
+        value_low: ResOperand,
+        value_high: ResOperand,
+        sqrt0: CellRef,
+        sqrt1: CellRef,
+        remainder_low: CellRef,
+        remainder_high: CellRef,
+        sqrt_mul_2_minus_remainder_ge_u128: CellRef,
+    },
     /// Finds some `x` and `y` such that `x * scalar + y = value` and `x <= max_x`.
     LinearSplit {
         value: ResOperand,
@@ -167,6 +213,27 @@ pub enum Hint {
     SetSequencerAddress {
         value: ResOperand,
     },
+    /// Pops the earliest unpopped event emitted by the contract at `value`.
+    /// Writes 0 to `opt_variant` and the keys and data of the event if there is one, and 1 to
+    /// `opt_variant` otherwise.
+    PopLog {
+        value: ResOperand,
+        opt_variant: CellRef,
+        keys_start: CellRef,
+        keys_end: CellRef,
+        data_start: CellRef,
+        data_end: CellRef,
+    },
+    /// Pops the earliest unpopped message sent to L1 by the contract at `value`.
+    /// Writes 0 to `opt_variant` and the recipient and payload of the message if there is one, and
+    /// 1 to `opt_variant` otherwise.
+    PopL2ToL1Message {
+        value: ResOperand,
+        opt_variant: CellRef,
+        to_address: CellRef,
+        payload_start: CellRef,
+        payload_end: CellRef,
+    },
 }
 
 struct DerefOrImmediateFormatter<'a>(&'a DerefOrImmediate);
@@ -287,6 +354,108 @@ impl Display for Hint {
             Hint::SquareRoot { value, dst } => {
                 write!(f, "(memory{dst}) = sqrt({})", ResOperandFormatter(value))
             }
+            Hint::WideMul128 { lhs, rhs, high, low } => write!(
+                f,
+                "(memory{high}, memory{low}) = divmod({} * {}, 2**128)",
+                ResOperandFormatter(lhs),
+                ResOperandFormatter(rhs)
+            ),
+            Hint::Uint256DivMod {
+                dividend0,
+                dividend1,
+                divisor0,
+                divisor1,
+                quotient0,
+                quotient1,
+                remainder0,
+                remainder1,
+            } => {
+                let (dividend0, dividend1, divisor0, divisor1) = (
+                    ResOperandFormatter(dividend0),
+                    ResOperandFormatter(dividend1),
+                    ResOperandFormatter(divisor0),
+                    ResOperandFormatter(divisor1),
+                );
+                writedoc!(
+                    f,
+                    "
+
+                        dividend = {dividend0} + {dividend1} * 2**128
+                        divisor = {divisor0} + {divisor1} * 2**128
+                        quotient, remainder = divmod(dividend, divisor)
+                        memory{quotient0} = quotient & 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
+                        memory{quotient1} = quotient >> 128
+                        memory{remainder0} = remainder & 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
+                        memory{remainder1} = remainder >> 128
+                    "
+                )
+            }
+            Hint::Uint512DivModByUint256 {
+                dividend0,
+                dividend1,
+                dividend2,
+                dividend3,
+                divisor0,
+                divisor1,
+                quotient0,
+                quotient1,
+                quotient2,
+                quotient3,
+                remainder0,
+                remainder1,
+            } => {
+                let (dividend0, dividend1, dividend2, dividend3, divisor0, divisor1) = (
+                    ResOperandFormatter(dividend0),
+                    ResOperandFormatter(dividend1),
+                    ResOperandFormatter(dividend2),
+                    ResOperandFormatter(dividend3),
+                    ResOperandFormatter(divisor0),
+                    ResOperandFormatter(divisor1),
+                );
+                writedoc!(
+                    f,
+                    "
+
+                        dividend = {dividend0} + {dividend1} * 2**128
+                        dividend += {dividend2} * 2**256 + {dividend3} * 2**384
+                        divisor = {divisor0} + {divisor1} * 2**128
+                        quotient, remainder = divmod(dividend, divisor)
+                        memory{quotient0} = quotient & 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
+                        memory{quotient1} = (quotient >> 128) & 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
+                        memory{quotient2} = (quotient >> 256) & 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
+                        memory{quotient3} = quotient >> 384
+                        memory{remainder0} = remainder & 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
+                        memory{remainder1} = remainder >> 128
+                    "
+                )
+            }
+            Hint::Uint256SquareRoot {
+                value_low,
+                value_high,
+                sqrt0,
+                sqrt1,
+                remainder_low,
+                remainder_high,
+                sqrt_mul_2_minus_remainder_ge_u128,
+            } => {
+                let (value_low, value_high) =
+                    (ResOperandFormatter(value_low), ResOperandFormatter(value_high));
+                writedoc!(
+                    f,
+                    "
+
+                        import math
+                        value = {value_low} + {value_high} * 2**128
+                        root = math.isqrt(value)
+                        remainder = value - root ** 2
+                        memory{sqrt0} = root & 0xFFFFFFFFFFFFFFFF
+                        memory{sqrt1} = root >> 64
+                        memory{remainder_low} = remainder & 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
+                        memory{remainder_high} = remainder >> 128
+                        memory{sqrt_mul_2_minus_remainder_ge_u128} = root * 2 - remainder >= 2**128
+                    "
+                )
+            }
             Hint::LinearSplit { value, scalar, max_x, x, y } => {
                 let (value, scalar, max_x) = (
                     ResOperandFormatter(value),
@@ -513,6 +682,49 @@ impl Display for Hint {
             Hint::SetSequencerAddress { value } => {
                 write!(f, "syscall_handler.sequencer_address = {}", ResOperandFormatter(value))
             }
+            Hint::PopLog { value, opt_variant, keys_start, keys_end, data_start, data_end } => {
+                writedoc!(
+                    f,
+                    "
+
+                        logs = syscall_handler.logs[{}]
+                        if len(logs) == 0:
+                            memory{opt_variant} = 1
+                        else:
+                            memory{opt_variant} = 0
+                            keys, data = logs.pop(0)
+                            memory{keys_start} = segments.add()
+                            memory{keys_end} = segments.load_data(memory{keys_start}, keys)
+                            memory{data_start} = segments.add()
+                            memory{data_end} = segments.load_data(memory{data_start}, data)
+                    ",
+                    ResOperandFormatter(value)
+                )
+            }
+            Hint::PopL2ToL1Message {
+                value,
+                opt_variant,
+                to_address,
+                payload_start,
+                payload_end,
+            } => {
+                writedoc!(
+                    f,
+                    "
+
+                        messages = syscall_handler.l2_to_l1_messages[{}]
+                        if len(messages) == 0:
+                            memory{opt_variant} = 1
+                        else:
+                            memory{opt_variant} = 0
+                            memory{to_address}, payload = messages.pop(0)
+                            memory{payload_start} = segments.add()
+                            memory{payload_end} = segments.load_data(memory{payload_start}, \
+                     payload)
+                    ",
+                    ResOperandFormatter(value)
+                )
+            }
         }
     }
 }
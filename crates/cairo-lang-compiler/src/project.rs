@@ -57,10 +57,12 @@ fn setup_single_file_project(
     }
 }
 
-/// Updates the crate roots from a ProjectConfig object.
+/// Updates the crate roots and the crate settings from a ProjectConfig object.
 pub fn update_crate_roots_from_project_config(db: &mut dyn SemanticGroup, config: ProjectConfig) {
     for (crate_name, directory_path) in config.content.crate_roots {
+        let settings = config.content.crates_config.get(&crate_name).clone();
         let crate_id = db.intern_crate(CrateLongId(crate_name));
+        db.set_crate_settings(crate_id, Some(settings));
         let mut path = PathBuf::from(&directory_path);
         if path.is_relative() {
             path = PathBuf::from(&config.base_path).join(path);
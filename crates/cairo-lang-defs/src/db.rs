@@ -11,9 +11,10 @@ use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::Upcast;
+use itertools::Itertools;
 
 use crate::ids::*;
-use crate::plugin::{DynGeneratedFileAuxData, MacroPlugin, PluginDiagnostic};
+use crate::plugin::{DynGeneratedFileAuxData, MacroPlugin, MacroPluginMetadata, PluginDiagnostic};
 
 /// Salsa database interface.
 /// See [`super::ids`] for further details.
@@ -37,6 +38,10 @@ pub trait DefsGroup:
     #[salsa::interned]
     fn intern_impl_function(&self, id: ImplFunctionLongId) -> ImplFunctionId;
     #[salsa::interned]
+    fn intern_impl_type_def(&self, id: ImplTypeDefLongId) -> ImplTypeDefId;
+    #[salsa::interned]
+    fn intern_impl_constant_def(&self, id: ImplConstantDefLongId) -> ImplConstantDefId;
+    #[salsa::interned]
     fn intern_struct(&self, id: StructLongId) -> StructId;
     #[salsa::interned]
     fn intern_enum(&self, id: EnumLongId) -> EnumId;
@@ -51,6 +56,10 @@ pub trait DefsGroup:
     #[salsa::interned]
     fn intern_trait_function(&self, id: TraitFunctionLongId) -> TraitFunctionId;
     #[salsa::interned]
+    fn intern_trait_type(&self, id: TraitTypeLongId) -> TraitTypeId;
+    #[salsa::interned]
+    fn intern_trait_constant(&self, id: TraitConstantLongId) -> TraitConstantId;
+    #[salsa::interned]
     fn intern_impl(&self, id: ImplDefLongId) -> ImplDefId;
     #[salsa::interned]
     fn intern_extern_type(&self, id: ExternTypeLongId) -> ExternTypeId;
@@ -286,6 +295,15 @@ fn priv_module_data(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<ModuleData
         }
     };
 
+    let crate_settings = db.crate_settings(module_id.owning_crate(db));
+    let plugins = db
+        .macro_plugins()
+        .into_iter()
+        .filter(|plugin| crate_settings.is_plugin_enabled(plugin.name()))
+        .collect_vec();
+    let metadata =
+        MacroPluginMetadata { cfg_set: &crate_settings.cfg, edition: crate_settings.edition };
+
     let mut module_queue = VecDeque::new();
     module_queue.push_back((module_file, item_asts));
     let mut res = ModuleData::default();
@@ -302,8 +320,8 @@ fn priv_module_data(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<ModuleData
             // Iterate the plugins by their order. The first one to change something (either
             // generate new code, remove the original code, or both), breaks the loop. If more
             // plugins might have act on the item, they can do it on the generated code.
-            for plugin in db.macro_plugins() {
-                let result = plugin.generate_code(db.upcast(), item_ast.clone());
+            for plugin in &plugins {
+                let result = plugin.generate_code(db.upcast(), item_ast.clone(), &metadata);
                 for plugin_diag in result.diagnostics {
                     res.plugin_diagnostics.push((module_file_id, plugin_diag));
                 }
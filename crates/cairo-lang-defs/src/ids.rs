@@ -14,6 +14,11 @@
 // Has 2 definitions:
 //   * Trait "MyTrait"
 //   * TraitFunction "foo".
+// * trait MyTrait{ type Foo; const BAR: u32; }
+// Has 3 definitions:
+//   * Trait "MyTrait"
+//   * TraitType "Foo".
+//   * TraitConstant "BAR".
 // * impl A for MyTrait{ fn foo() -> (){...} }
 // Has 2 definitions:
 //   * Impl "A"
@@ -281,6 +286,7 @@ pub struct FileIndex(pub usize);
 pub struct ModuleFileId(pub ModuleId, pub FileIndex);
 
 define_language_element_id_as_enum! {
+    #[toplevel]
     /// Id for direct children of a module.
     pub enum ModuleItemId {
         Constant(ConstantId),
@@ -334,21 +340,32 @@ define_language_element_id_partial!(
     lookup_intern_impl_function,
     name
 );
+/// Returns the impl containing the impl item with the given stable pointer.
+fn containing_impl_def_id(
+    db: &dyn DefsGroup,
+    module_file_id: ModuleFileId,
+    item_ptr: SyntaxStablePtrId,
+) -> ImplDefId {
+    // Impl item ast lies a few levels bellow the impl ast.
+    // Fetch the grand grand grand parent.
+    // TODO(spapini): Use a parent function.
+    let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(item_ptr) else {
+        panic!()
+    };
+    let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(parent) else {
+        panic!()
+    };
+    let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(parent) else {
+        panic!()
+    };
+    let impl_ptr = ast::ItemImplPtr(parent);
+    db.intern_impl(ImplDefLongId(module_file_id, impl_ptr))
+}
+
 impl ImplFunctionId {
     pub fn impl_def_id(&self, db: &dyn DefsGroup) -> ImplDefId {
         let ImplFunctionLongId(module_file_id, ptr) = db.lookup_intern_impl_function(*self);
-        // TODO(spapini): Use a parent function.
-        let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(ptr.untyped()) else {
-            panic!()
-        };
-        let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(parent) else {
-            panic!()
-        };
-        let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(parent) else {
-            panic!()
-        };
-        let impl_ptr = ast::ItemImplPtr(parent);
-        db.intern_impl(ImplDefLongId(module_file_id, impl_ptr))
+        containing_impl_def_id(db, module_file_id, ptr.untyped())
     }
 }
 impl UnstableSalsaId for ImplFunctionId {
@@ -366,6 +383,52 @@ impl TopLevelLanguageElementId for ImplFunctionId {
     }
 }
 
+define_language_element_id_partial!(
+    ImplTypeDefId,
+    ImplTypeDefLongId,
+    ast::ItemTypeAlias,
+    lookup_intern_impl_type_def,
+    name
+);
+impl ImplTypeDefId {
+    pub fn impl_def_id(&self, db: &dyn DefsGroup) -> ImplDefId {
+        let ImplTypeDefLongId(module_file_id, ptr) = db.lookup_intern_impl_type_def(*self);
+        containing_impl_def_id(db, module_file_id, ptr.untyped())
+    }
+}
+impl TopLevelLanguageElementId for ImplTypeDefId {
+    fn full_path(&self, db: &dyn DefsGroup) -> String {
+        format!("{}::{}", self.impl_def_id(db).name(db), self.name(db))
+    }
+
+    fn name(&self, db: &dyn DefsGroup) -> SmolStr {
+        db.lookup_intern_impl_type_def(*self).name(db)
+    }
+}
+
+define_language_element_id_partial!(
+    ImplConstantDefId,
+    ImplConstantDefLongId,
+    ast::ItemConstant,
+    lookup_intern_impl_constant_def,
+    name
+);
+impl ImplConstantDefId {
+    pub fn impl_def_id(&self, db: &dyn DefsGroup) -> ImplDefId {
+        let ImplConstantDefLongId(module_file_id, ptr) = db.lookup_intern_impl_constant_def(*self);
+        containing_impl_def_id(db, module_file_id, ptr.untyped())
+    }
+}
+impl TopLevelLanguageElementId for ImplConstantDefId {
+    fn full_path(&self, db: &dyn DefsGroup) -> String {
+        format!("{}::{}", self.impl_def_id(db).name(db), self.name(db))
+    }
+
+    fn name(&self, db: &dyn DefsGroup) -> SmolStr {
+        db.lookup_intern_impl_constant_def(*self).name(db)
+    }
+}
+
 define_language_element_id_as_enum! {
     /// Represents a function that has a body.
     pub enum FunctionWithBodyId {
@@ -426,23 +489,32 @@ define_language_element_id_partial!(
     lookup_intern_trait_function,
     name
 );
+/// Returns the trait containing the trait item with the given stable pointer.
+fn containing_trait_id(
+    db: &dyn DefsGroup,
+    module_file_id: ModuleFileId,
+    item_ptr: SyntaxStablePtrId,
+) -> TraitId {
+    // Trait item ast lies a few levels bellow the trait ast.
+    // Fetch the grand grand grand parent.
+    // TODO(spapini): Use a parent function.
+    let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(item_ptr) else {
+        panic!()
+    };
+    let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(parent) else {
+        panic!()
+    };
+    let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(parent) else {
+        panic!()
+    };
+    let trait_ptr = ast::ItemTraitPtr(parent);
+    db.intern_trait(TraitLongId(module_file_id, trait_ptr))
+}
+
 impl TraitFunctionId {
     pub fn trait_id(&self, db: &dyn DefsGroup) -> TraitId {
         let TraitFunctionLongId(module_file_id, ptr) = db.lookup_intern_trait_function(*self);
-        // Trait function ast lies a few levels bellow the trait ast.
-        // Fetch the grand grand grand parent.
-        // TODO(spapini): Use a parent function.
-        let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(ptr.untyped()) else {
-            panic!()
-        };
-        let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(parent) else {
-            panic!()
-        };
-        let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(parent) else {
-            panic!()
-        };
-        let trait_ptr = ast::ItemTraitPtr(parent);
-        db.intern_trait(TraitLongId(module_file_id, trait_ptr))
+        containing_trait_id(db, module_file_id, ptr.untyped())
     }
 }
 impl TopLevelLanguageElementId for TraitFunctionId {
@@ -455,6 +527,52 @@ impl TopLevelLanguageElementId for TraitFunctionId {
     }
 }
 
+define_language_element_id_partial!(
+    TraitTypeId,
+    TraitTypeLongId,
+    ast::TraitItemType,
+    lookup_intern_trait_type,
+    name
+);
+impl TraitTypeId {
+    pub fn trait_id(&self, db: &dyn DefsGroup) -> TraitId {
+        let TraitTypeLongId(module_file_id, ptr) = db.lookup_intern_trait_type(*self);
+        containing_trait_id(db, module_file_id, ptr.untyped())
+    }
+}
+impl TopLevelLanguageElementId for TraitTypeId {
+    fn full_path(&self, db: &dyn DefsGroup) -> String {
+        format!("{}::{}", self.trait_id(db).name(db), self.name(db))
+    }
+
+    fn name(&self, db: &dyn DefsGroup) -> SmolStr {
+        db.lookup_intern_trait_type(*self).name(db)
+    }
+}
+
+define_language_element_id_partial!(
+    TraitConstantId,
+    TraitConstantLongId,
+    ast::TraitItemConstant,
+    lookup_intern_trait_constant,
+    name
+);
+impl TraitConstantId {
+    pub fn trait_id(&self, db: &dyn DefsGroup) -> TraitId {
+        let TraitConstantLongId(module_file_id, ptr) = db.lookup_intern_trait_constant(*self);
+        containing_trait_id(db, module_file_id, ptr.untyped())
+    }
+}
+impl TopLevelLanguageElementId for TraitConstantId {
+    fn full_path(&self, db: &dyn DefsGroup) -> String {
+        format!("{}::{}", self.trait_id(db).name(db), self.name(db))
+    }
+
+    fn name(&self, db: &dyn DefsGroup) -> SmolStr {
+        db.lookup_intern_trait_constant(*self).name(db)
+    }
+}
+
 // Struct items.
 // TODO(spapini): Override full_path for to include parents, for better debug.
 define_language_element_id!(MemberId, MemberLongId, ast::Member, lookup_intern_member, name);
@@ -478,13 +596,20 @@ define_language_element_id!(
     lookup_intern_generic_param
 );
 impl GenericParamLongId {
-    pub fn name(&self, db: &dyn SyntaxGroup) -> SmolStr {
+    /// Returns the name of the generic parameter, or None for anonymous impl parameters (e.g.
+    /// `+Drop<T>`).
+    pub fn name(&self, db: &dyn SyntaxGroup) -> Option<SmolStr> {
         let SyntaxStablePtr::Child {key_fields, .. }=
             db.lookup_intern_stable_ptr(self.1.0) else {
                 unreachable!()
             };
-        let name_green = TerminalIdentifierGreen(key_fields[0]);
-        name_green.identifier(db)
+        let name_green = TerminalIdentifierGreen(*key_fields.first()?);
+        Some(name_green.identifier(db))
+    }
+    /// Returns the name of the generic parameter for debug and formatting purposes.
+    /// Anonymous impl parameters are named `_`.
+    pub fn debug_name(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.name(db).unwrap_or_else(|| "_".into())
     }
     pub fn kind(&self, db: &dyn SyntaxGroup) -> GenericKind {
         let SyntaxStablePtr::Child { kind, .. } =
@@ -494,7 +619,9 @@ impl GenericParamLongId {
         match kind {
             SyntaxKind::GenericParamType => GenericKind::Type,
             SyntaxKind::GenericParamConst => GenericKind::Const,
-            SyntaxKind::GenericParamImpl => GenericKind::Impl,
+            SyntaxKind::GenericParamImpl | SyntaxKind::GenericParamImplAnonymous => {
+                GenericKind::Impl
+            }
             _ => unreachable!(),
         }
     }
@@ -510,9 +637,12 @@ impl GenericParamLongId {
     }
 }
 impl GenericParamId {
-    pub fn name(&self, db: &dyn DefsGroup) -> SmolStr {
+    pub fn name(&self, db: &dyn DefsGroup) -> Option<SmolStr> {
         db.lookup_intern_generic_param(*self).name(db.upcast())
     }
+    pub fn debug_name(&self, db: &dyn DefsGroup) -> SmolStr {
+        db.lookup_intern_generic_param(*self).debug_name(db.upcast())
+    }
     pub fn kind(&self, db: &dyn DefsGroup) -> GenericKind {
         db.lookup_intern_generic_param(*self).kind(db.upcast())
     }
@@ -527,7 +657,7 @@ impl DebugWithDb<dyn DefsGroup> for GenericParamLongId {
             "GenericParam{}({}::{})",
             self.kind(db.upcast()),
             self.generic_item(db).full_path(db),
-            self.name(db.upcast())
+            self.debug_name(db.upcast())
         )
     }
 }
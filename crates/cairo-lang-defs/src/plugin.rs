@@ -2,6 +2,7 @@ use std::any::Any;
 use std::ops::Deref;
 use std::sync::Arc;
 
+use cairo_lang_filesystem::db::Edition;
 use cairo_lang_syntax::node::ast;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
@@ -62,11 +63,28 @@ pub struct PluginDiagnostic {
     pub message: String,
 }
 
+/// Information about the crate of the item a macro plugin generates code for.
+#[derive(Clone, Debug)]
+pub struct MacroPluginMetadata<'a> {
+    /// The configuration values enabled for the crate.
+    pub cfg_set: &'a [SmolStr],
+    /// The edition of the crate.
+    pub edition: Edition,
+}
+
 // TOD(spapini): Move to another place.
 /// A trait for a macro plugin: external plugin that generates additional code for items.
 pub trait MacroPlugin: std::fmt::Debug + Sync + Send {
+    /// The name of the plugin, used to enable it for specific crates in the project config.
+    fn name(&self) -> &'static str;
+
     /// Generates code for an item. If no code should be generated returns None.
     /// Otherwise, returns (virtual_module_name, module_content), and a virtual submodule
     /// with that name and content should be created.
-    fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult;
+    fn generate_code(
+        &self,
+        db: &dyn SyntaxGroup,
+        item_ast: ast::Item,
+        metadata: &MacroPluginMetadata<'_>,
+    ) -> PluginResult;
 }
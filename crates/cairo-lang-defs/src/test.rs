@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 use cairo_lang_debug::debug::DebugWithDb;
 use cairo_lang_filesystem::db::{
-    init_files_group, AsFilesGroupMut, FilesDatabase, FilesGroup, FilesGroupEx,
+    init_files_group, AsFilesGroupMut, CrateSettings, FilesDatabase, FilesGroup, FilesGroupEx,
 };
 use cairo_lang_filesystem::ids::{CrateLongId, Directory, FileLongId};
 use cairo_lang_parser::db::{ParserDatabase, ParserGroup};
@@ -19,8 +19,8 @@ use crate::ids::{
     FileIndex, GenericParamLongId, ModuleFileId, ModuleId, ModuleItemId, SubmoduleLongId,
 };
 use crate::plugin::{
-    DynGeneratedFileAuxData, GeneratedFileAuxData, MacroPlugin, PluginDiagnostic,
-    PluginGeneratedFile, PluginResult,
+    DynGeneratedFileAuxData, GeneratedFileAuxData, MacroPlugin, MacroPluginMetadata,
+    PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
 
 #[salsa::database(DefsDatabase, ParserDatabase, SyntaxDatabase, FilesDatabase)]
@@ -104,7 +104,8 @@ fn test_generic_item_id(inputs: &OrderedHashMap<String, String>) -> OrderedHashM
             }
             SyntaxKind::GenericParamType
             | SyntaxKind::GenericParamConst
-            | SyntaxKind::GenericParamImpl => {
+            | SyntaxKind::GenericParamImpl
+            | SyntaxKind::GenericParamImplAnonymous => {
                 let param_id = db.intern_generic_param(GenericParamLongId(
                     module_file_id,
                     ast::GenericParamPtr(node.stable_ptr()),
@@ -221,7 +222,16 @@ impl GeneratedFileAuxData for DummyAuxData {
 #[derive(Debug)]
 struct DummyPlugin {}
 impl MacroPlugin for DummyPlugin {
-    fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
+    fn name(&self) -> &'static str {
+        "dummy"
+    }
+
+    fn generate_code(
+        &self,
+        db: &dyn SyntaxGroup,
+        item_ast: ast::Item,
+        _metadata: &MacroPluginMetadata<'_>,
+    ) -> PluginResult {
         match item_ast {
             ast::Item::Struct(struct_ast) => {
                 let remove_original_item = struct_ast
@@ -280,6 +290,33 @@ fn test_plugin() {
     );
 }
 
+#[test]
+fn test_plugin_crate_settings() {
+    let mut db_val = DatabaseForTesting::default();
+    let db = &mut db_val;
+
+    let crate_id = db.intern_crate(CrateLongId("test".into()));
+    let root = Directory("src".into());
+    db.set_crate_root(crate_id, Some(root));
+    db.set_crate_settings(
+        crate_id,
+        Some(CrateSettings {
+            plugins: Some(vec!["foo_to_bar".into(), "remove_orig".into()]),
+            ..Default::default()
+        }),
+    );
+
+    // Main module file.
+    set_file_content(db, "src/lib.cairo", "struct A{}");
+
+    // Verify that the dummy plugin, which is not enabled for the crate, generated nothing.
+    let module_id = ModuleId::CrateRoot(crate_id);
+    assert_eq!(
+        format!("{:?}", db.module_items(module_id).unwrap().debug(db)),
+        "[StructId(test::A)]"
+    );
+}
+
 #[test]
 fn test_plugin_remove_original() {
     let mut db_val = DatabaseForTesting::default();
@@ -309,7 +346,16 @@ fn test_plugin_remove_original() {
 #[derive(Debug)]
 struct RemoveOrigPlugin {}
 impl MacroPlugin for RemoveOrigPlugin {
-    fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
+    fn name(&self) -> &'static str {
+        "remove_orig"
+    }
+
+    fn generate_code(
+        &self,
+        db: &dyn SyntaxGroup,
+        item_ast: ast::Item,
+        _metadata: &MacroPluginMetadata<'_>,
+    ) -> PluginResult {
         let Some(free_function_ast) = try_extract_matches!(item_ast, ast::Item::FreeFunction) else { return PluginResult::default(); };
         if !free_function_ast
             .attributes(db)
@@ -328,7 +374,16 @@ impl MacroPlugin for RemoveOrigPlugin {
 #[derive(Debug)]
 struct FooToBarPlugin {}
 impl MacroPlugin for FooToBarPlugin {
-    fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
+    fn name(&self) -> &'static str {
+        "foo_to_bar"
+    }
+
+    fn generate_code(
+        &self,
+        db: &dyn SyntaxGroup,
+        item_ast: ast::Item,
+        _metadata: &MacroPluginMetadata<'_>,
+    ) -> PluginResult {
         let Some(free_function_ast) = try_extract_matches!(item_ast, ast::Item::FreeFunction) else { return PluginResult::default(); };
         if free_function_ast.declaration(db).name(db).text(db) != "foo" {
             return PluginResult::default();
@@ -8,6 +8,8 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use cairo_lang_utils::Upcast;
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
 
 use crate::flag::Flag;
 use crate::ids::{CrateId, CrateLongId, Directory, FileId, FileLongId, FlagId, FlagLongId};
@@ -15,6 +17,52 @@ use crate::span::{FileSummary, TextOffset, TextWidth};
 
 pub const CORELIB_CRATE_NAME: &str = "core";
 
+/// A version of the Cairo language. Editions allow introducing breaking changes to the language
+/// without breaking existing crates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Edition {
+    #[default]
+    #[serde(rename = "2023_01")]
+    V2023_01,
+}
+
+/// Compilation settings of a single crate.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrateSettings {
+    /// The edition of the language used by the crate.
+    #[serde(default)]
+    pub edition: Edition,
+    /// The names of the crates this crate may refer to. If not set, all the crates are visible.
+    /// The corelib and the crate itself are always visible.
+    #[serde(default)]
+    pub dependencies: Option<Vec<SmolStr>>,
+    /// The configuration values enabled for the crate, used by `#[cfg(...)]` attributes.
+    #[serde(default)]
+    pub cfg: Vec<SmolStr>,
+    /// The names of the plugins enabled for the crate. If not set, all the plugins are enabled.
+    #[serde(default)]
+    pub plugins: Option<Vec<SmolStr>>,
+}
+impl CrateSettings {
+    /// Returns whether the crate named `crate_name` is visible from a crate with these settings.
+    pub fn is_dependency_visible(&self, crate_name: &str) -> bool {
+        match &self.dependencies {
+            Some(dependencies) => {
+                crate_name == CORELIB_CRATE_NAME || dependencies.iter().any(|dep| dep == crate_name)
+            }
+            None => true,
+        }
+    }
+
+    /// Returns whether the plugin named `plugin_name` is enabled for a crate with these settings.
+    pub fn is_plugin_enabled(&self, plugin_name: &str) -> bool {
+        match &self.plugins {
+            Some(plugins) => plugins.iter().any(|plugin| plugin == plugin_name),
+            None => true,
+        }
+    }
+}
+
 // Salsa database interface.
 #[salsa::query_group(FilesDatabase)]
 pub trait FilesGroup {
@@ -29,6 +77,11 @@ pub trait FilesGroup {
     #[salsa::input]
     fn crate_roots(&self) -> Arc<HashMap<CrateId, Directory>>;
 
+    /// Compilation settings of the crates. Crates that do not appear here use the default
+    /// settings.
+    #[salsa::input]
+    fn crate_configs(&self) -> Arc<HashMap<CrateId, CrateSettings>>;
+
     /// Overrides for file content. Mostly used by language server and tests.
     /// TODO(spapini): Currently, when this input changes, all the file_content() queries will
     /// be invalidated.
@@ -46,6 +99,8 @@ pub trait FilesGroup {
     fn crates(&self) -> Vec<CrateId>;
     /// Root directory of the crate.
     fn crate_root_dir(&self, crate_id: CrateId) -> Option<Directory>;
+    /// Compilation settings of the crate.
+    fn crate_settings(&self, crate_id: CrateId) -> CrateSettings;
 
     /// Query for raw file contents. Private.
     fn priv_raw_file_content(&self, file_id: FileId) -> Option<Arc<String>>;
@@ -61,6 +116,7 @@ pub fn init_files_group(db: &mut (dyn FilesGroup + 'static)) {
     // Initialize inputs.
     db.set_file_overrides(Arc::new(HashMap::new()));
     db.set_crate_roots(Arc::new(HashMap::new()));
+    db.set_crate_configs(Arc::new(HashMap::new()));
     db.set_flags(Arc::new(HashMap::new()));
 }
 
@@ -95,6 +151,15 @@ pub trait FilesGroupEx: Upcast<dyn FilesGroup> + AsFilesGroupMut {
         };
         self.as_files_group_mut().set_crate_roots(Arc::new(crate_roots));
     }
+    /// Sets the compilation settings of the crate. None value resets them to the default.
+    fn set_crate_settings(&mut self, crt: CrateId, settings: Option<CrateSettings>) {
+        let mut crate_configs = Upcast::upcast(self).crate_configs().as_ref().clone();
+        match settings {
+            Some(settings) => crate_configs.insert(crt, settings),
+            None => crate_configs.remove(&crt),
+        };
+        self.as_files_group_mut().set_crate_configs(Arc::new(crate_configs));
+    }
     /// Sets the given flag value. None value removes the flag.
     fn set_flag(&mut self, id: FlagId, value: Option<Arc<Flag>>) {
         let mut flags = Upcast::upcast(self).flags().as_ref().clone();
@@ -118,6 +183,9 @@ fn crates(db: &dyn FilesGroup) -> Vec<CrateId> {
 fn crate_root_dir(db: &dyn FilesGroup, crt: CrateId) -> Option<Directory> {
     db.crate_roots().get(&crt).cloned()
 }
+fn crate_settings(db: &dyn FilesGroup, crt: CrateId) -> CrateSettings {
+    db.crate_configs().get(&crt).cloned().unwrap_or_default()
+}
 
 fn priv_raw_file_content(db: &dyn FilesGroup, file: FileId) -> Option<Arc<String>> {
     match db.lookup_intern_file(file) {
@@ -4,7 +4,7 @@ use cairo_lang_utils::Upcast;
 use test_log::test;
 
 use super::FilesGroup;
-use crate::db::FilesGroupEx;
+use crate::db::{CrateSettings, FilesGroupEx};
 use crate::flag::Flag;
 use crate::ids::{CrateLongId, Directory, FlagId};
 use crate::test_utils::FilesDatabaseForTesting;
@@ -37,3 +37,29 @@ fn test_flags() {
     assert_eq!(*db.get_flag(add_withdraw_gas_flag_id).unwrap(), Flag::AddWithdrawGas(true));
     assert!(db.get_flag(FlagId::new(db.upcast(), "non_existing_flag")).is_none());
 }
+
+#[test]
+fn test_crate_settings() {
+    let mut db = FilesDatabaseForTesting::default();
+
+    let crt = db.intern_crate(CrateLongId("my_crate".into()));
+    let crt2 = db.intern_crate(CrateLongId("my_crate2".into()));
+    let settings = CrateSettings {
+        dependencies: Some(vec!["my_crate2".into()]),
+        cfg: vec!["test".into()],
+        plugins: Some(vec!["derive".into()]),
+        ..CrateSettings::default()
+    };
+    db.set_crate_settings(crt, Some(settings.clone()));
+
+    assert_eq!(db.crate_settings(crt), settings);
+    assert_eq!(db.crate_settings(crt2), CrateSettings::default());
+
+    assert!(settings.is_dependency_visible("my_crate2"));
+    assert!(settings.is_dependency_visible("core"));
+    assert!(!settings.is_dependency_visible("my_crate3"));
+    assert!(CrateSettings::default().is_dependency_visible("my_crate3"));
+    assert!(settings.is_plugin_enabled("derive"));
+    assert!(!settings.is_plugin_enabled("starknet"));
+    assert!(CrateSettings::default().is_plugin_enabled("starknet"));
+}
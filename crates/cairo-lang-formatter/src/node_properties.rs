@@ -32,6 +32,12 @@ impl SyntaxNodeFormat for SyntaxNode {
             {
                 true
             }
+            SyntaxKind::TokenOr if is_closure_param_wrapper_or(db, self, false) => true,
+            SyntaxKind::TokenDotDot
+                if grandparent_kind(db, self) == Some(SyntaxKind::ExprBinary) =>
+            {
+                true
+            }
             SyntaxKind::TokenLT | SyntaxKind::TokenGT
                 if matches!(
                     grandparent_kind(db, self),
@@ -72,9 +78,18 @@ impl SyntaxNodeFormat for SyntaxNode {
             {
                 true
             }
+            SyntaxKind::TokenOr if is_closure_param_wrapper_or(db, self, true) => true,
+            SyntaxKind::TokenDotDot
+                if grandparent_kind(db, self) == Some(SyntaxKind::ExprBinary) =>
+            {
+                true
+            }
             SyntaxKind::TokenMinus | SyntaxKind::TokenMul => {
                 matches!(grandparent_kind(db, self), Some(SyntaxKind::ExprUnary))
             }
+            SyntaxKind::TokenPlus => {
+                matches!(grandparent_kind(db, self), Some(SyntaxKind::GenericParamImplAnonymous))
+            }
             SyntaxKind::TokenLT
                 if matches!(
                     grandparent_kind(db, self),
@@ -389,15 +404,19 @@ impl SyntaxNodeFormat for SyntaxNode {
                         true,
                     )),
                 },
-                SyntaxKind::TerminalPlus => WrappingBreakLinePoints {
-                    leading: Some(BreakLinePointProperties::new(
-                        7,
-                        BreakLinePointIndentation::Indented,
-                        true,
-                        true,
-                    )),
-                    trailing: None,
-                },
+                SyntaxKind::TerminalPlus
+                    if parent_kind(db, self) != Some(SyntaxKind::GenericParamImplAnonymous) =>
+                {
+                    WrappingBreakLinePoints {
+                        leading: Some(BreakLinePointProperties::new(
+                            7,
+                            BreakLinePointIndentation::Indented,
+                            true,
+                            true,
+                        )),
+                        trailing: None,
+                    }
+                }
                 SyntaxKind::TerminalMinus
                     if parent_kind(db, self) != Some(SyntaxKind::ExprUnary) =>
                 {
@@ -450,3 +469,14 @@ impl SyntaxNodeFormat for SyntaxNode {
         }
     }
 }
+
+/// Returns true if the given token is the left (or the right, if `left` is false) `|` wrapping the
+/// parameters of a closure.
+fn is_closure_param_wrapper_or(db: &dyn SyntaxGroup, node: &SyntaxNode, left: bool) -> bool {
+    let Some(terminal) = node.parent() else { return false };
+    let Some(wrapper) = terminal.parent() else { return false };
+    if wrapper.kind(db) != SyntaxKind::ClosureParamWrapperNAry {
+        return false;
+    }
+    (terminal.offset() == wrapper.offset()) == left
+}
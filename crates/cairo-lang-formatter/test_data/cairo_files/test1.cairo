@@ -46,3 +46,12 @@ fn fib(a: Box::<felt252>, b: Box::<felt252>, n: Box::<felt252>) -> Box::<felt252
         },
     }
 }
+
+fn closures() {
+    let f = | x ,y:felt252 | x+y;
+    let g = ||f(1, 2);
+    apply(|x|->felt252{x*2}, 3);
+}
+
+fn bounds<T,+ Drop<T>,  impl TCopy :Copy<T>>(x: T) -> T { x }
+impl ArrayBounds<T, +   Drop<T>> of Foo<Array<T>>;
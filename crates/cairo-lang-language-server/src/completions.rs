@@ -0,0 +1,458 @@
+use cairo_lang_defs::ids::{
+    FunctionWithBodyId, GenericTypeId, LanguageElementId, LookupItemId, ModuleFileId, ModuleItemId,
+    TopLevelLanguageElementId,
+};
+use cairo_lang_filesystem::ids::CrateLongId;
+use cairo_lang_filesystem::span::TextOffset;
+use cairo_lang_semantic::corelib::core_module;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::diagnostic::{NotFoundItemType, SemanticDiagnostics};
+use cairo_lang_semantic::expr::compute::all_module_trait_ids;
+use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
+use cairo_lang_semantic::items::imp::find_possible_impls_at_context;
+use cairo_lang_semantic::items::structure::SemanticStructEx;
+use cairo_lang_semantic::items::us::SemanticUseEx;
+use cairo_lang_semantic::resolve_path::{ResolvedConcreteItem, ResolvedGenericItem, Resolver};
+use cairo_lang_semantic::{ConcreteTypeId, Expr, Statement, TypeId, TypeLongId};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use tower_lsp::lsp_types::{CompletionItem, CompletionItemKind};
+
+use crate::lookup_item_function_with_body;
+
+#[cfg(test)]
+#[path = "completions_test.rs"]
+mod test;
+
+/// Where a keyword is offered as a completion.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum KeywordContext {
+    /// At the start of a module item.
+    Item,
+    /// Inside a function body.
+    Body,
+    /// Never, e.g. `of`, which only follows the name of an impl.
+    None,
+}
+
+/// All the keywords of the language, and where they are offered as completions. These can not be
+/// used as names.
+pub const KEYWORDS: &[(&str, KeywordContext)] = &[
+    ("break", KeywordContext::Body),
+    ("const", KeywordContext::Item),
+    ("continue", KeywordContext::Body),
+    ("else", KeywordContext::Body),
+    ("enum", KeywordContext::Item),
+    ("extern", KeywordContext::Item),
+    ("false", KeywordContext::Body),
+    ("fn", KeywordContext::Item),
+    ("for", KeywordContext::Body),
+    ("if", KeywordContext::Body),
+    ("impl", KeywordContext::Item),
+    ("implicits", KeywordContext::None),
+    ("let", KeywordContext::Body),
+    ("loop", KeywordContext::Body),
+    ("match", KeywordContext::Body),
+    ("mod", KeywordContext::Item),
+    ("mut", KeywordContext::Body),
+    ("nopanic", KeywordContext::None),
+    ("of", KeywordContext::None),
+    ("ref", KeywordContext::Body),
+    ("return", KeywordContext::Body),
+    ("struct", KeywordContext::Item),
+    ("super", KeywordContext::None),
+    ("trait", KeywordContext::Item),
+    ("true", KeywordContext::Body),
+    ("type", KeywordContext::Item),
+    ("use", KeywordContext::Item),
+    ("while", KeywordContext::Body),
+];
+
+/// The kind of completion requested, determined by the syntax around the cursor.
+pub enum CompletionKind {
+    /// Completion of a member or a method of the given expression, e.g. `expr.|`.
+    Dot(ast::Expr),
+    /// Completion of the next segment of a path, e.g. `a::b::|`.
+    ColonColon(Vec<ast::PathSegment>),
+    /// Any other completion, e.g. a plain identifier.
+    Generic,
+}
+
+/// Determines the kind of completion requested, given the syntax node just before the cursor.
+pub fn completion_kind(db: &dyn SemanticGroup, node: SyntaxNode) -> CompletionKind {
+    let syntax_db = db.upcast();
+    match node.kind(syntax_db) {
+        SyntaxKind::TokenDot => {
+            let Some(binary_node) = node.parent().and_then(|terminal| terminal.parent()) else {
+                return CompletionKind::Generic;
+            };
+            if binary_node.kind(syntax_db) != SyntaxKind::ExprBinary {
+                return CompletionKind::Generic;
+            }
+            let binary = ast::ExprBinary::from_syntax_node(syntax_db, binary_node);
+            CompletionKind::Dot(binary.lhs(syntax_db))
+        }
+        SyntaxKind::TokenColonColon => {
+            let Some(path_node) = node.parent().and_then(|terminal| terminal.parent()) else {
+                return CompletionKind::Generic;
+            };
+            if path_node.kind(syntax_db) != SyntaxKind::ExprPath {
+                return CompletionKind::Generic;
+            }
+            CompletionKind::ColonColon(segments_before(db, path_node, node.offset()))
+        }
+        SyntaxKind::TokenIdentifier => {
+            // An identifier that is being typed: token -> terminal -> segment -> path.
+            let Some(segment_node) = node.parent().and_then(|terminal| terminal.parent()) else {
+                return CompletionKind::Generic;
+            };
+            let Some(path_node) = segment_node.parent() else {
+                return CompletionKind::Generic;
+            };
+            if path_node.kind(syntax_db) != SyntaxKind::ExprPath {
+                return CompletionKind::Generic;
+            }
+            let segments = segments_before(db, path_node.clone(), segment_node.offset());
+            if !segments.is_empty() {
+                return CompletionKind::ColonColon(segments);
+            }
+            // Check whether this path is the member part of a member access or a method call, e.g.
+            // `expr.ide|` or `expr.ide|()`.
+            let mut member_node = path_node;
+            let Some(mut binary_node) = member_node.parent() else {
+                return CompletionKind::Generic;
+            };
+            if binary_node.kind(syntax_db) == SyntaxKind::ExprFunctionCall {
+                member_node = binary_node;
+                let Some(parent) = member_node.parent() else {
+                    return CompletionKind::Generic;
+                };
+                binary_node = parent;
+            }
+            if binary_node.kind(syntax_db) != SyntaxKind::ExprBinary {
+                return CompletionKind::Generic;
+            }
+            let binary = ast::ExprBinary::from_syntax_node(syntax_db, binary_node);
+            if matches!(binary.op(syntax_db), ast::BinaryOperator::Dot(_))
+                && binary.rhs(syntax_db).as_syntax_node().offset() == member_node.offset()
+            {
+                CompletionKind::Dot(binary.lhs(syntax_db))
+            } else {
+                CompletionKind::Generic
+            }
+        }
+        _ => CompletionKind::Generic,
+    }
+}
+
+/// Returns the segments of the given path that start before the given offset.
+fn segments_before(
+    db: &dyn SemanticGroup,
+    path_node: SyntaxNode,
+    offset: TextOffset,
+) -> Vec<ast::PathSegment> {
+    let syntax_db = db.upcast();
+    ast::ExprPath::from_syntax_node(syntax_db, path_node)
+        .elements(syntax_db)
+        .into_iter()
+        .take_while(|segment| segment.as_syntax_node().offset() < offset)
+        .collect()
+}
+
+/// Completions for a plain identifier at the given syntax node: keywords, local variables and
+/// parameters, and the items visible from the current module.
+pub fn generic_completions(
+    db: &dyn SemanticGroup,
+    module_file_id: ModuleFileId,
+    lookup_items: Vec<LookupItemId>,
+    node: SyntaxNode,
+) -> Vec<CompletionItem> {
+    let function_id = lookup_items.into_iter().find_map(lookup_item_function_with_body);
+    let context = if function_id.is_some() { KeywordContext::Body } else { KeywordContext::Item };
+    let mut completions: Vec<_> = KEYWORDS
+        .iter()
+        .filter(|(_, keyword_context)| *keyword_context == context)
+        .map(|(keyword, _)| CompletionItem {
+            label: keyword.to_string(),
+            kind: Some(CompletionItemKind::KEYWORD),
+            ..CompletionItem::default()
+        })
+        .collect();
+
+    if let Some(function_id) = function_id {
+        completions.extend(local_variable_completions(db, function_id, node));
+        completions.extend(function_completions(db, function_id));
+    }
+
+    // Items of the current module shadow items of the core crate.
+    let mut names = OrderedHashSet::default();
+    for module_id in [module_file_id.0, core_module(db)] {
+        let Ok(items) = db.module_items(module_id) else { continue; };
+        for item in items.iter() {
+            let name = item.name(db.upcast());
+            if names.insert(name) {
+                completions.push(module_item_completion(db, *item));
+            }
+        }
+    }
+
+    for crate_id in db.crates() {
+        let CrateLongId(name) = db.lookup_intern_crate(crate_id);
+        if names.insert(name.clone()) {
+            completions.push(CompletionItem {
+                label: name.to_string(),
+                kind: Some(CompletionItemKind::MODULE),
+                ..CompletionItem::default()
+            });
+        }
+    }
+    completions
+}
+
+/// Completions for the generic parameters and the parameters of a function.
+fn function_completions(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+) -> Vec<CompletionItem> {
+    let mut completions = vec![];
+    for generic_param in db.function_with_body_generic_params(function_id).unwrap_or_default() {
+        // Anonymous impl params can not be referred to.
+        let Some(name) = generic_param.id().name(db.upcast()) else { continue };
+        completions.push(CompletionItem {
+            label: name.to_string(),
+            kind: Some(CompletionItemKind::TYPE_PARAMETER),
+            ..CompletionItem::default()
+        });
+    }
+    if let Ok(signature) = db.function_with_body_signature(function_id) {
+        for param in signature.params {
+            completions.push(CompletionItem {
+                label: param.name.to_string(),
+                kind: Some(CompletionItemKind::VARIABLE),
+                detail: Some(param.ty.format(db)),
+                ..CompletionItem::default()
+            });
+        }
+    }
+    completions
+}
+
+/// Completions for the variables defined by `let` statements before the given node, in the blocks
+/// containing it. Variables shadowed by later statements or inner blocks are omitted.
+fn local_variable_completions(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+    node: SyntaxNode,
+) -> Vec<CompletionItem> {
+    let syntax_db = db.upcast();
+    let offset = node.offset();
+    let mut names = OrderedHashSet::default();
+    let mut completions = vec![];
+    let mut root = node.clone();
+    while let Some(parent) = root.parent() {
+        root = parent;
+    }
+    let mut block_node = node;
+    while let Some(parent) = block_node.parent() {
+        block_node = parent;
+        if block_node.kind(syntax_db) != SyntaxKind::ExprBlock {
+            continue;
+        }
+        let block = ast::ExprBlock::from_syntax_node(syntax_db, block_node.clone());
+        let Ok(expr_id) =
+            db.lookup_expr_by_ptr(function_id, ast::Expr::Block(block).stable_ptr()) else {
+            continue;
+        };
+        let Expr::Block(block) = db.expr_semantic(function_id, expr_id) else { continue; };
+        for statement_id in block.statements.iter().rev() {
+            let Statement::Let(statement_let) = db.statement_semantic(function_id, *statement_id)
+            else {
+                continue;
+            };
+            let statement_node = root.lookup_ptr(syntax_db, statement_let.stable_ptr.untyped());
+            if statement_node.span_without_trivia(syntax_db).end > offset {
+                continue;
+            }
+            for variable in statement_let.pattern.variables() {
+                if names.insert(variable.name.clone()) {
+                    completions.push(CompletionItem {
+                        label: variable.name.to_string(),
+                        kind: Some(CompletionItemKind::VARIABLE),
+                        detail: Some(variable.var.ty.format(db)),
+                        ..CompletionItem::default()
+                    });
+                }
+            }
+        }
+    }
+    completions
+}
+
+/// Completions for the segment following the given path segments, e.g. `a::b::|`.
+pub fn colon_colon_completions(
+    db: &dyn SemanticGroup,
+    module_file_id: ModuleFileId,
+    segments: Vec<ast::PathSegment>,
+) -> Option<Vec<CompletionItem>> {
+    let mut resolver = Resolver::new_with_inference(db, module_file_id);
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let item = resolver
+        .resolve_concrete_path(&mut diagnostics, segments, NotFoundItemType::Identifier)
+        .ok()?;
+
+    Some(match item {
+        ResolvedConcreteItem::Module(module_id) => db
+            .module_items(module_id)
+            .ok()?
+            .iter()
+            .map(|item| module_item_completion(db, *item))
+            .collect(),
+        ResolvedConcreteItem::Type(ty) => {
+            let TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) =
+                db.lookup_intern_type(ty) else { return None; };
+            let enum_id = concrete_enum_id.enum_id(db);
+            db.enum_variants(enum_id)
+                .ok()?
+                .keys()
+                .map(|name| CompletionItem {
+                    label: name.to_string(),
+                    kind: Some(CompletionItemKind::ENUM_MEMBER),
+                    detail: Some(enum_id.full_path(db.upcast())),
+                    ..CompletionItem::default()
+                })
+                .collect()
+        }
+        ResolvedConcreteItem::Trait(concrete_trait_id) => {
+            let trait_id = concrete_trait_id.trait_id(db);
+            db.trait_functions(trait_id)
+                .ok()?
+                .keys()
+                .map(|name| CompletionItem {
+                    label: name.to_string(),
+                    kind: Some(CompletionItemKind::FUNCTION),
+                    detail: Some(trait_id.full_path(db.upcast())),
+                    ..CompletionItem::default()
+                })
+                .collect()
+        }
+        _ => return None,
+    })
+}
+
+/// Completions for the members and methods of the given expression, e.g. `expr.|`.
+pub fn dot_completions(
+    db: &dyn SemanticGroup,
+    lookup_items: Vec<LookupItemId>,
+    expr: ast::Expr,
+) -> Option<Vec<CompletionItem>> {
+    let function_id = lookup_items.into_iter().find_map(lookup_item_function_with_body)?;
+    let expr_id = db.lookup_expr_by_ptr(function_id, expr.stable_ptr()).ok()?;
+    let ty = db.expr_semantic(function_id, expr_id).ty();
+    if ty.is_missing(db) {
+        return None;
+    }
+
+    let mut completions = vec![];
+    if let TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) =
+        db.lookup_intern_type(peel_snapshots(db, ty))
+    {
+        for (name, member) in db.concrete_struct_members(concrete_struct_id).ok()?.iter() {
+            completions.push(CompletionItem {
+                label: name.to_string(),
+                kind: Some(CompletionItemKind::FIELD),
+                detail: Some(member.ty.format(db)),
+                ..CompletionItem::default()
+            });
+        }
+    }
+
+    // Find the trait functions whose `self` param fits the type of the expression and that have
+    // an impl available, as done when computing a method call.
+    let module_file_id = function_id.module_file_id(db.upcast());
+    let mut resolver = Resolver::new_with_inference(db, module_file_id);
+    for generic_param in db.function_with_body_generic_params(function_id).unwrap_or_default() {
+        resolver.add_generic_param(generic_param);
+    }
+    let stable_ptr = expr.stable_ptr().untyped();
+    for trait_id in all_module_trait_ids(db, module_file_id.0).unwrap_or_default() {
+        let Ok(trait_functions) = db.trait_functions(trait_id) else { continue; };
+        for (name, trait_function) in trait_functions {
+            let mut inference = resolver.inference.clone();
+            let mut lookup_context = resolver.impl_lookup_context();
+            let Some((concrete_trait_id, _)) = inference.infer_concrete_trait_by_self(
+                trait_function, ty, &lookup_context, stable_ptr
+            ) else {
+                continue;
+            };
+            lookup_context.extra_modules.push(trait_id.module_file_id(db.upcast()).0);
+            let Ok(available_impls) = find_possible_impls_at_context(
+                db, &inference, &lookup_context, concrete_trait_id, stable_ptr
+            ) else {
+                continue;
+            };
+            if available_impls.is_empty() {
+                continue;
+            }
+            completions.push(CompletionItem {
+                label: name.to_string(),
+                kind: Some(CompletionItemKind::METHOD),
+                detail: Some(trait_id.full_path(db.upcast())),
+                ..CompletionItem::default()
+            });
+        }
+    }
+    Some(completions)
+}
+
+/// Removes all the snapshot wrappers of a type.
+fn peel_snapshots(db: &dyn SemanticGroup, mut ty: TypeId) -> TypeId {
+    while let TypeLongId::Snapshot(inner_ty) = db.lookup_intern_type(ty) {
+        ty = inner_ty;
+    }
+    ty
+}
+
+/// Returns a completion item for a module item.
+fn module_item_completion(db: &dyn SemanticGroup, item: ModuleItemId) -> CompletionItem {
+    let kind = match item {
+        ModuleItemId::Use(use_id) => match db.use_resolved_item(use_id) {
+            Ok(resolved_item) => resolved_item_completion_kind(&resolved_item),
+            Err(_) => CompletionItemKind::REFERENCE,
+        },
+        ModuleItemId::Constant(_) => CompletionItemKind::CONSTANT,
+        ModuleItemId::Submodule(_) => CompletionItemKind::MODULE,
+        ModuleItemId::FreeFunction(_) | ModuleItemId::ExternFunction(_) => {
+            CompletionItemKind::FUNCTION
+        }
+        ModuleItemId::Struct(_) => CompletionItemKind::STRUCT,
+        ModuleItemId::Enum(_) => CompletionItemKind::ENUM,
+        ModuleItemId::TypeAlias(_) | ModuleItemId::ExternType(_) => CompletionItemKind::CLASS,
+        ModuleItemId::Trait(_) => CompletionItemKind::INTERFACE,
+        ModuleItemId::Impl(_) => CompletionItemKind::CLASS,
+    };
+    CompletionItem {
+        label: item.name(db.upcast()).to_string(),
+        kind: Some(kind),
+        detail: Some(item.full_path(db.upcast())),
+        ..CompletionItem::default()
+    }
+}
+
+/// Returns the completion item kind of an item a `use` resolves to.
+fn resolved_item_completion_kind(item: &ResolvedGenericItem) -> CompletionItemKind {
+    match item {
+        ResolvedGenericItem::Constant(_) => CompletionItemKind::CONSTANT,
+        ResolvedGenericItem::Module(_) => CompletionItemKind::MODULE,
+        ResolvedGenericItem::GenericFunction(_) | ResolvedGenericItem::TraitFunction(_) => {
+            CompletionItemKind::FUNCTION
+        }
+        ResolvedGenericItem::GenericType(GenericTypeId::Struct(_)) => CompletionItemKind::STRUCT,
+        ResolvedGenericItem::GenericType(GenericTypeId::Enum(_)) => CompletionItemKind::ENUM,
+        ResolvedGenericItem::GenericType(GenericTypeId::Extern(_))
+        | ResolvedGenericItem::GenericTypeAlias(_)
+        | ResolvedGenericItem::Impl(_) => CompletionItemKind::CLASS,
+        ResolvedGenericItem::Variant(_) => CompletionItemKind::ENUM_MEMBER,
+        ResolvedGenericItem::Trait(_) => CompletionItemKind::INTERFACE,
+    }
+}
@@ -0,0 +1,101 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{FileIndex, ModuleFileId, ModuleId};
+use cairo_lang_filesystem::span::{TextOffset, TextWidth};
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_semantic::test_utils::{setup_test_crate, SemanticDatabaseForTesting};
+use cairo_lang_syntax::node::TypedSyntaxNode;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use tower_lsp::lsp_types::{CompletionItem, CompletionItemKind};
+
+use super::generic_completions;
+use crate::get_lookup_items;
+
+/// Returns the generic completions at the position of the `$` in `content`.
+fn generic_completions_at(content: &str) -> Vec<CompletionItem> {
+    let (before, after) = content.split_once('$').unwrap();
+    let mut db = SemanticDatabaseForTesting::default();
+    let crate_id = setup_test_crate(&mut db, &format!("{before}{after}"));
+    let module_id = ModuleId::CrateRoot(crate_id);
+    let file = db.module_main_file(module_id).unwrap();
+    let syntax = db.file_syntax(file).unwrap();
+    // As in the server, look at the character just before the cursor.
+    let offset = TextOffset::default().add_width(TextWidth::from_str(&before[..before.len() - 1]));
+    let node = syntax.as_syntax_node().lookup_offset(&db, offset);
+    let lookup_items = get_lookup_items(&db, file, node.clone()).unwrap();
+    generic_completions(&db, ModuleFileId(module_id, FileIndex(0)), lookup_items, node)
+}
+
+/// Returns the labels and details of the completions of the given kind.
+fn completions_of_kind(
+    completions: &[CompletionItem],
+    kind: CompletionItemKind,
+) -> Vec<(&str, Option<&str>)> {
+    completions
+        .iter()
+        .filter(|completion| completion.kind == Some(kind))
+        .map(|completion| (completion.label.as_str(), completion.detail.as_deref()))
+        .collect()
+}
+
+#[test]
+fn test_local_variable_completions() {
+    let completions = generic_completions_at(indoc! {"
+        fn foo(param: felt252) -> felt252 {
+            let a = 1;
+            let (b, c) = (2, true);
+            {
+                let inner = 3;
+            }
+            let a = false;
+            if c {
+                let d = 4;
+                let value = x$;
+            }
+            let after = 5;
+            param
+        }
+    "});
+    assert_eq!(
+        completions_of_kind(&completions, CompletionItemKind::VARIABLE),
+        [
+            ("d", Some("core::felt252")),
+            ("a", Some("core::bool")),
+            ("b", Some("core::felt252")),
+            ("c", Some("core::bool")),
+            ("param", Some("core::felt252")),
+        ]
+    );
+}
+
+#[test]
+fn test_keyword_completions() {
+    let completions = generic_completions_at(indoc! {"
+        fn foo() {
+            l$
+        }
+    "});
+    let keywords = completions_of_kind(&completions, CompletionItemKind::KEYWORD);
+    assert!(keywords.contains(&("let", None)));
+    assert!(!keywords.contains(&("fn", None)));
+    assert!(!keywords.contains(&("of", None)));
+
+    let completions = generic_completions_at("f$");
+    let keywords = completions_of_kind(&completions, CompletionItemKind::KEYWORD);
+    assert!(keywords.contains(&("fn", None)));
+    assert!(!keywords.contains(&("let", None)));
+}
+
+#[test]
+fn test_module_item_completions() {
+    let completions = generic_completions_at(indoc! {"
+        struct MyStruct {}
+        fn foo() {
+            M$
+        }
+    "});
+    assert!(
+        completions_of_kind(&completions, CompletionItemKind::STRUCT)
+            .contains(&("MyStruct", Some("test::MyStruct")))
+    );
+}
@@ -2,6 +2,8 @@
 //!
 //! Implements the LSP protocol over stdin/out.
 
+mod completions;
+mod references;
 mod semantic_highlighting;
 
 use std::collections::{HashMap, HashSet};
@@ -43,10 +45,14 @@ use cairo_lang_syntax::node::helpers::GetIdentifier;
 use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::stable_ptr::SyntaxStablePtr;
 use cairo_lang_syntax::node::utils::is_grandparent_of_kind;
-use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use cairo_lang_syntax::node::{ast, SyntaxNode, Terminal, TypedSyntaxNode};
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::{try_extract_matches, OptionHelper, Upcast};
+use completions::{
+    colon_colon_completions, completion_kind, dot_completions, generic_completions, CompletionKind,
+};
 use log::warn;
+use references::{find_references, symbol_at, validate_rename, Reference, Symbol};
 use salsa::InternKey;
 use semantic_highlighting::token_kind::SemanticTokenKind;
 use semantic_highlighting::SemanticTokensTraverser;
@@ -213,7 +219,7 @@ impl LanguageServer for Backend {
                 )),
                 completion_provider: Some(CompletionOptions {
                     resolve_provider: Some(false),
-                    trigger_characters: Some(vec![".".to_string()]),
+                    trigger_characters: Some(vec![".".to_string(), ":".to_string()]),
                     work_done_progress_options: Default::default(),
                     all_commit_characters: None,
                 }),
@@ -242,6 +248,8 @@ impl LanguageServer for Backend {
                 document_formatting_provider: Some(OneOf::Left(true)),
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
                 definition_provider: Some(OneOf::Left(true)),
+                references_provider: Some(OneOf::Left(true)),
+                rename_provider: Some(OneOf::Left(true)),
                 ..ServerCapabilities::default()
             },
         })
@@ -319,11 +327,35 @@ impl LanguageServer for Backend {
         self.refresh_diagnostics().await;
     }
 
-    async fn completion(&self, _: CompletionParams) -> Result<Option<CompletionResponse>> {
-        Ok(Some(CompletionResponse::Array(vec![
-            CompletionItem::new_simple("Hello".to_string(), "Some detail".to_string()),
-            CompletionItem::new_simple("Bye".to_string(), "More detail".to_string()),
-        ])))
+    async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
+        let db = self.db().await;
+        let text_document_position = params.text_document_position;
+        let file = self.file(&db, text_document_position.text_document.uri);
+        // Look at the character just before the cursor, e.g. the `.` that triggered completion.
+        let mut position = text_document_position.position;
+        position.character = position.character.saturating_sub(1);
+        let Some((node, lookup_items)) =
+            get_node_and_lookup_items(&*db, file, position) else { return Ok(None); };
+        let Some(module_file_id) = find_node_module_file(&*db, file, node.clone()) else {
+            eprintln!("Completion failed. Failed to find module.");
+            return Ok(None);
+        };
+
+        let trigger_kind = params
+            .context
+            .map(|context| context.trigger_kind)
+            .unwrap_or(CompletionTriggerKind::INVOKED);
+        let completions = match completion_kind(&*db, node.clone()) {
+            CompletionKind::Dot(expr) => dot_completions(&*db, lookup_items, expr),
+            CompletionKind::ColonColon(segments) if !segments.is_empty() => {
+                colon_colon_completions(&*db, module_file_id, segments)
+            }
+            _ if trigger_kind == CompletionTriggerKind::INVOKED => {
+                Some(generic_completions(&*db, module_file_id, lookup_items, node))
+            }
+            _ => None,
+        };
+        Ok(completions.map(CompletionResponse::Array))
     }
 
     async fn semantic_tokens_full(
@@ -395,16 +427,8 @@ impl LanguageServer for Backend {
         let Some(lookup_item_id) = lookup_items.into_iter().next() else {
                 return Ok(None);
             };
-        let function_id = match lookup_item_id {
-            LookupItemId::ModuleItem(ModuleItemId::FreeFunction(free_function_id)) => {
-                FunctionWithBodyId::Free(free_function_id)
-            }
-            LookupItemId::ImplFunction(impl_function_id) => {
-                FunctionWithBodyId::Impl(impl_function_id)
-            }
-            _ => {
-                return Ok(None);
-            }
+        let Some(function_id) = lookup_item_function_with_body(lookup_item_id) else {
+            return Ok(None);
         };
 
         // Build texts.
@@ -521,6 +545,66 @@ impl LanguageServer for Backend {
         }
         return Ok(None);
     }
+
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        let db = self.db().await;
+        let Some((_, references)) =
+            self.references_at(&db, params.text_document_position) else { return Ok(None); };
+        let include_declaration = params.context.include_declaration;
+        Ok(Some(
+            references
+                .into_iter()
+                .filter(|reference| include_declaration || !reference.is_definition)
+                .map(|reference| self.reference_location(&db, &reference))
+                .collect(),
+        ))
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let db = self.db().await;
+        let Some((symbol, references)) =
+            self.references_at(&db, params.text_document_position) else { return Ok(None); };
+        if let Err(message) = validate_rename(&*db, symbol, &references, &params.new_name) {
+            return Err(tower_lsp::jsonrpc::Error::invalid_params(message));
+        }
+        let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+        for reference in references {
+            let Location { uri, range } = self.reference_location(&db, &reference);
+            changes
+                .entry(uri)
+                .or_default()
+                .push(TextEdit { range, new_text: params.new_name.clone() });
+        }
+        Ok(Some(WorkspaceEdit::new(changes)))
+    }
+}
+
+impl Backend {
+    /// Finds the symbol referenced by the identifier at the given position, and all the references
+    /// to it.
+    fn references_at(
+        &self,
+        db: &RootDatabase,
+        text_document_position: TextDocumentPositionParams,
+    ) -> Option<(Symbol, Vec<Reference>)> {
+        let syntax_db = db.upcast();
+        let file = self.file(db, text_document_position.text_document.uri);
+        let (node, _) = get_node_and_lookup_items(db, file, text_document_position.position)?;
+        if node.kind(syntax_db) != SyntaxKind::TokenIdentifier {
+            return None;
+        }
+        let identifier = ast::TerminalIdentifier::from_syntax_node(syntax_db, node.parent()?);
+        let symbol = symbol_at(db, file, &identifier)?;
+        Some((symbol, find_references(db, symbol, &identifier.text(syntax_db))))
+    }
+
+    /// Returns the location of the identifier of a reference.
+    fn reference_location(&self, db: &RootDatabase, reference: &Reference) -> Location {
+        let span = reference.identifier.as_syntax_node().span_without_trivia(db.upcast());
+        let start = from_pos(span.start.position_in_file(db.upcast(), reference.file).unwrap());
+        let end = from_pos(span.end.position_in_file(db.upcast(), reference.file).unwrap());
+        Location { uri: self.get_uri(db, reference.file), range: Range { start, end } }
+    }
 }
 
 /// If the ast node is a lookup item, return the corresponding id. Otherwise, return None.
@@ -597,6 +681,19 @@ fn lookup_item_from_ast(
     }
 }
 
+/// If the lookup item is a function with a body, returns its id.
+fn lookup_item_function_with_body(lookup_item_id: LookupItemId) -> Option<FunctionWithBodyId> {
+    match lookup_item_id {
+        LookupItemId::ModuleItem(ModuleItemId::FreeFunction(free_function_id)) => {
+            Some(FunctionWithBodyId::Free(free_function_id))
+        }
+        LookupItemId::ImplFunction(impl_function_id) => {
+            Some(FunctionWithBodyId::Impl(impl_function_id))
+        }
+        _ => None,
+    }
+}
+
 /// Given a position in a file, return the syntax node for the token at that position, and all the
 /// lookup items above this node.
 fn get_node_and_lookup_items(
@@ -604,7 +701,6 @@ fn get_node_and_lookup_items(
     file: FileId,
     position: Position,
 ) -> Option<(SyntaxNode, Vec<LookupItemId>)> {
-    let mut res = Vec::new();
     let syntax_db = db.upcast();
     let filename = file.file_name(db.upcast());
 
@@ -633,16 +729,25 @@ fn get_node_and_lookup_items(
         offset = offset.add_width(TextWidth::from_char(c));
     }
     let node = syntax.as_syntax_node().lookup_offset(syntax_db, offset);
+    let lookup_items = get_lookup_items(db, file, node.clone())?;
+    Some((node, lookup_items))
+}
+
+/// Returns all the lookup items above the given syntax node.
+fn get_lookup_items(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: SyntaxNode,
+) -> Option<Vec<LookupItemId>> {
+    let mut res = Vec::new();
 
     // Find module.
-    let module_id = find_node_module(db, file, node.clone()).on_none(|| {
+    let module_file_id = find_node_module_file(db, file, node.clone()).on_none(|| {
         eprintln!("Hover failed. Failed to find module.");
     })?;
-    let file_index = FileIndex(0);
-    let module_file_id = ModuleFileId(module_id, file_index);
 
     // Find containing function.
-    let mut item_node = node.clone();
+    let mut item_node = node;
     loop {
         if let Some(item) = lookup_item_from_ast(db, module_file_id, item_node.clone()) {
             res.push(item);
@@ -651,11 +756,23 @@ fn get_node_and_lookup_items(
             Some(next_node) => {
                 item_node = next_node;
             }
-            None => return Some((node, res)),
+            None => return Some(res),
         }
     }
 }
 
+/// Returns the module of the given node, and the index of its file in the files of the module.
+/// Files generated by plugins are files of the module of the item they were generated from.
+fn find_node_module_file(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: SyntaxNode,
+) -> Option<ModuleFileId> {
+    let module_id = find_node_module(db, file, node)?;
+    let file_index = db.module_files(module_id).ok()?.iter().position(|f| *f == file)?;
+    Some(ModuleFileId(module_id, FileIndex(file_index)))
+}
+
 fn find_node_module(
     db: &(dyn SemanticGroup + 'static),
     main_file: FileId,
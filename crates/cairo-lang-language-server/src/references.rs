@@ -0,0 +1,536 @@
+use cairo_lang_defs::ids::{
+    ConstantId, GenericTypeId, ImplDefId, LanguageElementId, LocalVarLongId, MemberId, ModuleId,
+    ModuleItemId, ParamLongId, TraitFunctionId, TraitId, TypeAliasId, VarId, VariantId,
+};
+use cairo_lang_filesystem::ids::{FileId, FileLongId};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_semantic::resolve_path::ResolvedGenericItem;
+use cairo_lang_semantic::Expr;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::utils::is_grandparent_of_kind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, Terminal, TypedSyntaxNode};
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use cairo_lang_utils::try_extract_matches;
+use smol_str::SmolStr;
+
+use crate::completions::KEYWORDS;
+use crate::{
+    find_node_module, find_node_module_file, get_lookup_items, lookup_item_function_with_body,
+};
+
+#[cfg(test)]
+#[path = "references_test.rs"]
+mod test;
+
+/// A named element of the code that can be referenced by identifiers, e.g. a function or a local
+/// variable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Symbol {
+    Module(ModuleId),
+    Constant(ConstantId),
+    /// A free or an extern function.
+    Function(GenericFunctionId),
+    /// A function of a trait. Also represents the implementations of this function in impls.
+    TraitFunction(TraitFunctionId),
+    Type(GenericTypeId),
+    TypeAlias(TypeAliasId),
+    Variant(VariantId),
+    Trait(TraitId),
+    Impl(ImplDefId),
+    Member(MemberId),
+    Variable(VarId),
+}
+
+/// An identifier referencing a symbol.
+pub struct Reference {
+    pub file: FileId,
+    pub identifier: ast::TerminalIdentifier,
+    /// Whether this identifier is the name of the symbol in its definition.
+    pub is_definition: bool,
+}
+
+/// Returns the symbol an identifier refers to, either as its definition or as a usage.
+pub fn symbol_at(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    identifier: &ast::TerminalIdentifier,
+) -> Option<Symbol> {
+    definition_symbol(db, file, identifier).or_else(|| usage_symbol(db, file, identifier))
+}
+
+/// Finds all the identifiers referencing the given symbol, in all the files of the workspace.
+/// `name` is the name of the symbol.
+pub fn find_references(
+    db: &(dyn SemanticGroup + 'static),
+    symbol: Symbol,
+    name: &str,
+) -> Vec<Reference> {
+    let mut references = vec![];
+    for (file, node) in search_scopes(db, symbol, name) {
+        for identifier in identifiers_named(db, node, name) {
+            let is_definition = definition_symbol(db, file, &identifier) == Some(symbol);
+            if is_definition || usage_symbol(db, file, &identifier) == Some(symbol) {
+                references.push(Reference { file, identifier, is_definition });
+            }
+        }
+    }
+    references
+}
+
+/// Checks that the symbol referenced by `references` can be renamed to `new_name`.
+/// Returns an error message otherwise.
+pub fn validate_rename(
+    db: &(dyn SemanticGroup + 'static),
+    symbol: Symbol,
+    references: &[Reference],
+    new_name: &str,
+) -> Result<(), String> {
+    let syntax_db = db.upcast();
+    if !is_identifier(new_name) {
+        return Err(format!("`{new_name}` is not a valid identifier."));
+    }
+    let definitions: Vec<_> =
+        references.iter().filter(|reference| reference.is_definition).collect();
+    let Some(definition) = definitions.first() else {
+        return Err("The definition is not in the workspace.".into());
+    };
+    let core_crate = db.core_crate();
+    if definitions.iter().any(|definition| {
+        match db.file_modules(definition.file).ok().and_then(|modules| modules.first().copied()) {
+            Some(module_id) => module_id.owning_crate(db.upcast()) == core_crate,
+            None => true,
+        }
+    }) {
+        return Err("Items of the core library can not be renamed.".into());
+    }
+
+    let definition_node = definition.identifier.as_syntax_node();
+    let item_node = definition_node.parent().unwrap();
+    match symbol {
+        Symbol::Module(_) => {
+            let item_module = ast::ItemModule::from_syntax_node(syntax_db, item_node.clone());
+            if matches!(item_module.body(syntax_db), ast::MaybeModuleBody::None(_)) {
+                return Err("A module defined in its own file can not be renamed.".into());
+            }
+            check_module_item_collision(db, definition.file, item_node, new_name)
+        }
+        Symbol::Constant(_)
+        | Symbol::Function(_)
+        | Symbol::Type(_)
+        | Symbol::TypeAlias(_)
+        | Symbol::Trait(_)
+        | Symbol::Impl(_) => check_module_item_collision(db, definition.file, item_node, new_name),
+        Symbol::TraitFunction(trait_function_id) => {
+            let trait_id = trait_function_id.trait_id(db.upcast());
+            match db.trait_function_by_name(trait_id, new_name.into()) {
+                Ok(None) => Ok(()),
+                _ => Err(format!("The trait already has a function named `{new_name}`.")),
+            }
+        }
+        Symbol::Variant(_) | Symbol::Member(_) => {
+            // Member -> MemberList -> ItemStruct / ItemEnum.
+            let members_node = item_node.parent().unwrap();
+            let names_exist = members_node.children(syntax_db).any(|member| {
+                member.kind(syntax_db) == SyntaxKind::Member
+                    && ast::Member::from_syntax_node(syntax_db, member)
+                        .name(syntax_db)
+                        .text(syntax_db)
+                        == new_name
+            });
+            if names_exist {
+                Err(format!("A member named `{new_name}` already exists."))
+            } else {
+                Ok(())
+            }
+        }
+        Symbol::Variable(_) => {
+            let function_node = ancestor_of_kind(db, definition_node, SyntaxKind::FunctionWithBody)
+                .ok_or_else(|| "Could not find the function of the variable.".to_string())?;
+            let collides =
+                identifiers_named(db, function_node, new_name).iter().any(|identifier| {
+                    matches!(symbol_at(db, definition.file, identifier), Some(Symbol::Variable(_)))
+                });
+            if collides {
+                Err(format!("A variable named `{new_name}` already exists in this function."))
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Checks that the module of the given item does not already contain an item named `new_name`.
+fn check_module_item_collision(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    item_node: SyntaxNode,
+    new_name: &str,
+) -> Result<(), String> {
+    let module_id = find_node_module(db, file, item_node)
+        .ok_or_else(|| "Could not find the module of the item.".to_string())?;
+    match db.module_item_by_name(module_id, new_name.into()) {
+        Ok(None) => Ok(()),
+        _ => Err(format!(
+            "An item named `{new_name}` already exists in module `{}`.",
+            module_id.full_path(db.upcast())
+        )),
+    }
+}
+
+/// Returns whether the given text is a valid identifier, that is not a keyword.
+fn is_identifier(text: &str) -> bool {
+    let mut chars = text.chars();
+    let Some(first) = chars.next() else { return false; };
+    (first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !KEYWORDS.iter().any(|(keyword, _)| *keyword == text)
+}
+
+/// Returns the syntax nodes that may contain references to the given symbol, and their files.
+/// A variable may only be referenced in the function defining it. Other symbols may be referenced
+/// in any file of the workspace which contains their name.
+fn search_scopes(
+    db: &(dyn SemanticGroup + 'static),
+    symbol: Symbol,
+    name: &str,
+) -> Vec<(FileId, SyntaxNode)> {
+    if let Symbol::Variable(var_id) = symbol {
+        return variable_scope(db, var_id).into_iter().collect();
+    }
+    workspace_files(db)
+        .into_iter()
+        .filter_map(|file| {
+            if !db.file_content(file)?.contains(name) {
+                return None;
+            }
+            Some((file, db.file_syntax(file).ok()?.as_syntax_node()))
+        })
+        .collect()
+}
+
+/// Returns the function defining the given variable, and its file. If the variable is not defined
+/// in a function with a body, e.g. a parameter of a trait function, returns the whole file.
+fn variable_scope(
+    db: &(dyn SemanticGroup + 'static),
+    var_id: VarId,
+) -> Option<(FileId, SyntaxNode)> {
+    let file = db.module_file(var_id.module_file_id(db.upcast())).ok()?;
+    let root = db.file_syntax(file).ok()?.as_syntax_node();
+    let definition_node = root.lookup_ptr(db.upcast(), var_id.untyped_stable_ptr(db.upcast()));
+    let scope = ancestor_of_kind(db, definition_node, SyntaxKind::FunctionWithBody).unwrap_or(root);
+    Some((file, scope))
+}
+
+/// Returns the files of all the crates in the workspace that are on disk.
+fn workspace_files(db: &(dyn SemanticGroup + 'static)) -> OrderedHashSet<FileId> {
+    let mut files = OrderedHashSet::default();
+    for crate_id in db.crates() {
+        for module_id in db.crate_modules(crate_id).iter() {
+            for file in db.module_files(*module_id).unwrap_or_default() {
+                if matches!(db.lookup_intern_file(file), FileLongId::OnDisk(_)) {
+                    files.insert(file);
+                }
+            }
+        }
+    }
+    files
+}
+
+/// Returns all the identifiers with the given text under the given node.
+fn identifiers_named(
+    db: &(dyn SemanticGroup + 'static),
+    node: SyntaxNode,
+    name: &str,
+) -> Vec<ast::TerminalIdentifier> {
+    let syntax_db = db.upcast();
+    let mut identifiers = vec![];
+    let mut stack = vec![node];
+    while let Some(node) = stack.pop() {
+        if node.kind(syntax_db) == SyntaxKind::TerminalIdentifier {
+            let identifier = ast::TerminalIdentifier::from_syntax_node(syntax_db, node);
+            if identifier.text(syntax_db) == name {
+                identifiers.push(identifier);
+            }
+            continue;
+        }
+        stack.extend(node.children(syntax_db));
+    }
+    identifiers
+}
+
+/// Returns the closest ancestor of the node with the given kind.
+fn ancestor_of_kind(
+    db: &(dyn SemanticGroup + 'static),
+    mut node: SyntaxNode,
+    kind: SyntaxKind,
+) -> Option<SyntaxNode> {
+    let syntax_db = db.upcast();
+    while let Some(parent) = node.parent() {
+        if parent.kind(syntax_db) == kind {
+            return Some(parent);
+        }
+        node = parent;
+    }
+    None
+}
+
+/// If the identifier is the name of a definition, returns the defined symbol.
+fn definition_symbol(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    identifier: &ast::TerminalIdentifier,
+) -> Option<Symbol> {
+    let syntax_db = db.upcast();
+    let name = identifier.text(syntax_db);
+    let parent = identifier.as_syntax_node().parent()?;
+    match parent.kind(syntax_db) {
+        SyntaxKind::ItemModule
+        | SyntaxKind::ItemConstant
+        | SyntaxKind::ItemStruct
+        | SyntaxKind::ItemEnum
+        | SyntaxKind::ItemTypeAlias
+        | SyntaxKind::ItemTrait
+        | SyntaxKind::ItemImpl
+        | SyntaxKind::ItemExternType => module_item_symbol(
+            db.module_item_by_name(find_node_module(db, file, parent)?, name).ok()??,
+        ),
+        SyntaxKind::FunctionDeclaration => {
+            let function_node = parent.parent()?;
+            match function_node.kind(syntax_db) {
+                SyntaxKind::TraitItemFunction => {
+                    let trait_id = try_extract_matches!(
+                        containing_module_item(db, file, function_node, SyntaxKind::ItemTrait)?,
+                        ModuleItemId::Trait
+                    )?;
+                    Some(Symbol::TraitFunction(db.trait_function_by_name(trait_id, name).ok()??))
+                }
+                SyntaxKind::FunctionWithBody
+                    if is_grandparent_of_kind(syntax_db, &function_node, SyntaxKind::ImplBody) =>
+                {
+                    // Functions of impls are considered as definitions of the trait functions.
+                    let impl_def_id = try_extract_matches!(
+                        containing_module_item(db, file, function_node, SyntaxKind::ItemImpl)?,
+                        ModuleItemId::Impl
+                    )?;
+                    let trait_id = db.impl_def_concrete_trait(impl_def_id).ok()?.trait_id(db);
+                    Some(Symbol::TraitFunction(db.trait_function_by_name(trait_id, name).ok()??))
+                }
+                _ => module_item_symbol(
+                    db.module_item_by_name(find_node_module(db, file, function_node)?, name)
+                        .ok()??,
+                ),
+            }
+        }
+        SyntaxKind::Member => {
+            // Member -> MemberList -> ItemStruct / ItemEnum.
+            let item_node = parent.parent()?.parent()?;
+            let item_kind = item_node.kind(syntax_db);
+            match containing_module_item(db, file, parent, item_kind)? {
+                ModuleItemId::Struct(struct_id) => {
+                    Some(Symbol::Member(db.struct_members(struct_id).ok()?.get(&name)?.id))
+                }
+                ModuleItemId::Enum(enum_id) => {
+                    Some(Symbol::Variant(*db.enum_variants(enum_id).ok()?.get(&name)?))
+                }
+                _ => None,
+            }
+        }
+        SyntaxKind::Param => {
+            let module_file_id = find_node_module_file(db, file, parent.clone())?;
+            let param = ast::Param::from_syntax_node(syntax_db, parent);
+            Some(Symbol::Variable(VarId::Param(
+                db.intern_param(ParamLongId(module_file_id, param.stable_ptr())),
+            )))
+        }
+        SyntaxKind::PathSegmentSimple => {
+            // A variable pattern without modifiers, e.g. `let a = ...`, is a path with a single
+            // segment.
+            let path_node = parent.parent()?;
+            if !is_variable_pattern(db, path_node.clone()) {
+                return None;
+            }
+            let module_file_id = find_node_module_file(db, file, path_node)?;
+            Some(Symbol::Variable(VarId::Local(
+                db.intern_local_var(LocalVarLongId(module_file_id, identifier.stable_ptr())),
+            )))
+        }
+        SyntaxKind::PatternIdentifier => {
+            let module_file_id = find_node_module_file(db, file, parent)?;
+            Some(Symbol::Variable(VarId::Local(
+                db.intern_local_var(LocalVarLongId(module_file_id, identifier.stable_ptr())),
+            )))
+        }
+        _ => None,
+    }
+}
+
+/// Returns whether the given path is a pattern binding a variable, e.g. the `a` in `let a = 1;`.
+fn is_variable_pattern(db: &(dyn SemanticGroup + 'static), path_node: SyntaxNode) -> bool {
+    let syntax_db = db.upcast();
+    let Some(parent) = path_node.parent() else { return false; };
+    let pattern = match parent.kind(syntax_db) {
+        SyntaxKind::StatementLet => {
+            ast::StatementLet::from_syntax_node(syntax_db, parent).pattern(syntax_db)
+        }
+        SyntaxKind::MatchArm => {
+            ast::MatchArm::from_syntax_node(syntax_db, parent).pattern(syntax_db)
+        }
+        SyntaxKind::PatternEnum => {
+            ast::PatternEnum::from_syntax_node(syntax_db, parent).pattern(syntax_db)
+        }
+        SyntaxKind::PatternStructParamWithExpr => {
+            ast::PatternStructParamWithExpr::from_syntax_node(syntax_db, parent).pattern(syntax_db)
+        }
+        SyntaxKind::PatternList => return true,
+        _ => return false,
+    };
+    pattern.as_syntax_node().stable_ptr() == path_node.stable_ptr()
+        && ast::ExprPath::from_syntax_node(syntax_db, path_node).elements(syntax_db).len() == 1
+}
+
+/// Returns the symbol an identifier which is not a definition refers to.
+fn usage_symbol(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    identifier: &ast::TerminalIdentifier,
+) -> Option<Symbol> {
+    let lookup_items = get_lookup_items(db, file, identifier.as_syntax_node())?;
+    for lookup_item_id in &lookup_items {
+        if let Some(item) =
+            db.lookup_resolved_generic_item_by_ptr(*lookup_item_id, identifier.stable_ptr())
+        {
+            return resolved_item_symbol(item);
+        }
+    }
+    let function_id = lookup_items.into_iter().find_map(lookup_item_function_with_body)?;
+
+    // The identifier is not a resolved path. Look for the expression it is part of.
+    let syntax_db = db.upcast();
+    let parent = identifier.as_syntax_node().parent()?;
+    let lookup_expr = |node: SyntaxNode| {
+        let expr_id = db
+            .lookup_expr_by_ptr(
+                function_id,
+                ast::Expr::from_syntax_node(syntax_db, node).stable_ptr(),
+            )
+            .ok()?;
+        Some(db.expr_semantic(function_id, expr_id))
+    };
+    match parent.kind(syntax_db) {
+        SyntaxKind::PathSegmentSimple => {
+            let path_node = parent.parent()?;
+            if let Some(Expr::Var(expr_var)) = lookup_expr(path_node.clone()) {
+                return Some(Symbol::Variable(expr_var.var));
+            }
+            // A member access or a method call: `expr.member` or `expr.method()`.
+            let mut member_node = path_node;
+            let mut binary_node = member_node.parent()?;
+            if binary_node.kind(syntax_db) == SyntaxKind::ExprFunctionCall {
+                member_node = binary_node;
+                binary_node = member_node.parent()?;
+            }
+            if binary_node.kind(syntax_db) != SyntaxKind::ExprBinary {
+                return None;
+            }
+            let binary = ast::ExprBinary::from_syntax_node(syntax_db, binary_node.clone());
+            if !matches!(binary.op(syntax_db), ast::BinaryOperator::Dot(_))
+                || binary.rhs(syntax_db).as_syntax_node().offset() != member_node.offset()
+            {
+                return None;
+            }
+            // Method calls are looked up by the call expression.
+            match lookup_expr(binary_node).or_else(|| lookup_expr(member_node))? {
+                Expr::MemberAccess(member_access) => Some(Symbol::Member(member_access.member)),
+                Expr::FunctionCall(function_call) => {
+                    let function = db.lookup_intern_function(function_call.function).function;
+                    Some(generic_function_symbol(function.generic_function))
+                }
+                _ => None,
+            }
+        }
+        SyntaxKind::StructArgSingle => {
+            let ctor_node = ancestor_of_kind(db, parent, SyntaxKind::ExprStructCtorCall)?;
+            let Expr::StructCtor(struct_ctor) = lookup_expr(ctor_node)? else { return None; };
+            let name = identifier.text(syntax_db);
+            struct_ctor.members.into_iter().find_map(|(member_id, _)| {
+                (member_id.name(db.upcast()) == name).then_some(Symbol::Member(member_id))
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Returns the module item defined by the closest ancestor of the node with the given kind.
+fn containing_module_item(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: SyntaxNode,
+    kind: SyntaxKind,
+) -> Option<ModuleItemId> {
+    let syntax_db = db.upcast();
+    let item_node = ancestor_of_kind(db, node, kind)?;
+    let name: SmolStr = match kind {
+        SyntaxKind::ItemStruct => {
+            ast::ItemStruct::from_syntax_node(syntax_db, item_node.clone()).name(syntax_db)
+        }
+        SyntaxKind::ItemEnum => {
+            ast::ItemEnum::from_syntax_node(syntax_db, item_node.clone()).name(syntax_db)
+        }
+        SyntaxKind::ItemTrait => {
+            ast::ItemTrait::from_syntax_node(syntax_db, item_node.clone()).name(syntax_db)
+        }
+        SyntaxKind::ItemImpl => {
+            ast::ItemImpl::from_syntax_node(syntax_db, item_node.clone()).name(syntax_db)
+        }
+        _ => return None,
+    }
+    .text(syntax_db);
+    db.module_item_by_name(find_node_module(db, file, item_node)?, name).ok()?
+}
+
+/// Returns the symbol of a module item. Uses are not symbols by themselves.
+fn module_item_symbol(item: ModuleItemId) -> Option<Symbol> {
+    Some(match item {
+        ModuleItemId::Constant(id) => Symbol::Constant(id),
+        ModuleItemId::Submodule(id) => Symbol::Module(ModuleId::Submodule(id)),
+        ModuleItemId::Use(_) => return None,
+        ModuleItemId::FreeFunction(id) => Symbol::Function(GenericFunctionId::Free(id)),
+        ModuleItemId::ExternFunction(id) => Symbol::Function(GenericFunctionId::Extern(id)),
+        ModuleItemId::Struct(id) => Symbol::Type(GenericTypeId::Struct(id)),
+        ModuleItemId::Enum(id) => Symbol::Type(GenericTypeId::Enum(id)),
+        ModuleItemId::ExternType(id) => Symbol::Type(GenericTypeId::Extern(id)),
+        ModuleItemId::TypeAlias(id) => Symbol::TypeAlias(id),
+        ModuleItemId::Trait(id) => Symbol::Trait(id),
+        ModuleItemId::Impl(id) => Symbol::Impl(id),
+    })
+}
+
+/// Returns the symbol of a resolved path.
+fn resolved_item_symbol(item: ResolvedGenericItem) -> Option<Symbol> {
+    Some(match item {
+        ResolvedGenericItem::Constant(id) => Symbol::Constant(id),
+        ResolvedGenericItem::Module(id) => Symbol::Module(id),
+        ResolvedGenericItem::GenericFunction(id) => generic_function_symbol(id),
+        ResolvedGenericItem::TraitFunction(id) => Symbol::TraitFunction(id),
+        ResolvedGenericItem::GenericType(id) => Symbol::Type(id),
+        ResolvedGenericItem::GenericTypeAlias(id) => Symbol::TypeAlias(id),
+        ResolvedGenericItem::Variant(variant) => Symbol::Variant(variant.id),
+        ResolvedGenericItem::Trait(id) => Symbol::Trait(id),
+        ResolvedGenericItem::Impl(id) => Symbol::Impl(id),
+    })
+}
+
+/// Returns the symbol of a generic function. Functions of impls are represented by the trait
+/// function they implement.
+fn generic_function_symbol(generic_function: GenericFunctionId) -> Symbol {
+    match generic_function {
+        GenericFunctionId::Impl(impl_generic_function) => {
+            Symbol::TraitFunction(impl_generic_function.function)
+        }
+        GenericFunctionId::Free(_) | GenericFunctionId::Extern(_) => {
+            Symbol::Function(generic_function)
+        }
+    }
+}
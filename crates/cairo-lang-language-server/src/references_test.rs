@@ -0,0 +1,148 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{FileIndex, LanguageElementId, ModuleFileId, ModuleId, VarId};
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextOffset, TextWidth};
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_plugins::get_default_plugins;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::test_utils::{setup_test_crate, SemanticDatabaseForTesting};
+use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use super::{find_references, identifiers_named, symbol_at, validate_rename, Reference, Symbol};
+
+const CONTENT: &str = indoc! {"
+    mod inner {
+        fn foo(a: felt252) -> felt252 {
+            let b = a + 1;
+            b
+        }
+        fn bar(a: felt252) -> felt252 {
+            foo(a)
+        }
+    }
+    fn main() -> felt252 {
+        inner::foo(2)
+    }
+"};
+
+/// Sets up a crate with the given content, and returns the file of its root module.
+fn setup(db: &mut SemanticDatabaseForTesting, content: &str) -> FileId {
+    let crate_id = setup_test_crate(db, content);
+    db.module_main_file(ModuleId::CrateRoot(crate_id)).unwrap()
+}
+
+/// Returns the identifier at the given line and column (both 0-based) of the file.
+fn identifier_at(
+    db: &SemanticDatabaseForTesting,
+    file: FileId,
+    line: usize,
+    col: usize,
+) -> ast::TerminalIdentifier {
+    let content = db.file_content(file).unwrap();
+    let prefix_len = content.split_inclusive('\n').take(line).map(str::len).sum::<usize>() + col;
+    let offset = TextOffset::default().add_width(TextWidth::from_str(&content[..prefix_len]));
+    let token = db.file_syntax(file).unwrap().as_syntax_node().lookup_offset(db, offset);
+    ast::TerminalIdentifier::from_syntax_node(db, token.parent().unwrap())
+}
+
+/// Returns the sorted positions of the references, and whether each is a definition.
+fn reference_positions(
+    db: &SemanticDatabaseForTesting,
+    references: &[Reference],
+) -> Vec<(usize, usize, bool)> {
+    let mut positions: Vec<_> = references
+        .iter()
+        .map(|reference| {
+            let node = reference.identifier.as_syntax_node();
+            let position =
+                node.span_without_trivia(db).start.position_in_file(db, reference.file).unwrap();
+            (position.line, position.col, reference.is_definition)
+        })
+        .collect();
+    positions.sort();
+    positions
+}
+
+#[test]
+fn test_function_references() {
+    let mut db = SemanticDatabaseForTesting::default();
+    let file = setup(&mut db, CONTENT);
+    let symbol = symbol_at(&db, file, &identifier_at(&db, file, 10, 11)).unwrap();
+    assert!(matches!(symbol, Symbol::Function(_)));
+    assert_eq!(
+        reference_positions(&db, &find_references(&db, symbol, "foo")),
+        [(1, 7, true), (6, 8, false), (10, 11, false)]
+    );
+}
+
+#[test]
+fn test_variable_references() {
+    let mut db = SemanticDatabaseForTesting::default();
+    let file = setup(&mut db, CONTENT);
+    // A parameter, which is not referenced by the parameter of the same name in `bar`.
+    let symbol = symbol_at(&db, file, &identifier_at(&db, file, 1, 11)).unwrap();
+    assert!(matches!(symbol, Symbol::Variable(VarId::Param(_))));
+    assert_eq!(
+        reference_positions(&db, &find_references(&db, symbol, "a")),
+        [(1, 11, true), (2, 16, false)]
+    );
+    // A local variable.
+    let symbol = symbol_at(&db, file, &identifier_at(&db, file, 3, 8)).unwrap();
+    assert!(matches!(symbol, Symbol::Variable(VarId::Local(_))));
+    assert_eq!(
+        reference_positions(&db, &find_references(&db, symbol, "b")),
+        [(2, 12, true), (3, 8, false)]
+    );
+}
+
+#[test]
+fn test_validate_rename() {
+    let mut db = SemanticDatabaseForTesting::default();
+    let file = setup(&mut db, CONTENT);
+    let rename = |line, col, name: &str, new_name: &str| {
+        let symbol = symbol_at(&db, file, &identifier_at(&db, file, line, col)).unwrap();
+        validate_rename(&db, symbol, &find_references(&db, symbol, name), new_name)
+    };
+    assert_eq!(rename(1, 7, "foo", "baz"), Ok(()));
+    assert_eq!(
+        rename(1, 7, "foo", "bar"),
+        Err("An item named `bar` already exists in module `test::inner`.".into())
+    );
+    assert_eq!(rename(1, 7, "foo", "fn"), Err("`fn` is not a valid identifier.".into()));
+    assert_eq!(rename(1, 7, "foo", "1foo"), Err("`1foo` is not a valid identifier.".into()));
+    assert_eq!(
+        rename(2, 12, "b", "a"),
+        Err("A variable named `a` already exists in this function.".into())
+    );
+}
+
+#[test]
+fn test_variable_in_generated_file() {
+    let mut db = SemanticDatabaseForTesting::default();
+    db.set_semantic_plugins(get_default_plugins());
+    let crate_id = setup_test_crate(
+        &mut db,
+        indoc! {"
+            #[derive(Clone, Drop)]
+            enum A {
+                V: felt252,
+            }
+        "},
+    );
+    let module_id = ModuleId::CrateRoot(crate_id);
+    // The code generated by the derive plugin is in the second file of the module.
+    let generated_file = db.module_files(module_id).unwrap()[1];
+    let root = db.file_syntax(generated_file).unwrap().as_syntax_node();
+    let symbols: Vec<_> = identifiers_named(&db, root, "x")
+        .iter()
+        .map(|identifier| symbol_at(&db, generated_file, identifier))
+        .collect();
+    // The definition of the variable in the pattern, and its usage.
+    assert_eq!(symbols.len(), 2);
+    assert_eq!(symbols[0], symbols[1]);
+    let Some(Symbol::Variable(var_id)) = symbols[0] else { panic!("Expected a variable.") };
+    assert_eq!(var_id.module_file_id(&db), ModuleFileId(module_id, FileIndex(1)));
+}
@@ -0,0 +1,150 @@
+use cairo_lang_diagnostics::Maybe;
+use cairo_lang_filesystem::flag::Flag;
+use cairo_lang_filesystem::ids::FlagId;
+use cairo_lang_semantic::corelib::{
+    core_array_felt_ty, core_felt252_ty, core_submodule, get_core_ty_by_name, get_function_id,
+    option_none_variant, option_some_variant, unit_ty,
+};
+use cairo_lang_semantic::GenericArgumentId;
+use num_bigint::{BigInt, Sign};
+
+use crate::db::LoweringGroup;
+use crate::ids::{
+    ConcreteFunctionWithBodyId, ConcreteFunctionWithBodyLongId, GeneratedFunction,
+    GeneratedFunctionKey, SemanticFunctionIdEx,
+};
+use crate::lower::context::{LoweringContextBuilder, VarRequest};
+use crate::{
+    BlockId, FlatBlock, FlatBlockEnd, FlatLowered, MatchArm, MatchExternInfo, MatchInfo, Statement,
+    StatementCall, StatementLiteral,
+};
+
+/// Lowering phase that adds a `withdraw_gas_all` call at the beginning of functions generated for
+/// loops, so that each iteration of a loop pays for its gas, including the gas of the builtins it
+/// uses. The function panics with 'Out of gas' if the withdrawal fails.
+///
+/// Can be disabled by setting the `add_withdraw_gas` flag to false.
+pub fn add_withdraw_gas(
+    db: &dyn LoweringGroup,
+    function: ConcreteFunctionWithBodyId,
+    lowered: &mut FlatLowered,
+) -> Maybe<()> {
+    let ConcreteFunctionWithBodyLongId::Generated(GeneratedFunction {
+        key: GeneratedFunctionKey::Loop(loop_expr_id),
+        ..
+    }) = function.lookup(db)
+    else {
+        return Ok(());
+    };
+    if !flag_add_withdraw_gas(db) {
+        return Ok(());
+    }
+    lowered.blocks.has_root()?;
+
+    let lowering_info = LoweringContextBuilder::new_concrete(db, function)?;
+    let mut ctx = lowering_info.ctx()?;
+    ctx.variables = std::mem::take(&mut lowered.variables);
+    let location = ctx.get_location(ctx.function_body.exprs[loop_expr_id].stable_ptr().untyped());
+
+    let semantic_db = db.upcast();
+    let felt252_ty = core_felt252_ty(semantic_db);
+    let array_ty = core_array_felt_ty(semantic_db);
+    let felt252_arg = GenericArgumentId::Type(felt252_ty);
+    let array_module = core_submodule(semantic_db, "array");
+
+    // The block that panics with 'Out of gas'.
+    let new_array_var = ctx.new_var(VarRequest { ty: array_ty, location });
+    let out_of_gas_err_var = ctx.new_var(VarRequest { ty: felt252_ty, location });
+    let panic_data_var = ctx.new_var(VarRequest { ty: array_ty, location });
+    let panic_block = FlatBlock {
+        statements: vec![
+            Statement::Call(StatementCall {
+                function: get_function_id(
+                    semantic_db,
+                    array_module,
+                    "array_new".into(),
+                    vec![felt252_arg],
+                )
+                .lowered(db),
+                inputs: vec![],
+                outputs: vec![new_array_var],
+                location,
+            }),
+            Statement::Literal(StatementLiteral {
+                value: BigInt::from_bytes_be(Sign::Plus, "Out of gas".as_bytes()),
+                output: out_of_gas_err_var,
+            }),
+            Statement::Call(StatementCall {
+                function: get_function_id(
+                    semantic_db,
+                    array_module,
+                    "array_append".into(),
+                    vec![felt252_arg],
+                )
+                .lowered(db),
+                inputs: vec![new_array_var, out_of_gas_err_var],
+                outputs: vec![panic_data_var],
+                location,
+            }),
+        ],
+        end: FlatBlockEnd::Panic(panic_data_var),
+    };
+
+    // The original root block is moved, and the new root block starts with the gas withdrawal.
+    let old_root_block = std::mem::take(&mut lowered.blocks[BlockId::root()]);
+    let old_root_block_id = lowered.blocks.push(old_root_block);
+    let panic_block_id = lowered.blocks.push(panic_block);
+    let unit_arg = GenericArgumentId::Type(unit_ty(semantic_db));
+    let gas_module = core_submodule(semantic_db, "gas");
+    let builtin_costs_var = ctx.new_var(VarRequest {
+        ty: get_core_ty_by_name(semantic_db, "BuiltinCosts".into(), vec![]),
+        location,
+    });
+    lowered.blocks[BlockId::root()] = FlatBlock {
+        statements: vec![Statement::Call(StatementCall {
+            function: get_function_id(semantic_db, gas_module, "get_builtin_costs".into(), vec![])
+                .lowered(db),
+            inputs: vec![],
+            outputs: vec![builtin_costs_var],
+            location,
+        })],
+        end: FlatBlockEnd::Match {
+            info: MatchInfo::Extern(MatchExternInfo {
+                function: get_function_id(
+                    semantic_db,
+                    gas_module,
+                    "withdraw_gas_all".into(),
+                    vec![],
+                ),
+                inputs: vec![builtin_costs_var],
+                arms: vec![
+                    MatchArm {
+                        variant_id: option_some_variant(semantic_db, unit_arg),
+                        block_id: old_root_block_id,
+                        var_ids: vec![],
+                    },
+                    MatchArm {
+                        variant_id: option_none_variant(semantic_db, unit_arg),
+                        block_id: panic_block_id,
+                        var_ids: vec![],
+                    },
+                ],
+                location,
+            }),
+        },
+    };
+
+    lowered.variables = ctx.variables;
+    Ok(())
+}
+
+/// Returns the value of the `add_withdraw_gas` flag, defaulting to true if it is not set.
+fn flag_add_withdraw_gas(db: &dyn LoweringGroup) -> bool {
+    let flag = FlagId::new(db.upcast(), "add_withdraw_gas");
+    match db.get_flag(flag) {
+        Some(flag) => match *flag {
+            Flag::AddWithdrawGas(value) => value,
+        },
+        None => true,
+    }
+}
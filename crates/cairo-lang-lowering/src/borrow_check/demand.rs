@@ -16,10 +16,13 @@ pub trait DemandReporter<Var> {
 #[derive(Clone)]
 pub struct Demand<Var: std::hash::Hash + Eq + Copy> {
     pub vars: OrderedHashSet<Var>,
+    /// Whether the flow from this point is unreachable (e.g. it ends with a match on an empty
+    /// enum). Variables do not need to be dropped in unreachable flows.
+    pub unreachable: bool,
 }
 impl<Var: std::hash::Hash + Eq + Copy> Default for Demand<Var> {
     fn default() -> Self {
-        Self { vars: Default::default() }
+        Self { vars: Default::default(), unreachable: false }
     }
 }
 impl<Var: std::hash::Hash + Eq + Copy> Demand<Var> {
@@ -71,7 +74,7 @@ impl<Var: std::hash::Hash + Eq + Copy> Demand<Var> {
         position: T::IntroducePosition,
     ) {
         for var in vars {
-            if !self.vars.swap_remove(&(*var).into()) {
+            if !self.vars.swap_remove(&(*var).into()) && !self.unreachable {
                 // Variable introduced, but not demanded. If it's not drop, that is an issue.
                 reporter.drop(position, (*var).into());
             }
@@ -79,19 +82,24 @@ impl<Var: std::hash::Hash + Eq + Copy> Demand<Var> {
     }
 
     /// Merges [Demand]s from multiple branches into one, reporting diagnostics in the way.
+    /// The merged flow is unreachable if all the branches are unreachable (in particular, if there
+    /// are no branches).
     pub fn merge_demands<T: DemandReporter<Var>>(
         demands: &[(Self, T::IntroducePosition)],
         reporter: &mut T,
     ) -> Self {
         // Union demands.
-        let mut demand = Self::default();
+        let mut demand = Self {
+            unreachable: demands.iter().all(|(arm_demand, _)| arm_demand.unreachable),
+            ..Self::default()
+        };
         for (arm_demand, _) in demands {
             demand.vars.extend(arm_demand.vars.iter().copied());
         }
         // Check each var.
         for var in demand.vars.iter() {
             for (arm_demand, position) in demands {
-                if !arm_demand.vars.contains(var) {
+                if !arm_demand.vars.contains(var) && !arm_demand.unreachable {
                     // Variable demanded only on some branches. It should be dropped in other.
                     // If it's not drop, that is an issue.
                     reporter.drop(*position, *var);
@@ -1,18 +1,22 @@
-use cairo_lang_diagnostics::Maybe;
-use cairo_lang_semantic::substitution::{
-    GenericSubstitution, SemanticRewriter, SubstitutionRewriter,
-};
+use cairo_lang_diagnostics::{skip_diagnostic, Maybe};
+use cairo_lang_semantic::items::constant::ConstValue;
+use cairo_lang_semantic::substitution::{SemanticRewriter, SubstitutionRewriter};
 
 use crate::db::LoweringGroup;
-use crate::{FlatBlockEnd, FlatLowered, MatchArm, Statement};
+use crate::ids::{ConcreteFunctionWithBodyId, FunctionLongId, GeneratedFunction};
+use crate::lower::context::{LoweringContextBuilder, LoweringFlowError};
+use crate::lower::generators::StatementsBuilder;
+use crate::lower::lower_const_value;
+use crate::{FlatBlockEnd, FlatLowered, MatchArm, Statement, StatementConst, VariableId};
 
 /// Concretizes a lowered generic function by applying a generic parameter substitution on its
-/// variable types, variants and called functions.
+/// variable types, variants, called functions and constants.
 pub fn concretize_lowered(
     db: &dyn LoweringGroup,
+    function: ConcreteFunctionWithBodyId,
     lowered: &mut FlatLowered,
-    substitution: &GenericSubstitution,
 ) -> Maybe<()> {
+    let substitution = &function.substitution(db)?;
     let mut rewriter = SubstitutionRewriter { db: db.upcast(), substitution };
     // Substitute all types.
     for (_, var) in lowered.variables.iter_mut() {
@@ -26,11 +30,25 @@ pub fn concretize_lowered(
         for stmt in block.statements.iter_mut() {
             match stmt {
                 Statement::Call(stmt) => {
-                    stmt.function = rewriter.rewrite(stmt.function)?;
+                    stmt.function = match stmt.function.lookup(db) {
+                        FunctionLongId::Semantic(id) => {
+                            FunctionLongId::Semantic(rewriter.rewrite(id)?)
+                        }
+                        FunctionLongId::Generated(GeneratedFunction { parent, key }) => {
+                            FunctionLongId::Generated(GeneratedFunction {
+                                parent: rewriter.rewrite(parent)?,
+                                key,
+                            })
+                        }
+                    }
+                    .intern(db);
                 }
                 Statement::EnumConstruct(stmt) => {
                     stmt.variant = rewriter.rewrite(stmt.variant.clone())?;
                 }
+                Statement::Const(stmt) => {
+                    stmt.value = rewriter.rewrite(stmt.value.clone())?;
+                }
                 Statement::Snapshot(_)
                 | Statement::Desnap(_)
                 | Statement::Literal(_)
@@ -54,6 +72,62 @@ pub fn concretize_lowered(
             }
         }
     }
+    lowered.signature = lowered.signature.clone().concretize(db, substitution)?;
+
+    expand_consts(db, function, lowered)
+}
+
+/// Expands the constant statements of a concretized function into the statements constructing
+/// their values.
+fn expand_consts(
+    db: &dyn LoweringGroup,
+    function: ConcreteFunctionWithBodyId,
+    lowered: &mut FlatLowered,
+) -> Maybe<()> {
+    if !lowered
+        .blocks
+        .iter()
+        .any(|(_, block)| block.statements.iter().any(|stmt| matches!(stmt, Statement::Const(_))))
+    {
+        return Ok(());
+    }
+    let lowering_info = LoweringContextBuilder::new_concrete(db, function)?;
+    let mut ctx = lowering_info.ctx()?;
+    ctx.variables = std::mem::take(&mut lowered.variables);
+    for block in lowered.blocks.iter_mut() {
+        let mut builder = StatementsBuilder::default();
+        for stmt in std::mem::take(&mut block.statements) {
+            let Statement::Const(StatementConst { value, output }) = stmt else {
+                builder.push_statement(stmt);
+                continue;
+            };
+            // The impl of the constant should be concrete at this point.
+            if let ConstValue::ImplConstant(_) = value {
+                return Err(skip_diagnostic());
+            }
+            let location = ctx.variables[output].location;
+            lower_const_value(&mut ctx, &mut builder, value, location).map_err(
+                |err| match err {
+                    LoweringFlowError::Failed(diag_added) => diag_added,
+                    _ => unreachable!("Constant lowering only fails with a diagnostic."),
+                },
+            )?;
+            bind_last_output(&mut builder, output);
+        }
+        block.statements = builder.statements;
+    }
+    lowered.variables = ctx.variables;
 
     Ok(())
 }
+
+/// Replaces the output of the last statement in the builder, which constructed the value of a
+/// constant, with the original output variable of the constant.
+fn bind_last_output(builder: &mut StatementsBuilder, output: VariableId) {
+    match builder.statements.last_mut() {
+        Some(Statement::Literal(stmt)) => stmt.output = output,
+        Some(Statement::StructConstruct(stmt)) => stmt.output = output,
+        Some(Statement::EnumConstruct(stmt)) => stmt.output = output,
+        _ => unreachable!("A constant is constructed by a single output statement."),
+    }
+}
@@ -5,38 +5,60 @@ use std::sync::Arc;
 use cairo_lang_defs::ids::{FunctionWithBodyId, LanguageElementId, ModuleId, ModuleItemId};
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe};
 use cairo_lang_filesystem::ids::FileId;
-use cairo_lang_semantic as semantic;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::TypeId;
 use cairo_lang_utils::Upcast;
 use itertools::Itertools;
-use semantic::items::functions::ConcreteFunctionWithBodyId;
-use semantic::ConcreteFunction;
 
+use crate::add_withdraw_gas::add_withdraw_gas;
 use crate::borrow_check::borrow_check;
 use crate::concretize::concretize_lowered;
 use crate::destructs::add_destructs;
 use crate::diagnostic::LoweringDiagnostic;
+use crate::ids::{
+    ConcreteFunctionWithBodyId, FunctionId, FunctionLongId, FunctionWithBodyLongId,
+    SemanticFunctionWithBodyIdEx,
+};
 use crate::implicits::lower_implicits;
 use crate::inline::{apply_inlining, PrivInlineData};
-use crate::lower::lower;
+use crate::lower::{lower_semantic_function, MultiLowering};
 use crate::optimizations::match_optimizer::optimize_matches;
 use crate::optimizations::remappings::optimize_remappings;
 use crate::panic::lower_panics;
 use crate::reorganize_blocks::reorganize_blocks;
-use crate::{FlatBlockEnd, FlatLowered, MatchInfo, Statement};
+use crate::{ids, FlatBlockEnd, FlatLowered, MatchInfo, Statement};
 
 // Salsa database interface.
 #[salsa::query_group(LoweringDatabase)]
 pub trait LoweringGroup: SemanticGroup + Upcast<dyn SemanticGroup> {
+    #[salsa::interned]
+    fn intern_lowering_function(&self, id: FunctionLongId) -> FunctionId;
+    #[salsa::interned]
+    fn intern_lowering_concrete_function_with_body(
+        &self,
+        id: ids::ConcreteFunctionWithBodyLongId,
+    ) -> ids::ConcreteFunctionWithBodyId;
+    #[salsa::interned]
+    fn intern_lowering_function_with_body(
+        &self,
+        id: ids::FunctionWithBodyLongId,
+    ) -> ids::FunctionWithBodyId;
+
     // Reports inlining diagnostics.
     #[salsa::invoke(crate::inline::priv_inline_data)]
     fn priv_inline_data(&self, function_id: FunctionWithBodyId) -> Maybe<Arc<PrivInlineData>>;
 
+    /// Computes the lowered representation of a function with a body, along with all its
+    /// generated functions (e.g. the functions generated for loops).
+    fn priv_function_with_body_multi_lowering(
+        &self,
+        function_id: FunctionWithBodyId,
+    ) -> Maybe<Arc<MultiLowering>>;
+
     /// Computes the lowered representation of a function with a body.
     fn priv_function_with_body_lowered_flat(
         &self,
-        function_id: FunctionWithBodyId,
+        function_id: ids::FunctionWithBodyId,
     ) -> Maybe<Arc<FlatLowered>>;
 
     /// A concrete version of priv_function_with_body_lowered_flat
@@ -61,13 +83,13 @@ pub trait LoweringGroup: SemanticGroup + Upcast<dyn SemanticGroup> {
     fn concrete_function_with_body_postpanic_direct_callees(
         &self,
         function_id: ConcreteFunctionWithBodyId,
-    ) -> Maybe<Vec<ConcreteFunction>>;
+    ) -> Maybe<Vec<FunctionId>>;
 
     /// Returns the set of direct callees of a concrete function with a body.
     fn concrete_function_with_body_direct_callees(
         &self,
         function_id: ConcreteFunctionWithBodyId,
-    ) -> Maybe<Vec<ConcreteFunction>>;
+    ) -> Maybe<Vec<FunctionId>>;
 
     /// Returns the set of direct callees which are functions with body of a concrete function with
     /// a body (i.e. excluding libfunc callees).
@@ -115,7 +137,7 @@ pub trait LoweringGroup: SemanticGroup + Upcast<dyn SemanticGroup> {
     /// order is consistent, but not necessarily related to the order of the explicit implicits in
     /// the signature of the function.
     #[salsa::invoke(crate::implicits::function_all_implicits)]
-    fn function_all_implicits(&self, function: semantic::FunctionId) -> Maybe<Vec<TypeId>>;
+    fn function_all_implicits(&self, function: FunctionId) -> Maybe<Vec<TypeId>>;
 
     /// Returns all the implicit parameters that a concrete function with a body requires (according
     /// to both its signature and the functions it calls).
@@ -143,7 +165,7 @@ pub trait LoweringGroup: SemanticGroup + Upcast<dyn SemanticGroup> {
 
     /// Returns whether the function may panic.
     #[salsa::invoke(crate::panic::function_may_panic)]
-    fn function_may_panic(&self, function: semantic::FunctionId) -> Maybe<bool>;
+    fn function_may_panic(&self, function: FunctionId) -> Maybe<bool>;
 
     /// Returns whether the concrete function may panic.
     #[salsa::invoke(crate::panic::concrete_function_with_body_may_panic)]
@@ -257,26 +279,40 @@ pub struct ConcreteSCCRepresentative(pub ConcreteFunctionWithBodyId);
 
 // *** Main lowering phases in order.
 
+fn priv_function_with_body_multi_lowering(
+    db: &dyn LoweringGroup,
+    function_id: FunctionWithBodyId,
+) -> Maybe<Arc<MultiLowering>> {
+    Ok(Arc::new(lower_semantic_function(db.upcast(), function_id)?))
+}
+
 // * Borrow checking.
 fn priv_function_with_body_lowered_flat(
     db: &dyn LoweringGroup,
-    function_id: FunctionWithBodyId,
+    function_id: ids::FunctionWithBodyId,
 ) -> Maybe<Arc<FlatLowered>> {
-    let mut lowered = lower(db.upcast(), function_id)?;
-    borrow_check(db, function_id.module_file_id(db.upcast()), &mut lowered);
+    let semantic_function_id = function_id.base_semantic_function(db);
+    let multi_lowering = db.priv_function_with_body_multi_lowering(semantic_function_id)?;
+    let mut lowered = match function_id.lookup(db) {
+        FunctionWithBodyLongId::Semantic(_) => multi_lowering.main_lowering.clone(),
+        FunctionWithBodyLongId::Generated { key, .. } => {
+            multi_lowering.generated_lowerings[key].clone()
+        }
+    };
+    borrow_check(db, semantic_function_id.module_file_id(db.upcast()), &mut lowered);
     Ok(Arc::new(lowered))
 }
 
 // * Concretizes lowered representation (monomorphization).
+// * Adds withdraw_gas calls.
 fn priv_concrete_function_with_body_lowered_flat(
     db: &dyn LoweringGroup,
     function: ConcreteFunctionWithBodyId,
 ) -> Maybe<Arc<FlatLowered>> {
-    let semantic_db = db.upcast();
-    let mut lowered = (*db
-        .priv_function_with_body_lowered_flat(function.function_with_body_id(semantic_db))?)
-    .clone();
-    concretize_lowered(db, &mut lowered, &function.substitution(semantic_db)?)?;
+    let mut lowered =
+        (*db.priv_function_with_body_lowered_flat(function.function_with_body_id(db))?).clone();
+    concretize_lowered(db, function, &mut lowered)?;
+    add_withdraw_gas(db, function, &mut lowered)?;
     Ok(Arc::new(lowered))
 }
 
@@ -287,14 +323,17 @@ fn concrete_function_with_body_postpanic_lowered(
     db: &dyn LoweringGroup,
     function: ConcreteFunctionWithBodyId,
 ) -> Maybe<Arc<FlatLowered>> {
-    let semantic_db = db.upcast();
     let mut lowered = (*db.priv_concrete_function_with_body_lowered_flat(function)?).clone();
 
     // TODO(spapini): passing function.function_with_body_id might be weird here.
     // It's not really needed for inlining, so try to remove.
-    apply_inlining(db, function.function_with_body_id(semantic_db), &mut lowered)?;
+    apply_inlining(
+        db,
+        function.base_semantic_function(db).function_with_body_id(db.upcast()),
+        &mut lowered,
+    )?;
     lowered = lower_panics(db, function, &lowered)?;
-    add_destructs(db, &mut lowered);
+    add_destructs(db, function, &mut lowered)?;
     Ok(Arc::new(lowered))
 }
 
@@ -317,43 +356,33 @@ fn concrete_function_with_body_lowered(
 fn concrete_function_with_body_direct_callees(
     db: &dyn LoweringGroup,
     function_id: ConcreteFunctionWithBodyId,
-) -> Maybe<Vec<ConcreteFunction>> {
-    let mut direct_callees = Vec::new();
-    let lowered_function =
-        (*db.priv_concrete_function_with_body_lowered_flat(function_id)?).clone();
-    for (_, block) in &lowered_function.blocks {
-        for statement in &block.statements {
-            if let Statement::Call(statement_call) = statement {
-                let concrete = db.lookup_intern_function(statement_call.function).function;
-                direct_callees.push(concrete);
-            }
-        }
-        if let FlatBlockEnd::Match { info: MatchInfo::Extern(s) } = &block.end {
-            direct_callees.push(s.function.get_concrete(db.upcast()));
-        }
-    }
-    Ok(direct_callees)
+) -> Maybe<Vec<FunctionId>> {
+    let lowered_function = db.priv_concrete_function_with_body_lowered_flat(function_id)?;
+    Ok(get_direct_callees(db, &lowered_function))
 }
 
 fn concrete_function_with_body_postpanic_direct_callees(
     db: &dyn LoweringGroup,
     function_id: ConcreteFunctionWithBodyId,
-) -> Maybe<Vec<ConcreteFunction>> {
+) -> Maybe<Vec<FunctionId>> {
+    let lowered_function = db.concrete_function_with_body_postpanic_lowered(function_id)?;
+    Ok(get_direct_callees(db, &lowered_function))
+}
+
+/// Returns the set of direct callees of a lowered function.
+fn get_direct_callees(db: &dyn LoweringGroup, lowered_function: &FlatLowered) -> Vec<FunctionId> {
     let mut direct_callees = Vec::new();
-    let lowered_function =
-        (*db.concrete_function_with_body_postpanic_lowered(function_id)?).clone();
     for (_, block) in &lowered_function.blocks {
         for statement in &block.statements {
             if let Statement::Call(statement_call) = statement {
-                let concrete = db.lookup_intern_function(statement_call.function).function;
-                direct_callees.push(concrete);
+                direct_callees.push(statement_call.function);
             }
         }
         if let FlatBlockEnd::Match { info: MatchInfo::Extern(s) } = &block.end {
-            direct_callees.push(s.function.get_concrete(db.upcast()));
+            direct_callees.push(db.intern_lowering_function(FunctionLongId::Semantic(s.function)));
         }
     }
-    Ok(direct_callees)
+    direct_callees
 }
 
 fn concrete_function_with_body_direct_callees_with_body(
@@ -363,7 +392,7 @@ fn concrete_function_with_body_direct_callees_with_body(
     Ok(db
         .concrete_function_with_body_direct_callees(function_id)?
         .into_iter()
-        .map(|concrete| concrete.get_body(db.upcast()))
+        .map(|function_id| function_id.body(db))
         .collect::<Maybe<Vec<_>>>()?
         .into_iter()
         .flatten()
@@ -377,7 +406,7 @@ fn concrete_function_with_body_postpanic_direct_callees_with_body(
     Ok(db
         .concrete_function_with_body_postpanic_direct_callees(function_id)?
         .into_iter()
-        .map(|concrete| concrete.get_body(db.upcast()))
+        .map(|function_id| function_id.body(db))
         .collect::<Maybe<Vec<_>>>()?
         .into_iter()
         .flatten()
@@ -391,10 +420,24 @@ fn function_with_body_lowering_diagnostics(
     let mut diagnostics = DiagnosticsBuilder::default();
 
     diagnostics.extend(
-        db.priv_function_with_body_lowered_flat(function_id)
+        db.priv_function_with_body_lowered_flat(function_id.lowered(db))
             .map(|lowered| lowered.diagnostics.clone())
             .unwrap_or_default(),
     );
+    if let Ok(multi_lowering) = db.priv_function_with_body_multi_lowering(function_id) {
+        for key in multi_lowering.generated_lowerings.keys() {
+            let generated_id =
+                db.intern_lowering_function_with_body(FunctionWithBodyLongId::Generated {
+                    parent: function_id,
+                    key: *key,
+                });
+            diagnostics.extend(
+                db.priv_function_with_body_lowered_flat(generated_id)
+                    .map(|lowered| lowered.diagnostics.clone())
+                    .unwrap_or_default(),
+            );
+        }
+    }
 
     diagnostics.extend(
         db.priv_inline_data(function_id)
@@ -2,7 +2,8 @@
 //! This is similar to the borrow checking algorithm, except we handle "undroppable drops" by adding
 //! destructor calls.
 
-use cairo_lang_semantic::corelib::get_core_trait;
+use cairo_lang_diagnostics::Maybe;
+use cairo_lang_semantic::corelib::{get_core_trait, unit_ty};
 use cairo_lang_semantic::items::functions::{GenericFunctionId, ImplGenericFunctionId};
 use cairo_lang_semantic::items::imp::ImplId;
 use cairo_lang_semantic::{ConcreteFunction, FunctionLongId};
@@ -12,6 +13,8 @@ use crate::borrow_check::analysis::{Analyzer, BackAnalysis, StatementLocation};
 use crate::borrow_check::demand::DemandReporter;
 use crate::borrow_check::Demand;
 use crate::db::LoweringGroup;
+use crate::ids::{ConcreteFunctionWithBodyId, SemanticFunctionIdEx};
+use crate::lower::context::{LoweringContextBuilder, VarRequest};
 use crate::{BlockId, FlatLowered, MatchInfo, Statement, StatementCall, VarRemapping, VariableId};
 
 pub type LoweredDemand = Demand<VariableId>;
@@ -58,7 +61,9 @@ impl<'a> Analyzer for DestructAdder<'a> {
         statement_location: StatementLocation,
         stmt: &Statement,
     ) {
-        info.variables_introduced(self, &stmt.outputs(), statement_location);
+        let (block_id, statement_offset) = statement_location;
+        // Outputs that are not used are destructed right after the statement.
+        info.variables_introduced(self, &stmt.outputs(), (block_id, statement_offset + 1));
         info.variables_used(self, &stmt.inputs(), ());
     }
 
@@ -113,7 +118,11 @@ impl<'a> Analyzer for DestructAdder<'a> {
 }
 
 /// Report borrow checking diagnostics.
-pub fn add_destructs(db: &dyn LoweringGroup, lowered: &mut FlatLowered) {
+pub fn add_destructs(
+    db: &dyn LoweringGroup,
+    function_id: ConcreteFunctionWithBodyId,
+    lowered: &mut FlatLowered,
+) -> Maybe<()> {
     if lowered.blocks.has_root().is_ok() {
         let checker = DestructAdder { lowered, destructions: vec![] };
         let mut analysis =
@@ -130,27 +139,46 @@ pub fn add_destructs(db: &dyn LoweringGroup, lowered: &mut FlatLowered) {
         let trait_function =
             db.trait_function_by_name(trait_id, "destruct".into()).unwrap().unwrap();
 
-        // Add destructions.
-        for destruction in analysis.analyzer.destructions {
+        let mut destructions = analysis.analyzer.destructions;
+        if destructions.is_empty() {
+            return Ok(());
+        }
+
+        let lowering_info = LoweringContextBuilder::new_concrete(db, function_id)?;
+        let mut ctx = lowering_info.ctx()?;
+        ctx.variables = std::mem::take(&mut lowered.variables);
+        let unit_ty = unit_ty(db.upcast());
+
+        // Add destructions, starting from the last position in each block, so that earlier
+        // insertions do not shift the positions of later ones.
+        destructions.sort_by_key(|destruction| std::cmp::Reverse(destruction.position.1));
+        for destruction in destructions {
             let DestructionEntry { position: (block_id, statement_offset), var_id, impl_id } =
                 destruction;
+            let location = ctx.variables[var_id].location;
+            // The destructor returns a unit value, which is never used.
+            let output_var = ctx.new_var(VarRequest { ty: unit_ty, location });
             lowered.blocks[block_id].statements.insert(
                 statement_offset,
                 Statement::Call(StatementCall {
-                    function: db.intern_function(FunctionLongId {
-                        function: ConcreteFunction {
-                            generic_function: GenericFunctionId::Impl(ImplGenericFunctionId {
-                                impl_id,
-                                function: trait_function,
-                            }),
-                            generic_args: vec![],
-                        },
-                    }),
+                    function: db
+                        .intern_function(FunctionLongId {
+                            function: ConcreteFunction {
+                                generic_function: GenericFunctionId::Impl(ImplGenericFunctionId {
+                                    impl_id,
+                                    function: trait_function,
+                                }),
+                                generic_args: vec![],
+                            },
+                        })
+                        .lowered(db),
                     inputs: vec![var_id],
-                    outputs: vec![],
-                    location: lowered.variables[var_id].location,
+                    outputs: vec![output_var],
+                    location,
                 }),
             )
         }
+        lowered.variables = ctx.variables;
     }
+    Ok(())
 }
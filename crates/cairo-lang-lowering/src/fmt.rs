@@ -1,10 +1,12 @@
 use cairo_lang_debug::DebugWithDb;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::constant::ConstValue;
 use cairo_lang_semantic::ConcreteVariant;
 use id_arena::Arena;
 
 use crate::db::LoweringGroup;
 use crate::objects::{
-    BlockId, MatchExternInfo, Statement, StatementCall, StatementLiteral,
+    BlockId, MatchExternInfo, Statement, StatementCall, StatementConst, StatementLiteral,
     StatementStructDestructure, VariableId,
 };
 use crate::{
@@ -152,6 +154,7 @@ impl DebugWithDb<LoweredFormatter<'_>> for Statement {
         write!(f, ") <- ")?;
         match self {
             Statement::Literal(stmt) => stmt.fmt(f, ctx),
+            Statement::Const(stmt) => stmt.fmt(f, ctx),
             Statement::Call(stmt) => stmt.fmt(f, ctx),
             Statement::StructConstruct(stmt) => stmt.fmt(f, ctx),
             Statement::StructDestructure(stmt) => stmt.fmt(f, ctx),
@@ -181,9 +184,21 @@ impl DebugWithDb<LoweredFormatter<'_>> for StatementLiteral {
     }
 }
 
+impl DebugWithDb<LoweredFormatter<'_>> for StatementConst {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>, ctx: &LoweredFormatter<'_>) -> std::fmt::Result {
+        let semantic_db: &dyn SemanticGroup = ctx.db.upcast();
+        match &self.value {
+            ConstValue::ImplConstant(impl_constant_id) => {
+                write!(f, "const {}", impl_constant_id.format(semantic_db))
+            }
+            value => write!(f, "const {:?}", value.debug(semantic_db)),
+        }
+    }
+}
+
 impl DebugWithDb<LoweredFormatter<'_>> for StatementCall {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>, ctx: &LoweredFormatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}(", self.function.debug(ctx.db))?;
+        write!(f, "{}(", self.function.full_path(ctx.db))?;
         let mut inputs = self.inputs.iter().peekable();
         while let Some(var) = inputs.next() {
             var.fmt(f, ctx)?;
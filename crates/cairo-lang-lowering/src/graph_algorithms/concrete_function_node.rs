@@ -1,9 +1,9 @@
-use cairo_lang_semantic::ConcreteFunctionWithBodyId;
 use cairo_lang_utils::graph_algos::graph_node::GraphNode;
 use cairo_lang_utils::graph_algos::strongly_connected_components::ComputeScc;
 
 use super::strongly_connected_components::concrete_function_with_body_scc;
 use crate::db::LoweringGroup;
+use crate::ids::ConcreteFunctionWithBodyId;
 
 /// A node to use in graph-algorithms.
 #[derive(Clone)]
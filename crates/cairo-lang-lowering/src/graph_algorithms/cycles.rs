@@ -1,8 +1,8 @@
 use cairo_lang_defs::ids::FunctionWithBodyId;
 use cairo_lang_diagnostics::Maybe;
-use cairo_lang_semantic::ConcreteFunctionWithBodyId;
 
 use crate::db::LoweringGroup;
+use crate::ids::ConcreteFunctionWithBodyId;
 
 /// Query implementation of [LoweringGroup::contains_cycle].
 pub fn contains_cycle(
@@ -1,11 +1,11 @@
 use std::collections::HashSet;
 
 use cairo_lang_diagnostics::Maybe;
-use cairo_lang_semantic::ConcreteFunctionWithBodyId;
 use cairo_lang_utils::graph_algos::feedback_set::calc_feedback_set;
 
 use super::concrete_function_node::ConcreteFunctionWithBodyNode;
 use crate::db::{ConcreteSCCRepresentative, LoweringGroup};
+use crate::ids::ConcreteFunctionWithBodyId;
 
 /// Query implementation of [crate::db::LoweringGroup::function_with_body_feedback_set].
 pub fn function_with_body_feedback_set(
@@ -0,0 +1,409 @@
+use cairo_lang_debug::DebugWithDb;
+use cairo_lang_defs::ids::{ExternFunctionId, TraitFunctionId, UnstableSalsaId};
+use cairo_lang_diagnostics::{skip_diagnostic, Maybe};
+use cairo_lang_semantic as semantic;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
+use cairo_lang_semantic::items::imp::ImplId;
+use cairo_lang_semantic::substitution::{
+    GenericSubstitution, SemanticRewriter, SubstitutionRewriter,
+};
+use cairo_lang_semantic::{ConcreteTraitId, GenericArgumentId, Mutability, TypeLongId};
+use cairo_lang_utils::define_short_id;
+use itertools::Itertools;
+use semantic::items::functions::{GenericFunctionId, ImplGenericFunctionId};
+
+use crate::db::LoweringGroup;
+
+/// The id of a function with a body at the lowering level. Besides the semantic functions, this
+/// also includes functions that are generated by the lowering phase (e.g. for loops).
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum FunctionWithBodyLongId {
+    Semantic(cairo_lang_defs::ids::FunctionWithBodyId),
+    Generated { parent: cairo_lang_defs::ids::FunctionWithBodyId, key: GeneratedFunctionKey },
+}
+define_short_id!(
+    FunctionWithBodyId,
+    FunctionWithBodyLongId,
+    LoweringGroup,
+    lookup_intern_lowering_function_with_body
+);
+impl FunctionWithBodyLongId {
+    /// Returns the semantic function this function was created from.
+    pub fn base_semantic_function(&self) -> cairo_lang_defs::ids::FunctionWithBodyId {
+        match *self {
+            FunctionWithBodyLongId::Semantic(id) => id,
+            FunctionWithBodyLongId::Generated { parent, .. } => parent,
+        }
+    }
+}
+impl FunctionWithBodyId {
+    pub fn lookup(&self, db: &dyn LoweringGroup) -> FunctionWithBodyLongId {
+        db.lookup_intern_lowering_function_with_body(*self)
+    }
+    pub fn base_semantic_function(
+        &self,
+        db: &dyn LoweringGroup,
+    ) -> cairo_lang_defs::ids::FunctionWithBodyId {
+        self.lookup(db).base_semantic_function()
+    }
+    /// Returns the generic signature of the function.
+    pub fn signature(&self, db: &dyn LoweringGroup) -> Maybe<Signature> {
+        match self.lookup(db) {
+            FunctionWithBodyLongId::Semantic(id) => {
+                Ok(Signature::from_semantic(db, db.function_with_body_signature(id)?))
+            }
+            FunctionWithBodyLongId::Generated { parent, key } => {
+                Ok(db.priv_function_with_body_multi_lowering(parent)?.generated_lowerings[key]
+                    .signature
+                    .clone())
+            }
+        }
+    }
+}
+
+pub trait SemanticFunctionWithBodyIdEx {
+    fn lowered(&self, db: &dyn LoweringGroup) -> FunctionWithBodyId;
+}
+impl SemanticFunctionWithBodyIdEx for cairo_lang_defs::ids::FunctionWithBodyId {
+    fn lowered(&self, db: &dyn LoweringGroup) -> FunctionWithBodyId {
+        db.intern_lowering_function_with_body(FunctionWithBodyLongId::Semantic(*self))
+    }
+}
+
+/// The id of a concrete function with a body at the lowering level.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum ConcreteFunctionWithBodyLongId {
+    Semantic(semantic::ConcreteFunctionWithBodyId),
+    Generated(GeneratedFunction),
+}
+define_short_id!(
+    ConcreteFunctionWithBodyId,
+    ConcreteFunctionWithBodyLongId,
+    LoweringGroup,
+    lookup_intern_lowering_concrete_function_with_body
+);
+impl ConcreteFunctionWithBodyLongId {
+    /// Returns the concrete semantic function this function was created from.
+    pub fn base_semantic_function(&self) -> semantic::ConcreteFunctionWithBodyId {
+        match self {
+            ConcreteFunctionWithBodyLongId::Semantic(id) => *id,
+            ConcreteFunctionWithBodyLongId::Generated(generated) => generated.parent,
+        }
+    }
+}
+impl ConcreteFunctionWithBodyId {
+    pub fn lookup(&self, db: &dyn LoweringGroup) -> ConcreteFunctionWithBodyLongId {
+        db.lookup_intern_lowering_concrete_function_with_body(*self)
+    }
+    pub fn base_semantic_function(
+        &self,
+        db: &dyn LoweringGroup,
+    ) -> semantic::ConcreteFunctionWithBodyId {
+        self.lookup(db).base_semantic_function()
+    }
+    /// Returns the generic function this function is a concretization of.
+    pub fn function_with_body_id(&self, db: &dyn LoweringGroup) -> FunctionWithBodyId {
+        let long_id = match self.lookup(db) {
+            ConcreteFunctionWithBodyLongId::Semantic(id) => {
+                FunctionWithBodyLongId::Semantic(id.function_with_body_id(db.upcast()))
+            }
+            ConcreteFunctionWithBodyLongId::Generated(generated) => {
+                FunctionWithBodyLongId::Generated {
+                    parent: generated.parent.function_with_body_id(db.upcast()),
+                    key: generated.key,
+                }
+            }
+        };
+        db.intern_lowering_function_with_body(long_id)
+    }
+    pub fn substitution(&self, db: &dyn LoweringGroup) -> Maybe<GenericSubstitution> {
+        self.base_semantic_function(db).substitution(db.upcast())
+    }
+    pub fn function_id(&self, db: &dyn LoweringGroup) -> Maybe<FunctionId> {
+        let long_id = match self.lookup(db) {
+            ConcreteFunctionWithBodyLongId::Semantic(id) => {
+                FunctionLongId::Semantic(id.function_id(db.upcast())?)
+            }
+            ConcreteFunctionWithBodyLongId::Generated(generated) => {
+                FunctionLongId::Generated(generated)
+            }
+        };
+        Ok(db.intern_lowering_function(long_id))
+    }
+    /// Returns the concrete signature of the function.
+    pub fn signature(&self, db: &dyn LoweringGroup) -> Maybe<Signature> {
+        match self.lookup(db) {
+            ConcreteFunctionWithBodyLongId::Semantic(id) => Ok(Signature::from_semantic(
+                db,
+                db.concrete_function_signature(id.function_id(db.upcast())?)?,
+            )),
+            ConcreteFunctionWithBodyLongId::Generated(_) => self
+                .function_with_body_id(db)
+                .signature(db)?
+                .concretize(db, &self.substitution(db)?),
+        }
+    }
+    pub fn name(&self, db: &dyn LoweringGroup) -> String {
+        match self.lookup(db) {
+            ConcreteFunctionWithBodyLongId::Semantic(id) => id.name(db.upcast()).into(),
+            ConcreteFunctionWithBodyLongId::Generated(generated) => generated.name(db),
+        }
+    }
+}
+impl UnstableSalsaId for ConcreteFunctionWithBodyId {
+    fn get_internal_id(&self) -> &salsa::InternId {
+        &self.0
+    }
+}
+
+pub trait SemanticConcreteFunctionWithBodyIdEx {
+    fn lowered(&self, db: &dyn LoweringGroup) -> ConcreteFunctionWithBodyId;
+}
+impl SemanticConcreteFunctionWithBodyIdEx for semantic::ConcreteFunctionWithBodyId {
+    fn lowered(&self, db: &dyn LoweringGroup) -> ConcreteFunctionWithBodyId {
+        db.intern_lowering_concrete_function_with_body(ConcreteFunctionWithBodyLongId::Semantic(
+            *self,
+        ))
+    }
+}
+
+/// The id of a concrete function at the lowering level - either a semantic function or a function
+/// generated by the lowering phase.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum FunctionLongId {
+    Semantic(semantic::FunctionId),
+    Generated(GeneratedFunction),
+}
+define_short_id!(FunctionId, FunctionLongId, LoweringGroup, lookup_intern_lowering_function);
+impl FunctionLongId {
+    pub fn intern(self, db: &dyn LoweringGroup) -> FunctionId {
+        db.intern_lowering_function(self)
+    }
+    pub fn body(&self, db: &dyn LoweringGroup) -> Maybe<Option<ConcreteFunctionWithBodyId>> {
+        Ok(match self {
+            FunctionLongId::Semantic(id) => {
+                let concrete_function = id.get_concrete(db.upcast());
+                if let GenericFunctionId::Impl(ImplGenericFunctionId {
+                    impl_id: ImplId::Closure(concrete_trait_id),
+                    function,
+                }) = concrete_function.generic_function
+                {
+                    return Ok(Some(closure_call_body(db, concrete_trait_id, function)?));
+                }
+                concrete_function.get_body(db.upcast())?.map(|body| body.lowered(db))
+            }
+            FunctionLongId::Generated(generated) => Some(generated.body(db)),
+        })
+    }
+    pub fn signature(&self, db: &dyn LoweringGroup) -> Maybe<Signature> {
+        match self {
+            FunctionLongId::Semantic(id) => {
+                Ok(Signature::from_semantic(db, db.concrete_function_signature(*id)?))
+            }
+            FunctionLongId::Generated(generated) => generated.body(db).signature(db),
+        }
+    }
+    /// Returns the full path of the function, used for debug names.
+    pub fn full_path(&self, db: &dyn LoweringGroup) -> String {
+        match self {
+            FunctionLongId::Semantic(id) => {
+                format!("{:?}", id.get_concrete(db.upcast()).debug(db.upcast()))
+            }
+            FunctionLongId::Generated(generated) => generated.full_path(db),
+        }
+    }
+}
+
+/// Returns the function generated for the given call trait function of a closure type, given the
+/// concrete call trait of the closure.
+fn closure_call_body(
+    db: &dyn LoweringGroup,
+    concrete_trait_id: ConcreteTraitId,
+    function: TraitFunctionId,
+) -> Maybe<ConcreteFunctionWithBodyId> {
+    let semantic_db: &dyn SemanticGroup = db.upcast();
+    let Some(GenericArgumentId::Type(closure_ty)) =
+        concrete_trait_id.generic_args(semantic_db).first().copied()
+    else {
+        return Err(skip_diagnostic());
+    };
+    let TypeLongId::Closure(closure) = db.lookup_intern_type(closure_ty) else {
+        return Err(skip_diagnostic());
+    };
+    let expr_id = db.lookup_expr_by_ptr(
+        closure.parent_function.function_with_body_id(semantic_db),
+        closure.wrapper_location,
+    )?;
+    Ok(GeneratedFunction {
+        parent: closure.parent_function,
+        key: GeneratedFunctionKey::ClosureCall(expr_id, function),
+    }
+    .body(db))
+}
+impl FunctionId {
+    pub fn lookup(&self, db: &dyn LoweringGroup) -> FunctionLongId {
+        db.lookup_intern_lowering_function(*self)
+    }
+    /// Returns the concrete function with a body of this function, or None if it is an extern
+    /// function.
+    pub fn body(&self, db: &dyn LoweringGroup) -> Maybe<Option<ConcreteFunctionWithBodyId>> {
+        self.lookup(db).body(db)
+    }
+    pub fn signature(&self, db: &dyn LoweringGroup) -> Maybe<Signature> {
+        self.lookup(db).signature(db)
+    }
+    /// Returns the extern function id and its generic arguments, if this is an extern function.
+    pub fn get_extern(
+        &self,
+        db: &dyn LoweringGroup,
+    ) -> Option<(ExternFunctionId, Vec<GenericArgumentId>)> {
+        let FunctionLongId::Semantic(id) = self.lookup(db) else { return None; };
+        let concrete = id.get_concrete(db.upcast());
+        let GenericFunctionId::Extern(extern_id) = concrete.generic_function else {
+            return None;
+        };
+        Some((extern_id, concrete.generic_args))
+    }
+    pub fn full_path(&self, db: &dyn LoweringGroup) -> String {
+        self.lookup(db).full_path(db)
+    }
+}
+
+pub trait SemanticFunctionIdEx {
+    fn lowered(&self, db: &dyn LoweringGroup) -> FunctionId;
+}
+impl SemanticFunctionIdEx for semantic::FunctionId {
+    fn lowered(&self, db: &dyn LoweringGroup) -> FunctionId {
+        db.intern_lowering_function(FunctionLongId::Semantic(*self))
+    }
+}
+
+/// The semantic element a generated function was created from, identifying it within its parent
+/// function.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum GeneratedFunctionKey {
+    /// The function running the iterations of a loop expression.
+    Loop(semantic::ExprId),
+    /// The function implementing the given call trait function (`FnOnce::call_once` or
+    /// `Fn::call`) for a closure expression.
+    ClosureCall(semantic::ExprId, TraitFunctionId),
+}
+impl GeneratedFunctionKey {
+    /// Returns the expression the function was generated from.
+    pub fn expr_id(&self) -> semantic::ExprId {
+        match self {
+            GeneratedFunctionKey::Loop(expr_id) | GeneratedFunctionKey::ClosureCall(expr_id, _) => {
+                *expr_id
+            }
+        }
+    }
+    /// Returns the suffix added to the name of the parent function to name the generated
+    /// function.
+    fn name_suffix(&self, db: &dyn LoweringGroup) -> String {
+        match self {
+            GeneratedFunctionKey::Loop(expr_id) => format!("[expr{}]", expr_id.index()),
+            GeneratedFunctionKey::ClosureCall(expr_id, trait_function) => {
+                format!("[expr{}]::{}", expr_id.index(), trait_function.name(db.upcast()))
+            }
+        }
+    }
+}
+
+/// A function generated by the lowering phase, as a part of a concrete semantic function.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct GeneratedFunction {
+    /// The concrete semantic function containing the generated function.
+    pub parent: semantic::ConcreteFunctionWithBodyId,
+    /// The semantic element the function was generated from (e.g. a loop expression).
+    pub key: GeneratedFunctionKey,
+}
+impl GeneratedFunction {
+    pub fn body(&self, db: &dyn LoweringGroup) -> ConcreteFunctionWithBodyId {
+        db.intern_lowering_concrete_function_with_body(ConcreteFunctionWithBodyLongId::Generated(
+            self.clone(),
+        ))
+    }
+    pub fn name(&self, db: &dyn LoweringGroup) -> String {
+        format!("{}{}", self.parent.name(db.upcast()), self.key.name_suffix(db))
+    }
+    /// Returns the full path of the function, used for debug names.
+    pub fn full_path(&self, db: &dyn LoweringGroup) -> String {
+        let parent = match self.parent.concrete(db.upcast()) {
+            Ok(concrete) => format!("{:?}", concrete.debug(db.upcast())),
+            Err(_) => self.parent.name(db.upcast()).into(),
+        };
+        format!("{parent}{}", self.key.name_suffix(db))
+    }
+}
+
+/// Lowered signature of a function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    /// The function parameters, as semantic variables.
+    pub params: Vec<semantic::Variable>,
+    /// The parameters whose values are returned to the caller in addition to the return value.
+    /// For semantic functions, these are the `ref` parameters.
+    pub extra_rets: Vec<semantic::Variable>,
+    /// The return type.
+    pub return_type: semantic::TypeId,
+    /// Explicit implicit parameters.
+    pub implicits: Vec<semantic::TypeId>,
+    /// Whether the function may panic.
+    pub panicable: bool,
+}
+impl Signature {
+    pub fn from_semantic(_db: &dyn LoweringGroup, signature: semantic::Signature) -> Self {
+        let params = signature.params.into_iter().map(semantic::Variable::Param).collect_vec();
+        let extra_rets = params
+            .iter()
+            .filter(|param| {
+                matches!(param, semantic::Variable::Param(param)
+                    if param.mutability == Mutability::Reference)
+            })
+            .cloned()
+            .collect();
+        Self {
+            params,
+            extra_rets,
+            return_type: signature.return_type,
+            implicits: signature.implicits,
+            panicable: signature.panicable,
+        }
+    }
+
+    /// Applies a generic substitution on the types of the signature.
+    pub fn concretize(
+        self,
+        db: &dyn LoweringGroup,
+        substitution: &GenericSubstitution,
+    ) -> Maybe<Self> {
+        let mut rewriter = SubstitutionRewriter { db: db.upcast(), substitution };
+        let mut concretize_var = |var: semantic::Variable| -> Maybe<semantic::Variable> {
+            Ok(match var {
+                semantic::Variable::Local(mut local) => {
+                    local.ty = rewriter.rewrite(local.ty)?;
+                    semantic::Variable::Local(local)
+                }
+                semantic::Variable::Param(mut param) => {
+                    param.ty = rewriter.rewrite(param.ty)?;
+                    semantic::Variable::Param(param)
+                }
+            })
+        };
+        let params = self.params.into_iter().map(&mut concretize_var).collect::<Maybe<_>>()?;
+        let extra_rets =
+            self.extra_rets.into_iter().map(&mut concretize_var).collect::<Maybe<_>>()?;
+        Ok(Self {
+            params,
+            extra_rets,
+            return_type: rewriter.rewrite(self.return_type)?,
+            implicits: self
+                .implicits
+                .into_iter()
+                .map(|ty| rewriter.rewrite(ty))
+                .collect::<Maybe<_>>()?,
+            panicable: self.panicable,
+        })
+    }
+}
@@ -5,14 +5,15 @@ use cairo_lang_defs::ids::LanguageElementId;
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_semantic as semantic;
 use itertools::{chain, zip_eq, Itertools};
-use semantic::items::functions::{
-    ConcreteFunctionWithBody, GenericFunctionId, GenericFunctionWithBodyId,
-};
-use semantic::{ConcreteFunctionWithBodyId, TypeId};
+use semantic::items::functions::GenericFunctionWithBodyId;
+use semantic::TypeId;
 
 use crate::blocks::Blocks;
 use crate::db::{ConcreteSCCRepresentative, LoweringGroup};
 use crate::graph_algorithms::strongly_connected_components::concrete_function_with_body_scc;
+use crate::ids::{
+    ConcreteFunctionWithBodyId, ConcreteFunctionWithBodyLongId, FunctionId, SemanticFunctionIdEx,
+};
 use crate::lower::context::{LoweringContext, LoweringContextBuilder, VarRequest};
 use crate::{BlockId, FlatBlockEnd, FlatLowered, MatchArm, MatchInfo, Statement, VariableId};
 
@@ -44,7 +45,8 @@ pub fn inner_lower_implicits(
     function_id: ConcreteFunctionWithBodyId,
     lowered: &mut FlatLowered,
 ) -> Maybe<()> {
-    let generic_function_id = function_id.function_with_body_id(db.upcast());
+    let generic_function_id =
+        function_id.base_semantic_function(db).function_with_body_id(db.upcast());
     let function_signature = db.function_with_body_signature(generic_function_id)?;
     let location = StableLocationOption::new(
         generic_function_id.module_file_id(db.upcast()),
@@ -151,7 +153,8 @@ fn lower_block_implicits(ctx: &mut Context<'_>, block_id: BlockId) -> Maybe<()>
                 }
             }
             MatchInfo::Extern(stmt) => {
-                let callee_implicits = ctx.db.function_all_implicits(stmt.function)?;
+                let callee_implicits =
+                    ctx.db.function_all_implicits(stmt.function.lowered(ctx.db))?;
                 let indices =
                     callee_implicits.iter().map(|ty| ctx.implicit_index[ty]).collect_vec();
                 let implicit_input_vars = indices.iter().map(|i| implicits[*i]);
@@ -195,6 +198,10 @@ pub fn function_scc_explicit_implicits(
     let scc = concrete_function_with_body_scc(db, function.0);
     let mut explicit_implicits = HashSet::new();
     for func in scc {
+        // Generated functions have no explicit implicits of their own.
+        let ConcreteFunctionWithBodyLongId::Semantic(func) = func.lookup(db) else {
+            continue;
+        };
         let current_implicits: HashSet<TypeId> = match func.generic_function(db.upcast()) {
             GenericFunctionWithBodyId::Free(free_function) => {
                 db.free_function_declaration_implicits(free_function)?.into_iter().collect()
@@ -210,36 +217,10 @@ pub fn function_scc_explicit_implicits(
 }
 
 /// Query implementation of [crate::db::LoweringGroup::function_all_implicits].
-pub fn function_all_implicits(
-    db: &dyn LoweringGroup,
-    function: semantic::FunctionId,
-) -> Maybe<Vec<TypeId>> {
-    let concrete_function = function.get_concrete(db.upcast());
-    match concrete_function.generic_function {
-        GenericFunctionId::Free(free_function) => {
-            let concrete_with_body =
-                db.intern_concrete_function_with_body(ConcreteFunctionWithBody {
-                    generic_function: GenericFunctionWithBodyId::Free(free_function),
-                    generic_args: concrete_function.generic_args,
-                });
-            db.concrete_function_with_body_all_implicits_vec(concrete_with_body)
-        }
-        GenericFunctionId::Impl(impl_generic_function) => {
-            let Some(generic_with_body) =
-                impl_generic_function.to_generic_with_body(db.upcast())?
-                else {
-                    unreachable!();
-                };
-            let concrete_with_body =
-                db.intern_concrete_function_with_body(ConcreteFunctionWithBody {
-                    generic_function: generic_with_body,
-                    generic_args: concrete_function.generic_args,
-                });
-            db.concrete_function_with_body_all_implicits_vec(concrete_with_body)
-        }
-        GenericFunctionId::Extern(extern_function) => {
-            db.extern_function_declaration_implicits(extern_function)
-        }
+pub fn function_all_implicits(db: &dyn LoweringGroup, function: FunctionId) -> Maybe<Vec<TypeId>> {
+    match function.body(db)? {
+        Some(body) => db.concrete_function_with_body_all_implicits_vec(body),
+        None => Ok(function.signature(db)?.implicits),
     }
 }
 
@@ -257,49 +238,19 @@ pub fn concrete_function_with_body_all_implicits(
     let direct_callees = db.concrete_function_with_body_postpanic_direct_callees(function)?;
     // For each direct callee, add its implicits.
     for direct_callee in direct_callees {
-        let generic_function = direct_callee.generic_function;
-        let current_implicits = match generic_function {
-            GenericFunctionId::Free(free_function) => {
-                // For a free function, call this method recursively. To avoid cycles, first
-                // check that the callee is not in this function's SCC.
-                let concrete_with_body =
-                    db.intern_concrete_function_with_body(ConcreteFunctionWithBody {
-                        generic_function: GenericFunctionWithBodyId::Free(free_function),
-                        generic_args: direct_callee.generic_args,
-                    });
-                let direct_callee_representative =
-                    db.concrete_function_with_body_scc_postpanic_representative(concrete_with_body);
-                if direct_callee_representative == scc_representative {
-                    // We already have the implicits of this SCC - do nothing.
-                    continue;
-                }
-                db.concrete_function_with_body_all_implicits(direct_callee_representative.0)?
-            }
-            GenericFunctionId::Impl(impl_generic_function) => {
-                let Some(generic_with_body) =
-                    impl_generic_function.to_generic_with_body(db.upcast())?
-                    else {
-                        unreachable!();
-                    };
-                // For an impl function, call this method recursively. To avoid cycles, first
-                // check that the callee is not in this function's SCC.
-                let concrete_with_body =
-                    db.intern_concrete_function_with_body(ConcreteFunctionWithBody {
-                        generic_function: generic_with_body,
-                        generic_args: direct_callee.generic_args,
-                    });
-                let direct_callee_representative =
-                    db.concrete_function_with_body_scc_postpanic_representative(concrete_with_body);
-                if direct_callee_representative == scc_representative {
-                    // We already have the implicits of this SCC - do nothing.
-                    continue;
-                }
-                db.concrete_function_with_body_all_implicits(direct_callee_representative.0)?
-            }
-            GenericFunctionId::Extern(extern_function) => {
-                // All implicits of a libfunc are explicit implicits.
-                db.extern_function_declaration_implicits(extern_function)?.into_iter().collect()
+        let current_implicits = if let Some(callee_body) = direct_callee.body(db)? {
+            // For a function with a body, call this method recursively. To avoid cycles, first
+            // check that the callee is not in this function's SCC.
+            let direct_callee_representative =
+                db.concrete_function_with_body_scc_postpanic_representative(callee_body);
+            if direct_callee_representative == scc_representative {
+                // We already have the implicits of this SCC - do nothing.
+                continue;
             }
+            db.concrete_function_with_body_all_implicits(direct_callee_representative.0)?
+        } else {
+            // All implicits of a libfunc are explicit implicits.
+            direct_callee.signature(db)?.implicits.into_iter().collect()
         };
         all_implicits.extend(&current_implicits);
     }
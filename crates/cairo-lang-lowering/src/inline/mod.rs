@@ -7,13 +7,15 @@ use std::sync::Arc;
 use cairo_lang_defs::ids::{FunctionWithBodyId, LanguageElementId};
 use cairo_lang_diagnostics::{Diagnostics, Maybe};
 use cairo_lang_semantic::items::functions::InlineConfiguration;
-use cairo_lang_semantic::ConcreteFunctionWithBodyId;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use itertools::{izip, Itertools};
 
 use crate::blocks::{FlatBlocks, FlatBlocksBuilder};
 use crate::db::LoweringGroup;
 use crate::diagnostic::{LoweringDiagnostic, LoweringDiagnosticKind, LoweringDiagnostics};
+use crate::ids::{
+    ConcreteFunctionWithBodyId, ConcreteFunctionWithBodyLongId, SemanticFunctionWithBodyIdEx,
+};
 use crate::lower::context::{LoweringContext, LoweringContextBuilder, VarRequest};
 use crate::utils::{Rebuilder, RebuilderEx};
 use crate::{BlockId, FlatBlock, FlatBlockEnd, FlatLowered, Statement, VarRemapping, VariableId};
@@ -75,7 +77,7 @@ fn gather_inlining_info(
         return Ok(InlineInfo { is_inlinable: false, should_inline: false });
     }
 
-    let lowered = db.priv_function_with_body_lowered_flat(function_id)?;
+    let lowered = db.priv_function_with_body_lowered_flat(function_id.lowered(db))?;
 
     Ok(InlineInfo { is_inlinable: true, should_inline: should_inline(db, &lowered)? })
 }
@@ -256,6 +258,7 @@ impl<'db> FunctionInlinerRewriter<'db> {
             variables: rewriter.ctx.variables,
             blocks,
             parameters: flat_lower.parameters.clone(),
+            signature: flat_lower.signature.clone(),
         })
     }
 
@@ -263,11 +266,17 @@ impl<'db> FunctionInlinerRewriter<'db> {
     /// self.statements_rewrite_stack.
     fn rewrite(&mut self, statement: Statement) -> Maybe<()> {
         if let Statement::Call(ref stmt) = statement {
-            let concrete_function = self.ctx.db.lookup_intern_function(stmt.function).function;
-            let semantic_db = self.ctx.db.upcast();
-            if let Some(function_id) = concrete_function.get_body(semantic_db)? {
-                let inline_data =
-                    self.ctx.db.priv_inline_data(function_id.function_with_body_id(semantic_db))?;
+            // Functions generated by the lowering phase (e.g. for loops) are never inlined.
+            if let Some(function_id) = stmt.function.body(self.ctx.db)? {
+                let ConcreteFunctionWithBodyLongId::Semantic(semantic_function_id) =
+                    function_id.lookup(self.ctx.db)
+                else {
+                    self.statements.push(statement);
+                    return Ok(());
+                };
+                let inline_data = self.ctx.db.priv_inline_data(
+                    semantic_function_id.function_with_body_id(self.ctx.db.upcast()),
+                )?;
 
                 self.inlining_success = self
                     .inlining_success
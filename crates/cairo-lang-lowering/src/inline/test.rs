@@ -8,6 +8,7 @@ use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 
 use crate::db::LoweringGroup;
 use crate::fmt::LoweredFormatter;
+use crate::ids::SemanticConcreteFunctionWithBodyIdEx;
 use crate::inline::apply_inlining;
 use crate::test_utils::LoweringDatabaseForTesting;
 
@@ -35,7 +36,9 @@ fn test_function_inlining(
     )
     .split();
     let before = db
-        .priv_concrete_function_with_body_lowered_flat(test_function.concrete_function_id)
+        .priv_concrete_function_with_body_lowered_flat(
+            test_function.concrete_function_id.lowered(db),
+        )
         .unwrap();
 
     let lowering_diagnostics = db.module_lowering_diagnostics(test_function.module_id).unwrap();
@@ -1,6 +1,7 @@
 //! Cairo lowering.
 //!
 //! This crate is responsible for handling the lowering phase.
+pub mod add_withdraw_gas;
 pub mod borrow_check;
 pub mod concretize;
 pub mod db;
@@ -8,6 +9,7 @@ pub mod destructs;
 pub mod diagnostic;
 pub mod fmt;
 pub mod graph_algorithms;
+pub mod ids;
 pub mod implicits;
 pub mod inline;
 pub mod lower;
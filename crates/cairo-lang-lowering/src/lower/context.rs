@@ -7,31 +7,31 @@ use cairo_lang_semantic as semantic;
 use cairo_lang_semantic::expr::fmt::ExprFormatter;
 use cairo_lang_semantic::items::enm::SemanticEnumEx;
 use cairo_lang_semantic::items::imp::ImplLookupContext;
-use cairo_lang_semantic::{Mutability, VarId};
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use id_arena::Arena;
 use itertools::{zip_eq, Itertools};
 use semantic::expr::inference::InferenceError;
 use semantic::types::wrap_in_snapshots;
-use semantic::ConcreteFunctionWithBodyId;
 
 use super::generators;
 use super::scope::{BlockBuilder, SealedBlockBuilder};
 use crate::blocks::FlatBlocksBuilder;
 use crate::db::LoweringGroup;
 use crate::diagnostic::LoweringDiagnostics;
+use crate::ids::{self, Signature};
 use crate::lower::external::{extern_facade_expr, extern_facade_return_tys};
 use crate::objects::Variable;
-use crate::{MatchArm, MatchExternInfo, MatchInfo, VariableId};
+use crate::{FlatLowered, MatchArm, MatchExternInfo, MatchInfo, VariableId};
 
 /// Builds a Lowering context.
 pub struct LoweringContextBuilder<'db> {
     pub db: &'db dyn LoweringGroup,
     pub function_id: FunctionWithBodyId,
     pub function_body: Arc<semantic::items::function_with_body::FunctionBody>,
-    /// Semantic signature for current function.
-    pub signature: semantic::Signature,
+    /// Lowered signature for current function.
+    pub signature: Signature,
     /// The `ref` parameters of the current function.
     pub ref_params: Vec<semantic::VarId>,
 }
@@ -39,33 +39,29 @@ impl<'db> LoweringContextBuilder<'db> {
     /// Constructs a new LoweringContextBuilder with the generic signature of the given generic
     /// function.
     pub fn new(db: &'db dyn LoweringGroup, function_id: FunctionWithBodyId) -> Maybe<Self> {
-        let signature = db.function_with_body_signature(function_id)?;
+        let signature = Signature::from_semantic(db, db.function_with_body_signature(function_id)?);
         Self::new_inner(db, function_id, signature)
     }
     /// Constructs a new LoweringContextBuilder with a concrete signature of the given concrete
     /// function.
     pub fn new_concrete(
         db: &'db dyn LoweringGroup,
-        concrete_function_with_body_id: ConcreteFunctionWithBodyId,
+        concrete_function_with_body_id: ids::ConcreteFunctionWithBodyId,
     ) -> Maybe<Self> {
-        let function_id = concrete_function_with_body_id.function_with_body_id(db.upcast());
-
-        let signature = db.concrete_function_signature(
-            concrete_function_with_body_id.function_id(db.upcast())?,
-        )?;
+        let function_id = concrete_function_with_body_id
+            .base_semantic_function(db)
+            .function_with_body_id(db.upcast());
+        let signature = concrete_function_with_body_id.signature(db)?;
         Self::new_inner(db, function_id, signature)
     }
-    fn new_inner(
+    /// Constructs a new LoweringContextBuilder for a function with the given signature, generated
+    /// from the body of the given function.
+    pub fn new_inner(
         db: &'db dyn LoweringGroup,
         function_id: FunctionWithBodyId,
-        signature: semantic::Signature,
+        signature: Signature,
     ) -> Maybe<Self> {
-        let ref_params = signature
-            .params
-            .iter()
-            .filter(|param| param.mutability == Mutability::Reference)
-            .map(|param| VarId::Param(param.id))
-            .collect();
+        let ref_params = signature.extra_rets.iter().map(|var| var.id()).collect();
         Ok(LoweringContextBuilder {
             db,
             function_id,
@@ -94,6 +90,8 @@ impl<'db> LoweringContextBuilder<'db> {
                 generic_params,
             },
             expr_formatter: ExprFormatter { db: self.db.upcast(), function_id: self.function_id },
+            current_loop: None,
+            generated_lowerings: OrderedHashMap::default(),
         })
     }
 }
@@ -105,8 +103,8 @@ pub struct LoweringContext<'db> {
     pub function_id: FunctionWithBodyId,
     /// Semantic model for current function body.
     pub function_body: &'db semantic::FunctionBody,
-    /// Semantic signature for current function.
-    pub signature: &'db semantic::Signature,
+    /// Lowered signature for current function.
+    pub signature: &'db Signature,
     /// Current emitted diagnostics.
     pub diagnostics: LoweringDiagnostics,
     /// Arena of allocated lowered variables.
@@ -122,6 +120,10 @@ pub struct LoweringContext<'db> {
     pub lookup_context: ImplLookupContext,
     // Expression formatter of the free function.
     pub expr_formatter: ExprFormatter<'db>,
+    /// The loop expression the current function was generated from, if any.
+    pub current_loop: Option<semantic::ExprId>,
+    /// The lowered functions generated while lowering the current function (e.g. for loops).
+    pub generated_lowerings: OrderedHashMap<ids::GeneratedFunctionKey, FlatLowered>,
 }
 impl<'db> LoweringContext<'db> {
     pub fn new_var(&mut self, req: VarRequest) -> VariableId {
@@ -3,6 +3,7 @@
 
 use cairo_lang_defs::diagnostic_utils::StableLocationOption;
 use cairo_lang_semantic as semantic;
+use cairo_lang_semantic::items::constant::ConstValue;
 use cairo_lang_semantic::ConcreteVariant;
 use cairo_lang_utils::extract_matches;
 use itertools::chain;
@@ -12,7 +13,7 @@ use super::context::VarRequest;
 use super::VariableId;
 use crate::lower::context::LoweringContext;
 use crate::objects::{
-    Statement, StatementCall, StatementLiteral, StatementStructConstruct,
+    Statement, StatementCall, StatementConst, StatementLiteral, StatementStructConstruct,
     StatementStructDestructure,
 };
 use crate::{StatementDesnap, StatementEnumConstruct, StatementSnapshot};
@@ -42,11 +43,25 @@ impl Literal {
     }
 }
 
+/// Generator for [StatementConst].
+pub struct Const {
+    pub value: ConstValue,
+    pub location: StableLocationOption,
+    pub ty: semantic::TypeId,
+}
+impl Const {
+    pub fn add(self, ctx: &mut LoweringContext<'_>, scope: &mut StatementsBuilder) -> VariableId {
+        let output = ctx.new_var(VarRequest { ty: self.ty, location: self.location });
+        scope.push_statement(Statement::Const(StatementConst { value: self.value, output }));
+        output
+    }
+}
+
 /// Generator for [StatementCall].
 /// Note that scope.finalize_statement() must be called manually after ref bindings.
 pub struct Call {
     /// Called function.
-    pub function: semantic::FunctionId,
+    pub function: crate::ids::FunctionId,
     /// Inputs to function.
     pub inputs: Vec<VariableId>,
     /// Types for `ref` parameters of the function. An output variable will be introduced for each.
@@ -0,0 +1,181 @@
+use cairo_lang_debug::DebugWithDb;
+use cairo_lang_defs::diagnostic_utils::StableLocationOption;
+use cairo_lang_defs::ids::TraitFunctionId;
+use cairo_lang_diagnostics::Maybe;
+use cairo_lang_semantic as semantic;
+use cairo_lang_semantic::corelib::{
+    concrete_fn_once_trait, concrete_fn_trait, fn_call_trait_function, fn_once_call_trait_function,
+};
+use cairo_lang_semantic::items::functions::{
+    ConcreteFunction, GenericFunctionId, ImplGenericFunctionId,
+};
+use cairo_lang_semantic::items::imp::ImplId;
+use cairo_lang_utils::extract_matches;
+use itertools::{zip_eq, Itertools};
+use semantic::TypeLongId;
+
+use super::context::{LoweredExpr, LoweringContext, LoweringFlowError, LoweringResult};
+use super::scope::BlockBuilder;
+use super::{
+    alloc_empty_block, lower_expr, lowered_expr_to_block_scope_end, wrap_sealed_block_as_function,
+};
+use crate::blocks::FlatBlocks;
+use crate::ids::{GeneratedFunctionKey, Signature};
+use crate::lower::context::{LoweringContextBuilder, VarRequest};
+use crate::lower::generators;
+use crate::FlatLowered;
+
+/// Lowers an expression of type [semantic::ExprClosure].
+///
+/// The value of the closure is a struct of its captured variables. The closure body is lowered
+/// into separate generated functions implementing `FnOnce::call_once`, and also `Fn::call` if the
+/// closure is copyable. These functions get the closure value and a tuple of the closure
+/// arguments as parameters.
+pub fn lower_expr_closure(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    closure_expr_id: semantic::ExprId,
+) -> LoweringResult<LoweredExpr> {
+    let function_body = ctx.function_body;
+    let expr = extract_matches!(&function_body.exprs[closure_expr_id], semantic::Expr::Closure);
+    log::trace!("Lowering a closure: {:?}", expr.debug(&ctx.expr_formatter));
+    let location = ctx.get_location(expr.stable_ptr.untyped());
+
+    let mut call_functions = vec![fn_once_call_trait_function(ctx.db.upcast())];
+    let duplicatable = ctx
+        .db
+        .type_info(ctx.lookup_context.clone(), expr.ty)
+        .map_err(LoweringFlowError::Failed)?
+        .duplicatable
+        .is_ok();
+    if duplicatable {
+        call_functions.push(fn_call_trait_function(ctx.db.upcast()));
+    }
+    for trait_function in call_functions {
+        let lowered = lower_closure_function(ctx, closure_expr_id, trait_function, location)
+            .map_err(LoweringFlowError::Failed)?;
+        ctx.generated_lowerings
+            .insert(GeneratedFunctionKey::ClosureCall(closure_expr_id, trait_function), lowered);
+    }
+
+    let inputs = expr
+        .captured_vars
+        .iter()
+        .map(|var_id| scope.get_semantic(ctx, *var_id, location))
+        .collect();
+    Ok(LoweredExpr::AtVariable(
+        generators::StructConstruct { inputs, ty: expr.ty, location }
+            .add(ctx, &mut scope.statements),
+    ))
+}
+
+/// Lowers the function implementing the given call trait function for the closure with the given
+/// id.
+fn lower_closure_function(
+    ctx: &mut LoweringContext<'_>,
+    closure_expr_id: semantic::ExprId,
+    trait_function: TraitFunctionId,
+    location: StableLocationOption,
+) -> Maybe<FlatLowered> {
+    let semantic_db = ctx.db.upcast();
+    let function_body = ctx.function_body;
+    let expr = extract_matches!(&function_body.exprs[closure_expr_id], semantic::Expr::Closure);
+    let closure = extract_matches!(ctx.db.lookup_intern_type(expr.ty), TypeLongId::Closure);
+    let args_ty = ctx.db.intern_type(TypeLongId::Tuple(closure.param_tys.clone()));
+    let is_fn_call = trait_function == fn_call_trait_function(semantic_db);
+    let concrete_trait_id = if is_fn_call {
+        concrete_fn_trait(semantic_db, expr.ty, args_ty, closure.ret_ty)
+    } else {
+        concrete_fn_once_trait(semantic_db, expr.ty, args_ty, closure.ret_ty)
+    };
+    let function = ctx.db.intern_function(semantic::FunctionLongId {
+        function: ConcreteFunction {
+            generic_function: GenericFunctionId::Impl(ImplGenericFunctionId {
+                impl_id: ImplId::Closure(concrete_trait_id),
+                function: trait_function,
+            }),
+            generic_args: vec![],
+        },
+    });
+    let signature = Signature::from_semantic(ctx.db, ctx.db.concrete_function_signature(function)?);
+
+    let lowering_builder = LoweringContextBuilder::new_inner(ctx.db, ctx.function_id, signature)?;
+    let mut closure_ctx = lowering_builder.ctx()?;
+    closure_ctx.semantic_defs = ctx.semantic_defs.clone();
+
+    // Initialize scope.
+    let root_block_id = alloc_empty_block(&mut closure_ctx);
+    let mut scope = BlockBuilder::root(&closure_ctx, root_block_id);
+
+    let parameters = closure_ctx
+        .signature
+        .params
+        .clone()
+        .into_iter()
+        .map(|param| closure_ctx.new_var(VarRequest { ty: param.ty(), location }))
+        .collect_vec();
+    let [self_var, args_var] = parameters[..] else {
+        unreachable!("Closure call functions have exactly two parameters.");
+    };
+
+    // Bind the captured variables and the closure parameters.
+    let closure_var = if is_fn_call {
+        generators::Desnap { input: self_var, location }
+            .add(&mut closure_ctx, &mut scope.statements)
+    } else {
+        self_var
+    };
+    let captured_vars = generators::StructDestructure {
+        input: closure_var,
+        var_reqs: closure
+            .captured_types
+            .iter()
+            .map(|ty| VarRequest { ty: *ty, location })
+            .collect(),
+    }
+    .add(&mut closure_ctx, &mut scope.statements);
+    for (var_id, var) in zip_eq(&expr.captured_vars, captured_vars) {
+        scope.put_semantic(*var_id, var);
+    }
+    let param_vars = generators::StructDestructure {
+        input: args_var,
+        var_reqs: expr
+            .params
+            .iter()
+            .map(|param| VarRequest {
+                ty: param.ty,
+                location: closure_ctx
+                    .get_location(param.stable_ptr(semantic_db.upcast()).untyped()),
+            })
+            .collect(),
+    }
+    .add(&mut closure_ctx, &mut scope.statements);
+    for (param, var) in zip_eq(&expr.params, param_vars) {
+        let param_id = semantic::VarId::Local(param.id);
+        scope.put_semantic(param_id, var);
+        closure_ctx.semantic_defs.insert(param_id, semantic::Variable::Local(param.clone()));
+    }
+
+    let stable_ptr = function_body.exprs[expr.body].stable_ptr().untyped();
+    let body_expr = lower_expr(&mut closure_ctx, &mut scope, expr.body);
+    let root_ok =
+        lowered_expr_to_block_scope_end(&mut closure_ctx, scope, body_expr).and_then(|sealed| {
+            wrap_sealed_block_as_function(&mut closure_ctx, sealed, stable_ptr)?;
+            Ok(root_block_id)
+        });
+    let blocks = root_ok
+        .map(|_| closure_ctx.blocks.build().expect("Root block must exist."))
+        .unwrap_or_else(FlatBlocks::new_errored);
+
+    // Functions generated inside the closure belong to the same semantic function.
+    for (key, lowered) in closure_ctx.generated_lowerings {
+        ctx.generated_lowerings.insert(key, lowered);
+    }
+    Ok(FlatLowered {
+        diagnostics: closure_ctx.diagnostics.build(),
+        variables: closure_ctx.variables,
+        blocks,
+        parameters,
+        signature: closure_ctx.signature.clone(),
+    })
+}
@@ -0,0 +1,496 @@
+use cairo_lang_debug::DebugWithDb;
+use cairo_lang_defs::diagnostic_utils::StableLocationOption;
+use cairo_lang_diagnostics::Maybe;
+use cairo_lang_semantic as semantic;
+use cairo_lang_semantic::corelib::{self, never_ty, unit_ty};
+use cairo_lang_utils::extract_matches;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use itertools::{zip_eq, Itertools};
+use semantic::{ExprFunctionCallArg, TypeLongId};
+
+use super::context::{
+    lowering_flow_error_to_sealed_block, LoweredExpr, LoweringContext, LoweringFlowError,
+    LoweringResult,
+};
+use super::scope::{BlockBuilder, SealedBlockBuilder};
+use super::{
+    alloc_empty_block, create_subscope_with_bound_refs, lower_expr, lower_expr_block,
+    lower_single_pattern, lowered_expr_to_block_scope_end, wrap_sealed_block_as_function,
+};
+use crate::blocks::FlatBlocks;
+use crate::ids::{FunctionLongId, GeneratedFunction, GeneratedFunctionKey, Signature};
+use crate::lower::context::{LoweringContextBuilder, VarRequest};
+use crate::lower::generators;
+use crate::{FlatLowered, MatchArm, MatchEnumInfo, MatchInfo, VariableId};
+
+/// The semantic variables a loop body uses from its enclosing scope.
+struct LoopCaptures {
+    /// The variables the body uses (reads or changes), in order of first appearance.
+    usage: OrderedHashSet<semantic::VarId>,
+    /// The variables the body changes (assigns to or passes as `ref`), in order of first
+    /// appearance.
+    changes: OrderedHashSet<semantic::VarId>,
+}
+impl LoopCaptures {
+    /// Collects the variables of the current scope that are used by the iterations of the given
+    /// loop.
+    fn from_loop(ctx: &LoweringContext<'_>, loop_expr_id: semantic::ExprId) -> Self {
+        let mut captures =
+            Self { usage: OrderedHashSet::default(), changes: OrderedHashSet::default() };
+        captures.handle_loop_iteration(ctx, loop_expr_id);
+        // Variables defined inside the body are not captures.
+        let is_defined = |var_id: &semantic::VarId| ctx.semantic_defs.contains_key(var_id);
+        captures.usage = captures.usage.into_iter().filter(is_defined).collect();
+        captures.changes = captures.changes.into_iter().filter(is_defined).collect();
+        captures
+    }
+
+    fn handle_expr(&mut self, ctx: &LoweringContext<'_>, expr_id: semantic::ExprId) {
+        match &ctx.function_body.exprs[expr_id] {
+            semantic::Expr::Tuple(expr) => {
+                for item in &expr.items {
+                    self.handle_expr(ctx, *item);
+                }
+            }
+            semantic::Expr::Snapshot(semantic::ExprSnapshot { inner, .. })
+            | semantic::Expr::Desnap(semantic::ExprDesnap { inner, .. })
+            | semantic::Expr::PropagateError(semantic::ExprPropagateError { inner, .. }) => {
+                self.handle_expr(ctx, *inner);
+            }
+            semantic::Expr::Assignment(expr) => {
+                self.handle_changed_path(&expr.ref_arg);
+                self.handle_expr(ctx, expr.rhs);
+            }
+            semantic::Expr::Block(expr) => {
+                for stmt_id in &expr.statements {
+                    match &ctx.function_body.statements[*stmt_id] {
+                        semantic::Statement::Expr(stmt) => self.handle_expr(ctx, stmt.expr),
+                        semantic::Statement::Let(stmt) => self.handle_expr(ctx, stmt.expr),
+                        semantic::Statement::Return(stmt) => self.handle_expr(ctx, stmt.expr),
+                        semantic::Statement::Break(stmt) => {
+                            if let Some(expr) = stmt.expr_option {
+                                self.handle_expr(ctx, expr);
+                            }
+                        }
+                        semantic::Statement::Continue(_) => {}
+                    }
+                }
+                if let Some(tail) = expr.tail {
+                    self.handle_expr(ctx, tail);
+                }
+            }
+            semantic::Expr::FunctionCall(expr) => {
+                for arg in &expr.args {
+                    match arg {
+                        ExprFunctionCallArg::Reference(member_path) => {
+                            self.handle_changed_path(member_path)
+                        }
+                        ExprFunctionCallArg::Value(expr) => self.handle_expr(ctx, *expr),
+                    }
+                }
+            }
+            semantic::Expr::Match(expr) => {
+                self.handle_expr(ctx, expr.matched_expr);
+                for arm in &expr.arms {
+                    self.handle_expr(ctx, arm.expression);
+                }
+            }
+            semantic::Expr::If(expr) => {
+                self.handle_expr(ctx, expr.condition);
+                self.handle_expr(ctx, expr.if_block);
+                if let Some(else_block) = expr.else_block {
+                    self.handle_expr(ctx, else_block);
+                }
+            }
+            semantic::Expr::Loop(_) | semantic::Expr::While(_) => {
+                self.handle_loop_iteration(ctx, expr_id)
+            }
+            semantic::Expr::For(expr) => {
+                self.handle_expr(ctx, expr.into_iter_call);
+                self.handle_loop_iteration(ctx, expr_id);
+            }
+            semantic::Expr::Closure(expr) => {
+                for var_id in &expr.captured_vars {
+                    self.usage.insert(*var_id);
+                }
+            }
+            semantic::Expr::Var(expr) => {
+                self.usage.insert(expr.var);
+            }
+            semantic::Expr::MemberAccess(expr) => self.handle_expr(ctx, expr.expr),
+            semantic::Expr::StructCtor(expr) => {
+                for (_, member_expr) in &expr.members {
+                    self.handle_expr(ctx, *member_expr);
+                }
+            }
+            semantic::Expr::EnumVariantCtor(expr) => self.handle_expr(ctx, expr.value_expr),
+            semantic::Expr::Literal(_)
+            | semantic::Expr::Constant(_)
+            | semantic::Expr::ImplConstant(_)
+            | semantic::Expr::Missing(_) => {}
+        }
+    }
+
+    /// Handles the parts of the given loop expression that are evaluated in each iteration.
+    fn handle_loop_iteration(&mut self, ctx: &LoweringContext<'_>, loop_expr_id: semantic::ExprId) {
+        match &ctx.function_body.exprs[loop_expr_id] {
+            semantic::Expr::Loop(expr) => self.handle_expr(ctx, expr.body),
+            semantic::Expr::While(expr) => {
+                self.handle_expr(ctx, expr.condition);
+                self.handle_expr(ctx, expr.body);
+            }
+            semantic::Expr::For(expr) => {
+                self.handle_expr(ctx, expr.next_call);
+                self.handle_expr(ctx, expr.body);
+            }
+            _ => unreachable!("Not a loop expression."),
+        }
+    }
+
+    fn handle_changed_path(&mut self, member_path: &semantic::VarMemberPath) {
+        let var = member_path.base_var();
+        self.usage.insert(var);
+        self.changes.insert(var);
+    }
+}
+
+/// Lowers a loop expression: an expression of type [semantic::ExprLoop], [semantic::ExprWhile] or
+/// [semantic::ExprFor].
+///
+/// The loop is lowered into a separate generated function, that gets the captured variables as
+/// parameters, returns the changed ones, and calls itself recursively to run the next iteration.
+pub fn lower_expr_loop(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    loop_expr_id: semantic::ExprId,
+) -> LoweringResult<LoweredExpr> {
+    let function_body = ctx.function_body;
+    let expr = &function_body.exprs[loop_expr_id];
+    log::trace!("Lowering a loop: {:?}", expr.debug(&ctx.expr_formatter));
+    let location = ctx.get_location(expr.stable_ptr().untyped());
+    let ty = expr.ty();
+
+    if let semantic::Expr::For(expr) = expr {
+        // The iterator is created once, before the first iteration.
+        let iterator_var = lower_expr(ctx, scope, expr.into_iter_call)?.var(ctx, scope)?;
+        let iterator = semantic::Variable::Local(expr.iterator.clone());
+        scope.put_semantic(iterator.id(), iterator_var);
+        ctx.semantic_defs.insert(iterator.id(), iterator);
+    }
+
+    let captures = LoopCaptures::from_loop(ctx, loop_expr_id);
+    let signature = Signature {
+        params: captures.usage.iter().map(|var_id| ctx.semantic_defs[*var_id].clone()).collect(),
+        extra_rets: captures
+            .changes
+            .iter()
+            .map(|var_id| ctx.semantic_defs[*var_id].clone())
+            .collect(),
+        return_type: ty,
+        implicits: vec![],
+        panicable: true,
+    };
+
+    let lowered = lower_loop_function(ctx, loop_expr_id, signature.clone())
+        .map_err(LoweringFlowError::Failed)?;
+    ctx.generated_lowerings.insert(GeneratedFunctionKey::Loop(loop_expr_id), lowered);
+
+    let result = call_loop_function(ctx, scope, &signature, loop_expr_id, location)?;
+    if ty == never_ty(ctx.db.upcast()) {
+        // The loop never ends, so the flow does not continue after it.
+        let concrete_enum_id = extract_matches!(
+            extract_matches!(ctx.db.lookup_intern_type(ty), TypeLongId::Concrete),
+            semantic::ConcreteTypeId::Enum
+        );
+        return Err(LoweringFlowError::Match(MatchInfo::Enum(MatchEnumInfo {
+            concrete_enum_id,
+            input: result,
+            arms: vec![],
+        })));
+    }
+    Ok(LoweredExpr::AtVariable(result))
+}
+
+/// Lowers the function generated for the loop with the given id.
+fn lower_loop_function(
+    ctx: &mut LoweringContext<'_>,
+    loop_expr_id: semantic::ExprId,
+    signature: Signature,
+) -> Maybe<FlatLowered> {
+    let lowering_builder = LoweringContextBuilder::new_inner(ctx.db, ctx.function_id, signature)?;
+    let mut loop_ctx = lowering_builder.ctx()?;
+    loop_ctx.semantic_defs = ctx.semantic_defs.clone();
+    loop_ctx.current_loop = Some(loop_expr_id);
+
+    // Initialize scope.
+    let root_block_id = alloc_empty_block(&mut loop_ctx);
+    let mut scope = BlockBuilder::root(&loop_ctx, root_block_id);
+
+    let parameters = loop_ctx
+        .signature
+        .params
+        .clone()
+        .into_iter()
+        .map(|param| {
+            let location = loop_ctx.get_location(param.stable_ptr(loop_ctx.db.upcast()).untyped());
+            let var = loop_ctx.new_var(VarRequest { ty: param.ty(), location });
+            scope.put_semantic(param.id(), var);
+            var
+        })
+        .collect_vec();
+
+    let function_body = loop_ctx.function_body;
+    let expr = &function_body.exprs[loop_expr_id];
+    let stable_ptr = expr.stable_ptr().untyped();
+    let iteration = match expr {
+        semantic::Expr::Loop(expr) => lower_loop_body(&mut loop_ctx, &mut scope, expr.body),
+        semantic::Expr::While(expr) => lower_while_iteration(&mut loop_ctx, &mut scope, expr),
+        semantic::Expr::For(expr) => lower_for_iteration(&mut loop_ctx, &mut scope, expr),
+        _ => unreachable!("Not a loop expression."),
+    };
+    let root_ok =
+        lowered_expr_to_block_scope_end(&mut loop_ctx, scope, iteration).and_then(|sealed| {
+            wrap_sealed_block_as_function(&mut loop_ctx, sealed, stable_ptr)?;
+            Ok(root_block_id)
+        });
+    let blocks = root_ok
+        .map(|_| loop_ctx.blocks.build().expect("Root block must exist."))
+        .unwrap_or_else(FlatBlocks::new_errored);
+
+    // Functions generated inside the loop belong to the same semantic function.
+    for (key, lowered) in loop_ctx.generated_lowerings {
+        ctx.generated_lowerings.insert(key, lowered);
+    }
+    Ok(FlatLowered {
+        diagnostics: loop_ctx.diagnostics.build(),
+        variables: loop_ctx.variables,
+        blocks,
+        parameters,
+        signature: loop_ctx.signature.clone(),
+    })
+}
+
+/// Lowers the body of a loop, followed by a recursive call running the next iteration.
+fn lower_loop_body(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    body: semantic::ExprId,
+) -> LoweringResult<LoweredExpr> {
+    let semantic_block = extract_matches!(&ctx.function_body.exprs[body], semantic::Expr::Block);
+    let location = ctx.get_location(semantic_block.stable_ptr.untyped());
+    let block_expr = lower_expr_block(ctx, scope, semantic_block)?;
+    // The LoweredExpr must be evaluated now to push/bring back variables in case it is
+    // LoweredExpr::ExternEnum.
+    if let LoweredExpr::ExternEnum(x) = block_expr {
+        x.var(ctx, scope)?;
+    }
+    // Reaching the end of the body continues to the next iteration.
+    recursively_call_loop_function(ctx, scope, location)
+}
+
+/// Lowers an iteration of a `while` loop: runs the body and continues to the next iteration if the
+/// condition holds, and ends the loop otherwise.
+fn lower_while_iteration(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    expr: &semantic::ExprWhile,
+) -> LoweringResult<LoweredExpr> {
+    let location = ctx.get_location(expr.stable_ptr.untyped());
+    let condition_var = lower_expr(ctx, scope, expr.condition)?.var(ctx, scope)?;
+    let semantic_db = ctx.db.upcast();
+    let unit_ty = unit_ty(semantic_db);
+
+    let subscope_main = create_subscope_with_bound_refs(ctx, scope);
+    let block_main_id = subscope_main.block_id;
+    let main_block_var_id = ctx.new_var(VarRequest { ty: unit_ty, location });
+    let block_main = lower_loop_body_in_subscope(ctx, subscope_main, expr.body)?;
+
+    let subscope_else = create_subscope_with_bound_refs(ctx, scope);
+    let block_else_id = subscope_else.block_id;
+    let else_block_var_id = ctx.new_var(VarRequest { ty: unit_ty, location });
+    let block_else = end_loop_in_subscope(ctx, subscope_else, else_block_var_id, location)?;
+
+    let match_info = MatchInfo::Enum(MatchEnumInfo {
+        concrete_enum_id: corelib::core_bool_enum(semantic_db),
+        input: condition_var,
+        arms: vec![
+            MatchArm {
+                variant_id: corelib::false_variant(semantic_db),
+                block_id: block_else_id,
+                var_ids: vec![else_block_var_id],
+            },
+            MatchArm {
+                variant_id: corelib::true_variant(semantic_db),
+                block_id: block_main_id,
+                var_ids: vec![main_block_var_id],
+            },
+        ],
+    });
+    scope.merge_and_end_with_match(ctx, match_info, vec![block_main, block_else], location)
+}
+
+/// Lowers an iteration of a `for` loop: takes the next item from the iterator, and either binds it
+/// to the pattern, runs the body and continues to the next iteration, or ends the loop if the
+/// iterator is exhausted.
+fn lower_for_iteration(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    expr: &semantic::ExprFor,
+) -> LoweringResult<LoweredExpr> {
+    let location = ctx.get_location(expr.stable_ptr.untyped());
+    let next_var = lower_expr(ctx, scope, expr.next_call)?.var(ctx, scope)?;
+    let semantic_db = ctx.db.upcast();
+    let next_ty = ctx.function_body.exprs[expr.next_call].ty();
+    // The semantic model makes sure `next` returns an `Option`.
+    let concrete_enum_id = extract_matches!(
+        extract_matches!(ctx.db.lookup_intern_type(next_ty), TypeLongId::Concrete),
+        semantic::ConcreteTypeId::Enum
+    );
+    let item_ty = ctx.db.lookup_intern_concrete_enum(concrete_enum_id).generic_args[0];
+    let some_variant = corelib::option_some_variant(semantic_db, item_ty);
+    let none_variant = corelib::option_none_variant(semantic_db, item_ty);
+
+    let mut subscope_some = create_subscope_with_bound_refs(ctx, scope);
+    let block_some_id = subscope_some.block_id;
+    let some_block_var_id = ctx.new_var(VarRequest {
+        ty: some_variant.ty,
+        location: ctx.get_location(expr.pattern.stable_ptr().untyped()),
+    });
+    let block_some = match lower_single_pattern(
+        ctx,
+        &mut subscope_some,
+        &expr.pattern,
+        LoweredExpr::AtVariable(some_block_var_id),
+    ) {
+        Ok(()) => lower_loop_body_in_subscope(ctx, subscope_some, expr.body)?,
+        Err(err) => lowering_flow_error_to_sealed_block(ctx, subscope_some, err)
+            .map_err(LoweringFlowError::Failed)?,
+    };
+
+    let subscope_none = create_subscope_with_bound_refs(ctx, scope);
+    let block_none_id = subscope_none.block_id;
+    let none_block_var_id = ctx.new_var(VarRequest { ty: unit_ty(semantic_db), location });
+    let block_none = end_loop_in_subscope(ctx, subscope_none, none_block_var_id, location)?;
+
+    let match_info = MatchInfo::Enum(MatchEnumInfo {
+        concrete_enum_id,
+        input: next_var,
+        arms: vec![
+            MatchArm {
+                variant_id: some_variant,
+                block_id: block_some_id,
+                var_ids: vec![some_block_var_id],
+            },
+            MatchArm {
+                variant_id: none_variant,
+                block_id: block_none_id,
+                var_ids: vec![none_block_var_id],
+            },
+        ],
+    });
+    scope.merge_and_end_with_match(ctx, match_info, vec![block_some, block_none], location)
+}
+
+/// Lowers the body of a loop in the given subscope, and seals it.
+fn lower_loop_body_in_subscope(
+    ctx: &mut LoweringContext<'_>,
+    mut subscope: BlockBuilder,
+    body: semantic::ExprId,
+) -> LoweringResult<SealedBlockBuilder> {
+    let body_expr = lower_loop_body(ctx, &mut subscope, body);
+    lowered_expr_to_block_scope_end(ctx, subscope, body_expr).map_err(LoweringFlowError::Failed)
+}
+
+/// Ends the loop in the given subscope, returning the given unit variable from the function
+/// generated for the loop, and seals the subscope.
+fn end_loop_in_subscope(
+    ctx: &mut LoweringContext<'_>,
+    subscope: BlockBuilder,
+    unit_var: VariableId,
+    location: StableLocationOption,
+) -> LoweringResult<SealedBlockBuilder> {
+    lowered_expr_to_block_scope_end(
+        ctx,
+        subscope,
+        Err(LoweringFlowError::Return(unit_var, location)),
+    )
+    .map_err(LoweringFlowError::Failed)
+}
+
+/// Adds a call to the function generated for the loop with the given id, and rebinds the
+/// variables changed by the loop. Returns the variable holding the value of the loop.
+fn call_loop_function(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    signature: &Signature,
+    loop_expr_id: semantic::ExprId,
+    location: StableLocationOption,
+) -> LoweringResult<VariableId> {
+    let parent =
+        semantic::ConcreteFunctionWithBodyId::from_generic(ctx.db.upcast(), ctx.function_id)
+            .map_err(LoweringFlowError::Failed)?;
+    let function = FunctionLongId::Generated(GeneratedFunction {
+        parent,
+        key: GeneratedFunctionKey::Loop(loop_expr_id),
+    })
+    .intern(ctx.db);
+    let inputs = signature
+        .params
+        .iter()
+        .map(|param| scope.get_semantic(ctx, param.id(), location))
+        .collect();
+    let call_result = generators::Call {
+        function,
+        inputs,
+        ref_tys: signature.extra_rets.iter().map(|var| var.ty()).collect(),
+        ret_tys: vec![signature.return_type],
+        location,
+    }
+    .add(ctx, &mut scope.statements);
+    for (var, output) in zip_eq(&signature.extra_rets, call_result.ref_outputs) {
+        scope.put_semantic(var.id(), output);
+    }
+    Ok(call_result.returns[0])
+}
+
+/// Adds a recursive call to the function generated for the current loop, running the next
+/// iteration of the loop.
+fn recursively_call_loop_function(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    location: StableLocationOption,
+) -> LoweringResult<LoweredExpr> {
+    let loop_expr_id = ctx.current_loop.expect("Loop control flow is only allowed inside a loop.");
+    let signature = ctx.signature;
+    Ok(LoweredExpr::AtVariable(call_loop_function(ctx, scope, signature, loop_expr_id, location)?))
+}
+
+/// Lowers a `continue` statement, by ending the current iteration and starting the next one.
+pub fn lower_continue(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    stmt: &semantic::StatementContinue,
+) -> Result<(), LoweringFlowError> {
+    log::trace!("Lowering a continue statement.");
+    let location = ctx.get_location(stmt.stable_ptr.untyped());
+    let ret_var = recursively_call_loop_function(ctx, scope, location)?.var(ctx, scope)?;
+    Err(LoweringFlowError::Return(ret_var, location))
+}
+
+/// Lowers a `break` statement, by returning from the function generated for the current loop.
+pub fn lower_break(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    stmt: &semantic::StatementBreak,
+) -> Result<(), LoweringFlowError> {
+    log::trace!("Lowering a break statement.");
+    let location = ctx.get_location(stmt.stable_ptr.untyped());
+    let ret_var = match stmt.expr_option {
+        Some(expr) => lower_expr(ctx, scope, expr)?.var(ctx, scope)?,
+        None => {
+            generators::StructConstruct { inputs: vec![], ty: unit_ty(ctx.db.upcast()), location }
+                .add(ctx, &mut scope.statements)
+        }
+    };
+    Err(LoweringFlowError::Return(ret_var, location))
+}
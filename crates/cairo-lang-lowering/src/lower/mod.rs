@@ -3,15 +3,19 @@ use cairo_lang_defs::diagnostic_utils::StableLocationOption;
 use cairo_lang_defs::ids::FunctionWithBodyId;
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_semantic as semantic;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use cairo_lang_utils::{extract_matches, try_extract_matches};
 use itertools::{chain, zip_eq, Itertools};
-use num_traits::Zero;
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
 use scope::BlockBuilder;
 use semantic::corelib::{
     core_felt252_is_zero, core_felt252_ty, core_nonzero_ty, get_core_function_id,
-    jump_nz_nonzero_variant, jump_nz_zero_variant, unit_ty,
+    get_core_ty_by_name, jump_nz_nonzero_variant, jump_nz_zero_variant, unit_ty,
 };
+use semantic::items::constant::ConstValue;
 use semantic::items::enm::SemanticEnumEx;
 use semantic::items::structure::SemanticStructEx;
 use semantic::types::{peel_snapshots, wrap_in_snapshots};
@@ -22,11 +26,15 @@ use self::context::{
     LoweringFlowError,
 };
 use self::external::{extern_facade_expr, extern_facade_return_tys};
+use self::generators::StatementsBuilder;
+use self::lower_closure::lower_expr_closure;
 use self::lower_if::lower_expr_if;
+use self::lower_loop::{lower_break, lower_continue, lower_expr_loop};
 use self::scope::SealedBlockBuilder;
 use crate::blocks::FlatBlocks;
 use crate::db::LoweringGroup;
 use crate::diagnostic::LoweringDiagnosticKind::*;
+use crate::ids::{GeneratedFunctionKey, SemanticFunctionIdEx};
 use crate::lower::context::{LoweringContextBuilder, LoweringResult, VarRequest};
 use crate::{
     BlockId, FlatLowered, MatchArm, MatchEnumInfo, MatchExternInfo, MatchInfo, VariableId,
@@ -35,32 +43,34 @@ pub mod generators;
 
 pub mod context;
 mod external;
+mod lower_closure;
 mod lower_if;
+mod lower_loop;
 pub mod refs;
 mod scope;
 
+/// A lowered function code using flat blocks, along with the lowered functions generated from it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultiLowering {
+    pub main_lowering: FlatLowered,
+    pub generated_lowerings: OrderedHashMap<GeneratedFunctionKey, FlatLowered>,
+}
+
 /// Lowers a semantic free function.
-pub fn lower(db: &dyn LoweringGroup, function_id: FunctionWithBodyId) -> Maybe<FlatLowered> {
+pub fn lower_semantic_function(
+    db: &dyn LoweringGroup,
+    function_id: FunctionWithBodyId,
+) -> Maybe<MultiLowering> {
     log::trace!("Lowering a free function.");
     let semantic_diagnostics_free = db
         .function_declaration_diagnostics(function_id)
         .is_diagnostic_free()
         .and_then(|()| db.function_body_diagnostics(function_id).is_diagnostic_free());
     let function_def = db.function_body(function_id)?;
-    let signature = db.function_with_body_signature(function_id)?;
-
-    // Params.
-    let input_semantic_vars: Vec<semantic::Variable> =
-        signature.params.iter().cloned().map(semantic::Variable::Param).collect();
 
     let lowering_builder = LoweringContextBuilder::new(db, function_id)?;
     let mut ctx = lowering_builder.ctx()?;
 
-    // TODO(spapini): Build semantic_defs in semantic model.
-    for semantic_var in input_semantic_vars {
-        ctx.semantic_defs.insert(semantic_var.id(), semantic_var);
-    }
-
     // Fetch body block expr.
     let semantic_block =
         extract_matches!(&function_def.exprs[function_def.body_expr], semantic::Expr::Block);
@@ -72,13 +82,14 @@ pub fn lower(db: &dyn LoweringGroup, function_id: FunctionWithBodyId) -> Maybe<F
     let parameters = ctx
         .signature
         .params
-        .iter()
-        .cloned()
+        .clone()
+        .into_iter()
         .map(|param| {
-            let location = ctx.get_location(param.stable_ptr.untyped());
-            let semantic = semantic::Variable::Param(param);
-            let var = ctx.new_var(VarRequest { ty: semantic.ty(), location });
-            scope.put_semantic(semantic.id(), var);
+            let location = ctx.get_location(param.stable_ptr(ctx.db.upcast()).untyped());
+            let var = ctx.new_var(VarRequest { ty: param.ty(), location });
+            scope.put_semantic(param.id(), var);
+            // TODO(spapini): Build semantic_defs in semantic model.
+            ctx.semantic_defs.insert(param.id(), param);
             var
         })
         .collect_vec();
@@ -86,34 +97,50 @@ pub fn lower(db: &dyn LoweringGroup, function_id: FunctionWithBodyId) -> Maybe<F
     let root_ok = semantic_diagnostics_free.and_then(|()| {
         let maybe_sealed_block = lower_block(&mut ctx, scope, semantic_block);
         maybe_sealed_block.and_then(|block_sealed| {
-            match block_sealed {
-                SealedBlockBuilder::GotoCallsite { mut scope, expr } => {
-                    // Convert to a return.
-                    let var = expr.unwrap_or_else(|| {
-                        generators::StructConstruct {
-                            inputs: vec![],
-                            ty: unit_ty(ctx.db.upcast()),
-                            location: ctx.get_location(semantic_block.stable_ptr.untyped()),
-                        }
-                        .add(&mut ctx, &mut scope.statements)
-                    });
-                    let location = ctx.get_location(semantic_block.stable_ptr.untyped());
-                    scope.ret(&mut ctx, var, location)?;
-                }
-                SealedBlockBuilder::Ends(_) => {}
-            }
+            wrap_sealed_block_as_function(
+                &mut ctx,
+                block_sealed,
+                semantic_block.stable_ptr.untyped(),
+            )?;
             Ok(root_block_id)
         })
     });
     let blocks = root_ok
         .map(|_| ctx.blocks.build().expect("Root block must exist."))
         .unwrap_or_else(FlatBlocks::new_errored);
-    Ok(FlatLowered {
+    let main_lowering = FlatLowered {
         diagnostics: ctx.diagnostics.build(),
         variables: ctx.variables,
         blocks,
         parameters,
-    })
+        signature: ctx.signature.clone(),
+    };
+    Ok(MultiLowering { main_lowering, generated_lowerings: ctx.generated_lowerings })
+}
+
+/// Converts the sealed root block of a function into a function body, returning the value the
+/// block flows out with.
+fn wrap_sealed_block_as_function(
+    ctx: &mut LoweringContext<'_>,
+    block_sealed: SealedBlockBuilder,
+    stable_ptr: SyntaxStablePtrId,
+) -> Maybe<()> {
+    match block_sealed {
+        SealedBlockBuilder::GotoCallsite { mut scope, expr } => {
+            // Convert to a return.
+            let location = ctx.get_location(stable_ptr);
+            let var = expr.unwrap_or_else(|| {
+                generators::StructConstruct {
+                    inputs: vec![],
+                    ty: unit_ty(ctx.db.upcast()),
+                    location,
+                }
+                .add(ctx, &mut scope.statements)
+            });
+            scope.ret(ctx, var, location)
+        }
+        SealedBlockBuilder::Ends(_) => Ok(()),
+    }
 }
 
 /// Lowers a semantic block.
@@ -215,6 +242,8 @@ pub fn lower_statement(
             let ret_var = lower_expr(ctx, scope, *expr)?.var(ctx, scope)?;
             return Err(LoweringFlowError::Return(ret_var, ctx.get_location(stable_ptr.untyped())));
         }
+        semantic::Statement::Continue(stmt) => lower_continue(ctx, scope, stmt)?,
+        semantic::Statement::Break(stmt) => lower_break(ctx, scope, stmt)?,
     }
     Ok(())
 }
@@ -321,6 +350,7 @@ fn lower_expr(
     let expr = &ctx.function_body.exprs[expr_id];
     match expr {
         semantic::Expr::Constant(expr) => lower_expr_constant(ctx, expr, scope),
+        semantic::Expr::ImplConstant(expr) => lower_expr_impl_constant(ctx, expr, scope),
         semantic::Expr::Tuple(expr) => lower_expr_tuple(ctx, expr, scope),
         semantic::Expr::Snapshot(expr) => lower_expr_snapshot(ctx, expr, scope),
         semantic::Expr::Desnap(expr) => lower_expr_desnap(ctx, expr, scope),
@@ -329,6 +359,10 @@ fn lower_expr(
         semantic::Expr::FunctionCall(expr) => lower_expr_function_call(ctx, expr, scope),
         semantic::Expr::Match(expr) => lower_expr_match(ctx, expr, scope),
         semantic::Expr::If(expr) => lower_expr_if(ctx, scope, expr),
+        semantic::Expr::Loop(_) | semantic::Expr::While(_) | semantic::Expr::For(_) => {
+            lower_expr_loop(ctx, scope, expr_id)
+        }
+        semantic::Expr::Closure(_) => lower_expr_closure(ctx, scope, expr_id),
         semantic::Expr::Var(expr) => {
             log::trace!("Lowering a variable: {:?}", expr.debug(&ctx.expr_formatter));
             Ok(LoweredExpr::SemanticVar(expr.var, ctx.get_location(expr.stable_ptr.untyped())))
@@ -351,10 +385,35 @@ fn lower_expr_literal(
 ) -> LoweringResult<LoweredExpr> {
     log::trace!("Lowering a literal: {:?}", expr.debug(&ctx.expr_formatter));
     let location = ctx.get_location(expr.stable_ptr.untyped());
-    Ok(LoweredExpr::AtVariable(
-        generators::Literal { value: expr.value.clone(), ty: expr.ty, location }
-            .add(ctx, &mut scope.statements),
-    ))
+    Ok(LoweredExpr::AtVariable(lower_literal_value(
+        ctx,
+        &mut scope.statements,
+        expr.value.clone(),
+        expr.ty,
+        location,
+    )))
+}
+
+/// Lowers a numeric value of the given type into a variable.
+/// `u256` values are constructed from their `u128` low and high parts.
+fn lower_literal_value(
+    ctx: &mut LoweringContext<'_>,
+    statements: &mut StatementsBuilder,
+    value: BigInt,
+    ty: semantic::TypeId,
+    location: StableLocationOption,
+) -> VariableId {
+    let semantic_db = ctx.db.upcast();
+    if ty != get_core_ty_by_name(semantic_db, "u256".into(), vec![]) {
+        return generators::Literal { value, ty, location }.add(ctx, statements);
+    }
+    let u128_ty = get_core_ty_by_name(semantic_db, "u128".into(), vec![]);
+    let mask128 = (BigInt::one() << 128) - 1;
+    let inputs = [&value & &mask128, value >> 128]
+        .into_iter()
+        .map(|value| generators::Literal { value, ty: u128_ty, location }.add(ctx, statements))
+        .collect();
+    generators::StructConstruct { inputs, ty, location }.add(ctx, statements)
 }
 
 fn lower_expr_constant(
@@ -363,12 +422,57 @@ fn lower_expr_constant(
     scope: &mut BlockBuilder,
 ) -> LoweringResult<LoweredExpr> {
     log::trace!("Lowering a constant: {:?}", expr.debug(&ctx.expr_formatter));
-    let const_expr =
-        &ctx.db.constant_semantic_data(expr.constant_id).map_err(LoweringFlowError::Failed)?.value;
-    let semantic::Expr::Literal(const_expr_literal) = const_expr else {
-        panic!("Only literal constants are supported.");
-    };
-    lower_expr_literal(ctx, const_expr_literal, scope)
+    let location = ctx.get_location(expr.stable_ptr.untyped());
+    let value =
+        ctx.db.constant_semantic_data(expr.constant_id).map_err(LoweringFlowError::Failed)?.value;
+    Ok(LoweredExpr::AtVariable(lower_const_value(ctx, &mut scope.statements, value, location)?))
+}
+
+fn lower_expr_impl_constant(
+    ctx: &mut LoweringContext<'_>,
+    expr: &semantic::ExprImplConstant,
+    scope: &mut BlockBuilder,
+) -> LoweringResult<LoweredExpr> {
+    log::trace!("Lowering an impl constant: {:?}", expr.debug(&ctx.expr_formatter));
+    let location = ctx.get_location(expr.stable_ptr.untyped());
+    let value = ConstValue::ImplConstant(expr.impl_constant_id);
+    Ok(LoweredExpr::AtVariable(lower_const_value(ctx, &mut scope.statements, value, location)?))
+}
+
+/// Lowers a compile time evaluated constant value into a variable.
+/// Associated constants of impls that are not concrete yet are lowered into a
+/// [crate::StatementConst], which is expanded when the function is concretized.
+pub fn lower_const_value(
+    ctx: &mut LoweringContext<'_>,
+    statements: &mut StatementsBuilder,
+    value: ConstValue,
+    location: StableLocationOption,
+) -> LoweringResult<VariableId> {
+    Ok(match value {
+        ConstValue::Int(value, ty) => lower_literal_value(ctx, statements, value, ty, location),
+        ConstValue::Struct(members, ty) => {
+            let inputs = members
+                .into_iter()
+                .map(|member| lower_const_value(ctx, statements, member, location))
+                .collect::<LoweringResult<Vec<_>>>()?;
+            generators::StructConstruct { inputs, ty, location }.add(ctx, statements)
+        }
+        ConstValue::Enum(variant, value) => {
+            let input = lower_const_value(ctx, statements, *value, location)?;
+            generators::EnumConstruct { input, variant, location }.add(ctx, statements)
+        }
+        ConstValue::ImplConstant(impl_constant_id) => {
+            let semantic_db = ctx.db.upcast();
+            match impl_constant_id.concrete_value(semantic_db).map_err(LoweringFlowError::Failed)? {
+                Some(value) => return lower_const_value(ctx, statements, value, location),
+                None => {
+                    let ty = impl_constant_id.ty(semantic_db).map_err(LoweringFlowError::Failed)?;
+                    generators::Const { value, ty, location }.add(ctx, statements)
+                }
+            }
+        }
+        ConstValue::Missing(diag_added) => return Err(LoweringFlowError::Failed(diag_added)),
+    })
 }
 
 /// Lowers an expression of type [semantic::ExprTuple].
@@ -484,17 +588,23 @@ fn perform_function_call(
 ) -> Result<(Vec<VariableId>, LoweredExpr), LoweringFlowError> {
     // If the function is not extern, simply call it.
     if function.try_get_extern_function_id(ctx.db.upcast()).is_none() {
-        let call_result =
-            generators::Call { function, inputs, ref_tys, ret_tys: vec![ret_ty], location }
-                .add(ctx, &mut scope.statements);
+        let call_result = generators::Call {
+            function: function.lowered(ctx.db),
+            inputs,
+            ref_tys,
+            ret_tys: vec![ret_ty],
+            location,
+        }
+        .add(ctx, &mut scope.statements);
         let res = LoweredExpr::AtVariable(call_result.returns.into_iter().next().unwrap());
         return Ok((call_result.ref_outputs, res));
     };
 
     // Extern function.
     let ret_tys = extern_facade_return_tys(ctx, ret_ty);
-    let call_result = generators::Call { function, inputs, ref_tys, ret_tys, location }
-        .add(ctx, &mut scope.statements);
+    let call_result =
+        generators::Call { function: function.lowered(ctx.db), inputs, ref_tys, ret_tys, location }
+            .add(ctx, &mut scope.statements);
     Ok((call_result.ref_outputs, extern_facade_expr(ctx, ret_ty, call_result.returns, location)))
 }
 
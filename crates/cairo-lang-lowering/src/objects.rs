@@ -15,10 +15,12 @@ use num_bigint::BigInt;
 pub mod blocks;
 pub use blocks::BlockId;
 use semantic::expr::inference::InferenceResult;
+use semantic::items::constant::ConstValue;
 use semantic::items::imp::ImplId;
 
 use self::blocks::FlatBlocks;
 use crate::diagnostic::LoweringDiagnostic;
+use crate::ids::{FunctionId, Signature};
 
 pub type VariableId = Id<Variable>;
 
@@ -27,6 +29,8 @@ pub type VariableId = Id<Variable>;
 pub struct FlatLowered {
     /// Diagnostics produced while lowering.
     pub diagnostics: Diagnostics<LoweringDiagnostic>,
+    /// Function signature.
+    pub signature: Signature,
     /// Arena of allocated lowered variables.
     pub variables: Arena<Variable>,
     /// Arena of allocated lowered blocks.
@@ -113,8 +117,8 @@ pub struct Variable {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Statement {
     // Values.
-    // TODO(spapini): Consts.
     Literal(StatementLiteral),
+    Const(StatementConst),
 
     // Flow control.
     Call(StatementCall),
@@ -133,6 +137,7 @@ impl Statement {
     pub fn inputs(&self) -> Vec<VariableId> {
         match &self {
             Statement::Literal(_stmt) => vec![],
+            Statement::Const(_stmt) => vec![],
             Statement::Call(stmt) => stmt.inputs.clone(),
             Statement::StructConstruct(stmt) => stmt.inputs.clone(),
             Statement::StructDestructure(stmt) => vec![stmt.input],
@@ -144,6 +149,7 @@ impl Statement {
     pub fn outputs(&self) -> Vec<VariableId> {
         match &self {
             Statement::Literal(stmt) => vec![stmt.output],
+            Statement::Const(stmt) => vec![stmt.output],
             Statement::Call(stmt) => stmt.outputs.clone(),
             Statement::StructConstruct(stmt) => vec![stmt.output],
             Statement::StructDestructure(stmt) => stmt.outputs.clone(),
@@ -163,11 +169,22 @@ pub struct StatementLiteral {
     pub output: VariableId,
 }
 
+/// A statement that binds a compile time constant value to a variable.
+/// The value may depend on generic impls (e.g. an associated constant of a generic impl), and is
+/// expanded into simpler statements when the function is concretized.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatementConst {
+    /// The value of the constant.
+    pub value: ConstValue,
+    /// The variable to bind the value to.
+    pub output: VariableId,
+}
+
 /// A statement that calls a user function.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct StatementCall {
     /// A function to "call".
-    pub function: semantic::FunctionId,
+    pub function: FunctionId,
     /// Living variables in current scope to move to the function, as arguments.
     pub inputs: Vec<VariableId>,
     /// New variables to be introduced into the current scope from the function outputs.
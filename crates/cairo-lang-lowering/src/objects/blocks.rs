@@ -97,6 +97,13 @@ impl<T: Default> Blocks<T> {
     pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
         self.0.iter_mut()
     }
+
+    /// Adds a block, and returns its ID.
+    pub fn push(&mut self, block: T) -> BlockId {
+        let id = BlockId(self.0.len());
+        self.0.push(block);
+        id
+    }
 }
 impl<T> Index<BlockId> for Blocks<T> {
     type Output = T;
@@ -5,13 +5,11 @@ use cairo_lang_semantic as semantic;
 use cairo_lang_semantic::corelib::{get_core_enum_concrete_variant, get_panic_ty};
 use cairo_lang_semantic::GenericArgumentId;
 use itertools::{chain, zip_eq, Itertools};
-use semantic::items::functions::{
-    ConcreteFunctionWithBody, GenericFunctionId, GenericFunctionWithBodyId,
-};
-use semantic::{ConcreteFunctionWithBodyId, ConcreteVariant, Mutability, Signature, TypeId};
+use semantic::{ConcreteVariant, TypeId};
 
 use crate::blocks::FlatBlocksBuilder;
 use crate::db::LoweringGroup;
+use crate::ids::{ConcreteFunctionWithBodyId, FunctionId, Signature};
 use crate::lower::context::{LoweringContext, LoweringContextBuilder, VarRequest};
 use crate::{
     BlockId, FlatBlock, FlatBlockEnd, FlatLowered, MatchArm, MatchEnumInfo, MatchInfo, Statement,
@@ -40,6 +38,7 @@ pub fn lower_panics(
             variables: ctx.variables,
             blocks: lowered.blocks.clone(),
             parameters: lowered.parameters.clone(),
+            signature: lowered.signature.clone(),
         });
     }
 
@@ -61,6 +60,7 @@ pub fn lower_panics(
         variables: ctx.ctx.variables,
         blocks: ctx.flat_blocks.build().unwrap(),
         parameters: lowered.parameters.clone(),
+        signature: lowered.signature.clone(),
     })
 }
 
@@ -103,11 +103,7 @@ pub struct PanicSignatureInfo {
 }
 impl PanicSignatureInfo {
     pub fn new(db: &dyn LoweringGroup, signature: &Signature) -> Self {
-        let refs = signature
-            .params
-            .iter()
-            .filter(|param| matches!(param.mutability, Mutability::Reference))
-            .map(|param| param.ty);
+        let refs = signature.extra_rets.iter().map(|param| param.ty());
         let original_return_ty = signature.return_type;
 
         let ok_ret_tys = chain!(refs, [original_return_ty]).collect_vec();
@@ -166,8 +162,7 @@ impl<'a> PanicBlockLoweringContext<'a> {
     /// creates this second block, and returns it.
     fn handle_statement(&mut self, stmt: &Statement) -> Maybe<Option<(BlockId, FlatBlockEnd)>> {
         if let Statement::Call(call) = &stmt {
-            let concrete_function = self.db().lookup_intern_function(call.function).function;
-            if let Some(with_body) = concrete_function.get_body(self.db().upcast())? {
+            if let Some(with_body) = call.function.body(self.db())? {
                 if self.db().concrete_function_with_body_may_panic(with_body)? {
                     return Ok(Some(self.handle_call_panic(call)?));
                 }
@@ -186,7 +181,7 @@ impl<'a> PanicBlockLoweringContext<'a> {
         let location = self.ctx.ctx.variables[original_outputs[0]].location;
 
         // Get callee info.
-        let callee_signature = self.ctx.ctx.db.concrete_function_signature(call.function)?;
+        let callee_signature = call.function.signature(self.ctx.ctx.db)?;
         let callee_info = PanicSignatureInfo::new(self.ctx.ctx.db, &callee_signature);
 
         // Allocate 2 new variables.
@@ -307,33 +302,10 @@ impl<'a> PanicBlockLoweringContext<'a> {
 // ============= Query implementations =============
 
 /// Query implementation of [crate::db::LoweringGroup::function_may_panic].
-pub fn function_may_panic(db: &dyn LoweringGroup, function: semantic::FunctionId) -> Maybe<bool> {
-    let concrete_function = function.get_concrete(db.upcast());
-    match concrete_function.generic_function {
-        GenericFunctionId::Free(free_function) => {
-            let concrete_with_body =
-                db.intern_concrete_function_with_body(ConcreteFunctionWithBody {
-                    generic_function: GenericFunctionWithBodyId::Free(free_function),
-                    generic_args: concrete_function.generic_args,
-                });
-            db.concrete_function_with_body_may_panic(concrete_with_body)
-        }
-        GenericFunctionId::Impl(impl_generic_function) => {
-            let Some(generic_with_body) =
-            impl_generic_function.to_generic_with_body(db.upcast())?
-            else {
-                unreachable!();
-            };
-            let concrete_with_body =
-                db.intern_concrete_function_with_body(ConcreteFunctionWithBody {
-                    generic_function: generic_with_body,
-                    generic_args: concrete_function.generic_args,
-                });
-            db.concrete_function_with_body_may_panic(concrete_with_body)
-        }
-        GenericFunctionId::Extern(extern_function) => {
-            Ok(db.extern_function_signature(extern_function)?.panicable)
-        }
+pub fn function_may_panic(db: &dyn LoweringGroup, function: FunctionId) -> Maybe<bool> {
+    match function.body(db)? {
+        Some(body) => db.concrete_function_with_body_may_panic(body),
+        None => Ok(function.signature(db)?.panicable),
     }
 }
 
@@ -352,35 +324,16 @@ pub fn concrete_function_with_body_may_panic(
     // For each direct callee, find if it may panic.
     let direct_callees = db.concrete_function_with_body_direct_callees(function)?;
     for direct_callee in direct_callees {
-        let generic_function = direct_callee.generic_function;
-        // For a function with a body, call this method recursively. To avoid cycles, first
-        // check that the callee is not in this function's SCC.
-        let generic_with_body = match generic_function {
-            GenericFunctionId::Free(free_function) => {
-                GenericFunctionWithBodyId::Free(free_function)
-            }
-            GenericFunctionId::Impl(impl_generic_function) => {
-                if let Some(generic_with_body) =
-                    impl_generic_function.to_generic_with_body(db.upcast())?
-                {
-                    generic_with_body
-                } else {
-                    unreachable!()
-                }
-            }
-            GenericFunctionId::Extern(extern_function) => {
-                if db.extern_function_signature(extern_function)?.panicable {
-                    return Ok(true);
-                }
-                continue;
+        let Some(callee_body) = direct_callee.body(db)? else {
+            if direct_callee.signature(db)?.panicable {
+                return Ok(true);
             }
+            continue;
         };
-        let concrete_with_body = db.intern_concrete_function_with_body(ConcreteFunctionWithBody {
-            generic_function: generic_with_body,
-            generic_args: direct_callee.generic_args,
-        });
+        // For a function with a body, call this method recursively. To avoid cycles, first
+        // check that the callee is not in this function's SCC.
         let direct_callee_representative =
-            db.concrete_function_with_body_scc_representative(concrete_with_body);
+            db.concrete_function_with_body_scc_representative(callee_body);
         if direct_callee_representative == scc_representative {
             // We already know this SCC may not panic - do nothing.
             continue;
@@ -8,6 +8,7 @@ use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 
 use crate::db::LoweringGroup;
 use crate::fmt::LoweredFormatter;
+use crate::ids::{GeneratedFunction, SemanticConcreteFunctionWithBodyIdEx};
 use crate::implicits::lower_implicits;
 use crate::inline::apply_inlining;
 use crate::optimizations::match_optimizer::optimize_matches;
@@ -24,6 +25,7 @@ cairo_lang_test_utils::test_file_test!(
         assignment :"assignment",
         borrow_check :"borrow_check",
         call :"call",
+        closure :"closure",
         constant :"constant",
         destruct :"destruct",
         enums :"enums",
@@ -32,6 +34,7 @@ cairo_lang_test_utils::test_file_test!(
         extern_ :"extern",
         arm_pattern_destructure :"arm_pattern_destructure",
         if_ :"if",
+        loop_ :"loop",
         match_ :"match",
         members :"members",
         panic :"panic",
@@ -65,8 +68,9 @@ fn test_function_lowering(
         inputs["module_code"].as_str(),
     )
     .split();
-    let lowered =
-        db.concrete_function_with_body_lowered(test_function.concrete_function_id).unwrap();
+    let lowered = db
+        .concrete_function_with_body_lowered(test_function.concrete_function_id.lowered(db))
+        .unwrap();
     assert!(
         lowered.blocks.iter().all(|(_, b)| b.is_set()),
         "There should not be any unset flat blocks"
@@ -74,10 +78,27 @@ fn test_function_lowering(
     let diagnostics = db.module_lowering_diagnostics(test_function.module_id).unwrap();
 
     let lowered_formatter = LoweredFormatter { db, variables: &lowered.variables };
+    let mut lowering_flat = format!("{:?}", lowered.debug(&lowered_formatter));
+    // Add the functions generated from the test function (e.g. for loops).
+    if let Ok(multi_lowering) = db.priv_function_with_body_multi_lowering(test_function.function_id)
+    {
+        for key in multi_lowering.generated_lowerings.keys() {
+            let generated_function =
+                GeneratedFunction { parent: test_function.concrete_function_id, key: *key };
+            let lowered =
+                db.concrete_function_with_body_lowered(generated_function.body(db)).unwrap();
+            let lowered_formatter = LoweredFormatter { db, variables: &lowered.variables };
+            lowering_flat += &format!(
+                "\n\nGenerated {}:\n{:?}",
+                generated_function.name(db),
+                lowered.debug(&lowered_formatter)
+            );
+        }
+    }
     OrderedHashMap::from([
         ("semantic_diagnostics".into(), semantic_diagnostics),
         ("lowering_diagnostics".into(), diagnostics.format(db)),
-        ("lowering_flat".into(), format!("{:?}", lowered.debug(&lowered_formatter))),
+        ("lowering_flat".into(), lowering_flat),
     ])
 }
 
@@ -96,7 +117,7 @@ fn test_function_lowering_phases(
         inputs["module_code"].as_str(),
     )
     .split();
-    let concrete_function = test_function.concrete_function_id;
+    let concrete_function = test_function.concrete_function_id.lowered(db);
 
     let before_all = db.priv_concrete_function_with_body_lowered_flat(concrete_function).unwrap();
     assert!(
@@ -2,9 +2,9 @@ use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 
 use crate::{
     BlockId, FlatBlock, FlatBlockEnd, MatchArm, MatchEnumInfo, MatchExternInfo, MatchInfo,
-    Statement, StatementCall, StatementDesnap, StatementEnumConstruct, StatementLiteral,
-    StatementSnapshot, StatementStructConstruct, StatementStructDestructure, VarRemapping,
-    VariableId,
+    Statement, StatementCall, StatementConst, StatementDesnap, StatementEnumConstruct,
+    StatementLiteral, StatementSnapshot, StatementStructConstruct, StatementStructDestructure,
+    VarRemapping, VariableId,
 };
 
 /// A rebuilder trait for rebuilding lowered representation.
@@ -25,6 +25,10 @@ pub trait RebuilderEx: Rebuilder {
                 value: stmt.value.clone(),
                 output: self.map_var_id(stmt.output),
             }),
+            Statement::Const(stmt) => Statement::Const(StatementConst {
+                value: stmt.value.clone(),
+                output: self.map_var_id(stmt.output),
+            }),
             Statement::Call(stmt) => Statement::Call(StatementCall {
                 function: stmt.function,
                 inputs: stmt.inputs.iter().map(|v| self.map_var_id(*v)).collect(),
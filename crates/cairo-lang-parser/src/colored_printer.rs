@@ -82,6 +82,11 @@ fn set_color(text: SmolStr, kind: SyntaxKind) -> ColoredString {
         | SyntaxKind::TokenReturn
         | SyntaxKind::TokenMatch
         | SyntaxKind::TokenIf
+        | SyntaxKind::TokenLoop
+        | SyntaxKind::TokenWhile
+        | SyntaxKind::TokenFor
+        | SyntaxKind::TokenContinue
+        | SyntaxKind::TokenBreak
         | SyntaxKind::TokenElse
         | SyntaxKind::TokenUse
         | SyntaxKind::TokenImplicits
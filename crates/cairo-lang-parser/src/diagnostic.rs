@@ -20,6 +20,7 @@ pub enum ParserDiagnosticKind {
     MissingPathSegment,
     MissingTypeClause,
     MissingTypeExpression,
+    MissingInKeyword,
     ReservedIdentifier { identifier: SmolStr },
     UnderscoreNotAllowedAsIdentifier,
 }
@@ -46,6 +47,7 @@ impl DiagnosticEntry for ParserDiagnostic {
             ParserDiagnosticKind::MissingTypeExpression => {
                 "Missing tokens. Expected a type expression.".to_string()
             }
+            ParserDiagnosticKind::MissingInKeyword => "Missing keyword 'in'.".to_string(),
             ParserDiagnosticKind::ReservedIdentifier { ref identifier } => {
                 format!("'{identifier}' is a reserved identifier.")
             }
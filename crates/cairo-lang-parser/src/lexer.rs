@@ -177,6 +177,11 @@ impl<'a> Lexer<'a> {
             "return" => TokenKind::Return,
             "match" => TokenKind::Match,
             "if" => TokenKind::If,
+            "loop" => TokenKind::Loop,
+            "while" => TokenKind::While,
+            "for" => TokenKind::For,
+            "continue" => TokenKind::Continue,
+            "break" => TokenKind::Break,
             "else" => TokenKind::Else,
             "use" => TokenKind::Use,
             "implicits" => TokenKind::Implicits,
@@ -331,6 +336,11 @@ enum TokenKind {
     Return,
     Match,
     If,
+    Loop,
+    While,
+    For,
+    Continue,
+    Break,
     Else,
     Use,
     Implicits,
@@ -410,6 +420,11 @@ fn token_kind_to_terminal_syntax_kind(kind: TokenKind) -> SyntaxKind {
         TokenKind::Return => SyntaxKind::TerminalReturn,
         TokenKind::Match => SyntaxKind::TerminalMatch,
         TokenKind::If => SyntaxKind::TerminalIf,
+        TokenKind::Loop => SyntaxKind::TerminalLoop,
+        TokenKind::While => SyntaxKind::TerminalWhile,
+        TokenKind::For => SyntaxKind::TerminalFor,
+        TokenKind::Continue => SyntaxKind::TerminalContinue,
+        TokenKind::Break => SyntaxKind::TerminalBreak,
         TokenKind::Else => SyntaxKind::TerminalElse,
         TokenKind::Use => SyntaxKind::TerminalUse,
         TokenKind::Implicits => SyntaxKind::TerminalImplicits,
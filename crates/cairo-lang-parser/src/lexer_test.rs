@@ -43,6 +43,11 @@ fn terminal_kind_to_text(kind: SyntaxKind) -> Vec<&'static str> {
         SyntaxKind::TerminalReturn => vec!["return"],
         SyntaxKind::TerminalMatch => vec!["match"],
         SyntaxKind::TerminalIf => vec!["if"],
+        SyntaxKind::TerminalLoop => vec!["loop"],
+        SyntaxKind::TerminalWhile => vec!["while"],
+        SyntaxKind::TerminalFor => vec!["for"],
+        SyntaxKind::TerminalContinue => vec!["continue"],
+        SyntaxKind::TerminalBreak => vec!["break"],
         SyntaxKind::TerminalElse => vec!["else"],
         SyntaxKind::TerminalUse => vec!["use"],
         SyntaxKind::TerminalAnd => vec!["&"],
@@ -115,6 +120,11 @@ fn terminal_kinds() -> Vec<SyntaxKind> {
         SyntaxKind::TerminalReturn,
         SyntaxKind::TerminalMatch,
         SyntaxKind::TerminalIf,
+        SyntaxKind::TerminalLoop,
+        SyntaxKind::TerminalWhile,
+        SyntaxKind::TerminalFor,
+        SyntaxKind::TerminalContinue,
+        SyntaxKind::TerminalBreak,
         SyntaxKind::TerminalElse,
         SyntaxKind::TerminalUse,
         SyntaxKind::TerminalAnd,
@@ -25,12 +25,13 @@ pub fn get_post_operator_precedence(kind: SyntaxKind) -> Option<usize> {
         SyntaxKind::TerminalAnd => Some(6),
         SyntaxKind::TerminalOr => Some(7),
         SyntaxKind::TerminalXor => Some(8),
+        SyntaxKind::TerminalDotDot => Some(9),
         SyntaxKind::TerminalEq
         | SyntaxKind::TerminalPlusEq
         | SyntaxKind::TerminalMinusEq
         | SyntaxKind::TerminalMulEq
         | SyntaxKind::TerminalDivEq
-        | SyntaxKind::TerminalModEq => Some(9),
+        | SyntaxKind::TerminalModEq => Some(10),
         _ => None,
     }
 }
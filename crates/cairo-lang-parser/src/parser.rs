@@ -55,7 +55,7 @@ pub struct Parser<'a> {
 // never a missing kind.
 // Should only be called after checking the current token.
 
-const MAX_PRECEDENCE: usize = 10;
+const MAX_PRECEDENCE: usize = 11;
 impl<'a> Parser<'a> {
     /// Parses a file.
     pub fn parse_file(
@@ -471,6 +471,8 @@ impl<'a> Parser<'a> {
 
         match self.peek().kind {
             SyntaxKind::TerminalFunction => Some(self.expect_trait_function(attributes).into()),
+            SyntaxKind::TerminalType => Some(self.expect_trait_type(attributes).into()),
+            SyntaxKind::TerminalConst => Some(self.expect_trait_constant(attributes).into()),
             _ => None,
         }
     }
@@ -487,13 +489,34 @@ impl<'a> Parser<'a> {
         TraitItemFunction::new_green(self.db, attributes, declaration, body)
     }
 
+    /// Assumes the current token is Type.
+    /// Expected pattern: `type <Identifier>;`
+    fn expect_trait_type(&mut self, attributes: AttributeListGreen) -> TraitItemTypeGreen {
+        let type_kw = self.take::<TerminalType>();
+        let name = self.parse_identifier();
+        let semicolon = self.parse_token::<TerminalSemicolon>();
+        TraitItemType::new_green(self.db, attributes, type_kw, name, semicolon)
+    }
+
+    /// Assumes the current token is Const.
+    /// Expected pattern: `const <Identifier><TypeClause>;`
+    fn expect_trait_constant(&mut self, attributes: AttributeListGreen) -> TraitItemConstantGreen {
+        let const_kw = self.take::<TerminalConst>();
+        let name = self.parse_identifier();
+        let type_clause = self.parse_type_clause(ErrorRecovery {
+            should_stop: is_of_kind!(semicolon, rbrace, top_level),
+        });
+        let semicolon = self.parse_token::<TerminalSemicolon>();
+        TraitItemConstant::new_green(self.db, attributes, const_kw, name, type_clause, semicolon)
+    }
+
     /// Assumes the current token is Impl.
     fn expect_impl(&mut self, attributes: AttributeListGreen) -> ItemImplGreen {
         let impl_kw = self.take::<TerminalImpl>();
         let name = self.parse_identifier();
         let generic_params = self.parse_optional_generic_params();
         let of_kw = self.parse_token::<TerminalOf>();
-        let trait_path = self.parse_path();
+        let trait_path = self.parse_type_path();
         let body = if self.peek().kind == SyntaxKind::TerminalLBrace {
             let lbrace = self.take::<TerminalLBrace>();
             let items = ItemList::new_green(
@@ -566,6 +589,7 @@ impl<'a> Parser<'a> {
                 SyntaxKind::TerminalAnd => self.take::<TerminalAnd>().into(),
                 SyntaxKind::TerminalOr => self.take::<TerminalOr>().into(),
                 SyntaxKind::TerminalXor => self.take::<TerminalXor>().into(),
+                SyntaxKind::TerminalDotDot => self.take::<TerminalDotDot>().into(),
                 _ => unreachable!(),
             })
         }
@@ -677,6 +701,18 @@ impl<'a> Parser<'a> {
             SyntaxKind::TerminalIf if lbrace_allowed == LbraceAllowed::Allow => {
                 Some(self.expect_if_expr().into())
             }
+            SyntaxKind::TerminalLoop if lbrace_allowed == LbraceAllowed::Allow => {
+                Some(self.expect_loop_expr().into())
+            }
+            SyntaxKind::TerminalWhile if lbrace_allowed == LbraceAllowed::Allow => {
+                Some(self.expect_while_expr().into())
+            }
+            SyntaxKind::TerminalFor if lbrace_allowed == LbraceAllowed::Allow => {
+                Some(self.expect_for_expr().into())
+            }
+            SyntaxKind::TerminalOr | SyntaxKind::TerminalOrOr => {
+                Some(self.expect_closure_expr().into())
+            }
             _ => {
                 // TODO(yuval): report to diagnostics.
                 None
@@ -959,6 +995,64 @@ impl<'a> Parser<'a> {
         ExprIf::new_green(self.db, if_kw, condition, if_block, else_clause)
     }
 
+    /// Assumes the current token is `Loop`.
+    /// Expected pattern: `loop <block>`.
+    fn expect_loop_expr(&mut self) -> ExprLoopGreen {
+        let loop_kw = self.take::<TerminalLoop>();
+        let body = self.parse_block();
+
+        ExprLoop::new_green(self.db, loop_kw, body)
+    }
+
+    /// Assumes the current token is `While`.
+    /// Expected pattern: `while <condition> <block>`.
+    fn expect_while_expr(&mut self) -> ExprWhileGreen {
+        let while_kw = self.take::<TerminalWhile>();
+        let condition = self.parse_expr_limited(MAX_PRECEDENCE, LbraceAllowed::Forbid);
+        let body = self.parse_block();
+
+        ExprWhile::new_green(self.db, while_kw, condition, body)
+    }
+
+    /// Assumes the current token is `For`.
+    /// Expected pattern: `for <pattern> in <expr> <block>`.
+    fn expect_for_expr(&mut self) -> ExprForGreen {
+        let for_kw = self.take::<TerminalFor>();
+        let pattern = self.parse_pattern();
+        // `in` is a contextual keyword, parsed as an identifier.
+        let identifier =
+            if self.peek().kind == SyntaxKind::TerminalIdentifier && self.peek().text == "in" {
+                self.take::<TerminalIdentifier>()
+            } else {
+                self.create_and_report_missing::<TerminalIdentifier>(
+                    ParserDiagnosticKind::MissingInKeyword,
+                )
+            };
+        let expr = self.parse_expr_limited(MAX_PRECEDENCE, LbraceAllowed::Forbid);
+        let body = self.parse_block();
+
+        ExprFor::new_green(self.db, for_kw, pattern, identifier, expr, body)
+    }
+
+    /// Assumes the current token is `|` or `||`.
+    /// Expected pattern: `\|<ClosureParamList>\| <expr>` or `|| <expr>`, where the expression may
+    /// be preceded by a return type clause, in which case it must be a block.
+    fn expect_closure_expr(&mut self) -> ExprClosureGreen {
+        let wrapper = if self.peek().kind == SyntaxKind::TerminalOrOr {
+            self.take::<TerminalOrOr>().into()
+        } else {
+            let leftor = self.take::<TerminalOr>();
+            let params = self.parse_closure_param_list();
+            let rightor = self.parse_token::<TerminalOr>();
+            ClosureParamWrapperNAry::new_green(self.db, leftor, params, rightor).into()
+        };
+        let has_ret_ty = self.peek().kind == SyntaxKind::TerminalArrow;
+        let ret_ty = self.parse_option_return_type_clause();
+        let expr = if has_ret_ty { self.parse_block().into() } else { self.parse_expr() };
+
+        ExprClosure::new_green(self.db, wrapper, ret_ty, expr)
+    }
+
     /// Returns a GreenId of a node with a MatchArm kind or None if a match arm can't be parsed.
     pub fn try_parse_match_arm(&mut self) -> Option<MatchArmGreen> {
         let pattern = self.try_parse_pattern()?;
@@ -1097,6 +1191,20 @@ impl<'a> Parser<'a> {
                 let semicolon = self.parse_token::<TerminalSemicolon>();
                 Some(StatementReturn::new_green(self.db, return_kw, expr, semicolon).into())
             }
+            SyntaxKind::TerminalContinue => {
+                let continue_kw = self.take::<TerminalContinue>();
+                let semicolon = self.parse_token::<TerminalSemicolon>();
+                Some(StatementContinue::new_green(self.db, continue_kw, semicolon).into())
+            }
+            SyntaxKind::TerminalBreak => {
+                let break_kw = self.take::<TerminalBreak>();
+                let expr_clause = match self.try_parse_expr() {
+                    Some(expr) => ExprClause::new_green(self.db, expr).into(),
+                    None => OptionExprClauseEmpty::new_green(self.db).into(),
+                };
+                let semicolon = self.parse_token::<TerminalSemicolon>();
+                Some(StatementBreak::new_green(self.db, break_kw, expr_clause, semicolon).into())
+            }
             _ => match self.try_parse_expr() {
                 None => None,
                 Some(expr) => {
@@ -1193,6 +1301,22 @@ impl<'a> Parser<'a> {
         )
     }
 
+    /// Returns a GreenId of a node with kind ClosureParamList.
+    fn parse_closure_param_list(&mut self) -> ClosureParamListGreen {
+        ClosureParamList::new_green(
+            self.db,
+            self.parse_separated_list::<
+                ClosureParam,
+                TerminalComma,
+                ClosureParamListElementOrSeparatorGreen,
+            >(
+                Self::try_parse_closure_param,
+                is_of_kind!(or, block, lbrace, rbrace, top_level),
+                "parameter",
+            ),
+        )
+    }
+
     /// Returns a GreenId of a node with kind Modifier or None if a modifier can't be parsed.
     fn try_parse_modifier(&mut self) -> Option<ModifierGreen> {
         match self.peek().kind {
@@ -1233,6 +1357,26 @@ impl<'a> Parser<'a> {
         ))
     }
 
+    /// Returns a GreenId of a node with kind ClosureParam or None if a closure parameter can't be
+    /// parsed. Unlike function parameters, the type clause of a closure parameter is optional.
+    fn try_parse_closure_param(&mut self) -> Option<ClosureParamGreen> {
+        let modifier_list = self.parse_modifier_list();
+        let name = if modifier_list.is_empty() {
+            self.try_parse_identifier()?
+        } else {
+            // If we had modifiers then the identifier is not optional and can't be '_'.
+            self.parse_identifier()
+        };
+
+        let type_clause = self.parse_option_type_clause();
+        Some(ClosureParam::new_green(
+            self.db,
+            ModifierList::new_green(self.db, modifier_list),
+            name,
+            type_clause,
+        ))
+    }
+
     /// Returns a GreenId of a node with kind MemberList.
     fn parse_member_list(&mut self) -> MemberListGreen {
         MemberList::new_green(
@@ -1444,9 +1588,14 @@ impl<'a> Parser<'a> {
                 let impl_kw = self.take::<TerminalImpl>();
                 let name = self.parse_identifier();
                 let colon = self.parse_token::<TerminalColon>();
-                let trait_path = self.parse_path();
+                let trait_path = self.parse_type_path();
                 Some(GenericParamImpl::new_green(self.db, impl_kw, name, colon, trait_path).into())
             }
+            SyntaxKind::TerminalPlus => {
+                let plus = self.take::<TerminalPlus>();
+                let trait_path = self.parse_type_path();
+                Some(GenericParamImplAnonymous::new_green(self.db, plus, trait_path).into())
+            }
             _ => Some(GenericParamType::new_green(self.db, self.try_parse_identifier()?).into()),
         }
     }
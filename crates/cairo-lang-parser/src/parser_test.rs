@@ -310,6 +310,7 @@ cairo_lang_test_utils::test_file_test!(
         exprs: "exprs",
         fn_: "fn",
         if_: "if",
+        loop_: "loop_diagnostics",
         match_: "match",
         pattern: "pattern",
         unterminated_string: "unterminated_string",
@@ -334,6 +335,8 @@ cairo_lang_test_utils::test_file_test!(
         item_trait: "item_trait",
         let_statement: "let_statement",
         if_else: "if_else",
+        loop_: "loop",
+        closure: "closure",
         literal: "literal",
         module: "module",
         op_eq: "op_eq",
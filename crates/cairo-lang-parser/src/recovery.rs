@@ -41,6 +41,13 @@ macro_rules! rangle {
 }
 pub(crate) use rangle;
 
+macro_rules! or {
+    () => {
+        SyntaxKind::TerminalOr
+    };
+}
+pub(crate) use or;
+
 macro_rules! comma {
     () => {
         SyntaxKind::TerminalComma
@@ -80,7 +87,11 @@ pub(crate) use top_level;
 
 macro_rules! block {
     () => {
-        SyntaxKind::TerminalLet | SyntaxKind::TerminalMatch | SyntaxKind::TerminalReturn
+        SyntaxKind::TerminalLet
+            | SyntaxKind::TerminalMatch
+            | SyntaxKind::TerminalReturn
+            | SyntaxKind::TerminalContinue
+            | SyntaxKind::TerminalBreak
     };
 }
 pub(crate) use block;
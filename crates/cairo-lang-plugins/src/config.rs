@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 use std::sync::Arc;
 
-use cairo_lang_defs::plugin::{MacroPlugin, PluginResult};
+use cairo_lang_defs::plugin::{MacroPlugin, MacroPluginMetadata, PluginResult};
 use cairo_lang_semantic::plugin::{AsDynMacroPlugin, SemanticPlugin};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
@@ -9,13 +9,23 @@ use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
 /// Plugin that enables ignoring modules not involved in the current config.
 /// Mostly usefull for marking test modules to prevent usage of their functionality out of tests,
 /// and reduce compilation time when the tests data isn't required.
+/// An item is kept if its config is in `configs` or in the `cfg` settings of its crate.
 #[derive(Debug)]
 pub struct ConfigPlugin {
     pub configs: HashSet<String>,
 }
 
 impl MacroPlugin for ConfigPlugin {
-    fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
+    fn name(&self) -> &'static str {
+        "config"
+    }
+
+    fn generate_code(
+        &self,
+        db: &dyn SyntaxGroup,
+        item_ast: ast::Item,
+        metadata: &MacroPluginMetadata<'_>,
+    ) -> PluginResult {
         let item_attributes = match item_ast {
             ast::Item::Constant(ast_node) => ast_node.attributes(db),
             ast::Item::Module(ast_node) => ast_node.attributes(db),
@@ -32,9 +42,10 @@ impl MacroPlugin for ConfigPlugin {
         for attr in item_attributes.elements(db) {
             if attr.attr(db).text(db) == "cfg" {
                 if let ast::OptionAttributeArgs::AttributeArgs(args) = attr.args(db) {
-                    if !self
-                        .configs
-                        .contains(args.arg_list(db).as_syntax_node().get_text(db).trim())
+                    let config = args.arg_list(db).as_syntax_node().get_text(db);
+                    let config = config.trim();
+                    if !self.configs.contains(config)
+                        && !metadata.cfg_set.iter().any(|crate_config| crate_config == config)
                     {
                         return PluginResult {
                             code: None,
@@ -1,28 +1,60 @@
 use std::sync::Arc;
 
 use cairo_lang_defs::plugin::{
-    DynGeneratedFileAuxData, MacroPlugin, PluginDiagnostic, PluginGeneratedFile, PluginResult,
+    DynGeneratedFileAuxData, MacroPlugin, MacroPluginMetadata, PluginDiagnostic,
+    PluginGeneratedFile, PluginResult,
 };
 use cairo_lang_semantic::plugin::{AsDynMacroPlugin, SemanticPlugin, TrivialPluginAuxData};
-use cairo_lang_syntax::node::ast::AttributeList;
+use cairo_lang_syntax::node::ast::{AttributeList, MemberList, OptionWrappedGenericParamList};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
+use indoc::formatdoc;
+use itertools::Itertools;
+use smol_str::SmolStr;
 
 #[derive(Debug)]
 pub struct DerivePlugin {}
 
 impl MacroPlugin for DerivePlugin {
-    fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
+    fn name(&self) -> &'static str {
+        "derive"
+    }
+
+    fn generate_code(
+        &self,
+        db: &dyn SyntaxGroup,
+        item_ast: ast::Item,
+        _metadata: &MacroPluginMetadata<'_>,
+    ) -> PluginResult {
         match item_ast {
-            ast::Item::Struct(struct_ast) => {
-                generate_derive_code_for_type(db, struct_ast.name(db), struct_ast.attributes(db))
-            }
-            ast::Item::Enum(enum_ast) => {
-                generate_derive_code_for_type(db, enum_ast.name(db), enum_ast.attributes(db))
-            }
+            ast::Item::Struct(struct_ast) => generate_derive_code_for_type(
+                db,
+                DeriveInfo::new(
+                    db,
+                    struct_ast.name(db),
+                    struct_ast.generic_params(db),
+                    TypeVariantInfo::Struct(member_names(db, struct_ast.members(db))),
+                ),
+                struct_ast.attributes(db),
+            ),
+            ast::Item::Enum(enum_ast) => generate_derive_code_for_type(
+                db,
+                DeriveInfo::new(
+                    db,
+                    enum_ast.name(db),
+                    enum_ast.generic_params(db),
+                    TypeVariantInfo::Enum(member_names(db, enum_ast.variants(db))),
+                ),
+                enum_ast.attributes(db),
+            ),
             ast::Item::ExternType(extern_type_ast) => generate_derive_code_for_type(
                 db,
-                extern_type_ast.name(db),
+                DeriveInfo::new(
+                    db,
+                    extern_type_ast.name(db),
+                    extern_type_ast.generic_params(db),
+                    TypeVariantInfo::Extern,
+                ),
                 extern_type_ast.attributes(db),
             ),
             _ => PluginResult::default(),
@@ -39,10 +71,101 @@ impl AsDynMacroPlugin for DerivePlugin {
 }
 impl SemanticPlugin for DerivePlugin {}
 
+/// Information about the type the derives are applied to.
+struct DeriveInfo {
+    name: SmolStr,
+    /// The generic parameters of the type.
+    generic_params: Vec<GenericParamInfo>,
+    variant_info: TypeVariantInfo,
+}
+
+/// A generic parameter of the type the derives are applied to.
+enum GenericParamInfo {
+    /// A generic type parameter, with its name.
+    Type(SmolStr),
+    /// Any other kind of generic parameter, which derives do not support.
+    Unsupported(ast::GenericParam),
+}
+
+/// The members of the type the derives are applied to.
+enum TypeVariantInfo {
+    /// The names of the members of a struct.
+    Struct(Vec<SmolStr>),
+    /// The names of the variants of an enum.
+    Enum(Vec<SmolStr>),
+    Extern,
+}
+
+impl DeriveInfo {
+    fn new(
+        db: &dyn SyntaxGroup,
+        ident: ast::TerminalIdentifier,
+        generic_params: OptionWrappedGenericParamList,
+        variant_info: TypeVariantInfo,
+    ) -> Self {
+        let generic_params = match generic_params {
+            OptionWrappedGenericParamList::Empty(_) => vec![],
+            OptionWrappedGenericParamList::WrappedGenericParamList(params) => params
+                .generic_params(db)
+                .elements(db)
+                .into_iter()
+                .map(|param| match param {
+                    ast::GenericParam::Type(param) => {
+                        GenericParamInfo::Type(param.name(db).text(db))
+                    }
+                    _ => GenericParamInfo::Unsupported(param),
+                })
+                .collect(),
+        };
+        Self { name: ident.text(db), generic_params, variant_info }
+    }
+
+    /// Returns the names of the generic type parameters of the type.
+    fn generic_type_params(&self) -> impl Iterator<Item = &SmolStr> {
+        self.generic_params.iter().filter_map(|param| match param {
+            GenericParamInfo::Type(name) => Some(name),
+            GenericParamInfo::Unsupported(_) => None,
+        })
+    }
+
+    /// Returns the full type the derives are applied to, e.g. `A::<T, S>`.
+    fn full_type(&self) -> String {
+        if self.generic_params.is_empty() {
+            self.name.to_string()
+        } else {
+            format!("{}::<{}>", self.name, self.generic_type_params().join(", "))
+        }
+    }
+
+    /// Returns the header of the derived impl of `trait_path`, with the name `{name}{trait_name}`.
+    /// For generic types, each generic type parameter `T` is required to implement the traits in
+    /// `bounds`, given by their names and paths.
+    fn impl_header(&self, trait_name: &str, trait_path: &str, bounds: &[(&str, &str)]) -> String {
+        let name = &self.name;
+        let generic_params = if self.generic_params.is_empty() {
+            "".to_string()
+        } else {
+            let type_params = self.generic_type_params().map(|param| param.to_string());
+            let impl_params = self.generic_type_params().flat_map(|param| {
+                bounds.iter().map(move |(bound_name, bound_path)| {
+                    format!("impl {param}{bound_name}: {bound_path}::<{param}>")
+                })
+            });
+            format!("<{}>", type_params.chain(impl_params).join(", "))
+        };
+        format!("impl {name}{trait_name}{generic_params} of {trait_path}::<{}>", self.full_type())
+    }
+}
+
+/// Returns the names of the members of a struct or the variants of an enum.
+fn member_names(db: &dyn SyntaxGroup, members: MemberList) -> Vec<SmolStr> {
+    members.elements(db).into_iter().map(|member| member.name(db).text(db)).collect()
+}
+
 /// Adds an implementation for all requested derives for the type.
 fn generate_derive_code_for_type(
     db: &dyn SyntaxGroup,
-    ident: ast::TerminalIdentifier,
+    info: DeriveInfo,
     attributes: AttributeList,
 ) -> PluginResult {
     let mut diagnostics = vec![];
@@ -53,12 +176,13 @@ fn generate_derive_code_for_type(
                 for arg in args.arg_list(db).elements(db) {
                     if let ast::Expr::Path(expr) = arg {
                         if let [ast::PathSegment::Simple(segment)] = &expr.elements(db)[..] {
-                            let name = ident.text(db);
                             let derived = segment.ident(db).text(db);
-                            if matches!(derived.as_str(), "Copy" | "Drop") {
-                                impls.push(format!(
-                                    "impl {name}{derived} of {derived}::<{name}>;\n"
-                                ));
+                            let stable_ptr = expr.stable_ptr().untyped();
+                            match derive_impl(&info, &derived) {
+                                Ok(derived_impl) => impls.push(derived_impl),
+                                Err(message) => {
+                                    diagnostics.push(PluginDiagnostic { stable_ptr, message })
+                                }
                             }
                         } else {
                             diagnostics.push(PluginDiagnostic {
@@ -81,6 +205,17 @@ fn generate_derive_code_for_type(
             }
         }
     }
+    if !impls.is_empty() {
+        for param in &info.generic_params {
+            if let GenericParamInfo::Unsupported(param) = param {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: param.stable_ptr().untyped(),
+                    message: "Only generic type parameters are supported by derive.".into(),
+                });
+                impls.clear();
+            }
+        }
+    }
     PluginResult {
         code: if impls.is_empty() {
             None
@@ -95,3 +230,290 @@ fn generate_derive_code_for_type(
         remove_original_item: false,
     }
 }
+
+/// Returns the code of the impl of the derived trait `derived` for the type, or an error message
+/// if the trait cannot be derived for it.
+fn derive_impl(info: &DeriveInfo, derived: &str) -> Result<String, String> {
+    match (derived, &info.variant_info) {
+        ("Copy" | "Drop", _) => {
+            Ok(format!("{};\n", info.impl_header(derived, derived, &[(derived, derived)])))
+        }
+        (_, TypeVariantInfo::Extern) => {
+            Err(format!("Derive of `{derived}` is not supported for extern types."))
+        }
+        ("Default", TypeVariantInfo::Enum(variants)) if variants.is_empty() => {
+            Err("Derive of `Default` is not supported for empty enums.".into())
+        }
+        ("Clone", _) => Ok(get_clone_impl(info)),
+        ("Default", _) => Ok(get_default_impl(info)),
+        ("Destruct", _) => Ok(get_destruct_impl(info)),
+        ("PartialEq", _) => Ok(get_partial_eq_impl(info)),
+        ("Serde", _) => Ok(get_serde_impl(info)),
+        _ => Err(format!("Unknown derive `{derived}`.")),
+    }
+}
+
+fn get_clone_impl(info: &DeriveInfo) -> String {
+    let name = &info.name;
+    let full_type = info.full_type();
+    let body = match &info.variant_info {
+        TypeVariantInfo::Struct(members) => formatdoc!(
+            "
+            {name} {{
+                {}
+            }}",
+            indent(
+                members.iter().map(|member| format!("{member}: Clone::clone(self.{member}),")),
+                4
+            )
+        ),
+        TypeVariantInfo::Enum(variants) => formatdoc!(
+            "
+            match self {{
+                {}
+            }}",
+            indent(
+                variants.iter().map(|variant| {
+                    format!("{name}::{variant}(x) => {name}::{variant}(Clone::clone(x)),")
+                }),
+                4
+            )
+        ),
+        TypeVariantInfo::Extern => unreachable!(),
+    };
+    formatdoc!(
+        "
+        {} {{
+            fn clone(self: @{full_type}) -> {full_type} {{
+                {}
+            }}
+        }}
+        ",
+        info.impl_header("Clone", "Clone", &[("Clone", "Clone"), ("Drop", "Drop")]),
+        indent_lines(&body, 8)
+    )
+}
+
+/// Generates the `Default` impl. Structs are built from the default values of their members, and
+/// enums from the default value of their first variant.
+fn get_default_impl(info: &DeriveInfo) -> String {
+    let name = &info.name;
+    let full_type = info.full_type();
+    let body = match &info.variant_info {
+        TypeVariantInfo::Struct(members) => formatdoc!(
+            "
+            {name} {{
+                {}
+            }}",
+            indent(members.iter().map(|member| format!("{member}: Default::default(),")), 4)
+        ),
+        TypeVariantInfo::Enum(variants) => {
+            format!("{name}::{}(Default::default())", variants[0])
+        }
+        TypeVariantInfo::Extern => unreachable!(),
+    };
+    formatdoc!(
+        "
+        {} {{
+            fn default() -> {full_type} {{
+                {}
+            }}
+        }}
+        ",
+        info.impl_header("Default", "Default", &[("Default", "Default"), ("Drop", "Drop")]),
+        indent_lines(&body, 8)
+    )
+}
+
+fn get_destruct_impl(info: &DeriveInfo) -> String {
+    let name = &info.name;
+    let full_type = info.full_type();
+    let body = match &info.variant_info {
+        TypeVariantInfo::Struct(members) => {
+            indent(members.iter().map(|member| format!("Destruct::destruct(self.{member});")), 0)
+        }
+        TypeVariantInfo::Enum(variants) => formatdoc!(
+            "
+            match self {{
+                {}
+            }}",
+            indent(
+                variants
+                    .iter()
+                    .map(|variant| format!("{name}::{variant}(x) => Destruct::destruct(x),")),
+                4
+            )
+        ),
+        TypeVariantInfo::Extern => unreachable!(),
+    };
+    formatdoc!(
+        "
+        {} {{
+            fn destruct(self: {full_type}) nopanic {{
+                {}
+            }}
+        }}
+        ",
+        info.impl_header("Destruct", "Destruct", &[("Destruct", "Destruct")]),
+        indent_lines(&body, 8)
+    )
+}
+
+fn get_partial_eq_impl(info: &DeriveInfo) -> String {
+    let name = &info.name;
+    let full_type = info.full_type();
+    let body = match &info.variant_info {
+        TypeVariantInfo::Struct(members) => indent(
+            members
+                .iter()
+                .map(|member| {
+                    formatdoc!(
+                        "
+                        if a.{member} != b.{member} {{
+                            return false;
+                        }}"
+                    )
+                })
+                .chain(["true".to_string()]),
+            0,
+        ),
+        TypeVariantInfo::Enum(variants) => {
+            let arms = variants.iter().map(|variant| {
+                let inner_arms = variants.iter().map(|other_variant| {
+                    if other_variant == variant {
+                        format!("{name}::{other_variant}(y) => x == y,")
+                    } else {
+                        format!("{name}::{other_variant}(_) => false,")
+                    }
+                });
+                formatdoc!(
+                    "
+                    {name}::{variant}(x) => match b {{
+                        {}
+                    }},",
+                    indent(inner_arms, 4)
+                )
+            });
+            formatdoc!(
+                "
+                match a {{
+                    {}
+                }}",
+                indent(arms, 4)
+            )
+        }
+        TypeVariantInfo::Extern => unreachable!(),
+    };
+    formatdoc!(
+        "
+        {} {{
+            fn eq(a: {full_type}, b: {full_type}) -> bool {{
+                {}
+            }}
+            #[inline(always)]
+            fn ne(a: {full_type}, b: {full_type}) -> bool {{
+                !(a == b)
+            }}
+        }}
+        ",
+        info.impl_header("PartialEq", "PartialEq", &[("PartialEq", "PartialEq"), ("Drop", "Drop")]),
+        indent_lines(&body, 8)
+    )
+}
+
+/// Generates the `Serde` impl, using the same layout as the corelib: the members of a struct are
+/// serialized one after the other, and an enum is serialized as the index of its variant followed
+/// by the variant's value.
+fn get_serde_impl(info: &DeriveInfo) -> String {
+    let name = &info.name;
+    let full_type = info.full_type();
+    let (serialize_body, deserialize_body) = match &info.variant_info {
+        TypeVariantInfo::Struct(members) => (
+            indent(
+                members.iter().map(|member| {
+                    format!("serde::Serde::serialize(ref serialized, input.{member});")
+                }),
+                0,
+            ),
+            formatdoc!(
+                "
+                Option::Some(
+                    {name} {{
+                        {}
+                    }}
+                )",
+                indent(
+                    members.iter().map(|member| {
+                        format!("{member}: serde::Serde::deserialize(ref serialized)?,")
+                    }),
+                    8
+                )
+            ),
+        ),
+        TypeVariantInfo::Enum(variants) => {
+            let serialize_arms = variants.iter().enumerate().map(|(index, variant)| {
+                formatdoc!(
+                    "
+                    {name}::{variant}(x) => {{
+                        serde::Serde::<felt252>::serialize(ref serialized, {index});
+                        serde::Serde::serialize(ref serialized, x);
+                    }},"
+                )
+            });
+            let mut deserialize_branches = variants.iter().enumerate().map(|(index, variant)| {
+                formatdoc!(
+                    "
+                    if index == {index} {{
+                        {name}::{variant}(serde::Serde::deserialize(ref serialized)?)
+                    }} else"
+                )
+            });
+            (
+                formatdoc!(
+                    "
+                    match input {{
+                        {}
+                    }}",
+                    indent(serialize_arms, 4)
+                ),
+                formatdoc!(
+                    "
+                    let index: felt252 = serde::Serde::deserialize(ref serialized)?;
+                    Option::Some(
+                        {} {{
+                            return Option::None(());
+                        }}
+                    )",
+                    indent_lines(&deserialize_branches.join(" "), 4)
+                ),
+            )
+        }
+        TypeVariantInfo::Extern => unreachable!(),
+    };
+    formatdoc!(
+        "
+        {} {{
+            fn serialize(ref serialized: Array<felt252>, input: {full_type}) {{
+                {}
+            }}
+            fn deserialize(ref serialized: Span<felt252>) -> Option<{full_type}> {{
+                {}
+            }}
+        }}
+        ",
+        info.impl_header("Serde", "serde::Serde", &[("Serde", "serde::Serde"), ("Drop", "Drop")]),
+        indent_lines(&serialize_body, 8),
+        indent_lines(&deserialize_body, 8)
+    )
+}
+
+/// Joins the given pieces of code into lines, indenting all lines but the first by `n_spaces`.
+fn indent(mut pieces: impl Iterator<Item = String>, n_spaces: usize) -> String {
+    indent_lines(&pieces.join("\n"), n_spaces)
+}
+
+/// Indents all lines of `code` but the first by `n_spaces`, so that it can be placed in a template
+/// at that indentation.
+fn indent_lines(code: &str, n_spaces: usize) -> String {
+    code.lines().join(&format!("\n{}", " ".repeat(n_spaces)))
+}
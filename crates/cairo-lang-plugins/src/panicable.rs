@@ -1,7 +1,8 @@
 use std::sync::Arc;
 
 use cairo_lang_defs::plugin::{
-    DynGeneratedFileAuxData, MacroPlugin, PluginDiagnostic, PluginGeneratedFile, PluginResult,
+    DynGeneratedFileAuxData, MacroPlugin, MacroPluginMetadata, PluginDiagnostic,
+    PluginGeneratedFile, PluginResult,
 };
 use cairo_lang_semantic::plugin::{AsDynMacroPlugin, SemanticPlugin, TrivialPluginAuxData};
 use cairo_lang_syntax::node::ast::AttributeList;
@@ -14,7 +15,16 @@ use itertools::Itertools;
 pub struct PanicablePlugin {}
 
 impl MacroPlugin for PanicablePlugin {
-    fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
+    fn name(&self) -> &'static str {
+        "panicable"
+    }
+
+    fn generate_code(
+        &self,
+        db: &dyn SyntaxGroup,
+        item_ast: ast::Item,
+        _metadata: &MacroPluginMetadata<'_>,
+    ) -> PluginResult {
         let (declaration, attributes) = match item_ast {
             ast::Item::ExternFunction(extern_func_ast) => {
                 (extern_func_ast.declaration(db), extern_func_ast.attributes(db))
@@ -1,4 +1,4 @@
-use cairo_lang_defs::plugin::{PluginGeneratedFile, PluginResult};
+use cairo_lang_defs::plugin::{MacroPluginMetadata, PluginGeneratedFile, PluginResult};
 use cairo_lang_diagnostics::{format_diagnostics, DiagnosticLocation};
 use cairo_lang_parser::test_utils::create_virtual_file;
 use cairo_lang_parser::utils::{get_syntax_file_and_diagnostics, SimpleParserDatabase};
@@ -30,12 +30,13 @@ pub fn test_expand_plugin(
     assert_eq!(diagnostics.format(db), "");
     let file_syntax_node = syntax_file.as_syntax_node();
     let plugins = get_default_plugins();
+    let metadata = MacroPluginMetadata { cfg_set: &[], edition: Default::default() };
     let mut generated_items: Vec<String> = Vec::new();
     let mut diagnostic_items: Vec<String> = Vec::new();
     for item in syntax_file.items(db).elements(db).into_iter() {
         for plugin in &plugins {
             let PluginResult { code, diagnostics, remove_original_item } =
-                plugin.clone().as_dyn_macro_plugin().generate_code(db, item.clone());
+                plugin.clone().as_dyn_macro_plugin().generate_code(db, item.clone(), &metadata);
 
             diagnostic_items.extend(diagnostics.iter().map(|diag| {
                 let syntax_node = file_syntax_node.lookup_ptr(db, diag.stable_ptr);
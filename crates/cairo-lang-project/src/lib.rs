@@ -5,6 +5,7 @@ mod test;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
+use cairo_lang_filesystem::db::CrateSettings;
 use cairo_lang_filesystem::ids::Directory;
 use serde::{Deserialize, Serialize};
 use smol_str::SmolStr;
@@ -33,6 +34,26 @@ pub struct ProjectConfig {
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ProjectConfigContent {
     pub crate_roots: HashMap<SmolStr, PathBuf>,
+    /// Compilation settings of the crates.
+    #[serde(default, rename = "config")]
+    pub crates_config: AllCratesConfig,
+}
+
+/// Compilation settings of all the crates of a project.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllCratesConfig {
+    /// Settings of the crates that have no specific settings.
+    #[serde(default)]
+    pub global: CrateSettings,
+    /// Settings of specific crates, by crate name.
+    #[serde(default, rename = "override")]
+    pub override_map: HashMap<SmolStr, CrateSettings>,
+}
+impl AllCratesConfig {
+    /// Returns the settings of the given crate.
+    pub fn get(&self, crate_name: &str) -> &CrateSettings {
+        self.override_map.get(crate_name).unwrap_or(&self.global)
+    }
 }
 
 impl ProjectConfig {
@@ -1,11 +1,12 @@
 use indoc::indoc;
 
-use crate::ProjectConfigContent;
+use crate::{AllCratesConfig, ProjectConfigContent};
 
 #[test]
 fn test_serde() {
     let config = ProjectConfigContent {
         crate_roots: [("crate".into(), "dir".into())].into_iter().collect(),
+        crates_config: AllCratesConfig::default(),
     };
     let serialized = toml::to_string(&config).unwrap();
     assert_eq!(
@@ -13,7 +14,38 @@ fn test_serde() {
         indoc! { r#"
             [crate_roots]
             crate = "dir"
+            [config.global]
+            edition = "2023_01"
+            cfg = []
+
+            [config.override]
         "# }
     );
     assert_eq!(config, toml::from_str(&serialized).unwrap());
 }
+
+#[test]
+fn test_crates_config() {
+    let config: ProjectConfigContent = toml::from_str(indoc! { r#"
+        [crate_roots]
+        lib = "lib"
+        contract = "contract"
+
+        [config.global]
+        dependencies = []
+
+        [config.override.contract]
+        dependencies = ["lib"]
+        cfg = ["feature"]
+        plugins = ["derive", "starknet"]
+    "# })
+    .unwrap();
+    let lib_settings = config.crates_config.get("lib");
+    assert_eq!(lib_settings.dependencies, Some(vec![]));
+    assert!(lib_settings.cfg.is_empty());
+    assert_eq!(lib_settings.plugins, None);
+    let contract_settings = config.crates_config.get("contract");
+    assert_eq!(contract_settings.dependencies, Some(vec!["lib".into()]));
+    assert_eq!(contract_settings.cfg, vec!["feature"]);
+    assert_eq!(contract_settings.plugins, Some(vec!["derive".into(), "starknet".into()]));
+}
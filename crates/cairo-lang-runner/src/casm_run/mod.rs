@@ -1,15 +1,21 @@
 use std::any::Any;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
 
 use ark_ff::fields::{Fp256, MontBackend, MontConfig};
 use ark_ff::{Field, PrimeField};
 use ark_std::UniformRand;
 use cairo_felt::{self as felt, felt_str as felt252_str, Felt as Felt252, PRIME_STR};
 use cairo_lang_casm::hints::Hint;
+use cairo_lang_casm::inline::CasmContext;
 use cairo_lang_casm::instructions::Instruction;
 use cairo_lang_casm::operand::{
     BinOpOperand, CellRef, DerefOrImmediate, Operation, Register, ResOperand,
 };
+use cairo_lang_casm::{casm, casm_extend};
+use cairo_lang_starknet::casm_contract_class::{
+    CasmContractClass, CasmContractEntryPoint, ENTRY_POINT_COST,
+};
 use cairo_lang_utils::extract_matches;
 use cairo_vm::hint_processor::hint_processor_definition::{HintProcessor, HintReference};
 use cairo_vm::serde::deserialize_program::{
@@ -21,18 +27,25 @@ use cairo_vm::types::relocatable::{MaybeRelocatable, Relocatable};
 use cairo_vm::vm::errors::hint_errors::HintError;
 use cairo_vm::vm::errors::vm_errors::VirtualMachineError;
 use cairo_vm::vm::runners::cairo_runner::CairoRunner;
+use cairo_vm::vm::trace::trace_entry::RelocatedTraceEntry;
 use cairo_vm::vm::vm_core::VirtualMachine;
 use dict_manager::DictManagerExecScope;
+use itertools::chain;
 use num_bigint::BigUint;
+use num_integer::Integer;
 use num_traits::{FromPrimitive, ToPrimitive, Zero};
+use starknet_crypto::{pedersen_hash, FieldElement};
 
 use self::dict_manager::DictSquashExecScope;
+use self::poseidon::PoseidonBuiltin;
 use crate::short_string::as_cairo_short_string;
 
 #[cfg(test)]
 mod test;
 
 mod dict_manager;
+mod poseidon;
+mod secp256k1;
 
 // TODO(orizi): This def is duplicated.
 /// Returns the Beta value of the Starkware elliptic curve.
@@ -64,35 +77,51 @@ struct CairoHintProcessor {
     pub hints_dict: HashMap<usize, Vec<HintParams>>,
     // A mapping from a string that represents a hint to the hint object.
     pub string_to_hint: HashMap<String, Hint>,
+    // The simulated Starknet state, shared with the contracts called during the run.
+    pub starknet_state: StarknetState,
 }
 
 impl CairoHintProcessor {
     pub fn new<'a, Instructions: Iterator<Item = &'a Instruction> + Clone>(
         instructions: Instructions,
+        starknet_state: StarknetState,
+    ) -> Self {
+        Self::from_hints(instructions_hints(instructions), starknet_state)
+    }
+
+    /// Creates a hint processor from hints, given with the bytecode offset they are attached to.
+    fn from_hints<'a>(
+        hints: impl Iterator<Item = (usize, &'a [Hint])>,
+        starknet_state: StarknetState,
     ) -> Self {
         let mut hints_dict: HashMap<usize, Vec<HintParams>> = HashMap::new();
         let mut string_to_hint: HashMap<String, Hint> = HashMap::new();
 
-        let mut hint_offset = 0;
-
-        for instruction in instructions {
-            if !instruction.hints.is_empty() {
-                // Register hint with string for the hint processor.
-                for hint in instruction.hints.iter() {
-                    string_to_hint.insert(hint.to_string(), hint.clone());
-                }
-                // Add hint, associated with the instruction offset.
-                hints_dict.insert(
-                    hint_offset,
-                    instruction.hints.iter().map(hint_to_hint_params).collect(),
-                );
+        for (hint_offset, hints) in hints {
+            // Register hint with string for the hint processor.
+            for hint in hints.iter() {
+                string_to_hint.insert(hint.to_string(), hint.clone());
             }
-            hint_offset += instruction.body.op_size();
+            // Add hint, associated with the instruction offset.
+            hints_dict.insert(hint_offset, hints.iter().map(hint_to_hint_params).collect());
         }
-        CairoHintProcessor { hints_dict, string_to_hint }
+        CairoHintProcessor { hints_dict, string_to_hint, starknet_state }
     }
 }
 
+/// Returns the non-empty hints of `instructions`, with the bytecode offset they are attached to.
+fn instructions_hints<'a>(
+    instructions: impl Iterator<Item = &'a Instruction>,
+) -> impl Iterator<Item = (usize, &'a [Hint])> {
+    instructions
+        .scan(0, |hint_offset, instruction| {
+            let offset = *hint_offset;
+            *hint_offset += instruction.body.op_size();
+            Some((offset, instruction.hints.as_slice()))
+        })
+        .filter(|(_, hints)| !hints.is_empty())
+}
+
 fn cell_ref_to_relocatable(cell_ref: &CellRef, vm: &VirtualMachine) -> Relocatable {
     let base = match cell_ref.register {
         Register::AP => vm.get_ap(),
@@ -108,17 +137,418 @@ macro_rules! insert_value_to_cellref {
     };
 }
 
-/// Execution scope for starknet related data.
+/// The simulated Starknet state of a run.
 /// All values will be 0 and by default if not setup by the test.
-struct StarknetExecScope {
+#[derive(Clone, Default)]
+pub struct StarknetState {
     /// The values of addresses in the simulated storage per contract.
     storage: HashMap<Felt252, HashMap<Felt252, Felt252>>,
+    /// The declared contract classes, by class hash.
+    classes: HashMap<Felt252, Arc<CasmContractClass>>,
+    /// The class hash of each deployed contract, by contract address.
+    deployed_contracts: HashMap<Felt252, Felt252>,
+    /// The events emitted and the messages sent to L1 by each contract, by contract address.
+    logs: HashMap<Felt252, ContractLogs>,
     /// The simulated execution info.
     exec_info: ExecutionInfo,
 }
 
+impl StarknetState {
+    /// Declares `class` with the hash `class_hash`, so contracts of this class can be deployed.
+    pub fn declare_class(&mut self, class_hash: Felt252, class: CasmContractClass) {
+        self.classes.insert(class_hash, Arc::new(class));
+    }
+
+    /// Deploys a contract of the already declared class `class_hash` at `contract_address`.
+    pub fn deploy_contract(&mut self, contract_address: Felt252, class_hash: Felt252) {
+        assert!(self.classes.contains_key(&class_hash), "Deploying an undeclared class.");
+        self.deployed_contracts.insert(contract_address, class_hash);
+    }
+
+    /// Simulates a call to the external entry point `selector` of the contract deployed at
+    /// `contract_address`, made by the current contract.
+    /// Returns the retdata of the call, or the revert reason if it failed.
+    fn call_contract(
+        &mut self,
+        contract_address: Felt252,
+        selector: Felt252,
+        calldata: Vec<Felt252>,
+        gas_counter: &mut usize,
+    ) -> Result<SyscallResult<Vec<Felt252>>, HintError> {
+        let Some(class_hash) = self.deployed_contracts.get(&contract_address) else {
+            return Ok(Err(vec![Felt252::from_bytes_be(b"CONTRACT_NOT_DEPLOYED")]));
+        };
+        let class = self.classes[class_hash].clone();
+        let Some(entry_point) = find_entry_point(&class.entry_points_by_type.external, &selector)
+        else {
+            return Ok(Err(vec![Felt252::from_bytes_be(b"ENTRYPOINT_NOT_FOUND")]));
+        };
+        self.with_contract_context(contract_address, |state| {
+            state.run_entry_point(&class, entry_point, calldata, gas_counter)
+        })
+    }
+
+    /// Simulates a call to the external entry point `selector` of the class `class_hash`, in the
+    /// context (storage, contract address and caller address) of the current contract.
+    /// Returns the retdata of the call, or the revert reason if it failed.
+    fn library_call(
+        &mut self,
+        class_hash: Felt252,
+        selector: Felt252,
+        calldata: Vec<Felt252>,
+        gas_counter: &mut usize,
+    ) -> Result<SyscallResult<Vec<Felt252>>, HintError> {
+        let Some(class) = self.classes.get(&class_hash).cloned() else {
+            return Ok(Err(vec![Felt252::from_bytes_be(b"CLASS_HASH_NOT_DECLARED")]));
+        };
+        let Some(entry_point) = find_entry_point(&class.entry_points_by_type.external, &selector)
+        else {
+            return Ok(Err(vec![Felt252::from_bytes_be(b"ENTRYPOINT_NOT_FOUND")]));
+        };
+        self.run_entry_point(&class, entry_point, calldata, gas_counter)
+    }
+
+    /// Simulates the deployment of a contract of the class `class_hash` by the current contract,
+    /// including running its constructor with `calldata`.
+    /// Returns the address of the new contract and the retdata of the constructor, or the revert
+    /// reason if the deployment failed.
+    fn deploy(
+        &mut self,
+        class_hash: Felt252,
+        contract_address_salt: Felt252,
+        calldata: Vec<Felt252>,
+        deploy_from_zero: bool,
+        gas_counter: &mut usize,
+    ) -> Result<SyscallResult<(Felt252, Vec<Felt252>)>, HintError> {
+        let Some(class) = self.classes.get(&class_hash).cloned() else {
+            return Ok(Err(vec![Felt252::from_bytes_be(b"CLASS_HASH_NOT_DECLARED")]));
+        };
+        let deployer_address = if deploy_from_zero {
+            Felt252::from(0)
+        } else {
+            self.exec_info.contract_address.clone()
+        };
+        let contract_address = calculate_contract_address(
+            &contract_address_salt,
+            &class_hash,
+            &calldata,
+            &deployer_address,
+        );
+        if self.deployed_contracts.contains_key(&contract_address) {
+            return Ok(Err(vec![Felt252::from_bytes_be(b"CONTRACT_ALREADY_DEPLOYED")]));
+        }
+        let Some(constructor) = class.entry_points_by_type.constructor.first() else {
+            if !calldata.is_empty() {
+                return Ok(Err(vec![Felt252::from_bytes_be(b"INVALID_CALLDATA_LEN")]));
+            }
+            self.deployed_contracts.insert(contract_address.clone(), class_hash);
+            return Ok(Ok((contract_address, vec![])));
+        };
+        self.deployed_contracts.insert(contract_address.clone(), class_hash);
+        let result = self.with_contract_context(contract_address.clone(), |state| {
+            state.run_entry_point(&class, constructor, calldata, gas_counter)
+        })?;
+        Ok(match result {
+            Ok(retdata) => Ok((contract_address, retdata)),
+            Err(revert_reason) => {
+                self.deployed_contracts.remove(&contract_address);
+                Err(revert_reason)
+            }
+        })
+    }
+
+    /// Replaces the class of the current contract with the class `class_hash`.
+    /// Returns the revert reason if the replacement failed.
+    fn replace_class(&mut self, class_hash: Felt252) -> Option<Vec<Felt252>> {
+        if !self.classes.contains_key(&class_hash) {
+            return Some(vec![Felt252::from_bytes_be(b"CLASS_HASH_NOT_DECLARED")]);
+        }
+        self.deployed_contracts.insert(self.exec_info.contract_address.clone(), class_hash);
+        None
+    }
+
+    /// Records an event emitted by the current contract.
+    fn emit_event(&mut self, keys: Vec<Felt252>, data: Vec<Felt252>) {
+        self.current_contract_logs().events.push_back(Event { keys, data });
+    }
+
+    /// Records a message sent from the current contract to the L1 address `to_address`.
+    fn send_message_to_l1(&mut self, to_address: Felt252, payload: Vec<Felt252>) {
+        self.current_contract_logs()
+            .l2_to_l1_messages
+            .push_back(L2ToL1Message { to_address, payload });
+    }
+
+    /// Returns the logs of the current contract.
+    fn current_contract_logs(&mut self) -> &mut ContractLogs {
+        self.logs.entry(self.exec_info.contract_address.clone()).or_default()
+    }
+
+    /// Returns the unpopped logs of each contract, by contract address.
+    pub fn logs(&self) -> &HashMap<Felt252, ContractLogs> {
+        &self.logs
+    }
+
+    /// Runs `f` as the contract deployed at `contract_address`, called by the current contract.
+    fn with_contract_context<T>(
+        &mut self,
+        contract_address: Felt252,
+        f: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        let caller_address =
+            std::mem::replace(&mut self.exec_info.contract_address, contract_address);
+        let prev_caller_address =
+            std::mem::replace(&mut self.exec_info.caller_address, caller_address);
+        let result = f(self);
+        self.exec_info.contract_address =
+            std::mem::replace(&mut self.exec_info.caller_address, prev_caller_address);
+        result
+    }
+
+    /// Runs `entry_point` of `class` in a nested vm, on top of the current state.
+    /// If the run panics, all the changes it made to the state are reverted.
+    fn run_entry_point(
+        &mut self,
+        class: &CasmContractClass,
+        entry_point: &CasmContractEntryPoint,
+        calldata: Vec<Felt252>,
+        gas_counter: &mut usize,
+    ) -> Result<SyscallResult<Vec<Felt252>>, HintError> {
+        // The cost of the entry point is paid by the caller, before its execution begins.
+        let Some(initial_gas) = gas_counter.checked_sub(ENTRY_POINT_COST as usize) else {
+            return Ok(Err(vec![Felt252::from_bytes_be(b"Out of gas")]));
+        };
+        let entry_code = create_entry_point_code(entry_point, &calldata, initial_gas)?;
+        let footer = casm! { ret; };
+        let class_bytecode_offset = entry_code.current_code_offset;
+        let data = chain!(
+            encode_instructions(entry_code.instructions.iter()),
+            class.bytecode.iter().map(|value| Felt252::from(value.value.clone())),
+            encode_instructions(footer.instructions.iter()),
+        )
+        .map(MaybeRelocatable::from)
+        .collect();
+        let hints = chain!(
+            instructions_hints(entry_code.instructions.iter()),
+            class
+                .hints
+                .iter()
+                .map(|(offset, hints)| (class_bytecode_offset + offset, hints.as_slice())),
+        );
+        let snapshot = self.clone();
+        let mut hint_processor = CairoHintProcessor::from_hints(hints, std::mem::take(self));
+        let (cells, trace) =
+            match run_program(data, vec_of_builtins(), crate::initialize_vm, &mut hint_processor) {
+                Ok(res) => res,
+                Err(err) => {
+                    *self = snapshot;
+                    return Err(HintError::Internal(*err));
+                }
+            };
+        let ap = trace.last().unwrap().ap;
+        let output = read_entry_point_output(&cells, ap);
+        // The changes of the call are kept only if it succeeded.
+        *self = match &output {
+            Ok((_, Ok(_))) => hint_processor.starknet_state,
+            _ => snapshot,
+        };
+        let (remaining_gas, result) = output?;
+        *gas_counter = remaining_gas;
+        Ok(result)
+    }
+}
+
+/// Reads the output of an entry point called by the code from `create_entry_point_code`, given
+/// the final memory and ap - the remaining gas, and either the retdata or the panic data.
+fn read_entry_point_output(
+    cells: &[Option<Felt252>],
+    ap: usize,
+) -> Result<(usize, SyscallResult<Vec<Felt252>>), HintError> {
+    // The entry point returns the builtins, the gas builtin, the system and a
+    // `PanicResult<(Span<felt252>,)>`, made of a variant selector and the start and end of
+    // either the retdata or the panic data.
+    let cell_value = |index: usize| {
+        cells.get(index).cloned().flatten().ok_or_else(|| {
+            HintError::CustomHint(format!("Entry point output memory cell {index} is not set."))
+        })
+    };
+    let cell_usize = |index: usize| {
+        cell_value(index)?.to_usize().ok_or_else(|| {
+            HintError::CustomHint(format!("Entry point output memory cell {index} is not a usize."))
+        })
+    };
+    let remaining_gas = cell_usize(ap - 5)?;
+    let data_start = cell_usize(ap - 2)?;
+    let data_end = cell_usize(ap - 1)?;
+    let data = (data_start..data_end).map(cell_value).collect::<Result<_, _>>()?;
+    Ok((remaining_gas, if cell_value(ap - 3)?.is_zero() { Ok(data) } else { Err(data) }))
+}
+
+/// The result of a simulated syscall - its output, or its revert reason if it failed.
+type SyscallResult<T> = Result<T, Vec<Felt252>>;
+
+/// The events emitted and the messages sent to L1 by a contract, which were not popped yet, in the
+/// order they were recorded.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContractLogs {
+    pub events: VecDeque<Event>,
+    pub l2_to_l1_messages: VecDeque<L2ToL1Message>,
+}
+
+/// An event emitted by a contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Event {
+    pub keys: Vec<Felt252>,
+    pub data: Vec<Felt252>,
+}
+
+/// A message sent from L2 to L1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct L2ToL1Message {
+    /// The address of the recipient on L1.
+    pub to_address: Felt252,
+    /// The content of the message.
+    pub payload: Vec<Felt252>,
+}
+
+/// Calculates the address of a contract of the class `class_hash`, deployed by
+/// `deployer_address` with the given salt and constructor calldata, the same way Starknet does.
+fn calculate_contract_address(
+    salt: &Felt252,
+    class_hash: &Felt252,
+    constructor_calldata: &[Felt252],
+    deployer_address: &Felt252,
+) -> Felt252 {
+    // The upper bound of L2 addresses, `2**251 - 256`.
+    let l2_address_upper_bound = (BigUint::from(1u32) << 251) - 256u32;
+    let address = pedersen_hash_on_elements(&[
+        Felt252::from_bytes_be(b"STARKNET_CONTRACT_ADDRESS"),
+        deployer_address.clone(),
+        salt.clone(),
+        class_hash.clone(),
+        pedersen_hash_on_elements(constructor_calldata),
+    ]);
+    Felt252::from(address.to_biguint() % l2_address_upper_bound)
+}
+
+/// Computes the Pedersen hash chain of `elements`, ending with their count.
+fn pedersen_hash_on_elements(elements: &[Felt252]) -> Felt252 {
+    let to_field_element = |value: &Felt252| {
+        let bytes = value.to_bytes_be();
+        let mut padded = [0; 32];
+        padded[32 - bytes.len()..].copy_from_slice(&bytes);
+        FieldElement::from_bytes_be(&padded).unwrap()
+    };
+    let hash = elements
+        .iter()
+        .chain([&Felt252::from(elements.len())])
+        .fold(FieldElement::ZERO, |hash, value| pedersen_hash(&hash, &to_field_element(value)));
+    Felt252::from_bytes_be(&hash.to_bytes_be())
+}
+
+/// Returns the entry point with the given selector, if there is one.
+fn find_entry_point<'a>(
+    entry_points: &'a [CasmContractEntryPoint],
+    selector: &Felt252,
+) -> Option<&'a CasmContractEntryPoint> {
+    entry_points.iter().find(|entry_point| Felt252::from(entry_point.selector.clone()) == *selector)
+}
+
+/// Returns the builtins required to execute a program, in the formatting expected by the runner.
+pub fn vec_of_builtins() -> Vec<String> {
+    ["poseidon", "pedersen", "range_check", "bitwise", "ec_op"]
+        .map(&str::to_string)
+        .into_iter()
+        .collect()
+}
+
+/// Returns the instructions calling `entry_point` of a contract class, assuming the class bytecode
+/// is placed right after them, or an error if the entry point uses an unsupported builtin.
+fn create_entry_point_code(
+    entry_point: &CasmContractEntryPoint,
+    calldata: &[Felt252],
+    initial_gas: usize,
+) -> Result<CasmContext, HintError> {
+    let mut ctx = casm! {
+        // The calldata segment, its start is kept at `[fp + 0]`.
+        %{ memory[ap + 0] = segments.add() %}
+        ap += 1;
+    };
+    for (i, value) in calldata.iter().enumerate() {
+        casm_extend! {ctx,
+            [ap + 0] = (value.to_bigint()), ap++;
+            [ap - 1] = [[fp + 0] + (i as i16)];
+        }
+    }
+    if entry_point.builtins.iter().any(|builtin| builtin == "segment_arena") {
+        casm_extend! {ctx,
+            // SegmentArena segment.
+            %{ memory[ap + 0] = segments.add() %}
+            // Infos segment.
+            %{ memory[ap + 1] = segments.add() %}
+            ap += 2;
+            [ap + 0] = 0, ap++;
+            // Write Infos segment, n_constructed (0), and n_destructed (0) to the segment.
+            [ap - 2] = [[ap - 3]];
+            [ap - 1] = [[ap - 3] + 1];
+            [ap - 1] = [[ap - 3] + 2];
+        }
+    }
+    for (i, builtin) in entry_point.builtins.iter().enumerate() {
+        // The offset [fp - i] of the builtins, as given by `vec_of_builtins`.
+        let offset: i16 = match builtin.as_str() {
+            "poseidon" => 7,
+            "pedersen" => 6,
+            "range_check" => 5,
+            "bitwise" => 4,
+            "ec_op" => 3,
+            "segment_arena" => {
+                let offset = -(i as i16) - 3;
+                casm_extend! {ctx,
+                    [ap + 0] = [ap + offset] + 3, ap++;
+                }
+                continue;
+            }
+            _ => {
+                return Err(HintError::CustomHint(format!("Unsupported builtin `{builtin}`.")));
+            }
+        };
+        casm_extend! {ctx,
+            [ap + 0] = [fp - offset], ap++;
+        }
+    }
+    let calldata_len = calldata.len();
+    casm_extend! {ctx,
+        [ap + 0] = initial_gas, ap++;
+        // The system segment.
+        %{ memory[ap + 0] = segments.add() %}
+        ap += 1;
+        [ap + 0] = [fp + 0], ap++;
+        [ap + 0] = [fp + 0] + calldata_len, ap++;
+    }
+    let before_final_call = ctx.current_code_offset;
+    let final_call_size = 3;
+    let offset = final_call_size + entry_point.offset;
+    casm_extend! {ctx,
+        call rel offset;
+        ret;
+    }
+    assert_eq!(before_final_call + final_call_size, ctx.current_code_offset);
+    Ok(ctx)
+}
+
+/// The number of u64 words in a block of the keccak system call input.
+const KECCAK_FULL_RATE_IN_U64S: usize = 17;
+/// The gas cost of applying the keccak permutation on a single block, in the keccak system call.
+const KECCAK_ROUND_COST: usize = 1000;
+
+/// The implementation of a syscall, given the vm and the remaining gas, which it may further
+/// reduce. Returns the revert reason if the syscall failed.
+type SyscallHandler<'a> =
+    dyn FnMut(&mut VirtualMachine, &mut usize) -> Result<Option<Vec<Felt252>>, HintError> + 'a;
+
 /// Copy of the cairo `ExecutionInfo` struct.
-#[derive(Default)]
+#[derive(Clone, Default)]
 struct ExecutionInfo {
     block_info: BlockInfo,
     tx_info: TxInfo,
@@ -127,7 +557,7 @@ struct ExecutionInfo {
 }
 
 /// Copy of the cairo `BlockInfo` struct.
-#[derive(Default)]
+#[derive(Clone, Default)]
 struct BlockInfo {
     block_number: Felt252,
     block_timestamp: Felt252,
@@ -135,7 +565,7 @@ struct BlockInfo {
 }
 
 /// Copy of the cairo `TxInfo` struct.
-#[derive(Default)]
+#[derive(Clone, Default)]
 struct TxInfo {
     version: Felt252,
     account_contract_address: Felt252,
@@ -175,6 +605,67 @@ fn get_double_deref_val(
     Ok(vm.get_integer(&get_ptr(vm, cell, offset)?)?.as_ref().clone())
 }
 
+/// Fetches the felt252s between the start and end pointers stored at `cell` plus an offset, which
+/// are the representation of a `Span<felt252>` or an `Array<felt252>`.
+fn get_felt252_span(
+    vm: &VirtualMachine,
+    cell: &CellRef,
+    offset: &Felt252,
+) -> Result<Vec<Felt252>, VirtualMachineError> {
+    let start = vm.get_relocatable(&get_ptr(vm, cell, offset)?)?;
+    let end = vm.get_relocatable(&get_ptr(vm, cell, &(offset.clone() + 1u32))?)?;
+    let len = end.sub(&start)?;
+    Ok(vm.get_integer_range(&start, len)?.into_iter().map(|value| value.into_owned()).collect())
+}
+
+/// Fetches the u256 whose low and high words are stored at `cell` plus an offset and the cell
+/// after it.
+fn get_u256(
+    vm: &VirtualMachine,
+    cell: &CellRef,
+    offset: &Felt252,
+) -> Result<BigUint, VirtualMachineError> {
+    let low = get_double_deref_val(vm, cell, offset)?.to_biguint();
+    let high = get_double_deref_val(vm, cell, &(offset.clone() + 1u32))?.to_biguint();
+    Ok((high << 128) + low)
+}
+
+/// Writes the low and high words of the u256 `value` to `dst` and the cell after it.
+fn write_u256(
+    vm: &mut VirtualMachine,
+    dst: &Relocatable,
+    value: BigUint,
+) -> Result<(), VirtualMachineError> {
+    let (high, low) = value.div_rem(&(BigUint::from(u128::MAX) + 1u32));
+    vm.insert_value(dst, Felt252::from(low))?;
+    vm.insert_value(&(dst + 1), Felt252::from(high))
+}
+
+/// Writes `values` to a new segment, and its start and end pointers to `dst` and the cell after it.
+fn write_felt252_span(
+    vm: &mut VirtualMachine,
+    dst: &Relocatable,
+    values: Vec<Felt252>,
+) -> Result<(), VirtualMachineError> {
+    let (start, end) = segment_with_data(vm, values)?;
+    vm.insert_value(dst, start)?;
+    vm.insert_value(&(dst + 1), end)
+}
+
+/// Writes `values` to a new segment, and returns its start and end pointers.
+fn segment_with_data(
+    vm: &mut VirtualMachine,
+    values: Vec<Felt252>,
+) -> Result<(Relocatable, Relocatable), VirtualMachineError> {
+    let start = vm.add_memory_segment();
+    let mut end = start;
+    for value in values {
+        vm.insert_value(&end, value)?;
+        end.offset += 1;
+    }
+    Ok((start, end))
+}
+
 /// Fetches the value of `res_operand` from the vm.
 fn get_val(vm: &VirtualMachine, res_operand: &ResOperand) -> Result<Felt252, VirtualMachineError> {
     match res_operand {
@@ -242,6 +733,108 @@ impl HintProcessor for CairoHintProcessor {
                 let val = get_val(vm, value)?.to_biguint();
                 insert_value_to_cellref!(vm, dst, Felt252::from(val.sqrt()))?;
             }
+            Hint::WideMul128 { lhs, rhs, high, low } => {
+                let mask128 = BigUint::from(u128::MAX);
+                let lhs_val = get_val(vm, lhs)?.to_biguint();
+                let rhs_val = get_val(vm, rhs)?.to_biguint();
+                let prod = lhs_val * rhs_val;
+                insert_value_to_cellref!(vm, high, Felt252::from(prod.clone() >> 128))?;
+                insert_value_to_cellref!(vm, low, Felt252::from(prod & mask128))?;
+            }
+            Hint::Uint256DivMod {
+                dividend0,
+                dividend1,
+                divisor0,
+                divisor1,
+                quotient0,
+                quotient1,
+                remainder0,
+                remainder1,
+            } => {
+                let pow_2_128 = BigUint::from(u128::MAX) + 1u32;
+                let dividend0 = get_val(vm, dividend0)?.to_biguint();
+                let dividend1 = get_val(vm, dividend1)?.to_biguint();
+                let divisor0 = get_val(vm, divisor0)?.to_biguint();
+                let divisor1 = get_val(vm, divisor1)?.to_biguint();
+                let dividend: BigUint = dividend0 + dividend1 * pow_2_128.clone();
+                let divisor = divisor0 + divisor1 * pow_2_128.clone();
+                let quotient = dividend.clone() / divisor.clone();
+                let remainder = dividend % divisor;
+                let (limb1, limb0) = quotient.div_rem(&pow_2_128);
+                insert_value_to_cellref!(vm, quotient0, Felt252::from(limb0))?;
+                insert_value_to_cellref!(vm, quotient1, Felt252::from(limb1))?;
+                let (limb1, limb0) = remainder.div_rem(&pow_2_128);
+                insert_value_to_cellref!(vm, remainder0, Felt252::from(limb0))?;
+                insert_value_to_cellref!(vm, remainder1, Felt252::from(limb1))?;
+            }
+            Hint::Uint512DivModByUint256 {
+                dividend0,
+                dividend1,
+                dividend2,
+                dividend3,
+                divisor0,
+                divisor1,
+                quotient0,
+                quotient1,
+                quotient2,
+                quotient3,
+                remainder0,
+                remainder1,
+            } => {
+                let pow_2_128 = BigUint::from(u128::MAX) + 1u32;
+                let dividend0 = get_val(vm, dividend0)?.to_biguint();
+                let dividend1 = get_val(vm, dividend1)?.to_biguint();
+                let dividend2 = get_val(vm, dividend2)?.to_biguint();
+                let dividend3 = get_val(vm, dividend3)?.to_biguint();
+                let divisor0 = get_val(vm, divisor0)?.to_biguint();
+                let divisor1 = get_val(vm, divisor1)?.to_biguint();
+                let dividend: BigUint = dividend0
+                    + dividend1 * pow_2_128.pow(1)
+                    + dividend2 * pow_2_128.pow(2)
+                    + dividend3 * pow_2_128.pow(3);
+                let divisor = divisor0 + divisor1 * pow_2_128.clone();
+                let (quotient, remainder) = dividend.div_rem(&divisor);
+                let (quotient, limb0) = quotient.div_rem(&pow_2_128);
+                insert_value_to_cellref!(vm, quotient0, Felt252::from(limb0))?;
+                let (quotient, limb1) = quotient.div_rem(&pow_2_128);
+                insert_value_to_cellref!(vm, quotient1, Felt252::from(limb1))?;
+                let (limb3, limb2) = quotient.div_rem(&pow_2_128);
+                insert_value_to_cellref!(vm, quotient2, Felt252::from(limb2))?;
+                insert_value_to_cellref!(vm, quotient3, Felt252::from(limb3))?;
+                let (limb1, limb0) = remainder.div_rem(&pow_2_128);
+                insert_value_to_cellref!(vm, remainder0, Felt252::from(limb0))?;
+                insert_value_to_cellref!(vm, remainder1, Felt252::from(limb1))?;
+            }
+            Hint::Uint256SquareRoot {
+                value_low,
+                value_high,
+                sqrt0,
+                sqrt1,
+                remainder_low,
+                remainder_high,
+                sqrt_mul_2_minus_remainder_ge_u128,
+            } => {
+                let pow_2_64 = BigUint::from(u64::MAX) + 1u32;
+                let pow_2_128 = BigUint::from(u128::MAX) + 1u32;
+                let value_low = get_val(vm, value_low)?.to_biguint();
+                let value_high = get_val(vm, value_high)?.to_biguint();
+                let value = value_low + value_high * pow_2_128.clone();
+                let sqrt = value.sqrt();
+                let remainder = value - sqrt.clone() * sqrt.clone();
+                let sqrt_mul_2_minus_remainder_ge_u128_val =
+                    sqrt.clone() * 2u32 - remainder.clone() >= pow_2_128;
+                let (sqrt1_val, sqrt0_val) = sqrt.div_rem(&pow_2_64);
+                insert_value_to_cellref!(vm, sqrt0, Felt252::from(sqrt0_val))?;
+                insert_value_to_cellref!(vm, sqrt1, Felt252::from(sqrt1_val))?;
+                let (remainder_high_val, remainder_low_val) = remainder.div_rem(&pow_2_128);
+                insert_value_to_cellref!(vm, remainder_low, Felt252::from(remainder_low_val))?;
+                insert_value_to_cellref!(vm, remainder_high, Felt252::from(remainder_high_val))?;
+                insert_value_to_cellref!(
+                    vm,
+                    sqrt_mul_2_minus_remainder_ge_u128,
+                    Felt252::from(usize::from(sqrt_mul_2_minus_remainder_ge_u128_val))
+                )?;
+            }
             Hint::LinearSplit { value, scalar, max_x, x, y } => {
                 let value = get_val(vm, value)?.to_biguint();
                 let scalar = get_val(vm, scalar)?.to_biguint();
@@ -278,80 +871,74 @@ impl HintProcessor for CairoHintProcessor {
                 })?;
             }
             Hint::SystemCall { system } => {
-                let starknet_exec_scope = starknet_execution_scope(exec_scopes)?;
+                let starknet_state = &mut self.starknet_state;
                 let (cell, base_offset) = extract_buffer(system);
                 let selector = get_double_deref_val(vm, cell, &base_offset)?.to_bytes_be();
                 // Given `res_offset` as the offset in the system ptr where the result begins,
                 // `cost` as the cost of the function and a `handler` which actually implements the
                 // syscall, changes the vm status and writes the system buffer in case of success
                 // and may also return a revert reason if additional checks failed. Runs the
-                // simulation including gas checks and revert reasons.
-                let mut check_handle_oog =
-                    |res_offset: u32,
-                     cost: usize,
-                     handler: &mut dyn FnMut(
-                        &mut VirtualMachine,
-                    )
-                        -> Result<Option<Felt252>, HintError>|
-                     -> Result<(), HintError> {
-                        let gas_counter =
-                            get_double_deref_val(vm, cell, &(base_offset.clone() + 1u32))?;
-                        let gas_counter_updated_ptr =
-                            get_ptr(vm, cell, &(base_offset.clone() + res_offset))?;
-                        let failure_flag_ptr =
-                            get_ptr(vm, cell, &(base_offset.clone() + (res_offset + 1)))?;
-                        let revert_reason = if gas_counter < cost.into() {
-                            Felt252::from_bytes_be(b"Syscall out of gas")
-                        } else if let Some(revert_reason) = handler(vm)? {
-                            revert_reason
-                        } else {
-                            vm.insert_value(&gas_counter_updated_ptr, gas_counter - cost)?;
+                // simulation including gas checks and revert reasons. The handler is given the
+                // remaining gas, and may further reduce it.
+                let mut check_handle_oog = |res_offset: u32,
+                                            cost: usize,
+                                            handler: &mut SyscallHandler<'_>|
+                 -> Result<(), HintError> {
+                    let gas_counter =
+                        get_double_deref_val(vm, cell, &(base_offset.clone() + 1u32))?;
+                    let gas_counter_updated_ptr =
+                        get_ptr(vm, cell, &(base_offset.clone() + res_offset))?;
+                    let failure_flag_ptr =
+                        get_ptr(vm, cell, &(base_offset.clone() + (res_offset + 1)))?;
+                    let revert_reason = if gas_counter < cost.into() {
+                        vm.insert_value(&gas_counter_updated_ptr, gas_counter)?;
+                        vec![Felt252::from_bytes_be(b"Syscall out of gas")]
+                    } else {
+                        let mut gas_counter = (gas_counter - cost).to_usize().unwrap();
+                        let revert_reason = handler(vm, &mut gas_counter)?;
+                        vm.insert_value(&gas_counter_updated_ptr, Felt252::from(gas_counter))?;
+                        let Some(revert_reason) = revert_reason else {
                             vm.insert_value(&failure_flag_ptr, Felt252::from(0))?;
                             return Ok(());
                         };
-                        vm.insert_value(&gas_counter_updated_ptr, gas_counter)?;
-                        vm.insert_value(&failure_flag_ptr, Felt252::from(1))?;
-                        let revert_reason_start = vm.add_memory_segment();
-                        vm.insert_value(&revert_reason_start, revert_reason)?;
-                        let revert_reason_end = revert_reason_start + 1;
-                        let revert_reason_start_ptr =
-                            get_ptr(vm, cell, &(base_offset.clone() + (res_offset + 2)))?;
-                        vm.insert_value(&revert_reason_start_ptr, revert_reason_start)?;
-                        let revert_reason_end_ptr =
-                            get_ptr(vm, cell, &(base_offset.clone() + (res_offset + 3)))?;
-                        vm.insert_value(&revert_reason_end_ptr, revert_reason_end)?;
-                        Ok(())
+                        revert_reason
                     };
+                    vm.insert_value(&failure_flag_ptr, Felt252::from(1))?;
+                    let revert_reason_ptr =
+                        get_ptr(vm, cell, &(base_offset.clone() + (res_offset + 2)))?;
+                    write_felt252_span(vm, &revert_reason_ptr, revert_reason)?;
+                    Ok(())
+                };
                 if selector == "StorageWrite".as_bytes() {
-                    check_handle_oog(5, 1000, &mut |vm| {
+                    check_handle_oog(5, 1000, &mut |vm, _| {
                         let addr_domain =
                             get_double_deref_val(vm, cell, &(base_offset.clone() + 2u32))?;
                         if !addr_domain.is_zero() {
                             // Only address_domain 0 is currently supported.
-                            return Ok(Some(Felt252::from_bytes_be(b"Unsupported address domain")));
+                            return Ok(Some(vec![Felt252::from_bytes_be(
+                                b"Unsupported address domain",
+                            )]));
                         }
                         let addr = get_double_deref_val(vm, cell, &(base_offset.clone() + 3u32))?;
                         let value = get_double_deref_val(vm, cell, &(base_offset.clone() + 4u32))?;
-                        let contract = starknet_exec_scope.exec_info.contract_address.clone();
-                        starknet_exec_scope
-                            .storage
-                            .entry(contract)
-                            .or_default()
-                            .insert(addr, value);
+                        let contract = starknet_state.exec_info.contract_address.clone();
+                        starknet_state.storage.entry(contract).or_default().insert(addr, value);
                         Ok(None)
                     })?;
                 } else if selector == "StorageRead".as_bytes() {
-                    check_handle_oog(4, 100, &mut |vm| {
+                    check_handle_oog(4, 100, &mut |vm, _| {
                         let addr_domain =
                             get_double_deref_val(vm, cell, &(base_offset.clone() + 2u32))?;
                         if !addr_domain.is_zero() {
                             // Only address_domain 0 is currently supported.
-                            return Ok(Some(Felt252::from_bytes_be(b"Unsupported address domain")));
+                            return Ok(Some(vec![Felt252::from_bytes_be(
+                                b"Unsupported address domain",
+                            )]));
                         }
                         let addr = get_double_deref_val(vm, cell, &(base_offset.clone() + 3u32))?;
-                        let value = starknet_exec_scope
+                        let value = starknet_state
                             .storage
-                            .get(&starknet_exec_scope.exec_info.contract_address)
+                            .get(&starknet_state.exec_info.contract_address)
                             .and_then(|contract_storage| contract_storage.get(&addr))
                             .cloned()
                             .unwrap_or_else(|| Felt252::from(0));
@@ -360,9 +947,9 @@ impl HintProcessor for CairoHintProcessor {
                         Ok(None)
                     })?;
                 } else if selector == "GetExecutionInfo".as_bytes() {
-                    check_handle_oog(2, 50, &mut |vm| {
+                    check_handle_oog(2, 50, &mut |vm, _| {
                         let result_ptr = get_ptr(vm, cell, &(base_offset.clone() + 4u32))?;
-                        let exec_info = &starknet_exec_scope.exec_info;
+                        let exec_info = &starknet_state.exec_info;
                         let block_info = &exec_info.block_info;
                         let tx_info = &exec_info.tx_info;
                         let mut res_segment = vm.add_memory_segment();
@@ -397,38 +984,250 @@ impl HintProcessor for CairoHintProcessor {
                         Ok(None)
                     })?;
                 } else if selector == "EmitEvent".as_bytes() {
-                    check_handle_oog(6, 50, &mut |vm| {
-                        let _keys_start_ptr = get_ptr(vm, cell, &(base_offset.clone() + 2u32))?;
-                        let _keys_end_ptr = get_ptr(vm, cell, &(base_offset.clone() + 3u32))?;
-                        let _values_start_ptr = get_ptr(vm, cell, &(base_offset.clone() + 4u32))?;
-                        let _values_end_ptr = get_ptr(vm, cell, &(base_offset.clone() + 5u32))?;
+                    check_handle_oog(6, 50, &mut |vm, _| {
+                        let keys = get_felt252_span(vm, cell, &(base_offset.clone() + 2u32))?;
+                        let data = get_felt252_span(vm, cell, &(base_offset.clone() + 4u32))?;
+                        starknet_state.emit_event(keys, data);
                         Ok(None)
                     })?;
                 } else if selector == "CallContract".as_bytes() {
-                    todo!()
+                    check_handle_oog(6, 50, &mut |vm, gas_counter| {
+                        let contract_address =
+                            get_double_deref_val(vm, cell, &(base_offset.clone() + 2u32))?;
+                        let entry_point_selector =
+                            get_double_deref_val(vm, cell, &(base_offset.clone() + 3u32))?;
+                        let calldata = get_felt252_span(vm, cell, &(base_offset.clone() + 4u32))?;
+                        match starknet_state.call_contract(
+                            contract_address,
+                            entry_point_selector,
+                            calldata,
+                            gas_counter,
+                        )? {
+                            Ok(retdata) => {
+                                let retdata_ptr = get_ptr(vm, cell, &(base_offset.clone() + 8u32))?;
+                                write_felt252_span(vm, &retdata_ptr, retdata)?;
+                                Ok(None)
+                            }
+                            Err(revert_reason) => Ok(Some(revert_reason)),
+                        }
+                    })?;
+                } else if selector == "LibraryCall".as_bytes() {
+                    check_handle_oog(6, 50, &mut |vm, gas_counter| {
+                        let class_hash =
+                            get_double_deref_val(vm, cell, &(base_offset.clone() + 2u32))?;
+                        let entry_point_selector =
+                            get_double_deref_val(vm, cell, &(base_offset.clone() + 3u32))?;
+                        let calldata = get_felt252_span(vm, cell, &(base_offset.clone() + 4u32))?;
+                        match starknet_state.library_call(
+                            class_hash,
+                            entry_point_selector,
+                            calldata,
+                            gas_counter,
+                        )? {
+                            Ok(retdata) => {
+                                let retdata_ptr = get_ptr(vm, cell, &(base_offset.clone() + 8u32))?;
+                                write_felt252_span(vm, &retdata_ptr, retdata)?;
+                                Ok(None)
+                            }
+                            Err(revert_reason) => Ok(Some(revert_reason)),
+                        }
+                    })?;
+                } else if selector == "Deploy".as_bytes() {
+                    check_handle_oog(7, 50, &mut |vm, gas_counter| {
+                        let class_hash =
+                            get_double_deref_val(vm, cell, &(base_offset.clone() + 2u32))?;
+                        let contract_address_salt =
+                            get_double_deref_val(vm, cell, &(base_offset.clone() + 3u32))?;
+                        let calldata = get_felt252_span(vm, cell, &(base_offset.clone() + 4u32))?;
+                        let deploy_from_zero =
+                            get_double_deref_val(vm, cell, &(base_offset.clone() + 6u32))?;
+                        match starknet_state.deploy(
+                            class_hash,
+                            contract_address_salt,
+                            calldata,
+                            !deploy_from_zero.is_zero(),
+                            gas_counter,
+                        )? {
+                            Ok((contract_address, retdata)) => {
+                                let contract_address_ptr =
+                                    get_ptr(vm, cell, &(base_offset.clone() + 9u32))?;
+                                vm.insert_value(&contract_address_ptr, contract_address)?;
+                                let retdata_ptr =
+                                    get_ptr(vm, cell, &(base_offset.clone() + 10u32))?;
+                                write_felt252_span(vm, &retdata_ptr, retdata)?;
+                                Ok(None)
+                            }
+                            Err(revert_reason) => Ok(Some(revert_reason)),
+                        }
+                    })?;
+                } else if selector == "ReplaceClass".as_bytes() {
+                    check_handle_oog(3, 50, &mut |vm, _| {
+                        let class_hash =
+                            get_double_deref_val(vm, cell, &(base_offset.clone() + 2u32))?;
+                        Ok(starknet_state.replace_class(class_hash))
+                    })?;
+                } else if selector == "SendMessageToL1".as_bytes() {
+                    check_handle_oog(5, 50, &mut |vm, _| {
+                        let to_address =
+                            get_double_deref_val(vm, cell, &(base_offset.clone() + 2u32))?;
+                        let payload = get_felt252_span(vm, cell, &(base_offset.clone() + 3u32))?;
+                        starknet_state.send_message_to_l1(to_address, payload);
+                        Ok(None)
+                    })?;
+                } else if selector == "Keccak".as_bytes() {
+                    check_handle_oog(4, 50, &mut |vm, gas_counter| {
+                        let input = get_felt252_span(vm, cell, &(base_offset.clone() + 2u32))?;
+                        if input.len() % KECCAK_FULL_RATE_IN_U64S != 0 {
+                            return Ok(Some(vec![Felt252::from_bytes_be(
+                                b"Invalid keccak input size",
+                            )]));
+                        }
+                        let Some(input) =
+                            input.iter().map(|word| word.to_u64()).collect::<Option<Vec<_>>>()
+                        else {
+                            return Ok(Some(vec![Felt252::from_bytes_be(
+                                b"Invalid keccak input word",
+                            )]));
+                        };
+                        let mut state = [0u64; 25];
+                        for block in input.chunks(KECCAK_FULL_RATE_IN_U64S) {
+                            if *gas_counter < KECCAK_ROUND_COST {
+                                return Ok(Some(vec![Felt252::from_bytes_be(
+                                    b"Syscall out of gas",
+                                )]));
+                            }
+                            *gas_counter -= KECCAK_ROUND_COST;
+                            for (lane, word) in state.iter_mut().zip(block) {
+                                *lane ^= word;
+                            }
+                            keccak::f1600(&mut state);
+                        }
+                        let result = state[..4]
+                            .iter()
+                            .rev()
+                            .fold(BigUint::zero(), |acc, lane| (acc << 64) + *lane);
+                        let result_ptr = get_ptr(vm, cell, &(base_offset.clone() + 6u32))?;
+                        write_u256(vm, &result_ptr, result)?;
+                        Ok(None)
+                    })?;
+                } else if selector == "Secp256k1Add".as_bytes() {
+                    check_handle_oog(4, 500, &mut |vm, _| {
+                        let exec_scope = secp256k1::get_exec_scope(exec_scopes)?;
+                        let p0_id = get_double_deref_val(vm, cell, &(base_offset.clone() + 2u32))?;
+                        let Some(p0) = exec_scope.get_point(&p0_id) else {
+                            return Ok(Some(secp256k1::unknown_point_revert_reason()));
+                        };
+                        let p1_id = get_double_deref_val(vm, cell, &(base_offset.clone() + 3u32))?;
+                        let Some(p1) = exec_scope.get_point(&p1_id) else {
+                            return Ok(Some(secp256k1::unknown_point_revert_reason()));
+                        };
+                        let result_ptr = get_ptr(vm, cell, &(base_offset.clone() + 6u32))?;
+                        vm.insert_value(&result_ptr, exec_scope.add_point(secp256k1::add(p0, p1)))?;
+                        Ok(None)
+                    })?;
+                } else if selector == "Secp256k1Mul".as_bytes() {
+                    check_handle_oog(5, 5000, &mut |vm, _| {
+                        let exec_scope = secp256k1::get_exec_scope(exec_scopes)?;
+                        let p_id = get_double_deref_val(vm, cell, &(base_offset.clone() + 2u32))?;
+                        let Some(p) = exec_scope.get_point(&p_id) else {
+                            return Ok(Some(secp256k1::unknown_point_revert_reason()));
+                        };
+                        let scalar = get_u256(vm, cell, &(base_offset.clone() + 3u32))?;
+                        let result_ptr = get_ptr(vm, cell, &(base_offset.clone() + 7u32))?;
+                        vm.insert_value(
+                            &result_ptr,
+                            exec_scope.add_point(secp256k1::mul(p, &scalar)),
+                        )?;
+                        Ok(None)
+                    })?;
+                } else if selector == "Secp256k1GetPointFromX".as_bytes() {
+                    check_handle_oog(5, 500, &mut |vm, _| {
+                        let exec_scope = secp256k1::get_exec_scope(exec_scopes)?;
+                        let x = get_u256(vm, cell, &(base_offset.clone() + 2u32))?;
+                        let y_parity =
+                            get_double_deref_val(vm, cell, &(base_offset.clone() + 4u32))?;
+                        let result_ptr = get_ptr(vm, cell, &(base_offset.clone() + 7u32))?;
+                        // Writing the `Option<Secp256K1EcPoint>` variant, and its value.
+                        match secp256k1::get_point_from_x(&x, !y_parity.is_zero()) {
+                            Some(p) => {
+                                vm.insert_value(&result_ptr, Felt252::from(0))?;
+                                vm.insert_value(&(result_ptr + 1), exec_scope.add_point(p))?;
+                            }
+                            None => {
+                                vm.insert_value(&result_ptr, Felt252::from(1))?;
+                                vm.insert_value(&(result_ptr + 1), Felt252::from(0))?;
+                            }
+                        }
+                        Ok(None)
+                    })?;
+                } else if selector == "Secp256k1GetXy".as_bytes() {
+                    check_handle_oog(3, 50, &mut |vm, _| {
+                        let exec_scope = secp256k1::get_exec_scope(exec_scopes)?;
+                        let p_id = get_double_deref_val(vm, cell, &(base_offset.clone() + 2u32))?;
+                        let Some(p) = exec_scope.get_point(&p_id) else {
+                            return Ok(Some(secp256k1::unknown_point_revert_reason()));
+                        };
+                        let (x, y) = secp256k1::coordinates(p);
+                        let result_ptr = get_ptr(vm, cell, &(base_offset.clone() + 5u32))?;
+                        write_u256(vm, &result_ptr, x)?;
+                        write_u256(vm, &(result_ptr + 2), y)?;
+                        Ok(None)
+                    })?;
                 } else {
                     panic!("Unknown selector for system call!");
                 }
             }
             Hint::SetBlockNumber { value } => {
-                starknet_execution_scope(exec_scopes)?.exec_info.block_info.block_number =
-                    get_val(vm, value)?;
+                self.starknet_state.exec_info.block_info.block_number = get_val(vm, value)?;
             }
             Hint::SetSequencerAddress { value } => {
-                starknet_execution_scope(exec_scopes)?.exec_info.block_info.sequencer_address =
-                    get_val(vm, value)?;
+                self.starknet_state.exec_info.block_info.sequencer_address = get_val(vm, value)?;
             }
             Hint::SetBlockTimestamp { value } => {
-                starknet_execution_scope(exec_scopes)?.exec_info.block_info.block_timestamp =
-                    get_val(vm, value)?;
+                self.starknet_state.exec_info.block_info.block_timestamp = get_val(vm, value)?;
             }
             Hint::SetCallerAddress { value } => {
-                starknet_execution_scope(exec_scopes)?.exec_info.caller_address =
-                    get_val(vm, value)?;
+                self.starknet_state.exec_info.caller_address = get_val(vm, value)?;
             }
             Hint::SetContractAddress { value } => {
-                starknet_execution_scope(exec_scopes)?.exec_info.contract_address =
-                    get_val(vm, value)?;
+                self.starknet_state.exec_info.contract_address = get_val(vm, value)?;
+            }
+            Hint::PopLog { value, opt_variant, keys_start, keys_end, data_start, data_end } => {
+                let contract_logs = self.starknet_state.logs.get_mut(&get_val(vm, value)?);
+                if let Some(Event { keys, data }) =
+                    contract_logs.and_then(|contract_logs| contract_logs.events.pop_front())
+                {
+                    insert_value_to_cellref!(vm, opt_variant, Felt252::from(0))?;
+                    let (keys_start_ptr, keys_end_ptr) = segment_with_data(vm, keys)?;
+                    insert_value_to_cellref!(vm, keys_start, keys_start_ptr)?;
+                    insert_value_to_cellref!(vm, keys_end, keys_end_ptr)?;
+                    let (data_start_ptr, data_end_ptr) = segment_with_data(vm, data)?;
+                    insert_value_to_cellref!(vm, data_start, data_start_ptr)?;
+                    insert_value_to_cellref!(vm, data_end, data_end_ptr)?;
+                } else {
+                    insert_value_to_cellref!(vm, opt_variant, Felt252::from(1))?;
+                }
+            }
+            Hint::PopL2ToL1Message {
+                value,
+                opt_variant,
+                to_address,
+                payload_start,
+                payload_end,
+            } => {
+                let contract_logs = self.starknet_state.logs.get_mut(&get_val(vm, value)?);
+                if let Some(message) = contract_logs
+                    .and_then(|contract_logs| contract_logs.l2_to_l1_messages.pop_front())
+                {
+                    insert_value_to_cellref!(vm, opt_variant, Felt252::from(0))?;
+                    insert_value_to_cellref!(vm, to_address, message.to_address)?;
+                    let (payload_start_ptr, payload_end_ptr) =
+                        segment_with_data(vm, message.payload)?;
+                    insert_value_to_cellref!(vm, payload_start, payload_start_ptr)?;
+                    insert_value_to_cellref!(vm, payload_end, payload_end_ptr)?;
+                } else {
+                    insert_value_to_cellref!(vm, opt_variant, Felt252::from(1))?;
+                }
             }
             Hint::AllocFelt252Dict { segment_arena_ptr } => {
                 let (cell, base_offset) = extract_buffer(segment_arena_ptr);
@@ -702,23 +1501,6 @@ impl HintProcessor for CairoHintProcessor {
     }
 }
 
-/// Returns the starknet execution scope.
-fn starknet_execution_scope(
-    exec_scopes: &mut ExecutionScopes,
-) -> Result<&mut StarknetExecScope, HintError> {
-    Ok(exec_scopes
-        .get_local_variables_mut()?
-        .entry("starknet_exec_scope".to_string())
-        .or_insert_with(|| {
-            Box::new(StarknetExecScope {
-                storage: HashMap::default(),
-                exec_info: ExecutionInfo::default(),
-            })
-        })
-        .downcast_mut::<StarknetExecScope>()
-        .unwrap())
-}
-
 /// Extracts a parameter assumed to be a buffer.
 fn extract_buffer(buffer: &ResOperand) -> (&CellRef, Felt252) {
     let (cell, base_offset) = match buffer {
@@ -737,24 +1519,51 @@ pub struct RunFunctionContext<'a> {
     pub data_len: usize,
 }
 
-/// Runs `program` on layout with prime, and returns the memory layout and ap value.
+/// The memory layout, the ap value, the final Starknet state and the execution trace of a finished
+/// run.
+type RunFunctionRes = (Vec<Option<Felt252>>, usize, StarknetState, Vec<RelocatedTraceEntry>);
+
+/// The memory layout and the relocated execution trace of a finished run.
+type RunProgramRes = (Vec<Option<Felt252>>, Vec<RelocatedTraceEntry>);
+
+/// Runs `program` on layout with prime, on top of the given simulated Starknet state, and returns
+/// the memory layout, the ap value, the final Starknet state and the execution trace.
 pub fn run_function<'a, Instructions: Iterator<Item = &'a Instruction> + Clone>(
     instructions: Instructions,
     builtins: Vec<String>,
     additional_initialization: fn(
         context: RunFunctionContext<'_>,
     ) -> Result<(), Box<VirtualMachineError>>,
-) -> Result<(Vec<Option<Felt252>>, usize), Box<VirtualMachineError>> {
-    let data: Vec<MaybeRelocatable> = instructions
-        .clone()
-        .flat_map(|inst| inst.assemble().encode())
-        .map(Felt252::from)
-        .map(MaybeRelocatable::from)
-        .collect();
+    starknet_state: StarknetState,
+) -> Result<RunFunctionRes, Box<VirtualMachineError>> {
+    let data =
+        encode_instructions(instructions.clone()).into_iter().map(MaybeRelocatable::from).collect();
+    let mut hint_processor = CairoHintProcessor::new(instructions, starknet_state);
+    let (cells, trace) =
+        run_program(data, builtins, additional_initialization, &mut hint_processor)?;
+    let ap = trace.last().unwrap().ap;
+    Ok((cells, ap, hint_processor.starknet_state, trace))
+}
 
-    let mut hint_processor = CairoHintProcessor::new(instructions);
+/// Returns the encoding of `instructions` as felt252s.
+fn encode_instructions<'a>(instructions: impl Iterator<Item = &'a Instruction>) -> Vec<Felt252> {
+    instructions.flat_map(|inst| inst.assemble().encode()).map(Felt252::from).collect()
+}
 
+/// Runs the program given by its encoded `data` using `hint_processor`, and returns the memory
+/// layout and the relocated execution trace.
+fn run_program(
+    data: Vec<MaybeRelocatable>,
+    builtins: Vec<String>,
+    additional_initialization: fn(
+        context: RunFunctionContext<'_>,
+    ) -> Result<(), Box<VirtualMachineError>>,
+    hint_processor: &mut CairoHintProcessor,
+) -> Result<RunProgramRes, Box<VirtualMachineError>> {
     let data_len = data.len();
+    // The Poseidon builtin is not supported by the vm, and is simulated instead.
+    let (poseidon, builtins): (Vec<_>, Vec<_>) =
+        builtins.into_iter().partition(|builtin| builtin == "poseidon");
     let program = Program {
         builtins,
         prime: PRIME_STR.to_string(),
@@ -774,12 +1583,37 @@ pub fn run_function<'a, Instructions: Iterator<Item = &'a Instruction> + Clone>(
         .map_err(Box::new)?;
     let mut vm = VirtualMachine::new(true);
 
-    let end = runner.initialize(&mut vm).map_err(VirtualMachineError::from).map_err(Box::new)?;
+    runner.initialize_builtins(&mut vm).map_err(VirtualMachineError::from).map_err(Box::new)?;
+    runner.initialize_segments(&mut vm, None);
+    // The initial stack holds the builtins' bases, with the simulated Poseidon builtin first.
+    let mut poseidon_builtin = None;
+    let mut stack = vec![];
+    if !poseidon.is_empty() {
+        let base = vm.add_memory_segment();
+        poseidon_builtin = Some(PoseidonBuiltin::new(base));
+        stack.push(MaybeRelocatable::from(base));
+    }
+    for (_name, builtin_runner) in vm.get_builtin_runners() {
+        stack.extend(builtin_runner.initial_stack());
+    }
+    let return_fp = vm.add_memory_segment();
+    let end = runner
+        .initialize_function_entrypoint(&mut vm, 0, stack, return_fp.into())
+        .map_err(VirtualMachineError::from)
+        .map_err(Box::new)?;
+    runner.initialize_vm(&mut vm).map_err(VirtualMachineError::from).map_err(Box::new)?;
 
     additional_initialization(RunFunctionContext { vm: &mut vm, data_len })?;
 
-    runner.run_until_pc(end, &mut vm, &mut hint_processor)?;
-    runner.end_run(true, false, &mut vm, &mut hint_processor).map_err(Box::new)?;
+    let references = runner.get_reference_list();
+    let hint_data_dictionary = runner.get_hint_data_dictionary(&references, hint_processor)?;
+    while *vm.get_pc() != end {
+        if let Some(poseidon_builtin) = &mut poseidon_builtin {
+            poseidon_builtin.deduce_outputs(&mut vm)?;
+        }
+        vm.step(hint_processor, &mut runner.exec_scopes, &hint_data_dictionary, &HashMap::new())?;
+    }
+    runner.end_run(true, false, &mut vm, hint_processor).map_err(Box::new)?;
     runner.relocate(&mut vm).map_err(VirtualMachineError::from).map_err(Box::new)?;
-    Ok((runner.relocated_memory, runner.relocated_trace.unwrap().last().unwrap().ap))
+    Ok((runner.relocated_memory, runner.relocated_trace.unwrap()))
 }
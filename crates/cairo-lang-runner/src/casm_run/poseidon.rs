@@ -0,0 +1,42 @@
+use std::borrow::Cow;
+
+use cairo_felt::Felt as Felt252;
+use cairo_lang_starknet::poseidon::hades_permutation;
+use cairo_vm::types::relocatable::Relocatable;
+use cairo_vm::vm::errors::vm_errors::VirtualMachineError;
+use cairo_vm::vm::vm_core::VirtualMachine;
+
+/// The number of cells of a single builtin instance: the input state followed by the output state.
+const CELLS_PER_INSTANCE: usize = 6;
+
+/// A simulation of the Poseidon builtin, which is not supported by the vm: fills the outputs of
+/// every builtin instance whose inputs were all written.
+pub struct PoseidonBuiltin {
+    /// The start of the builtin segment.
+    base: Relocatable,
+    /// The number of instances whose outputs were already filled.
+    n_deduced_instances: usize,
+}
+impl PoseidonBuiltin {
+    pub fn new(base: Relocatable) -> Self {
+        Self { base, n_deduced_instances: 0 }
+    }
+
+    /// Fills the outputs of the instances whose inputs were written since the last call.
+    pub fn deduce_outputs(&mut self, vm: &mut VirtualMachine) -> Result<(), VirtualMachineError> {
+        loop {
+            let instance = self.base + self.n_deduced_instances * CELLS_PER_INSTANCE;
+            let Some(inputs) = (0..3)
+                .map(|i| vm.get_integer(&(instance + i)).ok().map(Cow::into_owned))
+                .collect::<Option<Vec<_>>>() else {
+                return Ok(());
+            };
+            let mut state: [Felt252; 3] = inputs.try_into().unwrap();
+            hades_permutation(&mut state);
+            for (i, value) in state.into_iter().enumerate() {
+                vm.insert_value(&(instance + 3 + i), value)?;
+            }
+            self.n_deduced_instances += 1;
+        }
+    }
+}
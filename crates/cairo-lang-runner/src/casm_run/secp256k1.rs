@@ -0,0 +1,107 @@
+use ark_ff::fields::{Fp256, MontBackend, MontConfig};
+use ark_ff::{Field, PrimeField, Zero};
+use cairo_felt::Felt as Felt252;
+use cairo_vm::types::exec_scope::ExecutionScopes;
+use cairo_vm::vm::errors::hint_errors::HintError;
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::ToPrimitive;
+
+#[derive(MontConfig)]
+#[modulus = "115792089237316195423570985008687907853269984665640564039457584007908834671663"]
+#[generator = "3"]
+pub struct FqConfig;
+/// The base field of the secp256k1 curve.
+pub type Fq = Fp256<MontBackend<FqConfig, 4>>;
+
+/// A point on the secp256k1 curve `y^2 = x^3 + 7`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Secp256k1Point {
+    Infinity,
+    Affine(Fq, Fq),
+}
+
+/// Returns the point with the x coordinate `x` and a y coordinate of parity `y_parity`, if there
+/// is one.
+pub fn get_point_from_x(x: &BigUint, y_parity: bool) -> Option<Secp256k1Point> {
+    let modulus: BigUint = Fq::MODULUS.into();
+    if *x >= modulus {
+        return None;
+    }
+    let x = Fq::from(x.clone());
+    let y = (x * x * x + Fq::from(7u64)).sqrt()?;
+    let y_big: BigUint = y.into_bigint().into();
+    Some(Secp256k1Point::Affine(x, if y_big.is_odd() == y_parity { y } else { -y }))
+}
+
+/// Returns the sum of the points `p0` and `p1`.
+pub fn add(p0: Secp256k1Point, p1: Secp256k1Point) -> Secp256k1Point {
+    let (Secp256k1Point::Affine(x0, y0), Secp256k1Point::Affine(x1, y1)) = (p0, p1) else {
+        return if p0 == Secp256k1Point::Infinity { p1 } else { p0 };
+    };
+    let slope = if x0 != x1 {
+        (y1 - y0) / (x1 - x0)
+    } else if y0 == y1 && !y0.is_zero() {
+        Fq::from(3u64) * x0 * x0 / (y0 + y0)
+    } else {
+        // `p1 == -p0`.
+        return Secp256k1Point::Infinity;
+    };
+    let x = slope * slope - x0 - x1;
+    let y = slope * (x0 - x) - y0;
+    Secp256k1Point::Affine(x, y)
+}
+
+/// Returns the multiplication of the point `p` by `scalar`.
+pub fn mul(p: Secp256k1Point, scalar: &BigUint) -> Secp256k1Point {
+    (0..scalar.bits()).rev().fold(Secp256k1Point::Infinity, |acc, bit| {
+        let acc = add(acc, acc);
+        if scalar.bit(bit) { add(acc, p) } else { acc }
+    })
+}
+
+/// Returns the coordinates of the point `p`, where the point at infinity is represented as
+/// `(0, 0)`.
+pub fn coordinates(p: Secp256k1Point) -> (BigUint, BigUint) {
+    match p {
+        Secp256k1Point::Infinity => (BigUint::zero(), BigUint::zero()),
+        Secp256k1Point::Affine(x, y) => (x.into_bigint().into(), y.into_bigint().into()),
+    }
+}
+
+/// Execution scope for the secp256k1 system calls, holding the points they created.
+/// A point is identified by its index in this scope.
+#[derive(Default)]
+pub struct Secp256k1ExecScope {
+    points: Vec<Secp256k1Point>,
+}
+impl Secp256k1ExecScope {
+    /// Adds `p` to the scope, and returns its id.
+    pub fn add_point(&mut self, p: Secp256k1Point) -> Felt252 {
+        self.points.push(p);
+        Felt252::from(self.points.len() - 1)
+    }
+
+    /// Returns the point with the id `id`, if it exists.
+    pub fn get_point(&self, id: &Felt252) -> Option<Secp256k1Point> {
+        id.to_usize().and_then(|idx| self.points.get(idx)).copied()
+    }
+}
+
+/// The revert reason of a secp256k1 system call given an id of a point that does not exist.
+pub fn unknown_point_revert_reason() -> Vec<Felt252> {
+    vec![Felt252::from_bytes_be(b"Unknown secp256k1 point")]
+}
+
+/// Returns the secp256k1 execution scope, creating it if it does not exist yet.
+pub fn get_exec_scope(
+    exec_scopes: &mut ExecutionScopes,
+) -> Result<&mut Secp256k1ExecScope, HintError> {
+    if exec_scopes.get_mut_ref::<Secp256k1ExecScope>("secp256k1_exec_scope").is_err() {
+        exec_scopes.assign_or_update_variable(
+            "secp256k1_exec_scope",
+            Box::<Secp256k1ExecScope>::default(),
+        );
+    }
+    exec_scopes.get_mut_ref::<Secp256k1ExecScope>("secp256k1_exec_scope")
+}
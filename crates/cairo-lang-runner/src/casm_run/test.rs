@@ -1,11 +1,11 @@
-use cairo_felt::Felt as Felt252;
+use cairo_felt::{self as felt, felt_str as felt252_str, Felt as Felt252};
 use cairo_lang_casm::inline::CasmContext;
 use cairo_lang_casm::{casm, deref};
 use itertools::Itertools;
 use num_traits::ToPrimitive;
 use test_case::test_case;
 
-use crate::casm_run::run_function;
+use crate::casm_run::{run_function, StarknetState};
 
 #[test_case(
     casm! {
@@ -101,8 +101,9 @@ use crate::casm_run::run_function;
     "simple_division"
 )]
 fn test_runner(function: CasmContext, n_returns: usize, expected: &[i128]) {
-    let (cells, ap) = run_function(function.instructions.iter(), vec![], |_| Ok(()))
-        .expect("Running code failed.");
+    let (cells, ap, _, _) =
+        run_function(function.instructions.iter(), vec![], |_| Ok(()), StarknetState::default())
+            .expect("Running code failed.");
     let cells = cells.into_iter().skip(ap - n_returns);
     assert_eq!(
         cells.take(n_returns).map(|cell| cell.unwrap()).collect_vec(),
@@ -112,7 +113,7 @@ fn test_runner(function: CasmContext, n_returns: usize, expected: &[i128]) {
 
 #[test]
 fn test_allocate_segment() {
-    let (memory, ap) = run_function(
+    let (memory, ap, _, _) = run_function(
         casm! {
             [ap] = 1337, ap++;
             %{ memory[ap] = segments.add() %}
@@ -123,6 +124,7 @@ fn test_allocate_segment() {
         .iter(),
         vec![],
         |_| Ok(()),
+        StarknetState::default(),
     )
     .expect("Running code failed.");
     let ptr = memory[ap]
@@ -132,3 +134,42 @@ fn test_allocate_segment() {
         .expect("Number not in index range.");
     assert_eq!(memory[ptr], Some(Felt252::from(1337)));
 }
+
+#[test]
+fn test_poseidon_builtin() {
+    let (memory, ap, _, _) = run_function(
+        casm! {
+            // The Poseidon builtin pointer is at `[fp - 3]`.
+            [ap] = 1, ap++;
+            [ap - 1] = [[fp - 3]];
+            [ap] = 2, ap++;
+            [ap - 1] = [[fp - 3] + 1];
+            [ap] = 3, ap++;
+            [ap - 1] = [[fp - 3] + 2];
+            [ap] = [[fp - 3] + 3], ap++;
+            [ap] = [[fp - 3] + 4], ap++;
+            [ap] = [[fp - 3] + 5], ap++;
+            ret;
+        }
+        .instructions
+        .iter(),
+        vec!["poseidon".to_string()],
+        |_| Ok(()),
+        StarknetState::default(),
+    )
+    .expect("Running code failed.");
+    assert_eq!(
+        memory[ap - 3..ap].iter().map(|cell| cell.clone().unwrap()).collect_vec(),
+        [
+            felt252_str!(
+                "442682200349489646213731521593476982257703159825582578145778919623645026501"
+            ),
+            felt252_str!(
+                "2233832504250924383748553933071188903279928981104663696710686541536735838182"
+            ),
+            felt252_str!(
+                "2512222140811166287287541003826449032093371832913959128171347018667852712082"
+            ),
+        ]
+    );
+}
@@ -2,12 +2,15 @@
 
 use std::path::Path;
 
-use anyhow::{Context, Ok};
+use anyhow::Context;
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
 use cairo_lang_compiler::project::setup_project;
 use cairo_lang_diagnostics::ToOption;
-use cairo_lang_runner::SierraCasmRunner;
+use cairo_lang_runner::profiling::{ProfileFormat, ProfileResource};
+use cairo_lang_runner::short_string::as_cairo_byte_array;
+use cairo_lang_runner::values::Value;
+use cairo_lang_runner::{RunResultValue, SierraCasmRunner, StarknetState};
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
 use clap::Parser;
@@ -20,12 +23,32 @@ struct Args {
     /// The file to compile and run.
     #[arg(short, long)]
     path: String,
+    /// The path of the function to run, e.g. `fib` or `my_module::fib`.
+    #[arg(short, long, default_value = "main")]
+    function: String,
+    /// The arguments to the function, as a JSON array with a value per parameter, or as
+    /// whitespace-separated values.
+    /// Arrays and structs are given as JSON arrays, `Option` values as `null` or their inner
+    /// value, and other enums as `{"<variant>": <value>}`, where the variant is given by its
+    /// index. felt252 values may also be given as hex strings (`"0x1f"`) or short strings
+    /// (`"'abc'"`).
+    #[arg(short, long, default_value = "[]", allow_hyphen_values = true, verbatim_doc_comment)]
+    args: String,
     /// In cases where gas is available, the amount of provided gas.
     #[arg(long)]
     available_gas: Option<usize>,
     /// Whether to print the memory.
     #[arg(long, default_value_t = false)]
     print_full_memory: bool,
+    /// Whether to print the backtrace of the panic, if the run panics.
+    #[arg(long, default_value_t = false)]
+    backtrace: bool,
+    /// Whether to profile the resources used by the run, and the format of the printed report.
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "top")]
+    profile: Option<ProfileFormat>,
+    /// The resource to report when profiling.
+    #[arg(long, value_enum, default_value_t = ProfileResource::Steps)]
+    profile_resource: ProfileResource,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -40,23 +63,57 @@ fn main() -> anyhow::Result<()> {
     }
 
     let sierra_program = db
-        .get_sierra_program(main_crate_ids)
+        .get_sierra_program_with_debug_info(main_crate_ids)
         .to_option()
         .with_context(|| "Compilation failed without any diagnostics.")?;
     let runner = SierraCasmRunner::new(
-        replace_sierra_ids_in_program(db, &sierra_program),
+        replace_sierra_ids_in_program(db, &sierra_program.program),
         args.available_gas.is_some(),
     )
-    .with_context(|| "Failed setting up runner.")?;
+    .with_context(|| "Failed setting up runner.")?
+    .with_trace(args.backtrace)
+    .with_profiling(args.profile.is_some());
+    let name_suffix = format!("::{}", args.function);
+    let func = runner.find_function(&name_suffix)?;
+    let func_args = runner
+        .args_from_json(func, &parse_args(&args.args))
+        .with_context(|| "Failed parsing the function arguments.")?;
     let result = runner
-        .run_function("::main", &[], args.available_gas)
+        .run_function_with_args(
+            &name_suffix,
+            &func_args,
+            args.available_gas,
+            StarknetState::default(),
+        )
         .with_context(|| "Failed to run the function.")?;
-    match result.value {
-        cairo_lang_runner::RunResultValue::Success(values) => {
-            println!("Run completed successfully, returning {values:?}")
+    match &result.value {
+        RunResultValue::Success(values) => {
+            let value = runner
+                .return_type(func)
+                .and_then(|ty| runner.decode_value(&ty, values, &result.memory));
+            match value {
+                Some(value) => println!("Run completed successfully, returning {value}"),
+                None => println!("Run completed successfully, returning {values:?}"),
+            }
         }
-        cairo_lang_runner::RunResultValue::Panic(values) => {
-            println!("Run panicked with err values: {values:?}")
+        RunResultValue::Panic(values) => {
+            // The panic data is either a serialized `ByteArray` or an array of felt252 values.
+            match as_cairo_byte_array(values) {
+                Some(message) => println!("Run panicked with \"{message}\"."),
+                None => {
+                    let values = Value::Array(values.iter().cloned().map(Value::Felt252).collect());
+                    println!("Run panicked with err values: {values}");
+                }
+            }
+            if let Some(trace) = &result.trace {
+                println!("Backtrace:");
+                for (i, frame) in runner.panic_backtrace(trace).iter().enumerate() {
+                    match sierra_program.debug_info.statement_locations.get(&frame.statement_idx) {
+                        Some(location) => println!("{i:>4}: {} at {location}", frame.function),
+                        None => println!("{i:>4}: {}", frame.function),
+                    }
+                }
+            }
         }
     }
     if let Some(gas) = result.gas_counter {
@@ -72,5 +129,20 @@ fn main() -> anyhow::Result<()> {
         }
         println!("]");
     }
+    if let (Some(format), Some(profiling_info)) = (args.profile, &result.profiling_info) {
+        print!("{}", profiling_info.report(args.profile_resource, format));
+    }
     Ok(())
 }
+
+/// Parses the arguments given in the command line into a JSON value per argument.
+fn parse_args(args: &str) -> Vec<serde_json::Value> {
+    if let Ok(serde_json::Value::Array(values)) = serde_json::from_str(args) {
+        return values;
+    }
+    args.split_whitespace()
+        .map(|arg| {
+            serde_json::from_str(arg).unwrap_or_else(|_| serde_json::Value::String(arg.into()))
+        })
+        .collect()
+}
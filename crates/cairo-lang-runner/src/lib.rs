@@ -1,21 +1,24 @@
 //! Basic runner for running a Sierra program on the vm.
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use cairo_felt::Felt as Felt252;
-use cairo_lang_casm::instructions::Instruction;
+use cairo_lang_casm::instructions::{Instruction, InstructionBody};
+use cairo_lang_casm::operand::{Register, ResOperand};
 use cairo_lang_casm::{casm, casm_extend};
 use cairo_lang_sierra::extensions::bitwise::BitwiseType;
 use cairo_lang_sierra::extensions::builtin_cost::CostTokenType;
-use cairo_lang_sierra::extensions::core::{CoreLibfunc, CoreType};
+use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
 use cairo_lang_sierra::extensions::ec::EcOpType;
-use cairo_lang_sierra::extensions::enm::EnumType;
+use cairo_lang_sierra::extensions::enm::{EnumConcreteLibfunc, EnumType};
 use cairo_lang_sierra::extensions::gas::GasBuiltinType;
 use cairo_lang_sierra::extensions::pedersen::PedersenType;
+use cairo_lang_sierra::extensions::poseidon::PoseidonType;
 use cairo_lang_sierra::extensions::range_check::RangeCheckType;
 use cairo_lang_sierra::extensions::segment_arena::SegmentArenaType;
 use cairo_lang_sierra::extensions::starknet::syscalls::SystemType;
 use cairo_lang_sierra::extensions::{ConcreteType, NamedType};
-use cairo_lang_sierra::program::{Function, GenericArg};
+use cairo_lang_sierra::ids::{ConcreteTypeId, FunctionId, GenericTypeId};
+use cairo_lang_sierra::program::{Function, GenericArg, Statement, StatementIdx};
 use cairo_lang_sierra::program_registry::{ProgramRegistry, ProgramRegistryError};
 use cairo_lang_sierra_ap_change::{calc_ap_changes, ApChangeError};
 use cairo_lang_sierra_gas::gas_info::GasInfo;
@@ -23,12 +26,18 @@ use cairo_lang_sierra_to_casm::compiler::{CairoProgram, CompilationError};
 use cairo_lang_sierra_to_casm::metadata::{calc_metadata, Metadata, MetadataError};
 use cairo_lang_utils::extract_matches;
 use cairo_vm::vm::errors::vm_errors::VirtualMachineError;
-use itertools::chain;
+use cairo_vm::vm::trace::trace_entry::RelocatedTraceEntry;
+use casm_run::{vec_of_builtins, RunFunctionContext};
+pub use casm_run::{ContractLogs, Event, L2ToL1Message, StarknetState};
+use itertools::{chain, Itertools};
 use num_traits::ToPrimitive;
+use profiling::{ProfileResource, ProfilingInfo, ResourceUsage};
 use thiserror::Error;
 
 mod casm_run;
+pub mod profiling;
 pub mod short_string;
+pub mod values;
 
 #[derive(Debug, Error)]
 pub enum RunnerError {
@@ -44,6 +53,10 @@ pub enum RunnerError {
     MissingFunction { suffix: String },
     #[error("Function expects arguments of size {expected} and received {actual} instead.")]
     ArgumentsSizeMismatch { expected: usize, actual: usize },
+    #[error("Function expects {expected} arguments and received {actual} instead.")]
+    ArgumentsCountMismatch { expected: usize, actual: usize },
+    #[error("Invalid argument `{value}` for a parameter of type `{ty}`.")]
+    InvalidArgument { value: String, ty: String },
     #[error(transparent)]
     ProgramRegistryError(#[from] Box<ProgramRegistryError>),
     #[error(transparent)]
@@ -59,6 +72,58 @@ pub struct RunResult {
     pub gas_counter: Option<Felt252>,
     pub memory: Vec<Option<Felt252>>,
     pub value: RunResultValue,
+    /// The simulated Starknet state at the end of the run.
+    pub starknet_state: StarknetState,
+    /// The execution trace of the run, if recorded.
+    pub trace: Option<Vec<TraceEntry>>,
+    /// The resources used by the run, if profiled.
+    pub profiling_info: Option<ProfilingInfo>,
+}
+
+/// An entry of the execution trace of a run, for a single executed instruction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TraceEntry {
+    /// The offset of the instruction in the code of the Sierra program, or `None` for the code
+    /// added by the runner.
+    pub code_offset: Option<usize>,
+    /// The value of the allocation pointer when executing the instruction.
+    pub ap: usize,
+    /// The value of the frame pointer when executing the instruction.
+    pub fp: usize,
+}
+
+/// A frame of the backtrace of a panic.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BacktraceFrame {
+    /// The function running in the frame.
+    pub function: FunctionId,
+    /// The statement executed in the frame - the origin of the panic for the innermost frame, and
+    /// the call to the next frame for the rest.
+    pub statement_idx: StatementIdx,
+}
+
+/// An argument to a function run by the runner.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Arg {
+    /// A single felt252 value.
+    Value(Felt252),
+    /// An array of felt252 values, allocated in a new segment before the run, and passed as its
+    /// start and end pointers.
+    Array(Vec<Felt252>),
+}
+impl Arg {
+    /// Returns the number of memory cells this argument occupies when passed to a function.
+    pub fn size(&self) -> usize {
+        match self {
+            Arg::Value(_) => 1,
+            Arg::Array(_) => 2,
+        }
+    }
+}
+impl From<Felt252> for Arg {
+    fn from(value: Felt252) -> Self {
+        Arg::Value(value)
+    }
 }
 
 /// The ran function return value.
@@ -83,6 +148,10 @@ pub struct SierraCasmRunner {
     sierra_program_registry: ProgramRegistry<CoreType, CoreLibfunc>,
     /// The casm program matching the Sierra code.
     casm_program: CairoProgram,
+    /// Whether to record the execution trace of the runs.
+    record_trace: bool,
+    /// Whether to profile the resources used by the runs.
+    profiling: bool,
 }
 impl SierraCasmRunner {
     pub fn new(
@@ -94,7 +163,27 @@ impl SierraCasmRunner {
             ProgramRegistry::<CoreType, CoreLibfunc>::new(&sierra_program)?;
         let casm_program =
             cairo_lang_sierra_to_casm::compiler::compile(&sierra_program, &metadata, calc_gas)?;
-        Ok(Self { sierra_program, metadata, sierra_program_registry, casm_program })
+        Ok(Self {
+            sierra_program,
+            metadata,
+            sierra_program_registry,
+            casm_program,
+            record_trace: false,
+            profiling: false,
+        })
+    }
+
+    /// Sets whether to record the execution trace of the runs, returned in [RunResult::trace].
+    pub fn with_trace(mut self, record_trace: bool) -> Self {
+        self.record_trace = record_trace;
+        self
+    }
+
+    /// Sets whether to profile the resources used by the runs, returned in
+    /// [RunResult::profiling_info].
+    pub fn with_profiling(mut self, profiling: bool) -> Self {
+        self.profiling = profiling;
+        self
     }
 
     /// Runs the vm starting from a function. Function may have implicits, but no other ref params.
@@ -104,31 +193,57 @@ impl SierraCasmRunner {
         name_suffix: &str,
         args: &[Felt252],
         available_gas: Option<usize>,
+    ) -> Result<RunResult, RunnerError> {
+        self.run_function_with_starknet_state(
+            name_suffix,
+            args,
+            available_gas,
+            StarknetState::default(),
+        )
+    }
+
+    /// Runs the vm starting from a function, as [Self::run_function], on top of the given
+    /// simulated Starknet state - allowing the function to interact with the contracts deployed in
+    /// it.
+    pub fn run_function_with_starknet_state(
+        &self,
+        name_suffix: &str,
+        args: &[Felt252],
+        available_gas: Option<usize>,
+        starknet_state: StarknetState,
+    ) -> Result<RunResult, RunnerError> {
+        let args = args.iter().cloned().map(Arg::Value).collect_vec();
+        self.run_function_with_args(name_suffix, &args, available_gas, starknet_state)
+    }
+
+    /// Runs the vm starting from a function, as [Self::run_function_with_starknet_state], with
+    /// arguments that may be arrays.
+    pub fn run_function_with_args(
+        &self,
+        name_suffix: &str,
+        args: &[Arg],
+        available_gas: Option<usize>,
+        starknet_state: StarknetState,
     ) -> Result<RunResult, RunnerError> {
         let func = self.find_function(name_suffix)?;
         let initial_gas = self.get_initial_available_gas(func, available_gas)?;
         let (entry_code, builtins) = self.create_entry_code(func, args, initial_gas)?;
         let footer = self.create_code_footer();
-        let (cells, ap) = casm_run::run_function(
+        let entry_code_size = entry_code.iter().map(|instruction| instruction.body.op_size()).sum();
+        let (cells, ap, starknet_state, trace) = casm_run::run_function(
             chain!(entry_code.iter(), self.casm_program.instructions.iter(), footer.iter()),
             builtins,
-            |context| {
-                let vm = context.vm;
-                // Create the builtin cost segment, with dummy values.
-                let builtin_cost_segment = vm.add_memory_segment();
-                for token_type in CostTokenType::iter_precost() {
-                    vm.insert_value(
-                        &(builtin_cost_segment + (token_type.offset_in_builtin_costs() as usize)),
-                        Felt252::from(DUMMY_BUILTIN_GAS_COST),
-                    )?;
-                }
-                // Put a pointer to the builtin cost segment at the end of the program (after the
-                // additional `ret` statement).
-                vm.insert_value(&(vm.get_pc() + context.data_len), builtin_cost_segment)?;
-                Ok(())
-            },
+            initialize_vm,
+            starknet_state,
         )?;
         let mut results_data = self.get_results_data(func, &cells, ap)?;
+        let code_trace = if self.record_trace || self.profiling {
+            self.get_code_trace(&trace, entry_code_size)
+        } else {
+            vec![]
+        };
+        let profiling_info =
+            self.profiling.then(|| self.get_profiling_info(&code_trace, &cells, &results_data));
         // Handling implicits.
         let mut gas_counter = None;
         results_data.retain_mut(|(ty, values)| {
@@ -139,12 +254,7 @@ impl SierraCasmRunner {
                 assert!(values.is_empty());
                 false
             } else {
-                *generic_ty != RangeCheckType::ID
-                    && *generic_ty != BitwiseType::ID
-                    && *generic_ty != EcOpType::ID
-                    && *generic_ty != PedersenType::ID
-                    && *generic_ty != SystemType::ID
-                    && *generic_ty != SegmentArenaType::ID
+                !self.is_implicit(ty)
             }
         });
         assert!(results_data.len() <= 1);
@@ -155,7 +265,207 @@ impl SierraCasmRunner {
             let [(ty, values)] = <[_; 1]>::try_from(results_data).ok().unwrap();
             self.handle_main_return_value(ty, values, &cells)?
         };
-        Ok(RunResult { gas_counter, memory: cells, value })
+        let trace = self.record_trace.then_some(code_trace);
+        Ok(RunResult { gas_counter, memory: cells, value, starknet_state, trace, profiling_info })
+    }
+
+    /// Converts the relocated trace of a run to [TraceEntry]s, given the size of the code added by
+    /// the runner before the Sierra program code.
+    fn get_code_trace(
+        &self,
+        trace: &[RelocatedTraceEntry],
+        entry_code_size: usize,
+    ) -> Vec<TraceEntry> {
+        // The run starts at the beginning of the code.
+        let Some(code_start) = trace.first().map(|entry| entry.pc) else { return vec![] };
+        let program_size = self
+            .casm_program
+            .debug_info
+            .sierra_statement_info
+            .last()
+            .map_or(0, |info| info.code_offset);
+        trace
+            .iter()
+            .map(|entry| TraceEntry {
+                code_offset: (entry.pc - code_start)
+                    .checked_sub(entry_code_size)
+                    .filter(|code_offset| *code_offset < program_size),
+                ap: entry.ap,
+                fp: entry.fp,
+            })
+            .collect()
+    }
+
+    /// Returns the backtrace of the panic of a run given its trace, starting from the innermost
+    /// frame. Returns an empty backtrace if the run did not panic.
+    pub fn panic_backtrace(&self, trace: &[TraceEntry]) -> Vec<BacktraceFrame> {
+        // The frames of the call stack, by their frame pointer, and the last statement executed in
+        // each of them.
+        let mut frames: Vec<(usize, StatementIdx)> = vec![];
+        for entry in trace {
+            let Some(code_offset) = entry.code_offset else { continue };
+            let Some(statement_idx) =
+                self.casm_program.debug_info.statement_at_code_offset(code_offset)
+            else {
+                continue;
+            };
+            // Returning from a function restores the frame pointer of the caller.
+            while frames.last().is_some_and(|(fp, _)| *fp > entry.fp) {
+                frames.pop();
+            }
+            match frames.last_mut() {
+                Some((fp, last_statement_idx)) if *fp == entry.fp => {
+                    *last_statement_idx = statement_idx
+                }
+                _ => frames.push((entry.fp, statement_idx)),
+            }
+            // The panic is propagated by the callers, so the first panic is its origin.
+            if let Some(panic_statement_idx) = self.find_panic_statement(code_offset) {
+                frames.last_mut().unwrap().1 = panic_statement_idx;
+                return frames
+                    .iter()
+                    .rev()
+                    .map(|(_, statement_idx)| BacktraceFrame {
+                        function: self.get_statement_function(*statement_idx),
+                        statement_idx: *statement_idx,
+                    })
+                    .collect();
+            }
+        }
+        vec![]
+    }
+
+    /// Returns the resources used by each call stack of a run, given its code trace, its memory at
+    /// the end of the run and its returned values.
+    fn get_profiling_info(
+        &self,
+        trace: &[TraceEntry],
+        cells: &[Option<Felt252>],
+        results_data: &[(ConcreteTypeId, Vec<Felt252>)],
+    ) -> ProfilingInfo {
+        let cell_value = |address: usize| cells.get(address)?.as_ref()?.to_usize();
+        // The addresses used by each builtin in the run - from its base, found in the frame of the
+        // entry code, to its final value, returned by the function.
+        let builtin_offsets = builtin_offsets();
+        let builtin_segments = results_data
+            .iter()
+            .filter_map(|(ty, values)| {
+                let generic_id = &self.get_info(ty).long_id.generic_id;
+                let (resource, cells_per_instance) = profiled_builtin(generic_id)?;
+                let base_address = trace.first()?.fp - *builtin_offsets.get(generic_id)? as usize;
+                let addresses = cell_value(base_address)?..values[0].to_usize()?;
+                Some((addresses, resource, cells_per_instance))
+            })
+            .collect_vec();
+        let instruction_offsets = self
+            .casm_program
+            .instructions
+            .iter()
+            .scan(0, |code_offset, instruction| {
+                let instruction_offset = *code_offset;
+                *code_offset += instruction.body.op_size();
+                Some(instruction_offset)
+            })
+            .collect_vec();
+        let mut info = ProfilingInfo::default();
+        let mut used_builtin_instances = HashSet::new();
+        // The frame pointers of the frames of the call stack, and the functions running in them.
+        let mut frames: Vec<usize> = vec![];
+        let mut stack: Vec<FunctionId> = vec![];
+        for (i, entry) in trace.iter().enumerate() {
+            let Some(code_offset) = entry.code_offset else { continue };
+            // Returning from a function restores the frame pointer of the caller.
+            while frames.last().is_some_and(|fp| *fp > entry.fp) {
+                frames.pop();
+                stack.pop();
+            }
+            if frames.last() != Some(&entry.fp) {
+                let Some(statement_idx) =
+                    self.casm_program.debug_info.statement_at_code_offset(code_offset)
+                else {
+                    continue;
+                };
+                frames.push(entry.fp);
+                stack.push(self.get_statement_function(statement_idx));
+            }
+            if !info.stack_usage.contains_key(&stack) {
+                info.stack_usage.insert(stack.clone(), ResourceUsage::default());
+            }
+            let usage = info.stack_usage.get_mut(&stack).unwrap();
+            usage.steps += 1;
+            // The cells allocated by the instruction that were never written are memory holes.
+            if let Some(next_entry) = trace.get(i + 1) {
+                usage.memory_holes += (entry.ap..next_entry.ap)
+                    .filter(|address| matches!(cells.get(*address), Some(None)))
+                    .count();
+            }
+            // Builtin segments are only accessed by double dereference operands.
+            let Ok(instruction_idx) = instruction_offsets.binary_search(&code_offset) else {
+                continue;
+            };
+            let operand = match &self.casm_program.instructions[instruction_idx].body {
+                InstructionBody::AssertEq(instruction) => &instruction.b,
+                InstructionBody::AddAp(instruction) => &instruction.operand,
+                _ => continue,
+            };
+            let ResOperand::DoubleDeref(cell, offset) = operand else { continue };
+            let register = match cell.register {
+                Register::AP => entry.ap,
+                Register::FP => entry.fp,
+            };
+            let Some(address) = register
+                .checked_add_signed(cell.offset as isize)
+                .and_then(cell_value)
+                .and_then(|pointer| pointer.checked_add_signed(*offset as isize))
+            else {
+                continue;
+            };
+            for (addresses, resource, cells_per_instance) in &builtin_segments {
+                if addresses.contains(&address)
+                    && used_builtin_instances
+                        .insert((*resource, (address - addresses.start) / cells_per_instance))
+                {
+                    *usage.get_mut(*resource) += 1;
+                }
+            }
+        }
+        info
+    }
+
+    /// Returns the statement starting at the given code offset that wraps a value with the panic
+    /// variant of a `PanicResult`, if any.
+    fn find_panic_statement(&self, code_offset: usize) -> Option<StatementIdx> {
+        let statement_info = &self.casm_program.debug_info.sierra_statement_info;
+        // Statements compiled to no instructions share the code offset of the following statement.
+        let first = statement_info.partition_point(|info| info.code_offset < code_offset);
+        (first..self.sierra_program.statements.len())
+            .take_while(|idx| statement_info[*idx].code_offset == code_offset)
+            .map(StatementIdx)
+            .find(|idx| {
+                let Statement::Invocation(invocation) = &self.sierra_program.statements[idx.0]
+                else {
+                    return false;
+                };
+                let Ok(CoreConcreteLibfunc::Enum(EnumConcreteLibfunc::Init(libfunc))) =
+                    self.sierra_program_registry.get_libfunc(&invocation.libfunc_id)
+                else {
+                    return false;
+                };
+                let ty = &libfunc.signature.branch_signatures[0].vars[0].ty;
+                libfunc.index == 1 && self.user_type_name(ty).starts_with("core::PanicResult::")
+            })
+    }
+
+    /// Returns the id of the function containing the given statement.
+    fn get_statement_function(&self, statement_idx: StatementIdx) -> FunctionId {
+        self.sierra_program
+            .funcs
+            .iter()
+            .filter(|func| func.entry_point.0 <= statement_idx.0)
+            .max_by_key(|func| func.entry_point.0)
+            .unwrap()
+            .id
+            .clone()
     }
 
     /// Handling the main return value to create a `RunResultValue`.
@@ -217,7 +527,7 @@ impl SierraCasmRunner {
     }
 
     /// Finds first function ending with `name_suffix`.
-    fn find_function(&self, name_suffix: &str) -> Result<&Function, RunnerError> {
+    pub fn find_function(&self, name_suffix: &str) -> Result<&Function, RunnerError> {
         self.sierra_program
             .funcs
             .iter()
@@ -227,6 +537,22 @@ impl SierraCasmRunner {
             .ok_or_else(|| RunnerError::MissingFunction { suffix: name_suffix.to_owned() })
     }
 
+    /// Returns whether the given type is of an implicit, which is passed to and returned from
+    /// functions implicitly, rather than being a parameter or the returned value.
+    fn is_implicit(&self, ty: &cairo_lang_sierra::ids::ConcreteTypeId) -> bool {
+        [
+            GasBuiltinType::ID,
+            RangeCheckType::ID,
+            BitwiseType::ID,
+            EcOpType::ID,
+            PedersenType::ID,
+            PoseidonType::ID,
+            SystemType::ID,
+            SegmentArenaType::ID,
+        ]
+        .contains(&self.get_info(ty).long_id.generic_id)
+    }
+
     fn get_info(
         &self,
         ty: &cairo_lang_sierra::ids::ConcreteTypeId,
@@ -239,24 +565,39 @@ impl SierraCasmRunner {
     fn create_entry_code(
         &self,
         func: &Function,
-        args: &[Felt252],
+        args: &[Arg],
         initial_gas: usize,
     ) -> Result<(Vec<Instruction>, Vec<String>), RunnerError> {
         let mut arg_iter = args.iter();
         let mut expected_arguments_size = 0;
         let mut ctx = casm! {};
         // The builtins in the formatting expected by the runner.
-        let builtins: Vec<_> = ["pedersen", "range_check", "bitwise", "ec_op"]
-            .map(&str::to_string)
-            .into_iter()
-            .collect();
-        // The offset [fp - i] for each of this builtins in this configuration.
-        let builtin_offset: HashMap<cairo_lang_sierra::ids::GenericTypeId, i16> = HashMap::from([
-            (PedersenType::ID, 6),
-            (RangeCheckType::ID, 5),
-            (BitwiseType::ID, 4),
-            (EcOpType::ID, 3),
-        ]);
+        let builtins = vec_of_builtins();
+        let builtin_offset = builtin_offsets();
+        // The segments of the array arguments are allocated before pushing any of the arguments, as
+        // the arguments must be contiguous. As `ap` equals `fp` at this point, the start of the
+        // `k`-th array is stored at `[fp + k]`.
+        let arrays = args
+            .iter()
+            .filter_map(|arg| if let Arg::Array(values) = arg { Some(values) } else { None })
+            .collect_vec();
+        for _ in &arrays {
+            casm_extend! {ctx,
+                %{ memory[ap + 0] = segments.add() %}
+                ap += 1;
+            }
+        }
+        for (start_offset, values) in arrays.iter().enumerate() {
+            let start_offset = start_offset as i16;
+            for (index, value) in values.iter().enumerate() {
+                let index = index as i16;
+                casm_extend! {ctx,
+                    [ap + 0] = (value.to_bigint()), ap++;
+                    [ap - 1] = [[fp + start_offset] + index];
+                }
+            }
+        }
+        let mut array_start_offsets = 0..arrays.len() as i16;
         if func
             .signature
             .param_types
@@ -298,21 +639,34 @@ impl SierraCasmRunner {
                     [ap + 0] = [ap + offset] + 3, ap++;
                 }
             } else {
-                let arg_size = info.size;
-                expected_arguments_size += arg_size as usize;
-                for _ in 0..arg_size {
-                    if let Some(value) = arg_iter.next() {
-                        casm_extend! {ctx,
-                            [ap + 0] = (value.to_bigint()), ap++;
+                let arg_size = info.size as usize;
+                expected_arguments_size += arg_size;
+                let mut taken_size = 0;
+                while taken_size < arg_size {
+                    let Some(arg) = arg_iter.next() else { break };
+                    taken_size += arg.size();
+                    match arg {
+                        Arg::Value(value) => {
+                            casm_extend! {ctx,
+                                [ap + 0] = (value.to_bigint()), ap++;
+                            }
+                        }
+                        Arg::Array(values) => {
+                            let start_offset = array_start_offsets.next().unwrap();
+                            casm_extend! {ctx,
+                                [ap + 0] = [fp + start_offset], ap++;
+                                [ap + 0] = [ap - 1] + (values.len()), ap++;
+                            }
                         }
                     }
                 }
             }
         }
-        if expected_arguments_size != args.len() {
+        let actual_arguments_size: usize = args.iter().map(Arg::size).sum();
+        if expected_arguments_size != actual_arguments_size {
             return Err(RunnerError::ArgumentsSizeMismatch {
                 expected: expected_arguments_size,
-                actual: args.len(),
+                actual: actual_arguments_size,
             });
         }
         let before_final_call = ctx.current_code_offset;
@@ -369,6 +723,49 @@ impl SierraCasmRunner {
     }
 }
 
+/// Returns the offsets `[fp - i]` of the bases of the builtins in the frame of the entry code, by
+/// the builtins' types.
+fn builtin_offsets() -> HashMap<GenericTypeId, i16> {
+    HashMap::from([
+        (PoseidonType::ID, 7),
+        (PedersenType::ID, 6),
+        (RangeCheckType::ID, 5),
+        (BitwiseType::ID, 4),
+        (EcOpType::ID, 3),
+    ])
+}
+
+/// Returns the profiled resource of the usage of a builtin given by its type, and the number of
+/// memory cells of each of the builtin's instances.
+fn profiled_builtin(generic_id: &GenericTypeId) -> Option<(ProfileResource, usize)> {
+    Some(match generic_id {
+        id if *id == RangeCheckType::ID => (ProfileResource::RangeCheck, 1),
+        id if *id == PedersenType::ID => (ProfileResource::Pedersen, 3),
+        id if *id == BitwiseType::ID => (ProfileResource::Bitwise, 5),
+        id if *id == EcOpType::ID => (ProfileResource::EcOp, 7),
+        id if *id == PoseidonType::ID => (ProfileResource::Poseidon, 6),
+        _ => return None,
+    })
+}
+
+/// Initializes the vm for running a program, followed by the code footer: creates the builtin cost
+/// segment, with dummy values, and puts a pointer to it at the end of the program.
+fn initialize_vm(context: RunFunctionContext<'_>) -> Result<(), Box<VirtualMachineError>> {
+    let vm = context.vm;
+    // Create the builtin cost segment, with dummy values.
+    let builtin_cost_segment = vm.add_memory_segment();
+    for token_type in CostTokenType::iter_precost() {
+        vm.insert_value(
+            &(builtin_cost_segment + (token_type.offset_in_builtin_costs() as usize)),
+            Felt252::from(DUMMY_BUILTIN_GAS_COST),
+        )?;
+    }
+    // Put a pointer to the builtin cost segment at the end of the program (after the additional
+    // `ret` statement).
+    vm.insert_value(&(vm.get_pc() + context.data_len), builtin_cost_segment)?;
+    Ok(())
+}
+
 /// Creates the metadata required for a Sierra program lowering to casm.
 fn create_metadata(
     sierra_program: &cairo_lang_sierra::program::Program,
@@ -0,0 +1,215 @@
+//! Profiling of the resources used by runs of Sierra programs.
+
+use std::fmt::Write;
+
+use cairo_lang_sierra::ids::FunctionId;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use clap::ValueEnum;
+use itertools::Itertools;
+
+#[cfg(test)]
+#[path = "profiling_test.rs"]
+mod test;
+
+/// A resource used by a run.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProfileResource {
+    /// The executed Cairo steps.
+    Steps,
+    /// The memory cells allocated and never written.
+    MemoryHoles,
+    /// The used instances of the range check builtin.
+    RangeCheck,
+    /// The used instances of the Pedersen builtin.
+    Pedersen,
+    /// The used instances of the bitwise builtin.
+    Bitwise,
+    /// The used instances of the EC operation builtin.
+    EcOp,
+    /// The used instances of the Poseidon builtin.
+    Poseidon,
+}
+impl ProfileResource {
+    /// Returns the name of the resource, as given in the command line.
+    pub fn name(&self) -> String {
+        self.to_possible_value().unwrap().get_name().to_string()
+    }
+}
+
+/// The format of a profiling report.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProfileFormat {
+    /// A line per function, with the resource used by the function itself (flat) and including
+    /// the functions it calls (cumulative), in the style of `pprof -top`.
+    Top,
+    /// A line per call stack, with its functions separated by `;` followed by the resource used
+    /// by the stack itself, as consumed by flamegraph tools.
+    Collapsed,
+}
+
+/// The amounts of the resources used by a part of a run.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ResourceUsage {
+    pub steps: usize,
+    pub memory_holes: usize,
+    pub range_check: usize,
+    pub pedersen: usize,
+    pub bitwise: usize,
+    pub ec_op: usize,
+    pub poseidon: usize,
+}
+impl ResourceUsage {
+    /// Returns the used amount of the given resource.
+    pub fn get(&self, resource: ProfileResource) -> usize {
+        match resource {
+            ProfileResource::Steps => self.steps,
+            ProfileResource::MemoryHoles => self.memory_holes,
+            ProfileResource::RangeCheck => self.range_check,
+            ProfileResource::Pedersen => self.pedersen,
+            ProfileResource::Bitwise => self.bitwise,
+            ProfileResource::EcOp => self.ec_op,
+            ProfileResource::Poseidon => self.poseidon,
+        }
+    }
+
+    /// Returns a mutable reference to the used amount of the given resource.
+    pub fn get_mut(&mut self, resource: ProfileResource) -> &mut usize {
+        match resource {
+            ProfileResource::Steps => &mut self.steps,
+            ProfileResource::MemoryHoles => &mut self.memory_holes,
+            ProfileResource::RangeCheck => &mut self.range_check,
+            ProfileResource::Pedersen => &mut self.pedersen,
+            ProfileResource::Bitwise => &mut self.bitwise,
+            ProfileResource::EcOp => &mut self.ec_op,
+            ProfileResource::Poseidon => &mut self.poseidon,
+        }
+    }
+
+    /// Adds the resources used by another part of a run.
+    pub fn add(&mut self, other: &ResourceUsage) {
+        for resource in ProfileResource::value_variants() {
+            *self.get_mut(*resource) += other.get(*resource);
+        }
+    }
+}
+
+/// The usage of a resource by a function.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FunctionUsage {
+    pub function: FunctionId,
+    /// The amount used by the function itself.
+    pub flat: usize,
+    /// The amount used by the function, including the functions it calls.
+    pub cumulative: usize,
+}
+
+/// The resources used by a run, by the call stacks using them.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProfilingInfo {
+    /// The resources used by each call stack itself, not including the calls made from it. A call
+    /// stack is given by its functions, starting from the outermost.
+    pub stack_usage: OrderedHashMap<Vec<FunctionId>, ResourceUsage>,
+}
+impl ProfilingInfo {
+    /// Adds the resources used by another run.
+    pub fn merge(&mut self, other: &ProfilingInfo) {
+        for (stack, usage) in other.stack_usage.iter() {
+            match self.stack_usage.get_mut(stack) {
+                Some(existing) => existing.add(usage),
+                None => {
+                    self.stack_usage.insert(stack.clone(), usage.clone());
+                }
+            }
+        }
+    }
+
+    /// Returns the total resources used by the run.
+    pub fn total_usage(&self) -> ResourceUsage {
+        let mut total = ResourceUsage::default();
+        for usage in self.stack_usage.values() {
+            total.add(usage);
+        }
+        total
+    }
+
+    /// Returns the usage of the given resource by each function using it, sorted from the
+    /// function with the largest flat usage.
+    pub fn function_usage(&self, resource: ProfileResource) -> Vec<FunctionUsage> {
+        let mut function_usage = OrderedHashMap::<FunctionId, FunctionUsage>::default();
+        for (stack, usage) in self.stack_usage.iter() {
+            let amount = usage.get(resource);
+            if amount == 0 {
+                continue;
+            }
+            // Recursive functions are counted once per stack in the cumulative usage.
+            for function in stack.iter().unique() {
+                function_usage
+                    .entry(function.clone())
+                    .or_insert_with(|| FunctionUsage {
+                        function: function.clone(),
+                        flat: 0,
+                        cumulative: 0,
+                    })
+                    .cumulative += amount;
+            }
+            if let Some(function) = stack.last() {
+                function_usage.get_mut(function).unwrap().flat += amount;
+            }
+        }
+        function_usage
+            .into_iter()
+            .map(|(_, usage)| usage)
+            .sorted_by(|a, b| {
+                b.flat
+                    .cmp(&a.flat)
+                    .then(b.cumulative.cmp(&a.cumulative))
+                    .then_with(|| a.function.to_string().cmp(&b.function.to_string()))
+            })
+            .collect()
+    }
+
+    /// Returns a report of the usage of the given resource in the given format.
+    pub fn report(&self, resource: ProfileResource, format: ProfileFormat) -> String {
+        match format {
+            ProfileFormat::Top => self.top_report(resource),
+            ProfileFormat::Collapsed => self.collapsed_report(resource),
+        }
+    }
+
+    /// Returns a report of the usage of the given resource by each function, in the style of
+    /// `pprof -top`.
+    fn top_report(&self, resource: ProfileResource) -> String {
+        let total = self.total_usage().get(resource);
+        let percentage = |amount: usize| amount as f64 * 100.0 / total as f64;
+        let mut report = format!("Total {}: {total}\n", resource.name());
+        writeln!(report, "{:>10} {:>7} {:>7} {:>10} {:>7}", "flat", "flat%", "sum%", "cum", "cum%")
+            .unwrap();
+        let mut sum = 0;
+        for usage in self.function_usage(resource) {
+            sum += usage.flat;
+            writeln!(
+                report,
+                "{:>10} {:>6.2}% {:>6.2}% {:>10} {:>6.2}%  {}",
+                usage.flat,
+                percentage(usage.flat),
+                percentage(sum),
+                usage.cumulative,
+                percentage(usage.cumulative),
+                usage.function
+            )
+            .unwrap();
+        }
+        report
+    }
+
+    /// Returns a report of the usage of the given resource by each call stack, in the collapsed
+    /// stacks format of flamegraph tools.
+    fn collapsed_report(&self, resource: ProfileResource) -> String {
+        self.stack_usage
+            .iter()
+            .filter(|(_, usage)| usage.get(resource) != 0)
+            .map(|(stack, usage)| format!("{} {}\n", stack.iter().join(";"), usage.get(resource)))
+            .sorted()
+            .collect()
+    }
+}
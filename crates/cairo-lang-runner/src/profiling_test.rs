@@ -0,0 +1,84 @@
+use cairo_lang_sierra::ids::FunctionId;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use super::{ProfileFormat, ProfileResource, ProfilingInfo, ResourceUsage};
+
+/// Returns the profiling info of a run of `main`, calling `foo` and `bar`, where `foo` calls
+/// `bar` as well.
+fn example_profiling_info() -> ProfilingInfo {
+    let stack = |names: &[&str]| names.iter().map(|name| FunctionId::from_string(*name)).collect();
+    ProfilingInfo {
+        stack_usage: [
+            (stack(&["main"]), ResourceUsage { steps: 10, memory_holes: 1, ..Default::default() }),
+            (stack(&["main", "foo"]), ResourceUsage { steps: 20, ..Default::default() }),
+            (
+                stack(&["main", "foo", "bar"]),
+                ResourceUsage { steps: 40, range_check: 2, ..Default::default() },
+            ),
+            (
+                stack(&["main", "bar"]),
+                ResourceUsage { steps: 30, range_check: 1, ..Default::default() },
+            ),
+        ]
+        .into_iter()
+        .collect(),
+    }
+}
+
+#[test_case(
+    ProfileResource::Steps,
+    ProfileFormat::Top,
+    indoc! {"
+        Total steps: 100
+              flat   flat%    sum%        cum    cum%
+                70  70.00%  70.00%         70  70.00%  bar
+                20  20.00%  90.00%         60  60.00%  foo
+                10  10.00% 100.00%        100 100.00%  main
+    "};
+    "steps top"
+)]
+#[test_case(
+    ProfileResource::Steps,
+    ProfileFormat::Collapsed,
+    indoc! {"
+        main 10
+        main;bar 30
+        main;foo 20
+        main;foo;bar 40
+    "};
+    "steps collapsed"
+)]
+#[test_case(
+    ProfileResource::RangeCheck,
+    ProfileFormat::Collapsed,
+    indoc! {"
+        main;bar 1
+        main;foo;bar 2
+    "};
+    "range check collapsed"
+)]
+#[test_case(
+    ProfileResource::Pedersen,
+    ProfileFormat::Top,
+    indoc! {"
+        Total pedersen: 0
+              flat   flat%    sum%        cum    cum%
+    "};
+    "unused resource top"
+)]
+fn report(resource: ProfileResource, format: ProfileFormat, expected: &str) {
+    assert_eq!(example_profiling_info().report(resource, format), expected);
+}
+
+#[test]
+fn merge() {
+    let mut info = example_profiling_info();
+    info.merge(&example_profiling_info());
+    assert_eq!(
+        info.total_usage(),
+        ResourceUsage { steps: 200, memory_holes: 2, range_check: 6, ..Default::default() }
+    );
+    assert_eq!(info.stack_usage.len(), 4);
+}
@@ -1,4 +1,9 @@
 use cairo_felt::Felt as Felt252;
+use num_traits::{Num, ToPrimitive};
+
+#[cfg(test)]
+#[path = "short_string_test.rs"]
+mod test;
 
 /// Converts a bigint representing a felt252 to a Cairo short-string.
 pub fn as_cairo_short_string(value: &Felt252) -> Option<String> {
@@ -15,3 +20,44 @@ pub fn as_cairo_short_string(value: &Felt252) -> Option<String> {
     }
     Some(as_string)
 }
+
+/// The magic value (in hex) at the start of panic data that holds a serialized Cairo `ByteArray`.
+pub const BYTE_ARRAY_MAGIC: &str =
+    "46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3";
+/// The number of bytes in a full word of a Cairo `ByteArray`.
+const BYTES_IN_WORD: usize = 31;
+
+/// Converts panic data holding the `ByteArray` magic value followed by a serialized Cairo
+/// `ByteArray` to a string.
+pub fn as_cairo_byte_array(values: &[Felt252]) -> Option<String> {
+    let (magic, values) = values.split_first()?;
+    if *magic != Felt252::from_str_radix(BYTE_ARRAY_MAGIC, 16).unwrap() {
+        return None;
+    }
+    let (n_words, values) = values.split_first()?;
+    let n_words = n_words.to_usize()?;
+    let [words @ .., pending_word, pending_word_len] = values else {
+        return None;
+    };
+    let pending_word_len = pending_word_len.to_usize().filter(|len| *len < BYTES_IN_WORD)?;
+    if words.len() != n_words {
+        return None;
+    }
+    let mut bytes = vec![];
+    for word in words {
+        bytes.extend(word_to_bytes(word, BYTES_IN_WORD)?);
+    }
+    bytes.extend(word_to_bytes(pending_word, pending_word_len)?);
+    String::from_utf8(bytes).ok()
+}
+
+/// Returns the big-endian representation of `word` in exactly `n_bytes` bytes, or `None` if it
+/// does not fit.
+fn word_to_bytes(word: &Felt252, n_bytes: usize) -> Option<Vec<u8>> {
+    let bytes = word.to_bytes_be();
+    let first_non_zero = bytes.iter().position(|byte| *byte != 0).unwrap_or(bytes.len());
+    let bytes = &bytes[first_non_zero..];
+    let mut result = vec![0; n_bytes.checked_sub(bytes.len())?];
+    result.extend_from_slice(bytes);
+    Some(result)
+}
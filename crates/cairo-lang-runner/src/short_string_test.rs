@@ -0,0 +1,67 @@
+use cairo_felt::Felt as Felt252;
+use num_traits::Num;
+use test_case::test_case;
+
+use super::{as_cairo_byte_array, word_to_bytes, BYTE_ARRAY_MAGIC};
+
+/// Returns the felt252 holding the big-endian value of the given bytes.
+fn word(bytes: &[u8]) -> Felt252 {
+    Felt252::from_bytes_be(bytes)
+}
+
+/// Returns the panic data of a serialized `ByteArray` with the given full words and pending word,
+/// prefixed by the `ByteArray` magic value.
+fn byte_array_panic_data(words: &[&[u8]], pending_word: &[u8]) -> Vec<Felt252> {
+    let mut values = vec![Felt252::from_str_radix(BYTE_ARRAY_MAGIC, 16).unwrap()];
+    values.push(Felt252::from(words.len()));
+    values.extend(words.iter().map(|bytes| word(bytes)));
+    values.push(word(pending_word));
+    values.push(Felt252::from(pending_word.len()));
+    values
+}
+
+#[test_case(b"abc", 3, Some(b"abc".to_vec()); "exact")]
+#[test_case(b"abc", 5, Some(b"\0\0abc".to_vec()); "padded")]
+#[test_case(b"\0a", 1, Some(b"a".to_vec()); "leading zeros")]
+#[test_case(b"", 0, Some(vec![]); "empty")]
+#[test_case(b"abc", 2, None; "too long")]
+fn test_word_to_bytes(bytes: &[u8], n_bytes: usize, expected: Option<Vec<u8>>) {
+    assert_eq!(word_to_bytes(&word(bytes), n_bytes), expected);
+}
+
+#[test]
+fn test_as_cairo_byte_array() {
+    assert_eq!(
+        as_cairo_byte_array(&byte_array_panic_data(
+            &[b"This is an error message longer"],
+            b" than 31 bytes."
+        )),
+        Some("This is an error message longer than 31 bytes.".into())
+    );
+    assert_eq!(as_cairo_byte_array(&byte_array_panic_data(&[], b"short")), Some("short".into()));
+    assert_eq!(as_cairo_byte_array(&byte_array_panic_data(&[], b"")), Some("".into()));
+}
+
+#[test]
+fn test_as_cairo_byte_array_invalid() {
+    // Missing magic value.
+    let mut values = byte_array_panic_data(&[], b"short");
+    values[0] = Felt252::from(0);
+    assert_eq!(as_cairo_byte_array(&values), None);
+    // Wrong number of full words.
+    let mut values = byte_array_panic_data(&[b"This is an error message longer"], b"");
+    values[1] = Felt252::from(2);
+    assert_eq!(as_cairo_byte_array(&values), None);
+    // Pending word longer than its length.
+    let mut values = byte_array_panic_data(&[], b"short");
+    values[3] = Felt252::from(3);
+    assert_eq!(as_cairo_byte_array(&values), None);
+    // Pending word length of a full word.
+    let mut values = byte_array_panic_data(&[], b"");
+    values[3] = Felt252::from(31);
+    assert_eq!(as_cairo_byte_array(&values), None);
+    // Truncated data.
+    assert_eq!(as_cairo_byte_array(&byte_array_panic_data(&[], b"short")[..3]), None);
+    // Non UTF-8 bytes.
+    assert_eq!(as_cairo_byte_array(&byte_array_panic_data(&[], b"\xff")), None);
+}
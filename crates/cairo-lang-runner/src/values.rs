@@ -0,0 +1,377 @@
+//! Conversion between typed values and the felt252 representation used by the runner, according to
+//! the types of the Sierra program.
+
+use std::fmt::Display;
+
+use cairo_felt::Felt as Felt252;
+use cairo_lang_sierra::extensions::core::CoreTypeConcrete;
+use cairo_lang_sierra::ids::ConcreteTypeId;
+use cairo_lang_sierra::program::{Function, GenericArg};
+use itertools::Itertools;
+use num_bigint::BigInt;
+use num_traits::{Num, One, ToPrimitive, Zero};
+
+use crate::short_string::as_cairo_short_string;
+use crate::{Arg, RunnerError, SierraCasmRunner};
+
+/// A value of a Sierra type, decoded from its felt252 representation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Value {
+    /// A felt252.
+    Felt252(Felt252),
+    /// An integer of one of the integer types.
+    Int(BigInt),
+    /// A `bool`.
+    Bool(bool),
+    /// A tuple, or the unit type if empty.
+    Tuple(Vec<Value>),
+    /// A struct, by the name of its type and the values of its members.
+    Struct { name: String, members: Vec<Value> },
+    /// An enum, by the name of its type, the index of its variant and the inner value.
+    Enum { name: String, variant: usize, value: Box<Value> },
+    /// An array or a span.
+    Array(Vec<Value>),
+    /// A value of a type that cannot be decoded, by the name of its type and its raw felts.
+    Raw { name: String, values: Vec<Felt252> },
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Felt252(value) => match as_printable_short_string(value) {
+                Some(short_string) => write!(f, "{value} ('{short_string}')"),
+                None => write!(f, "{value}"),
+            },
+            Value::Int(value) => write!(f, "{value}"),
+            Value::Bool(value) => write!(f, "{value}"),
+            Value::Tuple(values) if values.len() == 1 => write!(f, "({},)", values[0]),
+            Value::Tuple(values) => write!(f, "({})", values.iter().join(", ")),
+            Value::Struct { name, members } => {
+                write!(f, "{name} {{ {} }}", members.iter().join(", "))
+            }
+            Value::Enum { name, variant, value } => {
+                let is_unit = matches!(value.as_ref(), Value::Tuple(values) if values.is_empty());
+                match (core_variant_name(name, *variant), is_unit) {
+                    (Some(variant), true) => write!(f, "{variant}"),
+                    (Some(variant), false) => write!(f, "{variant}({value})"),
+                    (None, true) => write!(f, "{name}::{variant}"),
+                    (None, false) => write!(f, "{name}::{variant}({value})"),
+                }
+            }
+            Value::Array(values) => write!(f, "[{}]", values.iter().join(", ")),
+            Value::Raw { name, values } => write!(f, "{name}({})", values.iter().join(", ")),
+        }
+    }
+}
+
+/// Returns the content of `value` as a short string, if it is a non-empty one made of printable
+/// characters.
+fn as_printable_short_string(value: &Felt252) -> Option<String> {
+    as_cairo_short_string(value)
+        .filter(|s| !s.is_empty() && s.chars().all(|c| c.is_ascii_graphic() || c == ' '))
+}
+
+/// Returns the name of the given variant of the core enum with the given name, for the core enums
+/// whose variant names are well known.
+fn core_variant_name(enum_name: &str, variant: usize) -> Option<&'static str> {
+    let names: &[&str] = if enum_name.starts_with("core::option::Option::") {
+        &["Some", "None"]
+    } else if enum_name.starts_with("core::result::Result::") {
+        &["Ok", "Err"]
+    } else {
+        return None;
+    };
+    names.get(variant).copied()
+}
+
+/// Returns the inclusive range of values of the integer type with the given Sierra type, or `None`
+/// if it is not an integer type.
+fn integer_range(ty: &CoreTypeConcrete) -> Option<(BigInt, BigInt)> {
+    let unsigned = |bits: u32| (BigInt::zero(), (BigInt::one() << bits) - 1);
+    let signed = |bits: u32| (-(BigInt::one() << (bits - 1)), (BigInt::one() << (bits - 1)) - 1);
+    Some(match ty {
+        CoreTypeConcrete::Uint8(_) => unsigned(8),
+        CoreTypeConcrete::Uint16(_) => unsigned(16),
+        CoreTypeConcrete::Uint32(_) => unsigned(32),
+        CoreTypeConcrete::Uint64(_) => unsigned(64),
+        CoreTypeConcrete::Uint128(_) => unsigned(128),
+        CoreTypeConcrete::Sint8(_) => signed(8),
+        CoreTypeConcrete::Sint16(_) => signed(16),
+        CoreTypeConcrete::Sint32(_) => signed(32),
+        CoreTypeConcrete::Sint64(_) => signed(64),
+        CoreTypeConcrete::Sint128(_) => signed(128),
+        _ => return None,
+    })
+}
+
+/// Returns the variant selector of the variant with the given index, in an enum with the given
+/// number of variants. See `build_enum_init` in the Sierra to CASM compiler.
+fn variant_selector(n_variants: usize, index: usize) -> usize {
+    if n_variants <= 2 { index } else { 2 * (n_variants - index) - 1 }
+}
+
+/// Returns the index of the variant with the given selector, in an enum with the given number of
+/// variants. The inverse of [variant_selector].
+fn variant_index(n_variants: usize, selector: usize) -> Option<usize> {
+    if n_variants <= 2 {
+        Some(selector).filter(|index| *index < n_variants)
+    } else {
+        let index = n_variants.checked_sub(selector.div_ceil(2))?;
+        Some(index).filter(|index| selector % 2 == 1 && *index < n_variants)
+    }
+}
+
+/// Parses a felt252 from a JSON number, or from a string holding a decimal or hex number, or a
+/// short string in single quotes.
+fn parse_felt252(value: &serde_json::Value) -> Option<BigInt> {
+    match value {
+        serde_json::Value::Number(number) => number.as_i64().map(BigInt::from),
+        serde_json::Value::String(s) => {
+            if let Some(short_string) = s.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')) {
+                (short_string.len() <= 31 && short_string.is_ascii())
+                    .then(|| BigInt::from_bytes_be(num_bigint::Sign::Plus, short_string.as_bytes()))
+            } else if let Some(hex) = s.strip_prefix("0x") {
+                BigInt::from_str_radix(hex, 16).ok()
+            } else {
+                BigInt::from_str_radix(s, 10).ok()
+            }
+        }
+        _ => None,
+    }
+}
+
+impl SierraCasmRunner {
+    /// Converts the given JSON values, one per non-implicit parameter of `func`, to the arguments
+    /// for running it.
+    pub fn args_from_json(
+        &self,
+        func: &Function,
+        values: &[serde_json::Value],
+    ) -> Result<Vec<Arg>, RunnerError> {
+        let param_types =
+            func.signature.param_types.iter().filter(|ty| !self.is_implicit(ty)).collect_vec();
+        if param_types.len() != values.len() {
+            return Err(RunnerError::ArgumentsCountMismatch {
+                expected: param_types.len(),
+                actual: values.len(),
+            });
+        }
+        let mut args = vec![];
+        for (ty, value) in param_types.into_iter().zip(values) {
+            self.push_arg_from_json(ty, value, &mut args)?;
+        }
+        Ok(args)
+    }
+
+    /// Converts a JSON value to the arguments representing a value of the given type, and pushes
+    /// them to `args`.
+    fn push_arg_from_json(
+        &self,
+        ty: &ConcreteTypeId,
+        value: &serde_json::Value,
+        args: &mut Vec<Arg>,
+    ) -> Result<(), RunnerError> {
+        let invalid =
+            || RunnerError::InvalidArgument { value: value.to_string(), ty: ty.to_string() };
+        let concrete_ty = self.sierra_program_registry.get_type(ty)?;
+        match concrete_ty {
+            CoreTypeConcrete::Felt252(_) => {
+                args.push(Arg::Value(Felt252::from(parse_felt252(value).ok_or_else(invalid)?)));
+            }
+            CoreTypeConcrete::Array(info) => {
+                let serde_json::Value::Array(elements) = value else { return Err(invalid()) };
+                let mut element_args = vec![];
+                for element in elements {
+                    self.push_arg_from_json(&info.ty, element, &mut element_args)?;
+                }
+                let values = element_args
+                    .into_iter()
+                    .map(|arg| match arg {
+                        Arg::Value(value) => Ok(value),
+                        // Arrays of arrays are not supported.
+                        Arg::Array(_) => Err(invalid()),
+                    })
+                    .collect::<Result<_, _>>()?;
+                args.push(Arg::Array(values));
+            }
+            CoreTypeConcrete::Snapshot(info) | CoreTypeConcrete::NonZero(info) => {
+                self.push_arg_from_json(&info.ty, value, args)?;
+            }
+            CoreTypeConcrete::Struct(info) => {
+                // A struct with a single member, such as a span, may be given by the value of the
+                // member.
+                if let [member_ty] = &info.members[..] {
+                    let mut member_args = vec![];
+                    if self.push_arg_from_json(member_ty, value, &mut member_args).is_ok() {
+                        args.extend(member_args);
+                        return Ok(());
+                    }
+                }
+                let serde_json::Value::Array(members) = value else { return Err(invalid()) };
+                if members.len() != info.members.len() {
+                    return Err(invalid());
+                }
+                for (member_ty, member) in info.members.iter().zip(members) {
+                    self.push_arg_from_json(member_ty, member, args)?;
+                }
+            }
+            CoreTypeConcrete::Enum(info) => {
+                let n_variants = info.variants.len();
+                let type_name = self.user_type_name(ty);
+                let (index, inner) = match value {
+                    serde_json::Value::Bool(b) if type_name == "core::bool" => {
+                        (usize::from(*b), serde_json::Value::Array(vec![]))
+                    }
+                    serde_json::Value::Null if core_variant_name(&type_name, 1) == Some("None") => {
+                        (1, serde_json::Value::Array(vec![]))
+                    }
+                    serde_json::Value::Object(object) if object.len() == 1 => {
+                        let (variant, inner) = object.iter().next().unwrap();
+                        let index = (0..n_variants)
+                            .find(|index| core_variant_name(&type_name, *index) == Some(variant))
+                            .or_else(|| variant.parse().ok())
+                            .filter(|index| *index < n_variants)
+                            .ok_or_else(invalid)?;
+                        (index, inner.clone())
+                    }
+                    _ if core_variant_name(&type_name, 0) == Some("Some") => (0, value.clone()),
+                    _ => return Err(invalid()),
+                };
+                let mut variant_args = vec![];
+                self.push_arg_from_json(&info.variants[index], &inner, &mut variant_args)?;
+                let variant_size: usize = variant_args.iter().map(Arg::size).sum();
+                let padding = self.type_size(ty).ok_or_else(invalid)? - 1 - variant_size;
+                args.push(Arg::Value(Felt252::from(variant_selector(n_variants, index))));
+                args.extend((0..padding).map(|_| Arg::Value(Felt252::from(0))));
+                args.extend(variant_args);
+            }
+            _ => {
+                let (min, max) = integer_range(concrete_ty).ok_or_else(invalid)?;
+                let value = parse_felt252(value)
+                    .filter(|value| min <= *value && *value <= max)
+                    .ok_or_else(invalid)?;
+                args.push(Arg::Value(Felt252::from(value)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the type of the value returned by `func`, ignoring implicits and unwrapping the
+    /// panic wrapper, if any.
+    pub fn return_type(&self, func: &Function) -> Option<ConcreteTypeId> {
+        let ty = func.signature.ret_types.iter().find(|ty| !self.is_implicit(ty))?;
+        if !self.user_type_name(ty).starts_with("core::PanicResult::") {
+            return Some(ty.clone());
+        }
+        // The successful variant of the panic wrapper holds a tuple of the returned values.
+        let CoreTypeConcrete::Enum(info) = self.sierra_program_registry.get_type(ty).ok()? else {
+            return None;
+        };
+        let tuple_ty = &info.variants[0];
+        match self.sierra_program_registry.get_type(tuple_ty).ok()? {
+            CoreTypeConcrete::Struct(tuple) if tuple.members.len() == 1 => {
+                Some(tuple.members[0].clone())
+            }
+            _ => Some(tuple_ty.clone()),
+        }
+    }
+
+    /// Decodes a value of the given type from its felts, where `memory` is the memory at the end
+    /// of the run, holding the content of arrays and boxes.
+    pub fn decode_value(
+        &self,
+        ty: &ConcreteTypeId,
+        values: &[Felt252],
+        memory: &[Option<Felt252>],
+    ) -> Option<Value> {
+        let concrete_ty = self.sierra_program_registry.get_type(ty).ok()?;
+        let raw = || Value::Raw { name: ty.to_string(), values: values.to_vec() };
+        Some(match concrete_ty {
+            CoreTypeConcrete::Felt252(_) => Value::Felt252(values.first()?.clone()),
+            CoreTypeConcrete::Array(info) => {
+                let [start, end] = values else { return None };
+                let elements = memory.get(start.to_usize()?..end.to_usize()?)?;
+                let element_size = self.type_size(&info.ty).filter(|size| *size > 0)?;
+                Value::Array(
+                    elements
+                        .iter()
+                        .cloned()
+                        .collect::<Option<Vec<_>>>()?
+                        .chunks(element_size)
+                        .map(|element| self.decode_value(&info.ty, element, memory))
+                        .collect::<Option<_>>()?,
+                )
+            }
+            CoreTypeConcrete::Box(info) => {
+                let [pointer] = values else { return None };
+                let start = pointer.to_usize()?;
+                let inner = memory.get(start..start + self.type_size(&info.ty)?)?;
+                let inner = inner.iter().cloned().collect::<Option<Vec<_>>>()?;
+                self.decode_value(&info.ty, &inner, memory)?
+            }
+            CoreTypeConcrete::Snapshot(info) | CoreTypeConcrete::NonZero(info) => {
+                self.decode_value(&info.ty, values, memory)?
+            }
+            CoreTypeConcrete::Struct(info) => {
+                let name = self.user_type_name(ty);
+                let mut members = vec![];
+                let mut offset = 0;
+                for member_ty in &info.members {
+                    let size = self.type_size(member_ty)?;
+                    members.push(self.decode_value(
+                        member_ty,
+                        values.get(offset..offset + size)?,
+                        memory,
+                    )?);
+                    offset += size;
+                }
+                if name == "Tuple" {
+                    Value::Tuple(members)
+                } else if name == "core::integer::u256" {
+                    let [Value::Int(low), Value::Int(high)] = &members[..] else { return None };
+                    Value::Int(low + (high << 128))
+                } else if name.starts_with("core::array::Span::") {
+                    members.into_iter().next()?
+                } else {
+                    Value::Struct { name, members }
+                }
+            }
+            CoreTypeConcrete::Enum(info) => {
+                let (selector, inner) = values.split_first()?;
+                let variant = variant_index(info.variants.len(), selector.to_usize()?)?;
+                let variant_ty = &info.variants[variant];
+                let inner = &inner[inner.len().checked_sub(self.type_size(variant_ty)?)?..];
+                let name = self.user_type_name(ty);
+                if name == "core::bool" {
+                    Value::Bool(variant == 1)
+                } else {
+                    Value::Enum {
+                        name,
+                        variant,
+                        value: Box::new(self.decode_value(variant_ty, inner, memory)?),
+                    }
+                }
+            }
+            _ => match integer_range(concrete_ty) {
+                Some(_) => Value::Int(values.first()?.to_bigint()),
+                None => raw(),
+            },
+        })
+    }
+
+    /// Returns the number of felts in the representation of a value of the given type.
+    fn type_size(&self, ty: &ConcreteTypeId) -> Option<usize> {
+        usize::try_from(self.get_info(ty).size).ok()
+    }
+
+    /// Returns the debug name of the user type of the given struct or enum type, or an empty
+    /// string if there is none.
+    pub(crate) fn user_type_name(&self, ty: &ConcreteTypeId) -> String {
+        match self.get_info(ty).long_id.generic_args.first() {
+            Some(GenericArg::UserType(user_type)) => {
+                user_type.debug_name.as_ref().map(|name| name.to_string()).unwrap_or_default()
+            }
+            _ => String::new(),
+        }
+    }
+}
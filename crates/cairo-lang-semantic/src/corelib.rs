@@ -1,4 +1,6 @@
-use cairo_lang_defs::ids::{EnumId, GenericTypeId, ImplDefId, ModuleId, ModuleItemId, TraitId};
+use cairo_lang_defs::ids::{
+    EnumId, GenericTypeId, ImplDefId, ModuleId, ModuleItemId, TraitFunctionId, TraitId,
+};
 use cairo_lang_diagnostics::{Maybe, ToOption};
 use cairo_lang_filesystem::ids::{CrateId, CrateLongId};
 use cairo_lang_syntax::node::ast::{self, BinaryOperator, UnaryOperator};
@@ -294,6 +296,8 @@ pub fn unwrap_error_propagation_type(
         )
         | TypeLongId::Tuple(_)
         | TypeLongId::Snapshot(_)
+        | TypeLongId::ImplType(_)
+        | TypeLongId::Closure(_)
         | TypeLongId::Var(_)
         | TypeLongId::Missing(_) => None,
     }
@@ -356,6 +360,7 @@ pub fn core_binary_operator(
         BinaryOperator::And(_) => ("BitAnd", "bitand"),
         BinaryOperator::Or(_) => ("BitOr", "bitor"),
         BinaryOperator::Xor(_) => ("BitXor", "bitxor"),
+        BinaryOperator::DotDot(_) => ("RangeOp", "range"),
         _ => return Ok(Err(SemanticDiagnosticKind::UnknownBinaryOperator)),
     };
     Ok(Ok(get_core_trait_function_infer(
@@ -424,7 +429,17 @@ pub fn get_core_function_id(
     name: SmolStr,
     generic_args: Vec<GenericArgumentId>,
 ) -> FunctionId {
-    let generic_function = get_core_generic_function_id(db, name);
+    get_function_id(db, db.core_module(), name, generic_args)
+}
+
+/// Given a module, a library function name and its generic arguments, returns [FunctionId].
+pub fn get_function_id(
+    db: &dyn SemanticGroup,
+    module: ModuleId,
+    name: SmolStr,
+    generic_args: Vec<GenericArgumentId>,
+) -> FunctionId {
+    let generic_function = get_generic_function_id(db, module, name);
 
     db.intern_function(FunctionLongId {
         function: ConcreteFunction { generic_function, generic_args },
@@ -433,9 +448,17 @@ pub fn get_core_function_id(
 
 /// Given a core library function name, returns [GenericFunctionId].
 pub fn get_core_generic_function_id(db: &dyn SemanticGroup, name: SmolStr) -> GenericFunctionId {
-    let core_module = db.core_module();
+    get_generic_function_id(db, db.core_module(), name)
+}
+
+/// Given a module and a library function name, returns [GenericFunctionId].
+pub fn get_generic_function_id(
+    db: &dyn SemanticGroup,
+    module: ModuleId,
+    name: SmolStr,
+) -> GenericFunctionId {
     let module_item_id = db
-        .module_item_by_name(core_module, name.clone())
+        .module_item_by_name(module, name.clone())
         .expect("Failed to load core lib.")
         .unwrap_or_else(|| panic!("Function '{name}' was not found in core lib."));
     match module_item_id {
@@ -473,6 +496,70 @@ pub fn destruct_trait(db: &dyn SemanticGroup) -> TraitId {
     get_core_trait(db, "Destruct".into())
 }
 
+/// Returns the concrete `FnOnce` trait of a callable type `ty`, called with arguments of type
+/// `args_ty` (a tuple) and returning `ret_ty`.
+pub fn concrete_fn_once_trait(
+    db: &dyn SemanticGroup,
+    ty: TypeId,
+    args_ty: TypeId,
+    ret_ty: TypeId,
+) -> ConcreteTraitId {
+    get_core_concrete_trait(
+        db,
+        "FnOnce".into(),
+        vec![
+            GenericArgumentId::Type(ty),
+            GenericArgumentId::Type(args_ty),
+            GenericArgumentId::Type(ret_ty),
+        ],
+    )
+}
+
+/// Returns the concrete `Fn` trait of a callable type `ty`, called with arguments of type `args_ty`
+/// (a tuple) and returning `ret_ty`.
+pub fn concrete_fn_trait(
+    db: &dyn SemanticGroup,
+    ty: TypeId,
+    args_ty: TypeId,
+    ret_ty: TypeId,
+) -> ConcreteTraitId {
+    get_core_concrete_trait(
+        db,
+        "Fn".into(),
+        vec![
+            GenericArgumentId::Type(ty),
+            GenericArgumentId::Type(args_ty),
+            GenericArgumentId::Type(ret_ty),
+        ],
+    )
+}
+
+pub fn fn_once_trait(db: &dyn SemanticGroup) -> TraitId {
+    get_core_trait(db, "FnOnce".into())
+}
+
+pub fn fn_trait(db: &dyn SemanticGroup) -> TraitId {
+    get_core_trait(db, "Fn".into())
+}
+
+pub fn into_iterator_trait(db: &dyn SemanticGroup) -> TraitId {
+    get_core_trait(db, "IntoIterator".into())
+}
+
+pub fn iterator_trait(db: &dyn SemanticGroup) -> TraitId {
+    get_core_trait(db, "Iterator".into())
+}
+
+/// Returns the `FnOnce::call_once` trait function.
+pub fn fn_once_call_trait_function(db: &dyn SemanticGroup) -> TraitFunctionId {
+    db.trait_function_by_name(fn_once_trait(db), "call_once".into()).unwrap().unwrap()
+}
+
+/// Returns the `Fn::call` trait function.
+pub fn fn_call_trait_function(db: &dyn SemanticGroup) -> TraitFunctionId {
+    db.trait_function_by_name(fn_trait(db), "call".into()).unwrap().unwrap()
+}
+
 /// Given a core library trait name and its generic arguments, returns [ConcreteTraitId].
 fn get_core_concrete_trait(
     db: &dyn SemanticGroup,
@@ -539,6 +626,18 @@ pub fn get_const_libfunc_name_by_type(db: &dyn SemanticGroup, ty: TypeId) -> Str
         "u64_const".into()
     } else if ty == get_core_ty_by_name(db, "u128".into(), vec![]) {
         "u128_const".into()
+    } else if ty == get_core_ty_by_name(db, "i8".into(), vec![]) {
+        "i8_const".into()
+    } else if ty == get_core_ty_by_name(db, "i16".into(), vec![]) {
+        "i16_const".into()
+    } else if ty == get_core_ty_by_name(db, "i32".into(), vec![]) {
+        "i32_const".into()
+    } else if ty == get_core_ty_by_name(db, "i64".into(), vec![]) {
+        "i64_const".into()
+    } else if ty == get_core_ty_by_name(db, "i128".into(), vec![]) {
+        "i128_const".into()
+    } else if ty == get_core_ty_by_name(db, "bytes31".into(), vec![]) {
+        "bytes31_const".into()
     } else {
         panic!("No const libfunc for type {}.", ty.format(db))
     }
@@ -569,6 +668,20 @@ pub fn validate_literal(
         value.is_negative() || value.bits() > 64
     } else if ty == get_core_ty_by_name(db, "u128".into(), vec![]) {
         value.is_negative() || value.bits() > 128
+    } else if ty == get_core_ty_by_name(db, "u256".into(), vec![]) {
+        value.is_negative() || value.bits() > 256
+    } else if ty == get_core_ty_by_name(db, "i8".into(), vec![]) {
+        i8::try_from(&value).is_err()
+    } else if ty == get_core_ty_by_name(db, "i16".into(), vec![]) {
+        i16::try_from(&value).is_err()
+    } else if ty == get_core_ty_by_name(db, "i32".into(), vec![]) {
+        i32::try_from(&value).is_err()
+    } else if ty == get_core_ty_by_name(db, "i64".into(), vec![]) {
+        i64::try_from(&value).is_err()
+    } else if ty == get_core_ty_by_name(db, "i128".into(), vec![]) {
+        i128::try_from(&value).is_err()
+    } else if ty == get_core_ty_by_name(db, "bytes31".into(), vec![]) {
+        value.is_negative() || value.bits() > 248
     } else {
         return Err(SemanticDiagnosticKind::NoLiteralFunctionFound);
     };
@@ -5,9 +5,10 @@ use cairo_lang_defs::db::{DefsGroup, GeneratedFileInfo};
 use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_defs::ids::{
     ConstantId, EnumId, ExternFunctionId, ExternTypeId, FreeFunctionId, FunctionTitleId,
-    FunctionWithBodyId, GenericParamId, GenericTypeId, ImplDefId, ImplFunctionId,
-    LanguageElementId, LookupItemId, ModuleId, ModuleItemId, StructId, TraitFunctionId, TraitId,
-    TypeAliasId, UseId, VariantId,
+    FunctionWithBodyId, GenericParamId, GenericTypeId, ImplConstantDefId, ImplDefId,
+    ImplFunctionId, ImplTypeDefId, LanguageElementId, LookupItemId, ModuleId, ModuleItemId,
+    StructId, TraitConstantId, TraitFunctionId, TraitId, TraitTypeId, TypeAliasId, UseId,
+    VariantId,
 };
 use cairo_lang_defs::plugin::MacroPlugin;
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe};
@@ -96,6 +97,7 @@ pub trait SemanticGroup:
     // ====
     /// Private query to compute data about a constant definition.
     #[salsa::invoke(items::constant::priv_constant_semantic_data)]
+    #[salsa::cycle(items::constant::priv_constant_semantic_data_cycle)]
     fn priv_constant_semantic_data(
         &self,
         const_id: ConstantId,
@@ -280,6 +282,54 @@ pub trait SemanticGroup:
         trait_id: TraitId,
         name: SmolStr,
     ) -> Maybe<Option<TraitFunctionId>>;
+    /// Returns the associated types of a trait.
+    #[salsa::invoke(items::trt::trait_types)]
+    fn trait_types(&self, trait_id: TraitId) -> Maybe<OrderedHashMap<SmolStr, TraitTypeId>>;
+    /// Returns the associated type with the given name of the given trait, if exists.
+    #[salsa::invoke(items::trt::trait_type_by_name)]
+    fn trait_type_by_name(&self, trait_id: TraitId, name: SmolStr) -> Maybe<Option<TraitTypeId>>;
+    /// Returns the associated constants of a trait.
+    #[salsa::invoke(items::trt::trait_constants)]
+    fn trait_constants(&self, trait_id: TraitId)
+    -> Maybe<OrderedHashMap<SmolStr, TraitConstantId>>;
+    /// Returns the associated constant with the given name of the given trait, if exists.
+    #[salsa::invoke(items::trt::trait_constant_by_name)]
+    fn trait_constant_by_name(
+        &self,
+        trait_id: TraitId,
+        name: SmolStr,
+    ) -> Maybe<Option<TraitConstantId>>;
+
+    // Trait constant.
+    // ================
+    /// Private query to compute data about a trait constant.
+    #[salsa::invoke(items::trt::priv_trait_constant_data)]
+    fn priv_trait_constant_data(
+        &self,
+        trait_constant_id: TraitConstantId,
+    ) -> Maybe<items::trt::TraitConstantData>;
+    /// Returns the semantic diagnostics of a trait constant.
+    #[salsa::invoke(items::trt::trait_constant_diagnostics)]
+    fn trait_constant_diagnostics(
+        &self,
+        trait_constant_id: TraitConstantId,
+    ) -> Diagnostics<SemanticDiagnostic>;
+    /// Returns the type of a trait constant.
+    #[salsa::invoke(items::trt::trait_constant_type)]
+    fn trait_constant_type(&self, trait_constant_id: TraitConstantId) -> Maybe<TypeId>;
+    /// Returns the resolution lookback of a trait constant.
+    #[salsa::invoke(items::trt::trait_constant_resolved_lookback)]
+    fn trait_constant_resolved_lookback(
+        &self,
+        trait_constant_id: TraitConstantId,
+    ) -> Maybe<Arc<ResolvedLookback>>;
+    /// Returns the type of a trait constant, given a concrete trait.
+    #[salsa::invoke(items::trt::concrete_trait_constant_type)]
+    fn concrete_trait_constant_type(
+        &self,
+        concrete_trait_id: ConcreteTraitId,
+        trait_constant_id: TraitConstantId,
+    ) -> Maybe<TypeId>;
 
     // Trait function.
     // ================
@@ -387,6 +437,20 @@ pub trait SemanticGroup:
         impl_def_id: ImplDefId,
         trait_function_id: TraitFunctionId,
     ) -> Maybe<Option<ImplFunctionId>>;
+    /// Returns the impl type definition that matches the given trait type, if exists.
+    #[salsa::invoke(items::imp::impl_type_def_by_trait_type)]
+    fn impl_type_def_by_trait_type(
+        &self,
+        impl_def_id: ImplDefId,
+        trait_type_id: TraitTypeId,
+    ) -> Maybe<Option<ImplTypeDefId>>;
+    /// Returns the impl constant definition that matches the given trait constant, if exists.
+    #[salsa::invoke(items::imp::impl_constant_def_by_trait_constant)]
+    fn impl_constant_def_by_trait_constant(
+        &self,
+        impl_def_id: ImplDefId,
+        trait_constant_id: TraitConstantId,
+    ) -> Maybe<Option<ImplConstantDefId>>;
     /// Returns candidate [ImplDefId]s for a specific trait lookup constraint.
     #[salsa::invoke(items::imp::module_impl_ids_for_trait_info)]
     fn module_impl_ids_for_trait_info(
@@ -395,6 +459,59 @@ pub trait SemanticGroup:
         trait_lookup_constraint: items::imp::TraitFilter,
     ) -> Maybe<Vec<ImplDefId>>;
 
+    // Impl type def.
+    // ================
+    /// Private query to compute data about an impl type definition.
+    #[salsa::invoke(items::imp::priv_impl_type_def_data)]
+    #[salsa::cycle(items::imp::priv_impl_type_def_data_cycle)]
+    fn priv_impl_type_def_data(
+        &self,
+        impl_type_def_id: ImplTypeDefId,
+    ) -> Maybe<items::imp::ImplTypeDefData>;
+    /// Returns the semantic diagnostics of an impl type definition.
+    #[salsa::invoke(items::imp::impl_type_def_diagnostics)]
+    fn impl_type_def_diagnostics(
+        &self,
+        impl_type_def_id: ImplTypeDefId,
+    ) -> Diagnostics<SemanticDiagnostic>;
+    /// Returns the resolved type of an impl type definition.
+    #[salsa::invoke(items::imp::impl_type_def_resolved_type)]
+    fn impl_type_def_resolved_type(&self, impl_type_def_id: ImplTypeDefId) -> Maybe<TypeId>;
+    /// Returns the resolution lookback of an impl type definition.
+    #[salsa::invoke(items::imp::impl_type_def_resolved_lookback)]
+    fn impl_type_def_resolved_lookback(
+        &self,
+        impl_type_def_id: ImplTypeDefId,
+    ) -> Maybe<Arc<ResolvedLookback>>;
+
+    // Impl constant def.
+    // ================
+    /// Private query to compute data about an impl constant definition.
+    #[salsa::invoke(items::imp::priv_impl_constant_def_data)]
+    #[salsa::cycle(items::imp::priv_impl_constant_def_data_cycle)]
+    fn priv_impl_constant_def_data(
+        &self,
+        impl_constant_def_id: ImplConstantDefId,
+    ) -> Maybe<items::imp::ImplConstantDefData>;
+    /// Returns the semantic diagnostics of an impl constant definition.
+    #[salsa::invoke(items::imp::impl_constant_def_diagnostics)]
+    fn impl_constant_def_diagnostics(
+        &self,
+        impl_constant_def_id: ImplConstantDefId,
+    ) -> Diagnostics<SemanticDiagnostic>;
+    /// Returns the value of an impl constant definition.
+    #[salsa::invoke(items::imp::impl_constant_def_value)]
+    fn impl_constant_def_value(
+        &self,
+        impl_constant_def_id: ImplConstantDefId,
+    ) -> Maybe<items::constant::ConstValue>;
+    /// Returns the resolution lookback of an impl constant definition.
+    #[salsa::invoke(items::imp::impl_constant_def_resolved_lookback)]
+    fn impl_constant_def_resolved_lookback(
+        &self,
+        impl_constant_def_id: ImplConstantDefId,
+    ) -> Maybe<Arc<ResolvedLookback>>;
+
     // Impl function.
     // ================
     /// Returns the signature of an impl function.
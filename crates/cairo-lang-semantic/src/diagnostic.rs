@@ -142,6 +142,19 @@ impl DiagnosticEntry for SemanticDiagnostic {
                     trait_id.name(defs_db)
                 )
             }
+            SemanticDiagnosticKind::ImplItemNotMemberOfTrait {
+                impl_def_id,
+                item_kind,
+                item_name,
+                trait_id,
+            } => {
+                let defs_db = db.upcast();
+                format!(
+                    "Impl {item_kind} `{}::{item_name}` is not a member of trait `{}`.",
+                    impl_def_id.name(defs_db),
+                    trait_id.name(defs_db)
+                )
+            }
             SemanticDiagnosticKind::UnexpectedGenericArgs => "Unexpected generic arguments".into(),
             SemanticDiagnosticKind::UnknownMember => "Unknown member.".into(),
             SemanticDiagnosticKind::MemberSpecifiedMoreThanOnce => {
@@ -356,6 +369,13 @@ impl DiagnosticEntry for SemanticDiagnostic {
                     block_else_ty.format(db),
                 )
             }
+            SemanticDiagnosticKind::IncompatibleLoopBreakTypes { current_ty, break_ty } => {
+                format!(
+                    r#"Loop has incompatible return types: "{}" and "{}""#,
+                    current_ty.format(db),
+                    break_ty.format(db),
+                )
+            }
             SemanticDiagnosticKind::TypeHasNoMembers { ty, member_name: _ } => {
                 format!(r#"Type "{}" has no members."#, ty.format(db))
             }
@@ -445,6 +465,10 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::ShortStringMustBeAscii => {
                 "Short strings can only include ASCII characters.".into()
             }
+            SemanticDiagnosticKind::ShortStringTooLong => {
+                "Short strings can hold at most 31 bytes. Use the `_ByteArray` suffix instead."
+                    .into()
+            }
             SemanticDiagnosticKind::IllegalStringEscaping(err) => {
                 format!("Invalid string escaping:\n{err}")
             }
@@ -498,19 +522,50 @@ impl DiagnosticEntry for SemanticDiagnostic {
             }
             SemanticDiagnosticKind::UnsupportedOutsideOfFunction { feature_name } => {
                 let feature_name_str = match feature_name {
-                    UnsupportedOutsideOfFunctionFeatureName::FunctionCall => "Function call",
                     UnsupportedOutsideOfFunctionFeatureName::ReturnStatement => "Return statement",
                     UnsupportedOutsideOfFunctionFeatureName::ErrorPropagate => "The '?' operator",
+                    UnsupportedOutsideOfFunctionFeatureName::Closure => "Closure",
                 };
                 format!("{feature_name_str} is not supported outside of functions.")
             }
-            SemanticDiagnosticKind::OnlyLiteralConstants => {
-                "Only literal constants are currently supported.".into()
+            SemanticDiagnosticKind::UnsupportedConstant => {
+                "This expression is not supported as constant.".into()
+            }
+            SemanticDiagnosticKind::DivisionByZero => "Division by zero.".into(),
+            SemanticDiagnosticKind::ConstCycle => {
+                "Cycle detected while evaluating 'const' items.".into()
             }
             SemanticDiagnosticKind::ExternItemWithImplGenericsNotSupported => {
                 "Extern items with impl generics are not supported".into()
             }
             SemanticDiagnosticKind::MissingSemicolon => "Missing semicolon".into(),
+            SemanticDiagnosticKind::BreakOnlyAllowedInsideALoop => {
+                "`break` only allowed inside a `loop`.".into()
+            }
+            SemanticDiagnosticKind::ContinueOnlyAllowedInsideALoop => {
+                "`continue` only allowed inside a `loop`.".into()
+            }
+            SemanticDiagnosticKind::ReturnNotAllowedInsideALoop => {
+                "`return` not allowed inside a `loop`.".into()
+            }
+            SemanticDiagnosticKind::ErrorPropagateNotAllowedInsideALoop => {
+                "`?` not allowed inside a `loop`.".into()
+            }
+            SemanticDiagnosticKind::ReturnNotAllowedInsideAClosure => {
+                "`return` not allowed inside a closure.".into()
+            }
+            SemanticDiagnosticKind::ErrorPropagateNotAllowedInsideAClosure => {
+                "`?` not allowed inside a closure.".into()
+            }
+            SemanticDiagnosticKind::RefClosureParam => {
+                "Closure parameters cannot be passed by `ref`.".into()
+            }
+            SemanticDiagnosticKind::ClosureParamNameRedefinition { param_name } => {
+                format!(r#"Redefinition of closure parameter name "{param_name}"."#)
+            }
+            SemanticDiagnosticKind::MutationOfCapturedVar => {
+                "Cannot modify a variable captured by a closure.".into()
+            }
             SemanticDiagnosticKind::TraitMismatch => {
                 "Supplied impl does not match the required trait".into()
             }
@@ -539,6 +594,20 @@ impl DiagnosticEntry for SemanticDiagnostic {
                 method_name,
                 ty.format(db)
             ),
+            SemanticDiagnosticKind::NoImplementationOfIndexOperator(ty) => format!(
+                r#"Type "{}" does not implement the "Index" trait nor the "IndexView" trait."#,
+                ty.format(db)
+            ),
+            SemanticDiagnosticKind::MultipleImplementationOfIndexOperator(ty) => format!(
+                r#"Type "{}" implements both the "Index" trait and the "IndexView" trait."#,
+                ty.format(db)
+            ),
+            SemanticDiagnosticKind::NoImplementationOfIntoIterator(ty) => {
+                format!(r#"Type "{}" does not implement the "IntoIterator" trait."#, ty.format(db))
+            }
+            SemanticDiagnosticKind::NoImplementationOfIterator(ty) => {
+                format!(r#"Type "{}" does not implement the "Iterator" trait."#, ty.format(db))
+            }
         }
     }
 
@@ -595,6 +664,12 @@ pub enum SemanticDiagnosticKind {
         impl_function_id: ImplFunctionId,
         trait_id: TraitId,
     },
+    ImplItemNotMemberOfTrait {
+        impl_def_id: ImplDefId,
+        item_kind: SmolStr,
+        item_name: SmolStr,
+        trait_id: TraitId,
+    },
     UnexpectedGenericArgs,
     UnknownMember,
     MemberSpecifiedMoreThanOnce,
@@ -699,6 +774,10 @@ pub enum SemanticDiagnosticKind {
         block_if_ty: semantic::TypeId,
         block_else_ty: semantic::TypeId,
     },
+    IncompatibleLoopBreakTypes {
+        current_ty: semantic::TypeId,
+        break_ty: semantic::TypeId,
+    },
     TypeHasNoMembers {
         ty: semantic::TypeId,
         member_name: SmolStr,
@@ -707,6 +786,10 @@ pub enum SemanticDiagnosticKind {
         ty: semantic::TypeId,
         method_name: SmolStr,
     },
+    NoImplementationOfIndexOperator(semantic::TypeId),
+    MultipleImplementationOfIndexOperator(semantic::TypeId),
+    NoImplementationOfIntoIterator(semantic::TypeId),
+    NoImplementationOfIterator(semantic::TypeId),
     NoSuchMember {
         struct_id: StructId,
         member_name: SmolStr,
@@ -754,6 +837,7 @@ pub enum SemanticDiagnosticKind {
         actual_enum: EnumId,
     },
     ShortStringMustBeAscii,
+    ShortStringTooLong,
     IllegalStringEscaping(String),
     InvalidCopyTraitImpl {
         inference_error: InferenceError,
@@ -790,9 +874,22 @@ pub enum SemanticDiagnosticKind {
     UnsupportedOutsideOfFunction {
         feature_name: UnsupportedOutsideOfFunctionFeatureName,
     },
-    OnlyLiteralConstants,
+    UnsupportedConstant,
+    DivisionByZero,
+    ConstCycle,
     ExternItemWithImplGenericsNotSupported,
     MissingSemicolon,
+    BreakOnlyAllowedInsideALoop,
+    ContinueOnlyAllowedInsideALoop,
+    ReturnNotAllowedInsideALoop,
+    ErrorPropagateNotAllowedInsideALoop,
+    ReturnNotAllowedInsideAClosure,
+    ErrorPropagateNotAllowedInsideAClosure,
+    RefClosureParam,
+    ClosureParamNameRedefinition {
+        param_name: SmolStr,
+    },
+    MutationOfCapturedVar,
     TraitMismatch,
     DesnapNonSnapshot,
     InternalInferenceError(InferenceError),
@@ -814,9 +911,9 @@ pub enum NotFoundItemType {
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum UnsupportedOutsideOfFunctionFeatureName {
-    FunctionCall,
     ReturnStatement,
     ErrorPropagate,
+    Closure,
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -842,6 +939,7 @@ impl From<&ResolvedConcreteItem> for ElementKind {
             ResolvedConcreteItem::Variant(_) => ElementKind::Variant,
             ResolvedConcreteItem::Trait(_) => ElementKind::Trait,
             ResolvedConcreteItem::Impl(_) => ElementKind::Impl,
+            ResolvedConcreteItem::ImplConstant(_) => ElementKind::Constant,
         }
     }
 }
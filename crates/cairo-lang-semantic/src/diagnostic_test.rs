@@ -3,7 +3,8 @@ use std::sync::Arc;
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::ModuleId;
 use cairo_lang_defs::plugin::{
-    DynGeneratedFileAuxData, GeneratedFileAuxData, MacroPlugin, PluginGeneratedFile, PluginResult,
+    DynGeneratedFileAuxData, GeneratedFileAuxData, MacroPlugin, MacroPluginMetadata,
+    PluginGeneratedFile, PluginResult,
 };
 use cairo_lang_diagnostics::DiagnosticEntry;
 use cairo_lang_syntax as syntax;
@@ -73,10 +74,15 @@ fn test_missing_module_file() {
 struct AddInlineModuleDummyPlugin {}
 
 impl MacroPlugin for AddInlineModuleDummyPlugin {
+    fn name(&self) -> &'static str {
+        "add_inline_module_dummy"
+    }
+
     fn generate_code(
         &self,
         db: &dyn SyntaxGroup,
         item_ast: syntax::node::ast::Item,
+        _metadata: &MacroPluginMetadata<'_>,
     ) -> PluginResult {
         match item_ast {
             ast::Item::FreeFunction(func)
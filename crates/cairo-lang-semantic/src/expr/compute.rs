@@ -5,19 +5,23 @@
 use std::collections::HashMap;
 
 use ast::{BinaryOperator, PathSegment};
-use cairo_lang_defs::ids::{FunctionTitleId, LanguageElementId, LocalVarLongId, MemberId, TraitId};
-use cairo_lang_diagnostics::{Maybe, ToMaybe, ToOption};
+use cairo_lang_defs::ids::{
+    FunctionTitleId, FunctionWithBodyId, LanguageElementId, LocalVarLongId, MemberId, ModuleId,
+    TraitFunctionId, TraitId, VarId,
+};
+use cairo_lang_diagnostics::{skip_diagnostic, Maybe, ToMaybe, ToOption};
 use cairo_lang_syntax::node::ast::{BlockOrIf, PatternStructParam, UnaryOperator};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::{GetIdentifier, PathSegmentEx};
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
 use cairo_lang_utils::{try_extract_matches, OptionHelper};
 use id_arena::Arena;
-use itertools::{chain, zip_eq};
+use itertools::{chain, zip_eq, Itertools};
 use num_bigint::{BigInt, Sign};
 use smol_str::SmolStr;
 use unescaper::unescape;
@@ -28,24 +32,31 @@ use super::pattern::{
     Pattern, PatternEnumVariant, PatternLiteral, PatternOtherwise, PatternTuple, PatternVariable,
 };
 use crate::corelib::{
-    core_binary_operator, core_felt252_ty, core_unary_operator, false_literal_expr, never_ty,
+    concrete_fn_once_trait, concrete_fn_trait, core_binary_operator, core_bool_ty, core_felt252_ty,
+    core_submodule, core_unary_operator, false_literal_expr, fn_call_trait_function,
+    fn_once_call_trait_function, fn_once_trait, fn_trait, get_core_trait, get_core_ty_by_name,
+    get_function_id, into_iterator_trait, iterator_trait, never_ty, option_some_variant,
     true_literal_expr, try_get_core_ty_by_name, unit_ty, unwrap_error_propagation_type,
     validate_literal,
 };
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
 use crate::diagnostic::{
-    ElementKind, NotFoundItemType, SemanticDiagnostics, UnsupportedOutsideOfFunctionFeatureName,
+    ElementKind, NotFoundItemType, SemanticDiagnosticKind, SemanticDiagnostics,
+    UnsupportedOutsideOfFunctionFeatureName,
 };
 use crate::items::enm::SemanticEnumEx;
 use crate::items::imp::find_possible_impls_at_context;
 use crate::items::modifiers::compute_mutability;
 use crate::items::structure::SemanticStructEx;
-use crate::items::trt::ConcreteTraitGenericFunctionLongId;
+use crate::items::trt::{ConcreteTraitGenericFunctionId, ConcreteTraitGenericFunctionLongId};
 use crate::items::us::SemanticUseEx;
 use crate::literals::LiteralLongId;
 use crate::resolve_path::{ResolvedConcreteItem, ResolvedGenericItem, Resolver};
-use crate::semantic::{self, FunctionId, LocalVariable, TypeId, TypeLongId, Variable};
+use crate::semantic::{
+    self, ClosureTypeLongId, ConcreteFunctionWithBodyId, FunctionId, GenericArgumentId,
+    GenericParam, LocalVariable, TypeId, TypeLongId, Variable,
+};
 use crate::substitution::SemanticRewriter;
 use crate::types::{peel_snapshots, resolve_type, wrap_in_snapshots, ConcreteTypeId};
 use crate::{ConcreteFunction, FunctionLongId, Mutability, Parameter, PatternStruct, Signature};
@@ -55,18 +66,24 @@ pub struct ComputationContext<'ctx> {
     pub db: &'ctx dyn SemanticGroup,
     pub diagnostics: &'ctx mut SemanticDiagnostics,
     pub resolver: Resolver<'ctx>,
+    /// The function whose body is computed, or None if the expression is not inside a function.
+    function_id: Option<FunctionWithBodyId>,
     signature: Option<&'ctx Signature>,
     environment: Box<Environment>,
     pub exprs: Arena<semantic::Expr>,
     pub statements: Arena<semantic::Statement>,
     /// Definitions of semantic variables.
     pub semantic_defs: UnorderedHashMap<semantic::VarId, semantic::Variable>,
+    /// The merged type of the `break` statements of the innermost loop, or None if the current
+    /// expression is not inside a loop.
+    loop_flow_merge: Option<FlowMergeTypeHelper>,
 }
 impl<'ctx> ComputationContext<'ctx> {
     pub fn new(
         db: &'ctx dyn SemanticGroup,
         diagnostics: &'ctx mut SemanticDiagnostics,
         resolver: Resolver<'ctx>,
+        function_id: Option<FunctionWithBodyId>,
         signature: Option<&'ctx Signature>,
         environment: Environment,
     ) -> Self {
@@ -76,11 +93,13 @@ impl<'ctx> ComputationContext<'ctx> {
             db,
             diagnostics,
             resolver,
+            function_id,
             signature,
             environment: Box::new(environment),
             exprs: Arena::default(),
             statements: Arena::default(),
             semantic_defs,
+            loop_flow_merge: None,
         }
     }
 
@@ -105,6 +124,73 @@ impl<'ctx> ComputationContext<'ctx> {
         res
     }
 
+    /// Runs a function with a modified context, for computing the body of a loop.
+    /// Returns the result of the function, and the merged type of the `break` statements of the
+    /// loop.
+    fn run_in_loop<T, F>(&mut self, f: F) -> (T, FlowMergeTypeHelper)
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let new_flow_merge = FlowMergeTypeHelper::new(self.db);
+        let old_flow_merge = self.loop_flow_merge.replace(new_flow_merge);
+
+        let res = f(self);
+
+        let flow_merge = std::mem::replace(&mut self.loop_flow_merge, old_flow_merge).unwrap();
+        (res, flow_merge)
+    }
+
+    /// Runs a function with a modified context, for computing the body of a closure.
+    /// The closure gets a new environment, which records the variables of the enclosing scopes
+    /// used inside it. Returns the result of the function, and the captured variables.
+    fn run_in_closure<T, F>(&mut self, f: F) -> (T, OrderedHashSet<VarId>)
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        // Push a closure environment to the stack.
+        let new_environment = Box::new(Environment {
+            closure_captures: Some(Default::default()),
+            ..Default::default()
+        });
+        let old_environment = std::mem::replace(&mut self.environment, new_environment);
+        self.environment.parent = Some(old_environment);
+        // A closure body is not a part of an enclosing loop.
+        let old_flow_merge = self.loop_flow_merge.take();
+
+        let res = f(self);
+
+        self.loop_flow_merge = old_flow_merge;
+        // Pop the environment from the stack.
+        let parent = self.environment.parent.take().unwrap();
+        let closure_environment = std::mem::replace(&mut self.environment, parent);
+        (res, closure_environment.closure_captures.unwrap())
+    }
+
+    /// Returns true if the current expression is inside a closure.
+    fn is_inside_closure(&self) -> bool {
+        let mut maybe_env = Some(&*self.environment);
+        while let Some(env) = maybe_env {
+            if env.closure_captures.is_some() {
+                return true;
+            }
+            maybe_env = env.parent.as_deref();
+        }
+        false
+    }
+
+    /// Returns true if the given variable is captured by a closure whose body is currently
+    /// computed.
+    fn is_captured(&self, var_id: VarId) -> bool {
+        let mut maybe_env = Some(&*self.environment);
+        while let Some(env) = maybe_env {
+            if matches!(&env.closure_captures, Some(captures) if captures.contains(&var_id)) {
+                return true;
+            }
+            maybe_env = env.parent.as_deref();
+        }
+        false
+    }
+
     /// Returns [Self::signature] if it exists. Otherwise, reports a diagnostic and returns `Err`.
     fn get_signature(
         &mut self,
@@ -120,7 +206,7 @@ impl<'ctx> ComputationContext<'ctx> {
             .report_by_ptr(stable_ptr, UnsupportedOutsideOfFunction { feature_name }))
     }
 
-    fn reduce_ty(&mut self, ty: TypeId) -> TypeId {
+    pub fn reduce_ty(&mut self, ty: TypeId) -> TypeId {
         // TODO(spapini): Propagate error to diagnostics.
         self.resolver.inference.rewrite(ty).unwrap()
     }
@@ -136,8 +222,36 @@ pub type EnvVariables = HashMap<SmolStr, Variable>;
 pub struct Environment {
     parent: Option<Box<Environment>>,
     variables: EnvVariables,
+    /// The variables of the enclosing scopes used inside the environment, if it is the root
+    /// environment of a closure body. None otherwise.
+    closure_captures: Option<OrderedHashSet<VarId>>,
 }
 impl Environment {
+    /// Looks up a variable by name in the environment and its parents. Records the variable as
+    /// captured by every closure whose root environment is crossed on the way.
+    fn get_variable(&mut self, variable_name: &SmolStr) -> Option<Variable> {
+        if let Some(var) = self.variables.get(variable_name) {
+            return Some(var.clone());
+        }
+        let var = self.parent.as_mut()?.get_variable(variable_name)?;
+        if let Some(captures) = &mut self.closure_captures {
+            captures.insert(var.id());
+        }
+        Some(var)
+    }
+
+    /// Looks up a variable by name in the environment and its parents, without capturing it.
+    fn peek_variable(&self, variable_name: &SmolStr) -> Option<&Variable> {
+        let mut maybe_env = Some(self);
+        while let Some(env) = maybe_env {
+            if let Some(var) = env.variables.get(variable_name) {
+                return Some(var);
+            }
+            maybe_env = env.parent.as_deref();
+        }
+        None
+    }
+
     /// Adds a parameter to the environment.
     pub fn add_param(
         &mut self,
@@ -195,7 +309,7 @@ pub fn maybe_compute_expr_semantic(
             Ok(Expr::Literal(literal_to_semantic(ctx, literal_syntax)?))
         }
         ast::Expr::ShortString(literal_syntax) => {
-            Ok(Expr::Literal(short_string_to_semantic(ctx, literal_syntax)?))
+            short_string_expr_to_semantic(ctx, literal_syntax)
         }
         ast::Expr::False(syntax) => Ok(false_literal_expr(ctx, syntax.stable_ptr().into())),
         ast::Expr::True(syntax) => Ok(true_literal_expr(ctx, syntax.stable_ptr().into())),
@@ -212,11 +326,15 @@ pub fn maybe_compute_expr_semantic(
         ast::Expr::Block(block_syntax) => compute_expr_block_semantic(ctx, block_syntax),
         ast::Expr::Match(expr_match) => compute_expr_match_semantic(ctx, expr_match),
         ast::Expr::If(expr_if) => compute_expr_if_semantic(ctx, expr_if),
+        ast::Expr::Loop(expr_loop) => compute_expr_loop_semantic(ctx, expr_loop),
+        ast::Expr::While(expr_while) => compute_expr_while_semantic(ctx, expr_while),
+        ast::Expr::For(expr_for) => compute_expr_for_semantic(ctx, expr_for),
+        ast::Expr::Closure(expr_closure) => compute_expr_closure_semantic(ctx, expr_closure),
         ast::Expr::ErrorPropagate(expr) => compute_expr_error_propagate_semantic(ctx, expr),
         ast::Expr::Missing(_) | ast::Expr::FieldInitShorthand(_) => {
             Err(ctx.diagnostics.report(syntax, Unsupported))
         }
-        ast::Expr::Indexed(_) => todo!(),
+        ast::Expr::Indexed(expr) => compute_expr_indexed_semantic(ctx, expr),
     }
 }
 
@@ -312,6 +430,8 @@ fn compute_expr_binary_semantic(
         // Verify the variable argument is mutable.
         if !ctx.semantic_defs[member_path.base_var()].is_mut() {
             ctx.diagnostics.report(syntax, AssignmentToImmutableVar);
+        } else if ctx.is_captured(member_path.base_var()) {
+            ctx.diagnostics.report(syntax, MutationOfCapturedVar);
         }
         return Ok(Expr::Assignment(ExprAssignment {
             ref_arg: member_path,
@@ -368,6 +488,39 @@ fn call_core_binary_op(
     )
 }
 
+/// Computes the semantic model of an indexing expression (e.g. "expr[index]"), by calling the
+/// `index` function of the `Index` or `IndexView` trait implemented for the type of `expr`.
+fn compute_expr_indexed_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprIndexed,
+) -> Maybe<Expr> {
+    let syntax_db = ctx.db.upcast();
+    let expr = compute_expr_semantic(ctx, &syntax.expr(syntax_db));
+    let index_expr = compute_expr_semantic(ctx, &syntax.index_expr(syntax_db));
+    let ty = ctx.reduce_ty(expr.ty());
+    ty.check_not_missing(ctx.db)?;
+    let candidate_traits =
+        ["Index", "IndexView"].map(|trait_name| get_core_trait(ctx.db, trait_name.into()));
+    let stable_ptr: ast::ExprPtr = syntax.stable_ptr().into();
+    let (function_id, fixed_expr, mutability) = compute_method_function_call_data(
+        ctx,
+        &candidate_traits,
+        "index".into(),
+        expr,
+        stable_ptr.untyped(),
+        stable_ptr,
+        None,
+        NoImplementationOfIndexOperator,
+        |_, _| MultipleImplementationOfIndexOperator(ty),
+    )?;
+    expr_function_call(
+        ctx,
+        function_id,
+        vec![(fixed_expr, None, mutability), (index_expr, None, Mutability::Immutable)],
+        stable_ptr,
+    )
+}
+
 fn compute_expr_tuple_semantic(
     ctx: &mut ComputationContext<'_>,
     syntax: &ast::ExprTuple,
@@ -397,6 +550,9 @@ fn compute_expr_function_call_semantic(
     let syntax_db = db.upcast();
 
     let path = syntax.path(syntax_db);
+    if let Some(callee) = try_get_callable_variable(ctx, &path) {
+        return compute_expr_closure_call_semantic(ctx, callee, syntax);
+    }
     let item =
         ctx.resolver.resolve_concrete_path(ctx.diagnostics, &path, NotFoundItemType::Function)?;
     let args_syntax = syntax.arguments(syntax_db);
@@ -467,6 +623,144 @@ fn compute_expr_function_call_semantic(
     }
 }
 
+/// Returns the variable named by the given path if the path is a single identifier of a variable
+/// that can be called, i.e. a variable holding a closure or a value of a generic type.
+fn try_get_callable_variable(
+    ctx: &mut ComputationContext<'_>,
+    path: &ast::ExprPath,
+) -> Option<Expr> {
+    let syntax_db = ctx.db.upcast();
+    let [PathSegment::Simple(segment)] = &path.elements(syntax_db)[..] else {
+        return None;
+    };
+    let name = segment.ident(syntax_db).text(syntax_db);
+    let ty = ctx.environment.peek_variable(&name)?.ty();
+    let ty = ctx.reduce_ty(ty);
+    if !matches!(
+        ctx.db.lookup_intern_type(ty),
+        TypeLongId::Closure(_) | TypeLongId::GenericParameter(_)
+    ) {
+        return None;
+    }
+    get_variable_by_name(ctx, &name, path.stable_ptr().into())
+}
+
+/// Computes the semantic model of a call of a callable variable, e.g. `f(x, y)`, which is
+/// `FnOnce::call_once(f, (x, y))`. A variable of a generic type bounded only by `Fn` is called
+/// using `Fn::call(@f, (x, y))` instead.
+fn compute_expr_closure_call_semantic(
+    ctx: &mut ComputationContext<'_>,
+    callee: Expr,
+    syntax: &ast::ExprFunctionCall,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+    let stable_ptr: ast::ExprPtr = syntax.stable_ptr().into();
+
+    let callee_ty = ctx.reduce_ty(callee.ty());
+    let closure = try_extract_matches!(db.lookup_intern_type(callee_ty), TypeLongId::Closure);
+    let args_syntax = syntax.arguments(syntax_db);
+    let named_args: Vec<_> = args_syntax
+        .args(syntax_db)
+        .elements(syntax_db)
+        .into_iter()
+        .map(|arg_syntax| compute_named_argument_clause(ctx, arg_syntax))
+        .collect();
+    if let Some(closure) = &closure {
+        if named_args.len() != closure.param_tys.len() {
+            return Err(ctx.diagnostics.report(
+                &args_syntax,
+                WrongNumberOfArguments {
+                    expected: closure.param_tys.len(),
+                    actual: named_args.len(),
+                },
+            ));
+        }
+    }
+    let mut arg_tys = vec![];
+    let mut items = vec![];
+    let mut wrong_arg_type = None;
+    for (i, (arg, name_terminal, mutability)) in named_args.into_iter().enumerate() {
+        if let Some(name_terminal) = name_terminal {
+            ctx.diagnostics.report(&name_terminal, NamedArgumentsAreNotSupported);
+        }
+        if mutability != Mutability::Immutable {
+            ctx.diagnostics.report_by_ptr(arg.stable_ptr().untyped(), ImmutableArgWithModifiers);
+        }
+        let actual_ty = ctx.reduce_ty(arg.ty());
+        if let Some(closure) = &closure {
+            let expected_ty = ctx.reduce_ty(closure.param_tys[i]);
+            if !actual_ty.is_missing(db)
+                && ctx.resolver.inference.conform_ty(actual_ty, expected_ty).is_err()
+            {
+                wrong_arg_type = Some(ctx.diagnostics.report_by_ptr(
+                    arg.stable_ptr().untyped(),
+                    WrongArgumentType { expected_ty, actual_ty },
+                ));
+            }
+        }
+        arg_tys.push(actual_ty);
+        items.push(ctx.exprs.alloc(arg));
+    }
+    if let Some(diag_added) = wrong_arg_type {
+        return Err(diag_added);
+    }
+    let args_ty = db.intern_type(TypeLongId::Tuple(arg_tys));
+    let args = Expr::Tuple(ExprTuple { items, ty: args_ty, stable_ptr });
+    let ret_ty = match &closure {
+        Some(closure) => closure.ret_ty,
+        None => ctx.resolver.inference.new_type_var(stable_ptr.untyped()),
+    };
+
+    let lookup_context = ctx.resolver.impl_lookup_context();
+    let has_bound = |trait_id: TraitId| {
+        lookup_context.generic_params.iter().any(|generic_param| {
+            let GenericParam::Impl(param) = generic_param else { return false };
+            matches!(param.concrete_trait, Ok(concrete_trait_id)
+                if concrete_trait_id.trait_id(db) == trait_id
+                    && concrete_trait_id.generic_args(db).first()
+                        == Some(&GenericArgumentId::Type(callee_ty)))
+        })
+    };
+    let (concrete_trait_function, callee) = if closure.is_none()
+        && !has_bound(fn_once_trait(db))
+        && has_bound(fn_trait(db))
+    {
+        let snapshot_ty = db.intern_type(TypeLongId::Snapshot(callee_ty));
+        let callee = Expr::Snapshot(ExprSnapshot {
+            inner: ctx.exprs.alloc(callee),
+            ty: snapshot_ty,
+            stable_ptr,
+        });
+        let concrete_trait_id = concrete_fn_trait(db, callee_ty, args_ty, ret_ty);
+        (
+            ConcreteTraitGenericFunctionId::new(db, concrete_trait_id, fn_call_trait_function(db)),
+            callee,
+        )
+    } else {
+        let concrete_trait_id = concrete_fn_once_trait(db, callee_ty, args_ty, ret_ty);
+        (
+            ConcreteTraitGenericFunctionId::new(
+                db,
+                concrete_trait_id,
+                fn_once_call_trait_function(db),
+            ),
+            callee,
+        )
+    };
+    let function = ctx
+        .resolver
+        .inference
+        .infer_trait_function(concrete_trait_function, &lookup_context, stable_ptr.untyped())
+        .map_err(|err| err.report(ctx.diagnostics, stable_ptr.untyped()))?;
+    expr_function_call(
+        ctx,
+        function,
+        vec![(callee, None, Mutability::Immutable), (args, None, Mutability::Immutable)],
+        stable_ptr,
+    )
+}
+
 /// Computes the semantic model of an expression of type [ast::Arg].
 ///
 /// Returns the value and the optional argument name.
@@ -532,7 +826,8 @@ pub fn compute_root_expr(
     Ok(res)
 }
 
-fn infer_all(ctx: &mut ComputationContext<'_>) -> Maybe<()> {
+/// Applies the inference results to all the expressions and statements of the context.
+pub fn infer_all(ctx: &mut ComputationContext<'_>) -> Maybe<()> {
     let version = ctx.resolver.inference.version;
     for (_id, expr) in ctx.exprs.iter_mut() {
         *expr = ctx
@@ -582,7 +877,9 @@ pub fn compute_expr_block_semantic(
         let ty = if let Some(t) = &tail_semantic_expr {
             t.ty()
         } else if let Some(statement) = statements_semantic.last() {
-            if let Statement::Return(_) = &new_ctx.statements[*statement] {
+            if let Statement::Return(_) | Statement::Break(_) | Statement::Continue(_) =
+                &new_ctx.statements[*statement]
+            {
                 never_ty(new_ctx.db)
             } else {
                 unit_ty(db)
@@ -599,26 +896,27 @@ pub fn compute_expr_block_semantic(
     })
 }
 
-/// Helper for merging the return types of branch blocks (match or if else).
-struct FlowMergeTypeHelper<'a, 'ctx> {
-    inference: &'a mut Inference<'ctx>,
+/// Helper for merging the return types of branch blocks (match or if else) and of the `break`
+/// statements of a loop.
+struct FlowMergeTypeHelper {
     never_type: TypeId,
     final_type: Option<TypeId>,
 }
-impl<'a, 'ctx> FlowMergeTypeHelper<'a, 'ctx> {
-    fn new(db: &dyn SemanticGroup, inference: &'a mut Inference<'ctx>) -> Self {
-        Self { inference, never_type: never_ty(db), final_type: None }
+impl FlowMergeTypeHelper {
+    fn new(db: &dyn SemanticGroup) -> Self {
+        Self { never_type: never_ty(db), final_type: None }
     }
 
     /// Attempt merge a branch into the helper, on error will return the conflicting types.
     fn try_merge_types(
         &mut self,
         db: &dyn SemanticGroup,
+        inference: &mut Inference<'_>,
         ty: TypeId,
     ) -> Result<(), (TypeId, TypeId)> {
         if ty != self.never_type && !ty.is_missing(db) {
             if let Some(existing) = &self.final_type {
-                if self.inference.conform_ty(ty, *existing).is_err() {
+                if inference.conform_ty(ty, *existing).is_err() {
                     return Err((*existing, ty));
                 }
             } else {
@@ -670,9 +968,11 @@ fn compute_expr_match_semantic(
         })
         .collect();
     // Unify arm types.
-    let mut helper = FlowMergeTypeHelper::new(ctx.db, &mut ctx.resolver.inference);
+    let mut helper = FlowMergeTypeHelper::new(ctx.db);
     for (_, expr) in pattern_and_expr_options.iter().flatten() {
-        if let Err((match_ty, arm_ty)) = helper.try_merge_types(ctx.db, expr.ty()) {
+        if let Err((match_ty, arm_ty)) =
+            helper.try_merge_types(ctx.db, &mut ctx.resolver.inference, expr.ty())
+        {
             ctx.diagnostics.report_by_ptr(
                 expr.stable_ptr().untyped(),
                 IncompatibleMatchArms { match_ty, arm_ty },
@@ -716,10 +1016,10 @@ fn compute_expr_if_semantic(ctx: &mut ComputationContext<'_>, syntax: &ast::Expr
         }
     };
 
-    let mut helper = FlowMergeTypeHelper::new(ctx.db, &mut ctx.resolver.inference);
+    let mut helper = FlowMergeTypeHelper::new(ctx.db);
     helper
-        .try_merge_types(ctx.db, if_block.ty())
-        .and(helper.try_merge_types(ctx.db, else_block_ty))
+        .try_merge_types(ctx.db, &mut ctx.resolver.inference, if_block.ty())
+        .and(helper.try_merge_types(ctx.db, &mut ctx.resolver.inference, else_block_ty))
         .unwrap_or_else(|(block_if_ty, block_else_ty)| {
             ctx.diagnostics.report(syntax, IncompatibleIfBlockTypes { block_if_ty, block_else_ty });
         });
@@ -732,6 +1032,318 @@ fn compute_expr_if_semantic(ctx: &mut ComputationContext<'_>, syntax: &ast::Expr
     }))
 }
 
+/// Computes the semantic model of an expression of type [ast::ExprLoop].
+fn compute_expr_loop_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprLoop,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+
+    let body_syntax = syntax.body(syntax_db);
+    let (body, flow_merge) =
+        ctx.run_in_loop(|new_ctx| compute_expr_block_semantic(new_ctx, &body_syntax));
+    let body = body?;
+    let body_ty = body.ty();
+    if body_ty != never_ty(db)
+        && !body_ty.is_missing(db)
+        && ctx.resolver.inference.conform_ty(body_ty, unit_ty(db)).is_err()
+    {
+        ctx.diagnostics
+            .report(&body_syntax, WrongType { expected_ty: unit_ty(db), actual_ty: body_ty });
+    }
+    Ok(Expr::Loop(ExprLoop {
+        body: ctx.exprs.alloc(body),
+        ty: flow_merge.get_final_type(),
+        stable_ptr: syntax.stable_ptr().into(),
+    }))
+}
+
+/// Computes the semantic model of an expression of type [ast::ExprWhile].
+fn compute_expr_while_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprWhile,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+
+    let condition_syntax = syntax.condition(syntax_db);
+    let condition = compute_expr_semantic(ctx, &condition_syntax);
+    let condition_ty = ctx.reduce_ty(condition.ty());
+    let bool_ty = core_bool_ty(db);
+    if !condition_ty.is_missing(db)
+        && ctx.resolver.inference.conform_ty(condition_ty, bool_ty).is_err()
+    {
+        ctx.diagnostics
+            .report(&condition_syntax, WrongType { expected_ty: bool_ty, actual_ty: condition_ty });
+    }
+
+    let body = compute_unit_loop_body_semantic(ctx, &syntax.body(syntax_db))?;
+    Ok(Expr::While(ExprWhile {
+        condition: ctx.exprs.alloc(condition),
+        body: ctx.exprs.alloc(body),
+        ty: unit_ty(db),
+        stable_ptr: syntax.stable_ptr().into(),
+    }))
+}
+
+/// Computes the semantic model of an expression of type [ast::ExprFor].
+///
+/// `for pattern in expr { body }` iterates over `IntoIterator::into_iter(expr)`, binding each item
+/// returned by `Iterator::next` to `pattern`, until `next` returns `None`.
+fn compute_expr_for_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprFor,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+    let stable_ptr: ast::ExprPtr = syntax.stable_ptr().into();
+
+    let expr_syntax = syntax.expr(syntax_db);
+    let expr = compute_expr_semantic(ctx, &expr_syntax);
+    ctx.reduce_ty(expr.ty()).check_not_missing(db)?;
+    let expr_ptr = expr.stable_ptr();
+    let (into_iter_function, fixed_expr, mutability) = compute_method_function_call_data(
+        ctx,
+        &[into_iterator_trait(db)],
+        "into_iter".into(),
+        expr,
+        expr_ptr.untyped(),
+        expr_ptr,
+        None,
+        NoImplementationOfIntoIterator,
+        |trait_function_id0, trait_function_id1| AmbiguousTrait {
+            trait_function_id0,
+            trait_function_id1,
+        },
+    )?;
+    let into_iter_call = expr_function_call(
+        ctx,
+        into_iter_function,
+        vec![(fixed_expr, None, mutability)],
+        expr_ptr,
+    )?;
+
+    // The iterator is held by a variable that is not accessible by name, identified by the `in`
+    // keyword.
+    let iterator_ty = ctx.reduce_ty(into_iter_call.ty());
+    let iterator = LocalVariable {
+        id: db.intern_local_var(LocalVarLongId(
+            ctx.resolver.module_file_id,
+            syntax.identifier(syntax_db).stable_ptr(),
+        )),
+        ty: iterator_ty,
+        is_mut: true,
+    };
+    let iterator_def = Variable::Local(iterator.clone());
+    ctx.semantic_defs.insert(iterator_def.id(), iterator_def);
+    let iterator_expr =
+        Expr::Var(ExprVar { var: VarId::Local(iterator.id), ty: iterator_ty, stable_ptr });
+    let (next_function, fixed_iterator_expr, _) = compute_method_function_call_data(
+        ctx,
+        &[iterator_trait(db)],
+        "next".into(),
+        iterator_expr,
+        expr_ptr.untyped(),
+        stable_ptr,
+        None,
+        NoImplementationOfIterator,
+        |trait_function_id0, trait_function_id1| AmbiguousTrait {
+            trait_function_id0,
+            trait_function_id1,
+        },
+    )?;
+    let next_call = expr_function_call(
+        ctx,
+        next_function,
+        vec![(fixed_iterator_expr, None, Mutability::Reference)],
+        stable_ptr,
+    )?;
+
+    // `next` returns `Option<Item>`.
+    let pattern_syntax = syntax.pattern(syntax_db);
+    let item_ty = ctx.resolver.inference.new_type_var(pattern_syntax.stable_ptr().untyped());
+    let option_ty = db.intern_type(TypeLongId::Concrete(ConcreteTypeId::Enum(
+        option_some_variant(db, GenericArgumentId::Type(item_ty)).concrete_enum_id,
+    )));
+    let next_ty = ctx.reduce_ty(next_call.ty());
+    if ctx.resolver.inference.conform_ty(next_ty, option_ty).is_err() {
+        ctx.diagnostics.report(syntax, WrongType { expected_ty: option_ty, actual_ty: next_ty });
+    }
+
+    let (pattern, body) = ctx.run_in_subscope(|new_ctx| {
+        let pattern = compute_pattern_semantic(new_ctx, pattern_syntax, item_ty)?;
+        for v in pattern.variables() {
+            let var_def = Variable::Local(v.var.clone());
+            new_ctx.environment.variables.insert(v.name.clone(), var_def.clone());
+            new_ctx.semantic_defs.insert(var_def.id(), var_def);
+        }
+        let body = compute_unit_loop_body_semantic(new_ctx, &syntax.body(syntax_db))?;
+        Ok((pattern, body))
+    })?;
+    Ok(Expr::For(ExprFor {
+        into_iter_call: ctx.exprs.alloc(into_iter_call),
+        iterator,
+        next_call: ctx.exprs.alloc(next_call),
+        pattern,
+        body: ctx.exprs.alloc(body),
+        ty: unit_ty(db),
+        stable_ptr,
+    }))
+}
+
+/// Computes the semantic model of the body of a `while` or `for` loop. Such loops evaluate to the
+/// unit type, so `break` statements inside them may not have a value.
+fn compute_unit_loop_body_semantic(
+    ctx: &mut ComputationContext<'_>,
+    body_syntax: &ast::ExprBlock,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let (body, _) = ctx.run_in_loop(|new_ctx| {
+        let flow_merge = new_ctx.loop_flow_merge.as_mut().unwrap();
+        // Cannot fail, as this is the first type merged.
+        let _ = flow_merge.try_merge_types(db, &mut new_ctx.resolver.inference, unit_ty(db));
+        compute_expr_block_semantic(new_ctx, body_syntax)
+    });
+    let body = body?;
+    let body_ty = body.ty();
+    if body_ty != never_ty(db)
+        && !body_ty.is_missing(db)
+        && ctx.resolver.inference.conform_ty(body_ty, unit_ty(db)).is_err()
+    {
+        ctx.diagnostics
+            .report(body_syntax, WrongType { expected_ty: unit_ty(db), actual_ty: body_ty });
+    }
+    Ok(body)
+}
+
+/// Computes the semantic model of an expression of type [ast::ExprClosure].
+fn compute_expr_closure_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprClosure,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+    let Some(function_id) = ctx.function_id else {
+        return Err(ctx.diagnostics.report(
+            syntax,
+            UnsupportedOutsideOfFunction {
+                feature_name: UnsupportedOutsideOfFunctionFeatureName::Closure,
+            },
+        ));
+    };
+    let parent_function = ConcreteFunctionWithBodyId::from_generic(db, function_id)
+        .map_err(|_| ctx.diagnostics.report(syntax, Unsupported))?;
+
+    let ((params, body, ret_ty), captures) = ctx.run_in_closure(|new_ctx| {
+        let params = match syntax.wrapper(syntax_db) {
+            ast::ClosureParamWrapper::Nullary(_) => vec![],
+            ast::ClosureParamWrapper::NAry(wrapper) => wrapper
+                .params(syntax_db)
+                .elements(syntax_db)
+                .into_iter()
+                .map(|param_syntax| compute_closure_param_semantic(new_ctx, &param_syntax))
+                .collect(),
+        };
+
+        let explicit_ret_ty = match syntax.ret_ty(syntax_db) {
+            ast::OptionReturnTypeClause::Empty(_) => None,
+            ast::OptionReturnTypeClause::ReturnTypeClause(ret_ty_clause) => Some(resolve_type(
+                db,
+                new_ctx.diagnostics,
+                &mut new_ctx.resolver,
+                &ret_ty_clause.ty(syntax_db),
+            )),
+        };
+
+        let body_syntax = syntax.expr(syntax_db);
+        let diagnostics_count = new_ctx.diagnostics.diagnostics.count;
+        let body = compute_expr_semantic(new_ctx, &body_syntax);
+        let body_ty = new_ctx.reduce_ty(body.ty());
+        let ret_ty = match explicit_ret_ty {
+            Some(expected_ty) => {
+                if !body_ty.is_missing(db)
+                    && new_ctx.resolver.inference.conform_ty(body_ty, expected_ty).is_err()
+                {
+                    new_ctx
+                        .diagnostics
+                        .report(&body_syntax, WrongReturnType { expected_ty, actual_ty: body_ty });
+                }
+                expected_ty
+            }
+            // The type of a body with errors (e.g. with a `return` statement) is unreliable, and
+            // should not cause more errors where the closure is used.
+            None if new_ctx.diagnostics.diagnostics.count > diagnostics_count => {
+                TypeId::missing(db, skip_diagnostic())
+            }
+            None => body_ty,
+        };
+        (params, body, ret_ty)
+    });
+
+    let captured_vars = captures.into_iter().collect_vec();
+    let captured_types =
+        captured_vars.iter().map(|var_id| ctx.semantic_defs[*var_id].ty()).collect();
+    let ty = db.intern_type(TypeLongId::Closure(ClosureTypeLongId {
+        param_tys: params.iter().map(|param: &LocalVariable| param.ty).collect(),
+        ret_ty,
+        captured_types,
+        parent_function,
+        wrapper_location: syntax.stable_ptr().into(),
+    }));
+    Ok(Expr::Closure(ExprClosure {
+        body: ctx.exprs.alloc(body),
+        params,
+        captured_vars,
+        ty,
+        stable_ptr: syntax.stable_ptr().into(),
+    }))
+}
+
+/// Computes the semantic model of a closure parameter, and adds it to the closure environment.
+fn compute_closure_param_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ClosureParam,
+) -> LocalVariable {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+    let identifier = syntax.name(syntax_db);
+    let name = identifier.text(syntax_db);
+
+    let is_mut = match compute_mutability(
+        ctx.diagnostics,
+        syntax_db,
+        &syntax.modifiers(syntax_db).elements(syntax_db),
+    ) {
+        Mutability::Immutable => false,
+        Mutability::Mutable => true,
+        Mutability::Reference => {
+            ctx.diagnostics.report(syntax, RefClosureParam);
+            false
+        }
+    };
+    let ty = match syntax.type_clause(syntax_db) {
+        ast::OptionTypeClause::Empty(_) => {
+            ctx.resolver.inference.new_type_var(syntax.stable_ptr().untyped())
+        }
+        ast::OptionTypeClause::TypeClause(type_clause) => {
+            resolve_type(db, ctx.diagnostics, &mut ctx.resolver, &type_clause.ty(syntax_db))
+        }
+    };
+    let var = LocalVariable {
+        id: db
+            .intern_local_var(LocalVarLongId(ctx.resolver.module_file_id, identifier.stable_ptr())),
+        ty,
+        is_mut,
+    };
+    let var_def = Variable::Local(var.clone());
+    if ctx.environment.variables.insert(name.clone(), var_def.clone()).is_some() {
+        ctx.diagnostics.report(syntax, ClosureParamNameRedefinition { param_name: name });
+    }
+    ctx.semantic_defs.insert(var_def.id(), var_def);
+    var
+}
+
 /// Computes the semantic model of an expression of type [ast::ExprErrorPropagate].
 fn compute_expr_error_propagate_semantic(
     ctx: &mut ComputationContext<'_>,
@@ -741,6 +1353,12 @@ fn compute_expr_error_propagate_semantic(
     let inner = compute_expr_semantic(ctx, &syntax.expr(syntax_db));
     let inner_ty = ctx.reduce_ty(inner.ty());
     inner_ty.check_not_missing(ctx.db)?;
+    if ctx.loop_flow_merge.is_some() {
+        return Err(ctx.diagnostics.report(syntax, ErrorPropagateNotAllowedInsideALoop));
+    }
+    if ctx.is_inside_closure() {
+        return Err(ctx.diagnostics.report(syntax, ErrorPropagateNotAllowedInsideAClosure));
+    }
     let (ok_variant, err_variant) =
         unwrap_error_propagation_type(ctx.db, inner_ty).ok_or_else(|| {
             ctx.diagnostics.report(syntax, ErrorPropagateOnNonErrorType { ty: inner_ty })
@@ -1153,6 +1771,67 @@ fn literal_to_semantic(
     Ok(ExprLiteral { value, ty, stable_ptr: literal_syntax.stable_ptr().into() })
 }
 
+/// Creates the semantic model of a short string expression from its AST.
+/// A string with the `_ByteArray` suffix is a `ByteArray` literal, of any length.
+fn short_string_expr_to_semantic(
+    ctx: &mut ComputationContext<'_>,
+    short_string_syntax: &ast::TerminalShortString,
+) -> Maybe<Expr> {
+    let text = short_string_syntax.text(ctx.db.upcast());
+    if let Some((literal, "_ByteArray")) = text[1..].rsplit_once('\'') {
+        let unescaped_literal = unescape(literal).map_err(|err| {
+            ctx.diagnostics.report(short_string_syntax, IllegalStringEscaping(format!("{err}")))
+        })?;
+        return byte_array_literal_to_semantic(
+            ctx,
+            unescaped_literal.as_bytes(),
+            short_string_syntax.stable_ptr().into(),
+        );
+    }
+    Ok(Expr::Literal(short_string_to_semantic(ctx, short_string_syntax)?))
+}
+
+/// Creates the semantic model of a `ByteArray` literal holding the given bytes.
+/// The literal is built by calls to corelib functions: the first creates the byte array with its
+/// pending word, and the rest append the full 31-byte words, in order.
+fn byte_array_literal_to_semantic(
+    ctx: &mut ComputationContext<'_>,
+    bytes: &[u8],
+    stable_ptr: ast::ExprPtr,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let byte_array_module = core_submodule(db, "byte_array");
+    let literal = |value: BigInt, ty: TypeId| {
+        (Expr::Literal(ExprLiteral { value, ty, stable_ptr }), None, Mutability::Immutable)
+    };
+    let words = bytes.chunks_exact(31);
+    let pending_word = words.remainder();
+    let mut expr = expr_function_call(
+        ctx,
+        get_function_id(db, byte_array_module, "string_literal_new".into(), vec![]),
+        vec![
+            literal(BigInt::from_bytes_be(Sign::Plus, pending_word), core_felt252_ty(db)),
+            literal(pending_word.len().into(), get_core_ty_by_name(db, "u32".into(), vec![])),
+        ],
+        stable_ptr,
+    )?;
+    let append_word_function =
+        get_function_id(db, byte_array_module, "string_literal_append_word".into(), vec![]);
+    let bytes31_ty = get_core_ty_by_name(db, "bytes31".into(), vec![]);
+    for word in words {
+        expr = expr_function_call(
+            ctx,
+            append_word_function,
+            vec![
+                (expr, None, Mutability::Immutable),
+                literal(BigInt::from_bytes_be(Sign::Plus, word), bytes31_ty),
+            ],
+            stable_ptr,
+        )?;
+    }
+    Ok(expr)
+}
+
 /// Creates the semantic model of a short string from its AST.
 fn short_string_to_semantic(
     ctx: &mut ComputationContext<'_>,
@@ -1172,6 +1851,9 @@ fn short_string_to_semantic(
         let unescaped_literal = unescape(literal).map_err(|err| {
             ctx.diagnostics.report(short_string_syntax, IllegalStringEscaping(format!("{err}")))
         })?;
+        if suffix.is_empty() && unescaped_literal.len() > 31 {
+            return Err(ctx.diagnostics.report(short_string_syntax, ShortStringTooLong));
+        }
         let value = BigInt::from_bytes_be(Sign::Plus, unescaped_literal.as_bytes());
         validate_literal(db, ty, value.clone())
             .map_err(|err| ctx.diagnostics.report(short_string_syntax, err))?;
@@ -1215,11 +1897,11 @@ fn dot_expr(
     }
 }
 
-/// Finds all the trait ids usable in the current context.
-fn all_module_trait_ids(ctx: &mut ComputationContext<'_>) -> Maybe<Vec<TraitId>> {
-    let mut module_traits = ctx.db.module_traits_ids(ctx.resolver.module_file_id.0)?;
-    for use_id in ctx.db.module_uses_ids(ctx.resolver.module_file_id.0)? {
-        if let Ok(ResolvedGenericItem::Trait(trait_id)) = ctx.db.use_resolved_item(use_id) {
+/// Finds all the trait ids usable in the given module.
+pub fn all_module_trait_ids(db: &dyn SemanticGroup, module_id: ModuleId) -> Maybe<Vec<TraitId>> {
+    let mut module_traits = db.module_traits_ids(module_id)?;
+    for use_id in db.module_uses_ids(module_id)? {
+        if let Ok(ResolvedGenericItem::Trait(trait_id)) = db.use_resolved_item(use_id) {
             module_traits.push(trait_id);
         }
     }
@@ -1242,9 +1924,59 @@ fn method_call_expr(
     let segment = path.elements(syntax_db).last().unwrap().clone();
     let func_name = segment.identifier(syntax_db);
     let generic_args_syntax = segment.generic_args(syntax_db);
+    let candidate_traits = all_module_trait_ids(ctx.db, ctx.resolver.module_file_id.0)?;
+
+    let (function_id, fixed_lexpr, mutability) = compute_method_function_call_data(
+        ctx,
+        &candidate_traits,
+        func_name.clone(),
+        lexpr,
+        path.stable_ptr().untyped(),
+        stable_ptr,
+        generic_args_syntax,
+        |ty| NoSuchMethod { ty, method_name: func_name },
+        |trait_function_id0, trait_function_id1| AmbiguousTrait {
+            trait_function_id0,
+            trait_function_id1,
+        },
+    )?;
+
+    let named_args: Vec<_> = chain!(
+        [(fixed_lexpr, None, mutability)],
+        expr.arguments(syntax_db)
+            .args(syntax_db)
+            .elements(syntax_db)
+            .into_iter()
+            .map(|arg_syntax| compute_named_argument_clause(ctx, arg_syntax))
+    )
+    .collect();
+
+    expr_function_call(ctx, function_id, named_args, stable_ptr)
+}
+
+/// Finds the function a method call on `self_expr` resolves to, among the functions named
+/// `func_name` in `candidate_traits` that have an impl matching the type of `self_expr`.
+/// Returns the function, `self_expr` with the snapshots required by the function added, and the
+/// mutability of the `self` param.
+/// `method_ptr` is where diagnostics about the method are reported.
+#[allow(clippy::too_many_arguments)]
+fn compute_method_function_call_data(
+    ctx: &mut ComputationContext<'_>,
+    candidate_traits: &[TraitId],
+    func_name: SmolStr,
+    self_expr: Expr,
+    method_ptr: SyntaxStablePtrId,
+    stable_ptr: ast::ExprPtr,
+    generic_args_syntax: Option<Vec<ast::GenericArg>>,
+    no_candidate_diagnostic: impl FnOnce(TypeId) -> SemanticDiagnosticKind,
+    multiple_candidates_diagnostic: impl FnOnce(
+        TraitFunctionId,
+        TraitFunctionId,
+    ) -> SemanticDiagnosticKind,
+) -> Maybe<(FunctionId, Expr, Mutability)> {
     let mut candidates = vec![];
-    let ty = ctx.reduce_ty(lexpr.ty());
-    for trait_id in all_module_trait_ids(ctx)? {
+    let ty = ctx.reduce_ty(self_expr.ty());
+    for trait_id in candidate_traits.iter().copied() {
         for (name, trait_function) in ctx.db.trait_functions(trait_id)? {
             if name != func_name {
                 continue;
@@ -1276,16 +2008,13 @@ fn method_call_expr(
 
     let trait_function = match candidates[..] {
         [] => {
-            return Err(ctx.diagnostics.report_by_ptr(
-                path.stable_ptr().untyped(),
-                NoSuchMethod { ty, method_name: func_name },
-            ));
+            return Err(ctx.diagnostics.report_by_ptr(method_ptr, no_candidate_diagnostic(ty)));
         }
         [trait_function] => trait_function,
         [trait_function_id0, trait_function_id1, ..] => {
             return Err(ctx.diagnostics.report_by_ptr(
                 stable_ptr.untyped(),
-                AmbiguousTrait { trait_function_id0, trait_function_id1 },
+                multiple_candidates_diagnostic(trait_function_id0, trait_function_id1),
             ));
         }
     };
@@ -1317,32 +2046,21 @@ fn method_call_expr(
         .infer_trait_generic_function(
             concrete_trait_function_id,
             &ctx.resolver.impl_lookup_context(),
-            path.stable_ptr().untyped(),
+            method_ptr,
         )
-        .map_err(|err| err.report(ctx.diagnostics, path.stable_ptr().untyped()))?;
+        .map_err(|err| err.report(ctx.diagnostics, method_ptr))?;
 
     let function_id = ctx.db.intern_function(FunctionLongId {
         function: ConcreteFunction { generic_function, generic_args },
     });
 
-    let mut fixed_lexpr = lexpr;
+    let mut fixed_expr = self_expr;
     for _ in 0..n_snapshots {
-        let ty = ctx.db.intern_type(TypeLongId::Snapshot(fixed_lexpr.ty()));
-        fixed_lexpr =
-            Expr::Snapshot(ExprSnapshot { inner: ctx.exprs.alloc(fixed_lexpr), ty, stable_ptr });
+        let ty = ctx.db.intern_type(TypeLongId::Snapshot(fixed_expr.ty()));
+        fixed_expr =
+            Expr::Snapshot(ExprSnapshot { inner: ctx.exprs.alloc(fixed_expr), ty, stable_ptr });
     }
-
-    let named_args: Vec<_> = chain!(
-        [(fixed_lexpr, None, first_param.mutability)],
-        expr.arguments(syntax_db)
-            .args(syntax_db)
-            .elements(syntax_db)
-            .into_iter()
-            .map(|arg_syntax| compute_named_argument_clause(ctx, arg_syntax))
-    )
-    .collect();
-
-    expr_function_call(ctx, function_id, named_args, stable_ptr)
+    Ok((function_id, fixed_expr, first_param.mutability))
 }
 
 /// Computes the semantic model of a member access expression (e.g. "expr.member").
@@ -1406,7 +2124,7 @@ fn member_access_expr(
             // TODO(spapini): Handle snapshot members.
             Err(ctx.diagnostics.report(&rhs_syntax, Unsupported))
         }
-        TypeLongId::GenericParameter(_) => {
+        TypeLongId::GenericParameter(_) | TypeLongId::ImplType(_) | TypeLongId::Closure(_) => {
             Err(ctx.diagnostics.report(&rhs_syntax, TypeHasNoMembers { ty, member_name }))
         }
         TypeLongId::Var(_) => Err(ctx
@@ -1437,6 +2155,14 @@ fn resolve_expr_path(ctx: &mut ComputationContext<'_>, path: &ast::ExprPath) ->
     // Check if this is a constant.
     let resolved_item =
         ctx.resolver.resolve_concrete_path(ctx.diagnostics, path, NotFoundItemType::Identifier)?;
+    if let ResolvedConcreteItem::ImplConstant(impl_constant_id) = resolved_item {
+        let ty = impl_constant_id.ty(db)?;
+        return Ok(Expr::ImplConstant(ExprImplConstant {
+            impl_constant_id,
+            ty,
+            stable_ptr: path.stable_ptr().into(),
+        }));
+    }
     let ResolvedConcreteItem::Constant(constant_id) = resolved_item else {
         return Err(
             ctx.diagnostics.report(path, UnexpectedElement{
@@ -1446,7 +2172,10 @@ fn resolve_expr_path(ctx: &mut ComputationContext<'_>, path: &ast::ExprPath) ->
         );
     };
 
-    let ty = db.constant_semantic_data(constant_id)?.value.ty();
+    // Check for cycles in this constant definition.
+    // TODO: Handle this without using `priv_constant_semantic_data`.
+    db.priv_constant_semantic_data(constant_id)?.check_no_cycle()?;
+    let ty = db.constant_semantic_data(constant_id)?.ty;
     Ok(Expr::Constant(ExprConstant { constant_id, ty, stable_ptr: path.stable_ptr().into() }))
 }
 
@@ -1469,14 +2198,8 @@ pub fn get_variable_by_name(
     variable_name: &SmolStr,
     stable_ptr: ast::ExprPtr,
 ) -> Option<Expr> {
-    let mut maybe_env = Some(&*ctx.environment);
-    while let Some(env) = maybe_env {
-        if let Some(var) = env.variables.get(variable_name) {
-            return Some(Expr::Var(ExprVar { var: var.id(), ty: var.ty(), stable_ptr }));
-        }
-        maybe_env = env.parent.as_deref();
-    }
-    None
+    let var = ctx.environment.get_variable(variable_name)?;
+    Some(Expr::Var(ExprVar { var: var.id(), ty: var.ty(), stable_ptr }))
 }
 
 /// Typechecks a function call.
@@ -1498,14 +2221,9 @@ fn expr_function_call(
     }
 
     // Check panicable.
-    if signature.panicable
-        && !ctx
-            .get_signature(
-                stable_ptr.untyped(),
-                UnsupportedOutsideOfFunctionFeatureName::FunctionCall,
-            )?
-            .panicable
-    {
+    // Outside of functions, calls may only appear in constant expressions, which are evaluated at
+    // compile time.
+    if signature.panicable && ctx.signature.is_some_and(|signature| !signature.panicable) {
         // TODO(spapini): Delay this check until after inference, to allow resolving specific
         //   impls first.
         return Err(ctx.diagnostics.report_by_ptr(stable_ptr.untyped(), PanicableFromNonPanicable));
@@ -1541,6 +2259,8 @@ fn expr_function_call(
             // Verify the variable argument is mutable.
             if !ctx.semantic_defs[ref_arg.base_var()].is_mut() {
                 ctx.diagnostics.report_by_ptr(arg.stable_ptr().untyped(), RefArgNotMutable);
+            } else if ctx.is_captured(ref_arg.base_var()) {
+                ctx.diagnostics.report_by_ptr(arg.stable_ptr().untyped(), MutationOfCapturedVar);
             }
             // Verify that it is passed explicitly as 'ref'.
             if mutability != Mutability::Reference {
@@ -1658,7 +2378,12 @@ pub fn compute_statement_semantic(
                 ast::OptionTerminalSemicolon::Empty(_)
             ) && !matches!(
                 expr_syntax,
-                ast::Expr::Block(_) | ast::Expr::If(_) | ast::Expr::Match(_)
+                ast::Expr::Block(_)
+                    | ast::Expr::If(_)
+                    | ast::Expr::Match(_)
+                    | ast::Expr::Loop(_)
+                    | ast::Expr::While(_)
+                    | ast::Expr::For(_)
             ) {
                 // Point to after the expression, where the semicolon is missing.
                 ctx.diagnostics.report_after(&expr_syntax, MissingSemicolon);
@@ -1668,7 +2393,23 @@ pub fn compute_statement_semantic(
                 stable_ptr: syntax.stable_ptr(),
             })
         }
+        ast::Statement::Continue(continue_syntax) => {
+            if ctx.loop_flow_merge.is_none() {
+                return Err(ctx
+                    .diagnostics
+                    .report(continue_syntax, ContinueOnlyAllowedInsideALoop));
+            }
+            semantic::Statement::Continue(semantic::StatementContinue {
+                stable_ptr: syntax.stable_ptr(),
+            })
+        }
         ast::Statement::Return(return_syntax) => {
+            if ctx.loop_flow_merge.is_some() {
+                return Err(ctx.diagnostics.report(return_syntax, ReturnNotAllowedInsideALoop));
+            }
+            if ctx.is_inside_closure() {
+                return Err(ctx.diagnostics.report(return_syntax, ReturnNotAllowedInsideAClosure));
+            }
             let expr_syntax = return_syntax.expr(syntax_db);
             let expr = compute_expr_semantic(ctx, &expr_syntax);
             let expr_ty = expr.ty();
@@ -1690,6 +2431,28 @@ pub fn compute_statement_semantic(
                 stable_ptr: syntax.stable_ptr(),
             })
         }
+        ast::Statement::Break(break_syntax) => {
+            let expr_option = match break_syntax.expr_clause(syntax_db) {
+                ast::OptionExprClause::Empty(_) => None,
+                ast::OptionExprClause::ExprClause(expr_clause) => {
+                    Some(compute_expr_semantic(ctx, &expr_clause.expr(syntax_db)))
+                }
+            };
+            let ty = expr_option.as_ref().map(|expr| expr.ty()).unwrap_or_else(|| unit_ty(db));
+            let Some(flow_merge) = ctx.loop_flow_merge.as_mut() else {
+                return Err(ctx.diagnostics.report(break_syntax, BreakOnlyAllowedInsideALoop));
+            };
+            if let Err((current_ty, break_ty)) =
+                flow_merge.try_merge_types(ctx.db, &mut ctx.resolver.inference, ty)
+            {
+                ctx.diagnostics
+                    .report(break_syntax, IncompatibleLoopBreakTypes { current_ty, break_ty });
+            }
+            semantic::Statement::Break(semantic::StatementBreak {
+                expr_option: expr_option.map(|expr| ctx.exprs.alloc(expr)),
+                stable_ptr: syntax.stable_ptr(),
+            })
+        }
         ast::Statement::Missing(_) => todo!(),
     };
     Ok(ctx.statements.alloc(statement))
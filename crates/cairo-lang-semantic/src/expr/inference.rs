@@ -5,34 +5,37 @@ use std::collections::HashMap;
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::ids::{
     ConstantId, EnumId, ExternFunctionId, ExternTypeId, FreeFunctionId, GenericParamId, ImplDefId,
-    ImplFunctionId, LanguageElementId, LocalVarId, MemberId, ParamId, StructId, TraitFunctionId,
-    TraitId, VarId, VariantId,
+    ImplFunctionId, LanguageElementId, LocalVarId, MemberId, ParamId, StructId, TraitConstantId,
+    TraitFunctionId, TraitId, TraitTypeId, VarId, VariantId,
 };
 use cairo_lang_diagnostics::{skip_diagnostic, DiagnosticAdded};
 use cairo_lang_proc_macros::DebugWithDb;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_utils::extract_matches;
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
-use itertools::{zip_eq, Itertools};
+use itertools::{chain, zip_eq, Itertools};
 
-use crate::corelib::never_ty;
+use crate::corelib::{copy_trait, drop_trait, fn_once_trait, fn_trait, never_ty};
 use crate::db::SemanticGroup;
 use crate::diagnostic::{SemanticDiagnosticKind, SemanticDiagnostics};
 use crate::expr::objects::*;
 use crate::expr::pattern::*;
+use crate::items::constant::ConstValue;
 use crate::items::functions::{
-    ConcreteFunctionWithBody, GenericFunctionId, GenericFunctionWithBodyId, ImplGenericFunctionId,
-    ImplGenericFunctionWithBodyId,
+    ConcreteFunctionWithBody, ConcreteFunctionWithBodyId, GenericFunctionId,
+    GenericFunctionWithBodyId, ImplGenericFunctionId, ImplGenericFunctionWithBodyId,
 };
 use crate::items::generics::{GenericParamConst, GenericParamImpl, GenericParamType};
 use crate::items::imp::{
-    find_possible_impls_at_context, ImplId, ImplLookupContext, UninferredImpl,
+    find_possible_impls_at_context, ImplConstantId, ImplId, ImplLookupContext, ImplTypeId,
+    UninferredImpl,
 };
 use crate::items::trt::{ConcreteTraitGenericFunctionId, ConcreteTraitGenericFunctionLongId};
 use crate::literals::LiteralId;
 use crate::substitution::{GenericSubstitution, HasDb, SemanticRewriter, SubstitutionRewriter};
 use crate::types::{
-    peel_snapshots, ConcreteEnumLongId, ConcreteExternTypeLongId, ConcreteStructLongId,
+    peel_snapshots, ClosureTypeLongId, ConcreteEnumLongId, ConcreteExternTypeLongId,
+    ConcreteStructLongId,
 };
 use crate::{
     add_basic_rewrites, add_expr_rewrites, ConcreteEnumId, ConcreteExternTypeId, ConcreteFunction,
@@ -345,8 +348,25 @@ impl<'db> Inference<'db> {
         match long_ty1 {
             TypeLongId::Var(var) => return Ok((self.assign_ty(var, ty0)?, 0)),
             TypeLongId::Missing(_) => return Ok((ty1, 0)),
-            TypeLongId::Snapshot(inner_ty) if ty0_is_self && inner_ty == ty0 => {
-                return Ok((ty1, 1));
+            TypeLongId::Snapshot(inner_ty) if ty0_is_self => {
+                if inner_ty == ty0 {
+                    return Ok((ty1, 1));
+                }
+                // A non-snapshot self conforms to a snapshot of a type variable by taking a
+                // snapshot of it.
+                if let TypeLongId::Var(var) = self.db.lookup_intern_type(inner_ty) {
+                    if matches!(
+                        self.db.lookup_intern_type(ty0),
+                        TypeLongId::Concrete(_)
+                            | TypeLongId::Tuple(_)
+                            | TypeLongId::GenericParameter(_)
+                            | TypeLongId::ImplType(_)
+                            | TypeLongId::Closure(_)
+                    ) {
+                        self.assign_ty(var, ty0)?;
+                        return Ok((self.db.intern_type(TypeLongId::Snapshot(ty0)), 1));
+                    }
+                }
             }
             _ => {}
         }
@@ -393,6 +413,47 @@ impl<'db> Inference<'db> {
                 Ok((self.db.intern_type(TypeLongId::Snapshot(ty)), n_snapshots))
             }
             TypeLongId::GenericParameter(_) => Err(InferenceError::TypeKindMismatch { ty0, ty1 }),
+            TypeLongId::ImplType(impl_type0) => {
+                let TypeLongId::ImplType(impl_type1) = long_ty1 else {
+                    return Err(InferenceError::TypeKindMismatch { ty0, ty1 });
+                };
+                if impl_type0.trait_type_id != impl_type1.trait_type_id {
+                    return Err(InferenceError::TypeKindMismatch { ty0, ty1 });
+                }
+                let impl_id = self.conform_impl(impl_type0.impl_id, impl_type1.impl_id)?;
+                let ty = self.db.intern_type(TypeLongId::ImplType(ImplTypeId {
+                    impl_id,
+                    trait_type_id: impl_type0.trait_type_id,
+                }));
+                // The impl might have become concrete.
+                Ok((self.rewrite(ty)?, n_snapshots))
+            }
+            TypeLongId::Closure(closure0) => {
+                let (n_snapshots, long_ty1) = self.maybe_peel_snapshots(ty0_is_self, ty1);
+                let TypeLongId::Closure(closure1) = long_ty1 else {
+                    return Err(InferenceError::TypeKindMismatch { ty0, ty1 });
+                };
+                // Each closure expression has its own type.
+                if closure0.wrapper_location != closure1.wrapper_location
+                    || closure0.parent_function != closure1.parent_function
+                {
+                    return Err(InferenceError::TypeKindMismatch { ty0, ty1 });
+                }
+                let param_tys = zip_eq(closure0.param_tys, closure1.param_tys)
+                    .map(|(subty0, subty1)| self.conform_ty(subty0, subty1))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let ret_ty = self.conform_ty(closure0.ret_ty, closure1.ret_ty)?;
+                let captured_types = zip_eq(closure0.captured_types, closure1.captured_types)
+                    .map(|(subty0, subty1)| self.conform_ty(subty0, subty1))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let long_ty = TypeLongId::Closure(ClosureTypeLongId {
+                    param_tys,
+                    ret_ty,
+                    captured_types,
+                    ..closure0
+                });
+                Ok((self.db.intern_type(long_ty), n_snapshots))
+            }
             TypeLongId::Var(var) => Ok((self.assign_ty(var, ty1)?, n_snapshots)),
             TypeLongId::Missing(_) => Ok((ty0, n_snapshots)),
         }
@@ -497,7 +558,15 @@ impl<'db> Inference<'db> {
                     generic_args,
                 })))
             }
-            ImplId::GenericParameter(_) => Err(InferenceError::ImplKindMismatch { impl0, impl1 }),
+            ImplId::GenericParameter(_) | ImplId::SelfImpl(_) => {
+                Err(InferenceError::ImplKindMismatch { impl0, impl1 })
+            }
+            ImplId::Closure(concrete_trait0) => {
+                let ImplId::Closure(concrete_trait1) = impl1 else {
+                    return Err(InferenceError::ImplKindMismatch { impl0, impl1 });
+                };
+                Ok(ImplId::Closure(self.conform_traits(concrete_trait0, concrete_trait1)?))
+            }
         }
     }
 
@@ -545,7 +614,17 @@ impl<'db> Inference<'db> {
                 .collect::<InferenceResult<Vec<_>>>()?
                 .into_iter()
                 .any(|x| x),
+            TypeLongId::Closure(closure) => {
+                chain!(closure.param_tys, [closure.ret_ty], closure.captured_types)
+                    .map(|ty| self.ty_contains_var(ty, var))
+                    .collect::<InferenceResult<Vec<_>>>()?
+                    .into_iter()
+                    .any(|x| x)
+            }
             TypeLongId::Snapshot(ty) => self.ty_contains_var(ty, var)?,
+            TypeLongId::ImplType(impl_type_id) => {
+                self.impl_contains_var(&impl_type_id.impl_id, var)?
+            }
             TypeLongId::Var(new_var) => {
                 if InferenceVar::Type(new_var.id) == var {
                     return Ok(true);
@@ -591,6 +670,9 @@ impl<'db> Inference<'db> {
                 var,
             )?,
             ImplId::GenericParameter(_) => false,
+            ImplId::Closure(concrete_trait_id) | ImplId::SelfImpl(concrete_trait_id) => {
+                self.generic_args_contain_var(&concrete_trait_id.generic_args(self.db), var)?
+            }
             ImplId::ImplVar(new_var) => {
                 if InferenceVar::Impl(new_var.id) == var {
                     return Ok(true);
@@ -725,7 +807,7 @@ impl<'db> Inference<'db> {
             let generic_arg =
                 self.infer_generic_arg(&generic_param, lookup_context.clone(), stable_ptr)?;
             generic_args.push(generic_arg);
-            substitution.0.insert(generic_param.id(), generic_arg);
+            substitution.param_to_arg.insert(generic_param.id(), generic_arg);
         }
         Ok(generic_args)
     }
@@ -882,6 +964,54 @@ impl<'db> Inference<'db> {
         Ok(())
     }
 
+    /// Tries to assign an [ImplVar] of a trait the compiler implements for closure types (`FnOnce`,
+    /// `Fn`, `Copy` and `Drop`), where the first generic argument is a closure type.
+    /// Returns None if the trait is not such a trait or the type is not (yet) a closure type.
+    fn try_to_infer_closure_impl(
+        &mut self,
+        var: ImplVar,
+        concrete_trait_id: ConcreteTraitId,
+    ) -> InferenceResult<Option<ImplId>> {
+        let generic_args = concrete_trait_id.generic_args(self.db);
+        let Some(GenericArgumentId::Type(ty)) = generic_args.first() else {
+            return Ok(None);
+        };
+        let TypeLongId::Closure(closure) = self.db.lookup_intern_type(*ty) else {
+            return Ok(None);
+        };
+        let trait_id = concrete_trait_id.trait_id(self.db);
+        // The trait the captured types are required to implement.
+        let captured_types_trait =
+            if trait_id == fn_once_trait(self.db) || trait_id == fn_trait(self.db) {
+                let [_, GenericArgumentId::Type(args_ty), GenericArgumentId::Type(ret_ty)] =
+                    generic_args[..]
+                else {
+                    return Ok(None);
+                };
+                let params_ty = self.db.intern_type(TypeLongId::Tuple(closure.param_tys));
+                self.conform_ty(params_ty, args_ty)?;
+                self.conform_ty(closure.ret_ty, ret_ty)?;
+                // Calling a closure by a snapshot requires copying its captured variables.
+                if trait_id == fn_trait(self.db) { Some(copy_trait(self.db)) } else { None }
+            } else if trait_id == copy_trait(self.db) || trait_id == drop_trait(self.db) {
+                Some(trait_id)
+            } else {
+                return Ok(None);
+            };
+        if let Some(captured_types_trait) = captured_types_trait {
+            let lookup_context = self.impl_var_data[var.id].lookup_context.clone();
+            for ty in closure.captured_types {
+                let concrete_trait_id = self.db.intern_concrete_trait(ConcreteTraitLongId {
+                    trait_id: captured_types_trait,
+                    generic_args: vec![GenericArgumentId::Type(ty)],
+                });
+                self.new_impl_var(concrete_trait_id, var.stable_ptr, lookup_context.clone())?;
+            }
+        }
+        let impl_id = ImplId::Closure(self.rewrite(concrete_trait_id)?);
+        Ok(Some(self.assign_impl(var, impl_id)?))
+    }
+
     /// Relaxes the information about an [ImplVar]. Prunes the current candidate impls, and assigns
     /// if only a single candidate is left.
     fn relax_impl_var(&mut self, var: ImplVar) -> InferenceResult<ImplId> {
@@ -890,6 +1020,9 @@ impl<'db> Inference<'db> {
             return self.rewrite(*res);
         }
         let var_concrete_trait_id = self.rewrite(var.concrete_trait_id)?;
+        if let Some(impl_id) = self.try_to_infer_closure_impl(var, var_concrete_trait_id)? {
+            return Ok(impl_id);
+        }
         self.try_to_resume_impl_var(var)?;
         let inference_clone = self.clone();
         let lookup_context = self.impl_var_data[var.id].lookup_context.clone();
@@ -971,7 +1104,14 @@ impl<'a> SemanticRewriter<TypeLongId, InferenceError> for Inference<'a> {
                 return self.rewrite(self.db.lookup_intern_type(*type_id));
             }
         }
-        value.default_rewrite(self)
+        let value = value.default_rewrite(self)?;
+        // Reduce associated types of impls that were inferred to be concrete.
+        if let TypeLongId::ImplType(impl_type_id) = value {
+            if let Some(ty) = impl_type_id.concrete_type(self.db).map_err(InferenceError::Failed)? {
+                return self.rewrite(self.db.lookup_intern_type(ty));
+            }
+        }
+        Ok(value)
     }
 }
 impl<'a> SemanticRewriter<ImplId, InferenceError> for Inference<'a> {
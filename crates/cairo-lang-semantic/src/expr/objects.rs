@@ -8,6 +8,7 @@ use num_bigint::BigInt;
 
 use super::fmt::ExprFormatter;
 use super::pattern::Pattern;
+use crate::items::imp::ImplConstantId;
 use crate::{semantic, ConcreteStructId, FunctionId, TypeId};
 
 pub type ExprId = Id<Expr>;
@@ -40,14 +41,18 @@ impl DebugWithDb<ExprFormatter<'_>> for StatementId {
 pub enum Statement {
     Expr(StatementExpr),
     Let(StatementLet),
+    Continue(StatementContinue),
     Return(StatementReturn),
+    Break(StatementBreak),
 }
 impl Statement {
     pub fn stable_ptr(&self) -> ast::StatementPtr {
         match self {
             Statement::Expr(stmt) => stmt.stable_ptr,
             Statement::Let(stmt) => stmt.stable_ptr,
+            Statement::Continue(stmt) => stmt.stable_ptr,
             Statement::Return(stmt) => stmt.stable_ptr,
+            Statement::Break(stmt) => stmt.stable_ptr,
         }
     }
 }
@@ -71,6 +76,14 @@ pub struct StatementLet {
     pub stable_ptr: ast::StatementPtr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct StatementContinue {
+    #[hide_field_debug_with_db]
+    #[dont_rewrite]
+    pub stable_ptr: ast::StatementPtr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
 #[debug_db(ExprFormatter<'a>)]
 pub struct StatementReturn {
@@ -80,6 +93,16 @@ pub struct StatementReturn {
     pub stable_ptr: ast::StatementPtr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct StatementBreak {
+    /// The value the loop evaluates to. None for a `break` without a value.
+    pub expr_option: Option<ExprId>,
+    #[hide_field_debug_with_db]
+    #[dont_rewrite]
+    pub stable_ptr: ast::StatementPtr,
+}
+
 // Expressions.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
 #[debug_db(ExprFormatter<'a>)]
@@ -92,6 +115,10 @@ pub enum Expr {
     FunctionCall(ExprFunctionCall),
     Match(ExprMatch),
     If(ExprIf),
+    Loop(ExprLoop),
+    While(ExprWhile),
+    For(ExprFor),
+    Closure(ExprClosure),
     Var(ExprVar),
     Literal(ExprLiteral),
     MemberAccess(ExprMemberAccess),
@@ -99,6 +126,7 @@ pub enum Expr {
     EnumVariantCtor(ExprEnumVariantCtor),
     PropagateError(ExprPropagateError),
     Constant(ExprConstant),
+    ImplConstant(ExprImplConstant),
     Missing(ExprMissing),
 }
 impl Expr {
@@ -112,6 +140,10 @@ impl Expr {
             Expr::FunctionCall(expr) => expr.ty,
             Expr::Match(expr) => expr.ty,
             Expr::If(expr) => expr.ty,
+            Expr::Loop(expr) => expr.ty,
+            Expr::While(expr) => expr.ty,
+            Expr::For(expr) => expr.ty,
+            Expr::Closure(expr) => expr.ty,
             Expr::Var(expr) => expr.ty,
             Expr::Literal(expr) => expr.ty,
             Expr::MemberAccess(expr) => expr.ty,
@@ -119,6 +151,7 @@ impl Expr {
             Expr::EnumVariantCtor(expr) => expr.ty,
             Expr::PropagateError(expr) => expr.ok_variant.ty,
             Expr::Constant(expr) => expr.ty,
+            Expr::ImplConstant(expr) => expr.ty,
             Expr::Missing(expr) => expr.ty,
         }
     }
@@ -132,6 +165,10 @@ impl Expr {
             Expr::FunctionCall(expr) => expr.stable_ptr,
             Expr::Match(expr) => expr.stable_ptr,
             Expr::If(expr) => expr.stable_ptr,
+            Expr::Loop(expr) => expr.stable_ptr,
+            Expr::While(expr) => expr.stable_ptr,
+            Expr::For(expr) => expr.stable_ptr,
+            Expr::Closure(expr) => expr.stable_ptr,
             Expr::Var(expr) => expr.stable_ptr,
             Expr::Literal(expr) => expr.stable_ptr,
             Expr::MemberAccess(expr) => expr.stable_ptr,
@@ -139,6 +176,7 @@ impl Expr {
             Expr::EnumVariantCtor(expr) => expr.stable_ptr,
             Expr::PropagateError(expr) => expr.stable_ptr,
             Expr::Constant(expr) => expr.stable_ptr,
+            Expr::ImplConstant(expr) => expr.stable_ptr,
             Expr::Missing(expr) => expr.stable_ptr,
         }
     }
@@ -274,6 +312,60 @@ pub struct ExprIf {
     pub stable_ptr: ast::ExprPtr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprLoop {
+    pub body: ExprId,
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    #[dont_rewrite]
+    pub stable_ptr: ast::ExprPtr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprWhile {
+    pub condition: ExprId,
+    pub body: ExprId,
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    #[dont_rewrite]
+    pub stable_ptr: ast::ExprPtr,
+}
+
+/// A `for` loop, iterating using the `IntoIterator` and `Iterator` traits.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprFor {
+    /// The call to `into_iter` on the iterated expression, evaluated once before the loop.
+    pub into_iter_call: ExprId,
+    /// The variable holding the iterator, which is not accessible by name.
+    pub iterator: semantic::LocalVariable,
+    /// The call to `next` on `iterator`, evaluated at the start of each iteration.
+    pub next_call: ExprId,
+    /// The pattern the items of the iterator are bound to.
+    pub pattern: Pattern,
+    pub body: ExprId,
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    #[dont_rewrite]
+    pub stable_ptr: ast::ExprPtr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprClosure {
+    pub body: ExprId,
+    pub params: Vec<semantic::LocalVariable>,
+    /// The variables of the enclosing function used by the closure body, in the order of their
+    /// first use.
+    pub captured_vars: Vec<VarId>,
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    #[dont_rewrite]
+    pub stable_ptr: ast::ExprPtr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
 #[debug_db(ExprFormatter<'a>)]
 pub struct MatchArm {
@@ -375,6 +467,17 @@ pub struct ExprConstant {
     pub stable_ptr: ast::ExprPtr,
 }
 
+/// An associated constant of an impl.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprImplConstant {
+    pub impl_constant_id: ImplConstantId,
+    pub ty: semantic::TypeId,
+    #[dont_rewrite]
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::ExprPtr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
 #[debug_db(ExprFormatter<'a>)]
 pub struct ExprMissing {
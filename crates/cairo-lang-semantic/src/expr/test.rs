@@ -21,15 +21,18 @@ cairo_lang_test_utils::test_file_test!(
     "src/expr/test_data",
     {
         assignment: "assignment",
+        closure: "closure",
         constant: "constant",
         enum_: "enum",
         error_propagate: "error_propagate",
         function_call: "function_call",
         generics: "generics",
         if_: "if",
+        index: "index",
         inference: "inference",
         let_statement: "let_statement",
         literal: "literal",
+        loop_: "loop",
         match_: "match",
         method: "method",
         operators: "operators",
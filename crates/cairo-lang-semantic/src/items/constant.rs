@@ -1,15 +1,27 @@
 use std::sync::Arc;
 
-use cairo_lang_defs::ids::{ConstantId, LanguageElementId};
-use cairo_lang_diagnostics::{Diagnostics, Maybe, ToMaybe};
-use cairo_lang_proc_macros::DebugWithDb;
+use cairo_lang_defs::ids::{ConstantId, LanguageElementId, MemberId};
+use cairo_lang_diagnostics::{skip_diagnostic, DiagnosticAdded, Diagnostics, Maybe, ToMaybe};
+use cairo_lang_proc_macros::{DebugWithDb, SemanticObject};
+use cairo_lang_syntax::node::ast;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use id_arena::Arena;
+use num_bigint::BigInt;
+use num_traits::{Num, Signed, Zero};
 
+use crate::corelib::{core_felt252_ty, get_core_trait, get_core_ty_by_name, validate_literal};
 use crate::db::SemanticGroup;
-use crate::diagnostic::SemanticDiagnostics;
-use crate::expr::compute::{compute_expr_semantic, ComputationContext, Environment};
+use crate::diagnostic::{SemanticDiagnosticKind, SemanticDiagnostics};
+use crate::expr::compute::{compute_expr_semantic, infer_all, ComputationContext, Environment};
+use crate::items::functions::GenericFunctionId;
+use crate::items::imp::ImplConstantId;
+use crate::items::structure::SemanticStructEx;
 use crate::resolve_path::{ResolvedLookback, Resolver};
 use crate::types::resolve_type;
-use crate::{Expr, SemanticDiagnostic};
+use crate::{
+    ConcreteVariant, Expr, ExprBlock, ExprFunctionCall, ExprFunctionCallArg, ExprId,
+    SemanticDiagnostic, TypeId,
+};
 
 #[cfg(test)]
 #[path = "constant_test.rs"]
@@ -18,7 +30,27 @@ mod test;
 #[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
 #[debug_db(dyn SemanticGroup + 'static)]
 pub struct Constant {
-    pub value: Expr,
+    /// The declared type of the constant.
+    pub ty: TypeId,
+    /// The value of the constant, evaluated at compile time.
+    pub value: ConstValue,
+}
+
+/// A value of a constant, evaluated at compile time.
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub enum ConstValue {
+    /// A numeric value of the given type.
+    Int(#[dont_rewrite] BigInt, TypeId),
+    /// A struct or a tuple of the given type, with the values of its members.
+    Struct(Vec<ConstValue>, TypeId),
+    /// An enum of the given variant, with the value of the variant.
+    Enum(ConcreteVariant, Box<ConstValue>),
+    /// An associated constant of an impl that is not concrete yet. The value is known once the
+    /// impl is substituted by a concrete impl.
+    ImplConstant(ImplConstantId),
+    /// The value could not be evaluated, and a diagnostic was reported.
+    Missing(#[dont_rewrite] DiagnosticAdded),
 }
 
 /// Information about a constant definition.
@@ -28,9 +60,19 @@ pub struct Constant {
 #[debug_db(dyn SemanticGroup + 'static)]
 pub struct ConstantData {
     diagnostics: Diagnostics<SemanticDiagnostic>,
-    constant: Constant,
+    constant: Maybe<Constant>,
     resolved_lookback: Arc<ResolvedLookback>,
 }
+impl ConstantData {
+    /// Returns Maybe::Err if a cycle is detected here.
+    // TODO: Remove this function when cycle validation is not required through a constant's value.
+    pub fn check_no_cycle(&self) -> Maybe<()> {
+        if let Err(diag_added) = &self.constant {
+            return Err(*diag_added);
+        }
+        Ok(())
+    }
+}
 
 /// Query implementation of [SemanticGroup::priv_constant_semantic_data].
 pub fn priv_constant_semantic_data(
@@ -55,33 +97,235 @@ pub fn priv_constant_semantic_data(
         &const_ast.type_clause(syntax_db).ty(syntax_db),
     );
 
+    let (value, resolved_lookback) = compute_constant_value(
+        db,
+        &mut diagnostics,
+        resolver,
+        const_type,
+        &const_ast.value(syntax_db),
+    );
+
+    let constant = Ok(Constant { ty: const_type, value });
+    let resolved_lookback = Arc::new(resolved_lookback);
+    Ok(ConstantData { diagnostics: diagnostics.build(), constant, resolved_lookback })
+}
+
+/// Computes the semantic model of the value expression of a constant of type `const_type`, and
+/// evaluates it at compile time.
+///
+/// Returns the value and the lookback of the given resolver.
+pub fn compute_constant_value(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    resolver: Resolver<'_>,
+    const_type: TypeId,
+    value_syntax: &ast::Expr,
+) -> (ConstValue, ResolvedLookback) {
     let mut ctx =
-        ComputationContext::new(db, &mut diagnostics, resolver, None, Environment::default());
-    let value = compute_expr_semantic(&mut ctx, &const_ast.value(syntax_db));
+        ComputationContext::new(db, diagnostics, resolver, None, None, Environment::default());
+    let value = compute_expr_semantic(&mut ctx, value_syntax);
     let value_type = value.ty();
+    let value_id = ctx.exprs.alloc(value);
 
     // Check that the type matches.
-    if !const_type.is_missing(db) && !value_type.is_missing(db) && value_type != const_type {
+    if !const_type.is_missing(db)
+        && !value_type.is_missing(db)
+        && ctx.resolver.inference.conform_ty(value_type, const_type).is_err()
+    {
+        let actual_ty = ctx.reduce_ty(value_type);
         ctx.diagnostics.report(
-            &const_ast.value(syntax_db),
-            crate::diagnostic::SemanticDiagnosticKind::WrongType {
-                expected_ty: const_type,
-                actual_ty: value_type,
-            },
+            value_syntax,
+            SemanticDiagnosticKind::WrongType { expected_ty: const_type, actual_ty },
         );
     }
 
-    // Check that the expression is a literal.
-    if !matches!(value, Expr::Literal(_)) {
-        ctx.diagnostics.report(
-            &const_ast.value(syntax_db),
-            crate::diagnostic::SemanticDiagnosticKind::OnlyLiteralConstants,
-        );
+    // Check fully resolved.
+    let value = if let Some((stable_ptr, inference_err)) = ctx.resolver.inference.finalize() {
+        ConstValue::Missing(inference_err.report(ctx.diagnostics, stable_ptr))
+    } else if let Err(diag_added) = infer_all(&mut ctx) {
+        ConstValue::Missing(diag_added)
+    } else {
+        ConstantEvaluateContext { db, exprs: &ctx.exprs, diagnostics: &mut *ctx.diagnostics }
+            .evaluate(value_id)
     };
+    (value, ctx.resolver.lookback)
+}
 
-    let constant = Constant { value };
-    let resolved_lookback = Arc::new(ctx.resolver.lookback);
-    Ok(ConstantData { diagnostics: diagnostics.build(), constant, resolved_lookback })
+/// Cycle handling for [SemanticGroup::priv_constant_semantic_data].
+pub fn priv_constant_semantic_data_cycle(
+    db: &dyn SemanticGroup,
+    _cycle: &[String],
+    const_id: &ConstantId,
+) -> Maybe<ConstantData> {
+    let module_file_id = const_id.module_file_id(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let module_constants = db.module_constants(module_file_id.0)?;
+    let const_ast = module_constants.get(const_id).to_maybe()?;
+    let syntax_db = db.upcast();
+    let constant =
+        Err(diagnostics.report(&const_ast.name(syntax_db), SemanticDiagnosticKind::ConstCycle));
+    Ok(ConstantData {
+        diagnostics: diagnostics.build(),
+        constant,
+        resolved_lookback: Arc::new(ResolvedLookback::default()),
+    })
+}
+
+/// Context for evaluating the semantic model of a constant expression at compile time.
+struct ConstantEvaluateContext<'a> {
+    db: &'a dyn SemanticGroup,
+    exprs: &'a Arena<Expr>,
+    diagnostics: &'a mut SemanticDiagnostics,
+}
+impl ConstantEvaluateContext<'_> {
+    /// Evaluates the given expression.
+    ///
+    /// Reports a diagnostic and returns [ConstValue::Missing] if the expression cannot be evaluated
+    /// at compile time.
+    fn evaluate(&mut self, expr_id: ExprId) -> ConstValue {
+        let expr = &self.exprs[expr_id];
+        match expr {
+            Expr::Literal(expr) => ConstValue::Int(expr.value.clone(), expr.ty),
+            Expr::Constant(expr) => match self.db.constant_semantic_data(expr.constant_id) {
+                Ok(constant) => constant.value,
+                Err(diag_added) => ConstValue::Missing(diag_added),
+            },
+            Expr::ImplConstant(expr) => match expr.impl_constant_id.concrete_value(self.db) {
+                Ok(Some(value)) => value,
+                Ok(None) => ConstValue::ImplConstant(expr.impl_constant_id),
+                Err(diag_added) => ConstValue::Missing(diag_added),
+            },
+            Expr::Block(ExprBlock { statements, tail: Some(tail), .. })
+                if statements.is_empty() =>
+            {
+                self.evaluate(*tail)
+            }
+            Expr::Tuple(expr) => ConstValue::Struct(
+                expr.items.iter().map(|expr_id| self.evaluate(*expr_id)).collect(),
+                expr.ty,
+            ),
+            Expr::StructCtor(expr) => {
+                let members = match self.db.concrete_struct_members(expr.concrete_struct_id) {
+                    Ok(members) => members,
+                    Err(diag_added) => return ConstValue::Missing(diag_added),
+                };
+                let member_exprs: UnorderedHashMap<MemberId, ExprId> =
+                    expr.members.iter().copied().collect();
+                ConstValue::Struct(
+                    members
+                        .values()
+                        .map(|member| match member_exprs.get(&member.id) {
+                            Some(expr_id) => self.evaluate(*expr_id),
+                            // A diagnostic was already reported for the missing member.
+                            None => ConstValue::Missing(skip_diagnostic()),
+                        })
+                        .collect(),
+                    expr.ty,
+                )
+            }
+            Expr::EnumVariantCtor(expr) => {
+                ConstValue::Enum(expr.variant.clone(), Box::new(self.evaluate(expr.value_expr)))
+            }
+            Expr::FunctionCall(expr) => self.evaluate_function_call(expr),
+            Expr::Missing(expr) => ConstValue::Missing(expr.diag_added),
+            _ => ConstValue::Missing(self.diagnostics.report_by_ptr(
+                expr.stable_ptr().untyped(),
+                SemanticDiagnosticKind::UnsupportedConstant,
+            )),
+        }
+    }
+
+    /// Evaluates a call to an arithmetic operator of the core library on numeric values.
+    fn evaluate_function_call(&mut self, expr: &ExprFunctionCall) -> ConstValue {
+        let db = self.db;
+        let unsupported = |diagnostics: &mut SemanticDiagnostics| {
+            ConstValue::Missing(diagnostics.report_by_ptr(
+                expr.stable_ptr.untyped(),
+                SemanticDiagnosticKind::UnsupportedConstant,
+            ))
+        };
+        let GenericFunctionId::Impl(impl_generic_function) =
+            expr.function.get_concrete(db).generic_function
+        else {
+            return unsupported(self.diagnostics);
+        };
+        let trait_id = impl_generic_function.function.trait_id(db.upcast());
+        let Some(trait_name) =
+            ["Add", "Sub", "Mul", "Div", "Rem", "BitAnd", "BitOr", "BitXor", "Neg"]
+                .into_iter()
+                .find(|trait_name| get_core_trait(db, (*trait_name).into()) == trait_id)
+        else {
+            return unsupported(self.diagnostics);
+        };
+
+        let mut args = vec![];
+        for arg in &expr.args {
+            let ExprFunctionCallArg::Value(expr_id) = arg else {
+                return unsupported(self.diagnostics);
+            };
+            match self.evaluate(*expr_id) {
+                ConstValue::Missing(diag_added) => return ConstValue::Missing(diag_added),
+                value => match self.as_int(&value) {
+                    Some(value) => args.push(value),
+                    None => return unsupported(self.diagnostics),
+                },
+            }
+        }
+
+        let value = match (trait_name, &args[..]) {
+            ("Add", [lhs, rhs]) => lhs + rhs,
+            ("Sub", [lhs, rhs]) => lhs - rhs,
+            ("Mul", [lhs, rhs]) => lhs * rhs,
+            ("Div" | "Rem", [_, rhs]) if rhs.is_zero() => {
+                return ConstValue::Missing(self.diagnostics.report_by_ptr(
+                    expr.stable_ptr.untyped(),
+                    SemanticDiagnosticKind::DivisionByZero,
+                ));
+            }
+            ("Div", [lhs, rhs]) => lhs / rhs,
+            ("Rem", [lhs, rhs]) => lhs % rhs,
+            ("BitAnd", [lhs, rhs]) => lhs & rhs,
+            ("BitOr", [lhs, rhs]) => lhs | rhs,
+            ("BitXor", [lhs, rhs]) => lhs ^ rhs,
+            ("Neg", [value]) => -value,
+            _ => return unsupported(self.diagnostics),
+        };
+        let value = if expr.ty == core_felt252_ty(db) {
+            let prime = felt252_prime();
+            let value = value % &prime;
+            if value.is_negative() { value + prime } else { value }
+        } else if let Err(err) = validate_literal(db, expr.ty, value.clone()) {
+            return ConstValue::Missing(
+                self.diagnostics.report_by_ptr(expr.stable_ptr.untyped(), err),
+            );
+        } else {
+            value
+        };
+        ConstValue::Int(value, expr.ty)
+    }
+
+    /// Returns the numeric value of the given constant value, if it is numeric.
+    /// `u256` values constructed from their members are also considered numeric.
+    fn as_int(&self, value: &ConstValue) -> Option<BigInt> {
+        match value {
+            ConstValue::Int(value, _) => Some(value.clone()),
+            ConstValue::Struct(members, ty)
+                if *ty == get_core_ty_by_name(self.db, "u256".into(), vec![]) =>
+            {
+                let [ConstValue::Int(low, _), ConstValue::Int(high, _)] = &members[..] else {
+                    return None;
+                };
+                Some((high << 128) + low)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Returns the prime of the felt252 field.
+fn felt252_prime() -> BigInt {
+    BigInt::from_str_radix("800000000000011000000000000000000000000000000000000000000000001", 16)
+        .unwrap()
 }
 
 /// Query implementation of [SemanticGroup::constant_semantic_diagnostics].
@@ -94,7 +338,7 @@ pub fn constant_semantic_diagnostics(
 
 /// Query implementation of [SemanticGroup::constant_semantic_data].
 pub fn constant_semantic_data(db: &dyn SemanticGroup, const_id: ConstantId) -> Maybe<Constant> {
-    Ok(db.priv_constant_semantic_data(const_id)?.constant)
+    db.priv_constant_semantic_data(const_id)?.constant
 }
 
 /// Query implementation of [crate::db::SemanticGroup::constant_resolved_lookback].
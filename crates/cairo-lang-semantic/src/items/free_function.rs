@@ -1,7 +1,9 @@
 use std::collections::HashSet;
 use std::sync::Arc;
 
-use cairo_lang_defs::ids::{FreeFunctionId, FunctionTitleId, LanguageElementId};
+use cairo_lang_defs::ids::{
+    FreeFunctionId, FunctionTitleId, FunctionWithBodyId, LanguageElementId,
+};
 use cairo_lang_diagnostics::{Diagnostics, Maybe, ToMaybe};
 use cairo_lang_utils::try_extract_matches;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
@@ -177,6 +179,7 @@ pub fn priv_free_function_body_data(
         db,
         &mut diagnostics,
         resolver,
+        Some(FunctionWithBodyId::Free(free_function_id)),
         Some(&declaration.signature),
         environment,
     );
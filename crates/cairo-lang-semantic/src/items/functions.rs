@@ -21,6 +21,7 @@ use crate::corelib::unit_ty;
 use crate::db::SemanticGroup;
 use crate::diagnostic::{SemanticDiagnosticKind, SemanticDiagnostics};
 use crate::expr::compute::Environment;
+use crate::items::imp::ConcreteImplLongId;
 use crate::resolve_path::{ResolvedLookback, Resolver};
 use crate::substitution::{GenericSubstitution, SemanticRewriter, SubstitutionRewriter};
 use crate::types::resolve_type;
@@ -39,7 +40,10 @@ impl ImplGenericFunctionId {
             ImplId::Concrete(concrete_impl_id) => {
                 concrete_impl_id.get_impl_function(db, self.function)
             }
-            ImplId::GenericParameter(_) | ImplId::ImplVar(_) => Ok(None),
+            ImplId::GenericParameter(_)
+            | ImplId::ImplVar(_)
+            | ImplId::Closure(_)
+            | ImplId::SelfImpl(_) => Ok(None),
         }
     }
     /// Converts to ImplGenericFunctionWithBodyId if this is a function of a concrete impl.
@@ -112,9 +116,12 @@ impl GenericFunctionId {
             GenericFunctionId::Extern(id) => db.extern_function_signature(id),
             GenericFunctionId::Impl(id) => {
                 let concrete_trait_id = db.impl_concrete_trait(id.impl_id)?;
-                let id = ConcreteTraitGenericFunctionId::new(db, concrete_trait_id, id.function);
-
-                db.concrete_trait_function_signature(id)
+                let concrete_id =
+                    ConcreteTraitGenericFunctionId::new(db, concrete_trait_id, id.function);
+                let signature = db.concrete_trait_function_signature(concrete_id)?;
+                // Associated items of the trait refer to those of the impl.
+                let substitution = GenericSubstitution::from_impl(id.impl_id);
+                SubstitutionRewriter { db, substitution: &substitution }.rewrite(signature)
             }
         }
     }
@@ -124,8 +131,11 @@ impl GenericFunctionId {
             GenericFunctionId::Extern(id) => db.extern_function_declaration_generic_params(id),
             GenericFunctionId::Impl(id) => {
                 let concrete_trait_id = db.impl_concrete_trait(id.impl_id)?;
-                let id = ConcreteTraitGenericFunctionId::new(db, concrete_trait_id, id.function);
-                db.concrete_trait_function_generic_params(id)
+                let concrete_id =
+                    ConcreteTraitGenericFunctionId::new(db, concrete_trait_id, id.function);
+                let generic_params = db.concrete_trait_function_generic_params(concrete_id)?;
+                let substitution = GenericSubstitution::from_impl(id.impl_id);
+                SubstitutionRewriter { db, substitution: &substitution }.rewrite(generic_params)
             }
         }
     }
@@ -287,6 +297,42 @@ impl ConcreteFunctionWithBody {
             }
         })
     }
+    /// Returns the concrete function of a generic function with a body, where the generic
+    /// parameters of the function (and of its impl, if any) are used as the generic arguments.
+    /// Fails if the function has const generic parameters.
+    pub fn from_generic(db: &dyn SemanticGroup, function_id: FunctionWithBodyId) -> Maybe<Self> {
+        Ok(match function_id {
+            FunctionWithBodyId::Free(free_function_id) => ConcreteFunctionWithBody {
+                generic_function: GenericFunctionWithBodyId::Free(free_function_id),
+                generic_args: generic_params_to_args(
+                    db,
+                    &db.free_function_generic_params(free_function_id)?,
+                )?,
+            },
+            FunctionWithBodyId::Impl(impl_function_id) => {
+                let impl_def_id = impl_function_id.impl_def_id(db.upcast());
+                let concrete_impl_id = db.intern_concrete_impl(ConcreteImplLongId {
+                    impl_def_id,
+                    generic_args: generic_params_to_args(
+                        db,
+                        &db.impl_def_generic_params(impl_def_id)?,
+                    )?,
+                });
+                ConcreteFunctionWithBody {
+                    generic_function: GenericFunctionWithBodyId::Impl(
+                        ImplGenericFunctionWithBodyId {
+                            concrete_impl_id,
+                            function: impl_function_id,
+                        },
+                    ),
+                    generic_args: generic_params_to_args(
+                        db,
+                        &db.impl_function_generic_params(impl_function_id)?,
+                    )?,
+                }
+            }
+        })
+    }
     pub fn from_no_generics_free(
         db: &dyn SemanticGroup,
         free_function_id: FreeFunctionId,
@@ -335,6 +381,12 @@ impl ConcreteFunctionWithBodyId {
     pub fn substitution(&self, db: &dyn SemanticGroup) -> Maybe<GenericSubstitution> {
         self.get(db).substitution(db)
     }
+    pub fn from_generic(db: &dyn SemanticGroup, function_id: FunctionWithBodyId) -> Maybe<Self> {
+        Ok(db.intern_concrete_function_with_body(ConcreteFunctionWithBody::from_generic(
+            db,
+            function_id,
+        )?))
+    }
     pub fn from_no_generics_free(
         db: &dyn SemanticGroup,
         free_function_id: FreeFunctionId,
@@ -357,6 +409,26 @@ impl ConcreteFunctionWithBodyId {
     }
 }
 
+/// Converts generic parameters to the generic arguments referring to them. Fails on const
+/// generic parameters, which have no matching argument.
+pub fn generic_params_to_args(
+    db: &dyn SemanticGroup,
+    generic_params: &[GenericParam],
+) -> Maybe<Vec<semantic::GenericArgumentId>> {
+    generic_params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Type(param) => Ok(semantic::GenericArgumentId::Type(
+                db.intern_type(semantic::TypeLongId::GenericParameter(param.id)),
+            )),
+            GenericParam::Impl(param) => {
+                Ok(semantic::GenericArgumentId::Impl(ImplId::GenericParameter(param.id)))
+            }
+            GenericParam::Const(_) => Err(skip_diagnostic()),
+        })
+        .collect()
+}
+
 impl UnstableSalsaId for ConcreteFunctionWithBodyId {
     fn get_internal_id(&self) -> &salsa::InternId {
         &self.0
@@ -204,15 +204,29 @@ fn semantic_from_generic_param_ast(
         ast::GenericParam::Type(_) => GenericParam::Type(GenericParamType { id }),
         ast::GenericParam::Const(_) => GenericParam::Const(GenericParamConst { id }),
         ast::GenericParam::Impl(syntax) => {
-            let path_syntax = syntax.trait_path(db.upcast());
-            let concrete_trait = resolver
-                .resolve_concrete_path(diagnostics, &path_syntax, NotFoundItemType::Trait)
-                .and_then(|resolved_item| {
-                    try_extract_matches!(resolved_item, ResolvedConcreteItem::Trait).ok_or_else(
-                        || diagnostics.report(&path_syntax, SemanticDiagnosticKind::UnknownTrait),
-                    )
-                });
+            let concrete_trait =
+                resolve_trait_path(resolver, diagnostics, &syntax.trait_path(db.upcast()));
+            GenericParam::Impl(GenericParamImpl { id, concrete_trait })
+        }
+        ast::GenericParam::ImplAnonymous(syntax) => {
+            let concrete_trait =
+                resolve_trait_path(resolver, diagnostics, &syntax.trait_path(db.upcast()));
             GenericParam::Impl(GenericParamImpl { id, concrete_trait })
         }
     }
 }
+
+/// Resolves the concrete trait of an impl generic parameter.
+fn resolve_trait_path(
+    resolver: &mut Resolver<'_>,
+    diagnostics: &mut SemanticDiagnostics,
+    path_syntax: &ast::ExprPath,
+) -> Maybe<ConcreteTraitId> {
+    resolver.resolve_concrete_path(diagnostics, path_syntax, NotFoundItemType::Trait).and_then(
+        |resolved_item| {
+            try_extract_matches!(resolved_item, ResolvedConcreteItem::Trait).ok_or_else(|| {
+                diagnostics.report(path_syntax, SemanticDiagnosticKind::UnknownTrait)
+            })
+        },
+    )
+}
@@ -4,8 +4,10 @@ use std::vec;
 
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::ids::{
-    FunctionTitleId, GenericParamId, ImplDefId, ImplFunctionId, ImplFunctionLongId,
-    LanguageElementId, ModuleId, TopLevelLanguageElementId, TraitFunctionId, TraitId,
+    FunctionTitleId, FunctionWithBodyId, GenericParamId, ImplConstantDefId, ImplConstantDefLongId,
+    ImplDefId, ImplFunctionId, ImplFunctionLongId, ImplTypeDefId, ImplTypeDefLongId,
+    LanguageElementId, ModuleId, TopLevelLanguageElementId, TraitConstantId, TraitFunctionId,
+    TraitId, TraitTypeId,
 };
 use cairo_lang_diagnostics::{
     skip_diagnostic, Diagnostics, DiagnosticsBuilder, Maybe, ToMaybe, ToOption,
@@ -15,7 +17,7 @@ use cairo_lang_syntax as syntax;
 use cairo_lang_syntax::node::ast::{self, Item, MaybeImplBody, OptionReturnTypeClause};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
-use cairo_lang_syntax::node::TypedSyntaxNode;
+use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
@@ -24,10 +26,12 @@ use itertools::{chain, izip, Itertools};
 use smol_str::SmolStr;
 
 use super::attribute::{ast_attributes_to_semantic, Attribute};
+use super::constant::{compute_constant_value, ConstValue, Constant};
 use super::enm::SemanticEnumEx;
 use super::function_with_body::{get_inline_config, FunctionBody, FunctionBodyData};
 use super::functions::{
-    forbid_inline_always_with_impl_generic_param, FunctionDeclarationData, InlineConfiguration,
+    forbid_inline_always_with_impl_generic_param, generic_params_to_args, FunctionDeclarationData,
+    InlineConfiguration,
 };
 use super::generics::{semantic_generic_params, GenericArgumentHead};
 use super::structure::SemanticStructEx;
@@ -41,6 +45,7 @@ use crate::expr::inference::{ImplVar, Inference, InferenceResult};
 use crate::items::us::SemanticUseEx;
 use crate::resolve_path::{ResolvedConcreteItem, ResolvedGenericItem, ResolvedLookback, Resolver};
 use crate::substitution::{GenericSubstitution, SemanticRewriter, SubstitutionRewriter};
+use crate::types::resolve_type;
 use crate::{
     semantic, semantic_object_for_id, ConcreteTraitId, ConcreteTraitLongId, Expr, FunctionId,
     GenericArgumentId, GenericParam, Mutability, SemanticDiagnostic, TypeId, TypeLongId,
@@ -96,6 +101,21 @@ impl ConcreteImplId {
     pub fn name(&self, db: &dyn SemanticGroup) -> SmolStr {
         self.impl_def_id(db).name(db.upcast())
     }
+    /// Returns the impl referred to by `Self` inside an impl definition, i.e. the impl concretized
+    /// by its own generic params.
+    pub fn from_self(db: &dyn SemanticGroup, impl_def_id: ImplDefId) -> Maybe<Self> {
+        let generic_args = generic_params_to_args(db, &db.impl_def_generic_params(impl_def_id)?)?;
+        Ok(db.intern_concrete_impl(ConcreteImplLongId { impl_def_id, generic_args }))
+    }
+    /// Returns the substitution of the generic params of the impl definition by the generic args
+    /// of this concrete impl.
+    pub fn substitution(&self, db: &dyn SemanticGroup) -> Maybe<GenericSubstitution> {
+        let long_impl = db.lookup_intern_concrete_impl(*self);
+        Ok(GenericSubstitution::new(
+            &db.impl_def_generic_params(long_impl.impl_def_id)?,
+            &long_impl.generic_args,
+        ))
+    }
 }
 
 /// Represents a "callee" impl that can be referred to in the code.
@@ -105,20 +125,34 @@ pub enum ImplId {
     Concrete(ConcreteImplId),
     GenericParameter(GenericParamId),
     ImplVar(ImplVar),
+    /// An impl generated by the compiler for a closure type, of the given concrete trait.
+    Closure(ConcreteTraitId),
+    /// The impl of the given concrete trait, as referred to from within the trait itself (e.g.
+    /// by `Self::Key`). Replaced by the actual impl when the trait items are used through it.
+    SelfImpl(ConcreteTraitId),
 }
 impl ImplId {
     /// Returns the [ImplHead] of an impl if available.
     pub fn head(&self, db: &dyn SemanticGroup) -> Option<ImplHead> {
         Some(match self {
             ImplId::Concrete(concrete) => ImplHead::Concrete(concrete.impl_def_id(db)),
-            ImplId::GenericParameter(_) | ImplId::ImplVar(_) => return None,
+            ImplId::GenericParameter(_)
+            | ImplId::ImplVar(_)
+            | ImplId::Closure(_)
+            | ImplId::SelfImpl(_) => return None,
         })
     }
     pub fn name(&self, db: &dyn SemanticGroup) -> SmolStr {
         match self {
             ImplId::Concrete(concrete_impl) => concrete_impl.name(db),
-            ImplId::GenericParameter(generic_param_impl) => generic_param_impl.name(db.upcast()),
+            ImplId::GenericParameter(generic_param_impl) => {
+                generic_param_impl.debug_name(db.upcast())
+            }
             ImplId::ImplVar(var) => format!("{var:?}").into(),
+            ImplId::Closure(concrete_trait_id) => {
+                format!("{}ForClosure", concrete_trait_id.trait_id(db).name(db.upcast())).into()
+            }
+            ImplId::SelfImpl(_) => "Self".into(),
         }
     }
 }
@@ -132,6 +166,10 @@ impl DebugWithDb<dyn SemanticGroup> for ImplId {
             ImplId::Concrete(concrete_impl_id) => write!(f, "{:?}", concrete_impl_id.debug(db)),
             ImplId::GenericParameter(param) => write!(f, "{:?}", param.debug(db)),
             ImplId::ImplVar(var) => write!(f, "?{}", var.id),
+            ImplId::Closure(concrete_trait_id) => {
+                write!(f, "{}ForClosure", concrete_trait_id.trait_id(db).name(db.upcast()))
+            }
+            ImplId::SelfImpl(_) => write!(f, "Self"),
         }
     }
 }
@@ -144,6 +182,90 @@ pub enum ImplHead {
     Concrete(ImplDefId),
 }
 
+/// An associated type of a trait, as defined by an impl of the trait.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
+pub struct ImplTypeId {
+    pub impl_id: ImplId,
+    pub trait_type_id: TraitTypeId,
+}
+impl ImplTypeId {
+    /// Returns the type defined by the impl for this associated type, if the impl is concrete.
+    pub fn concrete_type(&self, db: &dyn SemanticGroup) -> Maybe<Option<TypeId>> {
+        let ImplId::Concrete(concrete_impl_id) = self.impl_id else {
+            return Ok(None);
+        };
+        let Some(impl_type_def_id) =
+            db.impl_type_def_by_trait_type(concrete_impl_id.impl_def_id(db), self.trait_type_id)?
+        else {
+            // Trait type not found in impl.
+            return Err(skip_diagnostic());
+        };
+        // Call the private query directly, to recover from cycles.
+        let ty = db.priv_impl_type_def_data(impl_type_def_id)?.resolved_type?;
+        let substitution = concrete_impl_id.substitution(db)?;
+        SubstitutionRewriter { db, substitution: &substitution }.rewrite(ty).map(Some)
+    }
+    pub fn format(&self, db: &dyn SemanticGroup) -> SmolStr {
+        format!("{}::{}", self.impl_id.name(db), self.trait_type_id.name(db.upcast())).into()
+    }
+}
+impl DebugWithDb<dyn SemanticGroup> for ImplTypeId {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        db: &(dyn SemanticGroup + 'static),
+    ) -> std::fmt::Result {
+        write!(f, "{}", self.format(db))
+    }
+}
+
+/// An associated constant of a trait, as defined by an impl of the trait.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
+pub struct ImplConstantId {
+    pub impl_id: ImplId,
+    pub trait_constant_id: TraitConstantId,
+}
+impl ImplConstantId {
+    /// Returns the value defined by the impl for this associated constant, if the impl is
+    /// concrete.
+    pub fn concrete_value(&self, db: &dyn SemanticGroup) -> Maybe<Option<ConstValue>> {
+        let ImplId::Concrete(concrete_impl_id) = self.impl_id else {
+            return Ok(None);
+        };
+        let Some(impl_constant_def_id) = db.impl_constant_def_by_trait_constant(
+            concrete_impl_id.impl_def_id(db),
+            self.trait_constant_id,
+        )?
+        else {
+            // Trait constant not found in impl.
+            return Err(skip_diagnostic());
+        };
+        // Call the private query directly, to recover from cycles.
+        let value = db.priv_impl_constant_def_data(impl_constant_def_id)?.constant?.value;
+        let substitution = concrete_impl_id.substitution(db)?;
+        SubstitutionRewriter { db, substitution: &substitution }.rewrite(value).map(Some)
+    }
+    /// Returns the type of the constant.
+    pub fn ty(&self, db: &dyn SemanticGroup) -> Maybe<TypeId> {
+        db.concrete_trait_constant_type(
+            db.impl_concrete_trait(self.impl_id)?,
+            self.trait_constant_id,
+        )
+    }
+    pub fn format(&self, db: &dyn SemanticGroup) -> SmolStr {
+        format!("{}::{}", self.impl_id.name(db), self.trait_constant_id.name(db.upcast())).into()
+    }
+}
+impl DebugWithDb<dyn SemanticGroup> for ImplConstantId {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        db: &(dyn SemanticGroup + 'static),
+    ) -> std::fmt::Result {
+        write!(f, "{}", self.format(db))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
 #[debug_db(dyn SemanticGroup + 'static)]
 pub struct ImplDeclarationData {
@@ -216,6 +338,9 @@ pub fn impl_concrete_trait(db: &dyn SemanticGroup, impl_id: ImplId) -> Maybe<Con
             param_impl.concrete_trait
         }
         ImplId::ImplVar(var) => Ok(var.concrete_trait_id),
+        ImplId::Closure(concrete_trait_id) | ImplId::SelfImpl(concrete_trait_id) => {
+            Ok(concrete_trait_id)
+        }
     }
 }
 
@@ -293,6 +418,8 @@ pub fn priv_impl_declaration_data_inner(
 pub struct ImplDefinitionData {
     diagnostics: Diagnostics<SemanticDiagnostic>,
     function_asts: OrderedHashMap<ImplFunctionId, ast::FunctionWithBody>,
+    type_asts: OrderedHashMap<ImplTypeDefId, ast::ItemTypeAlias>,
+    constant_asts: OrderedHashMap<ImplConstantDefId, ast::ItemConstant>,
 }
 
 /// Query implementation of [crate::db::SemanticGroup::impl_semantic_definition_diagnostics].
@@ -311,6 +438,12 @@ pub fn impl_semantic_definition_diagnostics(
         diagnostics.extend(db.impl_function_declaration_diagnostics(*impl_function_id));
         diagnostics.extend(db.impl_function_body_diagnostics(*impl_function_id));
     }
+    for impl_type_def_id in data.type_asts.keys() {
+        diagnostics.extend(db.impl_type_def_diagnostics(*impl_type_def_id));
+    }
+    for impl_constant_def_id in data.constant_asts.keys() {
+        diagnostics.extend(db.impl_constant_def_diagnostics(*impl_constant_def_id));
+    }
 
     diagnostics.build()
 }
@@ -363,16 +496,21 @@ pub fn priv_impl_definition_data(
     // TODO(yuval): verify that all functions of `concrete_trait` appear in this impl.
 
     let mut function_asts = OrderedHashMap::default();
+    let mut type_asts = OrderedHashMap::default();
+    let mut constant_asts = OrderedHashMap::default();
     let mut impl_item_names = OrderedHashSet::default();
 
     if let MaybeImplBody::Some(body) = impl_ast.body(syntax_db) {
         for item in body.items(syntax_db).elements(syntax_db) {
             match item {
-                Item::Constant(constant) => report_invalid_impl_item(
-                    syntax_db,
-                    &mut diagnostics,
-                    constant.const_kw(syntax_db),
-                ),
+                Item::Constant(constant) => {
+                    let impl_constant_def_id = db.intern_impl_constant_def(ImplConstantDefLongId(
+                        module_file_id,
+                        constant.stable_ptr(),
+                    ));
+                    constant_asts.insert(impl_constant_def_id, constant);
+                    impl_item_names.insert(impl_constant_def_id.name(defs_db));
+                }
                 Item::Module(module) => report_invalid_impl_item(
                     syntax_db,
                     &mut diagnostics,
@@ -409,7 +547,10 @@ pub fn priv_impl_definition_data(
                     report_invalid_impl_item(syntax_db, &mut diagnostics, enm.enum_kw(syntax_db))
                 }
                 Item::TypeAlias(ty) => {
-                    report_invalid_impl_item(syntax_db, &mut diagnostics, ty.type_kw(syntax_db))
+                    let impl_type_def_id =
+                        db.intern_impl_type_def(ImplTypeDefLongId(module_file_id, ty.stable_ptr()));
+                    type_asts.insert(impl_type_def_id, ty);
+                    impl_item_names.insert(impl_type_def_id.name(defs_db));
                 }
                 Item::FreeFunction(func) => {
                     let impl_function_id = db.intern_impl_function(ImplFunctionLongId(
@@ -428,10 +569,13 @@ pub fn priv_impl_definition_data(
     // to verify here that all items in `concrete_trait` appear in this impl.
     // TODO(yuval): Once default implementation of trait functions is supported, filter such
     // functions out.
-    let trait_item_names = db
-        .trait_functions(db.lookup_intern_concrete_trait(concrete_trait).trait_id)?
-        .into_keys()
-        .collect::<OrderedHashSet<_>>();
+    let trait_id = db.lookup_intern_concrete_trait(concrete_trait).trait_id;
+    let trait_item_names = chain!(
+        db.trait_functions(trait_id)?.into_keys(),
+        db.trait_types(trait_id)?.into_keys(),
+        db.trait_constants(trait_id)?.into_keys()
+    )
+    .collect::<OrderedHashSet<_>>();
     let missing_items_in_impl =
         trait_item_names.difference(&impl_item_names).cloned().collect::<Vec<_>>();
     if !missing_items_in_impl.is_empty() {
@@ -443,7 +587,12 @@ pub fn priv_impl_definition_data(
         );
     }
 
-    Ok(ImplDefinitionData { diagnostics: diagnostics.build(), function_asts })
+    Ok(ImplDefinitionData {
+        diagnostics: diagnostics.build(),
+        function_asts,
+        type_asts,
+        constant_asts,
+    })
 }
 
 /// Query implementation of [crate::db::SemanticGroup::impl_functions].
@@ -478,6 +627,38 @@ pub fn impl_function_by_trait_function(
     Ok(None)
 }
 
+/// Query implementation of [crate::db::SemanticGroup::impl_type_def_by_trait_type].
+pub fn impl_type_def_by_trait_type(
+    db: &dyn SemanticGroup,
+    impl_def_id: ImplDefId,
+    trait_type_id: TraitTypeId,
+) -> Maybe<Option<ImplTypeDefId>> {
+    let defs_db = db.upcast();
+    let name = trait_type_id.name(defs_db);
+    for impl_type_def_id in db.priv_impl_definition_data(impl_def_id)?.type_asts.keys() {
+        if impl_type_def_id.name(defs_db) == name {
+            return Ok(Some(*impl_type_def_id));
+        }
+    }
+    Ok(None)
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_constant_def_by_trait_constant].
+pub fn impl_constant_def_by_trait_constant(
+    db: &dyn SemanticGroup,
+    impl_def_id: ImplDefId,
+    trait_constant_id: TraitConstantId,
+) -> Maybe<Option<ImplConstantDefId>> {
+    let defs_db = db.upcast();
+    let name = trait_constant_id.name(defs_db);
+    for impl_constant_def_id in db.priv_impl_definition_data(impl_def_id)?.constant_asts.keys() {
+        if impl_constant_def_id.name(defs_db) == name {
+            return Ok(Some(*impl_constant_def_id));
+        }
+    }
+    Ok(None)
+}
+
 /// Handle special cases such as Copy and Drop checking.
 fn check_special_impls(
     db: &dyn SemanticGroup,
@@ -543,8 +724,9 @@ fn get_inner_types(db: &dyn SemanticGroup, ty: TypeId) -> Maybe<Vec<TypeId>> {
             }
         }
         TypeLongId::Tuple(tys) => tys,
+        TypeLongId::Closure(closure) => closure.captured_types,
         TypeLongId::Snapshot(_) => vec![],
-        TypeLongId::GenericParameter(_) => {
+        TypeLongId::GenericParameter(_) | TypeLongId::ImplType(_) => {
             return Err(skip_diagnostic());
         }
         TypeLongId::Var(_) => panic!("Types should be fully resolved at this point."),
@@ -681,7 +863,7 @@ impl DebugWithDb<dyn SemanticGroup> for UninferredImpl {
         match self {
             UninferredImpl::Def(impl_def) => write!(f, "{:?}", impl_def.full_path(db.upcast())),
             UninferredImpl::GenericParam(param) => {
-                write!(f, "generic param {}", param.name(db.upcast()))
+                write!(f, "generic param {}", param.debug_name(db.upcast()))
             }
         }
     }
@@ -913,6 +1095,9 @@ pub fn priv_impl_function_declaration_data(
     for generic_param in impl_def_generic_params {
         resolver.add_generic_param(generic_param);
     }
+    if let Ok(self_impl) = ConcreteImplId::from_self(db, impl_def_id) {
+        resolver.set_self_impl(ImplId::Concrete(self_impl));
+    }
     let function_generic_params = semantic_generic_params(
         db,
         &mut diagnostics,
@@ -1007,7 +1192,7 @@ fn validate_impl_function_signature(
         );
         return Ok(trait_function_id);
     }
-    let substitution = GenericSubstitution::new(
+    let mut substitution = GenericSubstitution::new(
         &func_generics,
         &impl_func_generics
             .iter()
@@ -1016,6 +1201,8 @@ fn validate_impl_function_signature(
             })
             .collect_vec(),
     );
+    // Associated items of the trait refer to those of this impl.
+    substitution.self_impl = Some(ImplId::Concrete(ConcreteImplId::from_self(db, impl_def_id)?));
     let concrete_trait_signature = SubstitutionRewriter { db, substitution: &substitution }
         .rewrite(concrete_trait_signature)?;
 
@@ -1153,6 +1340,9 @@ pub fn priv_impl_function_body_data(
     for generic_param in declaration.function_declaration_data.generic_params {
         resolver.add_generic_param(generic_param);
     }
+    if let Ok(self_impl) = ConcreteImplId::from_self(db, impl_def_id) {
+        resolver.set_self_impl(ImplId::Concrete(self_impl));
+    }
     let environment = declaration.function_declaration_data.environment;
 
     // Compute body semantic expr.
@@ -1160,6 +1350,7 @@ pub fn priv_impl_function_body_data(
         db,
         &mut diagnostics,
         resolver,
+        Some(FunctionWithBodyId::Impl(impl_function_id)),
         Some(&declaration.function_declaration_data.signature),
         environment,
     );
@@ -1189,3 +1380,227 @@ pub fn priv_impl_function_body_data(
         }),
     })
 }
+
+// === Impl Type Def ===
+
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub struct ImplTypeDefData {
+    diagnostics: Diagnostics<SemanticDiagnostic>,
+    resolved_type: Maybe<TypeId>,
+    resolved_lookback: Arc<ResolvedLookback>,
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_type_def_diagnostics].
+pub fn impl_type_def_diagnostics(
+    db: &dyn SemanticGroup,
+    impl_type_def_id: ImplTypeDefId,
+) -> Diagnostics<SemanticDiagnostic> {
+    db.priv_impl_type_def_data(impl_type_def_id).map(|data| data.diagnostics).unwrap_or_default()
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_type_def_resolved_type].
+pub fn impl_type_def_resolved_type(
+    db: &dyn SemanticGroup,
+    impl_type_def_id: ImplTypeDefId,
+) -> Maybe<TypeId> {
+    db.priv_impl_type_def_data(impl_type_def_id)?.resolved_type
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_type_def_resolved_lookback].
+pub fn impl_type_def_resolved_lookback(
+    db: &dyn SemanticGroup,
+    impl_type_def_id: ImplTypeDefId,
+) -> Maybe<Arc<ResolvedLookback>> {
+    Ok(db.priv_impl_type_def_data(impl_type_def_id)?.resolved_lookback)
+}
+
+/// Query implementation of [crate::db::SemanticGroup::priv_impl_type_def_data].
+pub fn priv_impl_type_def_data(
+    db: &dyn SemanticGroup,
+    impl_type_def_id: ImplTypeDefId,
+) -> Maybe<ImplTypeDefData> {
+    let module_file_id = impl_type_def_id.module_file_id(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let impl_def_id = impl_type_def_id.impl_def_id(db.upcast());
+    let data = db.priv_impl_definition_data(impl_def_id)?;
+    let type_syntax = &data.type_asts[impl_type_def_id];
+    let syntax_db = db.upcast();
+
+    let generic_params_syntax = type_syntax.generic_params(syntax_db);
+    if !matches!(generic_params_syntax, ast::OptionWrappedGenericParamList::Empty(_)) {
+        diagnostics.report(&generic_params_syntax, Unsupported);
+    }
+    // Ignore the result, as the type is resolved regardless.
+    validate_impl_item_in_trait(
+        db,
+        &mut diagnostics,
+        impl_def_id,
+        type_syntax.name(syntax_db),
+        "type",
+        |trait_id, name| db.trait_type_by_name(trait_id, name),
+    )
+    .ok();
+
+    let mut resolver = Resolver::new_with_inference(db, module_file_id);
+    for generic_param in db.impl_def_generic_params(impl_def_id)? {
+        resolver.add_generic_param(generic_param);
+    }
+    let ty = resolve_type(db, &mut diagnostics, &mut resolver, &type_syntax.ty(syntax_db));
+    let resolved_lookback = Arc::new(resolver.lookback);
+    Ok(ImplTypeDefData {
+        diagnostics: diagnostics.build(),
+        resolved_type: Ok(ty),
+        resolved_lookback,
+    })
+}
+
+/// Cycle handling for [crate::db::SemanticGroup::priv_impl_type_def_data].
+pub fn priv_impl_type_def_data_cycle(
+    db: &dyn SemanticGroup,
+    _cycle: &[String],
+    impl_type_def_id: &ImplTypeDefId,
+) -> Maybe<ImplTypeDefData> {
+    let module_file_id = impl_type_def_id.module_file_id(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let data = db.priv_impl_definition_data(impl_type_def_id.impl_def_id(db.upcast()))?;
+    let type_syntax = &data.type_asts[*impl_type_def_id];
+    let resolved_type = Err(diagnostics.report(&type_syntax.name(db.upcast()), TypeAliasCycle));
+    Ok(ImplTypeDefData {
+        diagnostics: diagnostics.build(),
+        resolved_type,
+        resolved_lookback: Arc::new(ResolvedLookback::default()),
+    })
+}
+
+// === Impl Constant Def ===
+
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub struct ImplConstantDefData {
+    diagnostics: Diagnostics<SemanticDiagnostic>,
+    constant: Maybe<Constant>,
+    resolved_lookback: Arc<ResolvedLookback>,
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_constant_def_diagnostics].
+pub fn impl_constant_def_diagnostics(
+    db: &dyn SemanticGroup,
+    impl_constant_def_id: ImplConstantDefId,
+) -> Diagnostics<SemanticDiagnostic> {
+    db.priv_impl_constant_def_data(impl_constant_def_id)
+        .map(|data| data.diagnostics)
+        .unwrap_or_default()
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_constant_def_value].
+pub fn impl_constant_def_value(
+    db: &dyn SemanticGroup,
+    impl_constant_def_id: ImplConstantDefId,
+) -> Maybe<ConstValue> {
+    Ok(db.priv_impl_constant_def_data(impl_constant_def_id)?.constant?.value)
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_constant_def_resolved_lookback].
+pub fn impl_constant_def_resolved_lookback(
+    db: &dyn SemanticGroup,
+    impl_constant_def_id: ImplConstantDefId,
+) -> Maybe<Arc<ResolvedLookback>> {
+    Ok(db.priv_impl_constant_def_data(impl_constant_def_id)?.resolved_lookback)
+}
+
+/// Query implementation of [crate::db::SemanticGroup::priv_impl_constant_def_data].
+pub fn priv_impl_constant_def_data(
+    db: &dyn SemanticGroup,
+    impl_constant_def_id: ImplConstantDefId,
+) -> Maybe<ImplConstantDefData> {
+    let module_file_id = impl_constant_def_id.module_file_id(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let impl_def_id = impl_constant_def_id.impl_def_id(db.upcast());
+    let data = db.priv_impl_definition_data(impl_def_id)?;
+    let constant_syntax = &data.constant_asts[impl_constant_def_id];
+    let syntax_db = db.upcast();
+
+    let trait_constant_id = validate_impl_item_in_trait(
+        db,
+        &mut diagnostics,
+        impl_def_id,
+        constant_syntax.name(syntax_db),
+        "constant",
+        |trait_id, name| db.trait_constant_by_name(trait_id, name),
+    );
+
+    let mut resolver = Resolver::new_without_inference(db, module_file_id);
+    for generic_param in db.impl_def_generic_params(impl_def_id)? {
+        resolver.add_generic_param(generic_param);
+    }
+    let type_syntax = constant_syntax.type_clause(syntax_db).ty(syntax_db);
+    let const_type = resolve_type(db, &mut diagnostics, &mut resolver, &type_syntax);
+    if let Ok(trait_constant_id) = trait_constant_id {
+        let expected_ty = db.concrete_trait_constant_type(
+            db.priv_impl_declaration_data(impl_def_id)?.concrete_trait?,
+            trait_constant_id,
+        )?;
+        if !expected_ty.is_missing(db) && !const_type.is_missing(db) && expected_ty != const_type {
+            diagnostics.report(&type_syntax, WrongType { expected_ty, actual_ty: const_type });
+        }
+    }
+
+    let (value, resolved_lookback) = compute_constant_value(
+        db,
+        &mut diagnostics,
+        resolver,
+        const_type,
+        &constant_syntax.value(syntax_db),
+    );
+    Ok(ImplConstantDefData {
+        diagnostics: diagnostics.build(),
+        constant: Ok(Constant { ty: const_type, value }),
+        resolved_lookback: Arc::new(resolved_lookback),
+    })
+}
+
+/// Cycle handling for [crate::db::SemanticGroup::priv_impl_constant_def_data].
+pub fn priv_impl_constant_def_data_cycle(
+    db: &dyn SemanticGroup,
+    _cycle: &[String],
+    impl_constant_def_id: &ImplConstantDefId,
+) -> Maybe<ImplConstantDefData> {
+    let module_file_id = impl_constant_def_id.module_file_id(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let data = db.priv_impl_definition_data(impl_constant_def_id.impl_def_id(db.upcast()))?;
+    let constant_syntax = &data.constant_asts[*impl_constant_def_id];
+    let constant = Err(diagnostics.report(&constant_syntax.name(db.upcast()), ConstCycle));
+    Ok(ImplConstantDefData {
+        diagnostics: diagnostics.build(),
+        constant,
+        resolved_lookback: Arc::new(ResolvedLookback::default()),
+    })
+}
+
+/// Checks that an associated item defined in an impl is a member of the implemented trait, and
+/// returns the matching trait item.
+fn validate_impl_item_in_trait<T>(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    impl_def_id: ImplDefId,
+    name_syntax: ast::TerminalIdentifier,
+    item_kind: &str,
+    trait_item_by_name: impl FnOnce(TraitId, SmolStr) -> Maybe<Option<T>>,
+) -> Maybe<T> {
+    let syntax_db = db.upcast();
+    let concrete_trait_id = db.priv_impl_declaration_data(impl_def_id)?.concrete_trait?;
+    let trait_id = concrete_trait_id.trait_id(db);
+    let name = name_syntax.text(syntax_db);
+    trait_item_by_name(trait_id, name.clone())?.ok_or_else(|| {
+        diagnostics.report(
+            &name_syntax,
+            ImplItemNotMemberOfTrait {
+                impl_def_id,
+                item_kind: item_kind.into(),
+                item_name: name,
+                trait_id,
+            },
+        )
+    })
+}
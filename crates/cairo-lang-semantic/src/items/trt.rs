@@ -2,8 +2,9 @@ use std::sync::Arc;
 
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::ids::{
-    FunctionTitleId, LanguageElementId, TopLevelLanguageElementId, TraitFunctionId,
-    TraitFunctionLongId, TraitId,
+    FunctionTitleId, LanguageElementId, TopLevelLanguageElementId, TraitConstantId,
+    TraitConstantLongId, TraitFunctionId, TraitFunctionLongId, TraitId, TraitTypeId,
+    TraitTypeLongId,
 };
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe, ToMaybe};
 use cairo_lang_proc_macros::{DebugWithDb, SemanticObject};
@@ -13,15 +14,18 @@ use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use smol_str::SmolStr;
 
 use super::attribute::{ast_attributes_to_semantic, Attribute};
+use super::functions::generic_params_to_args;
 use super::generics::semantic_generic_params;
+use super::imp::ImplId;
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnostics;
 use crate::expr::compute::Environment;
 use crate::resolve_path::{ResolvedLookback, Resolver};
 use crate::substitution::{GenericSubstitution, SemanticRewriter, SubstitutionRewriter};
+use crate::types::resolve_type;
 use crate::{
     semantic, semantic_object_for_id, GenericArgumentId, GenericParam, Mutability,
-    SemanticDiagnostic,
+    SemanticDiagnostic, TypeId,
 };
 
 #[cfg(test)]
@@ -70,6 +74,13 @@ impl ConcreteTraitId {
     }
 }
 
+/// Returns the impl referred to by `Self` inside a trait, i.e. the impl of the trait concretized
+/// by its own generic params.
+pub fn trait_self_impl(db: &dyn SemanticGroup, trait_id: TraitId) -> Maybe<ImplId> {
+    let generic_args = generic_params_to_args(db, &db.trait_generic_params(trait_id)?)?;
+    Ok(ImplId::SelfImpl(db.intern_concrete_trait(ConcreteTraitLongId { trait_id, generic_args })))
+}
+
 /// The ID of a generic function in a concrete trait.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
 #[debug_db(dyn SemanticGroup + 'static)]
@@ -133,6 +144,8 @@ pub struct TraitData {
     generic_params: Vec<GenericParam>,
     attributes: Vec<Attribute>,
     function_asts: OrderedHashMap<TraitFunctionId, ast::TraitItemFunction>,
+    type_asts: OrderedHashMap<TraitTypeId, ast::TraitItemType>,
+    constant_asts: OrderedHashMap<TraitConstantId, ast::TraitItemConstant>,
 }
 
 /// Query implementation of [crate::db::SemanticGroup::trait_semantic_diagnostics].
@@ -150,6 +163,9 @@ pub fn trait_semantic_diagnostics(
     for trait_function_id in data.function_asts.keys() {
         diagnostics.extend(db.trait_function_diagnostics(*trait_function_id));
     }
+    for trait_constant_id in data.constant_asts.keys() {
+        diagnostics.extend(db.trait_constant_diagnostics(*trait_constant_id));
+    }
 
     diagnostics.build()
 }
@@ -189,6 +205,50 @@ pub fn trait_function_by_name(
     Ok(db.trait_functions(trait_id)?.get(&name).copied())
 }
 
+/// Query implementation of [crate::db::SemanticGroup::trait_types].
+pub fn trait_types(
+    db: &dyn SemanticGroup,
+    trait_id: TraitId,
+) -> Maybe<OrderedHashMap<SmolStr, TraitTypeId>> {
+    Ok(db
+        .priv_trait_semantic_data(trait_id)?
+        .type_asts
+        .keys()
+        .map(|type_id| (type_id.name(db.upcast()), *type_id))
+        .collect())
+}
+
+/// Query implementation of [crate::db::SemanticGroup::trait_type_by_name].
+pub fn trait_type_by_name(
+    db: &dyn SemanticGroup,
+    trait_id: TraitId,
+    name: SmolStr,
+) -> Maybe<Option<TraitTypeId>> {
+    Ok(db.trait_types(trait_id)?.get(&name).copied())
+}
+
+/// Query implementation of [crate::db::SemanticGroup::trait_constants].
+pub fn trait_constants(
+    db: &dyn SemanticGroup,
+    trait_id: TraitId,
+) -> Maybe<OrderedHashMap<SmolStr, TraitConstantId>> {
+    Ok(db
+        .priv_trait_semantic_data(trait_id)?
+        .constant_asts
+        .keys()
+        .map(|constant_id| (constant_id.name(db.upcast()), *constant_id))
+        .collect())
+}
+
+/// Query implementation of [crate::db::SemanticGroup::trait_constant_by_name].
+pub fn trait_constant_by_name(
+    db: &dyn SemanticGroup,
+    trait_id: TraitId,
+    name: SmolStr,
+) -> Maybe<Option<TraitConstantId>> {
+    Ok(db.trait_constants(trait_id)?.get(&name).copied())
+}
+
 /// Query implementation of [crate::db::SemanticGroup::priv_trait_semantic_data].
 pub fn priv_trait_semantic_data(db: &dyn SemanticGroup, trait_id: TraitId) -> Maybe<TraitData> {
     let syntax_db = db.upcast();
@@ -212,6 +272,8 @@ pub fn priv_trait_semantic_data(db: &dyn SemanticGroup, trait_id: TraitId) -> Ma
 
     let attributes = ast_attributes_to_semantic(syntax_db, trait_ast.attributes(syntax_db));
     let mut function_asts = OrderedHashMap::default();
+    let mut type_asts = OrderedHashMap::default();
+    let mut constant_asts = OrderedHashMap::default();
     if let ast::MaybeTraitBody::Some(body) = trait_ast.body(syntax_db) {
         for item in body.items(syntax_db).elements(syntax_db) {
             match item {
@@ -224,11 +286,104 @@ pub fn priv_trait_semantic_data(db: &dyn SemanticGroup, trait_id: TraitId) -> Ma
                         func,
                     );
                 }
+                ast::TraitItem::Type(ty) => {
+                    type_asts.insert(
+                        db.intern_trait_type(TraitTypeLongId(module_file_id, ty.stable_ptr())),
+                        ty,
+                    );
+                }
+                ast::TraitItem::Constant(constant) => {
+                    constant_asts.insert(
+                        db.intern_trait_constant(TraitConstantLongId(
+                            module_file_id,
+                            constant.stable_ptr(),
+                        )),
+                        constant,
+                    );
+                }
             }
         }
     }
 
-    Ok(TraitData { diagnostics: diagnostics.build(), generic_params, attributes, function_asts })
+    Ok(TraitData {
+        diagnostics: diagnostics.build(),
+        generic_params,
+        attributes,
+        function_asts,
+        type_asts,
+        constant_asts,
+    })
+}
+
+// Trait constant.
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub struct TraitConstantData {
+    diagnostics: Diagnostics<SemanticDiagnostic>,
+    ty: TypeId,
+    resolved_lookback: Arc<ResolvedLookback>,
+}
+
+/// Query implementation of [crate::db::SemanticGroup::trait_constant_diagnostics].
+pub fn trait_constant_diagnostics(
+    db: &dyn SemanticGroup,
+    trait_constant_id: TraitConstantId,
+) -> Diagnostics<SemanticDiagnostic> {
+    db.priv_trait_constant_data(trait_constant_id).map(|data| data.diagnostics).unwrap_or_default()
+}
+/// Query implementation of [crate::db::SemanticGroup::trait_constant_type].
+pub fn trait_constant_type(
+    db: &dyn SemanticGroup,
+    trait_constant_id: TraitConstantId,
+) -> Maybe<TypeId> {
+    Ok(db.priv_trait_constant_data(trait_constant_id)?.ty)
+}
+/// Query implementation of [crate::db::SemanticGroup::trait_constant_resolved_lookback].
+pub fn trait_constant_resolved_lookback(
+    db: &dyn SemanticGroup,
+    trait_constant_id: TraitConstantId,
+) -> Maybe<Arc<ResolvedLookback>> {
+    Ok(db.priv_trait_constant_data(trait_constant_id)?.resolved_lookback)
+}
+
+/// Query implementation of [crate::db::SemanticGroup::priv_trait_constant_data].
+pub fn priv_trait_constant_data(
+    db: &dyn SemanticGroup,
+    trait_constant_id: TraitConstantId,
+) -> Maybe<TraitConstantData> {
+    let syntax_db = db.upcast();
+    let module_file_id = trait_constant_id.module_file_id(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let trait_id = trait_constant_id.trait_id(db.upcast());
+    let data = db.priv_trait_semantic_data(trait_id)?;
+    let constant_syntax = &data.constant_asts[trait_constant_id];
+    let mut resolver = Resolver::new_without_inference(db, module_file_id);
+    for generic_param in db.trait_generic_params(trait_id)? {
+        resolver.add_generic_param(generic_param);
+    }
+    let ty = resolve_type(
+        db,
+        &mut diagnostics,
+        &mut resolver,
+        &constant_syntax.type_clause(syntax_db).ty(syntax_db),
+    );
+    let resolved_lookback = Arc::new(resolver.lookback);
+
+    Ok(TraitConstantData { diagnostics: diagnostics.build(), ty, resolved_lookback })
+}
+
+/// Query implementation of [crate::db::SemanticGroup::concrete_trait_constant_type].
+pub fn concrete_trait_constant_type(
+    db: &dyn SemanticGroup,
+    concrete_trait_id: ConcreteTraitId,
+    trait_constant_id: TraitConstantId,
+) -> Maybe<TypeId> {
+    let substitution = GenericSubstitution::new(
+        &db.trait_generic_params(concrete_trait_id.trait_id(db))?,
+        &concrete_trait_id.generic_args(db),
+    );
+    let generic_ty = db.trait_constant_type(trait_constant_id)?;
+    SubstitutionRewriter { db, substitution: &substitution }.rewrite(generic_ty)
 }
 
 // Trait function.
@@ -296,6 +451,9 @@ pub fn priv_trait_function_data(
     for generic_param in trait_generic_params {
         resolver.add_generic_param(generic_param);
     }
+    if let Ok(self_impl) = trait_self_impl(db, trait_id) {
+        resolver.set_self_impl(self_impl);
+    }
     let function_generic_params = semantic_generic_params(
         db,
         &mut diagnostics,
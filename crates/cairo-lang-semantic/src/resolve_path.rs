@@ -5,20 +5,21 @@ mod test;
 use std::iter::Peekable;
 
 use cairo_lang_defs::ids::{
-    ConstantId, GenericTypeId, ImplDefId, LanguageElementId, ModuleFileId, ModuleId, ModuleItemId,
-    TraitFunctionId, TraitId, TypeAliasId,
+    ConstantId, GenericParamId, GenericTypeId, ImplDefId, LanguageElementId, ModuleFileId,
+    ModuleId, ModuleItemId, TraitFunctionId, TraitId, TypeAliasId,
 };
 use cairo_lang_diagnostics::Maybe;
-use cairo_lang_filesystem::ids::CrateLongId;
+use cairo_lang_filesystem::ids::{CrateId, CrateLongId};
 use cairo_lang_proc_macros::DebugWithDb;
 use cairo_lang_syntax as syntax;
+use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::PathSegmentEx;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::try_extract_matches;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
-use itertools::Itertools;
+use itertools::{chain, Itertools};
 use smol_str::SmolStr;
 
 use crate::corelib::core_module;
@@ -28,7 +29,9 @@ use crate::diagnostic::{NotFoundItemType, SemanticDiagnostics};
 use crate::expr::inference::Inference;
 use crate::items::enm::{ConcreteVariant, SemanticEnumEx};
 use crate::items::functions::{GenericFunctionId, ImplGenericFunctionId};
-use crate::items::imp::{ConcreteImplId, ConcreteImplLongId, ImplId, ImplLookupContext};
+use crate::items::imp::{
+    ConcreteImplId, ConcreteImplLongId, ImplConstantId, ImplId, ImplLookupContext, ImplTypeId,
+};
 use crate::items::trt::{
     ConcreteTraitGenericFunctionId, ConcreteTraitGenericFunctionLongId, ConcreteTraitId,
     ConcreteTraitLongId,
@@ -56,6 +59,8 @@ pub enum ResolvedConcreteItem {
     Variant(ConcreteVariant),
     Trait(ConcreteTraitId),
     Impl(ImplId),
+    /// An associated constant of an impl.
+    ImplConstant(ImplConstantId),
 }
 #[derive(Clone, PartialEq, Eq, Debug, DebugWithDb)]
 #[debug_db(dyn SemanticGroup + 'static)]
@@ -103,8 +108,14 @@ impl ResolvedConcreteItem {
                 ImplId::Concrete(concrete_impl_id) => ResolvedGenericItem::Impl(
                     db.lookup_intern_concrete_impl(*concrete_impl_id).impl_def_id,
                 ),
-                ImplId::GenericParameter(_) | ImplId::ImplVar(_) => return None,
+                ImplId::GenericParameter(_)
+                | ImplId::ImplVar(_)
+                | ImplId::Closure(_)
+                | ImplId::SelfImpl(_) => {
+                    return None;
+                }
             },
+            ResolvedConcreteItem::ImplConstant(_) => return None,
         })
     }
 }
@@ -148,13 +159,33 @@ impl ResolvedLookback {
     }
 }
 
+/// A path that can be resolved, given as a sequence of segments.
+/// Allows resolving partial paths, e.g. when completing a path in the language server.
+pub trait AsSegments {
+    fn to_segments(self, db: &dyn SyntaxGroup) -> Vec<ast::PathSegment>;
+}
+impl AsSegments for &ast::ExprPath {
+    fn to_segments(self, db: &dyn SyntaxGroup) -> Vec<ast::PathSegment> {
+        self.elements(db)
+    }
+}
+impl AsSegments for Vec<ast::PathSegment> {
+    fn to_segments(self, _: &dyn SyntaxGroup) -> Vec<ast::PathSegment> {
+        self
+    }
+}
+
 /// Resolves paths semantically.
 pub struct Resolver<'db> {
     db: &'db dyn SemanticGroup,
     // Current module in which to resolve the path.
     pub module_file_id: ModuleFileId,
     // Generic parameters accessible to the resolver.
-    generic_params: OrderedHashMap<SmolStr, GenericParam>,
+    generic_params: OrderedHashMap<GenericParamId, GenericParam>,
+    // The generic parameters that can be referred to by name.
+    generic_param_by_name: OrderedHashMap<SmolStr, GenericParamId>,
+    // The impl referred to by `Self`, inside a trait or an impl.
+    self_impl: Option<ImplId>,
     // Lookback map for resolved identifiers in path. Used in "Go to definition".
     pub lookback: ResolvedLookback,
     pub inference: Inference<'db>,
@@ -165,6 +196,8 @@ impl<'db> Resolver<'db> {
             db,
             module_file_id,
             generic_params: Default::default(),
+            generic_param_by_name: Default::default(),
+            self_impl: None,
             lookback: ResolvedLookback::default(),
             inference: Inference::new(db),
         }
@@ -175,6 +208,8 @@ impl<'db> Resolver<'db> {
             db,
             module_file_id,
             generic_params: Default::default(),
+            generic_param_by_name: Default::default(),
+            self_impl: None,
             lookback: ResolvedLookback::default(),
             inference: Inference::new(db),
         }
@@ -183,8 +218,20 @@ impl<'db> Resolver<'db> {
     /// Adds a generic param to an existing resolver.
     /// This is required since a resolver needs to exist before resolving the generic params,
     /// and thus, they are added to the Resolver only after they are resolved.
+    /// Anonymous impl params (e.g. `+Drop<T>`) cannot be referred to by name, and are only used for
+    /// impl inference.
     pub fn add_generic_param(&mut self, generic_param: GenericParam) {
-        self.generic_params.insert(generic_param.id().name(self.db.upcast()), generic_param);
+        let id = generic_param.id();
+        if let Some(name) = id.name(self.db.upcast()) {
+            self.generic_param_by_name.insert(name, id);
+        }
+        self.generic_params.insert(id, generic_param);
+    }
+
+    /// Sets the impl referred to by `Self`, whose associated types and constants may also be
+    /// referred to by their name alone.
+    pub fn set_self_impl(&mut self, impl_id: ImplId) {
+        self.self_impl = Some(impl_id);
     }
 
     /// Resolves a concrete item, given a path.
@@ -192,11 +239,11 @@ impl<'db> Resolver<'db> {
     pub fn resolve_concrete_path(
         &mut self,
         diagnostics: &mut SemanticDiagnostics,
-        path: &ast::ExprPath,
+        path: impl AsSegments,
         item_type: NotFoundItemType,
     ) -> Maybe<ResolvedConcreteItem> {
         let syntax_db = self.db.upcast();
-        let elements_vec = path.elements(syntax_db);
+        let elements_vec = path.to_segments(syntax_db);
         let mut segments = elements_vec.iter().peekable();
 
         // Find where the first segment lies in.
@@ -250,6 +297,11 @@ impl<'db> Resolver<'db> {
                 let identifier = simple_segment.ident(syntax_db);
                 if let Some(local_item) = self.determine_base_item_in_local_scope(&identifier) {
                     self.lookback.mark_concrete(self.db, segments.next().unwrap(), local_item)
+                } else if let Some(impl_id) = self.self_associated_item_impl(&identifier) {
+                    // An associated item of `Self` referred to by its name alone, e.g. `Key`.
+                    let item =
+                        self.resolve_impl_associated_item(diagnostics, impl_id, &identifier, None)?;
+                    self.lookback.mark_concrete(self.db, segments.next().unwrap(), item)
                 } else if let Some(module_id) = self.determine_base_module(&identifier) {
                     // This item lies inside a module.
                     ResolvedConcreteItem::Module(module_id)
@@ -272,11 +324,11 @@ impl<'db> Resolver<'db> {
     pub fn resolve_generic_path(
         &mut self,
         diagnostics: &mut SemanticDiagnostics,
-        path: &ast::ExprPath,
+        path: impl AsSegments,
         item_type: NotFoundItemType,
     ) -> Maybe<ResolvedGenericItem> {
         let syntax_db = self.db.upcast();
-        let elements_vec = path.elements(syntax_db);
+        let elements_vec = path.to_segments(syntax_db);
         let mut segments = elements_vec.iter().peekable();
 
         // Find where the first segment lies in.
@@ -408,15 +460,52 @@ impl<'db> Resolver<'db> {
                         self.db.concrete_enum_variant(concrete_enum_id, &variant)?;
                     Ok(ResolvedConcreteItem::Variant(concrete_variant))
                 } else {
-                    Err(diagnostics.report(identifier, InvalidPath))
+                    // Associated types and constants of a type (e.g. `T::Key`) are resolved
+                    // through the unique impl generic param providing them. The param is used
+                    // directly, since inferring it would query the generic params of the item
+                    // being resolved.
+                    let impl_id = self
+                        .associated_item_impl(*ty, &ident)
+                        .ok_or_else(|| diagnostics.report(identifier, InvalidPath))?;
+                    self.resolve_next_concrete(
+                        diagnostics,
+                        &ResolvedConcreteItem::Impl(impl_id),
+                        identifier,
+                        generic_args_syntax,
+                        item_type,
+                    )
                 }
             }
             ResolvedConcreteItem::Trait(concrete_trait_id) => {
                 // Find the relevant function in the trait.
                 let long_trait_id = self.db.lookup_intern_concrete_trait(*concrete_trait_id);
                 let trait_id = long_trait_id.trait_id;
-                let Some(trait_function_id) = self.db.trait_function_by_name(trait_id, ident)? else {
-                    return Err(diagnostics.report(identifier, InvalidPath));
+                let Some(trait_function_id) =
+                    self.db.trait_function_by_name(trait_id, ident.clone())?
+                else {
+                    // Associated types and constants are resolved through an inferred impl.
+                    if self.db.trait_type_by_name(trait_id, ident.clone())?.is_none()
+                        && self.db.trait_constant_by_name(trait_id, ident)?.is_none()
+                    {
+                        return Err(diagnostics.report(identifier, InvalidPath));
+                    }
+                    let impl_id = self
+                        .inference
+                        .new_impl_var(
+                            *concrete_trait_id,
+                            identifier.stable_ptr().untyped(),
+                            self.impl_lookup_context(),
+                        )
+                        .map_err(|err| {
+                            err.report(diagnostics, identifier.stable_ptr().untyped())
+                        })?;
+                    return self.resolve_next_concrete(
+                        diagnostics,
+                        &ResolvedConcreteItem::Impl(impl_id),
+                        identifier,
+                        generic_args_syntax,
+                        item_type,
+                    );
                 };
 
                 let concrete_trait_function = self.db.intern_concrete_trait_function(
@@ -443,12 +532,17 @@ impl<'db> Resolver<'db> {
                 )?))
             }
             ResolvedConcreteItem::Impl(impl_id) => {
-                let concrete_trait_id = self.db.impl_concrete_trait(*impl_id)?;
+                let concrete_trait_id = self.impl_concrete_trait(*impl_id)?;
                 let trait_id = concrete_trait_id.trait_id(self.db);
                 let Some(trait_function_id) = self.db.trait_function_by_name(
                     trait_id, ident,
                 )? else {
-                    return Err(diagnostics.report(identifier, InvalidPath));
+                    return self.resolve_impl_associated_item(
+                        diagnostics,
+                        *impl_id,
+                        identifier,
+                        generic_args_syntax,
+                    );
                 };
                 let generic_function_id = GenericFunctionId::Impl(ImplGenericFunctionId {
                     impl_id: *impl_id,
@@ -466,6 +560,83 @@ impl<'db> Resolver<'db> {
         }
     }
 
+    /// Returns the concrete trait of an impl.
+    /// Impl generic params of the resolver are looked up directly, since they are not available
+    /// from the db while the item declaring them is being resolved (e.g. in a signature).
+    fn impl_concrete_trait(&self, impl_id: ImplId) -> Maybe<ConcreteTraitId> {
+        if let ImplId::GenericParameter(param_id) = impl_id {
+            if let Some(GenericParam::Impl(param)) = self.generic_params.get(&param_id) {
+                return param.concrete_trait;
+            }
+        }
+        self.db.impl_concrete_trait(impl_id)
+    }
+
+    /// Returns the unique impl generic param of the resolver (or the impl referred to by `Self`)
+    /// which has `ty` as a generic argument and has an associated type or constant named `ident`.
+    fn associated_item_impl(&self, ty: TypeId, ident: &SmolStr) -> Option<ImplId> {
+        let impl_params = self.generic_params.values().filter_map(|param| {
+            let GenericParam::Impl(param) = param else { return None; };
+            Some(ImplId::GenericParameter(param.id))
+        });
+        let mut candidates = chain!(impl_params, self.self_impl).filter(|impl_id| {
+            let Ok(concrete_trait_id) = self.impl_concrete_trait(*impl_id) else {
+                return false;
+            };
+            if !concrete_trait_id.generic_args(self.db).contains(&GenericArgumentId::Type(ty)) {
+                return false;
+            }
+            let trait_id = concrete_trait_id.trait_id(self.db);
+            matches!(self.db.trait_type_by_name(trait_id, ident.clone()), Ok(Some(_)))
+                || matches!(self.db.trait_constant_by_name(trait_id, ident.clone()), Ok(Some(_)))
+        });
+        let impl_id = candidates.next()?;
+        candidates.next().is_none().then_some(impl_id)
+    }
+
+    /// Returns the impl referred to by `Self` if it has an associated type or constant with the
+    /// given name.
+    fn self_associated_item_impl(&self, identifier: &ast::TerminalIdentifier) -> Option<ImplId> {
+        let ident = identifier.text(self.db.upcast());
+        let impl_id = self.self_impl?;
+        let trait_id = self.impl_concrete_trait(impl_id).ok()?.trait_id(self.db);
+        let has_item = self.db.trait_type_by_name(trait_id, ident.clone()).ok()?.is_some()
+            || self.db.trait_constant_by_name(trait_id, ident).ok()?.is_some();
+        has_item.then_some(impl_id)
+    }
+
+    /// Resolves an associated type or constant of an impl, given its name.
+    fn resolve_impl_associated_item(
+        &mut self,
+        diagnostics: &mut SemanticDiagnostics,
+        impl_id: ImplId,
+        identifier: &ast::TerminalIdentifier,
+        generic_args_syntax: Option<Vec<ast::GenericArg>>,
+    ) -> Maybe<ResolvedConcreteItem> {
+        let ident = identifier.text(self.db.upcast());
+        let trait_id = self.impl_concrete_trait(impl_id)?.trait_id(self.db);
+        if generic_args_syntax.is_some() {
+            return Err(diagnostics.report(identifier, UnexpectedGenericArgs));
+        }
+        if let Some(trait_type_id) = self.db.trait_type_by_name(trait_id, ident.clone())? {
+            let ty =
+                self.db.intern_type(TypeLongId::ImplType(ImplTypeId { impl_id, trait_type_id }));
+            // Reduces the type if the impl is already known.
+            let ty = self
+                .inference
+                .rewrite(ty)
+                .map_err(|err| err.report(diagnostics, identifier.stable_ptr().untyped()))?;
+            return Ok(ResolvedConcreteItem::Type(ty));
+        }
+        if let Some(trait_constant_id) = self.db.trait_constant_by_name(trait_id, ident)? {
+            return Ok(ResolvedConcreteItem::ImplConstant(ImplConstantId {
+                impl_id,
+                trait_constant_id,
+            }));
+        }
+        Err(diagnostics.report(identifier, InvalidPath))
+    }
+
     /// Specializes a ResolvedGenericItem that came from a ModuleItem.
     fn specialize_generic_module_item(
         &mut self,
@@ -611,9 +782,13 @@ impl<'db> Resolver<'db> {
         let syntax_db = self.db.upcast();
         let ident = identifier.text(syntax_db);
 
+        if ident == "Self" {
+            return self.self_impl.map(ResolvedConcreteItem::Impl);
+        }
+
         // If a generic param with this name is found, use it.
-        if let Some(generic_param_id) = self.generic_params.get(&ident) {
-            let item = match generic_param_id {
+        if let Some(generic_param_id) = self.generic_param_by_name.get(&ident) {
+            let item = match self.generic_params[*generic_param_id] {
                 GenericParam::Type(param) => ResolvedConcreteItem::Type(
                     self.db.intern_type(TypeLongId::GenericParameter(param.id)),
                 ),
@@ -643,11 +818,11 @@ impl<'db> Resolver<'db> {
             return Some(self.module_file_id.0);
         }
 
-        // If the first segment is a name of a crate, use the crate's root module as the base
-        // module.
-        let crate_id = self.db.intern_crate(CrateLongId(ident));
+        // If the first segment is a name of a crate visible from the current crate, use the
+        // crate's root module as the base module.
+        let crate_id = self.db.intern_crate(CrateLongId(ident.clone()));
         // TODO(spapini): Use a better interface to check if the crate exists (not using `dir`).
-        if self.db.crate_root_dir(crate_id).is_some() {
+        if self.db.crate_root_dir(crate_id).is_some() && self.is_crate_visible(crate_id, &ident) {
             return None;
         }
 
@@ -655,6 +830,14 @@ impl<'db> Resolver<'db> {
         Some(core_module(self.db))
     }
 
+    /// Returns whether the crate named `crate_name` may be referred to from the current crate,
+    /// according to the dependencies declared in the current crate's settings.
+    fn is_crate_visible(&self, crate_id: CrateId, crate_name: &str) -> bool {
+        let current_crate = self.module_file_id.0.owning_crate(self.db.upcast());
+        crate_id == current_crate
+            || self.db.crate_settings(current_crate).is_dependency_visible(crate_name)
+    }
+
     /// Specializes a trait.
     fn specialize_trait(
         &mut self,
@@ -781,7 +964,7 @@ impl<'db> Resolver<'db> {
                 diagnostics,
             )?;
             resolved_args.push(generic_arg);
-            substitution.0.insert(generic_param.id(), generic_arg);
+            substitution.param_to_arg.insert(generic_param.id(), generic_arg);
         }
 
         Ok(resolved_args)
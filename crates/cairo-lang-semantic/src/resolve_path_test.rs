@@ -3,7 +3,7 @@ use std::sync::Arc;
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::ids::{FunctionWithBodyId, ModuleId, ModuleItemId};
 use cairo_lang_diagnostics::ToOption;
-use cairo_lang_filesystem::db::{AsFilesGroupMut, FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::db::{AsFilesGroupMut, CrateSettings, FilesGroup, FilesGroupEx};
 use cairo_lang_filesystem::ids::{CrateLongId, Directory, FileLongId};
 use cairo_lang_utils::extract_matches;
 use indoc::indoc;
@@ -106,6 +106,64 @@ fn test_resolve_path_super() {
     );
 }
 
+#[test]
+fn test_resolve_path_crate_dependencies() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let db = &mut db_val;
+
+    for (crate_name, dependencies) in
+        [("main", Some(vec!["dep".into()])), ("dep", None), ("hidden", None)]
+    {
+        let crate_id = db.intern_crate(CrateLongId(crate_name.into()));
+        db.set_crate_root(crate_id, Some(Directory(crate_name.into())));
+        db.set_crate_settings(crate_id, Some(CrateSettings { dependencies, ..Default::default() }));
+    }
+    set_file_content(
+        db,
+        "main/lib.cairo",
+        indoc! {"
+            struct MainStruct {
+                a: dep::DepStruct,
+                b: hidden::HiddenStruct,
+                c: main::MainStruct2,
+            }
+            struct MainStruct2 {}
+        "},
+    );
+    set_file_content(db, "dep/lib.cairo", "struct DepStruct {}");
+    set_file_content(db, "hidden/lib.cairo", "struct HiddenStruct {}");
+
+    let main_module = ModuleId::CrateRoot(db.intern_crate(CrateLongId("main".into())));
+    let struct_id = extract_matches!(
+        db.module_item_by_name(main_module, "MainStruct".into()).unwrap().unwrap(),
+        ModuleItemId::Struct
+    );
+    let members = db.struct_members(struct_id).unwrap();
+    assert_eq!(
+        format!("{:?}", members["a"].debug(db)),
+        "Member { id: MemberId(main::a), ty: dep::DepStruct }"
+    );
+    assert_eq!(
+        format!("{:?}", members["b"].debug(db)),
+        "Member { id: MemberId(main::b), ty: <missing> }"
+    );
+    assert_eq!(
+        format!("{:?}", members["c"].debug(db)),
+        "Member { id: MemberId(main::c), ty: main::MainStruct2 }"
+    );
+    // `hidden` is not a declared dependency of `main`, so it is not visible.
+    assert_eq!(
+        db.module_semantic_diagnostics(main_module).unwrap().format(db),
+        indoc! {"
+            error: Identifier not found.
+             --> lib.cairo:3:8
+                b: hidden::HiddenStruct,
+                   ^****^
+
+        "}
+    );
+}
+
 #[test]
 fn test_resolve_path_trait_impl() {
     let mut db_val = SemanticDatabaseForTesting::default();
@@ -22,7 +22,8 @@ pub use crate::items::imp::{ConcreteImplId, ConcreteImplLongId};
 pub use crate::items::structure::Member;
 pub use crate::items::trt::{ConcreteTraitId, ConcreteTraitLongId};
 pub use crate::types::{
-    ConcreteEnumId, ConcreteExternTypeId, ConcreteStructId, ConcreteTypeId, TypeId, TypeLongId,
+    ClosureTypeLongId, ConcreteEnumId, ConcreteExternTypeId, ConcreteStructId, ConcreteTypeId,
+    TypeId, TypeLongId,
 };
 
 /// Semantic model of a variable.
@@ -91,4 +92,10 @@ impl Variable {
             Variable::Param(param) => param.mutability != Mutability::Immutable,
         }
     }
+    pub fn stable_ptr(&self, db: &dyn DefsGroup) -> ast::TerminalIdentifierPtr {
+        match self {
+            Variable::Local(local) => local.stable_ptr(db),
+            Variable::Param(param) => param.stable_ptr,
+        }
+    }
 }
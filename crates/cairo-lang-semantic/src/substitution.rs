@@ -2,7 +2,8 @@ use std::ops::Deref;
 
 use cairo_lang_defs::ids::{
     EnumId, ExternFunctionId, ExternTypeId, FreeFunctionId, GenericParamId, ImplDefId,
-    ImplFunctionId, ParamId, StructId, TraitFunctionId, TraitId, VariantId,
+    ImplFunctionId, ParamId, StructId, TraitConstantId, TraitFunctionId, TraitId, TraitTypeId,
+    VariantId,
 };
 use cairo_lang_diagnostics::{DiagnosticAdded, Maybe};
 use cairo_lang_utils::extract_matches;
@@ -11,15 +12,18 @@ use itertools::{zip_eq, Itertools};
 
 use crate::db::SemanticGroup;
 use crate::expr::inference::{ImplVar, TypeVar};
+use crate::items::constant::ConstValue;
 use crate::items::functions::{
-    ConcreteFunctionWithBody, GenericFunctionId, GenericFunctionWithBodyId, ImplGenericFunctionId,
-    ImplGenericFunctionWithBodyId,
+    ConcreteFunctionWithBody, ConcreteFunctionWithBodyId, GenericFunctionId,
+    GenericFunctionWithBodyId, ImplGenericFunctionId, ImplGenericFunctionWithBodyId,
 };
 use crate::items::generics::{GenericParamConst, GenericParamImpl, GenericParamType};
-use crate::items::imp::{ImplId, UninferredImpl};
+use crate::items::imp::{ImplConstantId, ImplId, ImplTypeId, UninferredImpl};
 use crate::items::trt::{ConcreteTraitGenericFunctionId, ConcreteTraitGenericFunctionLongId};
 use crate::literals::LiteralId;
-use crate::types::{ConcreteEnumLongId, ConcreteExternTypeLongId, ConcreteStructLongId};
+use crate::types::{
+    ClosureTypeLongId, ConcreteEnumLongId, ConcreteExternTypeLongId, ConcreteStructLongId,
+};
 use crate::{
     ConcreteEnumId, ConcreteExternTypeId, ConcreteFunction, ConcreteImplId, ConcreteImplLongId,
     ConcreteStructId, ConcreteTraitId, ConcreteTraitLongId, ConcreteTypeId, ConcreteVariant,
@@ -29,18 +33,32 @@ use crate::{
 
 /// A substitution of generic arguments in generic parameters. Used for concretization.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
-pub struct GenericSubstitution(pub OrderedHashMap<GenericParamId, GenericArgumentId>);
+pub struct GenericSubstitution {
+    pub param_to_arg: OrderedHashMap<GenericParamId, GenericArgumentId>,
+    /// The impl substituted for [ImplId::SelfImpl], when using the items of a trait through an
+    /// impl of it.
+    pub self_impl: Option<ImplId>,
+}
 impl GenericSubstitution {
     pub fn new(generic_params: &[GenericParam], generic_args: &[GenericArgumentId]) -> Self {
-        GenericSubstitution(
-            zip_eq(generic_params.iter().map(|param| param.id()), generic_args.iter().copied())
-                .collect(),
-        )
+        GenericSubstitution {
+            param_to_arg: zip_eq(
+                generic_params.iter().map(|param| param.id()),
+                generic_args.iter().copied(),
+            )
+            .collect(),
+            self_impl: None,
+        }
+    }
+    /// Returns a substitution of [ImplId::SelfImpl] by the given impl.
+    pub fn from_impl(impl_id: ImplId) -> Self {
+        GenericSubstitution { param_to_arg: Default::default(), self_impl: Some(impl_id) }
     }
     pub fn concat(mut self, other: GenericSubstitution) -> Self {
-        for (key, value) in other.0.into_iter() {
-            self.0.insert(key, value);
+        for (key, value) in other.param_to_arg.into_iter() {
+            self.param_to_arg.insert(key, value);
         }
+        self.self_impl = other.self_impl.or(self.self_impl);
         self
     }
 }
@@ -48,13 +66,14 @@ impl Deref for GenericSubstitution {
     type Target = OrderedHashMap<GenericParamId, GenericArgumentId>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.param_to_arg
     }
 }
 #[allow(clippy::derived_hash_with_manual_eq)]
 impl std::hash::Hash for GenericSubstitution {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.0.iter().collect_vec().hash(state);
+        self.param_to_arg.iter().collect_vec().hash(state);
+        self.self_impl.hash(state);
     }
 }
 
@@ -175,6 +194,8 @@ macro_rules! add_basic_rewrites {
         $crate::prune_single!(__identitity_helper, ImplDefId, $($exclude)*);
         $crate::prune_single!(__identitity_helper, TraitId, $($exclude)*);
         $crate::prune_single!(__identitity_helper, TraitFunctionId, $($exclude)*);
+        $crate::prune_single!(__identitity_helper, TraitTypeId, $($exclude)*);
+        $crate::prune_single!(__identitity_helper, TraitConstantId, $($exclude)*);
         $crate::prune_single!(__identitity_helper, VariantId, $($exclude)*);
         $crate::prune_single!(__identitity_helper, ImplFunctionId, $($exclude)*);
         $crate::prune_single!(__identitity_helper, EnumId, $($exclude)*);
@@ -188,6 +209,7 @@ macro_rules! add_basic_rewrites {
         $crate::prune_single!(__regular_helper, GenericFunctionWithBodyId, $($exclude)*);
         $crate::prune_single!(__regular_helper, ConcreteFunction, $($exclude)*);
         $crate::prune_single!(__regular_helper, ConcreteFunctionWithBody, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ConcreteFunctionWithBodyId, $($exclude)*);
         $crate::prune_single!(__regular_helper, ImplGenericFunctionId, $($exclude)*);
         $crate::prune_single!(__regular_helper, ImplGenericFunctionWithBodyId, $($exclude)*);
         $crate::prune_single!(__regular_helper, Parameter, $($exclude)*);
@@ -200,6 +222,7 @@ macro_rules! add_basic_rewrites {
         $crate::prune_single!(__regular_helper, FunctionLongId, $($exclude)*);
         $crate::prune_single!(__regular_helper, TypeId, $($exclude)*);
         $crate::prune_single!(__regular_helper, TypeLongId, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ClosureTypeLongId, $($exclude)*);
         $crate::prune_single!(__regular_helper, ConcreteVariant, $($exclude)*);
         $crate::prune_single!(__regular_helper, ConcreteTypeId, $($exclude)*);
         $crate::prune_single!(__regular_helper, ConcreteStructId, $($exclude)*);
@@ -215,6 +238,9 @@ macro_rules! add_basic_rewrites {
         $crate::prune_single!(__regular_helper, ConcreteTraitGenericFunctionLongId, $($exclude)*);
         $crate::prune_single!(__regular_helper, ConcreteTraitGenericFunctionId, $($exclude)*);
         $crate::prune_single!(__regular_helper, ImplId, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ImplTypeId, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ImplConstantId, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ConstValue, $($exclude)*);
         $crate::prune_single!(__regular_helper, UninferredImpl, $($exclude)*);
     };
 }
@@ -245,6 +271,10 @@ macro_rules! add_expr_rewrites {
         $crate::prune_single!(__regular_helper, ExprFunctionCall, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprMatch, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprIf, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ExprLoop, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ExprWhile, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ExprFor, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ExprClosure, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprVar, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprLiteral, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprMemberAccess, $($exclude)*);
@@ -252,6 +282,7 @@ macro_rules! add_expr_rewrites {
         $crate::prune_single!(__regular_helper, ExprEnumVariantCtor, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprPropagateError, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprConstant, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ExprImplConstant, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprMissing, $($exclude)*);
         $crate::prune_single!(__regular_helper, VarMemberPath, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprFunctionCallArg, $($exclude)*);
@@ -259,7 +290,9 @@ macro_rules! add_expr_rewrites {
         $crate::prune_single!(__regular_helper, Statement, $($exclude)*);
         $crate::prune_single!(__regular_helper, StatementExpr, $($exclude)*);
         $crate::prune_single!(__regular_helper, StatementLet, $($exclude)*);
+        $crate::prune_single!(__regular_helper, StatementContinue, $($exclude)*);
         $crate::prune_single!(__regular_helper, StatementReturn, $($exclude)*);
+        $crate::prune_single!(__regular_helper, StatementBreak, $($exclude)*);
         $crate::prune_single!(__regular_helper, Pattern, $($exclude)*);
         $crate::prune_single!(__regular_helper, PatternLiteral, $($exclude)*);
         $crate::prune_single!(__regular_helper, PatternVariable, $($exclude)*);
@@ -281,7 +314,7 @@ impl<'a> HasDb<&'a dyn SemanticGroup> for SubstitutionRewriter<'a> {
         self.db
     }
 }
-add_basic_rewrites!(<'a>, SubstitutionRewriter<'a>, DiagnosticAdded, @exclude TypeLongId ImplId);
+add_basic_rewrites!(<'a>, SubstitutionRewriter<'a>, DiagnosticAdded, @exclude TypeLongId ImplId ConstValue);
 impl<'a> SemanticRewriter<TypeLongId, DiagnosticAdded> for SubstitutionRewriter<'a> {
     fn rewrite(&mut self, value: TypeLongId) -> Maybe<TypeLongId> {
         if let TypeLongId::GenericParameter(generic_param) = value {
@@ -291,16 +324,43 @@ impl<'a> SemanticRewriter<TypeLongId, DiagnosticAdded> for SubstitutionRewriter<
                 return Ok(self.db.lookup_intern_type(type_id));
             }
         }
-        value.default_rewrite(self)
+        let value = value.default_rewrite(self)?;
+        // Reduce associated types of impls that became concrete.
+        if let TypeLongId::ImplType(impl_type_id) = value {
+            if let Some(ty) = impl_type_id.concrete_type(self.db)? {
+                return Ok(self.db.lookup_intern_type(ty));
+            }
+        }
+        Ok(value)
+    }
+}
+impl<'a> SemanticRewriter<ConstValue, DiagnosticAdded> for SubstitutionRewriter<'a> {
+    fn rewrite(&mut self, value: ConstValue) -> Maybe<ConstValue> {
+        let value = value.default_rewrite(self)?;
+        // Reduce associated constants of impls that became concrete.
+        if let ConstValue::ImplConstant(impl_constant_id) = value {
+            if let Some(value) = impl_constant_id.concrete_value(self.db)? {
+                return Ok(value);
+            }
+        }
+        Ok(value)
     }
 }
 impl<'a> SemanticRewriter<ImplId, DiagnosticAdded> for SubstitutionRewriter<'a> {
     fn rewrite(&mut self, value: ImplId) -> Maybe<ImplId> {
-        if let ImplId::GenericParameter(generic_param) = value {
-            if let Some(generic_arg) = self.substitution.get(&generic_param) {
-                let impl_id = *extract_matches!(generic_arg, GenericArgumentId::Impl);
-                return self.rewrite(impl_id);
+        match value {
+            ImplId::GenericParameter(generic_param) => {
+                if let Some(generic_arg) = self.substitution.get(&generic_param) {
+                    let impl_id = *extract_matches!(generic_arg, GenericArgumentId::Impl);
+                    return self.rewrite(impl_id);
+                }
+            }
+            ImplId::SelfImpl(_) => {
+                if let Some(impl_id) = self.substitution.self_impl {
+                    return Ok(impl_id);
+                }
             }
+            _ => {}
         }
         value.default_rewrite(self)
     }
@@ -14,9 +14,9 @@ use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
 use crate::diagnostic::{NotFoundItemType, SemanticDiagnostics};
 use crate::expr::inference::{InferenceResult, TypeVar};
-use crate::items::imp::{get_impl_at_context, ImplId, ImplLookupContext};
+use crate::items::imp::{get_impl_at_context, ImplId, ImplLookupContext, ImplTypeId};
 use crate::resolve_path::{ResolvedConcreteItem, Resolver};
-use crate::{semantic, semantic_object_for_id};
+use crate::{semantic, semantic_object_for_id, ConcreteFunctionWithBodyId};
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
 pub enum TypeLongId {
@@ -26,6 +26,9 @@ pub enum TypeLongId {
     Tuple(Vec<TypeId>),
     Snapshot(TypeId),
     GenericParameter(GenericParamId),
+    /// An associated type of an impl that is not concrete yet.
+    ImplType(ImplTypeId),
+    Closure(ClosureTypeLongId),
     Var(TypeVar),
     Missing(#[dont_rewrite] DiagnosticAdded),
 }
@@ -85,6 +88,8 @@ impl TypeLongId {
             TypeLongId::GenericParameter(generic_param) => {
                 format!("{:?}", generic_param.debug(db.elongate()))
             }
+            TypeLongId::ImplType(impl_type_id) => impl_type_id.format(db).into(),
+            TypeLongId::Closure(closure) => closure.format(db),
             TypeLongId::Var(var) => format!("?{}", var.id),
             TypeLongId::Missing(_) => "<missing>".to_string(),
         }
@@ -96,7 +101,11 @@ impl TypeLongId {
             TypeLongId::Concrete(concrete) => TypeHead::Concrete(concrete.generic_type(db)),
             TypeLongId::Tuple(_) => TypeHead::Tuple,
             TypeLongId::Snapshot(inner) => TypeHead::Snapshot(Box::new(inner.head(db)?)),
-            TypeLongId::GenericParameter(_) | TypeLongId::Var(_) | TypeLongId::Missing(_) => {
+            TypeLongId::GenericParameter(_)
+            | TypeLongId::ImplType(_)
+            | TypeLongId::Closure(_)
+            | TypeLongId::Var(_)
+            | TypeLongId::Missing(_) => {
                 return None;
             }
         })
@@ -122,6 +131,29 @@ pub enum TypeHead {
     Tuple,
 }
 
+/// The type of a closure expression. Each closure expression has its own unique type, identified by
+/// the closure expression and the concrete function containing it.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
+pub struct ClosureTypeLongId {
+    pub param_tys: Vec<TypeId>,
+    pub ret_ty: TypeId,
+    /// The types of the variables captured by the closure, in the order of their capture.
+    pub captured_types: Vec<TypeId>,
+    /// The function containing the closure expression.
+    pub parent_function: ConcreteFunctionWithBodyId,
+    #[dont_rewrite]
+    pub wrapper_location: ast::ExprPtr,
+}
+impl ClosureTypeLongId {
+    pub fn format(&self, db: &dyn SemanticGroup) -> String {
+        format!(
+            "{{closure({}) -> {}}}",
+            self.param_tys.iter().map(|ty| ty.format(db)).join(", "),
+            self.ret_ty.format(db)
+        )
+    }
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
 pub enum ConcreteTypeId {
     Struct(ConcreteStructId),
@@ -388,7 +420,7 @@ pub fn type_info(
             .map(|_| ());
             TypeInfo { droppable, duplicatable, destruct_impl }
         }
-        TypeLongId::GenericParameter(_) => {
+        TypeLongId::GenericParameter(_) | TypeLongId::ImplType(_) => {
             let droppable = get_impl_at_context(
                 db,
                 lookup_context.clone(),
@@ -406,7 +438,8 @@ pub fn type_info(
 
             TypeInfo { droppable, duplicatable, destruct_impl }
         }
-        TypeLongId::Tuple(tys) => {
+        TypeLongId::Tuple(tys)
+        | TypeLongId::Closure(ClosureTypeLongId { captured_types: tys, .. }) => {
             let infos = tys
                 .into_iter()
                 .map(|ty| db.type_info(lookup_context.clone(), ty))
@@ -5,7 +5,8 @@ use cairo_lang_sierra::extensions::boxing::BoxConcreteLibfunc;
 use cairo_lang_sierra::extensions::builtin_cost::{
     BuiltinCostConcreteLibfunc, BuiltinCostWithdrawGasLibfunc, CostTokenType,
 };
-use cairo_lang_sierra::extensions::casts::CastConcreteLibfunc;
+use cairo_lang_sierra::extensions::bytes31::Bytes31ConcreteLibfunc;
+use cairo_lang_sierra::extensions::casts::{CastConcreteLibfunc, CastType};
 use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
 use cairo_lang_sierra::extensions::ec::EcConcreteLibfunc;
 use cairo_lang_sierra::extensions::enm::EnumConcreteLibfunc;
@@ -17,12 +18,19 @@ use cairo_lang_sierra::extensions::gas::GasConcreteLibfunc;
 use cairo_lang_sierra::extensions::mem::MemConcreteLibfunc;
 use cairo_lang_sierra::extensions::nullable::NullableConcreteLibfunc;
 use cairo_lang_sierra::extensions::pedersen::PedersenConcreteLibfunc;
+use cairo_lang_sierra::extensions::poseidon::PoseidonConcreteLibfunc;
+use cairo_lang_sierra::extensions::sint::{
+    Sint128Concrete, Sint16Concrete, Sint32Concrete, Sint64Concrete, Sint8Concrete,
+};
+use cairo_lang_sierra::extensions::starknet::testing::TestingConcreteLibfunc;
 use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
 use cairo_lang_sierra::extensions::structure::StructConcreteLibfunc;
 use cairo_lang_sierra::extensions::uint::{
     IntOperator, Uint16Concrete, Uint32Concrete, Uint64Concrete, Uint8Concrete,
 };
 use cairo_lang_sierra::extensions::uint128::Uint128Concrete;
+use cairo_lang_sierra::extensions::uint256::Uint256Concrete;
+use cairo_lang_sierra::extensions::uint512::Uint512Concrete;
 use cairo_lang_sierra::ids::ConcreteTypeId;
 
 use crate::ApChange;
@@ -94,7 +102,16 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
             BuiltinCostConcreteLibfunc::GetBuiltinCosts(_) => vec![ApChange::Known(3)],
         },
         CoreConcreteLibfunc::Cast(libfunc) => match libfunc {
-            CastConcreteLibfunc::Downcast(_) => vec![ApChange::Known(2), ApChange::Known(2)],
+            CastConcreteLibfunc::Downcast(libfunc) => {
+                let CastType { overflow_above, overflow_below } = libfunc.cast_type();
+                let canonical_ap_change = usize::from(!libfunc.to_range.is_unsigned());
+                vec![
+                    ApChange::Known(canonical_ap_change + 1 + usize::from(overflow_above)),
+                    ApChange::Known(
+                        canonical_ap_change + 2 + usize::from(overflow_above && overflow_below),
+                    ),
+                ]
+            }
             CastConcreteLibfunc::Upcast(_) => vec![ApChange::Known(0)],
         },
         CoreConcreteLibfunc::Ec(libfunc) => match libfunc {
@@ -110,6 +127,14 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
             EcConcreteLibfunc::Zero(_) => vec![ApChange::Known(0)],
         },
         CoreConcreteLibfunc::Drop(_) | CoreConcreteLibfunc::Dup(_) => vec![ApChange::Known(0)],
+        CoreConcreteLibfunc::Bytes31(libfunc) => match libfunc {
+            Bytes31ConcreteLibfunc::Const(_) | Bytes31ConcreteLibfunc::ToFelt252(_) => {
+                vec![ApChange::Known(0)]
+            }
+            Bytes31ConcreteLibfunc::TryFromFelt252(_) => {
+                vec![ApChange::Known(5), ApChange::Known(6)]
+            }
+        },
         CoreConcreteLibfunc::Felt252(libfunc) => match libfunc {
             Felt252Concrete::Const(_) => {
                 vec![ApChange::Known(0)]
@@ -224,6 +249,75 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
                 vec![ApChange::Known(0)]
             }
             Uint128Concrete::IsZero(_) => vec![ApChange::Known(0), ApChange::Known(0)],
+            Uint128Concrete::GuaranteeMul(_) => vec![ApChange::Known(2)],
+            Uint128Concrete::MulGuaranteeVerify(_) => vec![ApChange::Known(15)],
+        },
+        CoreConcreteLibfunc::Uint256(libfunc) => match libfunc {
+            Uint256Concrete::IsZero(_) => vec![ApChange::Known(0), ApChange::Known(0)],
+            Uint256Concrete::Divmod(_) => vec![ApChange::Known(21)],
+            Uint256Concrete::SquareRoot(_) => vec![ApChange::Known(30)],
+        },
+        CoreConcreteLibfunc::Uint512(libfunc) => match libfunc {
+            Uint512Concrete::DivModU256(_) => vec![ApChange::Known(45)],
+        },
+        CoreConcreteLibfunc::Sint8(libfunc) => match libfunc {
+            Sint8Concrete::Const(_) | Sint8Concrete::ToFelt252(_) => vec![ApChange::Known(0)],
+            Sint8Concrete::Operation(_) => {
+                vec![ApChange::Known(4), ApChange::Known(6), ApChange::Known(6)]
+            }
+            Sint8Concrete::LessThan(_) => vec![ApChange::Known(2), ApChange::Known(3)],
+            Sint8Concrete::Equal(_) => vec![ApChange::Known(1), ApChange::Known(1)],
+            Sint8Concrete::LessThanOrEqual(_) => vec![ApChange::Known(3), ApChange::Known(2)],
+            Sint8Concrete::FromFelt252(_) => vec![ApChange::Known(3), ApChange::Known(8)],
+            Sint8Concrete::IsZero(_) => vec![ApChange::Known(0), ApChange::Known(0)],
+            Sint8Concrete::WideMul(_) => vec![ApChange::Known(0)],
+        },
+        CoreConcreteLibfunc::Sint16(libfunc) => match libfunc {
+            Sint16Concrete::Const(_) | Sint16Concrete::ToFelt252(_) => vec![ApChange::Known(0)],
+            Sint16Concrete::Operation(_) => {
+                vec![ApChange::Known(4), ApChange::Known(6), ApChange::Known(6)]
+            }
+            Sint16Concrete::LessThan(_) => vec![ApChange::Known(2), ApChange::Known(3)],
+            Sint16Concrete::Equal(_) => vec![ApChange::Known(1), ApChange::Known(1)],
+            Sint16Concrete::LessThanOrEqual(_) => vec![ApChange::Known(3), ApChange::Known(2)],
+            Sint16Concrete::FromFelt252(_) => vec![ApChange::Known(3), ApChange::Known(8)],
+            Sint16Concrete::IsZero(_) => vec![ApChange::Known(0), ApChange::Known(0)],
+            Sint16Concrete::WideMul(_) => vec![ApChange::Known(0)],
+        },
+        CoreConcreteLibfunc::Sint32(libfunc) => match libfunc {
+            Sint32Concrete::Const(_) | Sint32Concrete::ToFelt252(_) => vec![ApChange::Known(0)],
+            Sint32Concrete::Operation(_) => {
+                vec![ApChange::Known(4), ApChange::Known(6), ApChange::Known(6)]
+            }
+            Sint32Concrete::LessThan(_) => vec![ApChange::Known(2), ApChange::Known(3)],
+            Sint32Concrete::Equal(_) => vec![ApChange::Known(1), ApChange::Known(1)],
+            Sint32Concrete::LessThanOrEqual(_) => vec![ApChange::Known(3), ApChange::Known(2)],
+            Sint32Concrete::FromFelt252(_) => vec![ApChange::Known(3), ApChange::Known(8)],
+            Sint32Concrete::IsZero(_) => vec![ApChange::Known(0), ApChange::Known(0)],
+            Sint32Concrete::WideMul(_) => vec![ApChange::Known(0)],
+        },
+        CoreConcreteLibfunc::Sint64(libfunc) => match libfunc {
+            Sint64Concrete::Const(_) | Sint64Concrete::ToFelt252(_) => vec![ApChange::Known(0)],
+            Sint64Concrete::Operation(_) => {
+                vec![ApChange::Known(4), ApChange::Known(6), ApChange::Known(6)]
+            }
+            Sint64Concrete::LessThan(_) => vec![ApChange::Known(2), ApChange::Known(3)],
+            Sint64Concrete::Equal(_) => vec![ApChange::Known(1), ApChange::Known(1)],
+            Sint64Concrete::LessThanOrEqual(_) => vec![ApChange::Known(3), ApChange::Known(2)],
+            Sint64Concrete::FromFelt252(_) => vec![ApChange::Known(3), ApChange::Known(8)],
+            Sint64Concrete::IsZero(_) => vec![ApChange::Known(0), ApChange::Known(0)],
+            Sint64Concrete::WideMul(_) => vec![ApChange::Known(0)],
+        },
+        CoreConcreteLibfunc::Sint128(libfunc) => match libfunc {
+            Sint128Concrete::Const(_) | Sint128Concrete::ToFelt252(_) => vec![ApChange::Known(0)],
+            Sint128Concrete::Operation(_) => {
+                vec![ApChange::Known(3), ApChange::Known(6), ApChange::Known(6)]
+            }
+            Sint128Concrete::LessThan(_) => vec![ApChange::Known(2), ApChange::Known(3)],
+            Sint128Concrete::Equal(_) => vec![ApChange::Known(1), ApChange::Known(1)],
+            Sint128Concrete::LessThanOrEqual(_) => vec![ApChange::Known(3), ApChange::Known(2)],
+            Sint128Concrete::FromFelt252(_) => vec![ApChange::Known(2), ApChange::Known(8)],
+            Sint128Concrete::IsZero(_) => vec![ApChange::Known(0), ApChange::Known(0)],
         },
         CoreConcreteLibfunc::Mem(libfunc) => match libfunc {
             MemConcreteLibfunc::StoreTemp(libfunc) => {
@@ -262,6 +356,9 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
         CoreConcreteLibfunc::Pedersen(libfunc) => match libfunc {
             PedersenConcreteLibfunc::PedersenHash(_) => vec![ApChange::Known(0)],
         },
+        CoreConcreteLibfunc::Poseidon(libfunc) => match libfunc {
+            PoseidonConcreteLibfunc::HadesPermutation(_) => vec![ApChange::Known(0)],
+        },
         CoreConcreteLibfunc::StarkNet(libfunc) => match libfunc {
             StarkNetConcreteLibfunc::ClassHashConst(_)
             | StarkNetConcreteLibfunc::ContractAddressConst(_) => vec![ApChange::Known(0)],
@@ -288,10 +385,25 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
             | StarkNetConcreteLibfunc::Deploy(_)
             | StarkNetConcreteLibfunc::LibraryCall(_)
             | StarkNetConcreteLibfunc::ReplaceClass(_)
-            | StarkNetConcreteLibfunc::SendMessageToL1(_) => {
+            | StarkNetConcreteLibfunc::SendMessageToL1(_)
+            | StarkNetConcreteLibfunc::Keccak(_)
+            | StarkNetConcreteLibfunc::Secp256K1EcAdd(_)
+            | StarkNetConcreteLibfunc::Secp256K1EcMul(_)
+            | StarkNetConcreteLibfunc::Secp256K1EcGetPointFromX(_)
+            | StarkNetConcreteLibfunc::Secp256K1EcGetCoordinates(_) => {
                 vec![ApChange::Known(2), ApChange::Known(2)]
             }
-            StarkNetConcreteLibfunc::Testing(_) => vec![ApChange::Known(0)],
+            StarkNetConcreteLibfunc::Testing(libfunc) => match libfunc {
+                TestingConcreteLibfunc::SetBlockNumber(_)
+                | TestingConcreteLibfunc::SetBlockTimestamp(_)
+                | TestingConcreteLibfunc::SetCallerAddress(_)
+                | TestingConcreteLibfunc::SetContractAddress(_)
+                | TestingConcreteLibfunc::SetSequencerAddress(_) => vec![ApChange::Known(0)],
+                TestingConcreteLibfunc::PopLog(_) => vec![ApChange::Known(5), ApChange::Known(5)],
+                TestingConcreteLibfunc::PopL2ToL1Message(_) => {
+                    vec![ApChange::Known(4), ApChange::Known(4)]
+                }
+            },
         },
         CoreConcreteLibfunc::Nullable(libfunc) => match libfunc {
             NullableConcreteLibfunc::Null(_) => vec![ApChange::Known(0)],
@@ -6,11 +6,13 @@ use cairo_lang_sierra::extensions::boxing::BoxConcreteLibfunc;
 use cairo_lang_sierra::extensions::builtin_cost::{
     BuiltinCostConcreteLibfunc, BuiltinCostWithdrawGasLibfunc, CostTokenType,
 };
-use cairo_lang_sierra::extensions::casts::CastConcreteLibfunc;
+use cairo_lang_sierra::extensions::bytes31::Bytes31ConcreteLibfunc;
+use cairo_lang_sierra::extensions::casts::{CastConcreteLibfunc, CastType};
 use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc::{
-    self, ApTracking, Array, Bitwise, Bool, Box, BranchAlign, BuiltinCost, Cast, Drop, Dup, Ec,
-    Enum, Felt252, Felt252Dict, FunctionCall, Gas, Mem, Pedersen, Struct, Uint128, Uint16, Uint32,
-    Uint64, Uint8, UnconditionalJump, UnwrapNonZero,
+    self, ApTracking, Array, Bitwise, Bool, Box, BranchAlign, BuiltinCost, Bytes31, Cast, Drop,
+    Dup, Ec, Enum, Felt252, Felt252Dict, FunctionCall, Gas, Mem, Pedersen, Poseidon, Sint128,
+    Sint16, Sint32, Sint64, Sint8, Struct, Uint128, Uint16, Uint256, Uint32, Uint512, Uint64,
+    Uint8, UnconditionalJump, UnwrapNonZero,
 };
 use cairo_lang_sierra::extensions::ec::EcConcreteLibfunc;
 use cairo_lang_sierra::extensions::enm::EnumConcreteLibfunc;
@@ -27,11 +29,17 @@ use cairo_lang_sierra::extensions::mem::MemConcreteLibfunc::{
 };
 use cairo_lang_sierra::extensions::nullable::NullableConcreteLibfunc;
 use cairo_lang_sierra::extensions::pedersen::PedersenConcreteLibfunc;
+use cairo_lang_sierra::extensions::poseidon::PoseidonConcreteLibfunc;
+use cairo_lang_sierra::extensions::sint::{
+    Sint128Concrete, Sint16Concrete, Sint32Concrete, Sint64Concrete, Sint8Concrete,
+};
 use cairo_lang_sierra::extensions::structure::StructConcreteLibfunc;
 use cairo_lang_sierra::extensions::uint::{
     IntOperator, Uint16Concrete, Uint32Concrete, Uint64Concrete, Uint8Concrete,
 };
 use cairo_lang_sierra::extensions::uint128::Uint128Concrete;
+use cairo_lang_sierra::extensions::uint256::Uint256Concrete;
+use cairo_lang_sierra::extensions::uint512::Uint512Concrete;
 use cairo_lang_sierra::extensions::ConcreteLibfunc;
 use cairo_lang_sierra::ids::ConcreteTypeId;
 use cairo_lang_sierra::program::Function;
@@ -147,6 +155,11 @@ pub fn core_libfunc_precost<Ops: CostOperations>(
                 vec![ops.cost_token(1, CostTokenType::Pedersen)]
             }
         },
+        Poseidon(libfunc) => match libfunc {
+            PoseidonConcreteLibfunc::HadesPermutation(_) => {
+                vec![ops.cost_token(1, CostTokenType::Poseidon)]
+            }
+        },
         BuiltinCost(BuiltinCostConcreteLibfunc::BuiltinWithdrawGas(_)) => {
             vec![
                 ops.sub(ops.steps(0), statement_vars_cost(ops, CostTokenType::iter_precost())),
@@ -182,10 +195,23 @@ pub fn core_libfunc_postcost<Ops: CostOperations, InfoProvider: InvocationCostIn
 
         Bool(BoolConcreteLibfunc::ToFelt252(_)) => vec![ops.steps(0)],
         Cast(libfunc) => match libfunc {
-            CastConcreteLibfunc::Downcast(_) => {
+            CastConcreteLibfunc::Downcast(libfunc) => {
+                let CastType { overflow_above, overflow_below } = libfunc.cast_type();
+                let canonical_steps = i32::from(!libfunc.to_range.is_unsigned());
                 vec![
-                    ops.add(ops.steps(3), ops.range_checks(1)),
-                    ops.add(ops.steps(4), ops.range_checks(1)),
+                    ops.const_cost(ConstCost {
+                        steps: canonical_steps
+                            + 1
+                            + i32::from(overflow_below)
+                            + 2 * i32::from(overflow_above),
+                        holes: 0,
+                        range_checks: i32::from(overflow_below) + i32::from(overflow_above),
+                    }),
+                    ops.const_cost(ConstCost {
+                        steps: canonical_steps + 4 + i32::from(overflow_above && overflow_below),
+                        holes: 0,
+                        range_checks: 1,
+                    }),
                 ]
             }
             CastConcreteLibfunc::Upcast(_) => vec![ops.steps(0)],
@@ -258,11 +284,27 @@ pub fn core_libfunc_postcost<Ops: CostOperations, InfoProvider: InvocationCostIn
             vec![ops.steps(if info_provider.type_size(&libfunc.ty) == 1 { 0 } else { 1 })]
         }
         Uint128(libfunc) => u128_libfunc_cost(ops, libfunc),
+        Uint256(libfunc) => u256_libfunc_cost(ops, libfunc),
+        Uint512(libfunc) => u512_libfunc_cost(ops, libfunc),
+        Sint8(libfunc) => i8_libfunc_cost(ops, libfunc),
+        Sint16(libfunc) => i16_libfunc_cost(ops, libfunc),
+        Sint32(libfunc) => i32_libfunc_cost(ops, libfunc),
+        Sint64(libfunc) => i64_libfunc_cost(ops, libfunc),
+        Sint128(libfunc) => i128_libfunc_cost(ops, libfunc),
         Uint8(libfunc) => u8_libfunc_cost(ops, libfunc),
         Uint16(libfunc) => u16_libfunc_cost(ops, libfunc),
         Uint32(libfunc) => u32_libfunc_cost(ops, libfunc),
         Uint64(libfunc) => u64_libfunc_cost(ops, libfunc),
         Felt252(libfunc) => felt252_libfunc_cost(ops, libfunc),
+        Bytes31(libfunc) => match libfunc {
+            Bytes31ConcreteLibfunc::Const(_) | Bytes31ConcreteLibfunc::ToFelt252(_) => {
+                vec![ops.steps(0)]
+            }
+            Bytes31ConcreteLibfunc::TryFromFelt252(_) => vec![
+                ops.const_cost(ConstCost { steps: 7, holes: 0, range_checks: 3 }),
+                ops.const_cost(ConstCost { steps: 9, holes: 0, range_checks: 3 }),
+            ],
+        },
         Drop(_) | Dup(_) | ApTracking(_) | UnwrapNonZero(_) | Mem(Rename(_)) => {
             vec![ops.steps(0)]
         }
@@ -293,7 +335,7 @@ pub fn core_libfunc_postcost<Ops: CostOperations, InfoProvider: InvocationCostIn
         Enum(EnumConcreteLibfunc::Match(sig) | EnumConcreteLibfunc::SnapshotMatch(sig)) => {
             let n = sig.signature.branch_signatures.len();
             match n {
-                0 => unreachable!(),
+                0 => vec![],
                 1 => vec![ops.steps(0)],
                 2 => vec![ops.steps(1); 2],
                 _ => chain!(iter::once(ops.steps(1)), itertools::repeat_n(ops.steps(2), n - 1),)
@@ -340,6 +382,9 @@ pub fn core_libfunc_postcost<Ops: CostOperations, InfoProvider: InvocationCostIn
         Pedersen(libfunc) => match libfunc {
             PedersenConcreteLibfunc::PedersenHash(_) => vec![ops.steps(2)],
         },
+        Poseidon(libfunc) => match libfunc {
+            PoseidonConcreteLibfunc::HadesPermutation(_) => vec![ops.steps(3)],
+        },
         BuiltinCost(builtin_libfunc) => match builtin_libfunc {
             BuiltinCostConcreteLibfunc::BuiltinWithdrawGas(_) => {
                 let cost_computation =
@@ -649,6 +694,240 @@ fn u128_libfunc_cost<Ops: CostOperations>(
                 ops.const_cost(ConstCost { steps: 4, holes: 0, range_checks: 1 }),
             ]
         }
+        Uint128Concrete::GuaranteeMul(_) => vec![ops.steps(1)],
+        Uint128Concrete::MulGuaranteeVerify(_) => {
+            vec![ops.const_cost(ConstCost { steps: 23, holes: 0, range_checks: 9 })]
+        }
+    }
+}
+
+/// Returns costs for u256 libfuncs.
+fn u256_libfunc_cost<Ops: CostOperations>(
+    ops: &Ops,
+    libfunc: &Uint256Concrete,
+) -> Vec<Ops::CostType> {
+    match libfunc {
+        Uint256Concrete::IsZero(_) => vec![ops.steps(2), ops.steps(2)],
+        Uint256Concrete::Divmod(_) => {
+            vec![ops.const_cost(ConstCost { steps: 28, holes: 0, range_checks: 6 })]
+        }
+        Uint256Concrete::SquareRoot(_) => {
+            vec![ops.const_cost(ConstCost { steps: 38, holes: 0, range_checks: 11 })]
+        }
+    }
+}
+
+/// Returns costs for u512 libfuncs.
+fn u512_libfunc_cost<Ops: CostOperations>(
+    ops: &Ops,
+    libfunc: &Uint512Concrete,
+) -> Vec<Ops::CostType> {
+    match libfunc {
+        Uint512Concrete::DivModU256(_) => {
+            vec![ops.const_cost(ConstCost { steps: 49, holes: 0, range_checks: 12 })]
+        }
+    }
+}
+
+/// Returns costs for i8 libfuncs.
+fn i8_libfunc_cost<Ops: CostOperations>(ops: &Ops, libfunc: &Sint8Concrete) -> Vec<Ops::CostType> {
+    match libfunc {
+        Sint8Concrete::Const(_) | Sint8Concrete::ToFelt252(_) | Sint8Concrete::WideMul(_) => {
+            vec![ops.steps(0)]
+        }
+        Sint8Concrete::Operation(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 6, holes: 0, range_checks: 2 }),
+                ops.const_cost(ConstCost { steps: 8, holes: 0, range_checks: 1 }),
+                ops.const_cost(ConstCost { steps: 8, holes: 0, range_checks: 1 }),
+            ]
+        }
+        Sint8Concrete::LessThan(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 3, holes: 0, range_checks: 1 }),
+                ops.const_cost(ConstCost { steps: 5, holes: 0, range_checks: 1 }),
+            ]
+        }
+        Sint8Concrete::Equal(_) => {
+            vec![ops.steps(2), ops.steps(3)]
+        }
+        Sint8Concrete::LessThanOrEqual(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 4, holes: 0, range_checks: 1 }),
+                ops.const_cost(ConstCost { steps: 4, holes: 0, range_checks: 1 }),
+            ]
+        }
+        Sint8Concrete::FromFelt252(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 5, holes: 0, range_checks: 2 }),
+                ops.const_cost(ConstCost { steps: 11, holes: 0, range_checks: 3 }),
+            ]
+        }
+        Sint8Concrete::IsZero(_) => vec![ops.steps(1), ops.steps(1)],
+    }
+}
+
+/// Returns costs for i16 libfuncs.
+fn i16_libfunc_cost<Ops: CostOperations>(
+    ops: &Ops,
+    libfunc: &Sint16Concrete,
+) -> Vec<Ops::CostType> {
+    match libfunc {
+        Sint16Concrete::Const(_) | Sint16Concrete::ToFelt252(_) | Sint16Concrete::WideMul(_) => {
+            vec![ops.steps(0)]
+        }
+        Sint16Concrete::Operation(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 6, holes: 0, range_checks: 2 }),
+                ops.const_cost(ConstCost { steps: 8, holes: 0, range_checks: 1 }),
+                ops.const_cost(ConstCost { steps: 8, holes: 0, range_checks: 1 }),
+            ]
+        }
+        Sint16Concrete::LessThan(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 3, holes: 0, range_checks: 1 }),
+                ops.const_cost(ConstCost { steps: 5, holes: 0, range_checks: 1 }),
+            ]
+        }
+        Sint16Concrete::Equal(_) => {
+            vec![ops.steps(2), ops.steps(3)]
+        }
+        Sint16Concrete::LessThanOrEqual(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 4, holes: 0, range_checks: 1 }),
+                ops.const_cost(ConstCost { steps: 4, holes: 0, range_checks: 1 }),
+            ]
+        }
+        Sint16Concrete::FromFelt252(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 5, holes: 0, range_checks: 2 }),
+                ops.const_cost(ConstCost { steps: 11, holes: 0, range_checks: 3 }),
+            ]
+        }
+        Sint16Concrete::IsZero(_) => vec![ops.steps(1), ops.steps(1)],
+    }
+}
+
+/// Returns costs for i32 libfuncs.
+fn i32_libfunc_cost<Ops: CostOperations>(
+    ops: &Ops,
+    libfunc: &Sint32Concrete,
+) -> Vec<Ops::CostType> {
+    match libfunc {
+        Sint32Concrete::Const(_) | Sint32Concrete::ToFelt252(_) | Sint32Concrete::WideMul(_) => {
+            vec![ops.steps(0)]
+        }
+        Sint32Concrete::Operation(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 6, holes: 0, range_checks: 2 }),
+                ops.const_cost(ConstCost { steps: 8, holes: 0, range_checks: 1 }),
+                ops.const_cost(ConstCost { steps: 8, holes: 0, range_checks: 1 }),
+            ]
+        }
+        Sint32Concrete::LessThan(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 3, holes: 0, range_checks: 1 }),
+                ops.const_cost(ConstCost { steps: 5, holes: 0, range_checks: 1 }),
+            ]
+        }
+        Sint32Concrete::Equal(_) => {
+            vec![ops.steps(2), ops.steps(3)]
+        }
+        Sint32Concrete::LessThanOrEqual(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 4, holes: 0, range_checks: 1 }),
+                ops.const_cost(ConstCost { steps: 4, holes: 0, range_checks: 1 }),
+            ]
+        }
+        Sint32Concrete::FromFelt252(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 5, holes: 0, range_checks: 2 }),
+                ops.const_cost(ConstCost { steps: 11, holes: 0, range_checks: 3 }),
+            ]
+        }
+        Sint32Concrete::IsZero(_) => vec![ops.steps(1), ops.steps(1)],
+    }
+}
+
+/// Returns costs for i64 libfuncs.
+fn i64_libfunc_cost<Ops: CostOperations>(
+    ops: &Ops,
+    libfunc: &Sint64Concrete,
+) -> Vec<Ops::CostType> {
+    match libfunc {
+        Sint64Concrete::Const(_) | Sint64Concrete::ToFelt252(_) | Sint64Concrete::WideMul(_) => {
+            vec![ops.steps(0)]
+        }
+        Sint64Concrete::Operation(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 6, holes: 0, range_checks: 2 }),
+                ops.const_cost(ConstCost { steps: 8, holes: 0, range_checks: 1 }),
+                ops.const_cost(ConstCost { steps: 8, holes: 0, range_checks: 1 }),
+            ]
+        }
+        Sint64Concrete::LessThan(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 3, holes: 0, range_checks: 1 }),
+                ops.const_cost(ConstCost { steps: 5, holes: 0, range_checks: 1 }),
+            ]
+        }
+        Sint64Concrete::Equal(_) => {
+            vec![ops.steps(2), ops.steps(3)]
+        }
+        Sint64Concrete::LessThanOrEqual(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 4, holes: 0, range_checks: 1 }),
+                ops.const_cost(ConstCost { steps: 4, holes: 0, range_checks: 1 }),
+            ]
+        }
+        Sint64Concrete::FromFelt252(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 5, holes: 0, range_checks: 2 }),
+                ops.const_cost(ConstCost { steps: 11, holes: 0, range_checks: 3 }),
+            ]
+        }
+        Sint64Concrete::IsZero(_) => vec![ops.steps(1), ops.steps(1)],
+    }
+}
+
+/// Returns costs for i128 libfuncs.
+fn i128_libfunc_cost<Ops: CostOperations>(
+    ops: &Ops,
+    libfunc: &Sint128Concrete,
+) -> Vec<Ops::CostType> {
+    match libfunc {
+        Sint128Concrete::Const(_) | Sint128Concrete::ToFelt252(_) => {
+            vec![ops.steps(0)]
+        }
+        Sint128Concrete::Operation(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 4, holes: 0, range_checks: 1 }),
+                ops.const_cost(ConstCost { steps: 8, holes: 0, range_checks: 1 }),
+                ops.const_cost(ConstCost { steps: 8, holes: 0, range_checks: 1 }),
+            ]
+        }
+        Sint128Concrete::LessThan(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 3, holes: 0, range_checks: 1 }),
+                ops.const_cost(ConstCost { steps: 5, holes: 0, range_checks: 1 }),
+            ]
+        }
+        Sint128Concrete::Equal(_) => {
+            vec![ops.steps(2), ops.steps(3)]
+        }
+        Sint128Concrete::LessThanOrEqual(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 4, holes: 0, range_checks: 1 }),
+                ops.const_cost(ConstCost { steps: 4, holes: 0, range_checks: 1 }),
+            ]
+        }
+        Sint128Concrete::FromFelt252(_) => {
+            vec![
+                ops.const_cost(ConstCost { steps: 3, holes: 0, range_checks: 1 }),
+                ops.const_cost(ConstCost { steps: 11, holes: 0, range_checks: 3 }),
+            ]
+        }
+        Sint128Concrete::IsZero(_) => vec![ops.steps(1), ops.steps(1)],
     }
 }
 
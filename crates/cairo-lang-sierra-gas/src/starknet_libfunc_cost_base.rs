@@ -1,3 +1,4 @@
+use cairo_lang_sierra::extensions::starknet::testing::TestingConcreteLibfunc;
 use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
 
 use crate::core_libfunc_cost_base::{ConstCost, CostOperations};
@@ -41,7 +42,21 @@ pub fn starknet_libfunc_cost_base<Ops: CostOperations>(
         StarkNetConcreteLibfunc::LibraryCall(_) => syscall_cost(ops, 9, 9),
         StarkNetConcreteLibfunc::ReplaceClass(_) => syscall_cost(ops, 6, 6),
         StarkNetConcreteLibfunc::SendMessageToL1(_) => syscall_cost(ops, 8, 8),
-        StarkNetConcreteLibfunc::Testing(_) => vec![ops.steps(1)],
+        StarkNetConcreteLibfunc::Keccak(_) => syscall_cost(ops, 7, 7),
+        StarkNetConcreteLibfunc::Secp256K1EcAdd(_) => syscall_cost(ops, 7, 7),
+        StarkNetConcreteLibfunc::Secp256K1EcMul(_) => syscall_cost(ops, 8, 8),
+        StarkNetConcreteLibfunc::Secp256K1EcGetPointFromX(_) => syscall_cost(ops, 8, 8),
+        StarkNetConcreteLibfunc::Secp256K1EcGetCoordinates(_) => syscall_cost(ops, 6, 6),
+        StarkNetConcreteLibfunc::Testing(libfunc) => match libfunc {
+            TestingConcreteLibfunc::SetBlockNumber(_)
+            | TestingConcreteLibfunc::SetBlockTimestamp(_)
+            | TestingConcreteLibfunc::SetCallerAddress(_)
+            | TestingConcreteLibfunc::SetContractAddress(_)
+            | TestingConcreteLibfunc::SetSequencerAddress(_) => vec![ops.steps(1)],
+            TestingConcreteLibfunc::PopLog(_) | TestingConcreteLibfunc::PopL2ToL1Message(_) => {
+                vec![ops.steps(2), ops.steps(2)]
+            }
+        },
     }
 }
 
@@ -1,5 +1,6 @@
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_lowering::db::LoweringGroup;
+use cairo_lang_lowering::ids::SemanticConcreteFunctionWithBodyIdEx;
 use cairo_lang_semantic::test_utils::setup_test_module;
 use cairo_lang_semantic::ConcreteFunctionWithBodyId;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
@@ -30,7 +31,9 @@ fn contains_cycles_test(inputs: &OrderedHashMap<String, String>) -> OrderedHashM
         .iter()
         .map(|(free_function_id, _)| {
             let function_id =
-                ConcreteFunctionWithBodyId::from_no_generics_free(db, *free_function_id).unwrap();
+                ConcreteFunctionWithBodyId::from_no_generics_free(db, *free_function_id)
+                    .unwrap()
+                    .lowered(db);
             format!(
                 "{}: ap_change={:?}, has_cycles={:?}",
                 free_function_id.name(db),
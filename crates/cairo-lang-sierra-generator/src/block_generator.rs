@@ -2,11 +2,12 @@
 #[path = "block_generator_test.rs"]
 mod test;
 
+use cairo_lang_defs::diagnostic_utils::StableLocationOption;
 use cairo_lang_diagnostics::Maybe;
-use cairo_lang_semantic::items::functions::GenericFunctionId;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use itertools::{chain, enumerate, zip_eq, Itertools};
 use lowering::borrow_check::analysis::StatementLocation;
+use lowering::ids::SemanticFunctionIdEx;
 use lowering::MatchArm;
 use sierra::program;
 use {cairo_lang_lowering as lowering, cairo_lang_sierra as sierra};
@@ -41,6 +42,11 @@ pub fn generate_block_body_code(
     // Process the statements.
     for (i, statement) in block.statements.iter().enumerate() {
         let statement_location = (block_id, i);
+        let location = match statement {
+            lowering::Statement::Call(statement_call) => statement_call.location,
+            _ => source_location(context, &statement.outputs()),
+        };
+        add_location_statement(location, &mut statements);
         statements.extend(generate_statement_code(context, statement, &statement_location)?);
         let drop_location = &DropLocation::PostStatement(statement_location);
         add_drop_statements(context, drops, drop_location, &mut statements)?;
@@ -56,6 +62,27 @@ pub fn generate_block_body_code(
     Ok(statements)
 }
 
+/// Returns the location in the Cairo source code of the first of the given variables, if any.
+fn source_location(
+    context: &ExprGeneratorContext<'_>,
+    vars: &[lowering::VariableId],
+) -> StableLocationOption {
+    match vars.first() {
+        Some(var) => context.get_var_location(*var),
+        None => StableLocationOption::None,
+    }
+}
+
+/// Adds a location statement for the statements that follow, if the location is known.
+fn add_location_statement(
+    location: StableLocationOption,
+    statements: &mut Vec<pre_sierra::Statement>,
+) {
+    if let StableLocationOption::Some(location) = location {
+        statements.push(pre_sierra::Statement::Location(location));
+    }
+}
+
 /// Adds calls to the `drop` libfunc for the given [DropLocation], according to the `drops`
 /// argument (computed by [find_variable_lifetime](crate::lifetime::find_variable_lifetime)).
 fn add_drop_statements(
@@ -93,6 +120,7 @@ pub fn generate_block_code(
     let mut statements = generate_block_body_code(context, block_id, block)?;
     match &block.end {
         lowering::FlatBlockEnd::Return(returned_variables) => {
+            add_location_statement(source_location(context, returned_variables), &mut statements);
             statements.extend(generate_return_code(
                 context,
                 returned_variables,
@@ -127,6 +155,11 @@ pub fn generate_block_code(
         // Process the block end if it's a match.
         lowering::FlatBlockEnd::Match { info } => {
             let statement_location = (block_id, block.statements.len());
+            let location = match info {
+                lowering::MatchInfo::Extern(s) => s.location,
+                lowering::MatchInfo::Enum(s) => source_location(context, &[s.input]),
+            };
+            add_location_statement(location, &mut statements);
             statements.extend(match info {
                 lowering::MatchInfo::Extern(s) => {
                     generate_match_extern_code(context, s, &statement_location)?
@@ -143,11 +176,13 @@ pub fn generate_block_code(
 /// Generates a push_values statement that corresponds to `remapping`.
 fn generate_push_values_statement_for_remapping(
     context: &mut ExprGeneratorContext<'_>,
-    _statement_location: (lowering::BlockId, usize),
+    statement_location: (lowering::BlockId, usize),
     remapping: &lowering::VarRemapping,
 ) -> Maybe<pre_sierra::Statement> {
     let mut push_values = Vec::<pre_sierra::PushValue>::new();
-    for (output, inner_output) in remapping.iter() {
+    for (idx, (output, inner_output)) in remapping.iter().enumerate() {
+        let use_location = UseLocation { statement_location, idx };
+        let should_dup = should_dup(context, &use_location);
         let ty = context.get_variable_sierra_type(*inner_output)?;
         let var_on_stack_ty = context.get_variable_sierra_type(*output)?;
         assert_eq!(
@@ -158,7 +193,7 @@ fn generate_push_values_statement_for_remapping(
             var: context.get_sierra_variable(*inner_output),
             var_on_stack: context.get_sierra_variable(*output),
             ty,
-            dup: false,
+            dup: should_dup,
         })
     }
     Ok(pre_sierra::Statement::PushValues(push_values))
@@ -208,6 +243,9 @@ pub fn generate_statement_code(
         lowering::Statement::Literal(statement_literal) => {
             generate_statement_literal_code(context, statement_literal)
         }
+        lowering::Statement::Const(_) => {
+            unreachable!("Constants should have been expanded in a previous phase.")
+        }
         lowering::Statement::Call(statement_call) => {
             generate_statement_call_code(context, statement_call, statement_location)
         }
@@ -257,11 +295,10 @@ fn generate_statement_call_code(
     let outputs = context.get_sierra_variables(&statement.outputs);
 
     // Check if this is a user defined function or a libfunc.
-    let (function_long_id, libfunc_id) =
-        get_concrete_libfunc_id(context.get_db(), statement.function);
+    let (body, libfunc_id) = get_concrete_libfunc_id(context.get_db(), statement.function)?;
 
-    match function_long_id.generic_function {
-        GenericFunctionId::Free(_) | GenericFunctionId::Impl(_) => {
+    match body {
+        Some(_) => {
             // Create [pre_sierra::PushValue] instances for the arguments.
             let mut args_on_stack: Vec<sierra::ids::VarId> = vec![];
             let mut push_values_vec: Vec<pre_sierra::PushValue> = vec![];
@@ -288,7 +325,7 @@ fn generate_statement_call_code(
                 simple_statement(libfunc_id, &args_on_stack, &outputs),
             ])
         }
-        GenericFunctionId::Extern(_) => {
+        None => {
             // Dup variables as needed.
             let mut statements: Vec<pre_sierra::Statement> = vec![];
             let inputs_after_dup = maybe_add_dup_statements(
@@ -387,8 +424,10 @@ fn generate_match_extern_code(
     let args =
         maybe_add_dup_statements(context, statement_location, &match_info.inputs, &mut statements)?;
     // Get the [ConcreteLibfuncId].
-    let (_function_long_id, libfunc_id) =
-        get_concrete_libfunc_id(context.get_db(), match_info.function);
+    let (_body, libfunc_id) = get_concrete_libfunc_id(
+        context.get_db(),
+        match_info.function.lowered(context.get_db().upcast()),
+    )?;
     // Call the match libfunc.
     statements.push(pre_sierra::Statement::Sierra(program::GenStatement::Invocation(
         program::GenInvocation { libfunc_id, args, branches },
@@ -494,14 +533,19 @@ fn generate_match_enum_code(
     // Generate a label for the end of the match.
     let (end_label, _) = context.new_label();
 
-    // Create the arm branches.
-    let arm_targets: Vec<program::GenBranchTarget<pre_sierra::LabelId>> = chain!(
-        [program::GenBranchTarget::Fallthrough],
-        arm_labels
-            .iter()
-            .map(|(_statement, label_id)| program::GenBranchTarget::Statement(*label_id)),
-    )
-    .collect();
+    // Create the arm branches. A match on an empty enum (e.g. `never`) has no branches.
+    let arm_targets: Vec<program::GenBranchTarget<pre_sierra::LabelId>> =
+        if match_info.arms.is_empty() {
+            vec![]
+        } else {
+            chain!(
+                [program::GenBranchTarget::Fallthrough],
+                arm_labels
+                    .iter()
+                    .map(|(_statement, label_id)| program::GenBranchTarget::Statement(*label_id)),
+            )
+            .collect()
+        };
 
     let branches: Vec<_> = zip_eq(&match_info.arms, arm_targets)
         .map(|(arm, target)| program::GenBranchInfo {
@@ -574,15 +618,16 @@ fn generate_statement_snapshot(
 fn generate_statement_desnap(
     context: &mut ExprGeneratorContext<'_>,
     statement: &lowering::StatementDesnap,
-    _statement_location: &StatementLocation,
+    statement_location: &StatementLocation,
 ) -> Maybe<Vec<pre_sierra::Statement>> {
     let mut statements: Vec<pre_sierra::Statement> = vec![];
 
-    let input = context.get_sierra_variable(statement.input);
+    let inputs_after_dup =
+        maybe_add_dup_statements(context, statement_location, &[statement.input], &mut statements)?;
 
     statements.push(simple_statement(
         rename_libfunc_id(context.get_db(), context.get_variable_sierra_type(statement.input)?),
-        &[input],
+        &inputs_after_dup,
         &[context.get_sierra_variable(statement.output)],
     ));
     Ok(statements)
@@ -1,6 +1,7 @@
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_lowering as lowering;
 use cairo_lang_lowering::db::LoweringGroup;
+use cairo_lang_lowering::ids::SemanticConcreteFunctionWithBodyIdEx;
 use cairo_lang_lowering::BlockId;
 use cairo_lang_semantic::test_utils::setup_test_function;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
@@ -10,6 +11,7 @@ use lowering::fmt::LoweredFormatter;
 use super::generate_block_code;
 use crate::expr_generator_context::ExprGeneratorContext;
 use crate::lifetime::find_variable_lifetime;
+use crate::pre_sierra;
 use crate::replace_ids::replace_sierra_ids;
 use crate::test_utils::SierraGenDatabaseForTesting;
 
@@ -41,8 +43,9 @@ fn block_generator_test(inputs: &OrderedHashMap<String, String>) -> OrderedHashM
     // Lower code.
     let lowering_diagnostics =
         db.function_with_body_lowering_diagnostics(test_function.function_id).unwrap();
-    let lowered =
-        db.concrete_function_with_body_lowered(test_function.concrete_function_id).unwrap();
+    let lowered = db
+        .concrete_function_with_body_lowered(test_function.concrete_function_id.lowered(db))
+        .unwrap();
 
     if lowered.blocks.is_empty() {
         return OrderedHashMap::from([
@@ -56,13 +59,20 @@ fn block_generator_test(inputs: &OrderedHashMap<String, String>) -> OrderedHashM
     // Generate (pre-)Sierra statements.
     let lifetime = find_variable_lifetime(&lowered, &OrderedHashSet::default())
         .expect("Failed to retrieve lifetime information.");
-    let mut expr_generator_context =
-        ExprGeneratorContext::new(db, &lowered, test_function.concrete_function_id, &lifetime);
+    let mut expr_generator_context = ExprGeneratorContext::new(
+        db,
+        &lowered,
+        test_function.concrete_function_id.lowered(db),
+        &lifetime,
+    );
 
     let mut expected_sierra_code = String::default();
 
     let statements = generate_block_code(&mut expr_generator_context, BlockId::root()).unwrap();
     for statement in &statements {
+        if matches!(statement, pre_sierra::Statement::Location(_)) {
+            continue;
+        }
         expected_sierra_code.push_str(&replace_sierra_ids(db, statement).to_string());
         expected_sierra_code.push('\n');
     }
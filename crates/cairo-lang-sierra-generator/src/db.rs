@@ -3,14 +3,13 @@ use std::sync::Arc;
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_lowering::db::LoweringGroup;
+use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
 use cairo_lang_lowering::panic::PanicSignatureInfo;
 use cairo_lang_semantic as semantic;
-use cairo_lang_semantic::ConcreteFunctionWithBodyId;
 use cairo_lang_sierra::extensions::lib_func::SierraApChange;
 use cairo_lang_sierra::extensions::{ConcreteType, GenericTypeEx};
 use cairo_lang_sierra::ids::ConcreteTypeId;
 use cairo_lang_utils::Upcast;
-use semantic::Mutability;
 
 use crate::program_generator::{self};
 use crate::specialization_context::SierraSignatureSpecializationContext;
@@ -33,13 +32,13 @@ pub trait SierraGenGroup: LoweringGroup + Upcast<dyn LoweringGroup> {
         id: cairo_lang_sierra::program::ConcreteTypeLongId,
     ) -> cairo_lang_sierra::ids::ConcreteTypeId;
 
-    /// Creates a Sierra function id for a function id of the semantic model.
+    /// Creates a Sierra function id for a lowering function id.
     // TODO(lior): Can we have the short and long ids in the same place? Currently, the short
-    //   id is defined in sierra and the long id is defined in semantic.
+    //   id is defined in sierra and the long id is defined in lowering.
     #[salsa::interned]
     fn intern_sierra_function(
         &self,
-        id: semantic::FunctionId,
+        id: cairo_lang_lowering::ids::FunctionId,
     ) -> cairo_lang_sierra::ids::FunctionId;
 
     /// Returns the matching sierra concrete type id for a given semantic type id.
@@ -84,15 +83,31 @@ pub trait SierraGenGroup: LoweringGroup + Upcast<dyn LoweringGroup> {
     #[salsa::invoke(program_generator::get_sierra_program_for_functions)]
     fn get_sierra_program_for_functions(
         &self,
-        requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
+        requested_function_ids: Vec<semantic::ConcreteFunctionWithBodyId>,
     ) -> Maybe<Arc<cairo_lang_sierra::program::Program>>;
 
+    /// Returns the [cairo_lang_sierra::program::Program] object of the requested functions, along
+    /// with its debug info, including the locations in the Cairo source code of its statements.
+    #[salsa::invoke(program_generator::get_sierra_program_with_debug_info_for_functions)]
+    fn get_sierra_program_with_debug_info_for_functions(
+        &self,
+        requested_function_ids: Vec<semantic::ConcreteFunctionWithBodyId>,
+    ) -> Maybe<Arc<program_generator::SierraProgramWithDebugInfo>>;
+
     /// Returns the [cairo_lang_sierra::program::Program] object of the requested crates.
     #[salsa::invoke(program_generator::get_sierra_program)]
     fn get_sierra_program(
         &self,
         requested_crate_ids: Vec<CrateId>,
     ) -> Maybe<Arc<cairo_lang_sierra::program::Program>>;
+
+    /// Returns the [cairo_lang_sierra::program::Program] object of the requested crates, along
+    /// with its debug info, including the locations in the Cairo source code of its statements.
+    #[salsa::invoke(program_generator::get_sierra_program_with_debug_info)]
+    fn get_sierra_program_with_debug_info(
+        &self,
+        requested_crate_ids: Vec<CrateId>,
+    ) -> Maybe<Arc<program_generator::SierraProgramWithDebugInfo>>;
 }
 
 fn get_function_signature(
@@ -104,12 +119,12 @@ fn get_function_signature(
     // of only the explicit ret_type. Also use it for params instead of the current logic. Then use
     // it in the end of program_generator::get_sierra_program instead of calling this function from
     // there.
-    let semantic_function_id = db.lookup_intern_sierra_function(function_id);
-    let signature = db.concrete_function_signature(semantic_function_id)?;
-    let may_panic = db.function_may_panic(semantic_function_id)?;
+    let lowered_function_id = db.lookup_intern_sierra_function(function_id);
+    let signature = lowered_function_id.signature(db.upcast())?;
+    let may_panic = db.function_may_panic(lowered_function_id)?;
 
     let implicits = db
-        .function_all_implicits(semantic_function_id)?
+        .function_all_implicits(lowered_function_id)?
         .iter()
         .map(|ty| db.get_concrete_type_id(*ty))
         .collect::<Maybe<Vec<ConcreteTypeId>>>()?;
@@ -118,11 +133,10 @@ fn get_function_signature(
     let mut all_params = implicits.clone();
     let mut ref_types = vec![];
     for param in &signature.params {
-        let concrete_type_id = db.get_concrete_type_id(param.ty)?;
-        all_params.push(concrete_type_id.clone());
-        if param.mutability == Mutability::Reference {
-            ref_types.push(concrete_type_id);
-        }
+        all_params.push(db.get_concrete_type_id(param.ty())?);
+    }
+    for var in &signature.extra_rets {
+        ref_types.push(db.get_concrete_type_id(var.ty())?);
     }
 
     // TODO(ilya): Handle tuple and struct types.
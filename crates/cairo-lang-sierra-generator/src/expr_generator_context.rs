@@ -1,6 +1,8 @@
+use cairo_lang_defs::diagnostic_utils::StableLocationOption;
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_lowering as lowering;
-use cairo_lang_semantic::{ConcreteFunctionWithBodyId, TypeId};
+use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
+use cairo_lang_semantic::TypeId;
 use cairo_lang_sierra::extensions::uninitialized::UninitializedType;
 use cairo_lang_sierra::extensions::NamedType;
 use cairo_lang_sierra::program::{ConcreteTypeLongId, GenericArg};
@@ -141,6 +143,11 @@ impl<'a> ExprGeneratorContext<'a> {
     pub fn get_var_type(&self, var_id: VariableId) -> TypeId {
         self.lowered.variables[var_id].ty
     }
+
+    /// Returns the location of the variable given by `var_id`.
+    pub fn get_var_location(&self, var_id: VariableId) -> StableLocationOption {
+        self.lowered.variables[var_id].location
+    }
 }
 
 /// A variant of ExprGeneratorContext::alloc_label_id that allows the caller to avoid
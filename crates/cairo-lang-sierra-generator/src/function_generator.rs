@@ -5,12 +5,12 @@ mod test;
 use std::sync::Arc;
 
 use cairo_lang_diagnostics::Maybe;
-use cairo_lang_semantic::ConcreteFunctionWithBodyId;
+use cairo_lang_lowering as lowering;
+use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
 use cairo_lang_sierra::ids::ConcreteLibfuncId;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use lowering::BlockId;
-use {cairo_lang_lowering as lowering, cairo_lang_semantic as semantic};
 
 use crate::block_generator::generate_block_code;
 use crate::db::SierraGenGroup;
@@ -49,7 +49,7 @@ fn get_function_code(
     db: &dyn SierraGenGroup,
     function_id: ConcreteFunctionWithBodyId,
 ) -> Maybe<Arc<pre_sierra::Function>> {
-    let signature = db.concrete_function_signature(function_id.function_id(db.upcast())?)?;
+    let signature = function_id.signature(db.upcast())?;
     let lowered_function = &*db.concrete_function_with_body_lowered(function_id)?;
     lowered_function.blocks.has_root()?;
 
@@ -97,12 +97,8 @@ fn get_function_code(
         sierra_local_variables,
     );
 
-    // TODO(spapini): Don't intern objects for the semantic model outside the crate. These should
-    // be regarded as private.
     Ok(pre_sierra::Function {
-        id: db.intern_sierra_function(db.intern_function(semantic::FunctionLongId {
-            function: function_id.concrete(db.upcast())?,
-        })),
+        id: db.intern_sierra_function(function_id.function_id(db.upcast())?),
         prolog_size,
         body: statements,
         entry_point: label_id,
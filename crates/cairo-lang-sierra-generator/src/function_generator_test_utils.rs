@@ -1,8 +1,10 @@
 use cairo_lang_lowering::db::LoweringGroup;
+use cairo_lang_lowering::ids::SemanticConcreteFunctionWithBodyIdEx;
 use cairo_lang_semantic::test_utils::setup_test_function;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 
 use crate::db::SierraGenGroup;
+use crate::pre_sierra;
 use crate::replace_ids::replace_sierra_ids;
 use crate::test_utils::SierraGenDatabaseForTesting;
 
@@ -24,10 +26,11 @@ pub fn test_function_generator(
     let lowering_diagnostics = db.module_lowering_diagnostics(test_function.module_id);
 
     // Compile the function.
-    let function = db.function_with_body_sierra(test_function.concrete_function_id);
+    let function = db.function_with_body_sierra(test_function.concrete_function_id.lowered(db));
     let sierra_code: String = function.map_or("None".into(), |func| {
         func.body
             .iter()
+            .filter(|x| !matches!(x, pre_sierra::Statement::Location(_)))
             .map(|x| replace_sierra_ids(db, x).to_string())
             .collect::<Vec<String>>()
             .join("\n")
@@ -99,7 +99,11 @@ pub fn find_variable_lifetime(
     local_vars: &OrderedHashSet<VariableId>,
 ) -> Maybe<VariableLifetimeResult> {
     lowered_function.blocks.has_root()?;
-    let context = VariableLifetimeContext { local_vars, res: VariableLifetimeResult::default() };
+    let context = VariableLifetimeContext {
+        lowered_function,
+        local_vars,
+        res: VariableLifetimeResult::default(),
+    };
     let mut analysis =
         BackAnalysis { lowered: lowered_function, cache: Default::default(), analyzer: context };
 
@@ -117,6 +121,7 @@ pub fn find_variable_lifetime(
 
 /// Context information for [find_variable_lifetime] and its helper functions.
 struct VariableLifetimeContext<'a> {
+    lowered_function: &'a FlatLowered,
     local_vars: &'a OrderedHashSet<VariableId>,
     res: VariableLifetimeResult,
 }
@@ -157,10 +162,21 @@ impl<'a> Analyzer for VariableLifetimeContext<'a> {
     fn visit_remapping(
         &mut self,
         info: &mut Self::Info,
-        _block_id: BlockId,
+        block_id: BlockId,
         _target_block_id: BlockId,
         remapping: &lowering::VarRemapping,
     ) {
+        // The remapping is at the end of the block, and a source variable may still be used
+        // afterwards (or be remapped more than once), so its last use is reported explicitly.
+        let statement_location =
+            (block_id, self.lowered_function.blocks[block_id].statements.len());
+        for (idx, (_dst, src)) in remapping.iter().enumerate() {
+            let used_later = info.vars.contains(&SierraGenVar::from(*src))
+                || remapping.iter().skip(idx + 1).any(|(_dst, other_src)| other_src == src);
+            if !used_later {
+                self.last_use(statement_location, idx, SierraGenVar::from(*src));
+            }
+        }
         info.apply_remapping(self, remapping.iter().map(|(dst, src)| (*dst, *src)));
         for (dst, _src) in remapping.iter() {
             if self.local_vars.contains(dst) {
@@ -17,6 +17,7 @@ use itertools::{zip_eq, Itertools};
 use lowering::borrow_check::analysis::{Analyzer, BackAnalysis, StatementLocation};
 use lowering::borrow_check::demand::DemandReporter;
 use lowering::borrow_check::Demand;
+use lowering::ids::SemanticFunctionIdEx;
 use lowering::{FlatLowered, MatchInfo, Statement, VarRemapping};
 
 use crate::db::SierraGenGroup;
@@ -232,9 +233,12 @@ impl<'a> FindLocalsContext<'a> {
                 self.prune_from_locals.insert(statement_literal.output);
                 BranchInfo { known_ap_change: true }
             }
+            lowering::Statement::Const(_) => {
+                unreachable!("Constants should have been expanded in a previous phase.")
+            }
             lowering::Statement::Call(statement_call) => {
                 let (_, concrete_function_id) =
-                    get_concrete_libfunc_id(self.db, statement_call.function);
+                    get_concrete_libfunc_id(self.db, statement_call.function)?;
 
                 self.analyze_call(concrete_function_id, &inputs, &outputs)
             }
@@ -287,7 +291,8 @@ impl<'a> FindLocalsContext<'a> {
     fn get_match_libfunc_signature(&self, match_info: &MatchInfo) -> Maybe<LibfuncSignature> {
         Ok(match match_info {
             MatchInfo::Extern(s) => {
-                let (_, concrete_function_id) = get_concrete_libfunc_id(self.db, s.function);
+                let (_, concrete_function_id) =
+                    get_concrete_libfunc_id(self.db, s.function.lowered(self.db.upcast()))?;
                 get_libfunc_signature(self.db, concrete_function_id)
             }
             MatchInfo::Enum(s) => {
@@ -1,4 +1,5 @@
-use cairo_lang_semantic::ConcreteFunctionWithBodyId;
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
 use cairo_lang_sierra as sierra;
 use cairo_lang_sierra::ids::ConcreteTypeId;
 use cairo_lang_sierra::program;
@@ -54,6 +55,9 @@ pub enum Statement {
     /// Note that push values does not guarantee that new copies of the values will be pushed.
     /// If a prefix of the values is already on the stack, they will not be re-pushed.
     PushValues(Vec<PushValue>),
+    /// The location in the Cairo source code of the statements that follow, until the next
+    /// location statement. Does not generate any Sierra statement.
+    Location(StableLocation),
 }
 impl std::fmt::Display for Statement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -75,6 +79,7 @@ impl std::fmt::Display for Statement {
                 )?;
                 write!(f, ")")
             }
+            Statement::Location(location) => write!(f, "// Location: {:?}", location.stable_ptr),
         }
     }
 }
@@ -1,9 +1,12 @@
-use std::collections::{HashSet, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 
+use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_diagnostics::{skip_diagnostic, Maybe, ToMaybe};
 use cairo_lang_filesystem::ids::CrateId;
-use cairo_lang_semantic::ConcreteFunctionWithBodyId;
+use cairo_lang_lowering::ids::{ConcreteFunctionWithBodyId, SemanticConcreteFunctionWithBodyIdEx};
+use cairo_lang_semantic as semantic;
+use cairo_lang_sierra::debug_info::{DebugInfo, SourceLocation};
 use cairo_lang_sierra::extensions::core::CoreLibfunc;
 use cairo_lang_sierra::extensions::lib_func::SierraApChange;
 use cairo_lang_sierra::extensions::GenericLibfuncEx;
@@ -11,6 +14,7 @@ use cairo_lang_sierra::ids::{ConcreteLibfuncId, ConcreteTypeId};
 use cairo_lang_sierra::program;
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::try_extract_matches;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
 use itertools::chain;
 
@@ -53,7 +57,8 @@ fn collect_used_libfuncs(
                 Some(invocation.libfunc_id.clone())
             }
             pre_sierra::Statement::Sierra(program::GenStatement::Return(_))
-            | pre_sierra::Statement::Label(_) => None,
+            | pre_sierra::Statement::Label(_)
+            | pre_sierra::Statement::Location(_) => None,
             pre_sierra::Statement::PushValues(_) => {
                 panic!("Unexpected pre_sierra::Statement::PushValues in collect_used_libfuncs().")
             }
@@ -131,15 +136,70 @@ fn collect_used_types(
         .collect()
 }
 
+/// A Sierra program, with its debug info - including the locations in the Cairo source code of
+/// its statements.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SierraProgramWithDebugInfo {
+    pub program: program::Program,
+    pub debug_info: DebugInfo,
+}
+
+/// Query implementation of [SierraGenGroup::get_sierra_program_for_functions].
 pub fn get_sierra_program_for_functions(
     db: &dyn SierraGenGroup,
-    requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
+    requested_function_ids: Vec<semantic::ConcreteFunctionWithBodyId>,
 ) -> Maybe<Arc<cairo_lang_sierra::program::Program>> {
+    let (program, _) = generate_program(db, requested_function_ids)?;
+    Ok(Arc::new(program))
+}
+
+/// Query implementation of [SierraGenGroup::get_sierra_program_with_debug_info_for_functions].
+pub fn get_sierra_program_with_debug_info_for_functions(
+    db: &dyn SierraGenGroup,
+    requested_function_ids: Vec<semantic::ConcreteFunctionWithBodyId>,
+) -> Maybe<Arc<SierraProgramWithDebugInfo>> {
+    let (program, locations) = generate_program(db, requested_function_ids)?;
+    let mut source_locations = UnorderedHashMap::<StableLocation, SourceLocation>::default();
+    let mut statement_locations = HashMap::new();
+    for (idx, location) in locations.into_iter().enumerate() {
+        let Some(location) = location else { continue };
+        let source_location = source_locations
+            .entry(location)
+            .or_insert_with(|| get_source_location(db, location))
+            .clone();
+        statement_locations.insert(program::StatementIdx(idx), source_location);
+    }
+    let debug_info = DebugInfo {
+        statement_locations,
+        ..DebugInfo::extract(&DebugReplacer { db }.apply(&program))
+    };
+    Ok(Arc::new(SierraProgramWithDebugInfo { program, debug_info }))
+}
+
+/// Returns the [SourceLocation] of the given [StableLocation].
+fn get_source_location(db: &dyn SierraGenGroup, location: StableLocation) -> SourceLocation {
+    let location = location.diagnostic_location(db.upcast());
+    let position = location.span.start.position_in_file(db.upcast(), location.file_id);
+    SourceLocation {
+        file_name: location.file_id.file_name(db.upcast()).into(),
+        line: position.as_ref().map_or(0, |position| position.line + 1),
+        col: position.as_ref().map_or(0, |position| position.col + 1),
+    }
+}
+
+/// Generates the Sierra program of the requested functions, along with the location of each of
+/// its statements, if known.
+fn generate_program(
+    db: &dyn SierraGenGroup,
+    requested_function_ids: Vec<semantic::ConcreteFunctionWithBodyId>,
+) -> Maybe<(program::Program, Vec<Option<StableLocation>>)> {
     let mut functions: Vec<Arc<pre_sierra::Function>> = vec![];
     let mut statements: Vec<pre_sierra::Statement> = vec![];
     let mut processed_function_ids = UnorderedHashSet::<ConcreteFunctionWithBodyId>::default();
-    let mut function_id_queue: VecDeque<ConcreteFunctionWithBodyId> =
-        requested_function_ids.into_iter().collect();
+    let mut function_id_queue: VecDeque<ConcreteFunctionWithBodyId> = requested_function_ids
+        .into_iter()
+        .map(|function_id| function_id.lowered(db.upcast()))
+        .collect();
     while let Some(function_id) = function_id_queue.pop_front() {
         if !processed_function_ids.insert(function_id) {
             continue;
@@ -150,7 +210,10 @@ pub fn get_sierra_program_for_functions(
         if !matches!(db.get_ap_change(function_id), Ok(SierraApChange::Known { .. })) {
             // If AP change is unknown for the function, adding a disable so that AP balancing would
             // not occur.
-            if function.body.get(function.prolog_size)
+            let first_statement = function.body[function.prolog_size..]
+                .iter()
+                .find(|statement| !matches!(statement, pre_sierra::Statement::Location(_)));
+            if first_statement
                 != Some(&simple_statement(revoke_ap_tracking_libfunc_id(db), &[], &[]))
             {
                 statements.push(simple_statement(disable_ap_tracking_libfunc_id(db), &[], &[]));
@@ -168,11 +231,12 @@ pub fn get_sierra_program_for_functions(
         generate_libfunc_declarations(db, collect_used_libfuncs(&statements).iter());
     let type_declarations =
         generate_type_declarations(db, collect_used_types(db, &libfunc_declarations).iter());
+    let locations = get_statement_locations(&statements, &functions);
     // Resolve labels.
     let label_replacer = LabelReplacer::from_statements(&statements);
     let resolved_statements = resolve_labels(statements, &label_replacer);
 
-    Ok(Arc::new(program::Program {
+    let program = program::Program {
         type_declarations,
         libfunc_declarations,
         statements: resolved_statements,
@@ -188,7 +252,36 @@ pub fn get_sierra_program_for_functions(
                 )
             })
             .collect(),
-    }))
+    };
+    Ok((program, locations))
+}
+
+/// Returns the location of each of the Sierra statements generated from the given pre-Sierra
+/// statements, according to the location statements preceding it in the same function.
+fn get_statement_locations(
+    statements: &[pre_sierra::Statement],
+    functions: &[Arc<pre_sierra::Function>],
+) -> Vec<Option<StableLocation>> {
+    let entry_points: UnorderedHashSet<pre_sierra::LabelId> =
+        functions.iter().map(|function| function.entry_point).collect();
+    let mut locations = vec![];
+    let mut current_location = None;
+    for statement in statements {
+        match statement {
+            pre_sierra::Statement::Sierra(_) => locations.push(current_location),
+            pre_sierra::Statement::Label(label) => {
+                // The locations of a function do not apply to the following function.
+                if entry_points.contains(&label.id) {
+                    current_location = None;
+                }
+            }
+            pre_sierra::Statement::Location(location) => current_location = Some(*location),
+            pre_sierra::Statement::PushValues(_) => {
+                panic!("Unexpected pre_sierra::Statement::PushValues in get_statement_locations().")
+            }
+        }
+    }
+    locations
 }
 
 /// Tries extracting a ConcreteFunctionWithBodyId from a pre-Sierra statement.
@@ -205,37 +298,53 @@ fn try_get_function_with_body_id(
     if libfunc.generic_id != "function_call".into() {
         return Err(skip_diagnostic());
     }
-    let function = db
-        .lookup_intern_function(
-            db.lookup_intern_sierra_function(
-                try_extract_matches!(
-                    libfunc.generic_args.get(0).to_maybe()?,
-                    cairo_lang_sierra::program::GenericArg::UserFunc
-                )
-                .to_maybe()?
-                .clone(),
-            ),
+    let function = db.lookup_intern_sierra_function(
+        try_extract_matches!(
+            libfunc.generic_args.get(0).to_maybe()?,
+            cairo_lang_sierra::program::GenericArg::UserFunc
         )
-        .function;
-    function.get_body(db.upcast())?.ok_or_else(skip_diagnostic)
+        .to_maybe()?
+        .clone(),
+    );
+    function.body(db.upcast())?.ok_or_else(skip_diagnostic)
 }
 
 pub fn get_sierra_program(
     db: &dyn SierraGenGroup,
     requested_crate_ids: Vec<CrateId>,
 ) -> Maybe<Arc<cairo_lang_sierra::program::Program>> {
+    db.get_sierra_program_for_functions(find_crate_functions(db, requested_crate_ids)?)
+}
+
+/// Returns the functions of the given crates to generate a Sierra program for.
+fn find_crate_functions(
+    db: &dyn SierraGenGroup,
+    requested_crate_ids: Vec<CrateId>,
+) -> Maybe<Vec<semantic::ConcreteFunctionWithBodyId>> {
     let mut requested_function_ids = vec![];
     for crate_id in requested_crate_ids {
         for module_id in db.crate_modules(crate_id).iter() {
             for (free_func_id, _) in db.module_free_functions(*module_id)? {
                 // TODO(spapini): Search Impl functions.
-                if let Some(function) =
-                    ConcreteFunctionWithBodyId::from_no_generics_free(db.upcast(), free_func_id)
-                {
+                if let Some(function) = semantic::ConcreteFunctionWithBodyId::from_no_generics_free(
+                    db.upcast(),
+                    free_func_id,
+                ) {
                     requested_function_ids.push(function)
                 }
             }
         }
     }
-    db.get_sierra_program_for_functions(requested_function_ids)
+    Ok(requested_function_ids)
+}
+
+/// Query implementation of [SierraGenGroup::get_sierra_program_with_debug_info].
+pub fn get_sierra_program_with_debug_info(
+    db: &dyn SierraGenGroup,
+    requested_crate_ids: Vec<CrateId>,
+) -> Maybe<Arc<SierraProgramWithDebugInfo>> {
+    db.get_sierra_program_with_debug_info_for_functions(find_crate_functions(
+        db,
+        requested_crate_ids,
+    )?)
 }
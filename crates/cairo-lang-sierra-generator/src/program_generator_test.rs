@@ -2,6 +2,7 @@ use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::ModuleItemId;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::ConcreteFunctionWithBodyId;
+use cairo_lang_sierra::program::StatementIdx;
 use cairo_lang_utils::try_extract_matches;
 use indoc::indoc;
 use itertools::Itertools;
@@ -60,6 +61,53 @@ fn test_program_generator() {
     );
 }
 
+#[test]
+fn test_statement_locations() {
+    let (db, crate_id) = setup_db_and_get_crate_id(indoc! {"
+        fn foo(a: felt252) -> felt252 {
+            let b = bar(a);
+            bar(b)
+        }
+
+        #[inline(never)]
+        fn bar(a: felt252) -> felt252 {
+            5
+        }
+    "});
+    let program_with_debug_info = db.get_sierra_program_with_debug_info(vec![crate_id]).unwrap();
+    let program = replace_sierra_ids_in_program(&db, &program_with_debug_info.program);
+    let statements = program
+        .statements
+        .iter()
+        .enumerate()
+        .map(|(idx, statement)| {
+            match program_with_debug_info.debug_info.statement_locations.get(&StatementIdx(idx)) {
+                Some(location) => format!("{statement}; // {location}"),
+                None => format!("{statement};"),
+            }
+        })
+        .join("\n");
+
+    assert_eq!(
+        statements,
+        indoc! {"
+            store_temp<felt252>([0]) -> ([2]); // lib.cairo:2:13
+            function_call<user@test::bar>([2]) -> ([1]); // lib.cairo:2:13
+            rename<felt252>([1]) -> ([4]); // lib.cairo:3:5
+            function_call<user@test::bar>([4]) -> ([3]); // lib.cairo:3:5
+            rename<felt252>([3]) -> ([5]); // lib.cairo:3:5
+            return([5]); // lib.cairo:3:5
+            drop<felt252>([0]) -> ();
+            felt252_const<5>() -> ([1]); // lib.cairo:8:5
+            store_temp<felt252>([1]) -> ([2]); // lib.cairo:8:5
+            return([2]); // lib.cairo:8:5"},
+    );
+    // The debug info holds the names of the program as well.
+    let mut populated_program = program_with_debug_info.program.clone();
+    program_with_debug_info.debug_info.populate(&mut populated_program);
+    assert_eq!(populated_program.to_string(), program.to_string());
+}
+
 #[test]
 fn test_type_dependency() {
     let program = checked_compile_to_sierra(indoc! {"
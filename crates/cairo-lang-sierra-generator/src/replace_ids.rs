@@ -1,4 +1,3 @@
-use cairo_lang_debug::DebugWithDb;
 use cairo_lang_sierra::program;
 use cairo_lang_utils::extract_matches;
 
@@ -131,16 +130,10 @@ impl SierraIdReplacer for DebugReplacer<'_> {
         &self,
         sierra_id: &cairo_lang_sierra::ids::FunctionId,
     ) -> cairo_lang_sierra::ids::FunctionId {
-        let semantic_id = self.db.lookup_intern_sierra_function(sierra_id.clone());
+        let function_id = self.db.lookup_intern_sierra_function(sierra_id.clone());
         cairo_lang_sierra::ids::FunctionId {
             id: sierra_id.id,
-            debug_name: Some(
-                format!(
-                    "{:?}",
-                    self.db.lookup_intern_function(semantic_id).debug(self.db.upcast())
-                )
-                .into(),
-            ),
+            debug_name: Some(function_id.full_path(self.db.upcast()).into()),
         }
     }
 }
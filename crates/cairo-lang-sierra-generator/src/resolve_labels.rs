@@ -18,7 +18,7 @@ pub fn resolve_labels(
             pre_sierra::Statement::Sierra(sierra_statement) => {
                 Some(label_replacer.handle_statement(sierra_statement))
             }
-            pre_sierra::Statement::Label(_) => None,
+            pre_sierra::Statement::Label(_) | pre_sierra::Statement::Location(_) => None,
             pre_sierra::Statement::PushValues(_) => {
                 panic!("Unexpected pre_sierra::Statement::PushValues in resolve_labels().")
             }
@@ -7,7 +7,10 @@ mod state;
 mod test;
 
 use cairo_lang_sierra as sierra;
-use cairo_lang_sierra::extensions::lib_func::{LibfuncSignature, ParamSignature, SierraApChange};
+use cairo_lang_sierra::extensions::lib_func::{
+    BranchSignature, LibfuncSignature, ParamSignature, SierraApChange,
+};
+use cairo_lang_sierra::extensions::OutputVarReferenceInfo;
 use cairo_lang_sierra::ids::ConcreteLibfuncId;
 use cairo_lang_sierra::program::{GenBranchInfo, GenBranchTarget, GenStatement};
 use cairo_lang_utils::extract_matches;
@@ -58,6 +61,32 @@ where
     handler.finalize()
 }
 
+/// Registers the outputs of a libfunc branch in `state`. See [State::register_outputs].
+///
+/// Partial outputs that should be stored as locals, but are neither deferred nor temporary (for
+/// example, a member of a struct that was passed as a parameter), are marked as temporary variables
+/// as well. This makes sure they are copied into their preallocated local variable, which is
+/// otherwise left uninitialized and never dropped.
+fn register_outputs(
+    local_variables: &LocalVariables,
+    state: &mut State,
+    results: &[sierra::ids::VarId],
+    branch_signature: &BranchSignature,
+    args: &[sierra::ids::VarId],
+    deferred_args: &OrderedHashMap<sierra::ids::VarId, DeferredVariableInfo>,
+) {
+    state.register_outputs(results, branch_signature, args, deferred_args);
+    for (var, var_info) in zip_eq(results, &branch_signature.vars) {
+        if matches!(var_info.ref_info, OutputVarReferenceInfo::PartialParam { .. })
+            && local_variables.contains_key(var)
+            && !state.deferred_variables.contains_key(var)
+            && !state.temporary_variables.contains_key(var)
+        {
+            state.temporary_variables.insert(var.clone(), var_info.ty.clone());
+        }
+    }
+}
+
 struct AddStoreVariableStatements<'a> {
     db: &'a dyn SierraGenGroup,
     local_variables: LocalVariables,
@@ -114,7 +143,10 @@ impl<'a> AddStoreVariableStatements<'a> {
                             SierraApChange::BranchAlign | SierraApChange::Known { .. } => {}
                         }
 
-                        self.state().register_outputs(
+                        let state = self.state_opt.as_mut().unwrap();
+                        register_outputs(
+                            &self.local_variables,
+                            state,
                             results,
                             branch_signature,
                             &invocation.args,
@@ -135,7 +167,9 @@ impl<'a> AddStoreVariableStatements<'a> {
                             zip_eq(&invocation.branches, signature.branch_signatures)
                         {
                             let mut state_at_branch = self.state().clone();
-                            state_at_branch.register_outputs(
+                            register_outputs(
+                                &self.local_variables,
+                                &mut state_at_branch,
                                 &branch.results,
                                 &branch_signature,
                                 &invocation.args,
@@ -175,6 +209,9 @@ impl<'a> AddStoreVariableStatements<'a> {
             pre_sierra::Statement::PushValues(push_values) => {
                 self.push_values(push_values);
             }
+            pre_sierra::Statement::Location(_) => {
+                self.result.push(statement);
+            }
         }
     }
 
@@ -221,6 +258,11 @@ impl<'a> AddStoreVariableStatements<'a> {
         let mut res_deferred_info: Option<DeferredVariableInfo> = None;
 
         if let Some(deferred_info) = self.state().deferred_variables.swap_remove(arg) {
+            // A deferred local variable must be stored before it is used, as its uninitialized
+            // local would not be consumed otherwise.
+            if self.local_variables.contains_key(arg) {
+                return (self.store_deferred(arg, deferred_info), None);
+            }
             match deferred_info.kind {
                 state::DeferredVariableKind::Const => {
                     if !allow_const {
@@ -192,6 +192,20 @@ fn get_lib_func_signature(db: &dyn SierraGenGroup, libfunc: ConcreteLibfuncId) -
             }],
             fallthrough: Some(0),
         },
+        "deconstruct" => LibfuncSignature::new_non_branch(
+            vec![felt252_ty.clone()],
+            vec![
+                OutputVarInfo {
+                    ty: felt252_ty.clone(),
+                    ref_info: OutputVarReferenceInfo::PartialParam { param_idx: 0 },
+                },
+                OutputVarInfo {
+                    ty: felt252_ty.clone(),
+                    ref_info: OutputVarReferenceInfo::PartialParam { param_idx: 0 },
+                },
+            ],
+            SierraApChange::Known { new_vars_only: true },
+        ),
         "temp_not_on_top" => LibfuncSignature::new_non_branch(
             vec![],
             vec![OutputVarInfo {
@@ -361,6 +375,60 @@ fn store_local_simple() {
     );
 }
 
+/// Tests that a local variable which is a partial output of a parameter (e.g. a member of a
+/// struct parameter) is copied into its local variable.
+#[test]
+fn store_local_partial_param() {
+    let db = SierraGenDatabaseForTesting::default();
+    let statements: Vec<pre_sierra::Statement> = vec![
+        dummy_simple_statement(&db, "deconstruct", &["0"], &["1", "2"]),
+        dummy_simple_statement(&db, "revoke_ap", &[], &[]),
+        dummy_simple_statement(&db, "felt252_add", &["1", "2"], &["3"]),
+        dummy_return_statement(&[]),
+    ];
+
+    assert_eq!(
+        test_add_store_statements(
+            &db,
+            statements,
+            OrderedHashMap::from_iter(vec![("1".into(), "101".into())])
+        ),
+        vec![
+            "deconstruct(0) -> (1, 2)",
+            "store_local<felt252>(101, 1) -> (1)",
+            "revoke_ap() -> ()",
+            "felt252_add(1, 2) -> (3)",
+            "return()",
+        ],
+    );
+}
+
+/// Tests that a deferred local variable is stored into its local variable before it is used, even
+/// if the libfunc using it accepts deferred values.
+#[test]
+fn store_local_deferred_before_use() {
+    let db = SierraGenDatabaseForTesting::default();
+    let statements: Vec<pre_sierra::Statement> = vec![
+        dummy_simple_statement(&db, "felt252_add3", &["0"], &["1"]),
+        dummy_simple_statement(&db, "felt252_add3", &["1"], &["2"]),
+        dummy_return_statement(&[]),
+    ];
+
+    assert_eq!(
+        test_add_store_statements(
+            &db,
+            statements,
+            OrderedHashMap::from_iter(vec![("1".into(), "101".into())])
+        ),
+        vec![
+            "felt252_add3(0) -> (1)",
+            "store_local<felt252>(101, 1) -> (1)",
+            "felt252_add3(1) -> (2)",
+            "return()",
+        ],
+    );
+}
+
 #[test]
 fn same_as_param() {
     let db = SierraGenDatabaseForTesting::default();
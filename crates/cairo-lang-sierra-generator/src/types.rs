@@ -91,11 +91,25 @@ pub fn get_concrete_type_id(
                 .collect(),
             }))
         }
+        // A closure is represented by a struct of its captured values.
+        semantic::TypeLongId::Closure(closure) => Ok(db.intern_concrete_type(ConcreteTypeLongId {
+            generic_id: "Struct".into(),
+            generic_args: chain!(
+                [cairo_lang_sierra::program::GenericArg::UserType("Closure".into())],
+                closure.captured_types.into_iter().map(|ty| {
+                    cairo_lang_sierra::program::GenericArg::Type(
+                        db.get_concrete_type_id(ty).unwrap(),
+                    )
+                })
+            )
+            .collect(),
+        })),
         semantic::TypeLongId::Snapshot(ty) => {
             let inner_ty = db.get_concrete_type_id(ty).unwrap();
             Ok(snapshot_ty(&SierraSignatureSpecializationContext(db), inner_ty).unwrap())
         }
         semantic::TypeLongId::GenericParameter(_)
+        | semantic::TypeLongId::ImplType(_)
         | semantic::TypeLongId::Var(_)
         | semantic::TypeLongId::Missing(_) => {
             panic!(
@@ -1,5 +1,4 @@
-use cairo_lang_debug::DebugWithDb;
-use cairo_lang_diagnostics::Maybe;
+use cairo_lang_diagnostics::{Maybe, ToMaybe};
 use cairo_lang_sierra::extensions::core::CoreLibfunc;
 use cairo_lang_sierra::extensions::lib_func::LibfuncSignature;
 use cairo_lang_sierra::extensions::snapshot::SnapshotType;
@@ -11,9 +10,8 @@ use cairo_lang_sierra::program;
 use cairo_lang_utils::extract_matches;
 use num_bigint::BigInt;
 use semantic::corelib::get_const_libfunc_name_by_type;
-use semantic::items::functions::GenericFunctionId;
 use smol_str::SmolStr;
-use {cairo_lang_defs as defs, cairo_lang_semantic as semantic};
+use {cairo_lang_defs as defs, cairo_lang_lowering as lowering, cairo_lang_semantic as semantic};
 
 use crate::db::SierraGenGroup;
 use crate::pre_sierra;
@@ -249,7 +247,7 @@ pub fn get_libfunc_signature(
         } = err
         {
             let function = db.lookup_intern_sierra_function(function);
-            panic!("Missing function {:?}", function.debug(db.elongate()));
+            panic!("Missing function {}", function.full_path(db.upcast()));
         }
         // If panic happens here, make sure the specified libfunc name is in one of the STR_IDs of
         // the libfuncs in the [`CoreLibfunc`] structured enum.
@@ -263,7 +261,7 @@ pub fn get_libfunc_signature(
 /// Returns the [ConcreteLibfuncId] for calling a user-defined function.
 pub fn function_call_libfunc_id(
     db: &dyn SierraGenGroup,
-    func: semantic::FunctionId,
+    func: lowering::ids::FunctionId,
 ) -> ConcreteLibfuncId {
     db.intern_concrete_lib_func(cairo_lang_sierra::program::ConcreteLibfuncLongId {
         generic_id: GenericLibfuncId::from_string("function_call"),
@@ -285,39 +283,37 @@ pub fn generic_libfunc_id(
     })
 }
 
-/// Returns the [ConcreteLibfuncId] used for calling a function (either user-defined or libfunc).
+/// Returns the [ConcreteLibfuncId] used for calling a function (either user-defined or libfunc),
+/// along with the body of the function if it is user-defined.
 pub fn get_concrete_libfunc_id(
     db: &dyn SierraGenGroup,
-    function: semantic::FunctionId,
-) -> (semantic::ConcreteFunction, ConcreteLibfuncId) {
+    function: lowering::ids::FunctionId,
+) -> Maybe<(Option<lowering::ids::ConcreteFunctionWithBodyId>, ConcreteLibfuncId)> {
     // Check if this is a user-defined function or a libfunc.
-    let concrete_function = db.lookup_intern_function(function).function;
-    match concrete_function.generic_function {
-        GenericFunctionId::Free(_) | GenericFunctionId::Impl(_) => {
-            (concrete_function, function_call_libfunc_id(db, function))
-        }
-        GenericFunctionId::Extern(extern_id) => {
-            let mut generic_args = vec![];
-            for generic_arg in &concrete_function.generic_args {
-                generic_args.push(match generic_arg {
-                    semantic::GenericArgumentId::Type(ty) => {
-                        // TODO(lior): How should the following unwrap() be handled?
-                        cairo_lang_sierra::program::GenericArg::Type(
-                            db.get_concrete_type_id(*ty).unwrap(),
-                        )
-                    }
-                    semantic::GenericArgumentId::Literal(literal_id) => {
-                        cairo_lang_sierra::program::GenericArg::Value(
-                            db.lookup_intern_literal(*literal_id).value,
-                        )
-                    }
-                    semantic::GenericArgumentId::Impl(_) => {
-                        panic!("Extern function with impl generics are not supported.")
-                    }
-                });
+    if let Some(body) = function.body(db.upcast())? {
+        // Call the function by the id of its body, as some functions (e.g. closure calls) are
+        // implemented by a different function.
+        let function = body.function_id(db.upcast())?;
+        return Ok((Some(body), function_call_libfunc_id(db, function)));
+    }
+    let (extern_id, extern_generic_args) = function.get_extern(db.upcast()).to_maybe()?;
+    let mut generic_args = vec![];
+    for generic_arg in &extern_generic_args {
+        generic_args.push(match generic_arg {
+            semantic::GenericArgumentId::Type(ty) => {
+                // TODO(lior): How should the following unwrap() be handled?
+                cairo_lang_sierra::program::GenericArg::Type(db.get_concrete_type_id(*ty).unwrap())
             }
-
-            (concrete_function, generic_libfunc_id(db, extern_id, generic_args))
-        }
+            semantic::GenericArgumentId::Literal(literal_id) => {
+                cairo_lang_sierra::program::GenericArg::Value(
+                    db.lookup_intern_literal(*literal_id).value,
+                )
+            }
+            semantic::GenericArgumentId::Impl(_) => {
+                panic!("Extern function with impl generics are not supported.")
+            }
+        });
     }
+
+    Ok((None, generic_libfunc_id(db, extern_id, generic_args)))
 }
@@ -77,6 +77,23 @@ pub struct CairoProgramDebugInfo {
     /// The debug information per Sierra statement.
     pub sierra_statement_info: Vec<SierraStatementDebugInfo>,
 }
+impl CairoProgramDebugInfo {
+    /// Returns the index of the Sierra statement the instruction at the given code offset was
+    /// compiled from, or `None` if the offset is outside of the program code.
+    ///
+    /// Statements compiled to no instructions share their code offset with the statement following
+    /// them, and are never returned.
+    pub fn statement_at_code_offset(&self, code_offset: usize) -> Option<StatementIdx> {
+        // The last entry holds the size of the program code, and is not a statement.
+        let end = self.sierra_statement_info.last()?.code_offset;
+        if code_offset >= end {
+            return None;
+        }
+        let next_statement =
+            self.sierra_statement_info.partition_point(|info| info.code_offset <= code_offset);
+        Some(StatementIdx(next_statement - 1))
+    }
+}
 
 /// Ensure the basic structure of the invocation is the same as the library function.
 pub fn check_basic_structure(
@@ -1,5 +1,7 @@
+use cairo_lang_sierra::program::StatementIdx;
 use cairo_lang_sierra::ProgramParser;
 use indoc::indoc;
+use itertools::Itertools;
 use pretty_assertions;
 use test_case::test_case;
 
@@ -384,6 +386,38 @@ fn sierra_to_casm(sierra_code: &str, check_gas_usage: bool, expected_casm: &str)
     );
 }
 
+#[test]
+fn statement_at_code_offset() {
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            type felt252 = felt252;
+            type Unit = Struct<ut@Tuple>;
+
+            libfunc felt252_div_3 = felt252_div_const<3>;
+            libfunc drop<felt252> = drop<felt252>;
+            libfunc struct_construct<Unit> = struct_construct<Unit>;
+            libfunc store_temp<Unit> = store_temp<Unit>;
+
+            felt252_div_3([0]) -> ([2]);
+            drop<felt252>([2]) -> ();
+            struct_construct<Unit>() -> ([3]);
+            store_temp<Unit>([3]) -> ([4]);
+            return([4]);
+
+            test::foo@0([0]: felt252) -> (Unit);
+        "})
+        .unwrap();
+    let debug_info = compile(&program, &build_metadata(&program, false), false)
+        .expect("Compilation failed.")
+        .debug_info;
+    // The division takes 2 cells, and is followed by the `ret` instruction, as all the statements
+    // in between are compiled to no instructions.
+    assert_eq!(
+        (0..4).map(|code_offset| debug_info.statement_at_code_offset(code_offset)).collect_vec(),
+        [Some(StatementIdx(0)), Some(StatementIdx(0)), Some(StatementIdx(4)), None]
+    );
+}
+
 // TODO(ilya, 10/10/2022): Improve error messages.
 #[test_case(indoc! {"
                 return([2]);
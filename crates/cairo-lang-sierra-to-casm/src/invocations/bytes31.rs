@@ -0,0 +1,32 @@
+use cairo_lang_sierra::extensions::bytes31::Bytes31ConcreteLibfunc;
+use cairo_lang_sierra::extensions::consts::SignatureAndConstConcreteLibfunc;
+use num_bigint::BigInt;
+use num_traits::Signed;
+
+use super::misc::{build_felt252_try_from_under_limit, build_identity, build_single_cell_const};
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+
+/// Builds instructions for Sierra bytes31 operations.
+pub fn build(
+    libfunc: &Bytes31ConcreteLibfunc,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        Bytes31ConcreteLibfunc::Const(libfunc) => build_bytes31_const(builder, libfunc),
+        Bytes31ConcreteLibfunc::ToFelt252(_) => build_identity(builder),
+        Bytes31ConcreteLibfunc::TryFromFelt252(_) => {
+            build_felt252_try_from_under_limit(builder, BigInt::from(1) << 248)
+        }
+    }
+}
+
+/// Handles the bytes31_const libfunc.
+fn build_bytes31_const(
+    builder: CompiledInvocationBuilder<'_>,
+    libfunc: &SignatureAndConstConcreteLibfunc,
+) -> Result<CompiledInvocation, InvocationError> {
+    if libfunc.c.is_negative() || libfunc.c >= BigInt::from(1) << 248 {
+        return Err(InvocationError::InvalidGenericArg);
+    }
+    build_single_cell_const(builder, libfunc.c.clone())
+}
@@ -1,8 +1,9 @@
 use cairo_lang_casm::builder::CasmBuilder;
 use cairo_lang_casm::casm_build_extend;
-use cairo_lang_sierra::extensions::casts::{CastConcreteLibfunc, DowncastConcreteLibfunc};
-use num_bigint::BigUint;
-use num_traits::Pow;
+use cairo_lang_sierra::extensions::casts::{
+    CastConcreteLibfunc, CastType, DowncastConcreteLibfunc,
+};
+use num_bigint::BigInt;
 
 use super::misc::build_identity;
 use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
@@ -33,40 +34,88 @@ pub fn build_downcast(
         deref value;
     );
 
-    // The casm code below assumes both types are at most 128 bits.
+    let CastType { overflow_above, overflow_below } = libfunc.cast_type();
+    let to_lower = libfunc.to_range.lower.clone();
+    let bound = libfunc.to_range.size();
+    let rc_bound = BigInt::from(u128::MAX) + 1;
+
+    // The casm code below assumes all the values are bounded by 128 bits.
     assert!(
-        libfunc.from_nbits <= 128 && libfunc.to_nbits <= 128,
+        bound <= rc_bound
+            && libfunc.from_range.upper.clone() - to_lower.clone() <= rc_bound.clone() * 2
+            && to_lower.clone() - libfunc.from_range.lower.clone() <= rc_bound,
         "Downcasting from types of size > 128 bit is not supported."
     );
 
-    let two = BigUint::from(2u64);
-    let bound = two.clone().pow(libfunc.to_nbits);
-    let rc_bound = two.pow(128_usize);
-
     casm_build_extend! {casm_builder,
         let orig_range_check = range_check;
-
+    };
+    // The value shifted such that the destination range is `[0, bound)`.
+    let canonical_value = if libfunc.to_range.is_unsigned() {
+        value
+    } else {
+        casm_build_extend! {casm_builder,
+            const to_lower_fixer = -to_lower;
+            tempvar canonical_value = value + to_lower_fixer;
+        };
+        canonical_value
+    };
+    casm_build_extend! {casm_builder,
         // Use a hint to guess whether the result is in range (is_valid=1) or overflows
         // (is_valid=0).
         tempvar is_valid;
         const limit = bound.clone();
-        hint TestLessThan {lhs: value, rhs: limit} into {dst: is_valid};
+        hint TestLessThan {lhs: canonical_value, rhs: limit} into {dst: is_valid};
         jump Success if is_valid != 0;
-        // Failure.
-        // value >= bound  <=>  value - bound >= 0.
-        // Note that we know that 0 <= value < 2^128.
-        tempvar shifted_value = value - limit;
-        assert shifted_value = *(range_check++);
-        jump Failure;
-
-        // Success.
-        Success:
-        // Verify that the value is in range:
-        // value < limit  <=>  value + (rc_bound - bound) < rc_bound.
-        const pos_shift = rc_bound - bound;
-        tempvar shifted_value = value + pos_shift;
-        assert shifted_value = *(range_check++);
     };
+    // Failure.
+    if overflow_above && overflow_below {
+        casm_build_extend! {casm_builder,
+            // Use a hint to guess whether the value is above the range (is_above=1) or below it
+            // (is_above=0).
+            tempvar is_above;
+            const double_limit = (rc_bound.clone() * 2) as BigInt;
+            hint TestLessThan {lhs: canonical_value, rhs: double_limit} into {dst: is_above};
+            jump IsAbove if is_above != 0;
+        };
+    }
+    if overflow_below {
+        casm_build_extend! {casm_builder,
+            // canonical_value < 0  <=>  canonical_value + rc_bound < rc_bound.
+            const rc_bound_imm = rc_bound.clone();
+            tempvar shifted_value = canonical_value + rc_bound_imm;
+            assert shifted_value = *(range_check++);
+            jump Failure;
+        };
+    }
+    if overflow_above {
+        if overflow_below {
+            casm_build_extend!(casm_builder, IsAbove:);
+        }
+        casm_build_extend! {casm_builder,
+            // canonical_value >= bound  <=>  canonical_value - bound >= 0.
+            tempvar shifted_value = canonical_value - limit;
+            assert shifted_value = *(range_check++);
+            jump Failure;
+        };
+    }
+    // Success.
+    casm_build_extend!(casm_builder, Success:);
+    if overflow_below {
+        casm_build_extend! {casm_builder,
+            // Verify that canonical_value >= 0.
+            assert canonical_value = *(range_check++);
+        };
+    }
+    if overflow_above {
+        casm_build_extend! {casm_builder,
+            // Verify that the value is in range:
+            // canonical_value < limit  <=>  canonical_value + (rc_bound - bound) < rc_bound.
+            const pos_shift = rc_bound - bound;
+            tempvar shifted_value = canonical_value + pos_shift;
+            assert shifted_value = *(range_check++);
+        };
+    }
 
     let target_statement_id = get_non_fallthrough_statement_id(&builder);
     Ok(builder.build_from_casm_builder(
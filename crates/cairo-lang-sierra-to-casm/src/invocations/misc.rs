@@ -1,13 +1,14 @@
+use cairo_felt::Felt as Felt252;
 use cairo_lang_casm::builder::{CasmBuilder, Var};
 use cairo_lang_casm::cell_expression::CellExpression;
 use cairo_lang_casm::{casm, casm_build_extend};
 use cairo_lang_sierra::program::{BranchInfo, BranchTarget};
 use itertools::Itertools;
-use num_bigint::BigInt;
+use num_bigint::{BigInt, ToBigInt};
 
 use super::{
     get_non_fallthrough_statement_id, CompiledInvocation, CompiledInvocationBuilder,
-    InvocationError,
+    CostValidationInfo, InvocationError,
 };
 use crate::invocations::add_input_variables;
 use crate::references::ReferenceExpression;
@@ -250,3 +251,53 @@ pub fn validate_under_limit<const K: u8>(
         _ => unreachable!("Only K value of 1 or 2 are supported."),
     }
 }
+
+/// Builds a libfunc that tries to convert a felt252 to a type with values in the range
+/// [0, `limit`).
+///
+/// The failure case is proven by showing that `value - limit` is in the range
+/// [0, PRIME - `limit`).
+pub fn build_felt252_try_from_under_limit(
+    builder: CompiledInvocationBuilder<'_>,
+    limit: BigInt,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, value] = builder.try_get_single_cells()?;
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(2) range_check;
+        deref value;
+    };
+    let auxiliary_vars: [_; 4] = std::array::from_fn(|_| casm_builder.alloc_var(false));
+    casm_build_extend! {casm_builder,
+        const limit_imm = limit.clone();
+        let orig_range_check = range_check;
+        tempvar is_under_limit;
+        hint TestLessThan {lhs: value, rhs: limit_imm} into {dst: is_under_limit};
+        jump IsUnderLimit if is_under_limit != 0;
+        tempvar shifted_value = value - limit_imm;
+    }
+    validate_under_limit::<1>(
+        &mut casm_builder,
+        &(-Felt252::from(limit.clone())).to_biguint().to_bigint().unwrap(),
+        shifted_value,
+        range_check,
+        &auxiliary_vars,
+    );
+    casm_build_extend! {casm_builder,
+        jump Failure;
+        IsUnderLimit:
+    };
+    validate_under_limit::<1>(&mut casm_builder, &limit, value, range_check, &auxiliary_vars);
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[value]], None),
+            ("Failure", &[&[range_check]], Some(failure_handle_statement_id)),
+        ],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
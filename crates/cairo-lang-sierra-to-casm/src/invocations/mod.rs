@@ -36,6 +36,7 @@ mod bitwise;
 mod boolean;
 mod boxing;
 mod builtin_cost;
+mod bytes31;
 mod casts;
 mod debug;
 mod ec;
@@ -48,11 +49,15 @@ mod mem;
 mod misc;
 mod nullable;
 mod pedersen;
+mod poseidon;
+mod sint;
 mod starknet;
 
 mod structure;
 mod uint;
 mod uint128;
+mod uint256;
+mod uint512;
 
 #[cfg(test)]
 mod test_utils;
@@ -545,6 +550,7 @@ pub fn compile_invocation(
         CompiledInvocationBuilder { program_info, invocation, libfunc, idx, refs, environment };
     match libfunc {
         CoreConcreteLibfunc::Felt252(libfunc) => felt252::build(libfunc, builder),
+        CoreConcreteLibfunc::Bytes31(libfunc) => bytes31::build(libfunc, builder),
         CoreConcreteLibfunc::Bitwise(_) => bitwise::build(builder),
         CoreConcreteLibfunc::Bool(libfunc) => boolean::build(libfunc, builder),
         CoreConcreteLibfunc::Cast(libfunc) => casts::build(libfunc, builder),
@@ -554,6 +560,13 @@ pub fn compile_invocation(
         CoreConcreteLibfunc::Uint32(libfunc) => uint::build_u32(libfunc, builder),
         CoreConcreteLibfunc::Uint64(libfunc) => uint::build_u64(libfunc, builder),
         CoreConcreteLibfunc::Uint128(libfunc) => uint128::build(libfunc, builder),
+        CoreConcreteLibfunc::Uint256(libfunc) => uint256::build(libfunc, builder),
+        CoreConcreteLibfunc::Uint512(libfunc) => uint512::build(libfunc, builder),
+        CoreConcreteLibfunc::Sint8(libfunc) => sint::build_i8(libfunc, builder),
+        CoreConcreteLibfunc::Sint16(libfunc) => sint::build_i16(libfunc, builder),
+        CoreConcreteLibfunc::Sint32(libfunc) => sint::build_i32(libfunc, builder),
+        CoreConcreteLibfunc::Sint64(libfunc) => sint::build_i64(libfunc, builder),
+        CoreConcreteLibfunc::Sint128(libfunc) => sint::build_i128(libfunc, builder),
         CoreConcreteLibfunc::Gas(libfunc) => gas::build(libfunc, builder),
         CoreConcreteLibfunc::BranchAlign(_) => misc::build_branch_align(builder),
         CoreConcreteLibfunc::Array(libfunc) => array::build(libfunc, builder),
@@ -569,6 +582,7 @@ pub fn compile_invocation(
         CoreConcreteLibfunc::Struct(libfunc) => structure::build(libfunc, builder),
         CoreConcreteLibfunc::Felt252Dict(libfunc) => felt252_dict::build(libfunc, builder),
         CoreConcreteLibfunc::Pedersen(libfunc) => pedersen::build(libfunc, builder),
+        CoreConcreteLibfunc::Poseidon(libfunc) => poseidon::build(libfunc, builder),
         CoreConcreteLibfunc::BuiltinCost(libfunc) => builtin_cost::build(libfunc, builder),
         CoreConcreteLibfunc::StarkNet(libfunc) => starknet::build(libfunc, builder),
         CoreConcreteLibfunc::Nullable(libfunc) => nullable::build(libfunc, builder),
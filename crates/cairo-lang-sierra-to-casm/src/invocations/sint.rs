@@ -0,0 +1,281 @@
+use cairo_felt::Felt as Felt252;
+use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_casm::cell_expression::CellExpression;
+use cairo_lang_sierra::extensions::sint::{
+    Sint128Concrete, Sint16Concrete, Sint32Concrete, Sint64Concrete, Sint8Concrete,
+    SintConstConcreteLibfunc, SintTraits,
+};
+use cairo_lang_sierra::extensions::uint::IntOperator;
+use cairo_lang_sierra::program::{BranchInfo, BranchTarget};
+use num_bigint::{BigInt, ToBigInt};
+use num_traits::One;
+
+use super::uint::{build_less_than, build_less_than_or_equal, build_small_wide_mul};
+use super::{misc, CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::misc::validate_under_limit;
+use crate::invocations::{
+    add_input_variables, get_non_fallthrough_statement_id, CostValidationInfo,
+};
+use crate::references::ReferenceExpression;
+
+/// Builds invocations for sint const values.
+fn build_const<TSintTraits: SintTraits>(
+    libfunc: &SintConstConcreteLibfunc<TSintTraits>,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    Ok(builder.build_only_reference_changes(
+        [ReferenceExpression::from_cell(CellExpression::Immediate(libfunc.c.into()))].into_iter(),
+    ))
+}
+
+/// Handles a sint overflowing add or sub operation, for a sint of `n_bits` bits.
+/// The value is in range if `value + 2**(n_bits - 1)` (the canonical value) is in
+/// `[0, 2**n_bits)`, and it underflows or overflows if the canonical value is below or above that
+/// range respectively.
+fn build_sint_overflowing_operation(
+    builder: CompiledInvocationBuilder<'_>,
+    n_bits: usize,
+    operator: IntOperator,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [underflow_statement_id, overflow_statement_id] =
+        match builder.invocation.branches.as_slice() {
+            [
+                BranchInfo { target: BranchTarget::Fallthrough, results: _ },
+                BranchInfo { target: BranchTarget::Statement(underflow_statement_id), results: _ },
+                BranchInfo { target: BranchTarget::Statement(overflow_statement_id), results: _ },
+            ] => [*underflow_statement_id, *overflow_statement_id],
+            _ => panic!("malformed invocation"),
+        };
+    let [range_check, a, b] = builder.try_get_single_cells()?;
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(0) range_check;
+        deref a;
+        deref b;
+    };
+    let range_size = BigInt::one() << n_bits;
+    casm_build_extend! {casm_builder,
+        let orig_range_check = range_check;
+        const canonical_shift = (BigInt::one() << (n_bits - 1)) as BigInt;
+        const limit = range_size.clone();
+        const double_limit = (BigInt::one() << (n_bits + 1)) as BigInt;
+        const u128_limit = (BigInt::from(u128::MAX) + 1) as BigInt;
+    };
+    let value = match operator {
+        IntOperator::OverflowingAdd => {
+            casm_build_extend!(casm_builder, tempvar value = a + b;);
+            value
+        }
+        IntOperator::OverflowingSub => {
+            casm_build_extend!(casm_builder, tempvar value = a - b;);
+            value
+        }
+    };
+    casm_build_extend! {casm_builder,
+            tempvar canonical_value = value + canonical_shift;
+            tempvar is_in_range;
+            hint TestLessThan {lhs: canonical_value, rhs: limit} into {dst: is_in_range};
+            jump InRange if is_in_range != 0;
+            tempvar is_overflow;
+            // The canonical value is in `[-2**(n_bits - 1), 2**(n_bits + 1))`, so it is above the
+            // range iff it is in `[2**n_bits, 2**(n_bits + 1))`.
+            hint TestLessThan {lhs: canonical_value, rhs: double_limit} into {dst: is_overflow};
+            jump IsOverflow if is_overflow != 0;
+            // Underflow:
+            // `canonical_value < 0` <==> `canonical_value + 2**128 < 2**128`.
+            tempvar fixed_below = canonical_value + u128_limit;
+            assert fixed_below = *(range_check++);
+            tempvar wrapping_below = value + limit;
+            jump Underflow;
+        IsOverflow:
+            // `canonical_value >= 2**n_bits` <==> `canonical_value - 2**n_bits >= 0`.
+            tempvar fixed_above = canonical_value - limit;
+            assert fixed_above = *(range_check++);
+            tempvar wrapping_above = value - limit;
+            jump Overflow;
+        InRange:
+            assert canonical_value = *(range_check++);
+    };
+    if n_bits < 128 {
+        casm_build_extend! {casm_builder,
+            // `canonical_value < 2**n_bits` <==> `canonical_value + 2**128 - 2**n_bits < 2**128`.
+            const limit_fixer = (BigInt::from(u128::MAX) + 1 - range_size) as BigInt;
+            tempvar fixed_canonical_value = canonical_value + limit_fixer;
+            assert fixed_canonical_value = *(range_check++);
+        };
+    }
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[value]], None),
+            ("Underflow", &[&[range_check], &[wrapping_below]], Some(underflow_statement_id)),
+            ("Overflow", &[&[range_check], &[wrapping_above]], Some(overflow_statement_id)),
+        ],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
+
+/// Handles a sint conversion from felt252, for a sint of `n_bits` bits.
+fn build_sint_from_felt252(
+    builder: CompiledInvocationBuilder<'_>,
+    n_bits: usize,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, value] = builder.try_get_single_cells()?;
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(2) range_check;
+        deref value;
+    };
+    let range_size = BigInt::one() << n_bits;
+    casm_build_extend! {casm_builder,
+        let orig_range_check = range_check;
+        const canonical_shift = (BigInt::one() << (n_bits - 1)) as BigInt;
+        const limit = range_size.clone();
+        // The value is in range iff `value + 2**(n_bits - 1)` is in `[0, 2**n_bits)`.
+        tempvar canonical_value = value + canonical_shift;
+        tempvar is_in_range;
+        hint TestLessThan {lhs: canonical_value, rhs: limit} into {dst: is_in_range};
+        jump InRange if is_in_range != 0;
+        tempvar shifted_value = canonical_value - limit;
+    }
+    let auxiliary_vars: [_; 5] = std::array::from_fn(|_| casm_builder.alloc_var(false));
+    validate_under_limit::<2>(
+        &mut casm_builder,
+        &(-Felt252::from(range_size.clone())).to_biguint().to_bigint().unwrap(),
+        shifted_value,
+        range_check,
+        &auxiliary_vars,
+    );
+    casm_build_extend! {casm_builder,
+        InRange:
+        assert canonical_value = *(range_check++);
+    };
+    if n_bits < 128 {
+        casm_build_extend! {casm_builder,
+            // `canonical_value < 2**n_bits` <==> `canonical_value + 2**128 - 2**n_bits < 2**128`.
+            const limit_fixer = (BigInt::from(u128::MAX) + 1 - range_size) as BigInt;
+            tempvar fixed_canonical_value = canonical_value + limit_fixer;
+            assert fixed_canonical_value = *(range_check++);
+        };
+    }
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[value]], None),
+            ("Done", &[&[range_check]], Some(failure_handle_statement_id)),
+        ],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
+
+/// Builds instructions for Sierra i8 operations.
+pub fn build_i8(
+    libfunc: &Sint8Concrete,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    const N_BITS: usize = 8;
+    match libfunc {
+        Sint8Concrete::Const(libfunc) => build_const(libfunc, builder),
+        Sint8Concrete::LessThan(_) => build_less_than(builder),
+        Sint8Concrete::Equal(_) => misc::build_cell_eq(builder),
+        Sint8Concrete::LessThanOrEqual(_) => build_less_than_or_equal(builder),
+        Sint8Concrete::Operation(libfunc) => {
+            build_sint_overflowing_operation(builder, N_BITS, libfunc.operator)
+        }
+        Sint8Concrete::ToFelt252(_) => misc::build_identity(builder),
+        Sint8Concrete::FromFelt252(_) => build_sint_from_felt252(builder, N_BITS),
+        Sint8Concrete::IsZero(_) => misc::build_is_zero(builder),
+        Sint8Concrete::WideMul(_) => build_small_wide_mul(builder),
+    }
+}
+
+/// Builds instructions for Sierra i16 operations.
+pub fn build_i16(
+    libfunc: &Sint16Concrete,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    const N_BITS: usize = 16;
+    match libfunc {
+        Sint16Concrete::Const(libfunc) => build_const(libfunc, builder),
+        Sint16Concrete::LessThan(_) => build_less_than(builder),
+        Sint16Concrete::Equal(_) => misc::build_cell_eq(builder),
+        Sint16Concrete::LessThanOrEqual(_) => build_less_than_or_equal(builder),
+        Sint16Concrete::Operation(libfunc) => {
+            build_sint_overflowing_operation(builder, N_BITS, libfunc.operator)
+        }
+        Sint16Concrete::ToFelt252(_) => misc::build_identity(builder),
+        Sint16Concrete::FromFelt252(_) => build_sint_from_felt252(builder, N_BITS),
+        Sint16Concrete::IsZero(_) => misc::build_is_zero(builder),
+        Sint16Concrete::WideMul(_) => build_small_wide_mul(builder),
+    }
+}
+
+/// Builds instructions for Sierra i32 operations.
+pub fn build_i32(
+    libfunc: &Sint32Concrete,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    const N_BITS: usize = 32;
+    match libfunc {
+        Sint32Concrete::Const(libfunc) => build_const(libfunc, builder),
+        Sint32Concrete::LessThan(_) => build_less_than(builder),
+        Sint32Concrete::Equal(_) => misc::build_cell_eq(builder),
+        Sint32Concrete::LessThanOrEqual(_) => build_less_than_or_equal(builder),
+        Sint32Concrete::Operation(libfunc) => {
+            build_sint_overflowing_operation(builder, N_BITS, libfunc.operator)
+        }
+        Sint32Concrete::ToFelt252(_) => misc::build_identity(builder),
+        Sint32Concrete::FromFelt252(_) => build_sint_from_felt252(builder, N_BITS),
+        Sint32Concrete::IsZero(_) => misc::build_is_zero(builder),
+        Sint32Concrete::WideMul(_) => build_small_wide_mul(builder),
+    }
+}
+
+/// Builds instructions for Sierra i64 operations.
+pub fn build_i64(
+    libfunc: &Sint64Concrete,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    const N_BITS: usize = 64;
+    match libfunc {
+        Sint64Concrete::Const(libfunc) => build_const(libfunc, builder),
+        Sint64Concrete::LessThan(_) => build_less_than(builder),
+        Sint64Concrete::Equal(_) => misc::build_cell_eq(builder),
+        Sint64Concrete::LessThanOrEqual(_) => build_less_than_or_equal(builder),
+        Sint64Concrete::Operation(libfunc) => {
+            build_sint_overflowing_operation(builder, N_BITS, libfunc.operator)
+        }
+        Sint64Concrete::ToFelt252(_) => misc::build_identity(builder),
+        Sint64Concrete::FromFelt252(_) => build_sint_from_felt252(builder, N_BITS),
+        Sint64Concrete::IsZero(_) => misc::build_is_zero(builder),
+        Sint64Concrete::WideMul(_) => build_small_wide_mul(builder),
+    }
+}
+
+/// Builds instructions for Sierra i128 operations.
+pub fn build_i128(
+    libfunc: &Sint128Concrete,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    const N_BITS: usize = 128;
+    match libfunc {
+        Sint128Concrete::Const(libfunc) => build_const(libfunc, builder),
+        Sint128Concrete::LessThan(_) => build_less_than(builder),
+        Sint128Concrete::Equal(_) => misc::build_cell_eq(builder),
+        Sint128Concrete::LessThanOrEqual(_) => build_less_than_or_equal(builder),
+        Sint128Concrete::Operation(libfunc) => {
+            build_sint_overflowing_operation(builder, N_BITS, libfunc.operator)
+        }
+        Sint128Concrete::ToFelt252(_) => misc::build_identity(builder),
+        Sint128Concrete::FromFelt252(_) => build_sint_from_felt252(builder, N_BITS),
+        Sint128Concrete::IsZero(_) => misc::build_is_zero(builder),
+    }
+}
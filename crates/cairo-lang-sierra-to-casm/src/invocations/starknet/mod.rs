@@ -1,11 +1,10 @@
-use cairo_felt::Felt as Felt252;
 use cairo_lang_casm::builder::CasmBuilder;
 use cairo_lang_casm::casm_build_extend;
 use cairo_lang_sierra::extensions::consts::SignatureAndConstConcreteLibfunc;
 use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
 use cairo_lang_sierra_gas::core_libfunc_cost::SYSTEM_CALL_COST;
 use itertools::Itertools;
-use num_bigint::{BigInt, ToBigInt};
+use num_bigint::BigInt;
 use num_traits::Signed;
 
 use self::storage::{
@@ -14,7 +13,6 @@ use self::storage::{
 };
 use super::misc::{build_identity, build_single_cell_const};
 use super::{misc, CompiledInvocation, CompiledInvocationBuilder};
-use crate::invocations::misc::validate_under_limit;
 use crate::invocations::{
     add_input_variables, get_non_fallthrough_statement_id, CostValidationInfo, InvocationError,
 };
@@ -76,6 +74,19 @@ pub fn build(
         StarkNetConcreteLibfunc::SendMessageToL1(_) => {
             build_syscalls(builder, "SendMessageToL1", [1, 2], [])
         }
+        StarkNetConcreteLibfunc::Keccak(_) => build_syscalls(builder, "Keccak", [2], [2]),
+        StarkNetConcreteLibfunc::Secp256K1EcAdd(_) => {
+            build_syscalls(builder, "Secp256k1Add", [1, 1], [1])
+        }
+        StarkNetConcreteLibfunc::Secp256K1EcMul(_) => {
+            build_syscalls(builder, "Secp256k1Mul", [1, 2], [1])
+        }
+        StarkNetConcreteLibfunc::Secp256K1EcGetPointFromX(_) => {
+            build_syscalls(builder, "Secp256k1GetPointFromX", [2, 1], [2])
+        }
+        StarkNetConcreteLibfunc::Secp256K1EcGetCoordinates(_) => {
+            build_syscalls(builder, "Secp256k1GetXy", [1], [2, 2])
+        }
         StarkNetConcreteLibfunc::Testing(libfunc) => testing::build(libfunc, builder),
     }
 }
@@ -97,46 +108,7 @@ pub fn build_u251_const(
 pub fn build_u251_try_from_felt252(
     builder: CompiledInvocationBuilder<'_>,
 ) -> Result<CompiledInvocation, InvocationError> {
-    let addr_bound: BigInt = BigInt::from(1) << 251;
-    let [range_check, value] = builder.try_get_single_cells()?;
-    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
-    let mut casm_builder = CasmBuilder::default();
-    add_input_variables! {casm_builder,
-        buffer(2) range_check;
-        deref value;
-    };
-    let auxiliary_vars: [_; 4] = std::array::from_fn(|_| casm_builder.alloc_var(false));
-    casm_build_extend! {casm_builder,
-        const limit = addr_bound.clone();
-        let orig_range_check = range_check;
-        tempvar is_valid_address;
-        hint TestLessThan {lhs: value, rhs: limit} into {dst: is_valid_address};
-        jump IsValidAddress if is_valid_address != 0;
-        tempvar shifted_value = value - limit;
-    }
-    validate_under_limit::<1>(
-        &mut casm_builder,
-        &(-Felt252::from(addr_bound.clone())).to_biguint().to_bigint().unwrap(),
-        shifted_value,
-        range_check,
-        &auxiliary_vars,
-    );
-    casm_build_extend! {casm_builder,
-        jump Failure;
-        IsValidAddress:
-    };
-    validate_under_limit::<1>(&mut casm_builder, &addr_bound, value, range_check, &auxiliary_vars);
-    Ok(builder.build_from_casm_builder(
-        casm_builder,
-        [
-            ("Fallthrough", &[&[range_check], &[value]], None),
-            ("Failure", &[&[range_check]], Some(failure_handle_statement_id)),
-        ],
-        CostValidationInfo {
-            range_check_info: Some((orig_range_check, range_check)),
-            extra_costs: None,
-        },
-    ))
+    misc::build_felt252_try_from_under_limit(builder, BigInt::from(1) << 251)
 }
 
 /// Builds instructions for Starknet system calls.
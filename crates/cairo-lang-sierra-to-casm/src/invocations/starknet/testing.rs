@@ -1,10 +1,10 @@
-use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::builder::{CasmBuilder, Var};
 use cairo_lang_casm::casm_build_extend;
 use cairo_lang_sierra::extensions::starknet::testing::TestingConcreteLibfunc;
 
 use crate::invocations::{
-    add_input_variables, CompiledInvocation, CompiledInvocationBuilder, CostValidationInfo,
-    InvocationError,
+    add_input_variables, get_non_fallthrough_statement_id, CompiledInvocation,
+    CompiledInvocationBuilder, CostValidationInfo, InvocationError,
 };
 
 /// Builds instructions for starknet test setup operations.
@@ -33,6 +33,10 @@ pub fn build(
         TestingConcreteLibfunc::SetSequencerAddress(_) => {
             casm_build_extend! {casm_builder, hint SetSequencerAddress {value: value}; };
         }
+        TestingConcreteLibfunc::PopLog(_) => return build_pop_log(casm_builder, value, builder),
+        TestingConcreteLibfunc::PopL2ToL1Message(_) => {
+            return build_pop_l2_to_l1_message(casm_builder, value, builder);
+        }
     }
     casm_build_extend! {casm_builder, ap += 0; };
     Ok(builder.build_from_casm_builder(
@@ -41,3 +45,72 @@ pub fn build(
         CostValidationInfo::default(),
     ))
 }
+
+/// Handles the pop_log libfunc, popping the earliest unpopped event of the contract at `address`.
+fn build_pop_log(
+    mut casm_builder: CasmBuilder,
+    address: Var,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    casm_build_extend! {casm_builder,
+        tempvar variant;
+        tempvar keys_start;
+        tempvar keys_end;
+        tempvar data_start;
+        tempvar data_end;
+        hint PopLog {
+            value: address
+        } into {
+            opt_variant: variant,
+            keys_start: keys_start,
+            keys_end: keys_end,
+            data_start: data_start,
+            data_end: data_end
+        };
+        ap += 5;
+        jump None if variant != 0;
+    };
+    let none_handle = get_non_fallthrough_statement_id(&builder);
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[keys_start, keys_end], &[data_start, data_end]], None),
+            ("None", &[], Some(none_handle)),
+        ],
+        CostValidationInfo::default(),
+    ))
+}
+
+/// Handles the pop_l2_to_l1_message libfunc, popping the earliest unpopped message sent to L1 by
+/// the contract at `address`.
+fn build_pop_l2_to_l1_message(
+    mut casm_builder: CasmBuilder,
+    address: Var,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    casm_build_extend! {casm_builder,
+        tempvar variant;
+        tempvar to_address;
+        tempvar payload_start;
+        tempvar payload_end;
+        hint PopL2ToL1Message {
+            value: address
+        } into {
+            opt_variant: variant,
+            to_address: to_address,
+            payload_start: payload_start,
+            payload_end: payload_end
+        };
+        ap += 4;
+        jump None if variant != 0;
+    };
+    let none_handle = get_non_fallthrough_statement_id(&builder);
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[to_address], &[payload_start, payload_end]], None),
+            ("None", &[], Some(none_handle)),
+        ],
+        CostValidationInfo::default(),
+    ))
+}
@@ -29,6 +29,8 @@ pub fn build(
         Uint128Concrete::Equal(_) => misc::build_cell_eq(builder),
         Uint128Concrete::SquareRoot(_) => super::uint::build_sqrt(builder),
         Uint128Concrete::LessThanOrEqual(_) => super::uint::build_less_than_or_equal(builder),
+        Uint128Concrete::GuaranteeMul(_) => build_u128_guarantee_mul(builder),
+        Uint128Concrete::MulGuaranteeVerify(_) => build_u128_mul_guarantee_verify(builder),
     }
 }
 
@@ -294,6 +296,111 @@ fn build_u128_widemul(
     ))
 }
 
+/// Handles a u128 multiplication, returning the result words and a guarantee for them, without
+/// verifying it.
+fn build_u128_guarantee_mul(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [a, b] = builder.try_get_single_cells()?;
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        deref a;
+        deref b;
+    };
+    casm_build_extend! {casm_builder,
+        tempvar high;
+        tempvar low;
+        hint WideMul128 { lhs: a, rhs: b } into { high: high, low: low };
+        ap += 2;
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&[high], &[low], &[a, b, high, low]], None)],
+        Default::default(),
+    ))
+}
+
+/// Handles the verification of a u128 multiplication guarantee, i.e. that
+/// `a * b == 2**128 * high + low`, where `high` and `low` are in [0, 2**128).
+fn build_u128_mul_guarantee_verify(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, guarantee] = builder.try_get_refs()?;
+    let range_check = range_check.try_unpack_single()?;
+    let [a, b, high, low] = guarantee.try_unpack()?;
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(8) range_check;
+        deref a;
+        deref b;
+        deref high;
+        deref low;
+    };
+    casm_build_extend! {casm_builder,
+        let orig_range_check = range_check;
+        tempvar a0;
+        tempvar a1;
+        const u64_limit = u64::MAX as u128 + 1;
+        // Break a into two 64bit halves s.t. a = a1 * 2**64 + a0.
+        hint DivMod { lhs: a, rhs: u64_limit } into { quotient: a1, remainder: a0 };
+        // Verify that a0 < 2**64 by constraining a0 + (2**128-1) - (2**64-1) < 2**128.
+        const u64_upper_fixer = u128::MAX - u64::MAX as u128;
+        tempvar fixed_a0 = a0 + u64_upper_fixer;
+        assert fixed_a0 = *(range_check++);
+        // Verify that a0, a1 are in [0, 2**128).
+        assert a0 = *(range_check++);
+        assert a1 = *(range_check++);
+        // Check the break: a = a1 * 2**64 + a0.
+        tempvar a1_times_2_64 = a1 * u64_limit;
+        assert a = a1_times_2_64 + a0;
+
+        // Same as in `u128_wide_mul`, a0_b and a1_b each fit within 192 bits, so a1_b is broken
+        // into its lower 64 bits, added to the lower word, and the rest, added to the upper word.
+        tempvar a0_b = a0 * b;
+        tempvar a1_b = a1 * b;
+        tempvar partial_upper_word;
+        tempvar a1_b0_bottom;
+        hint DivMod {
+            lhs: a1_b,
+            rhs: u64_limit
+        } into { quotient: partial_upper_word, remainder: a1_b0_bottom };
+        // Verify that a1_b0_bottom is in [0, 2**64) and partial_upper_word in [0, 2**128).
+        tempvar fixed_a1_b0_bottom = a1_b0_bottom + u64_upper_fixer;
+        assert fixed_a1_b0_bottom = *(range_check++);
+        assert a1_b0_bottom = *(range_check++);
+        assert partial_upper_word = *(range_check++);
+        // Check the break.
+        tempvar partial_upper_word_times_2_64 = partial_upper_word * u64_limit;
+        assert a1_b = partial_upper_word_times_2_64 + a1_b0_bottom;
+
+        // Compute the carry from the lower word into the upper word, which is in [0, 2**65), as
+        // `lower_uint128_with_carry` is bounded by 193 bits.
+        tempvar shifted_a1_b0_bottom = a1_b0_bottom * u64_limit;
+        tempvar lower_uint128_with_carry = a0_b + shifted_a1_b0_bottom;
+        tempvar shifted_carry = lower_uint128_with_carry - low;
+        const u128_limit = (BigInt::from(u128::MAX) + 1) as BigInt;
+        tempvar carry = shifted_carry / u128_limit;
+        // Verify that `carry` is in [0, 2**65) and `low` is in [0, 2**128). Then
+        // `lower_uint128_with_carry = carry * 2**128 + low` holds over the integers, as there is
+        // no wrap-around within 193 bits.
+        const carry_range_fixer = u128::MAX - (2u128.pow(65) - 1);
+        tempvar fixed_carry = carry + carry_range_fixer;
+        assert fixed_carry = *(range_check++);
+        assert carry = *(range_check++);
+        assert low = *(range_check++);
+        // The upper word is determined by the rest of the bits.
+        assert high = partial_upper_word + carry;
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&[range_check]], None)],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
+
 /// Handles a casting a felt252 into u128.
 fn build_u128_from_felt252(
     builder: CompiledInvocationBuilder<'_>,
@@ -0,0 +1,282 @@
+use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_sierra::extensions::uint256::Uint256Concrete;
+use num_bigint::BigInt;
+
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::{
+    add_input_variables, get_non_fallthrough_statement_id, CostValidationInfo,
+};
+
+/// Builds instructions for Sierra u256 operations.
+pub fn build(
+    libfunc: &Uint256Concrete,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        Uint256Concrete::IsZero(_) => build_u256_is_zero(builder),
+        Uint256Concrete::Divmod(_) => build_u256_divmod(builder),
+        Uint256Concrete::SquareRoot(_) => build_u256_sqrt(builder),
+    }
+}
+
+/// Handles a u256 is_zero operation.
+fn build_u256_is_zero(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [value] = builder.try_get_refs()?;
+    let [low, high] = value.try_unpack()?;
+    let target_statement_id = get_non_fallthrough_statement_id(&builder);
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        deref low;
+        deref high;
+    };
+    casm_build_extend! {casm_builder,
+        jump Target if low != 0;
+        jump Target if high != 0;
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[], None), ("Target", &[&[low, high]], Some(target_statement_id))],
+        Default::default(),
+    ))
+}
+
+/// Handles a u256 divmod operation.
+///
+/// The quotient and remainder are given by a hint, and verified, except for the multiplication of
+/// the lower words of the quotient and the divisor, for which a guarantee is returned.
+fn build_u256_divmod(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, dividend, divisor] = builder.try_get_refs()?;
+    let range_check = range_check.try_unpack_single()?;
+    let [dividend0, dividend1] = dividend.try_unpack()?;
+    let [divisor0, divisor1] = divisor.try_unpack()?;
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(6) range_check;
+        deref dividend0;
+        deref dividend1;
+        deref divisor0;
+        deref divisor1;
+    };
+    casm_build_extend! {casm_builder,
+        let orig_range_check = range_check;
+        const zero = 0;
+        const one = 1;
+        const u64_limit = u64::MAX as u128 + 1;
+        const u128_bound_minus_u64_bound = u128::MAX - u64::MAX as u128;
+        const u128_limit = (BigInt::from(u128::MAX) + 1) as BigInt;
+        tempvar quotient0;
+        tempvar quotient1;
+        tempvar remainder0;
+        tempvar remainder1;
+        hint Uint256DivMod {
+            dividend0: dividend0,
+            dividend1: dividend1,
+            divisor0: divisor0,
+            divisor1: divisor1
+        } into {
+            quotient0: quotient0,
+            quotient1: quotient1,
+            remainder0: remainder0,
+            remainder1: remainder1
+        };
+        // Verify that the words of the quotient and the remainder are in [0, 2**128).
+        assert quotient0 = *(range_check++);
+        assert quotient1 = *(range_check++);
+        assert remainder0 = *(range_check++);
+        assert remainder1 = *(range_check++);
+    };
+    casm_build_extend! {casm_builder,
+        // Verify that `remainder < divisor`.
+        tempvar diff1 = divisor1 - remainder1;
+        tempvar diff0;
+        tempvar diff_minus_1;
+        jump HighDiff if diff1 != 0;
+        // The higher words are equal, so the lower words must satisfy it.
+        assert diff0 = divisor0 - remainder0;
+        assert diff_minus_1 = diff0 - one;
+        assert diff_minus_1 = *(range_check++);
+        jump RemainderVerified;
+    HighDiff:
+        assert diff_minus_1 = diff1 - one;
+        assert diff_minus_1 = *(range_check++);
+    RemainderVerified:
+    };
+    casm_build_extend! {casm_builder,
+        // As `divisor > 0` and `quotient * divisor <= dividend < 2**256`, at least one of
+        // `quotient1` and `divisor1` is zero. The cross term of the multiplication is therefore
+        // `quotient1 * divisor0` if `divisor1` is zero, and `quotient0 * divisor1` otherwise.
+        tempvar cross_x;
+        tempvar cross_y;
+        jump DivisorHighNonZero if divisor1 != 0;
+        assert cross_x = quotient1;
+        assert cross_y = divisor0;
+        jump CrossTermFound;
+    DivisorHighNonZero:
+        assert quotient1 = zero;
+        assert cross_x = quotient0;
+        assert cross_y = divisor1;
+    CrossTermFound:
+        // The cross term must be below 2**128, so one of its factors must be below 2**64. Having
+        // it verified, the product is below 2**192 and is computed without wrap-around.
+        tempvar cross_x_is_small;
+        hint TestLessThan { lhs: cross_x, rhs: u64_limit } into { dst: cross_x_is_small };
+        tempvar cross_small;
+        tempvar cross_large;
+        jump CrossXIsSmall if cross_x_is_small != 0;
+        assert cross_small = cross_y;
+        assert cross_large = cross_x;
+        jump CrossTermOrdered;
+    CrossXIsSmall:
+        assert cross_small = cross_x;
+        assert cross_large = cross_y;
+    CrossTermOrdered:
+        tempvar fixed_cross_small = cross_small + u128_bound_minus_u64_bound;
+        assert fixed_cross_small = *(range_check++);
+        tempvar cross = cross_small * cross_large;
+    };
+    casm_build_extend! {casm_builder,
+        // Verify that `quotient * divisor + remainder == dividend`, where the words of
+        // `quotient0 * divisor0` are given by a hint and covered by the returned guarantee.
+        tempvar q0d0_high;
+        tempvar q0d0_low;
+        hint WideMul128 { lhs: quotient0, rhs: divisor0 } into { high: q0d0_high, low: q0d0_low };
+        // The lower word, with a carry of 0 or 1.
+        tempvar part0 = q0d0_low + remainder0;
+        tempvar part1 = part0 - dividend0;
+        tempvar carry = part1 / u128_limit;
+        assert carry = carry * carry;
+        // The higher word, which must have no carry.
+        tempvar part2 = q0d0_high + carry;
+        tempvar part3 = part2 + cross;
+        assert dividend1 = part3 + remainder1;
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [(
+            "Fallthrough",
+            &[
+                &[range_check],
+                &[quotient0, quotient1],
+                &[remainder0, remainder1],
+                &[quotient0, divisor0, q0d0_high, q0d0_low],
+            ],
+            None,
+        )],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
+
+/// Handles a u256 floored square root operation.
+fn build_u256_sqrt(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, value] = builder.try_get_refs()?;
+    let range_check = range_check.try_unpack_single()?;
+    let [value_low, value_high] = value.try_unpack()?;
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(11) range_check;
+        deref value_low;
+        deref value_high;
+    };
+    casm_build_extend! {casm_builder,
+        let orig_range_check = range_check;
+        const u64_limit = u64::MAX as u128 + 1;
+        const u128_bound_minus_u64_bound = u128::MAX - u64::MAX as u128;
+        const u128_bound_minus_3 = u128::MAX - 2;
+        const u128_limit = (BigInt::from(u128::MAX) + 1) as BigInt;
+        tempvar sqrt0;
+        tempvar sqrt1;
+        tempvar remainder_low;
+        tempvar remainder_high;
+        tempvar sqrt_mul_2_minus_remainder_ge_u128;
+        hint Uint256SquareRoot {
+            value_low: value_low,
+            value_high: value_high
+        } into {
+            sqrt0: sqrt0,
+            sqrt1: sqrt1,
+            remainder_low: remainder_low,
+            remainder_high: remainder_high,
+            sqrt_mul_2_minus_remainder_ge_u128: sqrt_mul_2_minus_remainder_ge_u128
+        };
+        // Verify that `sqrt0` and `sqrt1` are in [0, 2**64), `remainder_low` is in [0, 2**128) and
+        // `remainder_high` is 0 or 1.
+        tempvar fixed_sqrt0 = sqrt0 + u128_bound_minus_u64_bound;
+        assert fixed_sqrt0 = *(range_check++);
+        assert sqrt0 = *(range_check++);
+        tempvar fixed_sqrt1 = sqrt1 + u128_bound_minus_u64_bound;
+        assert fixed_sqrt1 = *(range_check++);
+        assert sqrt1 = *(range_check++);
+        assert remainder_low = *(range_check++);
+        assert remainder_high = remainder_high * remainder_high;
+    };
+    casm_build_extend! {casm_builder,
+        // Verify that `value == sqrt**2 + remainder`, where `sqrt = sqrt0 + 2**64 * sqrt1`, and
+        // `sqrt**2 = sqrt0**2 + 2**64 * (2 * sqrt0 * sqrt1) + 2**128 * sqrt1**2`.
+        tempvar sqrt0_squared = sqrt0 * sqrt0;
+        tempvar sqrt1_squared = sqrt1 * sqrt1;
+        tempvar sqrt0_sqrt1 = sqrt0 * sqrt1;
+        tempvar sqrt0_sqrt1_mul_2 = sqrt0_sqrt1 + sqrt0_sqrt1;
+        // Break `2 * sqrt0 * sqrt1`, which is below 2**129, into its lower 64 bits, that go into
+        // the lower word, and the rest, that goes into the higher word.
+        tempvar middle_high;
+        tempvar middle_low;
+        hint DivMod {
+            lhs: sqrt0_sqrt1_mul_2,
+            rhs: u64_limit
+        } into { quotient: middle_high, remainder: middle_low };
+        tempvar fixed_middle_low = middle_low + u128_bound_minus_u64_bound;
+        assert fixed_middle_low = *(range_check++);
+        assert middle_low = *(range_check++);
+        assert middle_high = *(range_check++);
+        tempvar middle_high_shifted = middle_high * u64_limit;
+        assert sqrt0_sqrt1_mul_2 = middle_high_shifted + middle_low;
+        // The lower word, with a carry in [0, 3), as it is a sum of three values below 2**128.
+        tempvar middle_low_shifted = middle_low * u64_limit;
+        tempvar low_part0 = sqrt0_squared + middle_low_shifted;
+        tempvar low_part1 = low_part0 + remainder_low;
+        tempvar low_diff = low_part1 - value_low;
+        tempvar carry = low_diff / u128_limit;
+        tempvar fixed_carry = carry + u128_bound_minus_3;
+        assert fixed_carry = *(range_check++);
+        assert carry = *(range_check++);
+        // The higher word, which must have no carry.
+        tempvar high_part0 = sqrt1_squared + middle_high;
+        tempvar high_part1 = high_part0 + remainder_high;
+        assert value_high = high_part1 + carry;
+    };
+    casm_build_extend! {casm_builder,
+        // Verify that `remainder <= 2 * sqrt`, so that `(sqrt + 1)**2 > value`.
+        tempvar sqrt1_shifted = sqrt1 * u64_limit;
+        tempvar sqrt = sqrt1_shifted + sqrt0;
+        tempvar sqrt_mul_2 = sqrt + sqrt;
+        tempvar remainder_high_shifted = remainder_high * u128_limit;
+        tempvar remainder = remainder_high_shifted + remainder_low;
+        tempvar sqrt_mul_2_minus_remainder = sqrt_mul_2 - remainder;
+        tempvar shifted_sqrt_mul_2_minus_remainder;
+        jump GreaterOrEqualU128 if sqrt_mul_2_minus_remainder_ge_u128 != 0;
+        assert sqrt_mul_2_minus_remainder = *(range_check++);
+        jump Done;
+    GreaterOrEqualU128:
+        assert shifted_sqrt_mul_2_minus_remainder = sqrt_mul_2_minus_remainder - u128_limit;
+        assert shifted_sqrt_mul_2_minus_remainder = *(range_check++);
+    Done:
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&[range_check], &[sqrt]], None)],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
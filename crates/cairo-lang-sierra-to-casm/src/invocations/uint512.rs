@@ -0,0 +1,200 @@
+use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_sierra::extensions::uint512::Uint512Concrete;
+use num_bigint::BigInt;
+
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::{add_input_variables, CostValidationInfo};
+
+/// Builds instructions for Sierra u512 operations.
+pub fn build(
+    libfunc: &Uint512Concrete,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        Uint512Concrete::DivModU256(_) => build_u512_divmod_by_u256(builder),
+    }
+}
+
+/// Handles a u512 divmod by u256 operation.
+///
+/// The quotient and remainder are given by a hint, and verified, except for the multiplications of
+/// the words of the quotient and the divisor, for which guarantees are returned.
+fn build_u512_divmod_by_u256(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, dividend, divisor] = builder.try_get_refs()?;
+    let range_check = range_check.try_unpack_single()?;
+    let [dividend0, dividend1, dividend2, dividend3] = dividend.try_unpack()?;
+    let [divisor0, divisor1] = divisor.try_unpack()?;
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(12) range_check;
+        deref dividend0;
+        deref dividend1;
+        deref dividend2;
+        deref dividend3;
+        deref divisor0;
+        deref divisor1;
+    };
+    casm_build_extend! {casm_builder,
+        let orig_range_check = range_check;
+        const zero = 0;
+        const one = 1;
+        const u64_limit = u64::MAX as u128 + 1;
+        const u128_bound_minus_u64_bound = u128::MAX - u64::MAX as u128;
+        const u128_bound_minus_4 = u128::MAX - 3;
+        const u128_limit = (BigInt::from(u128::MAX) + 1) as BigInt;
+        tempvar quotient0;
+        tempvar quotient1;
+        tempvar quotient2;
+        tempvar quotient3;
+        tempvar remainder0;
+        tempvar remainder1;
+        hint Uint512DivModByUint256 {
+            dividend0: dividend0,
+            dividend1: dividend1,
+            dividend2: dividend2,
+            dividend3: dividend3,
+            divisor0: divisor0,
+            divisor1: divisor1
+        } into {
+            quotient0: quotient0,
+            quotient1: quotient1,
+            quotient2: quotient2,
+            quotient3: quotient3,
+            remainder0: remainder0,
+            remainder1: remainder1
+        };
+        // Verify that the words of the quotient and the remainder are in [0, 2**128).
+        assert quotient0 = *(range_check++);
+        assert quotient1 = *(range_check++);
+        assert quotient2 = *(range_check++);
+        assert quotient3 = *(range_check++);
+        assert remainder0 = *(range_check++);
+        assert remainder1 = *(range_check++);
+    };
+    casm_build_extend! {casm_builder,
+        // Verify that `remainder < divisor`.
+        tempvar diff1 = divisor1 - remainder1;
+        tempvar diff0;
+        tempvar diff_minus_1;
+        jump HighDiff if diff1 != 0;
+        // The higher words are equal, so the lower words must satisfy it.
+        assert diff0 = divisor0 - remainder0;
+        assert diff_minus_1 = diff0 - one;
+        assert diff_minus_1 = *(range_check++);
+        jump RemainderVerified;
+    HighDiff:
+        assert diff_minus_1 = diff1 - one;
+        assert diff_minus_1 = *(range_check++);
+    RemainderVerified:
+    };
+    casm_build_extend! {casm_builder,
+        // As `divisor > 0` and `quotient * divisor <= dividend < 2**512`, at least one of
+        // `quotient3` and `divisor1` is zero. The remaining term of the highest word is therefore
+        // `quotient3 * divisor0` if `divisor1` is zero, and `quotient2 * divisor1` otherwise.
+        tempvar cross_x;
+        tempvar cross_y;
+        jump DivisorHighNonZero if divisor1 != 0;
+        assert cross_x = quotient3;
+        assert cross_y = divisor0;
+        jump CrossTermFound;
+    DivisorHighNonZero:
+        assert quotient3 = zero;
+        assert cross_x = quotient2;
+        assert cross_y = divisor1;
+    CrossTermFound:
+        // The term must be below 2**128, so one of its factors must be below 2**64. Having it
+        // verified, the product is below 2**192 and is computed without wrap-around.
+        tempvar cross_x_is_small;
+        hint TestLessThan { lhs: cross_x, rhs: u64_limit } into { dst: cross_x_is_small };
+        tempvar cross_small;
+        tempvar cross_large;
+        jump CrossXIsSmall if cross_x_is_small != 0;
+        assert cross_small = cross_y;
+        assert cross_large = cross_x;
+        jump CrossTermOrdered;
+    CrossXIsSmall:
+        assert cross_small = cross_x;
+        assert cross_large = cross_y;
+    CrossTermOrdered:
+        tempvar fixed_cross_small = cross_small + u128_bound_minus_u64_bound;
+        assert fixed_cross_small = *(range_check++);
+        tempvar cross = cross_small * cross_large;
+    };
+    casm_build_extend! {casm_builder,
+        // The words of the products of the quotient and divisor words, given by hints and covered
+        // by the returned guarantees.
+        tempvar q0d0_high;
+        tempvar q0d0_low;
+        hint WideMul128 { lhs: quotient0, rhs: divisor0 } into { high: q0d0_high, low: q0d0_low };
+        tempvar q1d0_high;
+        tempvar q1d0_low;
+        hint WideMul128 { lhs: quotient1, rhs: divisor0 } into { high: q1d0_high, low: q1d0_low };
+        tempvar q2d0_high;
+        tempvar q2d0_low;
+        hint WideMul128 { lhs: quotient2, rhs: divisor0 } into { high: q2d0_high, low: q2d0_low };
+        tempvar q0d1_high;
+        tempvar q0d1_low;
+        hint WideMul128 { lhs: quotient0, rhs: divisor1 } into { high: q0d1_high, low: q0d1_low };
+        tempvar q1d1_high;
+        tempvar q1d1_low;
+        hint WideMul128 { lhs: quotient1, rhs: divisor1 } into { high: q1d1_high, low: q1d1_low };
+        ap += 10;
+    };
+    casm_build_extend! {casm_builder,
+        // Verify that `quotient * divisor + remainder == dividend`, word by word.
+        // The first word, with a carry of 0 or 1.
+        tempvar part0 = q0d0_low + remainder0;
+        tempvar part1 = part0 - dividend0;
+        tempvar carry0 = part1 / u128_limit;
+        assert carry0 = carry0 * carry0;
+        // The second word, with a carry in [0, 4), as it is a sum of four values below 2**128 and
+        // a previous carry.
+        tempvar part2 = q0d0_high + q1d0_low;
+        tempvar part3 = part2 + q0d1_low;
+        tempvar part4 = part3 + remainder1;
+        tempvar part5 = part4 + carry0;
+        tempvar part6 = part5 - dividend1;
+        tempvar carry1 = part6 / u128_limit;
+        tempvar fixed_carry1 = carry1 + u128_bound_minus_4;
+        assert fixed_carry1 = *(range_check++);
+        assert carry1 = *(range_check++);
+        // The third word, with a carry in [0, 4), for the same reason.
+        tempvar part7 = q1d0_high + q0d1_high;
+        tempvar part8 = part7 + q2d0_low;
+        tempvar part9 = part8 + q1d1_low;
+        tempvar part10 = part9 + carry1;
+        tempvar part11 = part10 - dividend2;
+        tempvar carry2 = part11 / u128_limit;
+        tempvar fixed_carry2 = carry2 + u128_bound_minus_4;
+        assert fixed_carry2 = *(range_check++);
+        assert carry2 = *(range_check++);
+        // The fourth word, which must have no carry.
+        tempvar part12 = q2d0_high + q1d1_high;
+        tempvar part13 = part12 + cross;
+        assert dividend3 = part13 + carry2;
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [(
+            "Fallthrough",
+            &[
+                &[range_check],
+                &[quotient0, quotient1, quotient2, quotient3],
+                &[remainder0, remainder1],
+                &[quotient0, divisor0, q0d0_high, q0d0_low],
+                &[quotient1, divisor0, q1d0_high, q1d0_low],
+                &[quotient2, divisor0, q2d0_high, q2d0_low],
+                &[quotient0, divisor1, q0d1_high, q0d1_low],
+                &[quotient1, divisor1, q1d1_high, q1d1_low],
+            ],
+            None,
+        )],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::fmt::Display;
 use std::hash::Hash;
 
 use itertools::Itertools;
@@ -6,30 +7,39 @@ use serde::{Deserialize, Serialize};
 use smol_str::SmolStr;
 
 use crate::ids::{ConcreteLibfuncId, ConcreteTypeId, FunctionId};
-use crate::program::{GenericArg, Program, Statement};
+use crate::program::{GenericArg, Program, Statement, StatementIdx};
 
 #[cfg(test)]
 #[path = "debug_info_test.rs"]
 mod test;
 
 /// Debug information for a Sierra program, to get readable names.
-#[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct DebugInfo {
     #[serde(
-        serialize_with = "serialize_map::<ConcreteTypeId, _>",
-        deserialize_with = "deserialize_map::<ConcreteTypeId, _>"
+        serialize_with = "serialize_map::<ConcreteTypeId, _, _>",
+        deserialize_with = "deserialize_map::<ConcreteTypeId, _, _>"
     )]
     pub type_names: HashMap<ConcreteTypeId, SmolStr>,
     #[serde(
-        serialize_with = "serialize_map::<ConcreteLibfuncId, _>",
-        deserialize_with = "deserialize_map::<ConcreteLibfuncId, _>"
+        serialize_with = "serialize_map::<ConcreteLibfuncId, _, _>",
+        deserialize_with = "deserialize_map::<ConcreteLibfuncId, _, _>"
     )]
     pub libfunc_names: HashMap<ConcreteLibfuncId, SmolStr>,
     #[serde(
-        serialize_with = "serialize_map::<FunctionId, _>",
-        deserialize_with = "deserialize_map::<FunctionId, _>"
+        serialize_with = "serialize_map::<FunctionId, _, _>",
+        deserialize_with = "deserialize_map::<FunctionId, _, _>"
     )]
     pub user_func_names: HashMap<FunctionId, SmolStr>,
+    /// The locations in the Cairo source code of the statements, for the statements with a known
+    /// location.
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "serialize_map::<StatementIdx, _, _>",
+        deserialize_with = "deserialize_map::<StatementIdx, _, _>"
+    )]
+    pub statement_locations: HashMap<StatementIdx, SourceLocation>,
 }
 impl DebugInfo {
     /// Extracts the existing debug info from a program.
@@ -59,6 +69,7 @@ impl DebugInfo {
                     func.id.debug_name.clone().map(|name| (FunctionId::new(func.id.id), name))
                 })
                 .collect(),
+            statement_locations: HashMap::new(),
         }
     }
 
@@ -128,6 +139,22 @@ impl DebugInfo {
     }
 }
 
+/// A location in the Cairo source code.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SourceLocation {
+    /// The path of the source file.
+    pub file_name: SmolStr,
+    /// The line in the file, starting from 1.
+    pub line: usize,
+    /// The column in the line, starting from 1.
+    pub col: usize,
+}
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.file_name, self.line, self.col)
+    }
+}
+
 /// Trait for handling serde for the ids as map keys.
 pub trait IdAsHashKey: Hash + Eq {
     /// Gets the inner id.
@@ -164,19 +191,30 @@ impl IdAsHashKey for FunctionId {
     }
 }
 
-fn serialize_map<Id: IdAsHashKey, S: serde::Serializer>(
-    m: &HashMap<Id, SmolStr>,
+impl IdAsHashKey for StatementIdx {
+    fn get(&self) -> u64 {
+        self.0 as u64
+    }
+
+    fn new(id: u64) -> Self {
+        Self(id as usize)
+    }
+}
+
+fn serialize_map<Id: IdAsHashKey, Value: Serialize, S: serde::Serializer>(
+    m: &HashMap<Id, Value>,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
-    let v: Vec<_> = m.iter().map(|(id, name)| (id.get(), name)).sorted().collect();
+    let v: Vec<_> =
+        m.iter().map(|(id, value)| (id.get(), value)).sorted_by_key(|(id, _)| *id).collect();
     v.serialize(serializer)
 }
 
-fn deserialize_map<'de, Id: IdAsHashKey, D: serde::Deserializer<'de>>(
+fn deserialize_map<'de, Id: IdAsHashKey, Value: Deserialize<'de>, D: serde::Deserializer<'de>>(
     deserializer: D,
-) -> Result<HashMap<Id, SmolStr>, D::Error> {
-    Ok(Vec::<(u64, SmolStr)>::deserialize(deserializer)?
+) -> Result<HashMap<Id, Value>, D::Error> {
+    Ok(Vec::<(u64, Value)>::deserialize(deserializer)?
         .into_iter()
-        .map(|(id, name)| (Id::new(id), name))
+        .map(|(id, value)| (Id::new(id), value))
         .collect())
 }
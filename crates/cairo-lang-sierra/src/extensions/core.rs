@@ -4,6 +4,7 @@ use super::bitwise::{BitwiseLibfunc, BitwiseType};
 use super::boolean::BoolLibfunc;
 use super::branch_align::BranchAlignLibfunc;
 use super::builtin_cost::{BuiltinCostLibfunc, BuiltinCostsType};
+use super::bytes31::{Bytes31Libfunc, Bytes31Type};
 use super::casts::CastLibfunc;
 use super::debug::DebugLibfunc;
 use super::drop::DropLibfunc;
@@ -17,12 +18,19 @@ use super::modules::function_call::FunctionCallLibfunc;
 use super::modules::gas::{GasBuiltinType, GasLibfunc};
 use super::modules::mem::MemLibfunc;
 use super::modules::non_zero::{NonZeroType, UnwrapNonZeroLibfunc};
-use super::modules::uint128::{Uint128Libfunc, Uint128Type};
+use super::modules::uint128::{U128MulGuaranteeType, Uint128Libfunc, Uint128Type};
+use super::modules::uint256::Uint256Libfunc;
+use super::modules::uint512::Uint512Libfunc;
 use super::modules::unconditional_jump::UnconditionalJumpLibfunc;
 use super::nullable::{NullableLibfunc, NullableType};
 use super::pedersen::{PedersenLibfunc, PedersenType};
+use super::poseidon::{PoseidonLibfunc, PoseidonType};
 use super::range_check::RangeCheckType;
 use super::segment_arena::SegmentArenaType;
+use super::sint::{
+    Sint128Libfunc, Sint128Type, Sint16Libfunc, Sint16Type, Sint32Libfunc, Sint32Type,
+    Sint64Libfunc, Sint64Type, Sint8Libfunc, Sint8Type,
+};
 use super::snapshot::{SnapshotTakeLibfunc, SnapshotType};
 use super::squashed_felt252_dict::SquashedFelt252DictType;
 use super::starknet::{StarkNetLibfunc, StarkNetType};
@@ -50,6 +58,12 @@ define_type_hierarchy! {
         Uint32(Uint32Type),
         Uint64(Uint64Type),
         Uint128(Uint128Type),
+        Uint128MulGuarantee(U128MulGuaranteeType),
+        Sint8(Sint8Type),
+        Sint16(Sint16Type),
+        Sint32(Sint32Type),
+        Sint64(Sint64Type),
+        Sint128(Sint128Type),
         NonZero(NonZeroType),
         Nullable(NullableType),
         RangeCheck(RangeCheckType),
@@ -59,9 +73,11 @@ define_type_hierarchy! {
         Felt252Dict(Felt252DictType),
         SquashedFelt252Dict(SquashedFelt252DictType),
         Pedersen(PedersenType),
+        Poseidon(PoseidonType),
         StarkNet(StarkNetType),
         SegmentArena(SegmentArenaType),
         Snapshot(SnapshotType),
+        Bytes31(Bytes31Type),
     }, CoreTypeConcrete
 }
 
@@ -86,6 +102,13 @@ define_libfunc_hierarchy! {
         Uint32(Uint32Libfunc),
         Uint64(Uint64Libfunc),
         Uint128(Uint128Libfunc),
+        Uint256(Uint256Libfunc),
+        Uint512(Uint512Libfunc),
+        Sint8(Sint8Libfunc),
+        Sint16(Sint16Libfunc),
+        Sint32(Sint32Libfunc),
+        Sint64(Sint64Libfunc),
+        Sint128(Sint128Libfunc),
         Mem(MemLibfunc),
         Nullable(NullableLibfunc),
         UnwrapNonZero(UnwrapNonZeroLibfunc),
@@ -94,8 +117,10 @@ define_libfunc_hierarchy! {
         Struct(StructLibfunc),
         Felt252Dict(Felt252DictLibfunc),
         Pedersen(PedersenLibfunc),
+        Poseidon(PoseidonLibfunc),
         StarkNet(StarkNetLibfunc),
         Debug(DebugLibfunc),
         SnapshotTake(SnapshotTakeLibfunc),
+        Bytes31(Bytes31Libfunc),
     }, CoreConcreteLibfunc
 }
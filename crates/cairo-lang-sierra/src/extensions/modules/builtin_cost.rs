@@ -25,6 +25,8 @@ pub enum CostTokenType {
     Bitwise,
     /// One invocation of the EC op builtin.
     EcOp,
+    /// One invocation of the poseidon hades permutation.
+    Poseidon,
 }
 impl CostTokenType {
     pub fn iter()
@@ -33,7 +35,13 @@ impl CostTokenType {
     }
 
     pub fn iter_precost() -> std::slice::Iter<'static, Self> {
-        [CostTokenType::Pedersen, CostTokenType::Bitwise, CostTokenType::EcOp].iter()
+        [
+            CostTokenType::Pedersen,
+            CostTokenType::Bitwise,
+            CostTokenType::EcOp,
+            CostTokenType::Poseidon,
+        ]
+        .iter()
     }
 
     /// Returns the name of the token type, in snake_case.
@@ -43,6 +51,7 @@ impl CostTokenType {
             CostTokenType::Pedersen => "pedersen",
             CostTokenType::Bitwise => "bitwise",
             CostTokenType::EcOp => "ec_op",
+            CostTokenType::Poseidon => "poseidon",
         }
         .into()
     }
@@ -59,6 +68,7 @@ impl CostTokenType {
             CostTokenType::Pedersen => 0,
             CostTokenType::Bitwise => 1,
             CostTokenType::EcOp => 2,
+            CostTokenType::Poseidon => 3,
         }
     }
 }
@@ -1,6 +1,9 @@
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
 
 use super::range_check::RangeCheckType;
+use super::sint::{Sint128Type, Sint16Type, Sint32Type, Sint64Type, Sint8Type};
 use super::uint::Uint16Type;
 use crate::define_libfunc_hierarchy;
 use crate::extensions::lib_func::{
@@ -24,42 +27,94 @@ define_libfunc_hierarchy! {
     }, CastConcreteLibfunc
 }
 
-/// Returns a map from (concrete) integer type to the number of bits in the type.
-fn get_type_to_nbits_map(
+/// The range of values of an integer type: `[lower, upper)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Range {
+    pub lower: BigInt,
+    pub upper: BigInt,
+}
+impl Range {
+    /// Returns the range of an unsigned integer type with `n_bits` bits.
+    fn unsigned(n_bits: usize) -> Self {
+        Self { lower: BigInt::zero(), upper: BigInt::one() << n_bits }
+    }
+
+    /// Returns the range of a signed integer type with `n_bits` bits.
+    fn signed(n_bits: usize) -> Self {
+        let half = BigInt::one() << (n_bits - 1);
+        Self { lower: -half.clone(), upper: half }
+    }
+
+    /// Returns true if the range starts at zero, i.e. it is the range of an unsigned type.
+    pub fn is_unsigned(&self) -> bool {
+        self.lower.is_zero()
+    }
+
+    /// Returns the number of values in the range.
+    pub fn size(&self) -> BigInt {
+        &self.upper - &self.lower
+    }
+
+    /// Returns true if all the values of `self` are also in `other`.
+    pub fn is_subrange_of(&self, other: &Range) -> bool {
+        other.lower <= self.lower && self.upper <= other.upper
+    }
+}
+
+/// The checks required by a downcast, according to the values of the source type that are out of
+/// the range of the destination type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CastType {
+    /// Whether the source type has values above the range of the destination type.
+    pub overflow_above: bool,
+    /// Whether the source type has values below the range of the destination type.
+    pub overflow_below: bool,
+}
+
+/// Returns a map from (concrete) integer type to the range of values of the type.
+fn get_type_to_range_map(
     context: &dyn SignatureSpecializationContext,
-) -> UnorderedHashMap<ConcreteTypeId, usize> {
+) -> UnorderedHashMap<ConcreteTypeId, Range> {
     vec![
-        (Uint8Type::ID, 8),
-        (Uint16Type::ID, 16),
-        (Uint32Type::ID, 32),
-        (Uint64Type::ID, 64),
-        (Uint128Type::ID, 128),
+        (Uint8Type::ID, Range::unsigned(8)),
+        (Uint16Type::ID, Range::unsigned(16)),
+        (Uint32Type::ID, Range::unsigned(32)),
+        (Uint64Type::ID, Range::unsigned(64)),
+        (Uint128Type::ID, Range::unsigned(128)),
+        (Sint8Type::ID, Range::signed(8)),
+        (Sint16Type::ID, Range::signed(16)),
+        (Sint32Type::ID, Range::signed(32)),
+        (Sint64Type::ID, Range::signed(64)),
+        (Sint128Type::ID, Range::signed(128)),
     ]
     .into_iter()
-    .filter_map(|(generic_type, n_bits)| {
-        Some((context.get_concrete_type(generic_type, &[]).ok()?, n_bits))
+    .filter_map(|(generic_type, range)| {
+        Some((context.get_concrete_type(generic_type, &[]).ok()?, range))
     })
     .collect()
 }
 
-/// Returns the number of bits for the given types.
+/// Returns the ranges of the given types.
 // TODO(lior): Convert to a generic function that can take arbitrary number of arguments once
 //   `try_map` is a stable feature.
-fn get_n_bits(
+fn get_ranges(
     context: &dyn SignatureSpecializationContext,
     from_type: &ConcreteTypeId,
     to_type: &ConcreteTypeId,
-) -> Result<(usize, usize), SpecializationError> {
-    let type_to_n_bits = get_type_to_nbits_map(context);
-    let from_nbits =
-        *type_to_n_bits.get(from_type).ok_or(SpecializationError::UnsupportedGenericArg)?;
-    let to_nbits =
-        *type_to_n_bits.get(to_type).ok_or(SpecializationError::UnsupportedGenericArg)?;
-    Ok((from_nbits, to_nbits))
+) -> Result<(Range, Range), SpecializationError> {
+    let mut type_to_range = get_type_to_range_map(context);
+    let from_range =
+        type_to_range.remove(from_type).ok_or(SpecializationError::UnsupportedGenericArg)?;
+    let to_range = if from_type == to_type {
+        from_range.clone()
+    } else {
+        type_to_range.remove(to_type).ok_or(SpecializationError::UnsupportedGenericArg)?
+    };
+    Ok((from_range, to_range))
 }
 
 /// Libfunc for casting from one type to another where any input value can fit into the destination
-/// type. For example, from u8 to u64.
+/// type. For example, from u8 to u64 or to i16.
 #[derive(Default)]
 pub struct UpcastLibfunc {}
 impl SignatureOnlyGenericLibfunc for UpcastLibfunc {
@@ -71,10 +126,8 @@ impl SignatureOnlyGenericLibfunc for UpcastLibfunc {
         args: &[GenericArg],
     ) -> Result<LibfuncSignature, SpecializationError> {
         let (from_ty, to_ty) = args_as_two_types(args)?;
-        let (from_nbits, to_nbits) = get_n_bits(context, &from_ty, &to_ty)?;
-
-        let is_valid = from_nbits <= to_nbits;
-        if !is_valid {
+        let (from_range, to_range) = get_ranges(context, &from_ty, &to_ty)?;
+        if !from_range.is_subrange_of(&to_range) {
             return Err(SpecializationError::UnsupportedGenericArg);
         }
 
@@ -93,9 +146,18 @@ impl SignatureOnlyGenericLibfunc for UpcastLibfunc {
 pub struct DowncastConcreteLibfunc {
     pub signature: LibfuncSignature,
     pub from_ty: ConcreteTypeId,
-    pub from_nbits: usize,
+    pub from_range: Range,
     pub to_ty: ConcreteTypeId,
-    pub to_nbits: usize,
+    pub to_range: Range,
+}
+impl DowncastConcreteLibfunc {
+    /// Returns the checks required for the cast.
+    pub fn cast_type(&self) -> CastType {
+        CastType {
+            overflow_above: self.from_range.upper > self.to_range.upper,
+            overflow_below: self.from_range.lower < self.to_range.lower,
+        }
+    }
 }
 impl SignatureBasedConcreteLibfunc for DowncastConcreteLibfunc {
     fn signature(&self) -> &LibfuncSignature {
@@ -104,7 +166,7 @@ impl SignatureBasedConcreteLibfunc for DowncastConcreteLibfunc {
 }
 
 /// Libfunc for casting from one type to another where the input value may not fit into the
-/// destination type. For example, from u64 to u8.
+/// destination type. For example, from u64 to u8 or from i8 to u8.
 #[derive(Default)]
 pub struct DowncastLibfunc {}
 impl NamedLibfunc for DowncastLibfunc {
@@ -117,10 +179,8 @@ impl NamedLibfunc for DowncastLibfunc {
         args: &[GenericArg],
     ) -> Result<LibfuncSignature, SpecializationError> {
         let (from_ty, to_ty) = args_as_two_types(args)?;
-        let (from_nbits, to_nbits) = get_n_bits(context, &from_ty, &to_ty)?;
-
-        let is_valid = from_nbits >= to_nbits;
-        if !is_valid {
+        let (from_range, to_range) = get_ranges(context, &from_ty, &to_ty)?;
+        if from_range.is_subrange_of(&to_range) {
             return Err(SpecializationError::UnsupportedGenericArg);
         }
 
@@ -173,14 +233,14 @@ impl NamedLibfunc for DowncastLibfunc {
         args: &[GenericArg],
     ) -> Result<Self::Concrete, SpecializationError> {
         let (from_ty, to_ty) = args_as_two_types(args)?;
-        let (from_nbits, to_nbits) = get_n_bits(context.upcast(), &from_ty, &to_ty)?;
+        let (from_range, to_range) = get_ranges(context.upcast(), &from_ty, &to_ty)?;
 
         Ok(DowncastConcreteLibfunc {
             signature: self.specialize_signature(context.upcast(), args)?,
             from_ty,
-            from_nbits,
+            from_range,
             to_ty,
-            to_nbits,
+            to_range,
         })
     }
 }
@@ -213,7 +213,7 @@ impl SignatureOnlyGenericLibfunc for EnumMatchLibfunc {
         let variant_types =
             EnumConcreteType::new(context.as_type_specialization_context(), &generic_args)?
                 .variants;
-        let branch_signatures = variant_types
+        let branch_signatures: Vec<_> = variant_types
             .into_iter()
             .map(|ty| BranchSignature {
                 vars: vec![OutputVarInfo {
@@ -224,10 +224,12 @@ impl SignatureOnlyGenericLibfunc for EnumMatchLibfunc {
             })
             .collect();
 
+        // Matching an enum with no variants has no branches, and therefore no fallthrough.
+        let fallthrough = if branch_signatures.is_empty() { None } else { Some(0) };
         Ok(LibfuncSignature {
             param_signatures: vec![enum_type.into()],
             branch_signatures,
-            fallthrough: Some(0),
+            fallthrough,
         })
     }
 }
@@ -12,6 +12,7 @@ pub mod boolean;
 pub mod boxing;
 pub mod branch_align;
 pub mod builtin_cost;
+pub mod bytes31;
 pub mod casts;
 pub mod consts;
 pub mod debug;
@@ -28,8 +29,10 @@ pub mod mem;
 pub mod non_zero;
 pub mod nullable;
 pub mod pedersen;
+pub mod poseidon;
 pub mod range_check;
 pub mod segment_arena;
+pub mod sint;
 pub mod snapshot;
 pub mod squashed_felt252_dict;
 pub mod starknet;
@@ -37,6 +40,8 @@ pub mod structure;
 pub mod try_from_felt252;
 pub mod uint;
 pub mod uint128;
+pub mod uint256;
+pub mod uint512;
 pub mod unconditional_jump;
 pub mod uninitialized;
 
@@ -0,0 +1,603 @@
+use std::marker::PhantomData;
+
+use num_bigint::BigInt;
+
+use super::felt252::Felt252Type;
+use super::is_zero::{IsZeroLibfunc, IsZeroTraits};
+use super::range_check::RangeCheckType;
+use super::try_from_felt252::{TryFromFelt252, TryFromFelt252Libfunc};
+use super::uint::IntOperator;
+use crate::define_libfunc_hierarchy;
+use crate::extensions::lib_func::{
+    BranchSignature, DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature,
+    SierraApChange, SignatureSpecializationContext, SpecializationContext,
+};
+use crate::extensions::{
+    GenericLibfunc, NamedLibfunc, NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType,
+    OutputVarReferenceInfo, SignatureBasedConcreteLibfunc, SpecializationError,
+};
+use crate::ids::{GenericLibfuncId, GenericTypeId};
+use crate::program::GenericArg;
+
+/// Trait for implementing signed integers.
+pub trait SintTraits: Default {
+    /// The rust matching type to this type.
+    type SintType: TryFrom<BigInt> + Into<BigInt> + Copy;
+    /// The number of bits of the type.
+    const N_BITS: usize;
+    /// The generic type id for this type.
+    const GENERIC_TYPE_ID: GenericTypeId;
+    /// The generic libfunc id for getting a const of this type.
+    const CONST: &'static str;
+    /// The generic libfunc id for comparing equality.
+    const EQUAL: &'static str;
+    /// The generic libfunc id for testing if less than.
+    const LESS_THAN: &'static str;
+    /// The generic libfunc id for testing if less than or equal.
+    const LESS_THAN_OR_EQUAL: &'static str;
+    /// The generic libfunc id for addition.
+    const OVERFLOWING_ADD: &'static str;
+    /// The generic libfunc id for subtraction.
+    const OVERFLOWING_SUB: &'static str;
+    /// The generic libfunc id for conversion to felt252.
+    const TO_FELT252: &'static str;
+    /// The generic libfunc id for conversion from felt252.
+    const TRY_FROM_FELT252: &'static str;
+}
+
+/// Trait for implementing multiplication for signed integers.
+pub trait SintMulTraits: SintTraits {
+    /// The generic libfunc id that multiplies two integers.
+    const WIDE_MUL: &'static str;
+    /// The generic type id for this type multiplication result.
+    const WIDE_MUL_RES_TYPE_ID: GenericTypeId;
+}
+
+#[derive(Default)]
+pub struct SintType<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NoGenericArgsGenericType for SintType<TSintTraits> {
+    const ID: GenericTypeId = TSintTraits::GENERIC_TYPE_ID;
+    const STORABLE: bool = true;
+    const DUPLICATABLE: bool = true;
+    const DROPPABLE: bool = true;
+    const SIZE: i16 = 1;
+}
+
+/// Libfunc for creating a constant signed integer.
+#[derive(Default)]
+pub struct SintConstLibfunc<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NamedLibfunc for SintConstLibfunc<TSintTraits> {
+    const STR_ID: &'static str = TSintTraits::CONST;
+    type Concrete = SintConstConcreteLibfunc<TSintTraits>;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        _args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch(
+            vec![],
+            vec![OutputVarInfo {
+                ty: context.get_concrete_type(TSintTraits::GENERIC_TYPE_ID, &[])?,
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Const),
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        match args {
+            [GenericArg::Value(c)] => Ok(Self::Concrete {
+                c: TSintTraits::SintType::try_from(c.clone())
+                    .map_err(|_| SpecializationError::UnsupportedGenericArg)?,
+                signature: <Self as NamedLibfunc>::specialize_signature(
+                    self,
+                    context.upcast(),
+                    args,
+                )?,
+            }),
+            _ => Err(SpecializationError::UnsupportedGenericArg),
+        }
+    }
+}
+
+pub struct SintConstConcreteLibfunc<TSintTraits: SintTraits> {
+    pub c: TSintTraits::SintType,
+    pub signature: LibfuncSignature,
+}
+impl<TSintTraits: SintTraits> SignatureBasedConcreteLibfunc
+    for SintConstConcreteLibfunc<TSintTraits>
+{
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
+
+/// Libfunc for comparing sints` equality.
+#[derive(Default)]
+pub struct SintEqualLibfunc<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NoGenericArgsGenericLibfunc for SintEqualLibfunc<TSintTraits> {
+    const STR_ID: &'static str = TSintTraits::EQUAL;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let ty = context.get_concrete_type(TSintTraits::GENERIC_TYPE_ID, &[])?;
+        let param_signatures = vec![
+            ParamSignature {
+                ty: ty.clone(),
+                allow_deferred: false,
+                allow_add_const: false,
+                allow_const: true,
+            },
+            ParamSignature { ty, allow_deferred: false, allow_add_const: false, allow_const: true },
+        ];
+        let branch_signatures = (0..2)
+            .map(|_| BranchSignature {
+                vars: vec![],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            })
+            .collect();
+        Ok(LibfuncSignature { param_signatures, branch_signatures, fallthrough: Some(0) })
+    }
+}
+
+/// Returns the signature of a libfunc comparing two sints, with a branch per result.
+fn sint_comparison_signature<TSintTraits: SintTraits>(
+    context: &dyn SignatureSpecializationContext,
+) -> Result<LibfuncSignature, SpecializationError> {
+    let ty = context.get_concrete_type(TSintTraits::GENERIC_TYPE_ID, &[])?;
+    let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+    let param_signatures = vec![
+        ParamSignature {
+            ty: range_check_type.clone(),
+            allow_deferred: false,
+            allow_add_const: true,
+            allow_const: false,
+        },
+        ParamSignature::new(ty.clone()),
+        ParamSignature::new(ty),
+    ];
+    let branch_signatures = (0..2)
+        .map(|_| BranchSignature {
+            vars: vec![OutputVarInfo {
+                ty: range_check_type.clone(),
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
+                    param_idx: 0,
+                }),
+            }],
+            ap_change: SierraApChange::Known { new_vars_only: false },
+        })
+        .collect();
+    Ok(LibfuncSignature { param_signatures, branch_signatures, fallthrough: Some(0) })
+}
+
+/// Libfunc for comparing sints.
+#[derive(Default)]
+pub struct SintLessThanLibfunc<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NoGenericArgsGenericLibfunc for SintLessThanLibfunc<TSintTraits> {
+    const STR_ID: &'static str = TSintTraits::LESS_THAN;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        sint_comparison_signature::<TSintTraits>(context)
+    }
+}
+
+/// Libfunc for comparing sints.
+#[derive(Default)]
+pub struct SintLessThanOrEqualLibfunc<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NoGenericArgsGenericLibfunc
+    for SintLessThanOrEqualLibfunc<TSintTraits>
+{
+    const STR_ID: &'static str = TSintTraits::LESS_THAN_OR_EQUAL;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        sint_comparison_signature::<TSintTraits>(context)
+    }
+}
+
+pub struct SintOperationConcreteLibfunc {
+    pub operator: IntOperator,
+    pub signature: LibfuncSignature,
+}
+impl SignatureBasedConcreteLibfunc for SintOperationConcreteLibfunc {
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
+
+/// Libfunc for sints operations.
+/// Has three branches: the result is in range, the result underflowed, or the result overflowed,
+/// where the last two return the wrapped result.
+pub struct SintOperationLibfunc<TSintTraits: SintTraits> {
+    pub operator: IntOperator,
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> SintOperationLibfunc<TSintTraits> {
+    const OVERFLOWING_ADD: &'static str = TSintTraits::OVERFLOWING_ADD;
+    const OVERFLOWING_SUB: &'static str = TSintTraits::OVERFLOWING_SUB;
+    fn new(operator: IntOperator) -> Option<Self> {
+        Some(Self { operator, _phantom: PhantomData })
+    }
+}
+impl<TSintTraits: SintTraits> GenericLibfunc for SintOperationLibfunc<TSintTraits> {
+    type Concrete = SintOperationConcreteLibfunc;
+
+    fn supported_ids() -> Vec<GenericLibfuncId> {
+        vec![
+            GenericLibfuncId::from(Self::OVERFLOWING_ADD),
+            GenericLibfuncId::from(Self::OVERFLOWING_SUB),
+        ]
+    }
+
+    fn by_id(id: &GenericLibfuncId) -> Option<Self> {
+        match id.0.as_str() {
+            id if id == Self::OVERFLOWING_ADD => Self::new(IntOperator::OverflowingAdd),
+            id if id == Self::OVERFLOWING_SUB => Self::new(IntOperator::OverflowingSub),
+            _ => None,
+        }
+    }
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        if !args.is_empty() {
+            return Err(SpecializationError::WrongNumberOfGenericArgs);
+        }
+        let ty = context.get_concrete_type(TSintTraits::GENERIC_TYPE_ID, &[])?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let branch_signatures = (0..3)
+            .map(|_| BranchSignature {
+                vars: vec![
+                    OutputVarInfo {
+                        ty: range_check_type.clone(),
+                        ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
+                            param_idx: 0,
+                        }),
+                    },
+                    OutputVarInfo {
+                        ty: ty.clone(),
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: None },
+                    },
+                ],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            })
+            .collect();
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                ParamSignature {
+                    ty: range_check_type,
+                    allow_deferred: false,
+                    allow_add_const: true,
+                    allow_const: false,
+                },
+                ParamSignature::new(ty.clone()),
+                ParamSignature::new(ty),
+            ],
+            branch_signatures,
+            fallthrough: Some(0),
+        })
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        Ok(SintOperationConcreteLibfunc {
+            operator: self.operator,
+            signature: self.specialize_signature(context.upcast(), args)?,
+        })
+    }
+}
+
+/// Libfunc for converting a sint into a felt252.
+#[derive(Default)]
+pub struct SintToFelt252Libfunc<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NoGenericArgsGenericLibfunc for SintToFelt252Libfunc<TSintTraits> {
+    const STR_ID: &'static str = TSintTraits::TO_FELT252;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![ParamSignature {
+                ty: context.get_concrete_type(TSintTraits::GENERIC_TYPE_ID, &[])?,
+                allow_deferred: true,
+                allow_add_const: true,
+                allow_const: true,
+            }],
+            vec![OutputVarInfo {
+                ty: context.get_concrete_type(Felt252Type::id(), &[])?,
+                ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 0 },
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+
+/// Libfunc for attempting to convert a felt252 into a sint.
+#[derive(Default)]
+pub struct SintFromFelt252Trait<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> TryFromFelt252 for SintFromFelt252Trait<TSintTraits> {
+    const STR_ID: &'static str = TSintTraits::TRY_FROM_FELT252;
+    const GENERIC_TYPE_ID: GenericTypeId = TSintTraits::GENERIC_TYPE_ID;
+}
+
+pub type SintFromFelt252Libfunc<T> = TryFromFelt252Libfunc<SintFromFelt252Trait<T>>;
+
+/// Libfunc for sint wide multiplication.
+#[derive(Default)]
+pub struct SintWideMulLibfunc<TSintMulTraits: SintMulTraits> {
+    _phantom: PhantomData<TSintMulTraits>,
+}
+impl<TSintMulTraits: SintMulTraits> NoGenericArgsGenericLibfunc
+    for SintWideMulLibfunc<TSintMulTraits>
+{
+    const STR_ID: &'static str = TSintMulTraits::WIDE_MUL;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let ty = context.get_concrete_type(TSintMulTraits::GENERIC_TYPE_ID, &[])?;
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![
+                ParamSignature::new(ty.clone()),
+                ParamSignature {
+                    ty,
+                    allow_deferred: false,
+                    allow_add_const: false,
+                    allow_const: true,
+                },
+            ],
+            vec![OutputVarInfo {
+                ty: context.get_concrete_type(TSintMulTraits::WIDE_MUL_RES_TYPE_ID, &[])?,
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+
+#[derive(Default)]
+pub struct Sint8Traits;
+
+impl SintTraits for Sint8Traits {
+    type SintType = i8;
+    const N_BITS: usize = 8;
+    const GENERIC_TYPE_ID: GenericTypeId = GenericTypeId::new_inline("i8");
+    const CONST: &'static str = "i8_const";
+    const EQUAL: &'static str = "i8_eq";
+    const LESS_THAN: &'static str = "i8_lt";
+    const LESS_THAN_OR_EQUAL: &'static str = "i8_le";
+    const OVERFLOWING_ADD: &'static str = "i8_overflowing_add_impl";
+    const OVERFLOWING_SUB: &'static str = "i8_overflowing_sub_impl";
+    const TO_FELT252: &'static str = "i8_to_felt252";
+    const TRY_FROM_FELT252: &'static str = "i8_try_from_felt252";
+}
+
+impl SintMulTraits for Sint8Traits {
+    const WIDE_MUL: &'static str = "i8_wide_mul";
+    const WIDE_MUL_RES_TYPE_ID: GenericTypeId = <Sint16Type as NamedType>::ID;
+}
+
+impl IsZeroTraits for Sint8Traits {
+    const IS_ZERO: &'static str = "i8_is_zero";
+    const GENERIC_TYPE_ID: GenericTypeId = <Sint8Type as NamedType>::ID;
+}
+
+/// Type for i8.
+pub type Sint8Type = SintType<Sint8Traits>;
+
+define_libfunc_hierarchy! {
+    pub enum Sint8Libfunc {
+        Const(SintConstLibfunc<Sint8Traits>),
+        Operation(SintOperationLibfunc<Sint8Traits>),
+        LessThan(SintLessThanLibfunc<Sint8Traits>),
+        Equal(SintEqualLibfunc<Sint8Traits>),
+        LessThanOrEqual(SintLessThanOrEqualLibfunc<Sint8Traits>),
+        ToFelt252(SintToFelt252Libfunc<Sint8Traits>),
+        FromFelt252(SintFromFelt252Libfunc<Sint8Traits>),
+        IsZero(IsZeroLibfunc<Sint8Traits>),
+        WideMul(SintWideMulLibfunc<Sint8Traits>),
+    }, Sint8Concrete
+}
+
+#[derive(Default)]
+pub struct Sint16Traits;
+
+impl SintTraits for Sint16Traits {
+    type SintType = i16;
+    const N_BITS: usize = 16;
+    const GENERIC_TYPE_ID: GenericTypeId = GenericTypeId::new_inline("i16");
+    const CONST: &'static str = "i16_const";
+    const EQUAL: &'static str = "i16_eq";
+    const LESS_THAN: &'static str = "i16_lt";
+    const LESS_THAN_OR_EQUAL: &'static str = "i16_le";
+    const OVERFLOWING_ADD: &'static str = "i16_overflowing_add_impl";
+    const OVERFLOWING_SUB: &'static str = "i16_overflowing_sub_impl";
+    const TO_FELT252: &'static str = "i16_to_felt252";
+    const TRY_FROM_FELT252: &'static str = "i16_try_from_felt252";
+}
+
+impl SintMulTraits for Sint16Traits {
+    const WIDE_MUL: &'static str = "i16_wide_mul";
+    const WIDE_MUL_RES_TYPE_ID: GenericTypeId = <Sint32Type as NamedType>::ID;
+}
+
+impl IsZeroTraits for Sint16Traits {
+    const IS_ZERO: &'static str = "i16_is_zero";
+    const GENERIC_TYPE_ID: GenericTypeId = <Sint16Type as NamedType>::ID;
+}
+
+/// Type for i16.
+pub type Sint16Type = SintType<Sint16Traits>;
+
+define_libfunc_hierarchy! {
+    pub enum Sint16Libfunc {
+        Const(SintConstLibfunc<Sint16Traits>),
+        Operation(SintOperationLibfunc<Sint16Traits>),
+        LessThan(SintLessThanLibfunc<Sint16Traits>),
+        Equal(SintEqualLibfunc<Sint16Traits>),
+        LessThanOrEqual(SintLessThanOrEqualLibfunc<Sint16Traits>),
+        ToFelt252(SintToFelt252Libfunc<Sint16Traits>),
+        FromFelt252(SintFromFelt252Libfunc<Sint16Traits>),
+        IsZero(IsZeroLibfunc<Sint16Traits>),
+        WideMul(SintWideMulLibfunc<Sint16Traits>),
+    }, Sint16Concrete
+}
+
+#[derive(Default)]
+pub struct Sint32Traits;
+
+impl SintTraits for Sint32Traits {
+    type SintType = i32;
+    const N_BITS: usize = 32;
+    const GENERIC_TYPE_ID: GenericTypeId = GenericTypeId::new_inline("i32");
+    const CONST: &'static str = "i32_const";
+    const EQUAL: &'static str = "i32_eq";
+    const LESS_THAN: &'static str = "i32_lt";
+    const LESS_THAN_OR_EQUAL: &'static str = "i32_le";
+    const OVERFLOWING_ADD: &'static str = "i32_overflowing_add_impl";
+    const OVERFLOWING_SUB: &'static str = "i32_overflowing_sub_impl";
+    const TO_FELT252: &'static str = "i32_to_felt252";
+    const TRY_FROM_FELT252: &'static str = "i32_try_from_felt252";
+}
+
+impl SintMulTraits for Sint32Traits {
+    const WIDE_MUL: &'static str = "i32_wide_mul";
+    const WIDE_MUL_RES_TYPE_ID: GenericTypeId = <Sint64Type as NamedType>::ID;
+}
+
+impl IsZeroTraits for Sint32Traits {
+    const IS_ZERO: &'static str = "i32_is_zero";
+    const GENERIC_TYPE_ID: GenericTypeId = <Sint32Type as NamedType>::ID;
+}
+
+/// Type for i32.
+pub type Sint32Type = SintType<Sint32Traits>;
+
+define_libfunc_hierarchy! {
+    pub enum Sint32Libfunc {
+        Const(SintConstLibfunc<Sint32Traits>),
+        Operation(SintOperationLibfunc<Sint32Traits>),
+        LessThan(SintLessThanLibfunc<Sint32Traits>),
+        Equal(SintEqualLibfunc<Sint32Traits>),
+        LessThanOrEqual(SintLessThanOrEqualLibfunc<Sint32Traits>),
+        ToFelt252(SintToFelt252Libfunc<Sint32Traits>),
+        FromFelt252(SintFromFelt252Libfunc<Sint32Traits>),
+        IsZero(IsZeroLibfunc<Sint32Traits>),
+        WideMul(SintWideMulLibfunc<Sint32Traits>),
+    }, Sint32Concrete
+}
+
+#[derive(Default)]
+pub struct Sint64Traits;
+
+impl SintTraits for Sint64Traits {
+    type SintType = i64;
+    const N_BITS: usize = 64;
+    const GENERIC_TYPE_ID: GenericTypeId = GenericTypeId::new_inline("i64");
+    const CONST: &'static str = "i64_const";
+    const EQUAL: &'static str = "i64_eq";
+    const LESS_THAN: &'static str = "i64_lt";
+    const LESS_THAN_OR_EQUAL: &'static str = "i64_le";
+    const OVERFLOWING_ADD: &'static str = "i64_overflowing_add_impl";
+    const OVERFLOWING_SUB: &'static str = "i64_overflowing_sub_impl";
+    const TO_FELT252: &'static str = "i64_to_felt252";
+    const TRY_FROM_FELT252: &'static str = "i64_try_from_felt252";
+}
+
+impl SintMulTraits for Sint64Traits {
+    const WIDE_MUL: &'static str = "i64_wide_mul";
+    const WIDE_MUL_RES_TYPE_ID: GenericTypeId = <Sint128Type as NamedType>::ID;
+}
+
+impl IsZeroTraits for Sint64Traits {
+    const IS_ZERO: &'static str = "i64_is_zero";
+    const GENERIC_TYPE_ID: GenericTypeId = <Sint64Type as NamedType>::ID;
+}
+
+/// Type for i64.
+pub type Sint64Type = SintType<Sint64Traits>;
+
+define_libfunc_hierarchy! {
+    pub enum Sint64Libfunc {
+        Const(SintConstLibfunc<Sint64Traits>),
+        Operation(SintOperationLibfunc<Sint64Traits>),
+        LessThan(SintLessThanLibfunc<Sint64Traits>),
+        Equal(SintEqualLibfunc<Sint64Traits>),
+        LessThanOrEqual(SintLessThanOrEqualLibfunc<Sint64Traits>),
+        ToFelt252(SintToFelt252Libfunc<Sint64Traits>),
+        FromFelt252(SintFromFelt252Libfunc<Sint64Traits>),
+        IsZero(IsZeroLibfunc<Sint64Traits>),
+        WideMul(SintWideMulLibfunc<Sint64Traits>),
+    }, Sint64Concrete
+}
+
+#[derive(Default)]
+pub struct Sint128Traits;
+
+impl SintTraits for Sint128Traits {
+    type SintType = i128;
+    const N_BITS: usize = 128;
+    const GENERIC_TYPE_ID: GenericTypeId = GenericTypeId::new_inline("i128");
+    const CONST: &'static str = "i128_const";
+    const EQUAL: &'static str = "i128_eq";
+    const LESS_THAN: &'static str = "i128_lt";
+    const LESS_THAN_OR_EQUAL: &'static str = "i128_le";
+    const OVERFLOWING_ADD: &'static str = "i128_overflowing_add_impl";
+    const OVERFLOWING_SUB: &'static str = "i128_overflowing_sub_impl";
+    const TO_FELT252: &'static str = "i128_to_felt252";
+    const TRY_FROM_FELT252: &'static str = "i128_try_from_felt252";
+}
+
+impl IsZeroTraits for Sint128Traits {
+    const IS_ZERO: &'static str = "i128_is_zero";
+    const GENERIC_TYPE_ID: GenericTypeId = <Sint128Type as NamedType>::ID;
+}
+
+/// Type for i128.
+pub type Sint128Type = SintType<Sint128Traits>;
+
+define_libfunc_hierarchy! {
+    pub enum Sint128Libfunc {
+        Const(SintConstLibfunc<Sint128Traits>),
+        Operation(SintOperationLibfunc<Sint128Traits>),
+        LessThan(SintLessThanLibfunc<Sint128Traits>),
+        Equal(SintEqualLibfunc<Sint128Traits>),
+        LessThanOrEqual(SintLessThanOrEqualLibfunc<Sint128Traits>),
+        ToFelt252(SintToFelt252Libfunc<Sint128Traits>),
+        FromFelt252(SintFromFelt252Libfunc<Sint128Traits>),
+        IsZero(IsZeroLibfunc<Sint128Traits>),
+    }, Sint128Concrete
+}
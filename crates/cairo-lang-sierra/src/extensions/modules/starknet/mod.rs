@@ -11,7 +11,7 @@ use storage::{
 };
 
 pub mod syscalls;
-use syscalls::{ReplaceClassLibfunc, SystemType};
+use syscalls::{KeccakLibfunc, ReplaceClassLibfunc, SystemType};
 
 pub mod getter;
 
@@ -22,6 +22,12 @@ pub mod testing;
 pub mod interoperability;
 use interoperability::{CallContractLibfunc, ContractAddressConstLibfunc, ContractAddressType};
 
+pub mod secp256k1;
+use secp256k1::{
+    Secp256K1EcAddLibfunc, Secp256K1EcGetCoordinatesLibfunc, Secp256K1EcGetPointFromXLibfunc,
+    Secp256K1EcMulLibfunc, Secp256K1EcPointType,
+};
+
 use self::getter::{GetExecutionInfoTrait, GetterLibfunc};
 use self::interoperability::{
     ClassHashConstLibfunc, ClassHashToFelt252Libfunc, ClassHashTryFromFelt252Trait, ClassHashType,
@@ -46,6 +52,7 @@ define_type_hierarchy! {
         StorageBaseAddress(StorageBaseAddressType),
         StorageAddress(StorageAddressType),
         System(SystemType),
+        Secp256K1EcPoint(Secp256K1EcPointType),
     }, StarkNetTypeConcrete
 }
 
@@ -72,6 +79,11 @@ define_libfunc_hierarchy! {
          LibraryCall(LibraryCallLibfunc),
          ReplaceClass(ReplaceClassLibfunc),
          SendMessageToL1(SendMessageToL1Libfunc),
+         Keccak(KeccakLibfunc),
+         Secp256K1EcAdd(Secp256K1EcAddLibfunc),
+         Secp256K1EcMul(Secp256K1EcMulLibfunc),
+         Secp256K1EcGetPointFromX(Secp256K1EcGetPointFromXLibfunc),
+         Secp256K1EcGetCoordinates(Secp256K1EcGetCoordinatesLibfunc),
          Testing(TestingLibfunc),
     }, StarkNetConcreteLibfunc
 }
@@ -80,15 +92,23 @@ define_libfunc_hierarchy! {
 fn felt252_span_ty(
     context: &dyn SignatureSpecializationContext,
 ) -> Result<ConcreteTypeId, SpecializationError> {
-    let felt252_array_ty = context.get_wrapped_concrete_type(
-        ArrayType::id(),
-        context.get_concrete_type(Felt252Type::id(), &[])?,
-    )?;
+    span_ty(context, context.get_concrete_type(Felt252Type::id(), &[])?, "core::felt252")
+}
+
+/// User type for `Span<T>`, where `wrapped_ty_name` is the full path of `T`.
+fn span_ty(
+    context: &dyn SignatureSpecializationContext,
+    wrapped_ty: ConcreteTypeId,
+    wrapped_ty_name: &str,
+) -> Result<ConcreteTypeId, SpecializationError> {
+    let array_ty = context.get_wrapped_concrete_type(ArrayType::id(), wrapped_ty)?;
     context.get_concrete_type(
         StructType::id(),
         &[
-            GenericArg::UserType(UserTypeId::from_string("core::array::Span::<core::felt252>")),
-            GenericArg::Type(snapshot_ty(context, felt252_array_ty)?),
+            GenericArg::UserType(UserTypeId::from_string(format!(
+                "core::array::Span::<{wrapped_ty_name}>"
+            ))),
+            GenericArg::Type(snapshot_ty(context, array_ty)?),
         ],
     )
 }
@@ -0,0 +1,138 @@
+use super::syscalls::SyscallGenericLibfunc;
+use crate::extensions::enm::EnumType;
+use crate::extensions::lib_func::SignatureSpecializationContext;
+use crate::extensions::modules::{get_bool_type, get_unit_type};
+use crate::extensions::uint256::get_u256_type;
+use crate::extensions::{NamedType, NoGenericArgsGenericType, SpecializationError};
+use crate::ids::{ConcreteTypeId, GenericTypeId, UserTypeId};
+use crate::program::GenericArg;
+
+/// Type for a point on the secp256k1 curve.
+/// The point itself is held by the system, and this type only identifies it.
+#[derive(Default)]
+pub struct Secp256K1EcPointType {}
+impl NoGenericArgsGenericType for Secp256K1EcPointType {
+    const ID: GenericTypeId = GenericTypeId::new_inline("Secp256K1EcPoint");
+    const STORABLE: bool = true;
+    const DUPLICATABLE: bool = true;
+    const DROPPABLE: bool = true;
+    const SIZE: i16 = 1;
+}
+
+/// Helper for the `Option<Secp256K1EcPoint>` type def.
+fn get_option_secp256k1_ec_point_type(
+    context: &dyn SignatureSpecializationContext,
+) -> Result<ConcreteTypeId, SpecializationError> {
+    context.get_concrete_type(
+        EnumType::id(),
+        &[
+            GenericArg::UserType(UserTypeId::from_string(
+                "core::option::Option::<core::starknet::secp256k1::Secp256K1EcPoint>",
+            )),
+            GenericArg::Type(context.get_concrete_type(Secp256K1EcPointType::id(), &[])?),
+            GenericArg::Type(get_unit_type(context)?),
+        ],
+    )
+}
+
+/// Libfunc for the secp256k1 point addition system call.
+#[derive(Default)]
+pub struct Secp256K1EcAddLibfunc {}
+impl SyscallGenericLibfunc for Secp256K1EcAddLibfunc {
+    const STR_ID: &'static str = "secp256k1_ec_add_syscall";
+
+    fn input_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        let point_ty = context.get_concrete_type(Secp256K1EcPointType::id(), &[])?;
+        Ok(vec![
+            // p0
+            point_ty.clone(),
+            // p1
+            point_ty,
+        ])
+    }
+
+    fn success_output_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![context.get_concrete_type(Secp256K1EcPointType::id(), &[])?])
+    }
+}
+
+/// Libfunc for the secp256k1 scalar multiplication system call.
+#[derive(Default)]
+pub struct Secp256K1EcMulLibfunc {}
+impl SyscallGenericLibfunc for Secp256K1EcMulLibfunc {
+    const STR_ID: &'static str = "secp256k1_ec_mul_syscall";
+
+    fn input_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![
+            // p
+            context.get_concrete_type(Secp256K1EcPointType::id(), &[])?,
+            // scalar
+            get_u256_type(context)?,
+        ])
+    }
+
+    fn success_output_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![context.get_concrete_type(Secp256K1EcPointType::id(), &[])?])
+    }
+}
+
+/// Libfunc for the system call getting the secp256k1 point with the given x coordinate and parity
+/// of the y coordinate, if there is one.
+#[derive(Default)]
+pub struct Secp256K1EcGetPointFromXLibfunc {}
+impl SyscallGenericLibfunc for Secp256K1EcGetPointFromXLibfunc {
+    const STR_ID: &'static str = "secp256k1_ec_get_point_from_x_syscall";
+
+    fn input_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![
+            // x
+            get_u256_type(context)?,
+            // y_parity
+            get_bool_type(context)?,
+        ])
+    }
+
+    fn success_output_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![get_option_secp256k1_ec_point_type(context)?])
+    }
+}
+
+/// Libfunc for the system call getting the coordinates of a secp256k1 point.
+#[derive(Default)]
+pub struct Secp256K1EcGetCoordinatesLibfunc {}
+impl SyscallGenericLibfunc for Secp256K1EcGetCoordinatesLibfunc {
+    const STR_ID: &'static str = "secp256k1_ec_get_coordinates_syscall";
+
+    fn input_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![
+            // p
+            context.get_concrete_type(Secp256K1EcPointType::id(), &[])?,
+        ])
+    }
+
+    fn success_output_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        let u256_ty = get_u256_type(context)?;
+        Ok(vec![
+            // x
+            u256_ty.clone(),
+            // y
+            u256_ty,
+        ])
+    }
+}
@@ -1,6 +1,7 @@
 use itertools::chain;
 
 use super::interoperability::ClassHashType;
+use super::span_ty;
 use crate::extensions::array::ArrayType;
 use crate::extensions::felt252::Felt252Type;
 use crate::extensions::gas::GasBuiltinType;
@@ -8,6 +9,8 @@ use crate::extensions::lib_func::{
     BranchSignature, DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature,
     SierraApChange, SignatureSpecializationContext,
 };
+use crate::extensions::uint::Uint64Type;
+use crate::extensions::uint256::get_u256_type;
 use crate::extensions::{
     NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType, OutputVarReferenceInfo,
     SpecializationError,
@@ -147,3 +150,31 @@ impl SyscallGenericLibfunc for ReplaceClassLibfunc {
         Ok(vec![])
     }
 }
+
+/// Libfunc for the keccak system call.
+/// Applies the keccak permutation on the given blocks of 17 u64 words, which must already be
+/// padded, and returns the first 4 words of the resulting state as a little-endian u256.
+#[derive(Default)]
+pub struct KeccakLibfunc {}
+impl SyscallGenericLibfunc for KeccakLibfunc {
+    const STR_ID: &'static str = "keccak_syscall";
+
+    fn input_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<crate::ids::ConcreteTypeId>, SpecializationError> {
+        Ok(vec![
+            // input
+            span_ty(
+                context,
+                context.get_concrete_type(Uint64Type::id(), &[])?,
+                "core::integer::u64",
+            )?,
+        ])
+    }
+
+    fn success_output_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<crate::ids::ConcreteTypeId>, SpecializationError> {
+        Ok(vec![get_u256_type(context)?])
+    }
+}
@@ -1,9 +1,12 @@
 use std::marker::PhantomData;
 
+use super::felt252_span_ty;
 use super::interoperability::ContractAddressType;
 use crate::define_libfunc_hierarchy;
+use crate::extensions::felt252::Felt252Type;
 use crate::extensions::lib_func::{
-    LibfuncSignature, SierraApChange, SignatureSpecializationContext,
+    BranchSignature, DeferredOutputKind, LibfuncSignature, OutputVarInfo, OutputVarReferenceInfo,
+    ParamSignature, SierraApChange, SignatureSpecializationContext,
 };
 use crate::extensions::uint::Uint64Type;
 use crate::extensions::{
@@ -95,6 +98,68 @@ impl TestSetterTraits for SetSequencerAddressTrait {
     type ValueType = ContractAddressType;
 }
 
+/// Libfunc for popping the earliest unpopped event emitted by a contract.
+#[derive(Default)]
+pub struct PopLogLibfunc {}
+impl NoGenericArgsGenericLibfunc for PopLogLibfunc {
+    const STR_ID: &'static str = "pop_log";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let span_ty = felt252_span_ty(context)?;
+        pop_signature(context, vec![span_ty.clone(), span_ty])
+    }
+}
+
+/// Libfunc for popping the earliest unpopped message sent to L1 by a contract.
+#[derive(Default)]
+pub struct PopL2ToL1MessageLibfunc {}
+impl NoGenericArgsGenericLibfunc for PopL2ToL1MessageLibfunc {
+    const STR_ID: &'static str = "pop_l2_to_l1_message";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let felt252_ty = context.get_concrete_type(Felt252Type::id(), &[])?;
+        pop_signature(context, vec![felt252_ty, felt252_span_ty(context)?])
+    }
+}
+
+/// Returns the signature of a libfunc popping an entry of the given types from the log of a
+/// contract. The first branch returns the entry, and the second is taken if the log is empty.
+fn pop_signature(
+    context: &dyn SignatureSpecializationContext,
+    entry_tys: Vec<ConcreteTypeId>,
+) -> Result<LibfuncSignature, SpecializationError> {
+    Ok(LibfuncSignature {
+        param_signatures: vec![ParamSignature::new(
+            context.get_concrete_type(ContractAddressType::id(), &[])?,
+        )],
+        branch_signatures: vec![
+            // Some variant.
+            BranchSignature {
+                vars: entry_tys
+                    .into_iter()
+                    .map(|ty| OutputVarInfo {
+                        ty,
+                        ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+                    })
+                    .collect(),
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            },
+            // None variant.
+            BranchSignature {
+                vars: vec![],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            },
+        ],
+        fallthrough: Some(0),
+    })
+}
+
 define_libfunc_hierarchy! {
     pub enum TestingLibfunc {
          SetBlockNumber(TestSetterLibfunc<SetBlockNumberTrait>),
@@ -102,5 +167,7 @@ define_libfunc_hierarchy! {
          SetCallerAddress(TestSetterLibfunc<SetCallerAddressTrait>),
          SetContractAddress(TestSetterLibfunc<SetContractAddressTrait>),
          SetSequencerAddress(TestSetterLibfunc<SetSequencerAddressTrait>),
+         PopLog(PopLogLibfunc),
+         PopL2ToL1Message(PopL2ToL1MessageLibfunc),
     }, TestingConcreteLibfunc
 }
@@ -12,8 +12,8 @@ use crate::extensions::lib_func::{
     SierraApChange, SignatureSpecializationContext, SpecializationContext,
 };
 use crate::extensions::{
-    GenericLibfunc, NamedType, NoGenericArgsGenericLibfunc, OutputVarReferenceInfo,
-    SpecializationError,
+    GenericLibfunc, NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType,
+    OutputVarReferenceInfo, SpecializationError,
 };
 use crate::ids::{GenericLibfuncId, GenericTypeId};
 use crate::program::GenericArg;
@@ -34,6 +34,8 @@ define_libfunc_hierarchy! {
         FromFelt252(Uint128sFromFelt252Libfunc),
         ToFelt252(UintToFelt252Libfunc<Uint128Traits>),
         IsZero(IsZeroLibfunc<Uint128Traits>),
+        GuaranteeMul(U128GuaranteeMulLibfunc),
+        MulGuaranteeVerify(U128MulGuaranteeVerifyLibfunc),
     }, Uint128Concrete
 }
 
@@ -262,3 +264,79 @@ impl NoGenericArgsGenericLibfunc for Uint128sFromFelt252Libfunc {
         })
     }
 }
+
+/// Type for a guarantee that `a * b == 2**128 * high + low` for the u128s `a`, `b`, `high` and
+/// `low` it holds. It is not droppable, so it must be consumed by `u128_mul_guarantee_verify`.
+#[derive(Default)]
+pub struct U128MulGuaranteeType {}
+impl NoGenericArgsGenericType for U128MulGuaranteeType {
+    const ID: GenericTypeId = GenericTypeId::new_inline("U128MulGuarantee");
+    const STORABLE: bool = true;
+    const DUPLICATABLE: bool = false;
+    const DROPPABLE: bool = false;
+    const SIZE: i16 = 4;
+}
+
+/// Libfunc for multiplying two u128s, returning the high and low words of the result, and a
+/// guarantee for their correctness, without verifying it.
+#[derive(Default)]
+pub struct U128GuaranteeMulLibfunc {}
+impl NoGenericArgsGenericLibfunc for U128GuaranteeMulLibfunc {
+    const STR_ID: &'static str = "u128_guarantee_mul";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let ty = context.get_concrete_type(Uint128Type::id(), &[])?;
+        let guarantee_ty = context.get_concrete_type(U128MulGuaranteeType::id(), &[])?;
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![ParamSignature::new(ty.clone()), ParamSignature::new(ty.clone())],
+            vec![
+                OutputVarInfo {
+                    ty: ty.clone(),
+                    ref_info: OutputVarReferenceInfo::NewTempVar { idx: Some(0) },
+                },
+                OutputVarInfo { ty, ref_info: OutputVarReferenceInfo::NewTempVar { idx: Some(1) } },
+                OutputVarInfo {
+                    ty: guarantee_ty,
+                    ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+                },
+            ],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+
+/// Libfunc for verifying and consuming a u128 multiplication guarantee.
+#[derive(Default)]
+pub struct U128MulGuaranteeVerifyLibfunc {}
+impl NoGenericArgsGenericLibfunc for U128MulGuaranteeVerifyLibfunc {
+    const STR_ID: &'static str = "u128_mul_guarantee_verify";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let guarantee_ty = context.get_concrete_type(U128MulGuaranteeType::id(), &[])?;
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![
+                ParamSignature {
+                    ty: range_check_type.clone(),
+                    allow_deferred: false,
+                    allow_add_const: true,
+                    allow_const: false,
+                },
+                ParamSignature::new(guarantee_ty),
+            ],
+            vec![OutputVarInfo {
+                ty: range_check_type,
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
+                    param_idx: 0,
+                }),
+            }],
+            SierraApChange::Known { new_vars_only: false },
+        ))
+    }
+}
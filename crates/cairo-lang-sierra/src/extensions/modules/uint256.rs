@@ -0,0 +1,156 @@
+use super::non_zero::nonzero_ty;
+use super::range_check::RangeCheckType;
+use super::structure::StructType;
+use super::uint128::{U128MulGuaranteeType, Uint128Type};
+use crate::define_libfunc_hierarchy;
+use crate::extensions::lib_func::{
+    BranchSignature, DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature,
+    SierraApChange, SignatureSpecializationContext,
+};
+use crate::extensions::{
+    NamedType, NoGenericArgsGenericLibfunc, OutputVarReferenceInfo, SpecializationError,
+};
+use crate::ids::{ConcreteTypeId, UserTypeId};
+use crate::program::GenericArg;
+
+define_libfunc_hierarchy! {
+    pub enum Uint256Libfunc {
+        IsZero(Uint256IsZeroLibfunc),
+        Divmod(Uint256DivmodLibfunc),
+        SquareRoot(Uint256SquareRootLibfunc),
+    }, Uint256Concrete
+}
+
+/// Helper for the u256 type def: a struct of two u128s, the low word first.
+pub fn get_u256_type(
+    context: &dyn SignatureSpecializationContext,
+) -> Result<ConcreteTypeId, SpecializationError> {
+    let u128_ty = context.get_concrete_type(Uint128Type::id(), &[])?;
+    context.get_concrete_type(
+        StructType::id(),
+        &[
+            GenericArg::UserType(UserTypeId::from_string("core::integer::u256")),
+            GenericArg::Type(u128_ty.clone()),
+            GenericArg::Type(u128_ty),
+        ],
+    )
+}
+
+/// Libfunc for checking whether the given u256 is zero or not, and returning a non-zero wrapped
+/// value in case of success.
+#[derive(Default)]
+pub struct Uint256IsZeroLibfunc {}
+impl NoGenericArgsGenericLibfunc for Uint256IsZeroLibfunc {
+    const STR_ID: &'static str = "u256_is_zero";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let ty = get_u256_type(context)?;
+        Ok(LibfuncSignature {
+            param_signatures: vec![ParamSignature::new(ty.clone())],
+            branch_signatures: vec![
+                // Zero.
+                BranchSignature {
+                    vars: vec![],
+                    ap_change: SierraApChange::Known { new_vars_only: true },
+                },
+                // NonZero.
+                BranchSignature {
+                    vars: vec![OutputVarInfo {
+                        ty: nonzero_ty(context, &ty)?,
+                        ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 0 },
+                    }],
+                    ap_change: SierraApChange::Known { new_vars_only: true },
+                },
+            ],
+            fallthrough: Some(0),
+        })
+    }
+}
+
+/// Libfunc for u256 division and modulo.
+/// Returns the quotient, the remainder, and a guarantee for the multiplication of the lower words
+/// of the quotient and the divisor, that must be verified separately.
+#[derive(Default)]
+pub struct Uint256DivmodLibfunc {}
+impl NoGenericArgsGenericLibfunc for Uint256DivmodLibfunc {
+    const STR_ID: &'static str = "u256_safe_divmod";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let ty = get_u256_type(context)?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![
+                ParamSignature {
+                    ty: range_check_type.clone(),
+                    allow_deferred: false,
+                    allow_add_const: true,
+                    allow_const: false,
+                },
+                ParamSignature::new(ty.clone()),
+                ParamSignature::new(nonzero_ty(context, &ty)?),
+            ],
+            vec![
+                OutputVarInfo {
+                    ty: range_check_type,
+                    ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
+                        param_idx: 0,
+                    }),
+                },
+                OutputVarInfo {
+                    ty: ty.clone(),
+                    ref_info: OutputVarReferenceInfo::NewTempVar { idx: None },
+                },
+                OutputVarInfo { ty, ref_info: OutputVarReferenceInfo::NewTempVar { idx: None } },
+                OutputVarInfo {
+                    ty: context.get_concrete_type(U128MulGuaranteeType::id(), &[])?,
+                    ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+                },
+            ],
+            SierraApChange::Known { new_vars_only: false },
+        ))
+    }
+}
+
+/// Libfunc for the floored square root of a u256.
+#[derive(Default)]
+pub struct Uint256SquareRootLibfunc {}
+impl NoGenericArgsGenericLibfunc for Uint256SquareRootLibfunc {
+    const STR_ID: &'static str = "u256_sqrt";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![
+                ParamSignature {
+                    ty: range_check_type.clone(),
+                    allow_deferred: false,
+                    allow_add_const: true,
+                    allow_const: false,
+                },
+                ParamSignature::new(get_u256_type(context)?),
+            ],
+            vec![
+                OutputVarInfo {
+                    ty: range_check_type,
+                    ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
+                        param_idx: 0,
+                    }),
+                },
+                OutputVarInfo {
+                    ty: context.get_concrete_type(Uint128Type::id(), &[])?,
+                    ref_info: OutputVarReferenceInfo::NewTempVar { idx: None },
+                },
+            ],
+            SierraApChange::Known { new_vars_only: false },
+        ))
+    }
+}
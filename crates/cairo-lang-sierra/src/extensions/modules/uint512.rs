@@ -0,0 +1,95 @@
+use super::non_zero::nonzero_ty;
+use super::range_check::RangeCheckType;
+use super::structure::StructType;
+use super::uint128::{U128MulGuaranteeType, Uint128Type};
+use super::uint256::get_u256_type;
+use crate::define_libfunc_hierarchy;
+use crate::extensions::lib_func::{
+    DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange,
+    SignatureSpecializationContext,
+};
+use crate::extensions::{
+    NamedType, NoGenericArgsGenericLibfunc, OutputVarReferenceInfo, SpecializationError,
+};
+use crate::ids::{ConcreteTypeId, UserTypeId};
+use crate::program::GenericArg;
+
+define_libfunc_hierarchy! {
+    pub enum Uint512Libfunc {
+        DivModU256(Uint512DivModU256Libfunc),
+    }, Uint512Concrete
+}
+
+/// Helper for the u512 type def: a struct of four u128s, the lowest word first.
+pub fn get_u512_type(
+    context: &dyn SignatureSpecializationContext,
+) -> Result<ConcreteTypeId, SpecializationError> {
+    let u128_ty = context.get_concrete_type(Uint128Type::id(), &[])?;
+    context.get_concrete_type(
+        StructType::id(),
+        &[
+            GenericArg::UserType(UserTypeId::from_string("core::integer::u512")),
+            GenericArg::Type(u128_ty.clone()),
+            GenericArg::Type(u128_ty.clone()),
+            GenericArg::Type(u128_ty.clone()),
+            GenericArg::Type(u128_ty),
+        ],
+    )
+}
+
+/// The number of u128 multiplication guarantees returned by `u512_safe_divmod_by_u256`.
+pub const U512_DIVMOD_U256_GUARANTEES: usize = 5;
+
+/// Libfunc for u512 division and modulo by a u256.
+/// Returns the quotient, the remainder, and guarantees for the multiplications of the words of
+/// the quotient and the divisor, that must be verified separately.
+#[derive(Default)]
+pub struct Uint512DivModU256Libfunc {}
+impl NoGenericArgsGenericLibfunc for Uint512DivModU256Libfunc {
+    const STR_ID: &'static str = "u512_safe_divmod_by_u256";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let u512_ty = get_u512_type(context)?;
+        let u256_ty = get_u256_type(context)?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let guarantee_ty = context.get_concrete_type(U128MulGuaranteeType::id(), &[])?;
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![
+                ParamSignature {
+                    ty: range_check_type.clone(),
+                    allow_deferred: false,
+                    allow_add_const: true,
+                    allow_const: false,
+                },
+                ParamSignature::new(u512_ty.clone()),
+                ParamSignature::new(nonzero_ty(context, &u256_ty)?),
+            ],
+            [
+                OutputVarInfo {
+                    ty: range_check_type,
+                    ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
+                        param_idx: 0,
+                    }),
+                },
+                OutputVarInfo {
+                    ty: u512_ty,
+                    ref_info: OutputVarReferenceInfo::NewTempVar { idx: None },
+                },
+                OutputVarInfo {
+                    ty: u256_ty,
+                    ref_info: OutputVarReferenceInfo::NewTempVar { idx: None },
+                },
+            ]
+            .into_iter()
+            .chain((0..U512_DIVMOD_U256_GUARANTEES).map(|_| OutputVarInfo {
+                ty: guarantee_ty.clone(),
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+            }))
+            .collect(),
+            SierraApChange::Known { new_vars_only: false },
+        ))
+    }
+}
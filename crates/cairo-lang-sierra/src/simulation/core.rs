@@ -310,6 +310,9 @@ pub fn simulate<
         CoreConcreteLibfunc::Pedersen(_) => {
             unimplemented!("Simulation of the Pedersen hash function is not implemented yet.");
         }
+        CoreConcreteLibfunc::Poseidon(_) => {
+            unimplemented!("Simulation of the Poseidon hash function is not implemented yet.");
+        }
         CoreConcreteLibfunc::BuiltinCost(_) => {
             unimplemented!("Simulation of the builtin cost functionality is not implemented yet.")
         }
@@ -319,6 +322,19 @@ pub fn simulate<
         CoreConcreteLibfunc::Nullable(_) => {
             unimplemented!("Simulation of nullable is not implemented yet.")
         }
+        CoreConcreteLibfunc::Sint8(_)
+        | CoreConcreteLibfunc::Sint16(_)
+        | CoreConcreteLibfunc::Sint32(_)
+        | CoreConcreteLibfunc::Sint64(_)
+        | CoreConcreteLibfunc::Sint128(_) => {
+            unimplemented!("Simulation of signed integers is not implemented yet.")
+        }
+        CoreConcreteLibfunc::Uint256(_) | CoreConcreteLibfunc::Uint512(_) => {
+            unimplemented!("Simulation of u256 and u512 operations is not implemented yet.")
+        }
+        CoreConcreteLibfunc::Bytes31(_) => {
+            unimplemented!("Simulation of bytes31 is not implemented yet.")
+        }
         CoreConcreteLibfunc::Debug(_) => {
             if inputs.len() == 1 {
                 let arr = extract_matches!(&inputs[0], CoreValue::Array);
@@ -547,6 +563,9 @@ fn simulate_u128_libfunc(
             [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
+        Uint128Concrete::GuaranteeMul(_) | Uint128Concrete::MulGuaranteeVerify(_) => {
+            unimplemented!("Simulation of u128 multiplication guarantees is not implemented yet.")
+        }
     }
 }
 
@@ -20,6 +20,7 @@ fn format_test() {
                 libfunc OtherCalleeId = LibfuncId <arg, 4>;
                 libfunc [5642] = LibfuncId<[22 ], 4>;
                 libfunc CallFunction = Call<user@Function>;
+                libfunc CallGenerated = Call<user@module::Function [ expr12 ]>;
                 libfunc LibDependent = LibDependent<lib@[124]>;
                 callee() -> ();
                 callee(arg1) -> (res1);
@@ -34,6 +35,7 @@ fn format_test() {
 
                 Name@5() -> ();
                 Other@3([5]: T1) -> (T2);
+                Other[expr4]@4([5]: T1) -> (T2);
                 [343]@3([5]: [6343]) -> ([341]);
             "},)
             .map(|p| p.to_string()),
@@ -49,6 +51,7 @@ fn format_test() {
             libfunc OtherCalleeId = LibfuncId<arg, 4>;
             libfunc [5642] = LibfuncId<[22], 4>;
             libfunc CallFunction = Call<user@Function>;
+            libfunc CallGenerated = Call<user@module::Function[expr12]>;
             libfunc LibDependent = LibDependent<lib@[124]>;
 
             callee() -> ();
@@ -64,6 +67,7 @@ fn format_test() {
 
             Name@5() -> ();
             Other@3([5]: T1) -> (T2);
+            Other[expr4]@4([5]: T1) -> (T2);
             [343]@3([5]: [6343]) -> ([341]);
         "}
         .to_string())
@@ -4,6 +4,7 @@ use box::BoxTrait;
 use option::OptionTrait;
 use starknet::ContractAddressZeroable;
 use starknet::ContractAddressIntoFelt252;
+use starknet::SyscallResultTrait;
 use traits::Into;
 use zeroable::Zeroable;
 
@@ -32,9 +33,13 @@ mod TestContract {
         arr
     }
 
+    #[event]
+    fn ValueSet(a: felt252) {}
+
     #[external]
     fn set_value(a: felt252) {
         value::write(a);
+        ValueSet(a);
     }
 
     #[view]
@@ -248,3 +253,141 @@ fn test_storage_address() {
 
     assert(*args.at(0_u32) == *ret_data.at(0_u32), 'Unexpected ret_data.');
 }
+
+#[test]
+#[available_gas(300000)]
+fn test_pop_log() {
+    let mut keys = ArrayTrait::new();
+    keys.append(1);
+    let mut data = ArrayTrait::new();
+    data.append(2);
+    data.append(3);
+    starknet::syscalls::emit_event_syscall(keys.span(), data.span()).unwrap_syscall();
+    let contract_address = starknet::get_contract_address();
+    let (keys, data) = starknet::testing::pop_log(contract_address).unwrap();
+    assert(keys.len() == 1_u32, 'Wrong keys length');
+    assert(*keys.at(0_u32) == 1, 'Wrong key');
+    assert(data.len() == 2_u32, 'Wrong data length');
+    assert(*data.at(0_u32) == 2, 'Wrong first data');
+    assert(*data.at(1_u32) == 3, 'Wrong second data');
+    assert(starknet::testing::pop_log(contract_address).is_none(), 'Unexpected event');
+}
+
+#[test]
+#[available_gas(300000)]
+fn test_pop_log_of_contract_event() {
+    starknet::testing::set_contract_address(starknet::contract_address_const::<1>());
+    TestContract::__external::set_value(serialized_element(4));
+    TestContract::__external::set_value(serialized_element(5));
+    let contract_address = starknet::contract_address_const::<1>();
+    let (_, mut data) = starknet::testing::pop_log(contract_address).unwrap();
+    assert(single_deserialize(ref data) == 4, 'Wrong first event');
+    let (_, mut data) = starknet::testing::pop_log(contract_address).unwrap();
+    assert(single_deserialize(ref data) == 5, 'Wrong second event');
+    assert(starknet::testing::pop_log(contract_address).is_none(), 'Unexpected event');
+    let other_contract_address = starknet::contract_address_const::<0>();
+    assert(starknet::testing::pop_log(other_contract_address).is_none(), 'Unexpected event');
+}
+
+#[test]
+#[available_gas(300000)]
+fn test_pop_l2_to_l1_message() {
+    let mut payload = ArrayTrait::new();
+    payload.append(2);
+    starknet::send_message_to_l1_syscall(1, payload.span()).unwrap_syscall();
+    let contract_address = starknet::get_contract_address();
+    let (to_address, payload) = starknet::testing::pop_l2_to_l1_message(contract_address).unwrap();
+    assert(to_address == 1, 'Wrong to_address');
+    assert(payload.len() == 1_u32, 'Wrong payload length');
+    assert(*payload.at(0_u32) == 2, 'Wrong payload');
+    assert(
+        starknet::testing::pop_l2_to_l1_message(contract_address).is_none(), 'Unexpected message'
+    );
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_keccak_syscall() {
+    let mut input = ArrayTrait::new();
+    // The u256 1 in little-endian u64 words, followed by the padding.
+    input.append(1_u64);
+    append_zeros(ref input, 3_u32);
+    input.append(1_u64);
+    append_zeros(ref input, 11_u32);
+    input.append(0x8000000000000000_u64);
+    let res = starknet::keccak_syscall(input.span()).unwrap_syscall();
+    assert(res.low == 0x587f7cc3722e9654ea3963d5fe8c0748_u128, 'Wrong hash low');
+    assert(res.high == 0xa5963aa610cb75ba273817bce5f8c48f_u128, 'Wrong hash high');
+}
+
+fn append_zeros(ref input: Array<u64>, n: u32) {
+    match gas::withdraw_gas() {
+        Option::Some(_) => {},
+        Option::None(_) => {
+            let mut data = ArrayTrait::new();
+            data.append('Out of gas');
+            panic(data);
+        },
+    }
+    if n == 0_u32 {
+        return ();
+    }
+    input.append(0_u64);
+    append_zeros(ref input, n - 1_u32);
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_keccak_u256s() {
+    let mut input = ArrayTrait::new();
+    input.append(1.into());
+    input.append(2.into());
+    input.append(3.into());
+    let res = keccak::keccak_u256s_le(input.span());
+    assert(res.low == 0xd70c8a77ddbf3b47d52a3b6ba31d3013_u128, 'Wrong le hash low');
+    assert(res.high == 0xdf29f1ba1841fedd5bb4634c38cc9224_u128, 'Wrong le hash high');
+    let res = keccak::keccak_u256s_be(input.span());
+    assert(res.low == 0x071b3f711f7bfe32d44bb20079620c6e_u128, 'Wrong be hash low');
+    assert(res.high == 0x9c94f221eddf185aa6e49f6a641a0944_u128, 'Wrong be hash high');
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_keccak_u256s_multiple_blocks() {
+    // 5 u256 values are 20 words, which is more than a single block of 17 words.
+    let mut input = ArrayTrait::new();
+    input.append(1.into());
+    input.append(2.into());
+    input.append(3.into());
+    input.append(4.into());
+    input.append(5.into());
+    let res = keccak::keccak_u256s_le(input.span());
+    assert(res.low == 0x42f0f110294b1fd2d3d7ba88d0229aac_u128, 'Wrong hash low');
+    assert(res.high == 0xeff7a86033352d36e01fbc206c9acb13_u128, 'Wrong hash high');
+}
+
+#[test]
+#[available_gas(100000000)]
+fn test_verify_eth_signature() {
+    let msg_hash = u256 {
+        low: 0x788f195a6f509ca3e934f78d7a71dd85_u128, high: 0xe888fbb4cf9ae6254f19ba12e6d9af54_u128
+    };
+    let signature = starknet::secp256k1::Signature {
+        r: u256 {
+            low: 0x9bc3e06c17a227433966cb59ceee020d_u128,
+            high: 0xbb50e2d89a4ed70663d080659fe0ad4b_u128
+            }, s: u256 {
+            low: 0x27b047461531cce4520b6b8e3db9353e_u128,
+            high: 0x12b837cf37ebfd41de5ec859eef70049_u128
+        }, y_parity: false,
+    };
+    let eth_address = 0x2c7536e3605d9c16a7a3d7b1898e529396a65c23;
+    assert(
+        starknet::secp256k1::verify_eth_signature(msg_hash, signature, eth_address),
+        'Signature should be valid'
+    );
+    assert(
+        !starknet::secp256k1::verify_eth_signature(msg_hash + 1.into(), signature, eth_address),
+        'Signature should be invalid'
+    );
+}
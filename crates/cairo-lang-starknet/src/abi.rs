@@ -135,9 +135,11 @@ impl AbiBuilder {
                 Ok(())
             }
             TypeLongId::Snapshot(ty) => self.add_type(db, ty),
-            TypeLongId::GenericParameter(_) | TypeLongId::Var(_) | TypeLongId::Missing(_) => {
-                Err(ABIError::UnexpectedType)
-            }
+            TypeLongId::GenericParameter(_)
+            | TypeLongId::ImplType(_)
+            | TypeLongId::Var(_)
+            | TypeLongId::Missing(_)
+            | TypeLongId::Closure(_) => Err(ABIError::UnexpectedType),
         }
     }
 
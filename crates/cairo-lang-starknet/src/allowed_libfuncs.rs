@@ -29,6 +29,7 @@ pub enum AllowedLibfuncsError {
 }
 
 /// A selector for the allowed libfunc list.
+#[derive(Clone)]
 pub enum ListSelector {
     /// A list with one of the predfined names.
     ListName(String),
@@ -14,6 +14,8 @@ fn experimenal_list_includes_all() {
         "set_caller_address",
         "set_contract_address",
         "set_sequencer_address",
+        "pop_log",
+        "pop_l2_to_l1_message",
         "get_available_gas",
     ];
     pretty_assertions::assert_eq!(
@@ -2,6 +2,7 @@
 #[path = "casm_contract_class_test.rs"]
 mod test;
 
+use cairo_felt::Felt as Felt252;
 use cairo_lang_casm::hints::Hint;
 use cairo_lang_sierra::extensions::array::ArrayType;
 use cairo_lang_sierra::extensions::builtin_cost::CostTokenType;
@@ -10,6 +11,7 @@ use cairo_lang_sierra::extensions::enm::EnumType;
 use cairo_lang_sierra::extensions::felt252::Felt252Type;
 use cairo_lang_sierra::extensions::gas::GasBuiltinType;
 use cairo_lang_sierra::extensions::pedersen::PedersenType;
+use cairo_lang_sierra::extensions::poseidon::PoseidonType;
 use cairo_lang_sierra::extensions::range_check::RangeCheckType;
 use cairo_lang_sierra::extensions::segment_arena::SegmentArenaType;
 use cairo_lang_sierra::extensions::snapshot::SnapshotType;
@@ -36,6 +38,7 @@ use thiserror::Error;
 use crate::allowed_libfuncs::AllowedLibfuncsError;
 use crate::contract_class::{ContractClass, ContractEntryPoint};
 use crate::felt252_serde::{sierra_from_felt252s, Felt252SerdeError};
+use crate::poseidon::poseidon_hash_many;
 
 /// The expected gas cost of an entrypoint.
 pub const ENTRY_POINT_COST: i32 = 10000;
@@ -229,8 +232,7 @@ impl CasmContractClass {
                 RangeCheckType::id(),
                 PedersenType::id(),
                 EcOpType::id(),
-                // TODO(lior): Uncomment the line below once Poseidon is supported.
-                //   PoseidonType::ID,
+                PoseidonType::id(),
                 SegmentArenaType::id(),
                 GasBuiltinType::id(),
                 SystemType::id(),
@@ -345,6 +347,21 @@ impl CasmContractClass {
             },
         })
     }
+
+    /// Returns the compiled class hash of the contract, as defined by Starknet: the Poseidon hash
+    /// of the compiled class version, the entry points of each type and the bytecode.
+    pub fn compiled_class_hash(&self) -> BigUint {
+        let bytecode =
+            self.bytecode.iter().map(|value| Felt252::from(value.value.clone())).collect_vec();
+        poseidon_hash_many(&[
+            Felt252::from(BigUint::from_bytes_be(b"COMPILED_CLASS_V1")),
+            entry_points_hash(&self.entry_points_by_type.external),
+            entry_points_hash(&self.entry_points_by_type.l1_handler),
+            entry_points_hash(&self.entry_points_by_type.constructor),
+            poseidon_hash_many(&bytecode),
+        ])
+        .to_biguint()
+    }
 }
 
 #[derive(Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -367,3 +384,25 @@ pub struct CasmContractEntryPoints {
     #[serde(rename = "CONSTRUCTOR")]
     pub constructor: Vec<CasmContractEntryPoint>,
 }
+
+/// Returns the Poseidon hash of the given entry points, given by their selectors, offsets and the
+/// hash of the names of their builtins.
+fn entry_points_hash(entry_points: &[CasmContractEntryPoint]) -> Felt252 {
+    poseidon_hash_many(
+        &entry_points
+            .iter()
+            .flat_map(|entry_point| {
+                let builtins = entry_point
+                    .builtins
+                    .iter()
+                    .map(|builtin| Felt252::from(BigUint::from_bytes_be(builtin.as_bytes())))
+                    .collect_vec();
+                [
+                    Felt252::from(entry_point.selector.clone()),
+                    Felt252::from(entry_point.offset),
+                    poseidon_hash_many(&builtins),
+                ]
+            })
+            .collect_vec(),
+    )
+}
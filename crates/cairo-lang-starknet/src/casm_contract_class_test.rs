@@ -46,3 +46,25 @@ fn test_casm_contract_from_contract_class_failure(example_file_name: &str) {
         Err(StarknetSierraCompilationError::ValueOutOfRange)
     );
 }
+
+#[test_case(
+    "minimal_contract",
+    "0x73f17e5e8c771a97cb07bf6024753d514ed9a1b5de4ec151e06d0926b015694";
+    "minimal_contract"
+)]
+#[test_case(
+    "hello_starknet",
+    "0x5c82c98f2ab111bd50293ba64bb18cf49037374783ad2486c712709c4ba0d89";
+    "hello_starknet"
+)]
+#[test_case(
+    "erc20",
+    "0x3ee9e34526074541498450f4f138bb9e4aae5631809f69284a85979a5c678b3";
+    "erc20"
+)]
+fn test_compiled_class_hash(example_file_name: &str, expected: &str) {
+    let f = std::fs::File::open(get_example_file_path(&format!("{example_file_name}.casm.json")))
+        .unwrap();
+    let casm_contract: CasmContractClass = serde_json::from_reader(BufReader::new(f)).unwrap();
+    assert_eq!(format!("{:#x}", casm_contract.compiled_class_hash()), expected);
+}
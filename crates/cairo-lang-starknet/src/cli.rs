@@ -1,11 +1,12 @@
 use std::fs;
 use std::path::PathBuf;
 
-use anyhow::Context;
+use anyhow::{ensure, Context};
 use cairo_lang_compiler::CompilerConfig;
 use cairo_lang_starknet::allowed_libfuncs::{validate_compatible_sierra_version, ListSelector};
-use cairo_lang_starknet::contract_class::compile_path;
+use cairo_lang_starknet::contract_class::compile_contracts_in_path;
 use clap::Parser;
+use itertools::Itertools;
 
 /// Command line args parser.
 /// Exits with 0/1 if the input is formatted correctly/incorrectly.
@@ -16,6 +17,18 @@ struct Args {
     path: PathBuf,
     /// The output file name (default: stdout).
     output: Option<String>,
+    /// The module path of a contract to compile, e.g. `my_crate::my_contract`. May be given
+    /// multiple times (default: all the contracts).
+    #[arg(long = "contract")]
+    contracts: Vec<String>,
+    /// The directory to write the compiled contracts to, a file per contract named after its
+    /// module path, with `::` replaced by `_`.
+    #[arg(long, conflicts_with = "output")]
+    output_dir: Option<PathBuf>,
+    /// Prints the class hash of the compiled contract to stderr. When compiling to an output
+    /// directory, prints a `<module path>: <class hash>` line per contract.
+    #[arg(long, default_value_t = false)]
+    print_class_hash: bool,
     /// Replaces sierra ids with human-readable ones.
     #[arg(short, long, default_value_t = false)]
     replace_ids: bool,
@@ -32,15 +45,47 @@ fn main() -> anyhow::Result<()> {
     let list_selector =
         ListSelector::new(args.allowed_libfuncs_list_name, args.allowed_libfuncs_list_file)
             .expect("Both allowed libfunc list name and file were supplied.");
-    let contract = compile_path(
+    let contracts = compile_contracts_in_path(
         &args.path,
+        &args.contracts,
         CompilerConfig { replace_ids: args.replace_ids, ..CompilerConfig::default() },
     )?;
-    validate_compatible_sierra_version(&contract, list_selector)?;
-    let res = serde_json::to_string_pretty(&contract).with_context(|| "Serialization failed.")?;
-    match args.output {
-        Some(path) => fs::write(path, res).with_context(|| "Failed to write output.")?,
-        None => println!("{res}"),
+    for (_, contract) in &contracts {
+        validate_compatible_sierra_version(contract, list_selector.clone())?;
+    }
+    match args.output_dir {
+        Some(output_dir) => {
+            fs::create_dir_all(&output_dir)
+                .with_context(|| "Failed to create the output directory.")?;
+            for (contract_path, contract) in &contracts {
+                let res = serde_json::to_string_pretty(contract)
+                    .with_context(|| "Serialization failed.")?;
+                let file_name = format!("{}.json", contract_path.replace("::", "_"));
+                fs::write(output_dir.join(file_name), res)
+                    .with_context(|| "Failed to write output.")?;
+                if args.print_class_hash {
+                    eprintln!("{contract_path}: {:#x}", contract.class_hash());
+                }
+            }
+        }
+        None => {
+            ensure!(
+                contracts.len() == 1,
+                "Compilation unit must include only one contract, found: {}. Use `--contract` to \
+                 select a contract, or `--output-dir` to compile all of them.",
+                contracts.iter().map(|(contract_path, _)| contract_path).join(", ")
+            );
+            let (_, contract) = &contracts[0];
+            let res =
+                serde_json::to_string_pretty(contract).with_context(|| "Serialization failed.")?;
+            match args.output {
+                Some(path) => fs::write(path, res).with_context(|| "Failed to write output.")?,
+                None => println!("{res}"),
+            }
+            if args.print_class_hash {
+                eprintln!("{:#x}", contract.class_hash());
+            }
+        }
     }
 
     Ok(())
@@ -26,6 +26,11 @@ impl ContractDeclaration {
     pub fn module_id(&self) -> ModuleId {
         ModuleId::Submodule(self.submodule_id)
     }
+
+    /// Returns the path of the module that defines the contract, e.g. `my_crate::my_contract`.
+    pub fn module_path(&self, db: &dyn SemanticGroup) -> String {
+        self.module_id().full_path(db.upcast())
+    }
 }
 
 /// A variant of eth-keccak that computes a value that fits in a Starknet field element.
@@ -1,19 +1,20 @@
 use std::path::Path;
 use std::sync::Arc;
 
-use anyhow::{ensure, Context, Result};
+use anyhow::{bail, ensure, Context, Result};
+use cairo_felt::Felt as Felt252;
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::project::setup_project;
 use cairo_lang_compiler::CompilerConfig;
 use cairo_lang_diagnostics::ToOption;
 use cairo_lang_filesystem::ids::CrateId;
-use cairo_lang_semantic::db::SemanticGroup;
-use cairo_lang_semantic::{ConcreteFunctionWithBodyId, FunctionLongId};
+use cairo_lang_lowering::ids::SemanticConcreteFunctionWithBodyIdEx;
+use cairo_lang_semantic::ConcreteFunctionWithBodyId;
 use cairo_lang_sierra_generator::canonical_id_replacer::CanonicalReplacer;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::{replace_sierra_ids_in_program, SierraIdReplacer};
 use cairo_lang_utils::bigint::{deserialize_big_uint, serialize_big_uint, BigUintAsHex};
-use itertools::{chain, Itertools};
+use itertools::{chain, zip_eq, Itertools};
 use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -26,6 +27,7 @@ use crate::contract::{
 use crate::db::StarknetRootDatabaseBuilderEx;
 use crate::felt252_serde::sierra_to_felt252s;
 use crate::plugin::consts::{CONSTRUCTOR_MODULE, EXTERNAL_MODULE, L1_HANDLER_MODULE};
+use crate::poseidon::poseidon_hash_many;
 use crate::sierra_version::{self};
 
 #[cfg(test)]
@@ -52,6 +54,49 @@ pub struct ContractClass {
 
 const DEFAULT_CONTRACT_CLASS_VERSION: &str = "0.1.0";
 
+impl ContractClass {
+    /// Returns the class hash of the contract, as defined by Starknet: the Poseidon hash of the
+    /// class version, the entry points of each type, the starknet keccak of the ABI and the Sierra
+    /// program.
+    pub fn class_hash(&self) -> BigUint {
+        let version = format!("CONTRACT_CLASS_V{}", self.contract_class_version);
+        let abi = match &self.abi {
+            Some(abi) => serde_json::to_string(abi).unwrap(),
+            None => String::new(),
+        };
+        let program = self
+            .sierra_program
+            .iter()
+            .map(|value| Felt252::from(value.value.clone()))
+            .collect_vec();
+        poseidon_hash_many(&[
+            Felt252::from(BigUint::from_bytes_be(version.as_bytes())),
+            entry_points_hash(&self.entry_points_by_type.external),
+            entry_points_hash(&self.entry_points_by_type.l1_handler),
+            entry_points_hash(&self.entry_points_by_type.constructor),
+            Felt252::from(starknet_keccak(abi.as_bytes())),
+            poseidon_hash_many(&program),
+        ])
+        .to_biguint()
+    }
+}
+
+/// Returns the Poseidon hash of the given entry points, given by their selectors and function
+/// indices.
+fn entry_points_hash(entry_points: &[ContractEntryPoint]) -> Felt252 {
+    poseidon_hash_many(
+        &entry_points
+            .iter()
+            .flat_map(|entry_point| {
+                [
+                    Felt252::from(entry_point.selector.clone()),
+                    Felt252::from(entry_point.function_idx),
+                ]
+            })
+            .collect_vec(),
+    )
+}
+
 #[derive(Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ContractEntryPoints {
     #[serde(rename = "EXTERNAL")]
@@ -82,6 +127,35 @@ pub fn compile_path(path: &Path, compiler_config: CompilerConfig<'_>) -> Result<
     compile_only_contract_in_prepared_db(&mut db, main_crate_ids, compiler_config)
 }
 
+/// Compiles the contracts given by path, returning each contract class along with the path of the
+/// module defining the contract.
+///
+/// Compiles all the contracts if `contract_paths` is empty, and otherwise only the contracts
+/// defined in the given module paths, erroring if any of them is not found.
+pub fn compile_contracts_in_path(
+    path: &Path,
+    contract_paths: &[String],
+    compiler_config: CompilerConfig<'_>,
+) -> Result<Vec<(String, ContractClass)>> {
+    let mut db = RootDatabase::builder().detect_corelib().with_starknet().build()?;
+
+    let main_crate_ids = setup_project(&mut db, path)?;
+
+    let mut contracts = find_contracts(&db, &main_crate_ids);
+    if !contract_paths.is_empty() {
+        for contract_path in contract_paths {
+            if !contracts.iter().any(|contract| contract.module_path(&db) == *contract_path) {
+                bail!("Contract `{contract_path}` not found.");
+            }
+        }
+        contracts.retain(|contract| contract_paths.contains(&contract.module_path(&db)));
+    }
+    ensure!(!contracts.is_empty(), "Contract not found.");
+    let paths = contracts.iter().map(|contract| contract.module_path(&db)).collect_vec();
+    let classes = compile_prepared_db(&mut db, &contracts.iter().collect_vec(), compiler_config)?;
+    Ok(zip_eq(paths, classes).collect())
+}
+
 /// Runs StarkNet contract compiler on the only contract defined in main crates.
 ///
 /// This function will return an error if no, or more than 1 contract is found.
@@ -92,8 +166,11 @@ fn compile_only_contract_in_prepared_db(
 ) -> Result<ContractClass> {
     let contracts = find_contracts(db, &main_crate_ids);
     ensure!(!contracts.is_empty(), "Contract not found.");
-    // TODO(ilya): Add contract names.
-    ensure!(contracts.len() == 1, "Compilation unit must include only one contract.");
+    ensure!(
+        contracts.len() == 1,
+        "Compilation unit must include only one contract, found: {}.",
+        contracts.iter().map(|contract| contract.module_path(db)).join(", ")
+    );
 
     let contracts = contracts.iter().collect::<Vec<_>>();
     let mut classes = compile_prepared_db(db, &contracts, compiler_config)?;
@@ -192,12 +269,11 @@ fn get_entry_points(
 ) -> Result<Vec<ContractEntryPoint>> {
     let mut entry_points = vec![];
     for function_with_body_id in entry_point_functions {
-        let function_id = db.intern_function(FunctionLongId {
-            function: function_with_body_id
-                .concrete(db)
-                .to_option()
-                .with_context(|| "Function error.")?,
-        });
+        let function_id = function_with_body_id
+            .lowered(db)
+            .function_id(db)
+            .to_option()
+            .with_context(|| "Function error.")?;
 
         let sierra_id = db.intern_sierra_function(function_id);
 
@@ -1,12 +1,15 @@
+use cairo_lang_compiler::CompilerConfig;
 use cairo_lang_test_utils::compare_contents_or_fix_with_path;
 use indoc::indoc;
+use itertools::Itertools;
 use num_bigint::BigUint;
 use pretty_assertions::assert_eq;
 use test_case::test_case;
 
 use crate::allowed_libfuncs::{validate_compatible_sierra_version, ListSelector};
 use crate::contract_class::{
-    ContractClass, ContractEntryPoint, ContractEntryPoints, DEFAULT_CONTRACT_CLASS_VERSION,
+    compile_contracts_in_path, compile_path, ContractClass, ContractEntryPoint,
+    ContractEntryPoints, DEFAULT_CONTRACT_CLASS_VERSION,
 };
 use crate::felt252_serde::sierra_from_felt252s;
 use crate::sierra_version;
@@ -94,3 +97,82 @@ fn test_compile_path(example_file_name: &str) {
         sierra_program.to_string(),
     );
 }
+
+#[test_case(
+    &[],
+    &[
+        "multiple_contracts::multiple_contracts::FirstContract",
+        "multiple_contracts::multiple_contracts::inner::SecondContract"
+    ];
+    "all contracts"
+)]
+#[test_case(
+    &["multiple_contracts::multiple_contracts::inner::SecondContract"],
+    &["multiple_contracts::multiple_contracts::inner::SecondContract"];
+    "selected contract"
+)]
+fn test_compile_contracts_in_path(contract_paths: &[&str], expected_contract_paths: &[&str]) {
+    let contracts = compile_contracts_in_path(
+        &get_example_file_path("multiple_contracts.cairo"),
+        &contract_paths.iter().map(|contract_path| contract_path.to_string()).collect_vec(),
+        CompilerConfig::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        contracts.iter().map(|(contract_path, _)| contract_path.as_str()).collect_vec(),
+        expected_contract_paths
+    );
+    for (_, contract) in &contracts {
+        assert_eq!(contract.entry_points_by_type.external.len(), 1);
+    }
+}
+
+#[test]
+fn test_compile_contracts_in_path_missing_contract() {
+    let err = compile_contracts_in_path(
+        &get_example_file_path("multiple_contracts.cairo"),
+        &["multiple_contracts::multiple_contracts::ThirdContract".to_string()],
+        CompilerConfig::default(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Contract `multiple_contracts::multiple_contracts::ThirdContract` not found."
+    );
+}
+
+#[test]
+fn test_compile_path_multiple_contracts() {
+    let err = compile_path(&get_example_file_path("multiple_contracts.cairo"), Default::default())
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Compilation unit must include only one contract, found: \
+         multiple_contracts::multiple_contracts::FirstContract, \
+         multiple_contracts::multiple_contracts::inner::SecondContract."
+    );
+}
+
+#[test_case(
+    "minimal_contract",
+    "0x5e3dc84968b0267512b27c580c203d8aa0ff2e23405c788b5f59cc38021255b";
+    "minimal_contract"
+)]
+#[test_case(
+    "hello_starknet",
+    "0x28fef41e1000206bc9b7b41d510113e89b418a7b00e7d46955a65b281c9c3ce";
+    "hello_starknet"
+)]
+#[test_case(
+    "erc20",
+    "0x4191b33821c3715bf4e4940c1ee04c3a82f26d856e5b6ff140173b5f0830df5";
+    "erc20"
+)]
+fn test_class_hash(example_file_name: &str, expected: &str) {
+    let contract_class: ContractClass = serde_json::from_str(
+        &std::fs::read_to_string(get_example_file_path(&format!("{example_file_name}.json")))
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(format!("{:#x}", contract_class.class_hash()), expected);
+}
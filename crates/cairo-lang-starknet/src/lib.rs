@@ -12,6 +12,7 @@ pub mod contract_class;
 pub mod db;
 mod felt252_serde;
 pub mod plugin;
+pub mod poseidon;
 mod sierra_version;
 
 #[cfg(test)]
@@ -131,6 +131,13 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                     ret_decode,
                 ));
             }
+            ast::TraitItem::Type(_) | ast::TraitItem::Constant(_) => {
+                diagnostics.push(PluginDiagnostic {
+                    message: "Associated items are not supported in the ABI of a contract."
+                        .to_string(),
+                    stable_ptr: item_ast.stable_ptr().untyped(),
+                });
+            }
         }
     }
 
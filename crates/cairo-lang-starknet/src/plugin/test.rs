@@ -1,7 +1,9 @@
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::get_diagnostics_as_string;
 use cairo_lang_defs::db::DefsGroup;
-use cairo_lang_defs::plugin::{MacroPlugin, PluginGeneratedFile, PluginResult};
+use cairo_lang_defs::plugin::{
+    MacroPlugin, MacroPluginMetadata, PluginGeneratedFile, PluginResult,
+};
 use cairo_lang_parser::db::ParserGroup;
 use cairo_lang_semantic::test_utils::setup_test_module;
 use cairo_lang_syntax::node::TypedSyntaxNode;
@@ -28,11 +30,12 @@ impl TestFileRunner for ExpandContractTestRunner {
         let syntax_file = self.db.file_syntax(file_id).unwrap();
 
         let plugin = StarkNetPlugin {};
+        let metadata = MacroPluginMetadata { cfg_set: &[], edition: Default::default() };
         let mut generated_items: Vec<String> = Vec::new();
 
         for item in syntax_file.items(&self.db).elements(&self.db).into_iter() {
             let PluginResult { code, diagnostics: _, remove_original_item } =
-                plugin.generate_code(&self.db, item.clone());
+                plugin.generate_code(&self.db, item.clone(), &metadata);
 
             let content = match code {
                 Some(PluginGeneratedFile { content, .. }) => content,
@@ -0,0 +1,74 @@
+//! The Poseidon hash function used by Starknet, over the Hades permutation.
+
+use cairo_felt::Felt as Felt252;
+use lazy_static::lazy_static;
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
+
+#[cfg(test)]
+#[path = "poseidon_test.rs"]
+mod test;
+
+/// The number of full rounds in the Hades permutation, half at the start and half at the end.
+const N_FULL_ROUNDS: usize = 8;
+/// The number of partial rounds in the Hades permutation.
+const N_PARTIAL_ROUNDS: usize = 83;
+
+lazy_static! {
+    /// The round constants of the Hades permutation, where the `i`th constant is the sha256 of
+    /// `Hades{i}`, as a felt252.
+    static ref ROUND_CONSTANTS: Vec<[Felt252; 3]> = (0..N_FULL_ROUNDS + N_PARTIAL_ROUNDS)
+        .map(|round| {
+            [0, 1, 2].map(|i| {
+                let digest = Sha256::digest(format!("Hades{}", 3 * round + i).as_bytes());
+                Felt252::from(BigUint::from_bytes_be(&digest))
+            })
+        })
+        .collect();
+}
+
+/// Applies the Hades permutation to the given state.
+pub fn hades_permutation(state: &mut [Felt252; 3]) {
+    let first_partial_round = N_FULL_ROUNDS / 2;
+    let first_final_full_round = first_partial_round + N_PARTIAL_ROUNDS;
+    for (round, constants) in ROUND_CONSTANTS.iter().enumerate() {
+        for (value, constant) in state.iter_mut().zip(constants) {
+            *value = value.clone() + constant;
+        }
+        let is_full_round = round < first_partial_round || round >= first_final_full_round;
+        for value in if is_full_round { &mut state[..] } else { &mut state[2..] } {
+            *value = value.clone() * value.clone() * value.clone();
+        }
+        // Multiplication by the MDS matrix [[3, 1, 1], [1, -1, 1], [1, 1, -2]].
+        let sum = state[0].clone() + state[1].clone() + state[2].clone();
+        let [s0, s1, s2] = state.clone();
+        *state = [
+            sum.clone() + s0.clone() + s0,
+            sum.clone() - s1.clone() - s1,
+            sum - s2.clone() - s2.clone() - s2,
+        ];
+    }
+}
+
+/// Computes the Poseidon hash of the given values, compatible with `poseidon_hash_many` of the
+/// Starknet OS: the values are absorbed in pairs, padded with 1 (and 0 if needed to complete a
+/// pair), and the hash is the first element of the final state.
+pub fn poseidon_hash_many(values: &[Felt252]) -> Felt252 {
+    let mut state = [Felt252::from(0), Felt252::from(0), Felt252::from(0)];
+    let mut chunks = values.chunks_exact(2);
+    for chunk in chunks.by_ref() {
+        state[0] = state[0].clone() + &chunk[0];
+        state[1] = state[1].clone() + &chunk[1];
+        hades_permutation(&mut state);
+    }
+    match chunks.remainder() {
+        [value] => {
+            state[0] = state[0].clone() + value;
+            state[1] = state[1].clone() + Felt252::from(1);
+        }
+        _ => state[0] = state[0].clone() + Felt252::from(1),
+    }
+    hades_permutation(&mut state);
+    let [hash, _, _] = state;
+    hash
+}
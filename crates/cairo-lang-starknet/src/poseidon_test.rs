@@ -0,0 +1,32 @@
+use cairo_felt::Felt as Felt252;
+use num_bigint::BigUint;
+use num_traits::Num;
+use test_case::test_case;
+
+use super::{hades_permutation, poseidon_hash_many};
+
+fn felt252_from_hex(value: &str) -> Felt252 {
+    Felt252::from(BigUint::from_str_radix(value, 16).unwrap())
+}
+
+#[test]
+fn test_hades_permutation() {
+    let mut state = [Felt252::from(0), Felt252::from(0), Felt252::from(0)];
+    hades_permutation(&mut state);
+    assert_eq!(
+        state,
+        [
+            felt252_from_hex("79e8d1e78258000a28fc9d49e233bc6852357968577b1e386550ed6a9086133"),
+            felt252_from_hex("3840d003d0f3f96dbb796ff6aa6a63be5b5404b91ccaabca256154cbb6fb984"),
+            felt252_from_hex("1eb39da3f7d3b04142d0ac83d9da00c9325a61fb2ef326e50b70eaa8a3c7cc7"),
+        ]
+    );
+}
+
+#[test_case(&[], "2272be0f580fd156823304800919530eaa97430e972d7213ee13f4fbf7a5dbc"; "empty")]
+#[test_case(&[1], "579e8877c7755365d5ec1ec7d3a94a457eff5d1f40482bbe9729c064cdead2"; "odd")]
+#[test_case(&[1, 2, 3, 4], "26e3ad8b876e02bc8a4fc43dad40a8f81a6384083cabffa190bcf40d512ae1d"; "even")]
+fn test_poseidon_hash_many(values: &[u64], expected: &str) {
+    let values = values.iter().map(|value| Felt252::from(*value)).collect::<Vec<_>>();
+    assert_eq!(poseidon_hash_many(&values), felt252_from_hex(expected));
+}
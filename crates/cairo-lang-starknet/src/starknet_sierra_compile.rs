@@ -24,6 +24,9 @@ struct Args {
     /// Add pythonic hints.
     #[arg(long, default_value_t = false)]
     add_pythonic_hints: bool,
+    /// Prints the compiled class hash of the compiled contract to stderr.
+    #[arg(long, default_value_t = false)]
+    print_compiled_class_hash: bool,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -48,5 +51,8 @@ fn main() -> anyhow::Result<()> {
         Some(path) => fs::write(path, res).with_context(|| "Failed to write casm contract.")?,
         None => println!("{res}"),
     }
+    if args.print_compiled_class_hash {
+        eprintln!("{:#x}", casm_contract.compiled_class_hash());
+    }
     Ok(())
 }
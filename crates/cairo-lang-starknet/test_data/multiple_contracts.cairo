@@ -0,0 +1,17 @@
+#[contract]
+mod FirstContract {
+    #[external]
+    fn first() -> felt252 {
+        1
+    }
+}
+
+mod inner {
+    #[contract]
+    mod SecondContract {
+        #[external]
+        fn second() -> felt252 {
+            2
+        }
+    }
+}
@@ -28,9 +28,13 @@ pub fn get_spec() -> Vec<Node> {
         .node("Block")
         .node("Match")
         .node("If")
+        .node("Loop")
+        .node("While")
+        .node("For")
         .node("ErrorPropagate")
         .node("FieldInitShorthand")
-        .node("Indexed"),
+        .node("Indexed")
+        .node("Closure"),
     )
     .add_separated_list("ExprList", "Expr", "TerminalComma")
     .add_struct(StructBuilder::new("ArgNameClause")
@@ -114,6 +118,7 @@ pub fn get_spec() -> Vec<Node> {
         .node_with_explicit_kind("GE", "TerminalGE")
         .node_with_explicit_kind("LT", "TerminalLT")
         .node_with_explicit_kind("GT", "TerminalGT")
+        .node_with_explicit_kind("DotDot", "TerminalDotDot")
     )
     .add_struct(StructBuilder::new("ExprTuple")
         .node("lparen", "TerminalLParen")
@@ -167,6 +172,23 @@ pub fn get_spec() -> Vec<Node> {
         .node("else_block_or_if", "BlockOrIf")
     )
     .add_option("ElseClause")
+    .add_struct(StructBuilder::new("ExprLoop")
+        .node("loop_kw", "TerminalLoop")
+        .node("body", "ExprBlock")
+    )
+    .add_struct(StructBuilder::new("ExprWhile")
+        .node("while_kw", "TerminalWhile")
+        .node("condition", "Expr")
+        .node("body", "ExprBlock")
+    )
+    .add_struct(StructBuilder::new("ExprFor")
+        .node("for_kw", "TerminalFor")
+        .node("pattern", "Pattern")
+        // The `in` contextual keyword.
+        .node("identifier", "TerminalIdentifier")
+        .node("expr", "Expr")
+        .node("body", "ExprBlock")
+    )
     .add_struct(StructBuilder::new("ExprErrorPropagate").node("expr", "Expr").node("op", "TerminalQuestionMark"))
     .add_struct(StructBuilder::new("ExprIndexed")
         .node("expr", "Expr")
@@ -174,6 +196,26 @@ pub fn get_spec() -> Vec<Node> {
         .node("index_expr", "Expr")
         .node("rbrack", "TerminalRBrack")
     )
+    .add_struct(StructBuilder::new("ExprClosure")
+        .node("wrapper", "ClosureParamWrapper")
+        .node("ret_ty", "OptionReturnTypeClause")
+        .node("expr", "Expr")
+    )
+    .add_enum(EnumBuilder::new("ClosureParamWrapper")
+        .node_with_explicit_kind("Nullary", "TerminalOrOr")
+        .node("NAry")
+    )
+    .add_struct(StructBuilder::new("ClosureParamWrapperNAry")
+        .node("leftor", "TerminalOr")
+        .node("params", "ClosureParamList")
+        .node("rightor", "TerminalOr")
+    )
+    .add_separated_list("ClosureParamList", "ClosureParam", "TerminalComma")
+    .add_struct(StructBuilder::new("ClosureParam")
+        .node("modifiers", "ModifierList")
+        .key_node("name", "TerminalIdentifier")
+        .node("type_clause", "OptionTypeClause")
+    )
     // --- Struct ctor ---
     .add_struct(StructBuilder::new("StructArgExpr")
         .node("colon", "TerminalColon")
@@ -260,7 +302,9 @@ pub fn get_spec() -> Vec<Node> {
         .missing("Missing")
         .node("Let")
         .node("Expr")
+        .node("Continue")
         .node("Return")
+        .node("Break")
     )
     .add_list("StatementList", "Statement")
     .add_struct(StructBuilder::new("StatementMissing"))
@@ -277,11 +321,22 @@ pub fn get_spec() -> Vec<Node> {
         .node("expr", "Expr")
         .node("semicolon", "OptionTerminalSemicolon")
     )
+    .add_struct(StructBuilder::new("StatementContinue")
+        .node("continue_kw", "TerminalContinue")
+        .node("semicolon", "TerminalSemicolon")
+    )
+    .add_struct(StructBuilder::new("ExprClause").node("expr", "Expr"))
+    .add_option("ExprClause")
     .add_struct(StructBuilder::new("StatementReturn")
         .node("return_kw", "TerminalReturn")
         .node("expr", "Expr")
         .node("semicolon", "TerminalSemicolon")
     )
+    .add_struct(StructBuilder::new("StatementBreak")
+        .node("break_kw", "TerminalBreak")
+        .node("expr_clause", "OptionExprClause")
+        .node("semicolon", "TerminalSemicolon")
+    )
     // --- Functions ---
     .add_struct(StructBuilder::new("Param")
         .node("modifiers", "ModifierList")
@@ -420,8 +475,9 @@ pub fn get_spec() -> Vec<Node> {
     )
     .add_list("TraitItemList", "TraitItem")
     .add_enum(EnumBuilder::new("TraitItem")
-        // TODO(spapini): types and constants.
         .node("Function")
+        .node("Type")
+        .node("Constant")
     )
     .add_struct(StructBuilder::new("TraitItemFunction")
         .node("attributes" ,"AttributeList")
@@ -433,6 +489,19 @@ pub fn get_spec() -> Vec<Node> {
         .node_with_explicit_kind("Some", "ExprBlock")
         .node_with_explicit_kind("None", "TerminalSemicolon")
     )
+    .add_struct(StructBuilder::new("TraitItemType")
+        .node("attributes" ,"AttributeList")
+        .node("type_kw", "TerminalType")
+        .key_node("name", "TerminalIdentifier")
+        .node("semicolon", "TerminalSemicolon")
+    )
+    .add_struct(StructBuilder::new("TraitItemConstant")
+        .node("attributes" ,"AttributeList")
+        .node("const_kw", "TerminalConst")
+        .key_node("name", "TerminalIdentifier")
+        .node("type_clause", "TypeClause")
+        .node("semicolon", "TerminalSemicolon")
+    )
     .add_struct(StructBuilder::new("ItemImpl")
         .node("attributes" ,"AttributeList")
         .node("impl_kw", "TerminalImpl")
@@ -511,6 +580,7 @@ pub fn get_spec() -> Vec<Node> {
         .node("Type")
         .node("Const")
         .node("Impl")
+        .node("ImplAnonymous")
     )
     .add_struct(StructBuilder::new("GenericParamType")
         .key_node("name", "TerminalIdentifier")
@@ -525,20 +595,28 @@ pub fn get_spec() -> Vec<Node> {
         .node("colon", "TerminalColon")
         .node("trait_path", "ExprPath")
     )
+    .add_struct(StructBuilder::new("GenericParamImplAnonymous")
+        .node("plus", "TerminalPlus")
+        .node("trait_path", "ExprPath")
+    )
     // --- Tokens + Terminals ---
     .add_token_and_terminal("Identifier")
     .add_token_and_terminal("LiteralNumber")
     .add_token_and_terminal("ShortString")
+    .add_keyword_token_and_terminal("Break")
     .add_keyword_token_and_terminal("Const")
+    .add_keyword_token_and_terminal("Continue")
     .add_keyword_token_and_terminal("Else")
     .add_keyword_token_and_terminal("Enum")
     .add_keyword_token_and_terminal("Extern")
     .add_keyword_token_and_terminal("False")
+    .add_keyword_token_and_terminal("For")
     .add_keyword_token_and_terminal("Function")
     .add_keyword_token_and_terminal("If")
     .add_keyword_token_and_terminal("Impl")
     .add_keyword_token_and_terminal("Implicits")
     .add_keyword_token_and_terminal("Let")
+    .add_keyword_token_and_terminal("Loop")
     .add_keyword_token_and_terminal("Match")
     .add_keyword_token_and_terminal("Module")
     .add_keyword_token_and_terminal("Mut")
@@ -551,6 +629,7 @@ pub fn get_spec() -> Vec<Node> {
     .add_keyword_token_and_terminal("True")
     .add_keyword_token_and_terminal("Type")
     .add_keyword_token_and_terminal("Use")
+    .add_keyword_token_and_terminal("While")
     .add_token_and_terminal("And")
     .add_token_and_terminal("AndAnd")
     .add_token_and_terminal("Arrow")
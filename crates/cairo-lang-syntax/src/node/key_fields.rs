@@ -35,8 +35,15 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::ExprIf => vec![],
         SyntaxKind::ElseClause => vec![],
         SyntaxKind::OptionElseClauseEmpty => vec![],
+        SyntaxKind::ExprLoop => vec![],
+        SyntaxKind::ExprWhile => vec![],
+        SyntaxKind::ExprFor => vec![],
         SyntaxKind::ExprErrorPropagate => vec![],
         SyntaxKind::ExprIndexed => vec![],
+        SyntaxKind::ExprClosure => vec![],
+        SyntaxKind::ClosureParamWrapperNAry => vec![],
+        SyntaxKind::ClosureParamList => vec![],
+        SyntaxKind::ClosureParam => vec![/* name */ children[1]],
         SyntaxKind::StructArgExpr => vec![],
         SyntaxKind::OptionStructArgExprEmpty => vec![],
         SyntaxKind::StructArgSingle => vec![/* identifier */ children[0]],
@@ -59,7 +66,11 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::StatementLet => vec![/* pattern */ children[1]],
         SyntaxKind::OptionTerminalSemicolonEmpty => vec![],
         SyntaxKind::StatementExpr => vec![],
+        SyntaxKind::StatementContinue => vec![],
+        SyntaxKind::ExprClause => vec![],
+        SyntaxKind::OptionExprClauseEmpty => vec![],
         SyntaxKind::StatementReturn => vec![],
+        SyntaxKind::StatementBreak => vec![],
         SyntaxKind::Param => vec![/* name */ children[1]],
         SyntaxKind::ModifierList => vec![],
         SyntaxKind::ParamList => vec![],
@@ -87,6 +98,8 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::TraitBody => vec![],
         SyntaxKind::TraitItemList => vec![],
         SyntaxKind::TraitItemFunction => vec![/* declaration */ children[1]],
+        SyntaxKind::TraitItemType => vec![/* name */ children[2]],
+        SyntaxKind::TraitItemConstant => vec![/* name */ children[2]],
         SyntaxKind::ItemImpl => vec![/* name */ children[2]],
         SyntaxKind::ImplBody => vec![],
         SyntaxKind::ItemStruct => vec![/* name */ children[2]],
@@ -102,14 +115,19 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::GenericParamType => vec![/* name */ children[0]],
         SyntaxKind::GenericParamConst => vec![/* name */ children[1]],
         SyntaxKind::GenericParamImpl => vec![/* name */ children[1]],
+        SyntaxKind::GenericParamImplAnonymous => vec![],
         SyntaxKind::TokenIdentifier => vec![],
         SyntaxKind::TerminalIdentifier => vec![],
         SyntaxKind::TokenLiteralNumber => vec![],
         SyntaxKind::TerminalLiteralNumber => vec![],
         SyntaxKind::TokenShortString => vec![],
         SyntaxKind::TerminalShortString => vec![],
+        SyntaxKind::TokenBreak => vec![],
+        SyntaxKind::TerminalBreak => vec![],
         SyntaxKind::TokenConst => vec![],
         SyntaxKind::TerminalConst => vec![],
+        SyntaxKind::TokenContinue => vec![],
+        SyntaxKind::TerminalContinue => vec![],
         SyntaxKind::TokenElse => vec![],
         SyntaxKind::TerminalElse => vec![],
         SyntaxKind::TokenEnum => vec![],
@@ -118,6 +136,8 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::TerminalExtern => vec![],
         SyntaxKind::TokenFalse => vec![],
         SyntaxKind::TerminalFalse => vec![],
+        SyntaxKind::TokenFor => vec![],
+        SyntaxKind::TerminalFor => vec![],
         SyntaxKind::TokenFunction => vec![],
         SyntaxKind::TerminalFunction => vec![],
         SyntaxKind::TokenIf => vec![],
@@ -128,6 +148,8 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::TerminalImplicits => vec![],
         SyntaxKind::TokenLet => vec![],
         SyntaxKind::TerminalLet => vec![],
+        SyntaxKind::TokenLoop => vec![],
+        SyntaxKind::TerminalLoop => vec![],
         SyntaxKind::TokenMatch => vec![],
         SyntaxKind::TerminalMatch => vec![],
         SyntaxKind::TokenModule => vec![],
@@ -152,6 +174,8 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::TerminalType => vec![],
         SyntaxKind::TokenUse => vec![],
         SyntaxKind::TerminalUse => vec![],
+        SyntaxKind::TokenWhile => vec![],
+        SyntaxKind::TerminalWhile => vec![],
         SyntaxKind::TokenAnd => vec![],
         SyntaxKind::TerminalAnd => vec![],
         SyntaxKind::TokenAndAnd => vec![],
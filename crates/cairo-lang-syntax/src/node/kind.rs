@@ -31,8 +31,15 @@ pub enum SyntaxKind {
     ExprIf,
     ElseClause,
     OptionElseClauseEmpty,
+    ExprLoop,
+    ExprWhile,
+    ExprFor,
     ExprErrorPropagate,
     ExprIndexed,
+    ExprClosure,
+    ClosureParamWrapperNAry,
+    ClosureParamList,
+    ClosureParam,
     StructArgExpr,
     OptionStructArgExprEmpty,
     StructArgSingle,
@@ -55,7 +62,11 @@ pub enum SyntaxKind {
     StatementLet,
     OptionTerminalSemicolonEmpty,
     StatementExpr,
+    StatementContinue,
+    ExprClause,
+    OptionExprClauseEmpty,
     StatementReturn,
+    StatementBreak,
     Param,
     ModifierList,
     ParamList,
@@ -83,6 +94,8 @@ pub enum SyntaxKind {
     TraitBody,
     TraitItemList,
     TraitItemFunction,
+    TraitItemType,
+    TraitItemConstant,
     ItemImpl,
     ImplBody,
     ItemStruct,
@@ -98,14 +111,19 @@ pub enum SyntaxKind {
     GenericParamType,
     GenericParamConst,
     GenericParamImpl,
+    GenericParamImplAnonymous,
     TokenIdentifier,
     TerminalIdentifier,
     TokenLiteralNumber,
     TerminalLiteralNumber,
     TokenShortString,
     TerminalShortString,
+    TokenBreak,
+    TerminalBreak,
     TokenConst,
     TerminalConst,
+    TokenContinue,
+    TerminalContinue,
     TokenElse,
     TerminalElse,
     TokenEnum,
@@ -114,6 +132,8 @@ pub enum SyntaxKind {
     TerminalExtern,
     TokenFalse,
     TerminalFalse,
+    TokenFor,
+    TerminalFor,
     TokenFunction,
     TerminalFunction,
     TokenIf,
@@ -124,6 +144,8 @@ pub enum SyntaxKind {
     TerminalImplicits,
     TokenLet,
     TerminalLet,
+    TokenLoop,
+    TerminalLoop,
     TokenMatch,
     TerminalMatch,
     TokenModule,
@@ -148,6 +170,8 @@ pub enum SyntaxKind {
     TerminalType,
     TokenUse,
     TerminalUse,
+    TokenWhile,
+    TerminalWhile,
     TokenAnd,
     TerminalAnd,
     TokenAndAnd,
@@ -248,16 +272,20 @@ impl SyntaxKind {
             SyntaxKind::TokenIdentifier
                 | SyntaxKind::TokenLiteralNumber
                 | SyntaxKind::TokenShortString
+                | SyntaxKind::TokenBreak
                 | SyntaxKind::TokenConst
+                | SyntaxKind::TokenContinue
                 | SyntaxKind::TokenElse
                 | SyntaxKind::TokenEnum
                 | SyntaxKind::TokenExtern
                 | SyntaxKind::TokenFalse
+                | SyntaxKind::TokenFor
                 | SyntaxKind::TokenFunction
                 | SyntaxKind::TokenIf
                 | SyntaxKind::TokenImpl
                 | SyntaxKind::TokenImplicits
                 | SyntaxKind::TokenLet
+                | SyntaxKind::TokenLoop
                 | SyntaxKind::TokenMatch
                 | SyntaxKind::TokenModule
                 | SyntaxKind::TokenMut
@@ -270,6 +298,7 @@ impl SyntaxKind {
                 | SyntaxKind::TokenTrue
                 | SyntaxKind::TokenType
                 | SyntaxKind::TokenUse
+                | SyntaxKind::TokenWhile
                 | SyntaxKind::TokenAnd
                 | SyntaxKind::TokenAndAnd
                 | SyntaxKind::TokenArrow
@@ -326,16 +355,20 @@ impl SyntaxKind {
             SyntaxKind::TerminalIdentifier
                 | SyntaxKind::TerminalLiteralNumber
                 | SyntaxKind::TerminalShortString
+                | SyntaxKind::TerminalBreak
                 | SyntaxKind::TerminalConst
+                | SyntaxKind::TerminalContinue
                 | SyntaxKind::TerminalElse
                 | SyntaxKind::TerminalEnum
                 | SyntaxKind::TerminalExtern
                 | SyntaxKind::TerminalFalse
+                | SyntaxKind::TerminalFor
                 | SyntaxKind::TerminalFunction
                 | SyntaxKind::TerminalIf
                 | SyntaxKind::TerminalImpl
                 | SyntaxKind::TerminalImplicits
                 | SyntaxKind::TerminalLet
+                | SyntaxKind::TerminalLoop
                 | SyntaxKind::TerminalMatch
                 | SyntaxKind::TerminalModule
                 | SyntaxKind::TerminalMut
@@ -348,6 +381,7 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalTrue
                 | SyntaxKind::TerminalType
                 | SyntaxKind::TerminalUse
+                | SyntaxKind::TerminalWhile
                 | SyntaxKind::TerminalAnd
                 | SyntaxKind::TerminalAndAnd
                 | SyntaxKind::TerminalArrow
@@ -396,16 +430,20 @@ impl SyntaxKind {
     pub fn is_keyword_token(&self) -> bool {
         matches!(
             *self,
-            SyntaxKind::TokenConst
+            SyntaxKind::TokenBreak
+                | SyntaxKind::TokenConst
+                | SyntaxKind::TokenContinue
                 | SyntaxKind::TokenElse
                 | SyntaxKind::TokenEnum
                 | SyntaxKind::TokenExtern
                 | SyntaxKind::TokenFalse
+                | SyntaxKind::TokenFor
                 | SyntaxKind::TokenFunction
                 | SyntaxKind::TokenIf
                 | SyntaxKind::TokenImpl
                 | SyntaxKind::TokenImplicits
                 | SyntaxKind::TokenLet
+                | SyntaxKind::TokenLoop
                 | SyntaxKind::TokenMatch
                 | SyntaxKind::TokenModule
                 | SyntaxKind::TokenMut
@@ -418,21 +456,26 @@ impl SyntaxKind {
                 | SyntaxKind::TokenTrue
                 | SyntaxKind::TokenType
                 | SyntaxKind::TokenUse
+                | SyntaxKind::TokenWhile
         )
     }
     pub fn is_keyword_terminal(&self) -> bool {
         matches!(
             *self,
-            SyntaxKind::TerminalConst
+            SyntaxKind::TerminalBreak
+                | SyntaxKind::TerminalConst
+                | SyntaxKind::TerminalContinue
                 | SyntaxKind::TerminalElse
                 | SyntaxKind::TerminalEnum
                 | SyntaxKind::TerminalExtern
                 | SyntaxKind::TerminalFalse
+                | SyntaxKind::TerminalFor
                 | SyntaxKind::TerminalFunction
                 | SyntaxKind::TerminalIf
                 | SyntaxKind::TerminalImpl
                 | SyntaxKind::TerminalImplicits
                 | SyntaxKind::TerminalLet
+                | SyntaxKind::TerminalLoop
                 | SyntaxKind::TerminalMatch
                 | SyntaxKind::TerminalModule
                 | SyntaxKind::TerminalMut
@@ -445,6 +488,7 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalTrue
                 | SyntaxKind::TerminalType
                 | SyntaxKind::TerminalUse
+                | SyntaxKind::TerminalWhile
         )
     }
 }
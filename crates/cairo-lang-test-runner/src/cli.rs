@@ -3,6 +3,7 @@
 use std::collections::HashSet;
 use std::path::Path;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 use anyhow::{bail, Context};
 use cairo_lang_compiler::db::RootDatabase;
@@ -15,7 +16,8 @@ use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_plugins::config::ConfigPlugin;
 use cairo_lang_plugins::derive::DerivePlugin;
 use cairo_lang_plugins::panicable::PanicablePlugin;
-use cairo_lang_runner::short_string::as_cairo_short_string;
+use cairo_lang_runner::profiling::{ProfileFormat, ProfileResource, ProfilingInfo};
+use cairo_lang_runner::short_string::{as_cairo_byte_array, as_cairo_short_string};
 use cairo_lang_runner::{RunResultValue, SierraCasmRunner};
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::items::functions::GenericFunctionId;
@@ -24,18 +26,32 @@ use cairo_lang_semantic::{ConcreteFunction, ConcreteFunctionWithBodyId, Function
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
 use cairo_lang_starknet::plugin::StarkNetPlugin;
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use colored::Colorize;
 use itertools::Itertools;
+use num_traits::ToPrimitive;
 use plugin::TestPlugin;
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use reporter::{json_report, junit_report, PanicValue, TestRecord, TestRecordStatus, TestsCounts};
 use test_config::{try_extract_test_config, TestConfig};
 
 use crate::test_config::{PanicExpectation, TestExpectation};
 
 mod plugin;
+mod reporter;
 mod test_config;
 
+/// The format of the tests results output.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum OutputFormat {
+    /// Human readable output.
+    Human,
+    /// A JSON object per test, followed by a summary object, each in a separate line.
+    Json,
+    /// A JUnit XML report.
+    Junit,
+}
+
 /// Command line args parser.
 /// Exits with 0/1 if the input is formatted correctly/incorrectly.
 #[derive(Parser, Debug)]
@@ -56,6 +72,15 @@ struct Args {
     /// Should we add the starknet plugin to run the tests.
     #[arg(long, default_value_t = false)]
     starknet: bool,
+    /// The format of the tests results output.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Human)]
+    format: OutputFormat,
+    /// Whether to profile the resources used by the tests, and the format of the printed report.
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "top")]
+    profile: Option<ProfileFormat>,
+    /// The resource to report when profiling.
+    #[arg(long, value_enum, default_value_t = ProfileResource::Steps)]
+    profile_resource: ProfileResource,
 }
 
 /// The status of a ran test.
@@ -125,38 +150,42 @@ fn main() -> anyhow::Result<()> {
         .filter(|(_, test)| !args.ignored || test.ignored)
         .collect_vec();
     let filtered_out = total_tests_count - named_tests.len();
-    let TestsSummary { passed, failed, ignored, failed_run_results } =
-        run_tests(named_tests, sierra_program)?;
+    let TestsSummary { passed, failed, ignored, failed_run_results, mut records, profiling_info } =
+        run_tests(named_tests, sierra_program, args.format, args.profile.is_some())?;
+    records.sort_by(|a, b| a.name.cmp(&b.name));
+    let counts = TestsCounts {
+        passed: passed.len(),
+        failed: failed.len(),
+        ignored: ignored.len(),
+        filtered_out,
+    };
+    match args.format {
+        OutputFormat::Human => {}
+        OutputFormat::Json => print!("{}", json_report(&records, &counts)),
+        OutputFormat::Junit => print!("{}", junit_report(&records, &counts)),
+    }
+    if let Some(format) = args.profile {
+        print!("{}", profiling_info.report(args.profile_resource, format));
+    }
     if failed.is_empty() {
-        println!(
-            "test result: {}. {} passed; {} failed; {} ignored; {filtered_out} filtered out;",
-            "ok".bright_green(),
-            passed.len(),
-            failed.len(),
-            ignored.len()
-        );
+        if args.format == OutputFormat::Human {
+            println!(
+                "test result: {}. {} passed; {} failed; {} ignored; {filtered_out} filtered out;",
+                "ok".bright_green(),
+                passed.len(),
+                failed.len(),
+                ignored.len()
+            );
+        }
         Ok(())
     } else {
-        println!("failures:");
-        for (failure, run_result) in failed.iter().zip_eq(failed_run_results) {
-            print!("   {failure} - ");
-            match run_result {
-                RunResultValue::Success(_) => {
-                    println!("expected panic but finished successfully.");
-                }
-                RunResultValue::Panic(values) => {
-                    print!("panicked with [");
-                    for value in &values {
-                        match as_cairo_short_string(value) {
-                            Some(as_string) => print!("{value} ('{as_string}'), "),
-                            None => print!("{value}, "),
-                        }
-                    }
-                    println!("].")
-                }
+        if args.format == OutputFormat::Human {
+            println!("failures:");
+            for (failure, run_result) in failed.iter().zip_eq(failed_run_results) {
+                println!("   {failure} - {}", failure_message(&run_result));
             }
+            println!();
         }
-        println!();
         bail!(
             "test result: {}. {} passed; {} failed; {} ignored",
             "FAILED".bright_red(),
@@ -167,69 +196,129 @@ fn main() -> anyhow::Result<()> {
     }
 }
 
+/// Returns a description of the failure of a test with the given result.
+fn failure_message(run_result: &RunResultValue) -> String {
+    match run_result {
+        RunResultValue::Success(_) => "expected panic but finished successfully.".into(),
+        RunResultValue::Panic(values) => {
+            if let Some(as_string) = as_cairo_byte_array(values) {
+                return format!("panicked with \"{as_string}\".");
+            }
+            let mut message = "panicked with [".to_string();
+            for value in values {
+                match as_cairo_short_string(value) {
+                    Some(as_string) => message.push_str(&format!("{value} ('{as_string}'), ")),
+                    None => message.push_str(&format!("{value}, ")),
+                }
+            }
+            message.push_str("].");
+            message
+        }
+    }
+}
+
+/// The name, status, record and profiling info, if profiled, of a ran test.
+type TestRunResult = (String, TestStatus, TestRecord, Option<ProfilingInfo>);
+
 /// Summary data of the ran tests.
 struct TestsSummary {
     passed: Vec<String>,
     failed: Vec<String>,
     ignored: Vec<String>,
     failed_run_results: Vec<RunResultValue>,
+    /// The records of all the ran tests, in the order they finished.
+    records: Vec<TestRecord>,
+    /// The resources used by all the ran tests, if profiled.
+    profiling_info: ProfilingInfo,
 }
 
 /// Runs the tests and process the results for a summary.
 fn run_tests(
     named_tests: Vec<(String, TestConfig)>,
     sierra_program: cairo_lang_sierra::program::Program,
+    format: OutputFormat,
+    profiling: bool,
 ) -> anyhow::Result<TestsSummary> {
-    let runner =
-        SierraCasmRunner::new(sierra_program, true).with_context(|| "Failed setting up runner.")?;
-    println!("running {} tests", named_tests.len());
+    let runner = SierraCasmRunner::new(sierra_program, true)
+        .with_context(|| "Failed setting up runner.")?
+        .with_profiling(profiling);
+    if format == OutputFormat::Human {
+        println!("running {} tests", named_tests.len());
+    }
     let wrapped_summary = Mutex::new(Ok(TestsSummary {
         passed: vec![],
         failed: vec![],
         ignored: vec![],
         failed_run_results: vec![],
+        records: vec![],
+        profiling_info: ProfilingInfo::default(),
     }));
     named_tests
         .into_par_iter()
-        .map(|(name, test)| -> anyhow::Result<(String, TestStatus)> {
+        .map(|(name, test)| -> anyhow::Result<TestRunResult> {
             if test.ignored {
-                return Ok((name, TestStatus::Ignore));
+                let record =
+                    TestRecord::new(name.clone(), TestRecordStatus::Ignored, Default::default());
+                return Ok((name, TestStatus::Ignore, record, None));
             }
+            let start = Instant::now();
             let result = runner
                 .run_function(name.as_str(), &[], test.available_gas)
                 .with_context(|| format!("Failed to run the function `{}`.", name.as_str()))?;
-            Ok((
-                name,
-                match &result.value {
-                    RunResultValue::Success(_) => match test.expectation {
-                        TestExpectation::Success => TestStatus::Success,
-                        TestExpectation::Panics(_) => TestStatus::Fail(result.value),
-                    },
-                    RunResultValue::Panic(value) => match test.expectation {
-                        TestExpectation::Success => TestStatus::Fail(result.value),
-                        TestExpectation::Panics(panic_expectation) => match panic_expectation {
-                            PanicExpectation::Exact(expected) if value != &expected => {
-                                TestStatus::Fail(result.value)
-                            }
-                            _ => TestStatus::Success,
-                        },
+            let duration = start.elapsed();
+            let status = match &result.value {
+                RunResultValue::Success(_) => match test.expectation {
+                    TestExpectation::Success => TestStatus::Success,
+                    TestExpectation::Panics(_) => TestStatus::Fail(result.value),
+                },
+                RunResultValue::Panic(value) => match test.expectation {
+                    TestExpectation::Success => TestStatus::Fail(result.value),
+                    TestExpectation::Panics(panic_expectation) => match panic_expectation {
+                        PanicExpectation::Exact(expected) if value != &expected => {
+                            TestStatus::Fail(result.value)
+                        }
+                        _ => TestStatus::Success,
                     },
                 },
-            ))
+            };
+            let mut record = TestRecord::new(
+                name.clone(),
+                match status {
+                    TestStatus::Success => TestRecordStatus::Passed,
+                    TestStatus::Fail(_) => TestRecordStatus::Failed,
+                    TestStatus::Ignore => TestRecordStatus::Ignored,
+                },
+                duration,
+            );
+            if let TestStatus::Fail(run_result) = &status {
+                if let RunResultValue::Panic(values) = run_result {
+                    record.panic_data = Some(values.iter().map(PanicValue::new).collect());
+                }
+                record.failure_message = Some(failure_message(run_result));
+            }
+            // The gas consumed is only known if the test was given gas to run with.
+            record.gas_consumed = test
+                .available_gas
+                .zip(result.gas_counter.and_then(|gas_counter| gas_counter.to_usize()))
+                .and_then(|(available_gas, gas_counter)| available_gas.checked_sub(gas_counter));
+            Ok((name, status, record, result.profiling_info))
         })
         .for_each(|r| {
             let mut wrapped_summary = wrapped_summary.lock().unwrap();
             if wrapped_summary.is_err() {
                 return;
             }
-            let (name, status) = match r {
-                Ok((name, status)) => (name, status),
+            let (name, status, record, profiling_info) = match r {
+                Ok(result) => result,
                 Err(err) => {
                     *wrapped_summary = Err(err);
                     return;
                 }
             };
             let summary = wrapped_summary.as_mut().unwrap();
+            if let Some(profiling_info) = &profiling_info {
+                summary.profiling_info.merge(profiling_info);
+            }
             let (res_type, status_str) = match status {
                 TestStatus::Success => (&mut summary.passed, "ok".bright_green()),
                 TestStatus::Fail(run_result) => {
@@ -238,8 +327,14 @@ fn run_tests(
                 }
                 TestStatus::Ignore => (&mut summary.ignored, "ignored".bright_yellow()),
             };
-            println!("test {name} ... {status_str}",);
+            if format == OutputFormat::Human {
+                match record.gas_consumed {
+                    Some(gas) => println!("test {name} ... {status_str} (gas usage est.: {gas})"),
+                    None => println!("test {name} ... {status_str}"),
+                }
+            }
             res_type.push(name);
+            summary.records.push(record);
         });
     wrapped_summary.into_inner().unwrap()
 }
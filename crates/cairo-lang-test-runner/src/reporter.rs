@@ -0,0 +1,143 @@
+//! Machine readable reports of the tests results.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use cairo_felt::Felt as Felt252;
+use cairo_lang_runner::short_string::as_cairo_short_string;
+use serde::Serialize;
+
+#[cfg(test)]
+#[path = "reporter_test.rs"]
+mod test;
+
+/// The status of a ran test, as reported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TestRecordStatus {
+    Passed,
+    Failed,
+    Ignored,
+}
+
+/// A single value of the panic data of a test.
+#[derive(Clone, Debug, Serialize)]
+pub struct PanicValue {
+    /// The value, in decimal representation.
+    pub value: String,
+    /// The value decoded as a Cairo short string, if it is a valid one.
+    pub short_string: Option<String>,
+}
+impl PanicValue {
+    pub fn new(value: &Felt252) -> Self {
+        Self { value: value.to_string(), short_string: as_cairo_short_string(value) }
+    }
+}
+
+/// The result of a single test.
+#[derive(Clone, Debug, Serialize)]
+pub struct TestRecord {
+    /// The full path of the test function.
+    pub name: String,
+    pub status: TestRecordStatus,
+    /// The panic data of the test, if it panicked.
+    pub panic_data: Option<Vec<PanicValue>>,
+    /// A description of the failure, if the test failed.
+    pub failure_message: Option<String>,
+    /// The time it took to run the test, in seconds.
+    pub duration_secs: f64,
+    /// The gas consumed by the test, if it was run with available gas.
+    pub gas_consumed: Option<usize>,
+}
+impl TestRecord {
+    pub fn new(name: String, status: TestRecordStatus, duration: Duration) -> Self {
+        Self {
+            name,
+            status,
+            panic_data: None,
+            failure_message: None,
+            duration_secs: duration.as_secs_f64(),
+            gas_consumed: None,
+        }
+    }
+}
+
+/// Counts of the tests results.
+#[derive(Clone, Debug, Serialize)]
+pub struct TestsCounts {
+    pub passed: usize,
+    pub failed: usize,
+    pub ignored: usize,
+    pub filtered_out: usize,
+}
+
+/// A line of the JSON report.
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum JsonEvent<'a> {
+    Test(&'a TestRecord),
+    Summary(&'a TestsCounts),
+}
+
+/// Returns the JSON report of the tests: a JSON object per test, followed by a summary object, each
+/// in a separate line.
+pub fn json_report(records: &[TestRecord], counts: &TestsCounts) -> String {
+    let mut report = String::new();
+    for record in records {
+        writeln!(report, "{}", serde_json::to_string(&JsonEvent::Test(record)).unwrap()).unwrap();
+    }
+    writeln!(report, "{}", serde_json::to_string(&JsonEvent::Summary(counts)).unwrap()).unwrap();
+    report
+}
+
+/// Returns the JUnit XML report of the tests.
+pub fn junit_report(records: &[TestRecord], counts: &TestsCounts) -> String {
+    let total_duration: f64 = records.iter().map(|record| record.duration_secs).sum();
+    let mut report = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    writeln!(
+        report,
+        "<testsuites><testsuite name=\"cairo-test\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" \
+         time=\"{total_duration}\">",
+        records.len(),
+        counts.failed,
+        counts.ignored,
+    )
+    .unwrap();
+    for record in records {
+        write!(
+            report,
+            "<testcase name=\"{}\" time=\"{}\"",
+            xml_escape(&record.name),
+            record.duration_secs
+        )
+        .unwrap();
+        match record.status {
+            TestRecordStatus::Passed => report.push_str("/>\n"),
+            TestRecordStatus::Failed => writeln!(
+                report,
+                "><failure message=\"{}\"/></testcase>",
+                xml_escape(record.failure_message.as_deref().unwrap_or_default())
+            )
+            .unwrap(),
+            TestRecordStatus::Ignored => report.push_str("><skipped/></testcase>\n"),
+        }
+    }
+    report.push_str("</testsuite></testsuites>\n");
+    report
+}
+
+/// Escapes the given text to be used in an XML attribute.
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use cairo_felt::Felt as Felt252;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use super::{
+    json_report, junit_report, xml_escape, PanicValue, TestRecord, TestRecordStatus, TestsCounts,
+};
+
+/// Returns records of a passed, a failed and an ignored test, and their counts.
+fn records_and_counts() -> (Vec<TestRecord>, TestsCounts) {
+    let mut passed = TestRecord::new(
+        "test::passed".into(),
+        TestRecordStatus::Passed,
+        Duration::from_millis(500),
+    );
+    passed.gas_consumed = Some(1000);
+    let mut failed = TestRecord::new(
+        "test::failed<'a>".into(),
+        TestRecordStatus::Failed,
+        Duration::from_millis(250),
+    );
+    failed.panic_data = Some(vec![PanicValue::new(&Felt252::from_bytes_be(b"oops"))]);
+    failed.failure_message = Some("Panicked with \"oops\" & more.".into());
+    let ignored =
+        TestRecord::new("test::ignored".into(), TestRecordStatus::Ignored, Duration::ZERO);
+    (
+        vec![passed, failed, ignored],
+        TestsCounts { passed: 1, failed: 1, ignored: 1, filtered_out: 2 },
+    )
+}
+
+#[test]
+fn test_json_report() {
+    let (records, counts) = records_and_counts();
+    assert_eq!(
+        json_report(&records, &counts),
+        indoc! {r#"
+            {"type":"test","name":"test::passed","status":"passed","panic_data":null,"failure_message":null,"duration_secs":0.5,"gas_consumed":1000}
+            {"type":"test","name":"test::failed<'a>","status":"failed","panic_data":[{"value":"1869574259","short_string":"oops"}],"failure_message":"Panicked with \"oops\" & more.","duration_secs":0.25,"gas_consumed":null}
+            {"type":"test","name":"test::ignored","status":"ignored","panic_data":null,"failure_message":null,"duration_secs":0.0,"gas_consumed":null}
+            {"type":"summary","passed":1,"failed":1,"ignored":1,"filtered_out":2}
+        "#}
+    );
+}
+
+#[test]
+fn test_junit_report() {
+    let (records, counts) = records_and_counts();
+    assert_eq!(
+        junit_report(&records, &counts),
+        indoc! {r#"
+            <?xml version="1.0" encoding="UTF-8"?>
+            <testsuites><testsuite name="cairo-test" tests="3" failures="1" skipped="1" time="0.75">
+            <testcase name="test::passed" time="0.5"/>
+            <testcase name="test::failed&lt;&apos;a&gt;" time="0.25"><failure message="Panicked with &quot;oops&quot; &amp; more."/></testcase>
+            <testcase name="test::ignored" time="0"><skipped/></testcase>
+            </testsuite></testsuites>
+        "#}
+    );
+}
+
+#[test]
+fn test_xml_escape() {
+    assert_eq!(xml_escape("plain text"), "plain text");
+    assert_eq!(
+        xml_escape(r#"<a b="c" d='e'>&</a>"#),
+        "&lt;a b=&quot;c&quot; d=&apos;e&apos;&gt;&amp;&lt;/a&gt;"
+    );
+}
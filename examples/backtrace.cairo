@@ -0,0 +1,15 @@
+// Panics in `bar`, called by `foo`, called by `main`.
+fn main() -> felt252 {
+    foo(5) + 1
+}
+
+#[inline(never)]
+fn foo(a: felt252) -> felt252 {
+    bar(a + 1)
+}
+
+#[inline(never)]
+fn bar(b: felt252) -> felt252 {
+    assert(b == 0, 'b is not zero');
+    b
+}
@@ -0,0 +1,26 @@
+use array::ArrayTrait;
+use array::SpanTrait;
+use option::OptionTrait;
+use starknet::SyscallResultTrait;
+
+// The selector of `HelloStarknet::increase_balance`.
+const INCREASE_BALANCE_SELECTOR: felt252 =
+    0x362398bec32bc0ebb411203221a35a0301193a96f317ebe5e40be9f60d15320;
+// The selector of `HelloStarknet::get_balance`.
+const GET_BALANCE_SELECTOR: felt252 =
+    0x39e11d48192e4333233c7eb19d10ad67c362bb28580c604d67884c85da39695;
+
+// Increases the balance of the `HelloStarknet` contract deployed at `address` by `amount`, and
+// returns the updated balance.
+fn main(address: felt252, amount: felt252) -> felt252 {
+    let address = starknet::contract_address_try_from_felt252(address).unwrap();
+    let mut calldata = ArrayTrait::new();
+    calldata.append(amount);
+    starknet::call_contract_syscall(
+        address, INCREASE_BALANCE_SELECTOR, calldata.span()
+    ).unwrap_syscall();
+    let retdata = starknet::call_contract_syscall(
+        address, GET_BALANCE_SELECTOR, ArrayTrait::new().span()
+    ).unwrap_syscall();
+    *retdata.at(0_u32)
+}
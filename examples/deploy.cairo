@@ -0,0 +1,28 @@
+use array::ArrayTrait;
+use array::SpanTrait;
+use option::OptionTrait;
+use starknet::SyscallResultTrait;
+
+// The selector of `ERC20::get_total_supply`.
+const GET_TOTAL_SUPPLY_SELECTOR: felt252 =
+    0x2819e8b2b82ee4c56798709651ab9e8537f644c0823e42ba017efce4f2077e4;
+
+// Deploys an `ERC20` contract of the class `class_hash`, minting `initial_supply` tokens to
+// `recipient`, and returns its total supply.
+fn main(class_hash: felt252, recipient: felt252, initial_supply: felt252) -> felt252 {
+    let class_hash = starknet::class_hash_try_from_felt252(class_hash).unwrap();
+    let mut calldata = ArrayTrait::new();
+    calldata.append('TOKEN');
+    calldata.append('TKN');
+    calldata.append(18);
+    calldata.append(initial_supply);
+    calldata.append(0);
+    calldata.append(recipient);
+    let (address, _) = starknet::deploy_syscall(
+        class_hash, 0, calldata.span(), false
+    ).unwrap_syscall();
+    let retdata = starknet::call_contract_syscall(
+        address, GET_TOTAL_SUPPLY_SELECTOR, ArrayTrait::new().span()
+    ).unwrap_syscall();
+    *retdata.at(0_u32)
+}
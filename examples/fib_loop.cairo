@@ -0,0 +1,12 @@
+// Calculates fib, using a loop.
+fn fib(mut a: felt252, mut b: felt252, mut n: felt252) -> felt252 {
+    loop {
+        if n == 0 {
+            break a;
+        }
+        let temp = b;
+        b = a + b;
+        a = temp;
+        n = n - 1;
+    }
+}
@@ -0,0 +1,12 @@
+// Calculates H(...H(H(0, 1), ..., n))...) where H is the Pedersen hash function, using a loop.
+fn hash_chain(n: felt252) -> felt252 {
+    let mut i = 0;
+    let mut hash = 0;
+    loop {
+        if i == n {
+            break hash;
+        }
+        i = i + 1;
+        hash = pedersen(hash, i);
+    }
+}
@@ -1,4 +1,7 @@
+mod backtrace;
+mod call_contract;
 mod corelib_usage;
+mod deploy;
 mod enum_flow;
 mod fib;
 mod fib_array;
@@ -7,11 +10,17 @@ mod fib_caller;
 mod fib_counter;
 mod fib_gas;
 mod fib_local;
+mod fib_loop;
 mod fib_struct;
-mod fib_u128_checked;
 mod fib_u128;
+mod fib_u128_checked;
 mod fib_unary;
-mod hash_chain_gas;
 mod hash_chain;
+mod hash_chain_gas;
+mod hash_chain_loop;
+mod library_call;
 mod pedersen_test;
+mod replace_class;
+mod send_message_to_l1;
 mod testing;
+mod typed_args;
@@ -0,0 +1,21 @@
+use array::ArrayTrait;
+use array::SpanTrait;
+use option::OptionTrait;
+use starknet::SyscallResultTrait;
+use super::call_contract::INCREASE_BALANCE_SELECTOR;
+use super::call_contract::GET_BALANCE_SELECTOR;
+
+// Increases the balance of the current contract by `amount`, using the `HelloStarknet` class
+// `class_hash`, and returns the updated balance.
+fn main(class_hash: felt252, amount: felt252) -> felt252 {
+    let class_hash = starknet::class_hash_try_from_felt252(class_hash).unwrap();
+    let mut calldata = ArrayTrait::new();
+    calldata.append(amount);
+    starknet::library_call_syscall(
+        class_hash, INCREASE_BALANCE_SELECTOR, calldata.span()
+    ).unwrap_syscall();
+    let retdata = starknet::library_call_syscall(
+        class_hash, GET_BALANCE_SELECTOR, ArrayTrait::new().span()
+    ).unwrap_syscall();
+    *retdata.at(0_u32)
+}
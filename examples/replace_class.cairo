@@ -0,0 +1,23 @@
+use array::ArrayTrait;
+use array::SpanTrait;
+use option::OptionTrait;
+use starknet::SyscallResultTrait;
+use super::call_contract::INCREASE_BALANCE_SELECTOR;
+use super::call_contract::GET_BALANCE_SELECTOR;
+
+// Replaces the class of the current contract with the `HelloStarknet` class `class_hash`, then
+// increases its balance by `amount` and returns the updated balance.
+fn main(class_hash: felt252, amount: felt252) -> felt252 {
+    let class_hash = starknet::class_hash_try_from_felt252(class_hash).unwrap();
+    starknet::replace_class_syscall(class_hash).unwrap_syscall();
+    let address = starknet::get_contract_address();
+    let mut calldata = ArrayTrait::new();
+    calldata.append(amount);
+    starknet::call_contract_syscall(
+        address, INCREASE_BALANCE_SELECTOR, calldata.span()
+    ).unwrap_syscall();
+    let retdata = starknet::call_contract_syscall(
+        address, GET_BALANCE_SELECTOR, ArrayTrait::new().span()
+    ).unwrap_syscall();
+    *retdata.at(0_u32)
+}
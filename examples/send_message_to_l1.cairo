@@ -0,0 +1,9 @@
+use array::ArrayTrait;
+use starknet::SyscallResultTrait;
+
+// Sends `value` to the L1 address `to_address`.
+fn main(to_address: felt252, value: felt252) {
+    let mut payload = ArrayTrait::new();
+    payload.append(value);
+    starknet::send_message_to_l1_syscall(to_address, payload.span()).unwrap_syscall();
+}
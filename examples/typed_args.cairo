@@ -0,0 +1,58 @@
+use array::ArrayTrait;
+use array::SpanTrait;
+use option::OptionTrait;
+
+#[derive(Copy, Drop)]
+struct Point {
+    x: felt252,
+    y: u32,
+}
+
+#[derive(Drop)]
+enum Shape {
+    Dot: Point,
+    Line: (Point, Point),
+    Empty: (),
+}
+
+// Returns the first value of the array, multiplied by `scale`.
+fn first(values: Array::<u32>, scale: u32) -> u32 {
+    *values.at(0_usize) * scale
+}
+
+fn span_len(values: Span::<felt252>) -> usize {
+    values.len()
+}
+
+fn shift(p: Point, by: Option::<u32>) -> Point {
+    match by {
+        Option::Some(by) => Point { x: p.x + 1, y: p.y + by },
+        Option::None(_) => p,
+    }
+}
+
+fn make(n: felt252) -> Shape {
+    if n == 0 {
+        Shape::Empty(())
+    } else if n == 1 {
+        Shape::Dot(Point { x: 1, y: 2_u32 })
+    } else {
+        Shape::Line((Point { x: 1, y: 2_u32 }, Point { x: 3, y: 4_u32 }))
+    }
+}
+
+fn count(s: Shape) -> felt252 {
+    match s {
+        Shape::Dot(_) => 1,
+        Shape::Line(_) => 2,
+        Shape::Empty(_) => 0,
+    }
+}
+
+fn negate(a: i8, b: bool) -> (i8, bool, Option::<u8>) {
+    (-a, !b, Option::None(()))
+}
+
+fn greet() -> felt252 {
+    'hello'
+}
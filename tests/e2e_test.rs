@@ -17,19 +17,28 @@ cairo_lang_test_utils::test_file_test_with_runner!(
         bool: "bool",
         box_: "box",
         builtin_costs: "builtin_costs",
+        bytes31: "bytes31",
         casts: "casts",
         ec: "ec",
         enum_: "enum",
         enum_snapshot: "enum_snapshot",
         felt252: "felt252",
         felt252_dict: "felt252_dict",
+        i8: "i8",
+        i16: "i16",
+        i32: "i32",
+        i64: "i64",
+        i128: "i128",
         nullable: "nullable",
+        poseidon: "poseidon",
         snapshot: "snapshot",
         u8: "u8",
         u16: "u16",
         u32: "u32",
         u64: "u64",
         u128: "u128",
+        u256: "u256",
+        u512: "u512",
         withdraw_gas_all: "withdraw_gas_all",
     },
     SmallE2ETestRunner
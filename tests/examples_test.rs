@@ -8,13 +8,19 @@ use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
 use cairo_lang_compiler::project::setup_project;
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_filesystem::ids::CrateId;
-use cairo_lang_runner::{RunResultValue, SierraCasmRunner, DUMMY_BUILTIN_GAS_COST};
+use cairo_lang_runner::profiling::{ProfileFormat, ProfileResource};
+use cairo_lang_runner::{
+    L2ToL1Message, RunResultValue, SierraCasmRunner, StarknetState, DUMMY_BUILTIN_GAS_COST,
+};
 use cairo_lang_semantic::ConcreteFunctionWithBodyId;
+use cairo_lang_sierra::debug_info::DebugInfo;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
 use cairo_lang_sierra_to_casm::test_utils::build_metadata;
+use cairo_lang_starknet::casm_contract_class::CasmContractClass;
 use cairo_lang_test_utils::compare_contents_or_fix_with_path;
 use cairo_lang_utils::{extract_matches, Upcast};
+use itertools::Itertools;
 use rstest::{fixture, rstest};
 
 type ExampleDirData = (Mutex<RootDatabase>, Vec<CrateId>);
@@ -49,16 +55,16 @@ fn compare_contents_or_fix(name: &str, test_type: &str, content: String) {
     compare_contents_or_fix_with_path(&path, content)
 }
 
-/// Compiles the Cairo code for submodule `name` of the examples crates to a Sierra program.
-fn checked_compile_to_sierra(
+/// Returns the functions without generics of submodule `name` of the examples crates.
+fn example_functions(
     name: &str,
-    (db, crate_ids): &ExampleDirData,
-) -> cairo_lang_sierra::program::Program {
-    let db = db.lock().unwrap().snapshot();
+    db: &RootDatabase,
+    crate_ids: &[CrateId],
+) -> Vec<ConcreteFunctionWithBodyId> {
     let mut requested_function_ids = vec![];
     for crate_id in crate_ids {
         for module_id in db.crate_modules(*crate_id).iter() {
-            if module_id.full_path(&db) != format!("examples::{name}") {
+            if module_id.full_path(db) != format!("examples::{name}") {
                 continue;
             }
             for (free_func_id, _) in db.module_free_functions(*module_id).unwrap() {
@@ -70,11 +76,33 @@ fn checked_compile_to_sierra(
             }
         }
     }
+    requested_function_ids
+}
+
+/// Compiles the Cairo code for submodule `name` of the examples crates to a Sierra program.
+fn checked_compile_to_sierra(
+    name: &str,
+    (db, crate_ids): &ExampleDirData,
+) -> cairo_lang_sierra::program::Program {
+    let db = db.lock().unwrap().snapshot();
+    let requested_function_ids = example_functions(name, &db, crate_ids);
     let sierra_program = db.get_sierra_program_for_functions(requested_function_ids).unwrap();
     replace_sierra_ids_in_program(&db, &sierra_program)
 }
 
-/// Tests lowering from Cairo to Sierra.
+/// Compiles the Cairo code for submodule `name` of the examples crates to a Sierra program, along
+/// with its debug info.
+fn checked_compile_to_sierra_with_debug_info(
+    name: &str,
+    (db, crate_ids): &ExampleDirData,
+) -> (cairo_lang_sierra::program::Program, DebugInfo) {
+    let db = db.lock().unwrap().snapshot();
+    let requested_function_ids = example_functions(name, &db, crate_ids);
+    let sierra_program =
+        db.get_sierra_program_with_debug_info_for_functions(requested_function_ids).unwrap();
+    (replace_sierra_ids_in_program(&db, &sierra_program.program), sierra_program.debug_info.clone())
+}
+
 #[rstest]
 #[case::fib("fib")]
 #[case::fib_box("fib_box")]
@@ -85,11 +113,13 @@ fn checked_compile_to_sierra(
 #[case::fib_u128_checked("fib_u128_checked")]
 #[case::fib_gas("fib_gas")]
 #[case::fib_local("fib_local")]
+#[case::fib_loop("fib_loop")]
 #[case::fib_unary("fib_unary")]
 #[case::enum_flow("enum_flow")]
 #[case::corelib_usage("corelib_usage")]
 #[case::hash_chain("hash_chain")]
 #[case::hash_chain_gas("hash_chain_gas")]
+#[case::hash_chain_loop("hash_chain_loop")]
 #[case::pedersen_test("pedersen_test")]
 #[case::testing("testing")]
 fn cairo_to_sierra(#[case] name: &str, example_dir_data: &ExampleDirData) {
@@ -111,11 +141,13 @@ fn cairo_to_sierra(#[case] name: &str, example_dir_data: &ExampleDirData) {
 #[case::fib_u128_checked("fib_u128_checked", false)]
 #[case::fib_gas("fib_gas", true)]
 #[case::fib_local("fib_local", false)]
+#[case::fib_loop("fib_loop", true)]
 #[case::fib_unary("fib_unary", false)]
 #[case::enum_flow("enum_flow", false)]
 #[case::corelib_usage("corelib_usage", false)]
 #[case::hash_chain("hash_chain", false)]
 #[case::hash_chain_gas("hash_chain_gas", true)]
+#[case::hash_chain_loop("hash_chain_loop", true)]
 #[case::pedersen_test("pedersen_test", false)]
 #[case::testing("testing", false)]
 fn cairo_to_casm(
@@ -212,6 +244,16 @@ fn run_function(
     &[6].map(Felt252::from), None, None,
     RunResultValue::Success(vec![Felt252::from(13)])
 )]
+#[case::fib_loop_pass(
+    "fib_loop",
+    &[1, 1, 10].map(Felt252::from), Some(200000), None,
+    RunResultValue::Success([89].map(Felt252::from).into_iter().collect())
+)]
+#[case::fib_loop_fail(
+    "fib_loop",
+    &[1, 1, 10].map(Felt252::from), Some(5000), None,
+    RunResultValue::Panic(vec![Felt252::from_bytes_be(b"Out of gas")])
+)]
 #[case::fib_unary(
     "fib_unary",
     &[7].map(Felt252::from), None, None,
@@ -227,6 +269,15 @@ fn run_function(
     &[3].map(Felt252::from), Some(100000), Some(9880 + 3 * DUMMY_BUILTIN_GAS_COST),
     RunResultValue::Success(vec![felt252_str!(
         "2dca1ad81a6107a9ef68c69f791bcdbda1df257aab76bd43ded73d96ed6227d", 16)]))]
+#[case::hash_chain_loop_pass(
+    "hash_chain_loop",
+    &[3].map(Felt252::from), Some(100000), None,
+    RunResultValue::Success(vec![felt252_str!(
+        "2dca1ad81a6107a9ef68c69f791bcdbda1df257aab76bd43ded73d96ed6227d", 16)]))]
+#[case::hash_chain_loop_fail(
+    "hash_chain_loop",
+    &[3].map(Felt252::from), Some(20000), None,
+    RunResultValue::Panic(vec![Felt252::from_bytes_be(b"Out of gas")]))]
 #[case::testing("testing", &[], None, None, RunResultValue::Success(vec![]))]
 fn run_function_test(
     #[case] name: &str,
@@ -261,3 +312,281 @@ fn run_fib_array_len(#[case] n: usize, #[case] last: usize, example_dir_data: &E
         [_, _, actual_last, actual_len] if actual_last == &Felt252::from(last) && actual_len == &Felt252::from(n)
     );
 }
+
+#[rstest]
+#[case::array("first", "[[4, 2, 3], 10]", "40")]
+#[case::span("span_len", r#"[[1, "0x2", "'a'"]]"#, "3")]
+#[case::empty_span("span_len", "[[]]", "0")]
+#[case::some("shift", "[[5, 6], 7]", "examples::typed_args::Point { 6, 13 }")]
+#[case::none("shift", "[[5, 6], null]", "examples::typed_args::Point { 5, 6 }")]
+#[case::unit_variant("make", "[0]", "examples::typed_args::Shape::2")]
+#[case::struct_variant(
+    "make",
+    "[1]",
+    "examples::typed_args::Shape::0(examples::typed_args::Point { 1, 2 })"
+)]
+#[case::enum_arg("count", r#"[{"1": [[1, 2], [3, 4]]}]"#, "2")]
+#[case::signed("negate", "[-5, true]", "(5, false, None)")]
+#[case::short_string("greet", "[]", "448378203247 ('hello')")]
+fn run_function_with_typed_args(
+    #[case] name: &str,
+    #[case] args: &str,
+    #[case] expected_output: &str,
+    example_dir_data: &ExampleDirData,
+) {
+    let runner =
+        SierraCasmRunner::new(checked_compile_to_sierra("typed_args", example_dir_data), false)
+            .expect("Failed setting up runner.");
+    let name_suffix = format!("::{name}");
+    let func = runner.find_function(&name_suffix).unwrap();
+    let args = runner
+        .args_from_json(func, &serde_json::from_str::<Vec<serde_json::Value>>(args).unwrap())
+        .expect("Failed parsing the arguments.");
+    let result = runner
+        .run_function_with_args(&name_suffix, &args, None, StarknetState::default())
+        .expect("Failed running the function.");
+    let values = extract_matches!(result.value, RunResultValue::Success);
+    let value = runner.decode_value(&runner.return_type(func).unwrap(), &values, &result.memory);
+    assert_eq!(value.unwrap().to_string(), expected_output);
+}
+
+#[rstest]
+fn run_panic_backtrace(example_dir_data: &ExampleDirData) {
+    let (program, debug_info) =
+        checked_compile_to_sierra_with_debug_info("backtrace", example_dir_data);
+    let runner =
+        SierraCasmRunner::new(program, false).expect("Failed setting up runner.").with_trace(true);
+    let result = runner.run_function("::main", &[], None).expect("Failed running the function.");
+    assert_eq!(result.value, RunResultValue::Panic(vec![Felt252::from_bytes_be(b"b is not zero")]));
+    assert_eq!(
+        runner
+            .panic_backtrace(&result.trace.unwrap())
+            .into_iter()
+            .map(|frame| {
+                let location = &debug_info.statement_locations[&frame.statement_idx];
+                format!("{} at {}:{}", frame.function, location.file_name, location.line)
+            })
+            .collect_vec(),
+        [
+            "core::assert at lib.cairo:552",
+            "examples::backtrace::bar at backtrace.cairo:13",
+            "examples::backtrace::foo at backtrace.cairo:8",
+            "examples::backtrace::main at backtrace.cairo:3"
+        ]
+    );
+}
+
+#[rstest]
+#[case::pedersen(
+    "hash_chain",
+    "hash_chain",
+    &[2],
+    ProfileResource::Pedersen,
+    "examples::hash_chain::hash_chain 1\n\
+     examples::hash_chain::hash_chain;examples::hash_chain::hash_chain 1\n"
+)]
+#[case::range_check(
+    "fib_u128_checked",
+    "fib",
+    &[1, 1, 1],
+    ProfileResource::RangeCheck,
+    "examples::fib_u128_checked::fib;core::integer::u128_checked_add 1\n\
+     examples::fib_u128_checked::fib;core::integer::u128_checked_sub 1\n"
+)]
+fn run_with_profiling(
+    #[case] example: &str,
+    #[case] name: &str,
+    #[case] params: &[usize],
+    #[case] resource: ProfileResource,
+    #[case] expected_report: &str,
+    example_dir_data: &ExampleDirData,
+) {
+    let runner = SierraCasmRunner::new(checked_compile_to_sierra(example, example_dir_data), false)
+        .expect("Failed setting up runner.")
+        .with_profiling(true);
+    let args = params.iter().map(|param| Felt252::from(*param)).collect_vec();
+    let result = runner
+        .run_function(&format!("::{name}"), &args, None)
+        .expect("Failed running the function.");
+    assert_eq!(
+        result.profiling_info.unwrap().report(resource, ProfileFormat::Collapsed),
+        expected_report
+    );
+}
+
+/// Loads the compiled contract class `name` from the test data of the starknet crate.
+fn load_test_contract_class(name: &str) -> CasmContractClass {
+    let class_path: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "../crates/cairo-lang-starknet/test_data",
+        &format!("{name}.casm.json"),
+    ]
+    .into_iter()
+    .collect();
+    serde_json::from_str(&std::fs::read_to_string(class_path).unwrap()).unwrap()
+}
+
+#[rstest]
+#[case::single_call(&[5], 5)]
+#[case::multiple_calls(&[5, 7, 11], 23)]
+fn run_call_contract(
+    #[case] amounts: &[usize],
+    #[case] expected_balance: usize,
+    example_dir_data: &ExampleDirData,
+) {
+    let runner =
+        SierraCasmRunner::new(checked_compile_to_sierra("call_contract", example_dir_data), true)
+            .expect("Failed setting up runner.");
+    let (class_hash, contract_address) = (Felt252::from(1), Felt252::from(2));
+    let mut starknet_state = StarknetState::default();
+    starknet_state.declare_class(class_hash.clone(), load_test_contract_class("hello_starknet"));
+    starknet_state.deploy_contract(contract_address.clone(), class_hash);
+    let mut balance = Felt252::from(0);
+    for amount in amounts {
+        let result = runner
+            .run_function_with_starknet_state(
+                "::main",
+                &[contract_address.clone(), Felt252::from(*amount)],
+                Some(usize::MAX),
+                starknet_state,
+            )
+            .expect("Failed running the function.");
+        starknet_state = result.starknet_state;
+        balance = extract_matches!(result.value, RunResultValue::Success)[0].clone();
+    }
+    assert_eq!(balance, Felt252::from(expected_balance));
+}
+
+#[rstest]
+fn run_call_contract_not_deployed(example_dir_data: &ExampleDirData) {
+    let runner =
+        SierraCasmRunner::new(checked_compile_to_sierra("call_contract", example_dir_data), true)
+            .expect("Failed setting up runner.");
+    let result = runner
+        .run_function("::main", &[Felt252::from(2), Felt252::from(5)], Some(usize::MAX))
+        .expect("Failed running the function.");
+    assert_eq!(
+        result.value,
+        RunResultValue::Panic(vec![Felt252::from_bytes_be(b"CONTRACT_NOT_DEPLOYED")])
+    );
+}
+
+#[rstest]
+fn run_deploy(example_dir_data: &ExampleDirData) {
+    let runner = SierraCasmRunner::new(checked_compile_to_sierra("deploy", example_dir_data), true)
+        .expect("Failed setting up runner.");
+    let class_hash = Felt252::from(1);
+    let mut starknet_state = StarknetState::default();
+    starknet_state.declare_class(class_hash.clone(), load_test_contract_class("erc20"));
+    let args = [class_hash, Felt252::from(3), Felt252::from(1000)];
+    let result = runner
+        .run_function_with_starknet_state("::main", &args, Some(usize::MAX), starknet_state)
+        .expect("Failed running the function.");
+    assert_eq!(result.value, RunResultValue::Success(vec![Felt252::from(1000)]));
+    // Deploying again with the same salt and calldata results in the same address.
+    let result = runner
+        .run_function_with_starknet_state("::main", &args, Some(usize::MAX), result.starknet_state)
+        .expect("Failed running the function.");
+    assert_eq!(
+        result.value,
+        RunResultValue::Panic(vec![Felt252::from_bytes_be(b"CONTRACT_ALREADY_DEPLOYED")])
+    );
+}
+
+#[rstest]
+fn run_deploy_failing_constructor(example_dir_data: &ExampleDirData) {
+    let runner = SierraCasmRunner::new(checked_compile_to_sierra("deploy", example_dir_data), true)
+        .expect("Failed setting up runner.");
+    let class_hash = Felt252::from(1);
+    let mut starknet_state = StarknetState::default();
+    starknet_state.declare_class(class_hash.clone(), load_test_contract_class("erc20"));
+    // Minting to the zero address fails the constructor.
+    let args = [class_hash, Felt252::from(0), Felt252::from(1000)];
+    let result = runner
+        .run_function_with_starknet_state("::main", &args, Some(usize::MAX), starknet_state)
+        .expect("Failed running the function.");
+    assert_eq!(
+        result.value,
+        RunResultValue::Panic(vec![Felt252::from_bytes_be(b"ERC20: mint to the 0 address")])
+    );
+}
+
+#[rstest]
+fn run_library_call(example_dir_data: &ExampleDirData) {
+    let runner =
+        SierraCasmRunner::new(checked_compile_to_sierra("library_call", example_dir_data), true)
+            .expect("Failed setting up runner.");
+    let class_hash = Felt252::from(1);
+    let mut starknet_state = StarknetState::default();
+    starknet_state.declare_class(class_hash.clone(), load_test_contract_class("hello_starknet"));
+    let mut balance = Felt252::from(0);
+    for amount in [5, 7] {
+        let result = runner
+            .run_function_with_starknet_state(
+                "::main",
+                &[class_hash.clone(), Felt252::from(amount)],
+                Some(usize::MAX),
+                starknet_state,
+            )
+            .expect("Failed running the function.");
+        starknet_state = result.starknet_state;
+        balance = extract_matches!(result.value, RunResultValue::Success)[0].clone();
+    }
+    // The balance is kept in the storage of the calling contract.
+    assert_eq!(balance, Felt252::from(12));
+}
+
+#[rstest]
+fn run_library_call_not_declared(example_dir_data: &ExampleDirData) {
+    let runner =
+        SierraCasmRunner::new(checked_compile_to_sierra("library_call", example_dir_data), true)
+            .expect("Failed setting up runner.");
+    let result = runner
+        .run_function("::main", &[Felt252::from(1), Felt252::from(5)], Some(usize::MAX))
+        .expect("Failed running the function.");
+    assert_eq!(
+        result.value,
+        RunResultValue::Panic(vec![Felt252::from_bytes_be(b"CLASS_HASH_NOT_DECLARED")])
+    );
+}
+
+#[rstest]
+fn run_replace_class(example_dir_data: &ExampleDirData) {
+    let runner =
+        SierraCasmRunner::new(checked_compile_to_sierra("replace_class", example_dir_data), true)
+            .expect("Failed setting up runner.");
+    let (minimal_class_hash, hello_class_hash) = (Felt252::from(1), Felt252::from(2));
+    let mut starknet_state = StarknetState::default();
+    starknet_state
+        .declare_class(minimal_class_hash.clone(), load_test_contract_class("minimal_contract"));
+    starknet_state
+        .declare_class(hello_class_hash.clone(), load_test_contract_class("hello_starknet"));
+    // The contract running the function, with the default address.
+    starknet_state.deploy_contract(Felt252::from(0), minimal_class_hash);
+    let result = runner
+        .run_function_with_starknet_state(
+            "::main",
+            &[hello_class_hash, Felt252::from(5)],
+            Some(usize::MAX),
+            starknet_state,
+        )
+        .expect("Failed running the function.");
+    assert_eq!(result.value, RunResultValue::Success(vec![Felt252::from(5)]));
+}
+
+#[rstest]
+fn run_send_message_to_l1(example_dir_data: &ExampleDirData) {
+    let runner = SierraCasmRunner::new(
+        checked_compile_to_sierra("send_message_to_l1", example_dir_data),
+        true,
+    )
+    .expect("Failed setting up runner.");
+    let result = runner
+        .run_function("::main", &[Felt252::from(3), Felt252::from(5)], Some(usize::MAX))
+        .expect("Failed running the function.");
+    assert_eq!(result.value, RunResultValue::Success(vec![]));
+    assert_eq!(
+        result.starknet_state.logs()[&Felt252::from(0)].l2_to_l1_messages,
+        [L2ToL1Message { to_address: Felt252::from(3), payload: vec![Felt252::from(5)] }]
+    );
+}